@@ -12,12 +12,16 @@ try {
     console.log("✅ ao-sync-sdk loaded successfully from CDN:", WalletClient);
 } catch (error) {
     console.error("❌ Failed to import ao-sync-sdk via CDN:", error);
-    console.log("⚠️ Will fall back to mock implementation");
+    console.log("⚠️ Beacon wallet will be unavailable");
     WalletClient = null;
 }
 
 // Expose WalletClient to global scope for WASM access
 if (WalletClient) {
+    // The app draws its own pairing QR code (see BeaconPairingDialog), so
+    // keep the SDK from opening its modal on top of it
+    WalletClient.prototype.createModal = function () {};
+
     window.WalletClient = WalletClient;
     console.log("✅ Real WalletClient from ao-sync-sdk assigned to window.WalletClient");
     console.log("🔍 window.WalletClient type:", typeof window.WalletClient);
@@ -51,69 +55,22 @@ if (WalletClient) {
         
     } catch (e) {
         console.error("❌ Failed to create ao-sync-sdk instance:", e);
-        console.log("⚠️ Real SDK failed, will use mock instead");
+        console.log("⚠️ Beacon wallet will be unavailable");
         WalletClient = null;
+        delete window.WalletClient;
     }
 }
 
-// If we don't have real SDK, create mock
-if (!WalletClient) {
-    console.warn("🛠️ Creating mock WalletClient as fallback");
-    
-    window.WalletClient = class MockWalletClient {
-        constructor() {
-            console.log("🆕 Fallback Mock WalletClient created");
-        }
-        
-        async connect(options) {
-            console.log("🔗 Mock connect called with options:", options);
-            await new Promise(resolve => setTimeout(resolve, 1000));
-            return {
-                address: "mock_beacon_address_" + Math.random().toString(36).substr(2, 9)
-            };
-        }
-        
-        async disconnect() {
-            console.log("🔌 Mock disconnect called");
-            return true;
-        }
-        
-        async sign(transaction) {
-            console.log("✍️ Mock sign called:", transaction);
-            return {
-                ...transaction,
-                signature: "mock_signature_" + Math.random().toString(36).substr(2, 9)
-            };
-        }
-        
-        async signDataItem(dataItem) {
-            console.log("✍️ Mock signDataItem called:", dataItem);
-            return new ArrayBuffer(64); // Mock signature buffer
-        }
-        
-        async reconnect() {
-            console.log("🔄 Mock reconnect called");
-            return this.connect({});
-        }
-        
-        on(event, callback) {
-            console.log("👂 Mock event listener registered:", event);
-        }
-    };
-}
-
 // Final verification
 console.log("🔍 Final check - window.WalletClient exists:", typeof window.WalletClient !== 'undefined');
 console.log("🏗️ Final check - window.WalletClient is function:", typeof window.WalletClient === 'function');
 
-// Dispatch event to notify WASM that WalletClient is ready
-const isReal = WalletClient && window.WalletClient && window.WalletClient.name !== 'MockWalletClient';
+// Dispatch event to notify WASM whether WalletClient is ready
 window.dispatchEvent(new CustomEvent('walletClientReady', {
     detail: {
-        available: typeof window.WalletClient !== 'undefined',
-        type: 'beacon',
-        isReal: isReal
+        available: typeof window.WalletClient === 'function',
+        type: 'beacon'
     }
 }));
 
-console.log(`📡 Dispatched walletClientReady event - Using ${isReal ? 'Real' : 'Mock'} SDK`);
\ No newline at end of file
+console.log(`📡 Dispatched walletClientReady event - Beacon ${WalletClient ? 'available' : 'unavailable'}`);
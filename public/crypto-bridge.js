@@ -0,0 +1,135 @@
+/**
+ * Faithful Archive - Crypto Bridge
+ *
+ * WebCrypto helpers for encrypting data to a recipient's RSA public key.
+ * Data is encrypted with a fresh AES-GCM key, which is then wrapped with
+ * RSA-OAEP so only the holder of the private key can read it.
+ *
+ * Also encrypts private archive content with AES-GCM under a raw key,
+ * which may be derived from a passphrase with PBKDF2, and checks Arweave
+ * RSA-PSS signatures.
+ */
+
+window.cryptoBridge = {
+    toBase64(bytes) {
+        let binary = '';
+        new Uint8Array(bytes).forEach((b) => { binary += String.fromCharCode(b); });
+        return btoa(binary);
+    },
+
+    /**
+     * Encrypt data for the holder of an RSA-OAEP public key
+     * @param {Uint8Array} plaintext - data to encrypt
+     * @param {string} publicKeyJwk - recipient public key as a JWK JSON string
+     * @returns {Promise<string>} JSON envelope with wrapped key, IV and ciphertext (base64)
+     */
+    async encryptForRecipient(plaintext, publicKeyJwk) {
+        const publicKey = await crypto.subtle.importKey(
+            'jwk',
+            JSON.parse(publicKeyJwk),
+            { name: 'RSA-OAEP', hash: 'SHA-256' },
+            false,
+            ['wrapKey']
+        );
+
+        const contentKey = await crypto.subtle.generateKey(
+            { name: 'AES-GCM', length: 256 },
+            true,
+            ['encrypt']
+        );
+        const iv = crypto.getRandomValues(new Uint8Array(12));
+
+        const ciphertext = await crypto.subtle.encrypt({ name: 'AES-GCM', iv }, contentKey, plaintext);
+        const wrappedKey = await crypto.subtle.wrapKey('raw', contentKey, publicKey, { name: 'RSA-OAEP' });
+
+        return JSON.stringify({
+            alg: 'RSA-OAEP-256+A256GCM',
+            key: this.toBase64(wrappedKey),
+            iv: this.toBase64(iv),
+            data: this.toBase64(ciphertext)
+        });
+    },
+
+    PBKDF2_ITERATIONS: 310000,
+
+    /**
+     * Derive a 256-bit AES key from a passphrase
+     * @param {string} passphrase
+     * @param {Uint8Array} salt - random per item, stored alongside it
+     * @returns {Promise<Uint8Array>} raw key bytes
+     */
+    async deriveKey(passphrase, salt) {
+        const material = await crypto.subtle.importKey(
+            'raw',
+            new TextEncoder().encode(passphrase),
+            'PBKDF2',
+            false,
+            ['deriveBits']
+        );
+        const bits = await crypto.subtle.deriveBits(
+            { name: 'PBKDF2', salt, iterations: this.PBKDF2_ITERATIONS, hash: 'SHA-256' },
+            material,
+            256
+        );
+        return new Uint8Array(bits);
+    },
+
+    /**
+     * Encrypt with AES-256-GCM under a raw key
+     * @param {Uint8Array} plaintext
+     * @param {Uint8Array} rawKey - 32 bytes
+     * @returns {Promise<Uint8Array>} 12-byte IV followed by the ciphertext
+     */
+    async encryptWithKey(plaintext, rawKey) {
+        const key = await crypto.subtle.importKey('raw', rawKey, 'AES-GCM', false, ['encrypt']);
+        const iv = crypto.getRandomValues(new Uint8Array(12));
+        const ciphertext = await crypto.subtle.encrypt({ name: 'AES-GCM', iv }, key, plaintext);
+
+        const envelope = new Uint8Array(iv.byteLength + ciphertext.byteLength);
+        envelope.set(iv, 0);
+        envelope.set(new Uint8Array(ciphertext), iv.byteLength);
+        return envelope;
+    },
+
+    /**
+     * Decrypt the output of encryptWithKey; rejects if the key is wrong
+     * @param {Uint8Array} envelope - IV followed by the ciphertext
+     * @param {Uint8Array} rawKey - 32 bytes
+     * @returns {Promise<Uint8Array>} plaintext
+     */
+    async decryptWithKey(envelope, rawKey) {
+        const key = await crypto.subtle.importKey('raw', rawKey, 'AES-GCM', false, ['decrypt']);
+        const plaintext = await crypto.subtle.decrypt(
+            { name: 'AES-GCM', iv: envelope.subarray(0, 12) },
+            key,
+            envelope.subarray(12)
+        );
+        return new Uint8Array(plaintext);
+    },
+
+    /**
+     * Check an Arweave RSA-PSS signature (SHA-256). Wallets sign with a
+     * salt of 32 bytes, none, or the most the key allows, so like
+     * arweave-js this accepts any of the three.
+     * @param {string} owner - RSA modulus, base64url, as in a transaction's owner field
+     * @param {Uint8Array} signature - signature bytes
+     * @param {Uint8Array} message - signed message, e.g. a deep hash
+     * @returns {Promise<boolean>} whether the signature is valid
+     */
+    async verifyRsaPss(owner, signature, message) {
+        const key = await crypto.subtle.importKey(
+            'jwk',
+            { kty: 'RSA', n: owner, e: 'AQAB', alg: 'PS256', ext: true },
+            { name: 'RSA-PSS', hash: 'SHA-256' },
+            false,
+            ['verify']
+        );
+        const keyBytes = Math.ceil((key.algorithm.modulusLength - 1) / 8);
+        for (const saltLength of [32, 0, keyBytes - 32 - 2]) {
+            if (await crypto.subtle.verify({ name: 'RSA-PSS', saltLength }, key, signature, message)) {
+                return true;
+            }
+        }
+        return false;
+    }
+};
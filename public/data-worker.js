@@ -0,0 +1,64 @@
+/**
+ * Faithful Archive - Data Worker
+ *
+ * Runs hashing off the main thread so multi-hundred-MB uploads don't
+ * freeze the UI. Requests arrive as { id, task, payload } and are answered
+ * with progress, done or error messages carrying the same id.
+ */
+
+const CHUNK_SIZE = 256 * 1024;
+
+function toHex(buffer) {
+    return Array.from(new Uint8Array(buffer), (b) => b.toString(16).padStart(2, '0')).join('');
+}
+
+/**
+ * Bytes to hash: `data` as sent, or the whole of a chosen file's `blob`,
+ * read here so it never passes through the page
+ */
+async function input({ data, blob }) {
+    return data || new Uint8Array(await blob.arrayBuffer());
+}
+
+/**
+ * SHA-256 of each 256 KiB chunk and of the whole file
+ */
+async function digest(payload, progress) {
+    const data = await input(payload);
+    const chunkHashes = [];
+    for (let offset = 0; offset < data.byteLength; offset += CHUNK_SIZE) {
+        const chunk = data.subarray(offset, Math.min(offset + CHUNK_SIZE, data.byteLength));
+        chunkHashes.push(toHex(await crypto.subtle.digest('SHA-256', chunk)));
+        progress(Math.min(offset + CHUNK_SIZE, data.byteLength) / data.byteLength);
+    }
+    const sha256 = toHex(await crypto.subtle.digest('SHA-256', data));
+    return { sha256, chunkHashes, size: data.byteLength };
+}
+
+/**
+ * SHA-384 of the data, which an ANS-104 deep hash commits to
+ */
+async function sha384(payload, progress) {
+    const hash = new Uint8Array(await crypto.subtle.digest('SHA-384', await input(payload)));
+    progress(1);
+    return hash;
+}
+
+const TASKS = { digest, sha384 };
+
+self.onmessage = async (event) => {
+    const { id, task, payload } = event.data;
+    const progress = (fraction) => self.postMessage({ id, type: 'progress', fraction });
+
+    try {
+        const run = TASKS[task];
+        if (!run) {
+            throw new Error(`Unknown task: ${task}`);
+        }
+        const result = await run(payload, progress);
+        const transfer = result instanceof Uint8Array ? [result.buffer] : [];
+        self.postMessage({ id, type: 'done', result }, transfer);
+    } catch (error) {
+        self.postMessage({ id, type: 'error', message: error.message || String(error) });
+    }
+};
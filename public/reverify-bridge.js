@@ -0,0 +1,54 @@
+/**
+ * Faithful Archive - Re-verification Bridge
+ *
+ * Registers the service worker used for scheduled archive re-verification
+ * and forwards its periodic sync wake-ups to the WASM application
+ */
+
+window.reverifyBridge = {
+    SYNC_TAG: 'faithful-archive-reverify',
+    listeners: [],
+
+    /**
+     * Register the service worker and request a periodic sync
+     * @param {number} minInterval - minimum interval between syncs in milliseconds
+     * @returns {Promise<boolean>} resolves true when periodic sync is registered
+     */
+    async register(minInterval) {
+        if (!('serviceWorker' in navigator)) {
+            throw new Error('Service workers are not supported');
+        }
+
+        const registration = await navigator.serviceWorker.register('/sw.js');
+        await navigator.serviceWorker.ready;
+
+        if (!('periodicSync' in registration)) {
+            throw new Error('Periodic background sync is not supported');
+        }
+
+        const status = await navigator.permissions.query({ name: 'periodic-background-sync' });
+        if (status.state !== 'granted') {
+            throw new Error('Periodic background sync permission not granted');
+        }
+
+        await registration.periodicSync.register(this.SYNC_TAG, { minInterval });
+        console.log('🗓️ Periodic re-verification registered');
+        return true;
+    },
+
+    /**
+     * Subscribe to periodic sync wake-ups from the service worker
+     * @param {Function} callback - invoked with no arguments
+     */
+    onReverify(callback) {
+        this.listeners.push(callback);
+    }
+};
+
+if ('serviceWorker' in navigator) {
+    navigator.serviceWorker.addEventListener('message', (event) => {
+        if (event.data && event.data.type === window.reverifyBridge.SYNC_TAG) {
+            window.reverifyBridge.listeners.forEach((callback) => callback());
+        }
+    });
+}
@@ -0,0 +1,28 @@
+/**
+ * Faithful Archive - Service Worker
+ *
+ * Handles periodic background sync for scheduled archive re-verification.
+ * The checks themselves run in the WASM application, so the worker only
+ * wakes any open Faithful Archive tabs; closed browsers catch up on the
+ * next launch.
+ */
+
+const REVERIFY_TAG = 'faithful-archive-reverify';
+
+self.addEventListener('install', () => self.skipWaiting());
+
+self.addEventListener('activate', (event) => {
+    event.waitUntil(self.clients.claim());
+});
+
+self.addEventListener('periodicsync', (event) => {
+    if (event.tag !== REVERIFY_TAG) {
+        return;
+    }
+
+    event.waitUntil(
+        self.clients.matchAll({ type: 'window' }).then((clients) => {
+            clients.forEach((client) => client.postMessage({ type: REVERIFY_TAG }));
+        })
+    );
+});
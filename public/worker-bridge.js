@@ -0,0 +1,69 @@
+/**
+ * Faithful Archive - Worker Bridge
+ *
+ * Starts the data worker on first use and matches its replies to the
+ * WASM application's requests, forwarding progress as it arrives
+ */
+
+window.workerBridge = {
+    worker: null,
+    nextId: 0,
+    pending: new Map(),
+
+    /**
+     * Whether dedicated workers are available in this browser
+     * @returns {boolean}
+     */
+    isSupported() {
+        return typeof Worker !== 'undefined';
+    },
+
+    start() {
+        if (this.worker) {
+            return this.worker;
+        }
+
+        this.worker = new Worker('/data-worker.js');
+        this.worker.onmessage = (event) => {
+            const { id, type } = event.data;
+            const request = this.pending.get(id);
+            if (!request) {
+                return;
+            }
+
+            if (type === 'progress') {
+                request.onProgress(event.data.fraction);
+                return;
+            }
+            this.pending.delete(id);
+            if (type === 'done') {
+                request.resolve(event.data.result);
+            } else {
+                request.reject(new Error(event.data.message));
+            }
+        };
+        this.worker.onerror = (event) => {
+            console.error('❌ Data worker failed:', event.message);
+            this.pending.forEach((request) => request.reject(new Error(event.message)));
+            this.pending.clear();
+            this.worker = null;
+        };
+        return this.worker;
+    },
+
+    /**
+     * Run a task in the data worker
+     * @param {string} task - "digest" or "sha384"
+     * @param {Object} payload - task input, `data` bytes or a file's `blob`; typed arrays are copied to the worker
+     * @param {Function} onProgress - invoked with the fraction done, 0 to 1
+     * @returns {Promise<*>} the task's result
+     */
+    run(task, payload, onProgress) {
+        const worker = this.start();
+        const id = this.nextId++;
+        return new Promise((resolve, reject) => {
+            this.pending.set(id, { resolve, reject, onProgress: onProgress || (() => {}) });
+            worker.postMessage({ id, task, payload });
+        });
+    }
+};
@@ -1,60 +1,138 @@
 #![allow(non_snake_case)]
 
 use dioxus::prelude::*;
-use crate::services::arweave::ArweaveService;
-use crate::services::wallet::init_wallet_service;
-use crate::components::WalletConnectButton;
+use crate::services::wallet::{init_wallet_service, init_connection_monitor, use_wallet_idle_lock};
+use crate::services::reverify::init_reverification_scheduler;
+use crate::services::gateway::init_gateway_benchmark;
+use crate::services::search::init_search_index;
+use crate::services::bookmarks::init_bookmarks;
+use crate::services::identity::init_identity;
+use crate::services::playback::init_playback_positions;
+use crate::services::releases::init_release_notes;
+use crate::services::sync::init_background_sync;
+use crate::services::settings::{init_settings, use_browse_start};
+use crate::services::analytics::init_usage_stats;
+use crate::services::notifications::init_notifications;
+use crate::config::provide_app_config;
+use crate::i18n::{init_i18n, t};
+use crate::models::{BrowseQuery, SearchQuery};
+use crate::components::{WalletConnectButton, SearchBox, SearchPage, Browse, ContentDetail, StudyWorkspace, Upload, SeriesDetail, Collections, CollectionDetail, Library, WeeklyDigest, MigrateLocalData, MigrationPrompt, WhatsNew, WhatsNewBanner, ArchiveHealth, Settings, RightsClaimForm, ClaimReview, NameRedirect, ProfileEditor, UploaderPage, FollowingFeed, EmbedPlayer, AppErrorBoundary, CrashBoundary, ToastHost, ThemeProvider, ThemeToggle, GlobalShortcuts, NetworkBanner, ConnectionBanner, BeaconPairingDialog, DownloadsPanel, SyncIndicator, UsageStats, ReceiptHistory, ReceiptView, BulkImport, NotificationBell, ModerationAudit, SubmissionQueue, AdminDashboard, HomeStats, HomeFeed};
+
+/// Application routes
+#[derive(Routable, Clone, PartialEq)]
+pub enum Route {
+    #[layout(AppLayout)]
+        #[route("/")]
+        Home {},
+        #[route("/browse?:..query")]
+        Browse { query: BrowseQuery },
+        #[route("/following")]
+        FollowingFeed {},
+        #[route("/search?:..query")]
+        SearchPage { query: SearchQuery },
+        #[route("/item/:tx_id")]
+        ContentDetail { tx_id: String },
+        #[route("/study/:tx_id")]
+        StudyWorkspace { tx_id: String },
+        #[route("/upload")]
+        Upload {},
+        #[route("/upload/bulk")]
+        BulkImport {},
+        #[route("/series/:series_id")]
+        SeriesDetail { series_id: String },
+        #[route("/collections")]
+        Collections {},
+        #[route("/collection/:collection_id")]
+        CollectionDetail { collection_id: String },
+        #[route("/library")]
+        Library {},
+        #[route("/digest")]
+        WeeklyDigest {},
+        #[route("/migrate")]
+        MigrateLocalData {},
+        #[route("/whats-new")]
+        WhatsNew {},
+        #[route("/health")]
+        ArchiveHealth {},
+        #[route("/settings")]
+        Settings {},
+        #[route("/rights-claim")]
+        RightsClaimForm {},
+        #[route("/moderation/claims")]
+        ClaimReview {},
+        #[route("/moderation/submissions")]
+        SubmissionQueue {},
+        #[route("/moderation/log")]
+        ModerationAudit {},
+        #[route("/admin/stats")]
+        AdminDashboard {},
+        #[route("/name/:name")]
+        NameRedirect { name: String },
+        #[route("/profile")]
+        ProfileEditor {},
+        #[route("/uploader/:address")]
+        UploaderPage { address: String },
+        #[route("/stats")]
+        UsageStats {},
+        #[route("/receipts")]
+        ReceiptHistory {},
+        #[route("/receipts/:tx_id")]
+        ReceiptView { tx_id: String },
+    #[end_layout]
+    // Bare player for iframes on other sites
+    #[route("/embed/:tx_id")]
+    EmbedPlayer { tx_id: String },
+}
 
 #[component]
 pub fn App() -> Element {
-    // Initialize wallet service on app startup
+    provide_app_config();
+
+    // Initialize wallet service and background jobs on app startup
     use_effect(move || {
         init_wallet_service();
+        init_connection_monitor();
+        init_reverification_scheduler();
+        init_gateway_benchmark();
+        init_search_index();
+        init_bookmarks();
+        init_identity();
+        init_playback_positions();
+        init_release_notes();
+        init_background_sync();
+        init_i18n();
+        init_settings();
+        init_usage_stats();
+        init_notifications();
     });
-    
-    // State for testing bundles-rs integration
-    let mut test_result = use_signal(|| String::new());
-    let mut is_testing = use_signal(|| false);
 
-    // Test function for bundles-rs integration
-    let test_bundles_rs = move |_| {
-        spawn(async move {
-            is_testing.set(true);
-            test_result.set("Testing bundles-rs integration...".to_string());
-            
-            match ArweaveService::new_random() {
-                Ok(service) => {
-                    let address = service.get_address();
-                    match service.create_test_item("Hello from Faithful Archive!") {
-                        Ok(item) => {
-                            let item_id = service.get_item_id(&item);
-                            match service.serialize_item(&item) {
-                                Ok(bytes) => {
-                                    test_result.set(format!(
-                                        "✅ Success!\nSigner Address: {}\nDataItem ID: {}\nSerialized Size: {} bytes",
-                                        address, item_id, bytes.len()
-                                    ));
-                                }
-                                Err(e) => test_result.set(format!("❌ Serialization failed: {}", e)),
-                            }
-                        }
-                        Err(e) => test_result.set(format!("❌ DataItem creation failed: {}", e)),
-                    }
-                }
-                Err(e) => test_result.set(format!("❌ Service creation failed: {}", e)),
-            }
-            is_testing.set(false);
-        });
-    };
     rsx! {
         document::Stylesheet { href: asset!("/assets/tailwind.css") }
+        ThemeProvider {
+            CrashBoundary {
+                Router::<Route> {}
+            }
+        }
+    }
+}
+
+/// Shared page chrome: header, navigation and footer around the active route
+#[component]
+fn AppLayout() -> Element {
+    use_wallet_idle_lock();
+    let browse_start = use_browse_start();
+
+    rsx! {
         div {
             id: "app",
-            class: "min-h-screen bg-gradient-to-br from-green-50 to-green-100",
+            class: "min-h-screen bg-gradient-to-br from-green-50 to-green-100 dark:from-gray-950 dark:to-gray-900 dark:text-gray-100",
+
+            NetworkBanner {}
+            ConnectionBanner {}
             
             // Header
             header {
-                class: "bg-white shadow-sm border-b border-green-200",
+                class: "print:hidden bg-white dark:bg-gray-900 shadow-sm border-b border-green-200 dark:border-gray-800",
                 div {
                     class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8",
                     div {
@@ -69,12 +147,12 @@ pub fn App() -> Element {
                             }
                             div {
                                 h1 {
-                                    class: "text-2xl font-bold text-gray-900",
+                                    class: "text-2xl font-bold text-gray-900 dark:text-white",
                                     "Faithful Archive"
                                 }
                                 p {
-                                    class: "text-sm text-gray-600",
-                                    "Christ-honoring content on Arweave"
+                                    class: "text-sm text-gray-600 dark:text-gray-400",
+                                    {t!("app.tagline")}
                                 }
                             }
                         }
@@ -82,208 +160,60 @@ pub fn App() -> Element {
                         // Navigation
                         nav {
                             class: "hidden md:flex space-x-2",
-                            a {
-                                href: "#",
-                                class: "text-gray-700 hover:text-green-600 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-all duration-200 hover:shadow-sm",
-                                "Browse"
+                            Link {
+                                to: Route::Browse { query: browse_start() },
+                                class: "text-gray-700 dark:text-gray-300 hover:text-green-600 hover:bg-green-50 dark:hover:bg-gray-800 px-4 py-2 rounded-lg text-sm font-medium transition-all duration-200 hover:shadow-sm",
+                                {t!("nav.browse")}
                             }
-                            a {
-                                href: "#",
-                                class: "text-gray-700 hover:text-green-600 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-all duration-200 hover:shadow-sm",
-                                "Upload"
+                            Link {
+                                to: Route::Upload {},
+                                class: "text-gray-700 dark:text-gray-300 hover:text-green-600 hover:bg-green-50 dark:hover:bg-gray-800 px-4 py-2 rounded-lg text-sm font-medium transition-all duration-200 hover:shadow-sm",
+                                {t!("nav.upload")}
+                            }
+                            Link {
+                                to: Route::Library {},
+                                class: "text-gray-700 dark:text-gray-300 hover:text-green-600 hover:bg-green-50 dark:hover:bg-gray-800 px-4 py-2 rounded-lg text-sm font-medium transition-all duration-200 hover:shadow-sm",
+                                {t!("nav.library")}
                             }
                             a {
                                 href: "#",
-                                class: "text-gray-700 hover:text-green-600 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-all duration-200 hover:shadow-sm",
-                                "About"
+                                class: "text-gray-700 dark:text-gray-300 hover:text-green-600 hover:bg-green-50 dark:hover:bg-gray-800 px-4 py-2 rounded-lg text-sm font-medium transition-all duration-200 hover:shadow-sm",
+                                {t!("nav.about")}
                             }
                         }
                         
-                        // Wallet connection button
-                        WalletConnectButton {}
-                    }
-                }
-            }
-            
-            // Main content
-            main {
-                class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
-                
-                // Hero section
-                div {
-                    class: "text-center py-16",
-                    h2 {
-                        class: "text-4xl font-bold text-gray-900 mb-4",
-                        "Preserve Spiritual Content Forever"
-                    }
-                    p {
-                        class: "text-xl text-gray-600 mb-8 max-w-3xl mx-auto",
-                        "Upload sermons, worship resources, and Bible studies to Arweave's permanent storage. "
-                        "Built with Rust and WebAssembly for performance and security."
-                    }
-                    
-                    div {
-                        class: "space-x-4",
-                        button {
-                            class: "bg-green-600 hover:bg-green-700 text-white px-8 py-3 rounded-lg text-lg font-medium transition-all shadow-lg hover:shadow-xl transform hover:-translate-y-0.5",
-                            "Start Uploading"
-                        }
-                        button {
-                            class: "border-2 border-green-600 text-green-600 hover:bg-green-50 px-8 py-3 rounded-lg text-lg font-medium transition-all shadow-md hover:shadow-lg transform hover:-translate-y-0.5",
-                            "Browse Content"
-                        }
-                    }
-                }
-                
-                // bundles-rs Integration Test Section
-                div {
-                    class: "bg-white rounded-xl shadow-sm border border-blue-200 p-8 mb-16",
-                    h3 {
-                        class: "text-2xl font-bold text-gray-900 mb-4 text-center",
-                        "🧪 bundles-rs Integration Test"
-                    }
-                    p {
-                        class: "text-gray-600 text-center mb-6",
-                        "Test the bundles-rs DataItem creation and signing functionality"
-                    }
-                    
-                    div {
-                        class: "flex justify-center mb-6",
-                        button {
-                            class: if *is_testing.read() {
-                                "bg-gray-300 cursor-not-allowed text-gray-600 px-6 py-3 rounded-lg font-medium border-2 border-gray-200"
-                            } else {
-                                "bg-blue-600 hover:bg-blue-700 text-white px-6 py-3 rounded-lg font-medium transition-colors shadow-md hover:shadow-lg"
-                            },
-                            disabled: *is_testing.read(),
-                            onclick: test_bundles_rs,
-                            if *is_testing.read() { "Testing..." } else { "Test bundles-rs" }
-                        }
-                    }
-                    
-                    if !test_result.read().is_empty() {
-                        div {
-                            class: if test_result.read().contains("✅") {
-                                "bg-green-50 border border-green-200 rounded-lg p-4 font-mono text-sm whitespace-pre-line"
-                            } else {
-                                "bg-red-50 border border-red-200 rounded-lg p-4 font-mono text-sm whitespace-pre-line"
-                            },
-                            "{test_result}"
-                        }
-                    }
-                }
-                
-                // Features section
-                div {
-                    class: "grid md:grid-cols-3 gap-8 py-16",
-                    
-                    // Feature 1
-                    div {
-                        class: "text-center",
-                        div {
-                            class: "w-16 h-16 bg-green-100 rounded-lg flex items-center justify-center mx-auto mb-4",
-                            "🔗"
-                        }
-                        h3 {
-                            class: "text-xl font-semibold text-gray-900 mb-2",
-                            "Permanent Storage"
-                        }
-                        p {
-                            class: "text-gray-600",
-                            "Content stored on Arweave blockchain remains accessible for 200+ years, "
-                            "ensuring your spiritual resources are preserved for future generations."
-                        }
-                    }
-                    
-                    // Feature 2
-                    div {
-                        class: "text-center",
-                        div {
-                            class: "w-16 h-16 bg-green-100 rounded-lg flex items-center justify-center mx-auto mb-4",
-                            "⚡"
-                        }
-                        h3 {
-                            class: "text-xl font-semibold text-gray-900 mb-2",
-                            "High Performance"
-                        }
-                        p {
-                            class: "text-gray-600",
-                            "Built with Rust and compiled to WebAssembly for near-native performance. "
-                            "Fast loading and smooth interactions for the best user experience."
-                        }
-                    }
-                    
-                    // Feature 3
-                    div {
-                        class: "text-center",
-                        div {
-                            class: "w-16 h-16 bg-green-100 rounded-lg flex items-center justify-center mx-auto mb-4",
-                            "🛡️"
-                        }
-                        h3 {
-                            class: "text-xl font-semibold text-gray-900 mb-2",
-                            "Content Moderation"
-                        }
-                        p {
-                            class: "text-gray-600",
-                            "All content is reviewed to ensure only Christ-honoring material is published. "
-                            "Community-driven moderation maintains high quality standards."
-                        }
-                    }
-                }
-                
-                // Stats section
-                div {
-                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-center",
-                    div {
-                        class: "grid grid-cols-2 md:grid-cols-4 gap-8",
+                        // Search
                         div {
-                            div {
-                                class: "text-3xl font-bold text-green-600",
-                                "0"
-                            }
-                            div {
-                                class: "text-sm text-gray-600",
-                                "Items Archived"
-                            }
-                        }
-                        div {
-                            div {
-                                class: "text-3xl font-bold text-green-600",
-                                "0"
-                            }
-                            div {
-                                class: "text-sm text-gray-600",
-                                "Churches Served"
-                            }
-                        }
-                        div {
-                            div {
-                                class: "text-3xl font-bold text-green-600",
-                                "∞"
-                            }
-                            div {
-                                class: "text-sm text-gray-600",
-                                "Years Preserved"
-                            }
+                            class: "hidden md:block",
+                            SearchBox {}
                         }
+                        
                         div {
-                            div {
-                                class: "text-3xl font-bold text-green-600",
-                                "100%"
-                            }
-                            div {
-                                class: "text-sm text-gray-600",
-                                "Uptime Target"
-                            }
+                            class: "flex items-center gap-2",
+                            SyncIndicator {}
+                            NotificationBell {}
+                            ThemeToggle {}
+                            // Wallet connection button
+                            WalletConnectButton {}
                         }
                     }
                 }
             }
+
+            WhatsNewBanner {}
+            MigrationPrompt {}
+
+            AppErrorBoundary {
+                Outlet::<Route> {}
+            }
+            ToastHost {}
+            GlobalShortcuts {}
+            BeaconPairingDialog {}
+            DownloadsPanel {}
             
             // Footer
             footer {
-                class: "bg-gray-900 text-white mt-16",
+                class: "print:hidden bg-gray-900 text-white mt-16",
                 div {
                     class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-12",
                     div {
@@ -297,7 +227,7 @@ pub fn App() -> Element {
                             }
                             p {
                                 class: "text-gray-400",
-                                "Preserving Christ-honoring content on the blockchain for future generations."
+                                {t!("footer.about")}
                             }
                         }
                         
@@ -305,13 +235,20 @@ pub fn App() -> Element {
                         div {
                             h4 {
                                 class: "font-semibold mb-4",
-                                "Platform"
+                                {t!("footer.platform")}
                             }
                             ul {
                                 class: "space-y-2 text-gray-400",
-                                li { a { href: "#", class: "hover:text-white", "Browse Content" } }
-                                li { a { href: "#", class: "hover:text-white", "Upload" } }
-                                li { a { href: "#", class: "hover:text-white", "Moderation" } }
+                                li { Link { to: Route::Browse { query: browse_start() }, class: "hover:text-white", {t!("footer.browse_content")} } }
+                                li { Link { to: Route::Upload {}, class: "hover:text-white", {t!("nav.upload")} } }
+                                li { Link { to: Route::Library {}, class: "hover:text-white", {t!("nav.library")} } }
+                                li { Link { to: Route::Collections {}, class: "hover:text-white", {t!("footer.collections")} } }
+                                li { Link { to: Route::WeeklyDigest {}, class: "hover:text-white", {t!("footer.weekly_digest")} } }
+                                li { Link { to: Route::ClaimReview {}, class: "hover:text-white", {t!("footer.moderation")} } }
+                                li { Link { to: Route::ModerationAudit {}, class: "hover:text-white", {t!("footer.moderation_log")} } }
+                                li { Link { to: Route::SubmissionQueue {}, class: "hover:text-white", {t!("footer.review_queue")} } }
+                                li { Link { to: Route::AdminDashboard {}, class: "hover:text-white", {t!("footer.platform_stats")} } }
+                                li { Link { to: Route::ArchiveHealth {}, class: "hover:text-white", {t!("footer.archive_health")} } }
                             }
                         }
                         
@@ -319,11 +256,16 @@ pub fn App() -> Element {
                         div {
                             h4 {
                                 class: "font-semibold mb-4",
-                                "Resources"
+                                {t!("footer.resources")}
                             }
                             ul {
                                 class: "space-y-2 text-gray-400",
-                                li { a { href: "#", class: "hover:text-white", "Documentation" } }
+                                li { Link { to: Route::Settings {}, class: "hover:text-white", {t!("footer.settings")} } }
+                                li { Link { to: Route::ProfileEditor {}, class: "hover:text-white", {t!("footer.your_profile")} } }
+                                li { Link { to: Route::UsageStats {}, class: "hover:text-white", {t!("footer.your_stats")} } }
+                                li { Link { to: Route::ReceiptHistory {}, class: "hover:text-white", {t!("footer.upload_receipts")} } }
+                                li { Link { to: Route::WhatsNew {}, class: "hover:text-white", {t!("footer.whats_new")} } }
+                                li { a { href: "#", class: "hover:text-white", {t!("footer.documentation")} } }
                                 li { a { href: "#", class: "hover:text-white", "API" } }
                                 li { a { href: "#", class: "hover:text-white", "GitHub" } }
                             }
@@ -333,13 +275,14 @@ pub fn App() -> Element {
                         div {
                             h4 {
                                 class: "font-semibold mb-4",
-                                "Connect"
+                                {t!("footer.connect")}
                             }
                             ul {
                                 class: "space-y-2 text-gray-400",
-                                li { a { href: "#", class: "hover:text-white", "Contact" } }
-                                li { a { href: "#", class: "hover:text-white", "Support" } }
-                                li { a { href: "#", class: "hover:text-white", "Community" } }
+                                li { a { href: "#", class: "hover:text-white", {t!("footer.contact")} } }
+                                li { Link { to: Route::RightsClaimForm {}, class: "hover:text-white", {t!("footer.rights_claims")} } }
+                                li { a { href: "#", class: "hover:text-white", {t!("footer.support")} } }
+                                li { a { href: "#", class: "hover:text-white", {t!("footer.community")} } }
                             }
                         }
                     }
@@ -347,7 +290,7 @@ pub fn App() -> Element {
                     div {
                         class: "border-t border-gray-800 mt-8 pt-8 text-center text-gray-400",
                         p {
-                            "© 2025 Faithful Archive. Built with ❤️ and ⚡ Rust for the glory of God."
+                            {t!("footer.copyright")}
                         }
                     }
                 }
@@ -356,3 +299,101 @@ pub fn App() -> Element {
     }
 }
 
+/// Landing page
+#[component]
+fn Home() -> Element {
+    let browse_start = use_browse_start();
+
+    rsx! {
+        main {
+            class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-12",
+
+            div {
+                class: "flex flex-wrap items-end justify-between gap-4",
+                div {
+                    h2 { class: "text-3xl font-bold text-gray-900 dark:text-white", {t!("home.title")} }
+                    p {
+                        class: "text-lg text-gray-600 dark:text-gray-400 mt-2 max-w-3xl",
+                        {t!("home.subtitle")}
+                    }
+                }
+                div {
+                    class: "flex gap-3",
+                    Link {
+                        to: Route::Upload {},
+                        class: "bg-green-600 hover:bg-green-700 text-white px-6 py-2 rounded-lg font-medium transition-colors shadow-md",
+                        {t!("home.start_uploading")}
+                    }
+                    Link {
+                        to: Route::Browse { query: browse_start() },
+                        class: "border-2 border-green-600 text-green-600 hover:bg-green-50 dark:hover:bg-gray-800 px-6 py-2 rounded-lg font-medium transition-colors",
+                        {t!("home.browse_content")}
+                    }
+                }
+            }
+
+            HomeFeed {}
+
+            // Features section
+            div {
+                class: "grid md:grid-cols-3 gap-8 py-8",
+            
+                // Feature 1
+                div {
+                    class: "text-center",
+                    div {
+                        class: "w-16 h-16 bg-green-100 rounded-lg flex items-center justify-center mx-auto mb-4",
+                        "🔗"
+                    }
+                    h3 {
+                        class: "text-xl font-semibold text-gray-900 mb-2",
+                        "Permanent Storage"
+                    }
+                    p {
+                        class: "text-gray-600",
+                        "Content stored on Arweave blockchain remains accessible for 200+ years, "
+                        "ensuring your spiritual resources are preserved for future generations."
+                    }
+                }
+            
+                // Feature 2
+                div {
+                    class: "text-center",
+                    div {
+                        class: "w-16 h-16 bg-green-100 rounded-lg flex items-center justify-center mx-auto mb-4",
+                        "⚡"
+                    }
+                    h3 {
+                        class: "text-xl font-semibold text-gray-900 mb-2",
+                        "High Performance"
+                    }
+                    p {
+                        class: "text-gray-600",
+                        "Built with Rust and compiled to WebAssembly for near-native performance. "
+                        "Fast loading and smooth interactions for the best user experience."
+                    }
+                }
+            
+                // Feature 3
+                div {
+                    class: "text-center",
+                    div {
+                        class: "w-16 h-16 bg-green-100 rounded-lg flex items-center justify-center mx-auto mb-4",
+                        "🛡️"
+                    }
+                    h3 {
+                        class: "text-xl font-semibold text-gray-900 mb-2",
+                        "Content Moderation"
+                    }
+                    p {
+                        class: "text-gray-600",
+                        "All content is reviewed to ensure only Christ-honoring material is published. "
+                        "Community-driven moderation maintains high quality standards."
+                    }
+                }
+            }
+        
+            HomeStats {}
+        }
+    }
+}
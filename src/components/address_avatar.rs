@@ -0,0 +1,24 @@
+use dioxus::prelude::*;
+use crate::utils::display::identicon_data_url;
+
+/// Identicon for a wallet address, so addresses can be told apart at a
+/// glance
+///
+/// Decorative: place it next to the address or name it stands for.
+#[component]
+pub fn AddressAvatar(
+    address: String,
+    /// Tailwind size classes
+    #[props(default = "w-6 h-6")] size: &'static str,
+) -> Element {
+    let src = use_memo(use_reactive!(|address| identicon_data_url(&address)));
+
+    rsx! {
+        img {
+            class: "{size} rounded-full flex-shrink-0 ring-1 ring-black/10 dark:ring-white/20",
+            src: "{src}",
+            alt: "",
+            aria_hidden: "true",
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use dioxus::prelude::*;
+use crate::services::health::{load_history, record_run, HealthMonitor, HealthReport, HealthRun, ItemHealth};
+use crate::services::reverify::set_reverify_owner;
+use crate::services::wallet::{use_wallet_state, WalletService};
+
+/// Archive health view for organization admins
+///
+/// Lists every item published by an address, shows which probed gateways
+/// still serve it, and lets admins re-seed missing items through an
+/// alternate bundler from a saved copy of the signed DataItem. Past checks,
+/// including scheduled sampled re-verifications, are listed as a history.
+#[component]
+pub fn ArchiveHealth() -> Element {
+    let wallet_state = use_wallet_state();
+    let mut owner = use_signal(String::new);
+    let mut report = use_signal(|| None::<HealthReport>);
+    let mut is_checking = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut history = use_signal(Vec::<HealthRun>::new);
+
+    // Load recorded checks once on mount
+    use_effect(move || {
+        spawn(async move {
+            match load_history().await {
+                Ok(runs) => history.set(runs),
+                Err(e) => log::warn!("{}", e),
+            }
+        });
+    });
+
+    // Default to the connected wallet address
+    use_effect(move || {
+        if let Some(address) = wallet_state.read().address().map(str::to_string) {
+            if owner.peek().is_empty() {
+                owner.set(address);
+            }
+        }
+    });
+
+    let run_check = move |_| {
+        let address = owner.read().trim().to_string();
+        if address.is_empty() {
+            error.set(Some("Enter an address to check".to_string()));
+            return;
+        }
+
+        spawn(async move {
+            is_checking.set(true);
+            error.set(None);
+
+            match HealthMonitor::new().check_archive(&address).await {
+                Ok(result) => {
+                    // Scheduled re-verification follows the last archive checked here
+                    set_reverify_owner(&address);
+                    if let Err(e) = record_run(&HealthRun::from_report(&result, false)).await {
+                        log::warn!("{}", e);
+                    }
+                    if let Ok(runs) = load_history().await {
+                        history.set(runs);
+                    }
+                    report.set(Some(result));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+
+            is_checking.set(false);
+        });
+    };
+
+    rsx! {
+        main {
+            class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 mb-8",
+
+                h2 {
+                    class: "text-2xl font-bold text-gray-900 mb-2",
+                    "Archive Health"
+                }
+                p {
+                    class: "text-gray-600 mb-6",
+                    "Check that every item your ministry has published is still served by Arweave gateways."
+                }
+
+                div {
+                    class: "flex flex-col md:flex-row gap-3",
+                    input {
+                        class: "flex-1 px-3 py-2 border border-gray-300 rounded-md font-mono text-sm focus:outline-none focus:ring-2 focus:ring-green-500",
+                        placeholder: "Organization wallet address",
+                        value: "{owner}",
+                        oninput: move |evt| owner.set(evt.value()),
+                    }
+                    button {
+                        class: if *is_checking.read() {
+                            "bg-gray-300 cursor-not-allowed text-gray-600 px-6 py-2 rounded-lg font-medium"
+                        } else {
+                            "bg-green-600 hover:bg-green-700 text-white px-6 py-2 rounded-lg font-medium transition-colors"
+                        },
+                        disabled: *is_checking.read(),
+                        onclick: run_check,
+                        if *is_checking.read() { "Checking..." } else { "Run Health Check" }
+                    }
+                }
+
+                if let Some(message) = error.read().as_ref() {
+                    div {
+                        class: "mt-4 text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                        "{message}"
+                    }
+                }
+            }
+
+            if let Some(report) = report.read().as_ref() {
+                HealthSummary { report: report.clone() }
+
+                div {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 divide-y divide-gray-100",
+                    for item in report.items.iter() {
+                        ItemHealthRow { key: "{item.tx_id}", item: item.clone() }
+                    }
+                }
+            }
+
+            if !history.read().is_empty() {
+                HealthHistory { runs: history.read().clone() }
+            }
+        }
+    }
+}
+
+/// Past health checks with per-gateway failure rates, newest first
+#[component]
+fn HealthHistory(runs: Vec<HealthRun>) -> Element {
+    let mut gateways: Vec<String> = runs
+        .iter()
+        .flat_map(|run| run.gateway_failures.keys().cloned())
+        .collect();
+    gateways.sort();
+    gateways.dedup();
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 mt-8 overflow-x-auto",
+
+            h3 {
+                class: "text-lg font-semibold text-gray-900 mb-4",
+                "Check History"
+            }
+
+            table {
+                class: "min-w-full text-sm",
+                thead {
+                    tr {
+                        class: "text-left text-gray-500",
+                        th { class: "py-2 pr-4 font-medium", "Date" }
+                        th { class: "py-2 pr-4 font-medium", "Type" }
+                        th { class: "py-2 pr-4 font-medium", "Items" }
+                        th { class: "py-2 pr-4 font-medium", "Missing" }
+                        th { class: "py-2 pr-4 font-medium", "Degraded" }
+                        for gateway in gateways.iter() {
+                            th { class: "py-2 pr-4 font-medium", "{gateway}" }
+                        }
+                    }
+                }
+                tbody {
+                    class: "divide-y divide-gray-100",
+                    for run in runs.iter() {
+                        tr {
+                            key: "{run.checked_at}-{run.owner}",
+                            td {
+                                class: "py-2 pr-4 text-gray-700",
+                                "{format_timestamp(run.checked_at)}"
+                            }
+                            td {
+                                class: "py-2 pr-4 text-gray-600",
+                                if run.sampled { "Scheduled sample" } else { "Full check" }
+                            }
+                            td { class: "py-2 pr-4 text-gray-700", "{run.total_items}" }
+                            td {
+                                class: if run.missing_tx_ids.is_empty() { "py-2 pr-4 text-gray-700" } else { "py-2 pr-4 text-red-600 font-medium" },
+                                "{run.missing_tx_ids.len()}"
+                            }
+                            td { class: "py-2 pr-4 text-gray-700", "{run.degraded_count}" }
+                            for gateway in gateways.iter() {
+                                td {
+                                    class: "py-2 pr-4 text-gray-700",
+                                    "{(run.failure_rate(gateway) * 100.0).round()}% failed"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|date| date.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+#[component]
+fn HealthSummary(report: HealthReport) -> Element {
+    rsx! {
+        div {
+            class: "grid grid-cols-3 gap-4 mb-6",
+            SummaryCard { label: "Healthy", count: report.healthy_count(), color: "text-green-600" }
+            SummaryCard { label: "Degraded", count: report.degraded_count(), color: "text-yellow-600" }
+            SummaryCard { label: "Missing", count: report.missing_count(), color: "text-red-600" }
+        }
+    }
+}
+
+#[component]
+fn SummaryCard(label: &'static str, count: usize, color: &'static str) -> Element {
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-4 text-center",
+            div { class: "text-3xl font-bold {color}", "{count}" }
+            div { class: "text-sm text-gray-600", "{label}" }
+        }
+    }
+}
+
+#[component]
+fn ItemHealthRow(item: ItemHealth) -> Element {
+    let mut reseed_status = use_signal(|| None::<String>);
+
+    let status_class = if item.is_missing() {
+        "bg-red-100 text-red-800"
+    } else if item.is_degraded() {
+        "bg-yellow-100 text-yellow-800"
+    } else {
+        "bg-green-100 text-green-800"
+    };
+
+    let status_text = if item.is_missing() {
+        "Missing"
+    } else if item.is_degraded() {
+        "Degraded"
+    } else {
+        "Healthy"
+    };
+
+    let reseed = move |evt: FormEvent| async move {
+        let Some(file_engine) = evt.files() else {
+            return;
+        };
+
+        for file_name in file_engine.files() {
+            let Some(bytes) = file_engine.read_file(&file_name).await else {
+                reseed_status.set(Some(format!("Could not read {}", file_name)));
+                continue;
+            };
+
+            reseed_status.set(Some("Re-seeding...".to_string()));
+            match HealthMonitor::new().reseed(bytes).await {
+                Ok(bundler) => reseed_status.set(Some(format!("Re-seeded via {}", bundler))),
+                Err(e) => reseed_status.set(Some(e.to_string())),
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "p-4 flex flex-col md:flex-row md:items-center md:justify-between gap-3",
+
+            div {
+                code {
+                    class: "text-sm font-mono text-gray-800",
+                    title: "{item.tx_id}",
+                    "{WalletService::format_address(&item.tx_id)}"
+                }
+                div {
+                    class: "text-xs text-gray-500 mt-1",
+                    "Served by {item.available_on.len()} of {item.available_on.len() + item.unavailable_on.len()} gateways"
+                }
+            }
+
+            div {
+                class: "flex items-center gap-3",
+
+                span {
+                    class: "inline-flex items-center px-2 py-1 rounded-full text-xs font-medium {status_class}",
+                    "{status_text}"
+                }
+
+                if item.is_missing() || item.is_degraded() {
+                    label {
+                        class: "cursor-pointer text-sm text-green-700 hover:text-green-800 underline",
+                        title: "Select the signed DataItem (.bin) saved at upload time",
+                        "Re-seed"
+                        input {
+                            class: "hidden",
+                            r#type: "file",
+                            onchange: reseed,
+                        }
+                    }
+                }
+
+                if let Some(status) = reseed_status.read().as_ref() {
+                    span { class: "text-xs text-gray-600", "{status}" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::config::use_app_config;
+use crate::services::arns::{name_url, normalize_name, ArnsRecord, ArnsService, ArnsTarget, DEFAULT_RECORD_TTL_SECS};
+use crate::services::series::{SeriesService, SeriesSummary};
+use crate::services::wallet::{use_wallet_state, WalletService};
+
+/// Opens whatever an ArNS name points at, e.g. `/name/gracechurch`
+#[component]
+pub fn NameRedirect(name: String) -> Element {
+    let navigator = use_navigator();
+    let mut error = use_signal(|| None::<String>);
+    let mut external = use_signal(|| None::<String>);
+
+    let lookup_name = name.clone();
+    use_effect(use_reactive!(|lookup_name| {
+        spawn(async move {
+            match ArnsService::new().resolve_target(&lookup_name).await {
+                Ok(ArnsTarget::Item(tx_id)) => {
+                    navigator.replace(Route::ContentDetail { tx_id });
+                }
+                Ok(ArnsTarget::Series(series_id)) => {
+                    navigator.replace(Route::SeriesDetail { series_id });
+                }
+                Ok(ArnsTarget::External(url)) => external.set(Some(url)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    }));
+
+    rsx! {
+        main {
+            class: "max-w-3xl mx-auto px-4 sm:px-6 lg:px-8 py-8 text-center text-sm",
+            if let Some(message) = error.read().as_ref() {
+                p { class: "text-red-700", "{message}" }
+            } else if let Some(url) = external.read().as_ref() {
+                p { class: "text-gray-600", "{name} points outside the archive." }
+                a { class: "text-green-700 hover:text-green-800 underline", href: "{url}", "Open {url}" }
+            } else {
+                p { class: "text-gray-500", "Resolving {name}..." }
+            }
+        }
+    }
+}
+
+/// Point an ArNS name the connected wallet owns at a series or item
+#[component]
+pub fn ArnsSettings() -> Element {
+    let wallet_state = use_wallet_state();
+    let config = use_app_config();
+    let mut name = use_signal(String::new);
+    let mut record = use_signal(|| None::<ArnsRecord>);
+    let mut series = use_signal(Vec::<SeriesSummary>::new);
+    let mut target = use_signal(String::new);
+    let mut is_busy = use_signal(|| false);
+    let mut status = use_signal(|| None::<Result<String, String>>);
+
+    let owner = wallet_state.read().address().map(str::to_string);
+    use_effect(use_reactive!(|owner| {
+        let Some(owner) = owner else {
+            return;
+        };
+        spawn(async move {
+            match SeriesService::new().list_for_owner(&owner).await {
+                Ok(found) => series.set(found),
+                Err(e) => log::warn!("Could not load series: {}", e),
+            }
+        });
+    }));
+
+    let look_up = move |evt: FormEvent| {
+        evt.prevent_default();
+        spawn(async move {
+            is_busy.set(true);
+            status.set(None);
+            record.set(None);
+            match ArnsService::new().resolve(&name.read()).await {
+                Ok(found) => {
+                    target.set(found.tx_id.clone());
+                    record.set(Some(found));
+                }
+                Err(e) => status.set(Some(Err(e.to_string()))),
+            }
+            is_busy.set(false);
+        });
+    };
+
+    let update = move |_| {
+        let Some(current) = record.read().clone() else {
+            return;
+        };
+        let tx_id = target.read().trim().to_string();
+        spawn(async move {
+            is_busy.set(true);
+            let result = ArnsService::new()
+                .set_record(&current, &tx_id, DEFAULT_RECORD_TTL_SECS)
+                .await
+                .map(|message_id| format!("Update sent ({}). Gateways may take up to an hour to follow.", WalletService::format_address(&message_id)))
+                .map_err(|e| e.to_string());
+            status.set(Some(result));
+            is_busy.set(false);
+        });
+    };
+
+    let input_class = "w-full px-3 py-2 border border-gray-300 rounded-md text-sm";
+    let button_class = "border border-green-600 text-green-700 hover:bg-green-50 disabled:opacity-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors";
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 mt-6 space-y-4",
+            h3 { class: "text-lg font-semibold text-gray-900", "ArNS name" }
+            p {
+                class: "text-sm text-gray-600",
+                "Give your collection a friendly address like gracechurch.ar. "
+                a { class: "text-green-700 underline", href: "{config.arns_registration_url}", target: "_blank", "Register a name" }
+                ", then point it at a series or item here."
+            }
+
+            form {
+                class: "flex gap-2",
+                onsubmit: look_up,
+                input {
+                    class: input_class,
+                    aria_label: "ArNS name",
+                    placeholder: "gracechurch",
+                    value: "{name}",
+                    oninput: move |evt| name.set(evt.value()),
+                }
+                button { class: button_class, r#type: "submit", disabled: *is_busy.read(), "Look up" }
+            }
+
+            if let Some(current) = record.read().as_ref() {
+                div {
+                    class: "text-sm space-y-3",
+                    p {
+                        class: "text-gray-700",
+                        a { class: "text-green-700 underline", href: "{current.url()}", target: "_blank", "{current.url()}" }
+                        " points at "
+                        span { class: "font-mono", "{WalletService::format_address(&current.tx_id)}" }
+                    }
+                    Link {
+                        class: "text-green-700 hover:text-green-800 underline",
+                        to: Route::NameRedirect { name: current.name.clone() },
+                        "Open in the archive"
+                    }
+
+                    if owner.is_some() {
+                        select {
+                            class: input_class,
+                            aria_label: "New target",
+                            value: "{target}",
+                            onchange: move |evt| target.set(evt.value()),
+                            option { value: "{current.tx_id}", "Keep the current target" }
+                            for summary in series.read().iter() {
+                                option { key: "{summary.series_id}", value: "{summary.manifest_tx_id}", "Series: {summary.title}" }
+                            }
+                        }
+                        input {
+                            class: "{input_class} font-mono",
+                            aria_label: "Target transaction ID",
+                            placeholder: "Or paste an item's transaction ID",
+                            value: "{target}",
+                            oninput: move |evt| target.set(evt.value()),
+                        }
+                        button {
+                            class: button_class,
+                            disabled: *is_busy.read() || *target.read() == current.tx_id,
+                            onclick: update,
+                            "Point {current.name} here"
+                        }
+                    } else {
+                        p { class: "text-gray-500", "Connect the wallet that owns this name to change where it points." }
+                    }
+                }
+            } else if let Ok(normalized) = normalize_name(&name.read()) {
+                p { class: "text-xs text-gray-400", "{name_url(&normalized)}" }
+            }
+
+            match status.read().as_ref() {
+                Some(Ok(message)) => rsx! { p { class: "text-sm text-green-700", "{message}" } },
+                Some(Err(message)) => rsx! { p { class: "text-sm text-red-700", "{message}" } },
+                None => rsx! {},
+            }
+        }
+    }
+}
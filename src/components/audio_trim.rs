@@ -0,0 +1,157 @@
+use dioxus::prelude::*;
+use crate::services::audio_prep::DecodedAudio;
+use crate::services::file_reader::LocalFile;
+use crate::services::playback::format_position;
+use crate::services::upload::content_type_for;
+use crate::utils::audio::waveform_peaks;
+
+/// Bars drawn in the waveform preview
+const WAVEFORM_BARS: usize = 200;
+
+/// Waveform preview with leading/trailing silence trimming for audio uploads
+///
+/// Trimming re-encodes the kept range as WAV and replaces the selected
+/// file. That only pays off for uncompressed sources, so the estimated size
+/// is shown and trimming is refused when it would grow the upload.
+#[component]
+pub fn AudioTrimmer(file: Signal<Option<LocalFile>>) -> Element {
+    let mut decoded = use_signal(|| None::<DecodedAudio>);
+    let mut peaks = use_signal(Vec::<f32>::new);
+    let mut range = use_signal(|| (0.0f32, 0.0f32));
+    let mut error = use_signal(|| None::<String>);
+
+    use_effect(move || {
+        let Some(chosen) = file.read().clone() else {
+            decoded.set(None);
+            return;
+        };
+        if !content_type_for(chosen.name()).starts_with("audio/") {
+            decoded.set(None);
+            return;
+        }
+        spawn(async move {
+            error.set(None);
+            // Decoding needs the whole recording
+            let bytes = match chosen.read_all().await {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    error.set(Some(e.to_string()));
+                    return;
+                }
+            };
+            match DecodedAudio::decode(&bytes).await {
+                Ok(audio) => {
+                    peaks.set(waveform_peaks(audio.mono(), WAVEFORM_BARS));
+                    range.set(audio.audible_range());
+                    decoded.set(Some(audio));
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    });
+
+    if let Some(message) = error.read().as_ref() {
+        return rsx! { p { class: "text-xs text-gray-500 mt-1", "Waveform unavailable: {message}" } };
+    }
+    let Some(audio) = decoded.read().clone() else {
+        return rsx! {};
+    };
+
+    let duration = audio.duration_secs();
+    let (start, end) = *range.read();
+    let original_size = file.read().as_ref().map_or(0, |chosen| chosen.size() as usize);
+    let trimmed_size = audio.trimmed_size(start, end);
+    let saves_space = trimmed_size < original_size;
+    let (cut_start, cut_end) = (start / duration * 100.0, end / duration * 100.0);
+
+    let apply = move |_| {
+        let Some(audio) = decoded.read().clone() else {
+            return;
+        };
+        let (start, end) = *range.read();
+        let name = file.read().as_ref().map(|chosen| chosen.name().to_string()).unwrap_or_default();
+        let stem = name.rsplit_once('.').map_or(name.as_str(), |(stem, _)| stem).to_string();
+        match LocalFile::from_bytes(&format!("{}-trimmed.wav", stem), &audio.trim(start, end), "audio/wav") {
+            Ok(trimmed) => file.set(Some(trimmed)),
+            Err(e) => error.set(Some(e.to_string())),
+        }
+    };
+
+    rsx! {
+        div {
+            class: "mt-3 space-y-2",
+            svg {
+                class: "w-full h-16 bg-gray-50 rounded",
+                view_box: "0 0 {WAVEFORM_BARS} 100",
+                preserve_aspect_ratio: "none",
+                for (index, peak) in peaks.read().iter().enumerate() {
+                    rect {
+                        key: "{index}",
+                        x: "{index}",
+                        y: "{50.0 - peak * 50.0}",
+                        width: "0.8",
+                        height: "{(peak * 100.0).max(1.0)}",
+                        fill: "#16a34a",
+                    }
+                }
+                rect { x: "0", y: "0", width: "{cut_start * WAVEFORM_BARS as f32 / 100.0}", height: "100", fill: "#9ca3af", opacity: "0.6" }
+                rect {
+                    x: "{cut_end * WAVEFORM_BARS as f32 / 100.0}",
+                    y: "0",
+                    width: "{(100.0 - cut_end) * WAVEFORM_BARS as f32 / 100.0}",
+                    height: "100",
+                    fill: "#9ca3af",
+                    opacity: "0.6",
+                }
+            }
+            div {
+                class: "grid grid-cols-2 gap-4 text-xs text-gray-600",
+                label {
+                    "Start {format_position(start as f64)}"
+                    input {
+                        class: "w-full",
+                        r#type: "range",
+                        min: "0",
+                        max: "{duration}",
+                        step: "0.1",
+                        value: "{start}",
+                        oninput: move |evt| {
+                            let value = evt.value().parse().unwrap_or(0.0);
+                            range.with_mut(|(start, end)| *start = f32::min(value, *end));
+                        },
+                    }
+                }
+                label {
+                    "End {format_position(end as f64)}"
+                    input {
+                        class: "w-full",
+                        r#type: "range",
+                        min: "0",
+                        max: "{duration}",
+                        step: "0.1",
+                        value: "{end}",
+                        oninput: move |evt| {
+                            let value = evt.value().parse().unwrap_or(duration);
+                            range.with_mut(|(start, end)| *end = f32::max(value, *start));
+                        },
+                    }
+                }
+            }
+            div {
+                class: "flex items-center gap-3",
+                button {
+                    class: "border border-green-600 text-green-700 hover:bg-green-50 disabled:opacity-50 px-3 py-1 rounded text-xs font-medium",
+                    r#type: "button",
+                    disabled: !saves_space,
+                    onclick: apply,
+                    "Trim silence"
+                }
+                if saves_space {
+                    span { class: "text-xs text-gray-500", "{original_size / 1024} KB → {trimmed_size / 1024} KB" }
+                } else {
+                    span { class: "text-xs text-gray-500", "Trimming would re-encode to a larger WAV; upload the original instead." }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use dioxus::prelude::*;
+use crate::models::ContentMetadata;
+use crate::services::bookmarks::{use_is_bookmarked, BookmarkKind, BookmarkService};
+
+/// Heart (favorite) and clock (watch later) toggles for an item
+#[component]
+pub fn BookmarkButtons(item: ContentMetadata) -> Element {
+    let is_favorite = use_is_bookmarked(item.tx_id.clone(), BookmarkKind::Favorite);
+    let is_watch_later = use_is_bookmarked(item.tx_id.clone(), BookmarkKind::WatchLater);
+
+    let toggle = move |kind: BookmarkKind| {
+        let item = item.clone();
+        move |_| {
+            let item = item.clone();
+            spawn(async move {
+                if let Err(e) = BookmarkService::toggle(&item, kind).await {
+                    log::error!("Failed to update bookmarks: {}", e);
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex items-center gap-1",
+            button {
+                class: if *is_favorite.read() {
+                    "p-2 rounded-full text-red-500 hover:bg-red-50"
+                } else {
+                    "p-2 rounded-full text-gray-400 hover:text-red-500 hover:bg-red-50"
+                },
+                title: if *is_favorite.read() { "Remove from favorites" } else { "Add to favorites" },
+                onclick: toggle(BookmarkKind::Favorite),
+                if *is_favorite.read() { "♥" } else { "♡" }
+            }
+            button {
+                class: if *is_watch_later.read() {
+                    "p-2 rounded-full text-green-700 hover:bg-green-50"
+                } else {
+                    "p-2 rounded-full text-gray-400 hover:text-green-700 hover:bg-green-50"
+                },
+                title: if *is_watch_later.read() { "Remove from watch later" } else { "Watch later" },
+                onclick: toggle(BookmarkKind::WatchLater),
+                "🕒"
+            }
+        }
+    }
+}
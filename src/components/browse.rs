@@ -0,0 +1,470 @@
+use dioxus::prelude::*;
+use chrono::NaiveDate;
+use crate::app::Route;
+use crate::components::bookmark_buttons::BookmarkButtons;
+use crate::components::following::FeedTabs;
+use crate::components::profile::ProfileHeader;
+use crate::components::reactions::ReactionBar;
+use crate::components::reference_export::{MetadataExportButtons, ReferenceExportButtons};
+use crate::components::skeleton::CardSkeleton;
+use crate::i18n::t;
+use crate::models::{BrowseFilters, BrowseQuery, ContentKind, ContentMetadata, DurationBucket, LicenseFilter, SPIRITUAL_CONTENT_TYPE};
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, TransactionSummary};
+use crate::services::hide_registry::is_hidden;
+use crate::services::playback::{format_position, use_playback_position};
+use crate::services::profile::ProfileService;
+use crate::services::revisions::{latest_versions_only, revocation_of, superseded_by};
+use crate::services::search::filter_local;
+use crate::services::settings::use_settings;
+use crate::utils::citation::ReferenceFormat;
+use crate::utils::display::format_size;
+use crate::utils::language::{language_name, COMMON_LANGUAGES};
+use crate::utils::metadata_export::MetadataFormat;
+
+/// Items requested from the gateway per page
+const PAGE_SIZE: u32 = 50;
+
+/// Browse page listing archived items, narrowed by the filters in the URL
+#[component]
+pub fn Browse(query: BrowseQuery) -> Element {
+    let navigator = use_navigator();
+    let mut items = use_signal(Vec::<ContentMetadata>::new);
+    let mut next_cursor = use_signal(|| None::<String>);
+    let mut is_loading = use_signal(|| false);
+    let mut notice = use_signal(|| None::<String>);
+
+    let load_query = query.clone();
+    use_effect(use_reactive!(|load_query| {
+        items.set(Vec::new());
+        next_cursor.set(None);
+        notice.set(None);
+        spawn(async move {
+            is_loading.set(true);
+            match fetch_page(&load_query.filters, load_query.after()).await {
+                Ok((page, cursor)) => {
+                    items.set(page);
+                    next_cursor.set(cursor);
+                }
+                Err(e) => {
+                    log::warn!("Browse query failed, using local index: {}", e);
+                    items.set(filter_local(&load_query.filters));
+                    notice.set(Some("Gateway unavailable — showing items from your local index".to_string()));
+                }
+            }
+            is_loading.set(false);
+        });
+    }));
+
+    let mut is_exporting = use_signal(|| false);
+    let export_filters = query.filters.clone();
+    let export_all = move |format: ReferenceFormat| {
+        let filters = export_filters.clone();
+        spawn(async move {
+            is_exporting.set(true);
+            let result = match fetch_all(&filters).await {
+                Ok(all) => format.download(&all),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                notice.set(Some(format!("Export failed: {}", e)));
+            }
+            is_exporting.set(false);
+        });
+    };
+
+    let metadata_filters = query.filters.clone();
+    let export_metadata = move |format: MetadataFormat| {
+        let filters = metadata_filters.clone();
+        spawn(async move {
+            is_exporting.set(true);
+            let result = match fetch_all(&filters).await {
+                Ok(all) => format.download("faithful-archive-metadata", &all),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                notice.set(Some(format!("Export failed: {}", e)));
+            }
+            is_exporting.set(false);
+        });
+    };
+
+    let export_label = if *is_exporting.read() { "Exporting..." } else { "Export all results:" };
+    let next_query = query.clone();
+    let previous_query = query.previous_page();
+
+    rsx! {
+        main {
+            class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+
+            div {
+                class: "flex flex-wrap items-center justify-between gap-4 mb-6",
+                h2 {
+                    class: "text-3xl font-bold text-gray-900",
+                    {t!("browse.title")}
+                }
+                FeedTabs { following: false }
+                ReferenceExportButtons {
+                    label: export_label,
+                    busy: *is_exporting.read(),
+                    on_export: export_all,
+                }
+                MetadataExportButtons {
+                    label: "Metadata:",
+                    busy: *is_exporting.read(),
+                    on_export: export_metadata,
+                }
+            }
+
+            div {
+                class: "grid lg:grid-cols-4 gap-8",
+
+                FilterPanel {
+                    filters: query.filters.clone(),
+                    on_apply: move |filters| {
+                        navigator.push(Route::Browse { query: BrowseQuery::new(filters) });
+                    },
+                }
+
+                div {
+                    class: "lg:col-span-3 space-y-4",
+
+                    if let Some(message) = notice.read().as_ref() {
+                        div {
+                            class: "text-sm text-yellow-800 bg-yellow-50 px-3 py-2 rounded border border-yellow-200",
+                            "{message}"
+                        }
+                    }
+
+                    if items.read().is_empty() && !*is_loading.read() {
+                        div {
+                            class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-center text-gray-500",
+                            {t!("browse.no_matches")}
+                        }
+                    }
+
+                    for item in items.read().iter() {
+                        BrowseCard { key: "{item.tx_id}", item: item.clone() }
+                    }
+
+                    if *is_loading.read() {
+                        CardSkeleton {}
+                    } else {
+                        div {
+                            class: "flex items-center justify-between",
+                            if query.page_number() > 1 {
+                                Link {
+                                    class: "border border-green-600 text-green-700 hover:bg-green-50 px-6 py-2 rounded-lg text-sm font-medium transition-colors",
+                                    to: Route::Browse { query: previous_query },
+                                    {t!("browse.previous")}
+                                }
+                            } else {
+                                span {}
+                            }
+                            span { class: "text-sm text-gray-500", {t!("browse.page", page = query.page_number())} }
+                            if let Some(cursor) = next_cursor.read().clone() {
+                                Link {
+                                    class: "border border-green-600 text-green-700 hover:bg-green-50 px-6 py-2 rounded-lg text-sm font-medium transition-colors",
+                                    to: Route::Browse { query: next_query.next_page(cursor) },
+                                    {t!("browse.next")}
+                                }
+                            } else {
+                                span {}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Fetch one page from the gateway and apply the filters GraphQL can't express
+async fn fetch_page(filters: &BrowseFilters, after: Option<&str>) -> Result<(Vec<ContentMetadata>, Option<String>), String> {
+    let tag_filters = filters.tag_filters();
+    let mut tags: Vec<(&str, &[&str])> = vec![("Type", &[SPIRITUAL_CONTENT_TYPE])];
+    tags.extend(tag_filters.iter().map(|(name, values)| (*name, values.as_slice())));
+
+    let page = GraphQLClient::cached()
+        .find_transactions_page(&tags, PAGE_SIZE, after)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let items = visible_matches(filters, &page.transactions);
+    resolve_uploaders(&items).await;
+    Ok((items, page.next_cursor))
+}
+
+/// Every item matching the filters, across all pages
+async fn fetch_all(filters: &BrowseFilters) -> Result<Vec<ContentMetadata>, String> {
+    let tag_filters = filters.tag_filters();
+    let mut tags: Vec<(&str, &[&str])> = vec![("Type", &[SPIRITUAL_CONTENT_TYPE])];
+    tags.extend(tag_filters.iter().map(|(name, values)| (*name, values.as_slice())));
+
+    let transactions = GraphQLClient::cached()
+        .find_transactions(&[], &tags)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let items = visible_matches(filters, &transactions);
+    resolve_uploaders(&items).await;
+    Ok(items)
+}
+
+/// Look up the uploaders' profiles in one query before the cards render
+async fn resolve_uploaders(items: &[ContentMetadata]) {
+    let owners: Vec<&str> = items.iter().map(|item| item.owner.as_str()).collect();
+    if let Err(e) = ProfileService::new().resolve(&owners).await {
+        log::warn!("Could not load uploader profiles: {}", e);
+    }
+}
+
+fn visible_matches(filters: &BrowseFilters, transactions: &[TransactionSummary]) -> Vec<ContentMetadata> {
+    let items: Vec<ContentMetadata> = transactions
+        .iter()
+        .filter(|tx| !is_hidden(&tx.id))
+        .filter_map(|tx| ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp))
+        .filter(|metadata| filters.matches(metadata))
+        .collect();
+    if filters.all_versions {
+        items
+    } else {
+        latest_versions_only(items)
+    }
+}
+
+/// Advanced filter form; pages navigate on apply so the URL holds the filters
+#[component]
+pub fn FilterPanel(filters: BrowseFilters, on_apply: EventHandler<BrowseFilters>) -> Element {
+    let mut draft = use_signal(|| filters.clone());
+
+    // Keep the form in step with back/forward navigation
+    use_effect(use_reactive!(|filters| draft.set(filters)));
+
+    let text = |value: &Option<String>| value.clone().unwrap_or_default();
+    let date = |value: Option<NaiveDate>| value.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default();
+    let optional = |value: String| {
+        let value = value.trim().to_string();
+        if value.is_empty() { None } else { Some(value) }
+    };
+    let other_language = draft
+        .read()
+        .language
+        .clone()
+        .filter(|language| !COMMON_LANGUAGES.iter().any(|(code, _)| code == language));
+
+    rsx! {
+        form {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 space-y-4 h-fit",
+            onsubmit: move |evt| {
+                evt.prevent_default();
+                on_apply.call(draft.read().clone());
+            },
+
+            div {
+                class: "flex items-center justify-between",
+                h3 { class: "font-semibold text-gray-900", "Filters" }
+                if filters.active_count() > 0 {
+                    span {
+                        class: "text-xs bg-green-100 text-green-800 px-2 py-0.5 rounded-full",
+                        "{filters.active_count()} active"
+                    }
+                }
+            }
+
+            FilterField {
+                label: "Archived from",
+                input {
+                    class: "w-full px-3 py-2 border border-gray-300 rounded-lg text-sm",
+                    r#type: "date",
+                    value: date(draft.read().date_from),
+                    oninput: move |evt| draft.write().date_from = NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok(),
+                }
+            }
+            FilterField {
+                label: "Archived to",
+                input {
+                    class: "w-full px-3 py-2 border border-gray-300 rounded-lg text-sm",
+                    r#type: "date",
+                    value: date(draft.read().date_to),
+                    oninput: move |evt| draft.write().date_to = NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok(),
+                }
+            }
+            FilterField {
+                label: "Content type",
+                select {
+                    class: "w-full px-3 py-2 border border-gray-300 rounded-lg text-sm",
+                    value: draft.read().kind.map(|k| k.query_value()).unwrap_or_default(),
+                    onchange: move |evt| draft.write().kind = ContentKind::from_query_value(&evt.value()),
+                    option { value: "", "Any" }
+                    for kind in ContentKind::ALL {
+                        option { value: kind.query_value(), "{kind.display_name()}" }
+                    }
+                }
+            }
+            FilterField {
+                label: "Speaker",
+                input {
+                    class: "w-full px-3 py-2 border border-gray-300 rounded-lg text-sm",
+                    value: text(&draft.read().speaker),
+                    oninput: move |evt| draft.write().speaker = optional(evt.value()),
+                }
+            }
+            FilterField {
+                label: "Scripture book",
+                input {
+                    class: "w-full px-3 py-2 border border-gray-300 rounded-lg text-sm",
+                    placeholder: "e.g. Romans",
+                    value: text(&draft.read().scripture_book),
+                    oninput: move |evt| draft.write().scripture_book = optional(evt.value()),
+                }
+            }
+            FilterField {
+                label: "Language",
+                select {
+                    class: "w-full px-3 py-2 border border-gray-300 rounded-lg text-sm",
+                    value: text(&draft.read().language),
+                    onchange: move |evt| draft.write().language = optional(evt.value()),
+                    option { value: "", "Any language" }
+                    // A regional tag from a shared link isn't in the list
+                    if let Some(other) = other_language {
+                        option { value: "{other}", "{language_name(&other)}" }
+                    }
+                    for (code, name) in COMMON_LANGUAGES.iter().copied() {
+                        option { key: "{code}", value: code, "{name}" }
+                    }
+                }
+            }
+            FilterField {
+                label: "Duration",
+                select {
+                    class: "w-full px-3 py-2 border border-gray-300 rounded-lg text-sm",
+                    value: draft.read().duration.map(|d| d.query_value()).unwrap_or_default(),
+                    onchange: move |evt| draft.write().duration = DurationBucket::from_query_value(&evt.value()),
+                    option { value: "", "Any length" }
+                    for bucket in DurationBucket::ALL {
+                        option { value: bucket.query_value(), "{bucket.display_name()}" }
+                    }
+                }
+            }
+            FilterField {
+                label: "License",
+                select {
+                    class: "w-full px-3 py-2 border border-gray-300 rounded-lg text-sm",
+                    value: draft.read().license.map(|l| l.query_value()).unwrap_or_default(),
+                    onchange: move |evt| draft.write().license = LicenseFilter::from_query_value(&evt.value()),
+                    option { value: "", "Any license" }
+                    for license in LicenseFilter::ALL {
+                        option { value: license.query_value(), "{license.display_name()}" }
+                    }
+                }
+            }
+
+            label {
+                class: "flex items-center gap-2 text-sm text-gray-700",
+                input {
+                    r#type: "checkbox",
+                    checked: draft.read().all_versions,
+                    onchange: move |evt| draft.write().all_versions = evt.checked(),
+                }
+                "Show earlier versions"
+            }
+
+            div {
+                class: "flex gap-2 pt-2",
+                button {
+                    class: "flex-1 bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                    r#type: "submit",
+                    "Apply"
+                }
+                button {
+                    class: "px-4 py-2 rounded-lg text-sm font-medium text-gray-700 hover:bg-gray-100 transition-colors",
+                    r#type: "button",
+                    onclick: move |_| on_apply.call(BrowseFilters::default()),
+                    "Clear"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn FilterField(label: &'static str, children: Element) -> Element {
+    rsx! {
+        label {
+            class: "block",
+            span { class: "block text-xs font-medium text-gray-600 mb-1", "{label}" }
+            {children}
+        }
+    }
+}
+
+/// "32:10 · 28.4 MB" from the `Duration` and `File-Size` tags, leaving
+/// out whichever the item doesn't have
+pub fn length_and_size(item: &ContentMetadata) -> String {
+    let length = item.duration_secs.map(|secs| format_position(secs as f64));
+    let size = item.file_size.map(format_size);
+    length.into_iter().chain(size).collect::<Vec<_>>().join(" · ")
+}
+
+#[component]
+pub fn BrowseCard(item: ContentMetadata) -> Element {
+    let playback = use_playback_position(item.tx_id.clone());
+    let settings = use_settings();
+    let played_percent = playback
+        .read()
+        .as_ref()
+        .filter(|p| p.position_secs > 0.0)
+        .map(|p| (p.progress() * 100.0).round() as u32);
+    let updated = superseded_by(&item.tx_id).is_some();
+    let withdrawn = revocation_of(&item.tx_id).is_some();
+    let facts = length_and_size(&item);
+
+    rsx! {
+        div {
+            class: "flex items-start gap-2 bg-white rounded-xl shadow-sm border border-green-200 p-5 hover:shadow-md transition-shadow",
+
+            if let Some(cover_tx_id) = item.cover_tx_id.as_ref().filter(|_| !settings.read().data_saver) {
+                img {
+                    class: "w-16 h-16 rounded object-cover flex-shrink-0",
+                    src: GatewayManager::new().media_url(cover_tx_id),
+                    alt: "",
+                    loading: "lazy",
+                }
+            }
+            div {
+                class: "flex-1",
+                Link {
+                    class: "block",
+                    to: Route::ContentDetail { tx_id: item.tx_id.clone() },
+
+                    div { class: "font-semibold text-gray-900", "{item.title}" }
+                    if updated {
+                        span { class: "inline-block text-xs bg-blue-100 text-blue-800 px-2 py-0.5 rounded-full mt-1", "Updated version available" }
+                    } else if withdrawn {
+                        span { class: "inline-block text-xs bg-amber-100 text-amber-800 px-2 py-0.5 rounded-full mt-1", "Withdrawn by uploader" }
+                    }
+                    if let Some(speaker) = item.speaker.as_ref() {
+                        div { class: "text-sm text-gray-600", "{speaker}" }
+                    }
+                    if !item.scripture_refs.is_empty() {
+                        div { class: "text-xs text-green-700 mt-1", "{item.scripture_refs.join(\", \")}" }
+                    }
+                    if !facts.is_empty() {
+                        div { class: "text-xs text-gray-500 mt-1", "{facts}" }
+                    }
+                    if let Some(percent) = played_percent {
+                        div {
+                            class: "w-full bg-gray-200 rounded-full h-1 mt-3",
+                            title: "{percent}% played",
+                            div { class: "bg-green-600 h-1 rounded-full", style: "width: {percent}%" }
+                        }
+                    }
+                }
+                ProfileHeader { address: item.owner.clone(), compact: true }
+                ReactionBar { tx_id: item.tx_id.clone(), compact: true }
+            }
+            BookmarkButtons { item: item.clone() }
+        }
+    }
+}
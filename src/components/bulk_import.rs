@@ -0,0 +1,391 @@
+use std::sync::Arc;
+
+use dioxus::html::FileEngine;
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::upload_failure::UploadFailureDetails;
+use crate::services::bulk_import::{validate_rows, ColumnMapping, ImportField, ImportManifest};
+use crate::services::series::{SeriesService, SeriesSummary};
+use crate::services::settings::{update_settings, use_settings};
+use crate::services::transfer::UPLOAD_LIMIT_CHOICES;
+use crate::services::upload_queue::{
+    cancel_upload, clear_finished_uploads, pause_upload, queue_uploads, resume_upload, retry_failed_uploads, retry_upload, use_upload_queue, PendingUpload,
+    QueuedUpload, UploadQueueProgress, UploadStatus,
+};
+use crate::services::upload_failure::UploadFailure;
+use crate::services::wallet::use_wallet_state;
+
+/// Import a spreadsheet of existing sermons and their files in one batch
+///
+/// The manifest's columns are mapped to metadata fields (guessed from the
+/// headers to start with), every row is checked against the chosen files,
+/// and the rows that pass go to the upload queue.
+#[component]
+pub fn BulkImport() -> Element {
+    let wallet_state = use_wallet_state();
+    let settings = use_settings();
+    let mut manifest = use_signal(|| None::<(String, ImportManifest)>);
+    let mut manifest_error = use_signal(|| None::<String>);
+    let mut mapping = use_signal(ColumnMapping::new);
+    let mut files = use_signal(|| None::<Arc<dyn FileEngine>>);
+    let mut series = use_signal(Vec::<SeriesSummary>::new);
+    let mut accept_scan_warnings = use_signal(|| false);
+    let uploads = use_upload_queue();
+
+    let owner = wallet_state.read().address().map(str::to_string);
+    let series_owner = owner.clone().unwrap_or_default();
+    use_effect(use_reactive!(|series_owner| {
+        if series_owner.is_empty() {
+            return;
+        }
+        spawn(async move {
+            match SeriesService::new().list_for_owner(&series_owner).await {
+                Ok(found) => series.set(found),
+                Err(e) => log::warn!("Could not load series: {}", e),
+            }
+        });
+    }));
+
+    let checked = use_memo(move || {
+        let Some((_, manifest)) = manifest.read().clone() else {
+            return Vec::new();
+        };
+        let names = files.read().as_ref().map(|files| files.files()).unwrap_or_default();
+        let rows = manifest.import_rows(&mapping.read());
+        rows.iter().cloned().zip(validate_rows(&rows, &names, &series.read())).collect::<Vec<_>>()
+    });
+
+    let select_manifest = move |evt: FormEvent| async move {
+        let Some(file_engine) = evt.files() else {
+            return;
+        };
+        let Some(name) = file_engine.files().into_iter().next() else {
+            return;
+        };
+        let Some(text) = file_engine.read_file_to_string(&name).await else {
+            manifest_error.set(Some(format!("Could not read {}", name)));
+            return;
+        };
+        match ImportManifest::parse(&name, &text) {
+            Ok(parsed) => {
+                mapping.set(parsed.guess_mapping());
+                manifest.set(Some((name, parsed)));
+                manifest_error.set(None);
+            }
+            Err(e) => {
+                manifest.set(None);
+                manifest_error.set(Some(e.to_string()));
+            }
+        }
+    };
+
+    let select_files = move |evt: FormEvent| {
+        files.set(evt.files());
+    };
+
+    let queue_batch = move |_| {
+        let Some(file_engine) = files.read().clone() else {
+            return;
+        };
+        let license = settings.read().default_license.clone();
+        let batch: Vec<PendingUpload> = checked
+            .read()
+            .iter()
+            .filter_map(|(_, result)| result.as_ref().ok())
+            .map(|valid| PendingUpload {
+                request: valid.row.to_request(valid.series.clone(), license.clone(), accept_scan_warnings()),
+                file_name: valid.file_name.clone(),
+                files: file_engine.clone(),
+            })
+            .collect();
+        log::info!("📦 Queued {} items from the bulk import", batch.len());
+        queue_uploads(batch);
+        manifest.set(None);
+        files.set(None);
+    };
+
+    let headers = manifest.read().as_ref().map(|(_, manifest)| manifest.headers.clone()).unwrap_or_default();
+    let valid_count = checked.read().iter().filter(|(_, result)| result.is_ok()).count();
+    let problem_count = checked.read().len() - valid_count;
+    let missing_required: Vec<&str> = ImportField::ALL
+        .iter()
+        .filter(|field| field.is_required() && !mapping.read().contains_key(field))
+        .map(|field| field.display_name())
+        .collect();
+    let select_class = "w-full px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-green-500";
+
+    rsx! {
+        main {
+            class: "max-w-5xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                class: "flex items-center justify-between",
+                h2 { class: "text-2xl font-bold text-gray-900", "Bulk Import" }
+                Link { to: Route::Upload {}, class: "text-sm text-green-700 hover:text-green-800", "Upload a single item" }
+            }
+
+            UploadQueuePanel { uploads: uploads.clone() }
+
+            if owner.is_none() {
+                p { class: "text-gray-600", "Connect your wallet to import content." }
+            } else {
+                div {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 space-y-4",
+                    h3 { class: "font-semibold text-gray-900", "1. Choose the manifest and files" }
+                    p {
+                        class: "text-sm text-gray-600",
+                        "The manifest is a CSV file with a header row, or a JSON array of objects, with one row per item. Each row needs a title and the name of its file; scripture references are separated with semicolons. Rows can name one of your existing series."
+                    }
+                    div {
+                        class: "grid md:grid-cols-2 gap-4",
+                        label {
+                            class: "block text-sm font-medium text-gray-700",
+                            "Manifest (CSV or JSON)"
+                            input { class: "block text-sm mt-1", r#type: "file", accept: ".csv,.json", onchange: select_manifest }
+                        }
+                        label {
+                            class: "block text-sm font-medium text-gray-700",
+                            "Folder of files"
+                            input { class: "block text-sm mt-1", r#type: "file", directory: true, multiple: true, onchange: select_files }
+                            if let Some(chosen) = files.read().as_ref() {
+                                span { class: "block text-xs text-gray-500 mt-1 font-normal", "{chosen.files().len()} files chosen" }
+                            }
+                        }
+                    }
+                    if let Some(error) = manifest_error() {
+                        p { class: "text-sm text-red-700", "{error}" }
+                    }
+                }
+
+                if let Some((name, parsed)) = manifest.read().clone() {
+                    div {
+                        class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 space-y-4",
+                        h3 { class: "font-semibold text-gray-900", "2. Match the columns of {name}" }
+                        div {
+                            class: "grid sm:grid-cols-2 md:grid-cols-4 gap-4",
+                            for field in ImportField::ALL {
+                                label {
+                                    key: "{field.key()}",
+                                    class: "block text-sm font-medium text-gray-700",
+                                    if field.is_required() { "{field.display_name()} *" } else { "{field.display_name()}" }
+                                    select {
+                                        class: "{select_class} mt-1",
+                                        value: mapping.read().get(&field).map(|column| column.to_string()).unwrap_or_default(),
+                                        onchange: move |evt| {
+                                            match evt.value().parse::<usize>() {
+                                                Ok(column) => mapping.write().insert(field, column),
+                                                Err(_) => mapping.write().remove(&field),
+                                            };
+                                        },
+                                        option { value: "", "Not imported" }
+                                        for (column, header) in headers.iter().enumerate() {
+                                            option { key: "{column}", value: "{column}", "{header}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if !missing_required.is_empty() {
+                            p { class: "text-sm text-red-700", "Choose a column for: {missing_required.join(\", \")}" }
+                        }
+                    }
+
+                    div {
+                        class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 space-y-4",
+                        h3 { class: "font-semibold text-gray-900", "3. Check the rows" }
+                        p {
+                            class: "text-sm text-gray-600",
+                            "{valid_count} of {parsed.rows.len()} rows are ready to upload"
+                            if problem_count > 0 { "; rows with problems are skipped." } else { "." }
+                        }
+                        if files.read().is_none() {
+                            p { class: "text-sm text-yellow-800", "Choose the folder of files to check the rows against it." }
+                        }
+                        div {
+                            class: "max-h-96 overflow-y-auto border border-gray-200 rounded-lg divide-y divide-gray-100",
+                            for (row, result) in checked.read().iter() {
+                                div {
+                                    key: "{row.number}",
+                                    class: "flex items-start gap-3 px-4 py-2 text-sm",
+                                    span { class: "text-gray-400 w-10 shrink-0", "{row.number}" }
+                                    div {
+                                        class: "flex-1 min-w-0",
+                                        p { class: "font-medium text-gray-900 truncate", "{row.title}" }
+                                        p { class: "text-xs text-gray-500 truncate", "{row.file}" }
+                                    }
+                                    match result {
+                                        Ok(_) => rsx! { span { class: "text-green-700 shrink-0", "Ready" } },
+                                        Err(problem) => rsx! { span { class: "text-red-700 text-right", "{problem}" } },
+                                    }
+                                }
+                            }
+                        }
+                        label {
+                            class: "flex items-center gap-2 text-sm text-gray-700",
+                            input {
+                                r#type: "checkbox",
+                                checked: accept_scan_warnings(),
+                                onchange: move |evt| accept_scan_warnings.set(evt.checked()),
+                            }
+                            "Publish items the content scan only warns about (blocked items are never published)"
+                        }
+                        button {
+                            class: "bg-green-600 hover:bg-green-700 disabled:opacity-50 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                            disabled: valid_count == 0 || files.read().is_none(),
+                            onclick: queue_batch,
+                            "Upload {valid_count} items"
+                        }
+                        p { class: "text-xs text-gray-500", "Each item is signed with your wallet as its turn comes, so keep this tab open until the batch finishes." }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Aggregate progress of the upload queue, with each item's status
+#[component]
+fn UploadQueuePanel(uploads: Vec<QueuedUpload>) -> Element {
+    if uploads.is_empty() {
+        return rsx! {};
+    }
+    let progress = UploadQueueProgress::of(&uploads);
+    let percent = (progress.fraction() * 100.0).round();
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 space-y-3",
+            div {
+                class: "flex items-center justify-between",
+                h3 { class: "font-semibold text-gray-900", "Uploading {progress.finished()} of {progress.total}" }
+                div {
+                    class: "flex items-center gap-4",
+                    UploadLimitSelect {}
+                    if progress.failed > 0 {
+                        button {
+                            class: "text-sm text-green-700 hover:text-green-800",
+                            onclick: move |_| retry_failed_uploads(),
+                            "Retry failed"
+                        }
+                    }
+                    button {
+                        class: "text-sm text-green-700 hover:text-green-800",
+                        onclick: move |_| clear_finished_uploads(),
+                        "Clear finished"
+                    }
+                }
+            }
+            div {
+                class: "w-full h-2 bg-gray-200 rounded-full overflow-hidden",
+                div { class: "h-full bg-green-600 transition-all", style: "width: {percent}%" }
+            }
+            p {
+                class: "text-sm text-gray-600",
+                "{progress.published} published"
+                if progress.failed > 0 { ", {progress.failed} failed" }
+                if progress.cancelled > 0 { ", {progress.cancelled} cancelled" }
+            }
+            div {
+                class: "max-h-64 overflow-y-auto divide-y divide-gray-100 text-sm",
+                for upload in uploads {
+                    div {
+                        key: "{upload.id}",
+                        class: "flex flex-wrap items-center justify-between gap-3 py-2",
+                        span { class: "truncate text-gray-900", "{upload.title}" }
+                        match upload.status.clone() {
+                            UploadStatus::Queued => rsx! {
+                                TransferControls { id: upload.id, label: "Queued", label_class: "text-gray-500", paused: false }
+                            },
+                            UploadStatus::Uploading => rsx! {
+                                TransferControls { id: upload.id, label: "Uploading…", label_class: "text-green-700", paused: false }
+                            },
+                            UploadStatus::Paused => rsx! {
+                                TransferControls { id: upload.id, label: "Paused", label_class: "text-amber-700", paused: true }
+                            },
+                            UploadStatus::Cancelled => rsx! { span { class: "text-gray-500 shrink-0", "Cancelled" } },
+                            UploadStatus::Published(tx_id) => rsx! {
+                                Link { to: Route::ContentDetail { tx_id }, class: "text-green-700 underline shrink-0", "Published" }
+                            },
+                            UploadStatus::Failed(failure) => rsx! { FailedUpload { id: upload.id, failure } },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A failed upload's error class, opening into the full diagnosis with a
+/// retry from the part that failed
+#[component]
+fn FailedUpload(id: u32, failure: UploadFailure) -> Element {
+    let mut expanded = use_signal(|| false);
+
+    // The details take a line of their own under the row
+    rsx! {
+        span {
+            class: "flex items-center gap-3 text-red-700",
+            span { class: "text-right", "{failure}" }
+            button {
+                class: "text-gray-600 underline shrink-0",
+                onclick: move |_| expanded.toggle(),
+                if *expanded.read() { "Hide" } else { "Details" }
+            }
+            if failure.kind.is_retryable() {
+                button {
+                    class: "text-green-700 underline shrink-0",
+                    onclick: move |_| retry_upload(id),
+                    "Retry"
+                }
+            }
+        }
+        if *expanded.read() {
+            div {
+                class: "basis-full",
+                UploadFailureDetails { failure: failure.clone(), on_retry: move |_| retry_upload(id) }
+            }
+        }
+    }
+}
+
+/// A queued upload's status with pause or resume, and cancel
+#[component]
+fn TransferControls(id: u32, label: &'static str, label_class: &'static str, paused: bool) -> Element {
+    rsx! {
+        span {
+            class: "flex items-center gap-3 shrink-0",
+            span { class: "{label_class}", "{label}" }
+            if paused {
+                button { class: "text-green-700 underline", onclick: move |_| resume_upload(id), "Resume" }
+            } else {
+                button { class: "text-green-700 underline", onclick: move |_| pause_upload(id), "Pause" }
+            }
+            button { class: "text-red-700 underline", onclick: move |_| cancel_upload(id), "Cancel" }
+        }
+    }
+}
+
+/// The upload speed limit from Settings, changeable while a batch runs
+#[component]
+fn UploadLimitSelect() -> Element {
+    let settings = use_settings();
+
+    rsx! {
+        label {
+            class: "flex items-center gap-2 text-sm text-gray-600",
+            "Speed"
+            select {
+                class: "px-2 py-1 border border-gray-300 rounded-md text-sm",
+                value: "{settings.read().upload_limit_kbps.unwrap_or(0)}",
+                onchange: move |evt| {
+                    let kbps = evt.value().parse::<u32>().ok().filter(|kbps| *kbps > 0);
+                    update_settings(|settings| settings.upload_limit_kbps = kbps);
+                },
+                option { value: "0", "Unlimited" }
+                for kbps in UPLOAD_LIMIT_CHOICES {
+                    option { key: "{kbps}", value: "{kbps}", "{kbps} KB/s" }
+                }
+            }
+        }
+    }
+}
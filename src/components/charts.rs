@@ -0,0 +1,190 @@
+use dioxus::prelude::*;
+use crate::utils::charts::{
+    area_path, bars, donut_slices, format_value, line_coordinates, line_path, ChartPoint, DONUT_RADIUS, VIEW_HEIGHT,
+    VIEW_WIDTH,
+};
+
+/// Slice colours, repeated past the last
+const PALETTE: [&str; 7] = ["#16a34a", "#0ea5e9", "#f59e0b", "#8b5cf6", "#ef4444", "#14b8a6", "#6b7280"];
+const BAR_COLOR: &str = "#22c55e";
+const HOVER_COLOR: &str = "#15803d";
+
+fn slice_color(index: usize) -> &'static str {
+    PALETTE[index % PALETTE.len()]
+}
+
+/// Value of the point under the pointer, kept one line high so the chart
+/// doesn't jump
+#[component]
+fn Readout(points: Vec<ChartPoint>, hovered: Option<usize>) -> Element {
+    let point = hovered.and_then(|index| points.get(index));
+    rsx! {
+        p {
+            class: "h-5 text-xs text-gray-600",
+            "aria-live": "polite",
+            if let Some(point) = point {
+                "{point.label}: {format_value(point.value)}"
+            }
+        }
+    }
+}
+
+/// First and last labels under a bar or line chart
+#[component]
+fn AxisLabels(points: Vec<ChartPoint>) -> Element {
+    rsx! {
+        if let (Some(first), Some(last)) = (points.first(), points.last()) {
+            div {
+                class: "flex justify-between text-xs text-gray-500 mt-1",
+                span { "{first.label}" }
+                if points.len() > 1 {
+                    span { "{last.label}" }
+                }
+            }
+        }
+    }
+}
+
+/// Vertical bars, one per point
+#[component]
+pub fn BarChart(points: Vec<ChartPoint>, #[props(default = "h-32")] height: &'static str) -> Element {
+    let mut hovered = use_signal(|| None::<usize>);
+    let shapes = bars(&points);
+
+    rsx! {
+        div {
+            Readout { points: points.clone(), hovered: hovered() }
+            svg {
+                class: "w-full {height}",
+                view_box: "0 0 {VIEW_WIDTH} {VIEW_HEIGHT}",
+                preserve_aspect_ratio: "none",
+                role: "img",
+                onmouseleave: move |_| hovered.set(None),
+                for (index, bar) in shapes.into_iter().enumerate() {
+                    rect {
+                        key: "{index}",
+                        x: "{bar.x}",
+                        y: "{bar.y}",
+                        width: "{bar.width}",
+                        height: "{bar.height}",
+                        fill: if hovered() == Some(index) { HOVER_COLOR } else { BAR_COLOR },
+                        onmouseenter: move |_| hovered.set(Some(index)),
+                    }
+                }
+            }
+            AxisLabels { points }
+        }
+    }
+}
+
+/// A line through the points with the area under it shaded
+#[component]
+pub fn LineChart(points: Vec<ChartPoint>, #[props(default = "h-32")] height: &'static str) -> Element {
+    let mut hovered = use_signal(|| None::<usize>);
+    let coordinates = line_coordinates(&points);
+    // Each point answers to the pointer across its share of the width
+    let slot = VIEW_WIDTH / points.len().max(1) as f64;
+    let marker = hovered().and_then(|index| coordinates.get(index).copied());
+
+    rsx! {
+        div {
+            Readout { points: points.clone(), hovered: hovered() }
+            svg {
+                class: "w-full {height}",
+                view_box: "0 0 {VIEW_WIDTH} {VIEW_HEIGHT}",
+                preserve_aspect_ratio: "none",
+                role: "img",
+                onmouseleave: move |_| hovered.set(None),
+                path { d: area_path(&coordinates), fill: BAR_COLOR, opacity: "0.2" }
+                path {
+                    d: line_path(&coordinates),
+                    fill: "none",
+                    stroke: HOVER_COLOR,
+                    stroke_width: "2",
+                    vector_effect: "non-scaling-stroke",
+                }
+                if let Some((x, _)) = marker {
+                    line {
+                        x1: "{x}",
+                        y1: "0",
+                        x2: "{x}",
+                        y2: "{VIEW_HEIGHT}",
+                        stroke: "#9ca3af",
+                        stroke_width: "1",
+                        vector_effect: "non-scaling-stroke",
+                    }
+                }
+                for (index, (x, _)) in coordinates.iter().copied().enumerate() {
+                    rect {
+                        key: "{index}",
+                        x: "{x - slot / 2.0}",
+                        y: "0",
+                        width: "{slot}",
+                        height: "{VIEW_HEIGHT}",
+                        fill: "transparent",
+                        onmouseenter: move |_| hovered.set(Some(index)),
+                    }
+                }
+            }
+            AxisLabels { points }
+        }
+    }
+}
+
+/// Share of the whole per point, with a legend
+#[component]
+pub fn DonutChart(points: Vec<ChartPoint>) -> Element {
+    let mut hovered = use_signal(|| None::<usize>);
+    let slices = donut_slices(&points);
+    let total: f64 = points.iter().map(|point| point.value).sum();
+
+    rsx! {
+        div {
+            class: "flex flex-wrap items-center gap-6",
+            svg {
+                class: "w-32 h-32 shrink-0",
+                view_box: "0 0 42 42",
+                role: "img",
+                onmouseleave: move |_| hovered.set(None),
+                circle { cx: "21", cy: "21", r: "{DONUT_RADIUS}", fill: "none", stroke: "#f3f4f6", stroke_width: "6" }
+                for (index, slice) in slices.iter().copied().enumerate() {
+                    circle {
+                        key: "{index}",
+                        cx: "21",
+                        cy: "21",
+                        r: "{DONUT_RADIUS}",
+                        fill: "none",
+                        stroke: slice_color(index),
+                        stroke_width: if hovered() == Some(index) { "8" } else { "6" },
+                        stroke_dasharray: "{slice.length} {100.0 - slice.length}",
+                        stroke_dashoffset: "{slice.offset}",
+                        onmouseenter: move |_| hovered.set(Some(index)),
+                    }
+                }
+                text {
+                    x: "21",
+                    y: "23",
+                    text_anchor: "middle",
+                    font_size: "6",
+                    fill: "#111827",
+                    "{format_value(total)}"
+                }
+            }
+            ul {
+                class: "flex-1 min-w-40 space-y-1 text-sm",
+                for (index, (point, slice)) in points.iter().zip(slices.iter()).enumerate() {
+                    li {
+                        key: "{point.label}",
+                        class: if hovered() == Some(index) { "flex items-center gap-2 text-gray-900 font-medium" } else { "flex items-center gap-2 text-gray-700" },
+                        onmouseenter: move |_| hovered.set(Some(index)),
+                        onmouseleave: move |_| hovered.set(None),
+                        span { class: "inline-block w-3 h-3 rounded-sm", style: "background-color: {slice_color(index)}" }
+                        span { class: "flex-1", "{point.label}" }
+                        span { "{format_value(point.value)}" }
+                        span { class: "w-12 text-right text-gray-500", "{slice.length.round()}%" }
+                    }
+                }
+            }
+        }
+    }
+}
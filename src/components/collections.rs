@@ -0,0 +1,487 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::browse::BrowseCard;
+use crate::components::follow_button::FollowButton;
+use crate::components::modal::Modal;
+use crate::components::profile::ProfileHeader;
+use crate::components::share::{ShareButton, ShareHead};
+use crate::components::skeleton::{CardSkeleton, DetailSkeleton};
+use crate::models::{CollectionManifest, ContentMetadata};
+use crate::services::async_state::{use_async_resource, AsyncState};
+use crate::services::collections::{CollectionError, CollectionService, CuratedCollection};
+use crate::services::follows::FollowTarget;
+use crate::services::share::ShareMeta;
+use crate::services::wallet::use_wallet_state;
+use crate::utils::display::short_address;
+
+/// Every published collection, with a form to start a new one
+#[component]
+pub fn Collections() -> Element {
+    let navigator = use_navigator();
+    let wallet_state = use_wallet_state();
+    let mut title = use_signal(String::new);
+    let mut description = use_signal(String::new);
+    let mut is_creating = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let collections = use_async_resource(|| async { CollectionService::new().list().await });
+
+    let create = move |evt: FormEvent| {
+        evt.prevent_default();
+        let mut collection = CollectionManifest::new(&title.read());
+        let text = description.read().trim().to_string();
+        collection.description = (!text.is_empty()).then_some(text);
+        spawn(async move {
+            is_creating.set(true);
+            error.set(None);
+            match CollectionService::new().publish(&mut collection).await {
+                Ok(_) => {
+                    navigator.push(Route::CollectionDetail { collection_id: collection.collection_id.clone() });
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            is_creating.set(false);
+        });
+    };
+
+    rsx! {
+        main {
+            class: "max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                h2 { class: "text-3xl font-bold text-gray-900", "Collections" }
+                p {
+                    class: "text-gray-600 mt-1",
+                    "Compilations gathered by archive users from anyone's uploads, such as Easter sermons or a study on grace."
+                }
+            }
+
+            if wallet_state.read().is_connected() {
+                form {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 space-y-3",
+                    onsubmit: create,
+                    h3 { class: "text-lg font-semibold text-gray-900", "Start a collection" }
+                    input {
+                        class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm",
+                        aria_label: "Collection name",
+                        placeholder: "Name, e.g. Easter sermons",
+                        value: "{title}",
+                        oninput: move |evt| title.set(evt.value()),
+                    }
+                    textarea {
+                        class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm",
+                        aria_label: "Description",
+                        placeholder: "What the collection is about (optional)",
+                        rows: 2,
+                        value: "{description}",
+                        oninput: move |evt| description.set(evt.value()),
+                    }
+                    if let Some(e) = error.read().as_ref() {
+                        p { class: "text-sm text-red-700", "{e}" }
+                    }
+                    button {
+                        class: "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium disabled:opacity-50",
+                        r#type: "submit",
+                        disabled: title.read().trim().is_empty() || *is_creating.read(),
+                        if *is_creating.read() { "Publishing…" } else { "Create collection" }
+                    }
+                    p { class: "text-xs text-gray-500", "Add items from their pages with \"Add to collection\"." }
+                }
+            }
+
+            match collections.state() {
+                AsyncState::Loading => rsx! { CardSkeleton {} },
+                AsyncState::Failed(message) => rsx! {
+                    div {
+                        class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                        "{message}"
+                    }
+                },
+                AsyncState::Ready(collections) if collections.is_empty() => rsx! {
+                    div {
+                        class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-center text-gray-500",
+                        "No collections yet."
+                    }
+                },
+                AsyncState::Ready(collections) => rsx! {
+                    ul {
+                        class: "bg-white rounded-xl shadow-sm border border-green-200 divide-y divide-gray-100",
+                        for collection in collections {
+                            li {
+                                key: "{collection.collection_id}",
+                                class: "p-4 flex items-center justify-between gap-4",
+                                div {
+                                    Link {
+                                        class: "font-medium text-gray-900 hover:text-green-700",
+                                        to: Route::CollectionDetail { collection_id: collection.collection_id.clone() },
+                                        "{collection.title}"
+                                    }
+                                    div {
+                                        class: "text-sm text-gray-500",
+                                        "Curated by {short_address(&collection.curator)}"
+                                        if let Some(count) = collection.item_count {
+                                            " · {count} items"
+                                        }
+                                    }
+                                }
+                                FollowButton { target: FollowTarget::Collection(collection.collection_id.clone()) }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// A collection's items in the curator's order; the curator can reorder,
+/// remove and rename here
+#[component]
+pub fn CollectionDetail(collection_id: String) -> Element {
+    let wallet_state = use_wallet_state();
+    let mut draft = use_signal(|| None::<CollectionManifest>);
+    let mut is_saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let lookup_id = collection_id.clone();
+    let mut collection = use_async_resource(use_reactive!(|lookup_id| async move {
+        let service = CollectionService::new();
+        let collection = service.load(&lookup_id).await?;
+        let items = service.items(&collection.manifest).await?;
+        Ok::<(CuratedCollection, Vec<ContentMetadata>), CollectionError>((collection, items))
+    }));
+
+    let save = move |_| {
+        let Some(mut edited) = draft.read().clone() else {
+            return;
+        };
+        spawn(async move {
+            is_saving.set(true);
+            error.set(None);
+            match CollectionService::new().publish(&mut edited).await {
+                Ok(_) => {
+                    draft.set(None);
+                    collection.restart();
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            is_saving.set(false);
+        });
+    };
+
+    let state = collection.state();
+
+    rsx! {
+        main {
+            class: "max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            match &state {
+                AsyncState::Loading => rsx! {
+                    DetailSkeleton {}
+                    CardSkeleton {}
+                },
+                AsyncState::Failed(message) => rsx! {
+                    div {
+                        class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                        "{message}"
+                    }
+                },
+                AsyncState::Ready((loaded, items)) => {
+                    let is_curator = wallet_state.read().address() == Some(loaded.curator.as_str());
+                    let manifest = loaded.manifest.clone();
+                    rsx! {
+                        ShareHead { meta: ShareMeta::for_collection(&loaded.manifest) }
+                        div {
+                            class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 space-y-4",
+                            div {
+                                class: "flex flex-wrap items-center justify-between gap-4",
+                                h2 { class: "text-2xl font-bold text-gray-900", "{loaded.manifest.title}" }
+                                div {
+                                    class: "flex items-center gap-2",
+                                    FollowButton { target: FollowTarget::Collection(loaded.manifest.collection_id.clone()) }
+                                    ShareButton { meta: ShareMeta::for_collection(&loaded.manifest) }
+                                }
+                            }
+                            if let Some(description) = loaded.manifest.description.as_ref() {
+                                p { class: "text-gray-600 whitespace-pre-line", "{description}" }
+                            }
+                            div {
+                                class: "flex items-center gap-2 text-sm text-gray-500",
+                                "Curated by"
+                                ProfileHeader { address: loaded.curator.clone(), compact: true }
+                            }
+                            if is_curator && draft.read().is_none() {
+                                button {
+                                    class: "text-sm text-green-700 hover:text-green-800 font-medium",
+                                    onclick: move |_| draft.set(Some(manifest.clone())),
+                                    "Edit collection"
+                                }
+                            }
+                        }
+
+                        if let Some(edited) = draft.read().clone() {
+                            CollectionEditor {
+                                draft,
+                                edited,
+                                items: items.clone(),
+                                is_saving: *is_saving.read(),
+                                error: error.read().clone(),
+                                on_save: save,
+                            }
+                        } else if items.is_empty() {
+                            div {
+                                class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-center text-gray-500",
+                                "This collection is empty."
+                            }
+                        } else {
+                            for item in items {
+                                BrowseCard { key: "{item.tx_id}", item: item.clone() }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The curator's edit form: name, description and item order
+#[component]
+fn CollectionEditor(
+    draft: Signal<Option<CollectionManifest>>,
+    edited: CollectionManifest,
+    items: Vec<ContentMetadata>,
+    is_saving: bool,
+    error: Option<String>,
+    on_save: EventHandler<()>,
+) -> Element {
+    let count = edited.items.len();
+    let title_of = |tx_id: &str| {
+        items
+            .iter()
+            .find(|item| item.tx_id == tx_id)
+            .map(|item| item.title.clone())
+            .unwrap_or_else(|| short_address(tx_id))
+    };
+    let rows: Vec<(usize, String, String)> = edited
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, tx_id)| (index, tx_id.clone(), title_of(tx_id)))
+        .collect();
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 space-y-3",
+            input {
+                class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm",
+                aria_label: "Collection name",
+                value: "{edited.title}",
+                oninput: move |evt| {
+                    if let Some(draft) = draft.write().as_mut() {
+                        draft.title = evt.value();
+                    }
+                },
+            }
+            textarea {
+                class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm",
+                aria_label: "Description",
+                rows: 2,
+                value: "{edited.description.clone().unwrap_or_default()}",
+                oninput: move |evt| {
+                    if let Some(draft) = draft.write().as_mut() {
+                        let text = evt.value();
+                        draft.description = (!text.trim().is_empty()).then_some(text);
+                    }
+                },
+            }
+            ol {
+                class: "divide-y divide-gray-100 border border-gray-200 rounded-lg",
+                for (index, tx_id, title) in rows {
+                    li {
+                        key: "{tx_id}",
+                        class: "p-3 flex items-center gap-3 text-sm",
+                        span { class: "w-6 text-gray-400", "{index + 1}" }
+                        span { class: "flex-1 text-gray-900", "{title}" }
+                        button {
+                            class: "text-gray-500 hover:text-green-700 disabled:opacity-30",
+                            aria_label: "Move up",
+                            disabled: index == 0,
+                            onclick: {
+                                let tx_id = tx_id.clone();
+                                move |_| {
+                                    if let Some(draft) = draft.write().as_mut() {
+                                        draft.move_item(&tx_id, true);
+                                    }
+                                }
+                            },
+                            "↑"
+                        }
+                        button {
+                            class: "text-gray-500 hover:text-green-700 disabled:opacity-30",
+                            aria_label: "Move down",
+                            disabled: index + 1 == count,
+                            onclick: {
+                                let tx_id = tx_id.clone();
+                                move |_| {
+                                    if let Some(draft) = draft.write().as_mut() {
+                                        draft.move_item(&tx_id, false);
+                                    }
+                                }
+                            },
+                            "↓"
+                        }
+                        button {
+                            class: "text-red-600 hover:text-red-700",
+                            onclick: move |_| {
+                                if let Some(draft) = draft.write().as_mut() {
+                                    draft.remove_item(&tx_id);
+                                }
+                            },
+                            "Remove"
+                        }
+                    }
+                }
+            }
+            if let Some(e) = error.as_ref() {
+                p { class: "text-sm text-red-700", "{e}" }
+            }
+            div {
+                class: "flex justify-end gap-2",
+                button {
+                    class: "px-4 py-2 rounded-md text-sm text-gray-700 hover:bg-gray-100",
+                    onclick: move |_| draft.set(None),
+                    "Cancel"
+                }
+                button {
+                    class: "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium disabled:opacity-50",
+                    disabled: edited.title.trim().is_empty() || is_saving,
+                    onclick: move |_| on_save.call(()),
+                    if is_saving { "Publishing…" } else { "Publish changes" }
+                }
+            }
+        }
+    }
+}
+
+/// "Add to collection" on an item page: pick one of your collections or
+/// start a new one with this item in it
+#[component]
+pub fn AddToCollectionButton(tx_id: String) -> Element {
+    let wallet_state = use_wallet_state();
+    let mut open = use_signal(|| false);
+    let mut new_title = use_signal(String::new);
+    let mut busy = use_signal(|| false);
+    let mut status = use_signal(|| None::<Result<String, String>>);
+
+    let address = wallet_state.read().address().map(str::to_string);
+    let lookup_address = address.clone();
+    // Only looked up once the dialog opens, and again each time it does
+    let owned = use_async_resource(use_reactive!(|lookup_address| {
+        let owner = lookup_address.filter(|_| open());
+        async move {
+            match owner {
+                Some(owner) => CollectionService::new().list_for_owner(&owner).await,
+                None => Ok(Vec::new()),
+            }
+        }
+    }));
+
+    if address.is_none() {
+        return rsx! {};
+    }
+
+    // Adds to an existing collection, or creates one when `collection_id` is `None`
+    let add = {
+        let tx_id = tx_id.clone();
+        move |collection_id: Option<String>| {
+            let tx_id = tx_id.clone();
+            spawn(async move {
+                busy.set(true);
+                let service = CollectionService::new();
+                let result = async {
+                    let mut collection = match collection_id {
+                        Some(collection_id) => service.load(&collection_id).await?.manifest,
+                        None => CollectionManifest::new(&new_title.read()),
+                    };
+                    collection.add_item(&tx_id);
+                    service.publish(&mut collection).await?;
+                    Ok::<String, CollectionError>(collection.title)
+                }
+                .await;
+                status.set(Some(result.map_err(|e| e.to_string())));
+                busy.set(false);
+            });
+        }
+    };
+    let add_to_new = add.clone();
+
+    rsx! {
+        button {
+            class: "border border-green-600 text-green-700 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+            onclick: move |_| {
+                status.set(None);
+                open.set(true);
+            },
+            "Add to collection"
+        }
+        Modal {
+            open,
+            title: "Add to collection",
+            div {
+                class: "space-y-4 text-sm",
+                match status.read().as_ref() {
+                    Some(Ok(title)) => rsx! {
+                        p { class: "text-green-700", "Added to {title}. It shows there once the new version is indexed." }
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { class: "text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200", "{e}" }
+                    },
+                    None => rsx! {},
+                }
+                match owned.state() {
+                    AsyncState::Loading => rsx! { p { class: "text-gray-500", "Loading your collections…" } },
+                    AsyncState::Failed(message) => rsx! { p { class: "text-red-700", "{message}" } },
+                    AsyncState::Ready(collections) => rsx! {
+                        ul {
+                            class: "divide-y divide-gray-100 border border-gray-200 rounded-lg",
+                            for collection in collections {
+                                li {
+                                    key: "{collection.collection_id}",
+                                    class: "p-3 flex items-center justify-between gap-3",
+                                    span { class: "text-gray-900", "{collection.title}" }
+                                    button {
+                                        class: "text-green-700 hover:text-green-800 font-medium disabled:opacity-50",
+                                        disabled: *busy.read(),
+                                        onclick: {
+                                            let add = add.clone();
+                                            let collection_id = collection.collection_id.clone();
+                                            move |_| add(Some(collection_id.clone()))
+                                        },
+                                        "Add"
+                                    }
+                                }
+                            }
+                        }
+                    },
+                }
+                div {
+                    class: "flex gap-2",
+                    input {
+                        class: "flex-1 px-3 py-2 border border-gray-300 rounded-md",
+                        aria_label: "New collection name",
+                        placeholder: "New collection name",
+                        value: "{new_title}",
+                        oninput: move |evt| new_title.set(evt.value()),
+                    }
+                    button {
+                        class: "px-3 py-2 rounded-md bg-green-600 text-white font-medium hover:bg-green-700 disabled:opacity-50",
+                        disabled: new_title.read().trim().is_empty() || *busy.read(),
+                        onclick: move |_| add_to_new(None),
+                        "Create"
+                    }
+                }
+            }
+        }
+    }
+}
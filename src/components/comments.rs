@@ -0,0 +1,237 @@
+use dioxus::prelude::*;
+use crate::components::address_avatar::AddressAvatar;
+use crate::services::async_state::{use_async_resource, AsyncState};
+use crate::services::comments::{build_threads, Comment, CommentNode, CommentService, MAX_COMMENT_CHARS};
+use crate::services::hide_registry::{is_moderator, use_hidden_ids, HideAction, HideRegistry};
+use crate::services::identity::use_local_identity;
+use crate::services::toast::{show_toast, ToastKind};
+use crate::services::wallet::{use_wallet_state, WalletService};
+
+/// Replies deeper than this are shown without further indentation
+const MAX_INDENT_DEPTH: usize = 3;
+
+/// Threaded comments on an item, with a form to add one
+///
+/// Comments posted here are shown straight away, before the gateway
+/// indexes them. Moderator wallets get a Hide button that lists the
+/// comment in the hide registry.
+#[component]
+pub fn CommentThread(tx_id: String) -> Element {
+    let hidden_ids = use_hidden_ids();
+    let wallet_state = use_wallet_state();
+    let mut posted = use_signal(Vec::<Comment>::new);
+
+    let lookup_id = tx_id.clone();
+    let loaded = use_async_resource(use_reactive!(|lookup_id| async move {
+        CommentService::new().load(&lookup_id).await
+    }));
+
+    let can_moderate = wallet_state
+        .read()
+        .address()
+        .is_some_and(is_moderator);
+
+    let mut comments = loaded.ready().unwrap_or_default();
+    for comment in posted.read().iter() {
+        if !comments.iter().any(|loaded| loaded.id == comment.id) {
+            comments.push(comment.clone());
+        }
+    }
+    comments.retain(|comment| !hidden_ids.read().contains(&comment.id));
+    let threads = build_threads(comments);
+    let total: usize = threads.iter().map(CommentNode::count).sum();
+
+    rsx! {
+        div {
+            class: "mt-6 bg-white rounded-xl shadow-sm border border-green-200 p-6 space-y-4",
+            h3 { class: "text-lg font-semibold text-gray-900", "Comments ({total})" }
+
+            CommentForm {
+                tx_id: tx_id.clone(),
+                reply_to: None,
+                on_posted: move |comment| posted.write().push(comment),
+            }
+
+            match loaded.state() {
+                AsyncState::Loading => rsx! {
+                    p { class: "text-sm text-gray-500", "Loading comments…" }
+                },
+                AsyncState::Failed(message) => rsx! {
+                    p { class: "text-sm text-red-700", "{message}" }
+                },
+                AsyncState::Ready(_) => rsx! {
+                    if threads.is_empty() {
+                        p { class: "text-sm text-gray-500", "No comments yet. Be the first to share a thought." }
+                    }
+                    ul {
+                        class: "space-y-4",
+                        for node in threads {
+                            CommentView {
+                                key: "{node.comment.id}",
+                                node: node.clone(),
+                                depth: 0,
+                                can_moderate,
+                                on_posted: move |comment| posted.write().push(comment),
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn CommentView(node: CommentNode, depth: usize, can_moderate: bool, on_posted: EventHandler<Comment>) -> Element {
+    let mut replying = use_signal(|| false);
+    let mut hiding = use_signal(|| false);
+    let comment = node.comment.clone();
+
+    let hide = {
+        let comment_id = comment.id.clone();
+        move |_| {
+            let comment_id = comment_id.clone();
+            hiding.set(true);
+            spawn(async move {
+                if let Err(e) = HideRegistry::new()
+                    .publish(&comment_id, HideAction::Hide, "Comment removed by moderators", None, None)
+                    .await
+                {
+                    show_toast(ToastKind::Error, e.to_string());
+                }
+                hiding.set(false);
+            });
+        }
+    };
+
+    let posted_at = comment
+        .timestamp
+        .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0))
+        .map(|date| date.format("%b %-d, %Y").to_string())
+        .unwrap_or_else(|| "Pending".to_string());
+
+    rsx! {
+        li {
+            class: "space-y-2",
+            div {
+                class: "flex items-center gap-2 text-xs text-gray-500",
+                AddressAvatar { address: comment.author.clone(), size: "w-5 h-5" }
+                span { class: "font-mono text-gray-700", title: "{comment.author}", "{WalletService::format_address(&comment.author)}" }
+                span { "· {posted_at}" }
+            }
+            p { class: "text-sm text-gray-800 whitespace-pre-line break-words", "{comment.body}" }
+            div {
+                class: "flex gap-3 text-xs",
+                button {
+                    class: "text-green-700 hover:text-green-800 font-medium",
+                    onclick: move |_| {
+                        let open = !*replying.read();
+                        replying.set(open);
+                    },
+                    if *replying.read() { "Cancel" } else { "Reply" }
+                }
+                if can_moderate {
+                    button {
+                        class: "text-red-600 hover:text-red-700 disabled:opacity-50",
+                        disabled: *hiding.read(),
+                        onclick: hide,
+                        if *hiding.read() { "Hiding…" } else { "Hide" }
+                    }
+                }
+            }
+            if *replying.read() {
+                CommentForm {
+                    tx_id: comment.parent_tx.clone(),
+                    reply_to: Some(comment.id.clone()),
+                    on_posted: move |reply| {
+                        replying.set(false);
+                        on_posted.call(reply);
+                    },
+                }
+            }
+            if !node.replies.is_empty() {
+                ul {
+                    class: if depth < MAX_INDENT_DEPTH { "mt-3 ml-4 pl-4 border-l border-gray-200 space-y-4" } else { "mt-3 space-y-4" },
+                    for reply in node.replies.iter() {
+                        CommentView {
+                            key: "{reply.comment.id}",
+                            node: reply.clone(),
+                            depth: depth + 1,
+                            can_moderate,
+                            on_posted,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CommentForm(tx_id: String, reply_to: Option<String>, on_posted: EventHandler<Comment>) -> Element {
+    let wallet_state = use_wallet_state();
+    let identity = use_local_identity();
+    let mut body = use_signal(String::new);
+    let mut is_sending = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let length = body.read().trim().chars().count();
+    let is_reply = reply_to.is_some();
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        let tx_id = tx_id.clone();
+        let reply_to = reply_to.clone();
+        spawn(async move {
+            is_sending.set(true);
+            error.set(None);
+            let text = body.read().clone();
+            match CommentService::new().post(&tx_id, reply_to.as_deref(), &text).await {
+                Ok((_, comment)) => {
+                    body.set(String::new());
+                    on_posted.call(comment);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            is_sending.set(false);
+        });
+    };
+
+    rsx! {
+        form {
+            class: "space-y-2",
+            onsubmit: submit,
+            textarea {
+                class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm",
+                rows: if is_reply { "2" } else { "3" },
+                aria_label: if is_reply { "Reply" } else { "Comment" },
+                placeholder: if is_reply { "Write a reply (published publicly)" } else { "Add a comment (published publicly)" },
+                value: "{body}",
+                oninput: move |evt| body.set(evt.value()),
+            }
+            if !wallet_state.read().is_connected() {
+                if identity.read().is_some() {
+                    p { class: "text-xs text-gray-500", "Without a connected wallet, your comment is signed by your anonymous identity." }
+                } else {
+                    p { class: "text-xs text-gray-500", "Connect a wallet or enable an anonymous identity in Settings to comment." }
+                }
+            }
+            if let Some(message) = error.read().as_ref() {
+                p { class: "text-sm text-red-700", "{message}" }
+            }
+            div {
+                class: "flex items-center justify-between",
+                span {
+                    class: if length > MAX_COMMENT_CHARS { "text-xs text-red-600" } else { "text-xs text-gray-400" },
+                    "{length}/{MAX_COMMENT_CHARS}"
+                }
+                button {
+                    class: "bg-green-600 hover:bg-green-700 disabled:bg-gray-300 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                    r#type: "submit",
+                    disabled: *is_sending.read() || length == 0 || length > MAX_COMMENT_CHARS,
+                    if *is_sending.read() { "Posting…" } else if is_reply { "Reply" } else { "Post comment" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use dioxus::prelude::*;
+use crate::models::TxStatus;
+use crate::services::confirmation::use_confirmation_status;
+use crate::utils::constants::REQUIRED_CONFIRMATIONS;
+
+/// Live confirmation status pill for a submitted transaction
+///
+/// Subscribes to the confirmation poller and re-renders as the item moves
+/// from pending to confirmed.
+#[component]
+pub fn ConfirmationBadge(tx_id: String) -> Element {
+    let item = use_confirmation_status(tx_id);
+
+    let Some(item) = item() else {
+        return rsx! {};
+    };
+
+    let badge_class = match item.status {
+        TxStatus::Pending => "bg-yellow-100 text-yellow-800 dark:bg-yellow-900 dark:text-yellow-200",
+        TxStatus::Confirming => "bg-blue-100 text-blue-800 dark:bg-blue-900 dark:text-blue-200",
+        TxStatus::Confirmed => "bg-green-100 text-green-800 dark:bg-green-900 dark:text-green-200",
+    };
+
+    rsx! {
+        span {
+            class: "inline-flex items-center px-2 py-1 rounded-full text-xs font-medium {badge_class}",
+            title: "Block height: {item.block_height.map(|h| h.to_string()).unwrap_or_else(|| \"pending\".to_string())}",
+
+            "{item.status.display_name()}"
+
+            if item.status == TxStatus::Confirming {
+                span {
+                    class: "ml-1",
+                    "({item.confirmations}/{REQUIRED_CONFIRMATIONS})"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use dioxus::prelude::*;
+use crate::i18n::t;
+use crate::services::wallet::{
+    retry_connection, use_connection_health, ConnectionHealth, WalletService,
+    monitor::MAX_RECONNECT_ATTEMPTS,
+};
+
+/// Strip shown while the wallet session is being restored, or after it was
+/// lost, so a dropped connection doesn't first show up as a failed signature
+#[component]
+pub fn ConnectionBanner() -> Element {
+    let health = use_connection_health();
+    let wallet_state = WalletService::get_extended_state();
+
+    match health() {
+        ConnectionHealth::Healthy => rsx! {},
+        ConnectionHealth::Reconnecting { attempt } => rsx! {
+            div {
+                class: "bg-amber-100 dark:bg-amber-900 border-b border-amber-300 dark:border-amber-700",
+                role: "status",
+                div {
+                    class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-2 flex items-center gap-3 text-sm text-amber-900 dark:text-amber-100",
+                    div { class: "animate-spin rounded-full h-4 w-4 border-b-2 border-amber-700 dark:border-amber-300" }
+                    span { {t!("wallet.reconnecting", attempt = attempt, max = MAX_RECONNECT_ATTEMPTS)} }
+                }
+            }
+        },
+        // Connected again through the wallet button since
+        ConnectionHealth::Lost if wallet_state.read().base_state.is_connected() => rsx! {},
+        ConnectionHealth::Lost => rsx! {
+            div {
+                class: "bg-red-50 dark:bg-red-950 border-b border-red-200 dark:border-red-800",
+                role: "alert",
+                div {
+                    class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-2 flex items-center justify-between gap-4 text-sm",
+                    span { class: "text-red-800 dark:text-red-200", {t!("wallet.connection_lost")} }
+                    button {
+                        class: "font-medium text-red-700 dark:text-red-300 hover:underline",
+                        onclick: move |_| retry_connection(),
+                        {t!("wallet.reconnect")}
+                    }
+                }
+            }
+        },
+    }
+}
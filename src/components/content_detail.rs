@@ -0,0 +1,731 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::ConfirmationBadge;
+use crate::components::bookmark_buttons::BookmarkButtons;
+use crate::components::browse::length_and_size;
+use crate::components::comments::CommentThread;
+use crate::components::encrypted_content::EncryptedContent;
+use crate::components::follow_button::FollowButton;
+use crate::components::media_player::{seek_player, MediaPlayer};
+use crate::components::profile::ProfileHeader;
+use crate::components::reactions::ReactionBar;
+use crate::components::tip::SupportButton;
+use crate::components::reference_export::ReferenceExportButtons;
+use crate::components::downloads::DownloadButton;
+use crate::components::collections::AddToCollectionButton;
+use crate::components::share::{EmbedCodeButton, ShareButton, ShareHead, ShareQrButton};
+use crate::components::skeleton::DetailSkeleton;
+use crate::components::transcript_view::TranscriptView;
+use crate::components::verification_badge::VerificationBadge;
+use crate::models::{ContentMetadata, UDL_LICENSE_TX};
+use crate::services::async_state::{use_async_resource, AsyncState};
+use crate::services::evidence::EvidenceService;
+use crate::services::flags::{use_flag, Flag};
+use crate::services::follows::FollowTarget;
+use crate::services::identity::{use_local_identity, Signer};
+use crate::services::reports::{submit_report, ReportReason};
+use crate::services::revisions::{revision_records, revocation_of, version_chain, ItemVersion, RevisionAction, RevisionRegistry};
+use crate::services::settings::use_settings;
+use crate::services::share::ShareMeta;
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::GraphQLClient;
+use crate::services::hide_registry::{is_moderator, use_hidden_ids, HideAction, HideRegistry};
+use crate::services::playback::{format_position, use_playback_position};
+use crate::services::wallet::{use_wallet_state, WalletService};
+use crate::utils::citation::{CitationStyle, ReferenceFormat};
+use crate::utils::display::use_copy_feedback;
+use crate::utils::download::download_bytes;
+use crate::utils::ipfs::mirror_url;
+
+/// Page for a single archived item
+#[component]
+pub fn ContentDetail(tx_id: String) -> Element {
+    let hidden_ids = use_hidden_ids();
+    let settings = use_settings();
+    let comments_enabled = use_flag(Flag::AoComments);
+    let tipping_enabled = use_flag(Flag::Tipping);
+    let mut current_time = use_signal(|| 0.0);
+
+    let lookup_id = tx_id.clone();
+    let metadata = use_async_resource(use_reactive!(|lookup_id| async move {
+        match GraphQLClient::cached().transaction_details(&lookup_id).await {
+            Ok(Some(details)) => details
+                .metadata()
+                .ok_or_else(|| "This transaction is not a Faithful Archive item".to_string()),
+            Ok(None) => Err("Item not found".to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }));
+
+    // Read the records before the fetch so a refresh re-runs it
+    let chain_id = tx_id.clone();
+    let chain = use_async_resource(use_reactive!(|chain_id| {
+        let records = revision_records();
+        async move { version_chain(&records, &chain_id).await }
+    }));
+    let versions = chain.ready().unwrap_or_default();
+    let newest_version = versions.last().map(|version| version.tx_id.clone()).filter(|newest| *newest != tx_id);
+
+    if hidden_ids.read().contains(&tx_id) {
+        return rsx! {
+            main {
+                class: "max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+                div {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-gray-600",
+                    "This item has been removed from Faithful Archive by the moderation team."
+                }
+            }
+        };
+    }
+
+    rsx! {
+        main {
+            class: "max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+
+            match metadata.state() {
+                AsyncState::Loading => rsx! { DetailSkeleton {} },
+                AsyncState::Failed(message) => rsx! {
+                    div {
+                        class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                        "{message}"
+                    }
+                },
+                AsyncState::Ready(item) => rsx! {
+                    ShareHead { meta: ShareMeta::for_item(&item) }
+                    RevisionNotice { tx_id: item.tx_id.clone(), newest_version: newest_version.clone() }
+                    div {
+                        class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 space-y-4",
+
+                        div {
+                            class: "flex items-start justify-between gap-4",
+                            h2 {
+                                class: "text-2xl font-bold text-gray-900",
+                                "{item.title}"
+                            }
+                            div {
+                                class: "flex items-center gap-2",
+                                BookmarkButtons { item: item.clone() }
+                                ConfirmationBadge { tx_id: item.tx_id.clone() }
+                                if item.encryption.is_none() {
+                                    VerificationBadge { tx_id: item.tx_id.clone() }
+                                }
+                            }
+                        }
+                        div {
+                            class: "flex flex-wrap items-center justify-between gap-3",
+                            ProfileHeader { address: item.owner.clone() }
+                            if tipping_enabled() {
+                                SupportButton { uploader: item.owner.clone(), content_id: item.tx_id.clone() }
+                            }
+                        }
+
+                        if let Some(cover_tx_id) = item.cover_tx_id.as_ref().filter(|_| !settings.read().data_saver) {
+                            img {
+                                class: "w-full max-h-96 object-cover rounded-lg",
+                                src: GatewayManager::new().media_url(cover_tx_id),
+                                alt: "{item.title}",
+                            }
+                        }
+                        if let Some(speaker) = item.speaker.as_ref() {
+                            div {
+                                class: "flex items-center gap-3",
+                                p { class: "text-gray-700", "{speaker}" }
+                                FollowButton { target: FollowTarget::Speaker(speaker.clone()) }
+                            }
+                        }
+                        if let Some(series_id) = item.series_id.as_ref() {
+                            div {
+                                class: "flex items-center gap-3",
+                                Link {
+                                    class: "text-sm text-green-700 hover:text-green-800 underline",
+                                    to: Route::SeriesDetail { series_id: series_id.clone() },
+                                    "Part of the series {item.series.as_deref().unwrap_or(\"this series\")}"
+                                }
+                                FollowButton { target: FollowTarget::Series(series_id.clone()) }
+                            }
+                        }
+                        if !item.scripture_refs.is_empty() {
+                            p { class: "text-sm text-green-700", "{item.scripture_refs.join(\", \")}" }
+                        }
+                        if let Some(facts) = Some(length_and_size(&item)).filter(|facts| !facts.is_empty()) {
+                            p { class: "text-sm text-gray-500", "{facts}" }
+                        }
+                        if let Some(description) = item.description.as_ref() {
+                            p { class: "text-gray-600 whitespace-pre-line", "{description}" }
+                        }
+                        if let Some(license) = item.license.as_ref() {
+                            div {
+                                class: "text-sm bg-gray-50 border border-gray-200 rounded-lg p-3",
+                                a {
+                                    class: "font-medium text-green-700 hover:text-green-800 underline",
+                                    href: GatewayManager::new().media_url(UDL_LICENSE_TX),
+                                    target: "_blank",
+                                    "Universal Data License"
+                                }
+                                ul {
+                                    class: "mt-1 text-gray-600 list-disc list-inside",
+                                    for term in license.summary() {
+                                        li { "{term}" }
+                                    }
+                                }
+                            }
+                        }
+                        if let Some(rights) = item.rights.as_ref() {
+                            div {
+                                class: "text-sm bg-gray-50 border border-gray-200 rounded-lg p-3",
+                                span { class: "font-medium text-gray-700", "Rights declared by the uploader" }
+                                ul {
+                                    class: "mt-1 text-gray-600 list-disc list-inside",
+                                    for line in rights.summary() {
+                                        li { "{line}" }
+                                    }
+                                }
+                            }
+                        }
+
+                        if item.encryption.is_some() {
+                            EncryptedContent { item: item.clone() }
+                        } else if item.content_type.starts_with("audio/") || item.content_type.starts_with("video/") {
+                            div {
+                                class: "space-y-2",
+                                MediaPlayer {
+                                    item: item.clone(),
+                                    autoplay: settings.read().autoplay,
+                                    on_time: move |secs| current_time.set(secs),
+                                }
+                                ResumeButton { tx_id: item.tx_id.clone() }
+                                if let Some(transcript_tx_id) = item.transcript_tx_id.as_ref() {
+                                    TranscriptView {
+                                        media_tx_id: item.tx_id.clone(),
+                                        transcript_tx_id: transcript_tx_id.clone(),
+                                        current_time: *current_time.read(),
+                                    }
+                                }
+                            }
+                        }
+
+                        ReactionBar { tx_id: item.tx_id.clone() }
+
+                        div {
+                            class: "text-xs text-gray-500 font-mono",
+                            title: "{item.tx_id}",
+                            "Transaction {WalletService::format_address(&item.tx_id)}"
+                        }
+
+                        div {
+                            class: "flex flex-wrap gap-3 pt-4 border-t border-gray-100",
+                            if item.encryption.is_none() {
+                                a {
+                                    class: "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                                    href: GatewayManager::new().media_url(&item.tx_id),
+                                    target: "_blank",
+                                    "Open"
+                                }
+                                DownloadButton { item: item.clone() }
+                            }
+                            if let Some(cid) = item.ipfs_cid.as_ref() {
+                                a {
+                                    class: "border border-green-600 text-green-700 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                                    href: mirror_url(cid),
+                                    target: "_blank",
+                                    rel: "noopener",
+                                    title: "The same file on IPFS ({cid})",
+                                    "IPFS mirror"
+                                }
+                            }
+                            Link {
+                                class: "border border-green-600 text-green-700 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                                to: Route::StudyWorkspace { tx_id: item.tx_id.clone() },
+                                "Study"
+                            }
+                            AddToCollectionButton { tx_id: item.tx_id.clone() }
+                            ShareButton { meta: ShareMeta::for_item(&item) }
+                            ShareQrButton { tx_id: item.tx_id.clone(), title: item.title.clone() }
+                            if item.encryption.is_none() && (item.content_type.starts_with("audio/") || item.content_type.starts_with("video/")) {
+                                EmbedCodeButton { tx_id: item.tx_id.clone(), title: item.title.clone() }
+                            }
+                            ExportEvidenceButton { tx_id: item.tx_id.clone() }
+                        }
+                    }
+
+                    VersionHistory { tx_id: item.tx_id.clone(), versions: versions.clone() }
+                    CitationPanel { item: item.clone() }
+                    RevisionPanel { tx_id: item.tx_id.clone(), owner: item.owner.clone() }
+                    if comments_enabled() {
+                        CommentThread { tx_id: item.tx_id.clone() }
+                    }
+                    ReportPanel { tx_id: item.tx_id.clone() }
+                },
+            }
+        }
+    }
+}
+
+/// Offers to continue from where the item was last left off
+#[component]
+fn ResumeButton(tx_id: String) -> Element {
+    let saved = use_playback_position(tx_id.clone());
+    let Some(position) = saved.read().clone().filter(|p| p.is_resumable()) else {
+        return rsx! {};
+    };
+
+    rsx! {
+        button {
+            class: "text-sm text-green-700 hover:text-green-800 font-medium",
+            onclick: move |_| seek_player(&tx_id, position.position_secs),
+            "▶ Resume at {format_position(position.position_secs)}"
+        }
+    }
+}
+
+/// Points readers at a newer version, or says the uploader withdrew it
+#[component]
+fn RevisionNotice(tx_id: String, newest_version: Option<String>) -> Element {
+    let wallet_state = use_wallet_state();
+    let mut is_removing = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let can_moderate = wallet_state.read().address().is_some_and(is_moderator);
+
+    if let Some(newest) = newest_version {
+        return rsx! {
+            div {
+                class: "mb-4 text-sm text-blue-800 bg-blue-50 px-4 py-3 rounded-lg border border-blue-200 flex items-center justify-between gap-3",
+                "The uploader has published an updated version of this item."
+                Link {
+                    class: "font-medium underline shrink-0",
+                    to: Route::ContentDetail { tx_id: newest },
+                    "See the latest version"
+                }
+            }
+        };
+    }
+
+    let Some(revocation) = revocation_of(&tx_id) else {
+        return rsx! {};
+    };
+
+    let note = if revocation.reason.is_empty() {
+        "Withdrawn by the uploader".to_string()
+    } else {
+        format!("Withdrawn by the uploader: {}", revocation.reason)
+    };
+    let remove = move |_| {
+        let tx_id = tx_id.clone();
+        let note = note.clone();
+        spawn(async move {
+            is_removing.set(true);
+            error.set(None);
+            if let Err(e) = HideRegistry::new().publish(&tx_id, HideAction::Hide, &note, None, None).await {
+                error.set(Some(e.to_string()));
+            }
+            is_removing.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "mb-4 text-sm text-amber-900 bg-amber-50 px-4 py-3 rounded-lg border border-amber-300 space-y-2",
+            p {
+                "The uploader withdrew this item"
+                if !revocation.reason.is_empty() {
+                    ": {revocation.reason}"
+                }
+            }
+            if can_moderate {
+                div {
+                    class: "flex items-center gap-3",
+                    button {
+                        class: "bg-red-600 hover:bg-red-700 disabled:bg-gray-300 text-white px-3 py-1 rounded-lg text-sm font-medium transition-colors",
+                        disabled: *is_removing.read(),
+                        onclick: remove,
+                        if *is_removing.read() { "Removing..." } else { "Remove from archive" }
+                    }
+                    if let Some(message) = error.read().as_ref() {
+                        span { class: "text-red-700", "{message}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Earlier and later versions of an item, when it has any
+#[component]
+fn VersionHistory(tx_id: String, versions: Vec<ItemVersion>) -> Element {
+    if versions.len() < 2 {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "mt-6 bg-white rounded-xl shadow-sm border border-green-200 p-6",
+            h3 { class: "text-lg font-semibold text-gray-900 mb-3", "Version history" }
+            ol {
+                class: "relative border-l border-green-200 ml-2 space-y-4 text-sm",
+                for (index, version) in versions.iter().enumerate() {
+                    li {
+                        key: "{version.tx_id}",
+                        class: "ml-4",
+                        span {
+                            class: if version.tx_id == tx_id {
+                                "absolute -left-1.5 mt-1.5 w-3 h-3 rounded-full bg-green-600"
+                            } else {
+                                "absolute -left-1.5 mt-1.5 w-3 h-3 rounded-full bg-white border border-green-400"
+                            },
+                        }
+                        div {
+                            class: "flex flex-wrap items-baseline gap-x-3 text-gray-700",
+                            span { class: "text-gray-500", "Version {index + 1}" }
+                            if version.tx_id == tx_id {
+                                span { class: "font-medium text-gray-900", "{version_title(version)} (this version)" }
+                            } else {
+                                Link {
+                                    class: "text-green-700 hover:text-green-800 underline",
+                                    to: Route::ContentDetail { tx_id: version.tx_id.clone() },
+                                    "{version_title(version)}"
+                                }
+                            }
+                            span { class: "text-xs text-gray-500", "{format_version_date(version.timestamp)}" }
+                        }
+                        if let Some(record) = version.record.as_ref() {
+                            p {
+                                class: "text-xs text-gray-500 mt-0.5",
+                                "{revision_summary(record.action, record.timestamp)}"
+                                if !record.reason.is_empty() {
+                                    " — {record.reason}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn version_title(version: &ItemVersion) -> String {
+    version.title.clone().unwrap_or_else(|| WalletService::format_address(&version.tx_id))
+}
+
+fn format_version_date(timestamp: Option<i64>) -> String {
+    timestamp
+        .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0))
+        .map(|date| date.format("%b %-d, %Y").to_string())
+        .unwrap_or_else(|| "pending".to_string())
+}
+
+fn revision_summary(action: RevisionAction, timestamp: Option<i64>) -> String {
+    let when = format_version_date(timestamp);
+    match action {
+        RevisionAction::Replace => format!("Replaced {}", when),
+        RevisionAction::Revoke => format!("Withdrawn {}", when),
+    }
+}
+
+/// Lets the uploader point an item at a newer version or withdraw it
+///
+/// Both publish a signed revision record; the original stays on Arweave.
+#[component]
+fn RevisionPanel(tx_id: String, owner: String) -> Element {
+    let wallet_state = use_wallet_state();
+    let mut is_open = use_signal(|| false);
+    let mut action = use_signal(|| RevisionAction::Replace);
+    let mut replacement = use_signal(String::new);
+    let mut reason = use_signal(String::new);
+    let mut is_sending = use_signal(|| false);
+    let mut status = use_signal(|| None::<Result<String, String>>);
+
+    if wallet_state.read().address() != Some(owner.as_str()) {
+        return rsx! {};
+    }
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        let tx_id = tx_id.clone();
+        spawn(async move {
+            is_sending.set(true);
+            let selected = *action.read();
+            let registry = RevisionRegistry::new();
+            let result = match selected {
+                RevisionAction::Replace => registry.replace(&tx_id, &replacement.read(), reason.read().trim()).await,
+                RevisionAction::Revoke => registry.revoke(&tx_id, reason.read().trim()).await,
+            };
+            status.set(Some(result.map(|record| record.record_tx_id).map_err(|e| e.to_string())));
+            is_sending.set(false);
+        });
+    };
+
+    if !*is_open.read() {
+        return rsx! {
+            button {
+                class: "mt-4 mr-4 text-xs text-gray-500 hover:text-green-700",
+                onclick: move |_| is_open.set(true),
+                "Replace or withdraw this item"
+            }
+        };
+    }
+
+    rsx! {
+        div {
+            class: "mt-6 bg-white rounded-xl shadow-sm border border-green-200 p-6",
+            h3 { class: "text-lg font-semibold text-gray-900 mb-1", "Replace or withdraw" }
+            p {
+                class: "text-sm text-gray-500 mb-3",
+                "Arweave keeps every upload, so this publishes a signed record instead. Readers are sent to the new version, or told you withdrew the item; the moderation team decides whether a withdrawn item leaves the archive."
+            }
+
+            match status.read().as_ref() {
+                Some(Ok(record_tx_id)) => rsx! {
+                    p { class: "text-sm text-green-700", "Record {WalletService::format_address(record_tx_id)} published." }
+                },
+                _ => rsx! {
+                    form {
+                        class: "space-y-3",
+                        onsubmit: submit,
+                        div {
+                            class: "flex gap-4 text-sm",
+                            for (option, label) in [(RevisionAction::Replace, "Replace with a newer upload"), (RevisionAction::Revoke, "Withdraw")] {
+                                label {
+                                    key: "{label}",
+                                    class: "flex items-center gap-2 text-gray-700",
+                                    input {
+                                        r#type: "radio",
+                                        name: "revision-action",
+                                        checked: *action.read() == option,
+                                        onchange: move |_| action.set(option),
+                                    }
+                                    "{label}"
+                                }
+                            }
+                        }
+                        if *action.read() == RevisionAction::Replace {
+                            input {
+                                class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm font-mono",
+                                aria_label: "Transaction ID of the new version",
+                                placeholder: "Transaction ID of the new version",
+                                value: "{replacement}",
+                                oninput: move |evt| replacement.set(evt.value()),
+                            }
+                        }
+                        textarea {
+                            class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm",
+                            rows: "2",
+                            aria_label: "Reason",
+                            placeholder: "Reason (optional, published publicly)",
+                            value: "{reason}",
+                            oninput: move |evt| reason.set(evt.value()),
+                        }
+                        if let Some(Err(message)) = status.read().as_ref() {
+                            p { class: "text-sm text-red-700", "{message}" }
+                        }
+                        button {
+                            class: "bg-green-600 hover:bg-green-700 disabled:bg-gray-300 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                            r#type: "submit",
+                            disabled: *is_sending.read() || (*action.read() == RevisionAction::Replace && replacement.read().trim().is_empty()),
+                            if *is_sending.read() { "Publishing..." } else { "Sign and publish" }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Lets viewers flag an item for the moderation team
+///
+/// Viewers without a wallet can report with their anonymous identity;
+/// those reports are labeled as unverified.
+#[component]
+fn ReportPanel(tx_id: String) -> Element {
+    let identity = use_local_identity();
+    let mut is_open = use_signal(|| false);
+    let mut reason = use_signal(|| ReportReason::Other);
+    let mut note = use_signal(String::new);
+    let mut is_sending = use_signal(|| false);
+    let mut status = use_signal(|| None::<Result<Signer, String>>);
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        let tx_id = tx_id.clone();
+        spawn(async move {
+            is_sending.set(true);
+            let selected = *reason.read();
+            let result = submit_report(&tx_id, selected, &note.read())
+                .await
+                .map(|(signer, _)| signer)
+                .map_err(|e| e.to_string());
+            status.set(Some(result));
+            is_sending.set(false);
+        });
+    };
+
+    if !*is_open.read() {
+        return rsx! {
+            button {
+                class: "mt-4 text-xs text-gray-400 hover:text-red-600",
+                onclick: move |_| is_open.set(true),
+                "Report this item"
+            }
+        };
+    }
+
+    rsx! {
+        div {
+            class: "mt-6 bg-white rounded-xl shadow-sm border border-green-200 p-6",
+            h3 { class: "text-lg font-semibold text-gray-900 mb-3", "Report this item" }
+
+            match status.read().as_ref() {
+                Some(Ok(signer)) => rsx! {
+                    p {
+                        class: "text-sm text-green-700",
+                        if signer.verified {
+                            "Thank you. Your report was signed by {WalletService::format_address(&signer.address)}."
+                        } else {
+                            "Thank you. Your report was signed by anonymous identity {WalletService::format_address(&signer.address)} and is marked unverified."
+                        }
+                    }
+                },
+                _ => rsx! {
+                    form {
+                        class: "space-y-3",
+                        onsubmit: submit,
+                        select {
+                            class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm",
+                            aria_label: "Report reason",
+                            onchange: move |evt| {
+                                if let Some(selected) = ReportReason::ALL.into_iter().find(|r| r.tag_value() == evt.value()) {
+                                    reason.set(selected);
+                                }
+                            },
+                            for option_reason in ReportReason::ALL {
+                                option {
+                                    value: option_reason.tag_value(),
+                                    selected: option_reason == *reason.read(),
+                                    "{option_reason.display_name()}"
+                                }
+                            }
+                        }
+                        textarea {
+                            class: "w-full px-3 py-2 border border-gray-300 rounded-md text-sm",
+                            rows: "2",
+                            aria_label: "Report details",
+                            placeholder: "Details (optional, published publicly)",
+                            value: "{note}",
+                            oninput: move |evt| note.set(evt.value()),
+                        }
+                        if identity.read().is_some() {
+                            p { class: "text-xs text-gray-500", "Without a connected wallet, this report is signed by your anonymous identity and shown as unverified." }
+                        }
+                        if let Some(Err(message)) = status.read().as_ref() {
+                            p { class: "text-sm text-red-700", "{message}" }
+                        }
+                        button {
+                            class: "bg-red-600 hover:bg-red-700 disabled:bg-gray-300 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                            r#type: "submit",
+                            disabled: *is_sending.read(),
+                            if *is_sending.read() { "Sending..." } else { "Send report" }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Downloads the item's evidence bundle for legal or academic citation
+#[component]
+fn ExportEvidenceButton(tx_id: String) -> Element {
+    let mut is_exporting = use_signal(|| false);
+    let mut status = use_signal(|| None::<String>);
+
+    let export = move |_| {
+        let tx_id = tx_id.clone();
+        spawn(async move {
+            is_exporting.set(true);
+            status.set(None);
+
+            match EvidenceService::new().build_bundle(&tx_id).await {
+                Ok(bundle) => {
+                    if let Err(e) = download_bytes(&EvidenceService::bundle_filename(&tx_id), &bundle, "application/x-tar") {
+                        status.set(Some(e));
+                    }
+                }
+                Err(e) => status.set(Some(e.to_string())),
+            }
+
+            is_exporting.set(false);
+        });
+    };
+
+    rsx! {
+        button {
+            class: "border border-green-600 text-green-700 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+            disabled: *is_exporting.read(),
+            onclick: export,
+            if *is_exporting.read() { "Preparing bundle..." } else { "Export Evidence Bundle" }
+        }
+        if let Some(message) = status.read().as_ref() {
+            span { class: "text-sm text-red-700 self-center", "{message}" }
+        }
+    }
+}
+
+/// Formatted citation in a chosen style with a copy button
+#[component]
+fn CitationPanel(item: ContentMetadata) -> Element {
+    let mut style = use_signal(|| CitationStyle::Chicago);
+    let copy_feedback = use_copy_feedback();
+    let citation = style.read().format(&item);
+    let export_item = item.clone();
+
+    let copy_text = citation.clone();
+    let copy = move |_| copy_feedback.copy(copy_text.clone());
+
+    rsx! {
+        div {
+            class: "mt-6 bg-white rounded-xl shadow-sm border border-green-200 p-6",
+
+            div {
+                class: "flex items-center justify-between mb-3",
+                h3 { class: "text-lg font-semibold text-gray-900", "Cite this item" }
+                div {
+                    class: "flex gap-1",
+                    for option in CitationStyle::ALL {
+                        button {
+                            key: "{option.display_name()}",
+                            class: if *style.read() == option {
+                                "px-3 py-1 rounded text-xs font-medium bg-green-600 text-white"
+                            } else {
+                                "px-3 py-1 rounded text-xs font-medium text-gray-700 hover:bg-green-50"
+                            },
+                            onclick: move |_| style.set(option),
+                            "{option.display_name()}"
+                        }
+                    }
+                }
+            }
+
+            p {
+                class: "text-sm text-gray-800 bg-gray-50 rounded p-3 select-all break-words",
+                "{citation}"
+            }
+
+            div {
+                class: "flex items-center justify-between mt-3",
+                ReferenceExportButtons {
+                    label: "Reference manager:",
+                    on_export: move |format: ReferenceFormat| {
+                        if let Err(e) = format.download(std::slice::from_ref(&export_item)) {
+                            log::error!("Reference export failed: {}", e);
+                        }
+                    },
+                }
+                button {
+                    class: "text-sm text-green-700 hover:text-green-800 font-medium",
+                    onclick: copy,
+                    if copy_feedback.copied() { "Copied" } else { "Copy citation" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use log::Level;
+use crate::components::modal::Modal;
+use crate::services::flags::{flag_override, set_flag_override, use_flag, Flag};
+use crate::services::logging::{clear_logs, diagnostic_bundle, log_filter, recent_logs, set_log_filter, LogEntry, LogFilter};
+use crate::utils::display::use_copy_feedback;
+
+/// How often the open panel picks up new log entries (ms)
+const REFRESH_MS: u32 = 1000;
+
+fn level_class(level: Level) -> &'static str {
+    match level {
+        Level::Error => "text-red-700",
+        Level::Warn => "text-amber-700",
+        Level::Info => "text-gray-800",
+        Level::Debug | Level::Trace => "text-gray-500",
+    }
+}
+
+/// Recent log entries, the log filter and a "copy diagnostics" button for
+/// bug reports; opened with the "~" shortcut
+#[component]
+pub fn DebugPanel(open: Signal<bool>) -> Element {
+    let mut entries = use_signal(Vec::<LogEntry>::new);
+    let mut filter = use_signal(|| log_filter().to_string());
+    let copy = use_copy_feedback();
+
+    use_future(move || async move {
+        loop {
+            if open() {
+                entries.set(recent_logs());
+            }
+            TimeoutFuture::new(REFRESH_MS).await;
+        }
+    });
+
+    let apply_filter = move |evt: FormEvent| {
+        evt.prevent_default();
+        let parsed = LogFilter::parse(&filter.read());
+        filter.set(parsed.to_string());
+        set_log_filter(parsed);
+    };
+
+    rsx! {
+        Modal {
+            open,
+            title: "Debug log",
+            class: "bg-white rounded-2xl p-6 max-w-4xl w-full mx-4 relative shadow-2xl",
+            div {
+                class: "space-y-4",
+
+                form {
+                    class: "flex gap-2",
+                    onsubmit: apply_filter,
+                    input {
+                        class: "flex-1 px-3 py-2 border border-gray-300 rounded-md text-sm font-mono",
+                        "aria-label": "Log filter",
+                        placeholder: "info,faithful_archive::services::wallet=debug",
+                        value: "{filter}",
+                        oninput: move |evt| filter.set(evt.value()),
+                    }
+                    button {
+                        class: "bg-white hover:bg-green-50 text-green-700 border border-green-300 px-4 py-2 rounded-lg text-sm font-medium",
+                        r#type: "submit",
+                        "Apply"
+                    }
+                }
+
+                div {
+                    class: "h-80 overflow-y-auto rounded-lg border border-gray-200 bg-gray-50 p-3 font-mono text-xs",
+                    if entries.read().is_empty() {
+                        p { class: "text-gray-500", "Nothing logged yet." }
+                    }
+                    for (i, entry) in entries.read().iter().enumerate() {
+                        p {
+                            key: "{i}",
+                            class: "whitespace-pre-wrap break-all {level_class(entry.level)}",
+                            "{entry}"
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex items-center gap-2",
+                    button {
+                        class: "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        onclick: move |_| copy.copy(diagnostic_bundle()),
+                        if copy.copied() { "Copied" } else { "Copy diagnostics" }
+                    }
+                    button {
+                        class: "bg-white hover:bg-green-50 text-green-700 border border-green-300 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        onclick: move |_| {
+                            clear_logs();
+                            entries.set(Vec::new());
+                        },
+                        "Clear"
+                    }
+                    if let Some(error) = copy.error() {
+                        span { class: "text-sm text-red-700", "{error}" }
+                    }
+                }
+                FeatureFlags {}
+
+                p {
+                    class: "text-xs text-gray-500",
+                    "Diagnostics include your browser, network, gateway, wallet address and these log entries. Paste them into your report when asking for help with a wallet problem."
+                }
+            }
+        }
+    }
+}
+
+/// Local overrides of the runtime feature flags
+#[component]
+fn FeatureFlags() -> Element {
+    rsx! {
+        div {
+            class: "rounded-lg border border-gray-200 p-3 space-y-2",
+            h4 { class: "text-sm font-semibold text-gray-900", "Feature flags" }
+            for flag in Flag::ALL {
+                FlagRow { key: "{flag.key()}", flag }
+            }
+            p { class: "text-xs text-gray-500", "Overrides only apply in this browser." }
+        }
+    }
+}
+
+#[component]
+fn FlagRow(flag: Flag) -> Element {
+    let enabled = use_flag(flag);
+    let mut choice = use_signal(move || match flag_override(flag) {
+        Some(true) => "on",
+        Some(false) => "off",
+        None => "default",
+    });
+    let configured = if flag.configured() { "on" } else { "off" };
+    let status_class = if enabled() { "text-green-700" } else { "text-gray-500" };
+    let status = if enabled() { "On" } else { "Off" };
+
+    rsx! {
+        div {
+            class: "flex items-center justify-between gap-3 text-sm",
+            div {
+                class: "min-w-0",
+                p { class: "font-medium text-gray-900", "{flag.display_name()} " span { class: "{status_class}", "({status})" } }
+                p { class: "text-xs text-gray-500", "{flag.description()}" }
+            }
+            select {
+                class: "px-2 py-1 border border-gray-300 rounded-md text-sm",
+                "aria-label": "{flag.display_name()}",
+                value: "{choice}",
+                onchange: move |evt| {
+                    let (value, enabled) = match evt.value().as_str() {
+                        "on" => ("on", Some(true)),
+                        "off" => ("off", Some(false)),
+                        _ => ("default", None),
+                    };
+                    choice.set(value);
+                    set_flag_override(flag, enabled);
+                },
+                option { value: "default", "Default ({configured})" }
+                option { value: "on", "On" }
+                option { value: "off", "Off" }
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use dioxus::prelude::*;
+use crate::components::browse::BrowseCard;
+use crate::services::digest::DigestService;
+use crate::utils::display::use_copy_feedback;
+use crate::utils::download::download_bytes;
+
+/// This week's new items from follows and listening topics
+#[component]
+pub fn WeeklyDigest() -> Element {
+    // Re-assembled whenever the index, follows or bookmarks change
+    let digest = use_memo(|| DigestService::generate(chrono::Local::now().date_naive()));
+    let mut status = use_signal(|| None::<String>);
+
+    let download = move |_| {
+        let digest = digest.read();
+        let result = download_bytes(&digest.filename(), digest.to_markdown().as_bytes(), "text/markdown");
+        status.set(result.err());
+    };
+
+    let copy_feedback = use_copy_feedback();
+    let copy = move |_| copy_feedback.copy(digest.read().to_markdown());
+
+    let digest = digest.read();
+
+    rsx! {
+        main {
+            class: "max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                class: "flex items-center justify-between",
+                h2 { class: "text-2xl font-bold text-gray-900", "{digest.title()}" }
+                div {
+                    class: "flex gap-2",
+                    button {
+                        class: "border border-green-600 text-green-700 hover:bg-green-50 px-3 py-1 rounded-lg text-sm font-medium transition-colors",
+                        onclick: copy,
+                        if copy_feedback.copied() { "Copied" } else { "Copy" }
+                    }
+                    button {
+                        class: "border border-green-600 text-green-700 hover:bg-green-50 px-3 py-1 rounded-lg text-sm font-medium transition-colors",
+                        onclick: download,
+                        "Download"
+                    }
+                }
+            }
+
+            if let Some(message) = status.read().as_ref().cloned().or(copy_feedback.error()) {
+                p { class: "text-sm text-gray-600", "{message}" }
+            }
+
+            if digest.is_empty() {
+                div {
+                    class: "text-center text-sm text-gray-500 py-8",
+                    "Nothing new this week. Follow speakers and series, or save items to your Library, to fill your digest."
+                }
+            }
+
+            if !digest.followed.is_empty() {
+                section {
+                    class: "space-y-3",
+                    h3 { class: "text-lg font-semibold text-gray-900", "From people and series you follow" }
+                    for item in digest.followed.iter() {
+                        BrowseCard { key: "{item.tx_id}", item: item.clone() }
+                    }
+                }
+            }
+
+            for topic in digest.trending.iter() {
+                section {
+                    key: "{topic.book}",
+                    class: "space-y-3",
+                    h3 { class: "text-lg font-semibold text-gray-900", "Trending in {topic.book}" }
+                    for item in topic.items.iter() {
+                        BrowseCard { key: "{item.tx_id}", item: item.clone() }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use dioxus::prelude::*;
+use crate::models::ContentMetadata;
+use crate::services::content_fetch::use_download_progress;
+use crate::services::downloads::{
+    clear_finished_downloads, queue_download, remove_download, retry_download, use_downloads, DownloadEntry, DownloadStatus,
+    Integrity,
+};
+
+/// Queues an item's file for a verified download
+#[component]
+pub fn DownloadButton(item: ContentMetadata) -> Element {
+    let downloads = use_downloads();
+    let status = downloads.iter().find(|entry| entry.tx_id == item.tx_id).map(|entry| entry.status.clone());
+    let busy = status.as_ref().is_some_and(|status| !status.is_finished());
+
+    rsx! {
+        button {
+            class: "border border-green-600 text-green-700 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors disabled:opacity-50",
+            title: "Download and check the file against its on-chain record",
+            disabled: busy,
+            onclick: move |_| queue_download(&item),
+            if busy { "Downloading…" } else { "Download" }
+        }
+    }
+}
+
+/// Queued, running and finished downloads; rendered once by the layout
+/// and hidden while the queue is empty
+#[component]
+pub fn DownloadsPanel() -> Element {
+    let downloads = use_downloads();
+    let mut collapsed = use_signal(|| false);
+
+    if downloads.is_empty() {
+        return rsx! {};
+    }
+    let active = downloads.iter().filter(|entry| !entry.status.is_finished()).count();
+
+    rsx! {
+        section {
+            class: "fixed bottom-4 left-4 z-40 w-80 rounded-xl shadow-lg border border-gray-200 dark:border-gray-700 bg-white dark:bg-gray-900 text-sm",
+            aria_label: "Downloads",
+            div {
+                class: "flex items-center justify-between px-4 py-2 border-b border-gray-100 dark:border-gray-800",
+                button {
+                    class: "font-semibold text-gray-900 dark:text-white",
+                    onclick: move |_| collapsed.toggle(),
+                    if active > 0 { "Downloads ({active})" } else { "Downloads" }
+                    if collapsed() { " ▸" } else { " ▾" }
+                }
+                if active < downloads.len() {
+                    button {
+                        class: "text-xs text-gray-500 hover:text-gray-700",
+                        onclick: move |_| clear_finished_downloads(),
+                        "Clear finished"
+                    }
+                }
+            }
+            if !collapsed() {
+                ul {
+                    class: "max-h-72 overflow-y-auto divide-y divide-gray-100 dark:divide-gray-800",
+                    for entry in downloads {
+                        DownloadRow { key: "{entry.tx_id}", entry }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DownloadRow(entry: DownloadEntry) -> Element {
+    let progress = use_download_progress(entry.tx_id.clone());
+    let percent = progress().and_then(|progress| progress.fraction()).map(|fraction| (fraction * 100.0).round() as u32);
+
+    let (message, message_class) = match &entry.status {
+        DownloadStatus::Queued => ("Waiting…".to_string(), "text-gray-500"),
+        DownloadStatus::Downloading => match percent {
+            Some(percent) => (format!("Downloading… {}%", percent), "text-gray-500"),
+            None => ("Downloading…".to_string(), "text-gray-500"),
+        },
+        DownloadStatus::Verifying => ("Verifying…".to_string(), "text-gray-500"),
+        DownloadStatus::Saved(Integrity::Unchecked) => (Integrity::Unchecked.label().to_string(), "text-amber-700"),
+        DownloadStatus::Saved(integrity) => (format!("✓ {}", integrity.label()), "text-green-700"),
+        DownloadStatus::Failed(error) => (error.clone(), "text-red-700"),
+    };
+
+    rsx! {
+        li {
+            class: "px-4 py-2 space-y-1",
+            div {
+                class: "flex items-center justify-between gap-2",
+                p { class: "font-medium text-gray-900 dark:text-white truncate", title: "{entry.filename}", "{entry.title}" }
+                div {
+                    class: "flex-shrink-0 flex items-center gap-2 text-xs",
+                    if matches!(entry.status, DownloadStatus::Failed(_)) {
+                        button {
+                            class: "text-green-700 hover:text-green-800 font-medium",
+                            onclick: {
+                                let tx_id = entry.tx_id.clone();
+                                move |_| retry_download(&tx_id)
+                            },
+                            "Retry"
+                        }
+                    }
+                    button {
+                        class: "text-gray-400 hover:text-gray-600",
+                        aria_label: "Remove from downloads",
+                        onclick: {
+                            let tx_id = entry.tx_id.clone();
+                            move |_| remove_download(&tx_id)
+                        },
+                        "✕"
+                    }
+                }
+            }
+            if entry.status == DownloadStatus::Downloading {
+                div {
+                    class: "w-full bg-gray-200 rounded-full h-1",
+                    div { class: "bg-green-600 h-1 rounded-full", style: "width: {percent.unwrap_or(0)}%" }
+                }
+            }
+            p { class: "text-xs {message_class}", "{message}" }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use dioxus::prelude::*;
+use js_sys::{Object, Reflect};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use crate::components::media_player::MediaPlayer;
+use crate::services::async_state::{use_async_resource, AsyncState};
+use crate::services::graphql::GraphQLClient;
+use crate::services::hide_registry::use_hidden_ids;
+use crate::services::share::{share_url, EMBED_RESIZE_MESSAGE};
+
+/// Bare player for iframing an item into another website
+///
+/// Rendered outside the app layout, with just the player, the title and a
+/// link back to the item's page. The frame posts its height to the parent
+/// page whenever it changes so the snippet from `embed_code` can size it.
+#[component]
+pub fn EmbedPlayer(tx_id: String) -> Element {
+    let hidden_ids = use_hidden_ids();
+    use_hook(report_height_changes);
+
+    let lookup_id = tx_id.clone();
+    let metadata = use_async_resource(use_reactive!(|lookup_id| async move {
+        match GraphQLClient::cached().transaction_details(&lookup_id).await {
+            Ok(Some(details)) => details
+                .metadata()
+                .ok_or_else(|| "This transaction is not a Faithful Archive item".to_string()),
+            Ok(None) => Err("Item not found".to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }));
+
+    let link = share_url(&tx_id);
+    let notice = |message: String| {
+        rsx! {
+            div {
+                class: "p-4 text-sm text-gray-600 dark:text-gray-300",
+                "{message} "
+                a { class: "text-green-700 underline", href: "{link}", target: "_blank", rel: "noopener", "Open on Faithful Archive" }
+            }
+        }
+    };
+
+    if hidden_ids.read().contains(&tx_id) {
+        return notice("This item has been removed from Faithful Archive by the moderation team.".to_string());
+    }
+
+    match metadata.state() {
+        AsyncState::Loading => rsx! {
+            div { class: "h-24 m-3 rounded-lg bg-gray-100 dark:bg-gray-800 animate-pulse" }
+        },
+        AsyncState::Failed(message) => notice(message),
+        AsyncState::Ready(item) if item.encryption.is_some() || !(item.content_type.starts_with("audio/") || item.content_type.starts_with("video/")) => {
+            notice(format!("\u{201c}{}\u{201d} can't be played here.", item.title))
+        }
+        AsyncState::Ready(item) => rsx! {
+            div {
+                class: "p-3 space-y-2 bg-white dark:bg-gray-900",
+                MediaPlayer { item: item.clone() }
+                div {
+                    class: "flex items-center justify-between gap-3 text-sm",
+                    div {
+                        class: "min-w-0",
+                        p { class: "font-medium text-gray-900 dark:text-white truncate", title: "{item.title}", "{item.title}" }
+                        if let Some(speaker) = item.speaker.as_ref() {
+                            p { class: "text-xs text-gray-600 dark:text-gray-400 truncate", "{speaker}" }
+                        }
+                    }
+                    a {
+                        class: "flex-shrink-0 text-xs text-green-700 hover:text-green-800 font-medium",
+                        href: "{link}",
+                        target: "_blank",
+                        rel: "noopener",
+                        "✚ Faithful Archive ↗"
+                    }
+                }
+            }
+        },
+    }
+}
+
+// Tell the parent page the document's height
+fn post_height() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let Some(height) = window.document().and_then(|document| document.document_element()).map(|root| root.scroll_height()) else {
+        return;
+    };
+    // Outside an iframe the parent is the window itself, which ignores it
+    let Ok(Some(parent)) = window.parent() else {
+        return;
+    };
+
+    let message = Object::new();
+    let _ = Reflect::set(&message, &JsValue::from_str("type"), &JsValue::from_str(EMBED_RESIZE_MESSAGE));
+    let _ = Reflect::set(&message, &JsValue::from_str("height"), &JsValue::from(height));
+    let _ = parent.post_message(&message, "*");
+}
+
+// Post the height whenever the body resizes: on load, as metadata
+// arrives, and when a video's dimensions become known
+fn report_height_changes() {
+    let Some(body) = web_sys::window().and_then(|window| window.document()).and_then(|document| document.body()) else {
+        return;
+    };
+    let on_resize = Closure::<dyn FnMut()>::new(post_height);
+    match web_sys::ResizeObserver::new(on_resize.as_ref().unchecked_ref()) {
+        Ok(observer) => observer.observe(&body),
+        Err(_) => log::warn!("ResizeObserver unavailable; embed height won't be reported"),
+    }
+    // The embed page lives as long as its frame
+    on_resize.forget();
+}
@@ -0,0 +1,128 @@
+use dioxus::prelude::*;
+use crate::models::{ContentMetadata, KeySource};
+use crate::services::content_fetch::ContentFetchService;
+use crate::services::encryption::decrypt_content;
+
+/// Decrypted data, ready to display
+#[derive(Debug, Clone, PartialEq)]
+enum Decrypted {
+    Text(String),
+    /// Blob object URL of media or a file to download
+    Url(String),
+}
+
+/// Unlocks an encrypted item in the browser, with its passphrase or the
+/// uploader's wallet, and shows it
+#[component]
+pub fn EncryptedContent(item: ContentMetadata) -> Element {
+    let mut passphrase = use_signal(String::new);
+    let mut is_decrypting = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut decrypted = use_signal(|| None::<Decrypted>);
+
+    use_drop(move || {
+        if let Some(Decrypted::Url(url)) = decrypted.peek().as_ref() {
+            ContentFetchService::revoke_object_url(url);
+        }
+    });
+
+    let Some(info) = item.encryption.clone() else {
+        return rsx! {};
+    };
+
+    let tx_id = item.tx_id.clone();
+    let decrypt_info = info.clone();
+    let decrypt = move |evt: FormEvent| {
+        evt.prevent_default();
+        let tx_id = tx_id.clone();
+        let info = decrypt_info.clone();
+        spawn(async move {
+            is_decrypting.set(true);
+            error.set(None);
+            let result = async {
+                let content = ContentFetchService::new().fetch(&tx_id).await.map_err(|e| e.to_string())?;
+                let plaintext = decrypt_content(&content.bytes, &info, Some(passphrase.read().as_str()))
+                    .await
+                    .map_err(|e| e.to_string())?;
+                if info.content_type.starts_with("text/") {
+                    return Ok(Decrypted::Text(String::from_utf8_lossy(&plaintext).into_owned()));
+                }
+                ContentFetchService::blob_url(&plaintext, &info.content_type)
+                    .map(Decrypted::Url)
+                    .map_err(|e| e.to_string())
+            }
+            .await;
+            match result {
+                Ok(content) => decrypted.set(Some(content)),
+                Err(message) => error.set(Some(message)),
+            }
+            is_decrypting.set(false);
+        });
+    };
+
+    let content_type = info.content_type.clone();
+
+    rsx! {
+        div {
+            class: "border border-gray-200 rounded-lg p-4 space-y-3",
+
+            match decrypted.read().as_ref() {
+                Some(Decrypted::Text(text)) => rsx! {
+                    pre { class: "text-sm text-gray-800 whitespace-pre-wrap font-sans", "{text}" }
+                },
+                Some(Decrypted::Url(url)) if content_type.starts_with("audio/") => rsx! {
+                    audio { class: "w-full", src: "{url}", controls: true }
+                },
+                Some(Decrypted::Url(url)) if content_type.starts_with("video/") => rsx! {
+                    video { class: "w-full rounded", src: "{url}", controls: true }
+                },
+                Some(Decrypted::Url(url)) if content_type.starts_with("image/") => rsx! {
+                    img { class: "w-full rounded", src: "{url}", alt: "{item.title}" }
+                },
+                Some(Decrypted::Url(url)) => rsx! {
+                    a {
+                        class: "text-sm text-green-700 hover:text-green-800 font-medium underline",
+                        href: "{url}",
+                        download: "{item.title}",
+                        "Download decrypted file"
+                    }
+                },
+                None => rsx! {
+                    p { class: "text-sm text-gray-700", "🔒 This item is encrypted." }
+                    form {
+                        class: "flex flex-wrap items-center gap-2",
+                        onsubmit: decrypt,
+                        if info.key_source == KeySource::Passphrase {
+                            input {
+                                class: "flex-1 px-3 py-2 border border-gray-300 rounded-md text-sm",
+                                r#type: "password",
+                                placeholder: "Passphrase",
+                                value: "{passphrase}",
+                                oninput: move |evt| passphrase.set(evt.value()),
+                            }
+                        }
+                        button {
+                            class: "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                            r#type: "submit",
+                            disabled: *is_decrypting.read(),
+                            if *is_decrypting.read() {
+                                "Decrypting..."
+                            } else if info.key_source == KeySource::Wallet {
+                                "Decrypt with wallet"
+                            } else {
+                                "Decrypt"
+                            }
+                        }
+                    }
+                    if info.key_source == KeySource::Wallet {
+                        p { class: "text-xs text-gray-500", "Only the wallet that uploaded this item can decrypt it." }
+                    }
+                },
+            }
+
+            if let Some(message) = error.read().as_ref() {
+                p { class: "text-sm text-red-700", "{message}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::services::feed::{FeedService, FeedSource, PodcastFeed};
+use crate::services::wallet::use_wallet_state;
+use crate::utils::display::use_copy_feedback;
+use crate::utils::download::download_bytes;
+
+/// Podcast feed tools for an uploader or series
+///
+/// Copies the published feed address when `owner` has published one, and
+/// the feed itself otherwise, for pasting into a podcast host. The owner
+/// can publish (or refresh) a permanent copy on Arweave.
+#[component]
+pub fn PodcastFeedPanel(source: FeedSource, owner: String) -> Element {
+    let wallet_state = use_wallet_state();
+    let copy = use_copy_feedback();
+    let mut built = use_signal(|| None::<PodcastFeed>);
+    let mut published_url = use_signal(|| None::<String>);
+    let mut busy = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let lookup = (source.clone(), owner.clone());
+    use_effect(use_reactive!(|lookup| {
+        built.set(None);
+        published_url.set(None);
+        spawn(async move {
+            let (source, owner) = lookup;
+            match FeedService::new().latest_published(&source, &owner).await {
+                Ok(url) => published_url.set(url),
+                Err(e) => log::warn!("{}", e),
+            }
+        });
+    }));
+
+    let is_owner = wallet_state.read().address() == Some(owner.as_str());
+    let message = error.read().clone().or_else(|| copy.error());
+    let link = page_link(&source);
+
+    // Build the feed once and reuse it for copy, download and publish
+    let load_feed = {
+        let source = source.clone();
+        move || {
+            let source = source.clone();
+            let link = link.clone();
+            async move {
+                if let Some(feed) = built.read().clone() {
+                    return Ok(feed);
+                }
+                let feed = FeedService::new().build(&source, &link).await.map_err(|e| e.to_string())?;
+                built.set(Some(feed.clone()));
+                Ok::<PodcastFeed, String>(feed)
+            }
+        }
+    };
+
+    let copy_feed = {
+        let load_feed = load_feed.clone();
+        move |_| {
+            if let Some(url) = published_url.read().clone() {
+                copy.copy(url);
+                return;
+            }
+            let pending = load_feed();
+            busy.set(true);
+            spawn(async move {
+                match pending.await {
+                    Ok(feed) => copy.copy(feed.to_rss()),
+                    Err(e) => error.set(Some(e)),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let download = {
+        let load_feed = load_feed.clone();
+        move |_| {
+            let pending = load_feed();
+            busy.set(true);
+            spawn(async move {
+                let result = pending.await.and_then(|feed| download_bytes("feed.xml", feed.to_rss().as_bytes(), "application/rss+xml"));
+                if let Err(e) = result {
+                    error.set(Some(e));
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let publish = {
+        let source = source.clone();
+        move |_| {
+            let pending = load_feed();
+            let source = source.clone();
+            busy.set(true);
+            error.set(None);
+            spawn(async move {
+                let result = match pending.await {
+                    Ok(feed) => FeedService::new().publish(&source, &feed).await.map_err(|e| e.to_string()),
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(url) => published_url.set(Some(url)),
+                    Err(e) => error.set(Some(e)),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "space-y-2 text-sm",
+            div {
+                class: "flex flex-wrap items-center gap-2",
+                button {
+                    class: "px-3 py-1 rounded border border-green-600 text-green-700 hover:bg-green-50 text-xs font-medium transition-colors disabled:opacity-50",
+                    title: if published_url.read().is_some() { "Copy the feed address for your podcast app" } else { "Copy the RSS feed to paste into a podcast host" },
+                    disabled: *busy.read(),
+                    onclick: copy_feed,
+                    if copy.copied() { "Copied!" } else { "🎙️ Copy podcast feed" }
+                }
+                button {
+                    class: "px-3 py-1 rounded border border-gray-300 text-gray-700 hover:bg-gray-50 text-xs font-medium transition-colors disabled:opacity-50",
+                    disabled: *busy.read(),
+                    onclick: download,
+                    "Download feed.xml"
+                }
+                if is_owner {
+                    button {
+                        class: "px-3 py-1 rounded bg-green-600 hover:bg-green-700 text-white text-xs font-medium transition-colors disabled:opacity-50",
+                        disabled: *busy.read(),
+                        onclick: publish,
+                        if published_url.read().is_some() { "Republish feed to Arweave" } else { "Publish feed to Arweave" }
+                    }
+                }
+            }
+            if let Some(url) = published_url.read().as_ref() {
+                p {
+                    class: "text-xs text-gray-500 break-all",
+                    "Feed address: "
+                    a { class: "text-green-700 hover:text-green-800 underline", href: "{url}", target: "_blank", "{url}" }
+                    if is_owner {
+                        " · Published feeds are snapshots; republish after new uploads."
+                    }
+                }
+            }
+            if let Some(message) = message {
+                p { class: "text-xs text-red-700", "{message}" }
+            }
+        }
+    }
+}
+
+// Absolute URL of the app page a feed belongs to
+fn page_link(source: &FeedSource) -> String {
+    let route = match source {
+        FeedSource::Uploader(address) => Route::UploaderPage { address: address.clone() },
+        FeedSource::Series(series_id) => Route::SeriesDetail { series_id: series_id.clone() },
+    };
+    let origin = web_sys::window()
+        .and_then(|window| window.location().origin().ok())
+        .unwrap_or_default();
+    format!("{}{}", origin, route)
+}
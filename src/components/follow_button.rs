@@ -0,0 +1,22 @@
+use dioxus::prelude::*;
+use crate::services::follows::{toggle_follow, use_is_followed, FollowTarget};
+
+/// Follow toggle for a speaker, series, uploader or collection
+#[component]
+pub fn FollowButton(target: FollowTarget) -> Element {
+    let is_followed = use_is_followed(target.clone());
+
+    rsx! {
+        button {
+            class: if *is_followed.read() {
+                "px-3 py-1 rounded-full text-xs font-medium bg-green-600 text-white hover:bg-green-700"
+            } else {
+                "px-3 py-1 rounded-full text-xs font-medium border border-green-600 text-green-700 hover:bg-green-50"
+            },
+            onclick: move |_| {
+                toggle_follow(&target);
+            },
+            if *is_followed.read() { "Following" } else { "Follow" }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::browse::BrowseCard;
+use crate::components::skeleton::CardSkeleton;
+use crate::i18n::t;
+use crate::services::async_state::{use_async_resource, AsyncState};
+use crate::services::follows::{feed_last_seen, following_feed, is_new_since, mark_feed_seen, use_feed_new_count};
+use crate::services::settings::use_browse_start;
+
+/// "All" / "Following" switch above the Browse and Following pages
+#[component]
+pub fn FeedTabs(following: bool) -> Element {
+    let new_count = use_feed_new_count();
+    let browse_start = use_browse_start();
+
+    let tab_class = |active: bool| {
+        if active {
+            "px-4 py-2 rounded-lg text-sm font-medium bg-green-600 text-white"
+        } else {
+            "px-4 py-2 rounded-lg text-sm font-medium bg-white text-gray-700 border border-gray-300 hover:bg-green-50"
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex gap-2",
+            Link { class: tab_class(!following), to: Route::Browse { query: browse_start() }, {t!("feed.all")} }
+            Link {
+                class: tab_class(following),
+                to: Route::FollowingFeed {},
+                {t!("feed.following")}
+                if *new_count.read() > 0 {
+                    span {
+                        class: "ml-2 px-1.5 py-0.5 rounded-full text-xs bg-red-600 text-white",
+                        "{new_count}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// New uploads from followed uploaders, speakers and series, plus the items
+/// of followed collections
+#[component]
+pub fn FollowingFeed() -> Element {
+    // Read before the visit is recorded so this visit still shows what's new
+    let last_seen = use_hook(feed_last_seen);
+    let feed = use_async_resource(|| async {
+        let found = following_feed().await;
+        mark_feed_seen();
+        found
+    });
+
+    rsx! {
+        main {
+            class: "max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                class: "flex flex-wrap items-center justify-between gap-4",
+                h2 { class: "text-3xl font-bold text-gray-900", {t!("feed.following")} }
+                FeedTabs { following: true }
+            }
+
+            match feed.state() {
+                AsyncState::Loading => rsx! { CardSkeleton {} },
+                AsyncState::Failed(message) => rsx! {
+                    div {
+                        class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                        "{message}"
+                    }
+                },
+                AsyncState::Ready(items) if items.is_empty() => rsx! {
+                    div {
+                        class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-center text-gray-500",
+                        "Nothing here yet. Follow an uploader, speaker, series or collection to see their new uploads."
+                    }
+                },
+                AsyncState::Ready(items) => rsx! {
+                    for item in items {
+                        div {
+                            key: "{item.tx_id}",
+                            class: "relative",
+                            if is_new_since(&item, last_seen) {
+                                span {
+                                    class: "absolute -top-2 -left-2 z-10 px-2 py-0.5 rounded-full text-xs font-medium bg-green-600 text-white",
+                                    "New"
+                                }
+                            }
+                            BrowseCard { item: item.clone() }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,380 @@
+//! Shared building blocks for data-entry screens
+//!
+//! A `Field` holds one text input's value along with its validators and
+//! whether it has been touched or changed; `TextField` renders it with its
+//! label and inline error. A `Form` groups fields to validate them together,
+//! track the in-flight submit and show an `ErrorSummary` and `FormStatus`.
+//! Model-level checks, such as `UploaderProfile::validate`, still run on
+//! submit and report through `Form::fail`.
+
+use std::future::Future;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::utils::ipfs::Cid;
+use crate::utils::language::normalize_language_tag;
+
+/// Check of a field's value, returning a short message when it fails
+pub type Validator = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+const INPUT_CLASS: &str = "w-full px-3 py-2 border rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-green-500";
+
+/// Must not be blank
+pub fn required() -> Validator {
+    Rc::new(|value| if value.trim().is_empty() { Err("Required".to_string()) } else { Ok(()) })
+}
+
+pub fn max_length(max: usize) -> Validator {
+    Rc::new(move |value| {
+        if value.trim().chars().count() > max {
+            Err(format!("Must be {} characters or fewer", max))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// An http(s) address, if given
+pub fn web_address() -> Validator {
+    Rc::new(|value| {
+        let value = value.trim();
+        if value.is_empty() || value.starts_with("https://") || value.starts_with("http://") {
+            Ok(())
+        } else {
+            Err("Must start with https://".to_string())
+        }
+    })
+}
+
+/// Something shaped like an email address, if given
+pub fn email() -> Validator {
+    Rc::new(|value| {
+        let value = value.trim();
+        let valid = value
+            .split_once('@')
+            .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'));
+        if value.is_empty() || valid {
+            Ok(())
+        } else {
+            Err("Must be an email address".to_string())
+        }
+    })
+}
+
+/// A 43-character Arweave transaction ID, if given
+pub fn transaction_id() -> Validator {
+    Rc::new(|value| {
+        let value = value.trim();
+        let valid = value.len() == 43
+            && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if value.is_empty() || valid {
+            Ok(())
+        } else {
+            Err("Must be a 43-character transaction ID".to_string())
+        }
+    })
+}
+
+/// A 43-character Arweave wallet address, if given
+pub fn arweave_address() -> Validator {
+    Rc::new(|value| {
+        let value = value.trim();
+        let valid = value.len() == 43
+            && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if value.is_empty() || valid {
+            Ok(())
+        } else {
+            Err("Must be a 43-character Arweave address".to_string())
+        }
+    })
+}
+
+/// A BCP 47 language tag such as "en" or "pt-BR", if given
+pub fn language_tag() -> Validator {
+    Rc::new(|value| {
+        let value = value.trim();
+        if value.is_empty() || normalize_language_tag(value).is_some() {
+            Ok(())
+        } else {
+            Err("Must be a language code such as en or pt-BR".to_string())
+        }
+    })
+}
+
+/// An IPFS CID, if given
+pub fn ipfs_cid() -> Validator {
+    Rc::new(|value| {
+        let value = value.trim();
+        if value.is_empty() {
+            return Ok(());
+        }
+        value.parse::<Cid>().map(|_| ())
+    })
+}
+
+/// One text input's state
+#[derive(Clone, Copy, PartialEq)]
+pub struct Field {
+    label: &'static str,
+    value: Signal<String>,
+    // Value last loaded or saved, for dirty tracking
+    initial: Signal<String>,
+    touched: Signal<bool>,
+    validators: CopyValue<Vec<Validator>>,
+}
+
+impl Field {
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    pub fn value(&self) -> String {
+        self.value.read().clone()
+    }
+
+    pub fn trimmed(&self) -> String {
+        self.value.read().trim().to_string()
+    }
+
+    /// Trimmed value, or None when blank
+    pub fn optional(&self) -> Option<String> {
+        Some(self.trimmed()).filter(|value| !value.is_empty())
+    }
+
+    pub fn set(&mut self, value: String) {
+        self.value.set(value);
+    }
+
+    /// Replace the value with saved data without marking the field dirty
+    pub fn load(&mut self, value: String) {
+        self.initial.set(value.clone());
+        self.value.set(value);
+    }
+
+    /// Show errors from now on; called when the input loses focus
+    pub fn touch(&mut self) {
+        self.touched.set(true);
+    }
+
+    /// First failing validator's message, whether or not the field is touched
+    pub fn check(&self) -> Option<String> {
+        let value = self.value.read();
+        self.validators.read().iter().find_map(|validator| validator(&value).err())
+    }
+
+    /// Message to show beside the input
+    pub fn error(&self) -> Option<String> {
+        if *self.touched.read() { self.check() } else { None }
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        *self.value.read() != *self.initial.read()
+    }
+
+    fn mark_clean(&mut self) {
+        let value = self.value.peek().clone();
+        self.initial.set(value);
+    }
+}
+
+/// Hook creating an empty field
+pub fn use_field(label: &'static str, validators: Vec<Validator>) -> Field {
+    let value = use_signal(String::new);
+    let initial = use_signal(String::new);
+    let touched = use_signal(|| false);
+    let validators = use_hook(|| CopyValue::new(validators));
+    Field { label, value, initial, touched, validators }
+}
+
+/// Fields validated and submitted together
+#[derive(Clone, Copy, PartialEq)]
+pub struct Form {
+    fields: CopyValue<Vec<Field>>,
+    submitting: Signal<bool>,
+    // Set by the first submit, after which the error summary shows
+    attempted: Signal<bool>,
+    status: Signal<Option<Result<String, String>>>,
+}
+
+impl Form {
+    /// Failing fields, as (label, message)
+    pub fn errors(&self) -> Vec<(&'static str, String)> {
+        self.fields
+            .read()
+            .iter()
+            .filter_map(|field| field.check().map(|message| (field.label, message)))
+            .collect()
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.fields.read().iter().any(Field::is_dirty)
+    }
+
+    pub fn is_submitting(&self) -> bool {
+        *self.submitting.read()
+    }
+
+    /// Outcome of the last submit: a success message or what went wrong
+    pub fn status(&self) -> Option<Result<String, String>> {
+        self.status.read().clone()
+    }
+
+    /// Show every field's errors and report whether all of them pass
+    pub fn validate(&mut self) -> bool {
+        for mut field in self.fields.peek().iter().copied() {
+            field.touch();
+        }
+        self.attempted.set(true);
+        self.errors().is_empty()
+    }
+
+    /// Report a failure that isn't tied to one field
+    pub fn fail(&mut self, message: impl Into<String>) {
+        self.status.set(Some(Err(message.into())));
+    }
+
+    /// Report success; the current values become the saved state
+    pub fn succeed(&mut self, message: impl Into<String>) {
+        for mut field in self.fields.peek().iter().copied() {
+            field.mark_clean();
+        }
+        self.status.set(Some(Ok(message.into())));
+    }
+
+    /// Validate, then run `action` in the background while the form shows
+    /// as submitting
+    ///
+    /// `action` reports its outcome with `succeed` or `fail`.
+    pub fn submit(&mut self, action: impl Future<Output = ()> + 'static) {
+        if self.is_submitting() || !self.validate() {
+            return;
+        }
+        let mut submitting = self.submitting;
+        let mut status = self.status;
+        spawn(async move {
+            submitting.set(true);
+            status.set(None);
+            action.await;
+            submitting.set(false);
+        });
+    }
+}
+
+/// Hook grouping fields into a form
+pub fn use_form(fields: &[Field]) -> Form {
+    let fields = use_hook(|| CopyValue::new(fields.to_vec()));
+    let submitting = use_signal(|| false);
+    let attempted = use_signal(|| false);
+    let status = use_signal(|| None);
+    Form { fields, submitting, attempted, status }
+}
+
+/// Labelled text input, or a textarea when `rows` is set
+#[component]
+pub fn TextField(
+    field: Field,
+    #[props(default = "text")] input_type: &'static str,
+    #[props(default)] placeholder: String,
+    #[props(default)] rows: Option<u32>,
+    /// Extra classes for the input, e.g. "font-mono"
+    #[props(default)] class: String,
+) -> Element {
+    let mut field = field;
+    let id = use_hook(|| format!("field-{}", uuid::Uuid::new_v4().simple()));
+    let error_id = format!("{}-error", id);
+    let error = field.error();
+    let border = if error.is_some() { "border-red-400" } else { "border-gray-300" };
+    let input_class = format!("{} {} {}", INPUT_CLASS, border, class);
+    let described_by = error.as_ref().map(|_| error_id.clone());
+
+    rsx! {
+        div {
+            label { class: "block text-sm font-medium text-gray-700 mb-1", r#for: "{id}", "{field.label()}" }
+            if let Some(rows) = rows {
+                textarea {
+                    id: "{id}",
+                    class: "{input_class}",
+                    rows: "{rows}",
+                    placeholder: "{placeholder}",
+                    aria_invalid: error.is_some(),
+                    aria_describedby: described_by,
+                    value: "{field.value()}",
+                    oninput: move |evt| field.set(evt.value()),
+                    onblur: move |_| field.touch(),
+                }
+            } else {
+                input {
+                    id: "{id}",
+                    class: "{input_class}",
+                    r#type: input_type,
+                    placeholder: "{placeholder}",
+                    aria_invalid: error.is_some(),
+                    aria_describedby: described_by,
+                    value: "{field.value()}",
+                    oninput: move |evt| field.set(evt.value()),
+                    onblur: move |_| field.touch(),
+                }
+            }
+            if let Some(message) = error {
+                p { id: "{error_id}", class: "text-xs text-red-700 mt-1", "{message}" }
+            }
+        }
+    }
+}
+
+/// List of the fields still failing, shown once submit has been tried
+#[component]
+pub fn ErrorSummary(form: Form) -> Element {
+    let errors = form.errors();
+    if !*form.attempted.read() || errors.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+            role: "alert",
+            p { class: "font-medium", "Please fix the following:" }
+            ul {
+                class: "list-disc pl-5",
+                for (label, message) in errors {
+                    li { key: "{label}", "{label}: {message}" }
+                }
+            }
+        }
+    }
+}
+
+/// Outcome of the last submit
+#[component]
+pub fn FormStatus(form: Form) -> Element {
+    match form.status() {
+        Some(Ok(message)) if !message.is_empty() => rsx! {
+            p { class: "text-sm text-green-700", role: "status", "{message}" }
+        },
+        Some(Err(message)) => rsx! {
+            div {
+                class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                role: "alert",
+                "{message}"
+            }
+        },
+        _ => rsx! {},
+    }
+}
+
+/// Submit button that disables itself while the form is submitting
+#[component]
+pub fn SubmitButton(form: Form, label: &'static str, busy_label: &'static str) -> Element {
+    let busy = form.is_submitting();
+
+    rsx! {
+        button {
+            class: "bg-green-600 hover:bg-green-700 disabled:bg-gray-300 disabled:text-gray-600 disabled:cursor-not-allowed text-white px-6 py-2 rounded-lg font-medium transition-colors",
+            r#type: "submit",
+            disabled: busy,
+            if busy { "{busy_label}" } else { "{label}" }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::browse::BrowseCard;
+use crate::components::skeleton::CardSkeleton;
+use crate::models::{ContentMetadata, SeriesManifest};
+use crate::services::async_state::{use_async_resource, AsyncState};
+use crate::services::hide_registry::is_moderator;
+use crate::services::home_feed::{
+    featured_series_ids, load_featured_series, load_recent_items, load_trending_items, set_featured,
+};
+use crate::services::wallet::use_wallet_state;
+
+/// Recently approved, featured and trending sections of the home page
+#[component]
+pub fn HomeFeed() -> Element {
+    let recent = use_async_resource(load_recent_items);
+    let featured = use_async_resource(load_featured_series);
+    let trending = use_async_resource(load_trending_items);
+
+    rsx! {
+        div {
+            class: "space-y-12",
+
+            match recent.state() {
+                AsyncState::Loading => rsx! { HomeSection { title: "Recently approved", CardSkeleton {} } },
+                AsyncState::Failed(message) => rsx! { HomeSection { title: "Recently approved", SectionError { message } } },
+                AsyncState::Ready(recent) => {
+                    let title = if recent.approved { "Recently approved" } else { "New in the archive" };
+                    rsx! {
+                        HomeSection {
+                            title,
+                            ItemGrid { items: recent.items, empty: "Nothing has been archived yet." }
+                        }
+                    }
+                }
+            }
+
+            match featured.state() {
+                AsyncState::Loading => rsx! { HomeSection { title: "Featured series", CardSkeleton { count: 2 } } },
+                AsyncState::Failed(message) => rsx! { HomeSection { title: "Featured series", SectionError { message } } },
+                // The section stays hidden until the team features something
+                AsyncState::Ready(series) if series.is_empty() => rsx! {},
+                AsyncState::Ready(series) => rsx! {
+                    HomeSection {
+                        title: "Featured series",
+                        div {
+                            class: "grid md:grid-cols-2 lg:grid-cols-3 gap-4",
+                            for manifest in series {
+                                SeriesCard { key: "{manifest.series_id}", manifest }
+                            }
+                        }
+                    }
+                },
+            }
+
+            match trending.state() {
+                AsyncState::Loading => rsx! { HomeSection { title: "Trending this week", CardSkeleton {} } },
+                AsyncState::Failed(message) => rsx! { HomeSection { title: "Trending this week", SectionError { message } } },
+                AsyncState::Ready(items) => rsx! {
+                    HomeSection {
+                        title: "Trending this week",
+                        ItemGrid { items, empty: "No reactions this week yet. Say Amen to something you found encouraging." }
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn HomeSection(title: &'static str, children: Element) -> Element {
+    rsx! {
+        section {
+            h3 { class: "text-2xl font-bold text-gray-900 mb-4", "{title}" }
+            {children}
+        }
+    }
+}
+
+#[component]
+fn SectionError(message: String) -> Element {
+    rsx! {
+        div {
+            class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+            "{message}"
+        }
+    }
+}
+
+#[component]
+fn ItemGrid(items: Vec<ContentMetadata>, empty: &'static str) -> Element {
+    rsx! {
+        if items.is_empty() {
+            p { class: "text-sm text-gray-500", "{empty}" }
+        }
+        div {
+            class: "grid md:grid-cols-2 lg:grid-cols-3 gap-4",
+            for item in items {
+                BrowseCard { key: "{item.tx_id}", item }
+            }
+        }
+    }
+}
+
+#[component]
+fn SeriesCard(manifest: SeriesManifest) -> Element {
+    let count = manifest.members.len();
+
+    rsx! {
+        Link {
+            class: "block bg-white rounded-xl shadow-sm border border-green-200 p-5 hover:shadow-md transition-shadow",
+            to: Route::SeriesDetail { series_id: manifest.series_id.clone() },
+            div { class: "font-semibold text-gray-900", "{manifest.title}" }
+            if let Some(description) = manifest.description.as_ref() {
+                p { class: "text-sm text-gray-600 mt-1 line-clamp-2", "{description}" }
+            }
+            div { class: "text-xs text-green-700 mt-2", if count == 1 { "1 item" } else { "{count} items" } }
+        }
+    }
+}
+
+/// Lets moderators add a series to the home page or take it off
+#[component]
+pub fn FeatureSeriesButton(series_id: String) -> Element {
+    let wallet_state = use_wallet_state();
+    let mut featured_ids = use_async_resource(featured_series_ids);
+    let mut busy = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    if !wallet_state.read().address().is_some_and(is_moderator) {
+        return rsx! {};
+    }
+    let Some(ids) = featured_ids.ready() else {
+        return rsx! {};
+    };
+    let featured = ids.contains(&series_id);
+
+    let toggle = move |_| {
+        let series_id = series_id.clone();
+        busy.set(true);
+        error.set(None);
+        spawn(async move {
+            match set_featured(&series_id, !featured).await {
+                Ok(()) => featured_ids.restart(),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            busy.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2",
+            button {
+                class: "border border-green-600 text-green-700 hover:bg-green-50 px-3 py-1 rounded-lg text-sm font-medium disabled:opacity-50",
+                disabled: *busy.read(),
+                onclick: toggle,
+                if *busy.read() { "Publishing..." } else if featured { "Remove from home page" } else { "Feature on home page" }
+            }
+            if let Some(message) = error() {
+                span { class: "text-sm text-red-700", "{message}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::browse::BrowseCard;
+use crate::services::bookmarks::{use_bookmarks, BookmarkKind, BookmarkService};
+
+/// Items saved in this browser as favorites or to watch later
+#[component]
+pub fn Library() -> Element {
+    let bookmarks = use_bookmarks();
+    let mut tab = use_signal(|| BookmarkKind::Favorite);
+    let mut is_syncing = use_signal(|| false);
+    let mut status = use_signal(|| None::<Result<String, String>>);
+
+    let backup = move |_| {
+        spawn(async move {
+            is_syncing.set(true);
+            let result = BookmarkService::backup()
+                .await
+                .map(|tx_id| format!("Backed up to Arweave ({})", tx_id))
+                .map_err(|e| e.to_string());
+            status.set(Some(result));
+            is_syncing.set(false);
+        });
+    };
+
+    let restore = move |_| {
+        spawn(async move {
+            is_syncing.set(true);
+            let result = BookmarkService::restore()
+                .await
+                .map(|added| format!("Restored {} saved items", added))
+                .map_err(|e| e.to_string());
+            status.set(Some(result));
+            is_syncing.set(false);
+        });
+    };
+
+    let selected = *tab.read();
+    let items: Vec<_> = bookmarks
+        .read()
+        .iter()
+        .filter(|bookmark| bookmark.kind == selected)
+        .map(|bookmark| bookmark.item.clone())
+        .collect();
+
+    rsx! {
+        main {
+            class: "max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                class: "flex items-center justify-between",
+                div {
+                    h2 { class: "text-2xl font-bold text-gray-900", "Library" }
+                    Link {
+                        class: "text-sm text-green-700 hover:text-green-800 font-medium",
+                        to: Route::WeeklyDigest {},
+                        "This week's digest"
+                    }
+                }
+                div {
+                    class: "flex gap-2",
+                    for kind in [BookmarkKind::Favorite, BookmarkKind::WatchLater] {
+                        button {
+                            key: "{kind.display_name()}",
+                            class: if kind == selected {
+                                "px-4 py-2 rounded-lg text-sm font-medium bg-green-600 text-white"
+                            } else {
+                                "px-4 py-2 rounded-lg text-sm font-medium bg-white text-gray-700 border border-gray-300 hover:bg-green-50"
+                            },
+                            onclick: move |_| tab.set(kind),
+                            "{kind.display_name()}"
+                        }
+                    }
+                }
+            }
+
+            if items.is_empty() {
+                div {
+                    class: "text-center text-sm text-gray-500 py-8",
+                    "Nothing saved yet. Use ♡ or 🕒 on any item to add it here."
+                }
+            }
+            for item in items {
+                BrowseCard { key: "{item.tx_id}", item }
+            }
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-5",
+                h3 { class: "font-semibold text-gray-900", "Sync across devices" }
+                p {
+                    class: "text-sm text-gray-600 mt-1",
+                    "Back up your saved items as a signed Arweave upload and restore them on another device with the same wallet. Backups are public."
+                }
+                div {
+                    class: "flex gap-2 mt-4",
+                    button {
+                        class: "bg-green-600 hover:bg-green-700 disabled:bg-gray-300 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        disabled: *is_syncing.read(),
+                        onclick: backup,
+                        "Back up to Arweave"
+                    }
+                    button {
+                        class: "bg-white hover:bg-green-50 disabled:bg-gray-100 text-green-700 border border-green-300 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        disabled: *is_syncing.read(),
+                        onclick: restore,
+                        "Restore"
+                    }
+                }
+                match status.read().as_ref() {
+                    Some(Ok(message)) => rsx! { p { class: "text-sm text-green-700 mt-3", "{message}" } },
+                    Some(Err(message)) => rsx! { p { class: "text-sm text-red-700 mt-3", "{message}" } },
+                    None => rsx! {},
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use dioxus::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::models::ContentMetadata;
+use crate::services::analytics::{record_usage, UsageEvent};
+use crate::services::gateway::GatewayManager;
+use crate::services::playback::{use_playback_position, PlaybackService};
+use crate::services::settings::use_settings;
+use crate::services::shortcuts::{use_keyboard_shortcuts, Shortcut};
+
+fn player_id(tx_id: &str) -> String {
+    format!("player-{}", tx_id)
+}
+
+fn media_element(tx_id: &str) -> Option<web_sys::HtmlMediaElement> {
+    web_sys::window()?
+        .document()?
+        .get_element_by_id(&player_id(tx_id))?
+        .dyn_into()
+        .ok()
+}
+
+/// Jump the item's player to `secs` and start playing
+pub fn seek_player(tx_id: &str, secs: f64) {
+    if let Some(media) = media_element(tx_id) {
+        media.set_current_time(secs);
+        let _ = media.play();
+    }
+}
+
+/// Audio or video player that remembers where playback stopped
+///
+/// With `resume`, playback continues from the saved position once the
+/// media's metadata has loaded.
+#[component]
+pub fn MediaPlayer(
+    item: ContentMetadata,
+    #[props(default)] autoplay: bool,
+    #[props(default)] resume: bool,
+    #[props(default)] on_ended: EventHandler<()>,
+    /// Called with the current time as playback progresses
+    #[props(default)] on_time: EventHandler<f64>,
+) -> Element {
+    let saved = use_playback_position(item.tx_id.clone());
+    let settings = use_settings();
+    let preload = if settings.read().data_saver { "none" } else { "metadata" };
+
+    // The player can be handed a different item without remounting
+    let mut current_id = use_signal(|| item.tx_id.clone());
+    let tx_id = item.tx_id.clone();
+    use_effect(use_reactive!(|tx_id| current_id.set(tx_id)));
+    use_keyboard_shortcuts(move || {
+        vec![Shortcut::new(" ", "Play or pause", move || {
+            if let Some(media) = media_element(&current_id.peek()) {
+                if media.paused() {
+                    let _ = media.play();
+                } else {
+                    let _ = media.pause();
+                }
+            }
+        })]
+    });
+
+    let record = move |tx_id: String, force: bool| {
+        let Some(media) = media_element(&tx_id) else {
+            return;
+        };
+        let (position, duration) = (media.current_time(), media.duration());
+        on_time.call(position);
+        if !duration.is_finite() {
+            return;
+        }
+        spawn(async move {
+            PlaybackService::record(&tx_id, position, duration, force).await;
+        });
+    };
+
+    // Count a play the first time each item starts, not on every resume
+    let mut counted_id = use_signal(|| None::<String>);
+    let mut count_play = move |tx_id: String| {
+        if counted_id.peek().as_deref() != Some(tx_id.as_str()) {
+            counted_id.set(Some(tx_id.clone()));
+            record_usage(UsageEvent::Play(tx_id));
+        }
+    };
+
+    let loaded_id = item.tx_id.clone();
+    let on_loaded = move |_| {
+        if !resume {
+            return;
+        }
+        if let Some(position) = saved.read().as_ref().filter(|p| p.is_resumable()) {
+            if let Some(media) = media_element(&loaded_id) {
+                media.set_current_time(position.position_secs);
+            }
+        }
+    };
+
+    let play_id = item.tx_id.clone();
+    let update_id = item.tx_id.clone();
+    let pause_id = item.tx_id.clone();
+    let ended_id = item.tx_id.clone();
+    let src = GatewayManager::new().media_url(&item.tx_id);
+    let id = player_id(&item.tx_id);
+
+    if item.content_type.starts_with("video/") {
+        rsx! {
+            video {
+                id,
+                class: "w-full rounded",
+                src,
+                controls: true,
+                autoplay,
+                preload,
+                onloadedmetadata: on_loaded,
+                onplay: move |_| count_play(play_id.clone()),
+                ontimeupdate: move |_| record(update_id.clone(), false),
+                onpause: move |_| record(pause_id.clone(), true),
+                onended: move |_| {
+                    record(ended_id.clone(), true);
+                    on_ended.call(());
+                },
+            }
+        }
+    } else {
+        rsx! {
+            audio {
+                id,
+                class: "w-full",
+                src,
+                controls: true,
+                autoplay,
+                preload,
+                onloadedmetadata: on_loaded,
+                onplay: move |_| count_play(play_id.clone()),
+                ontimeupdate: move |_| record(update_id.clone(), false),
+                onpause: move |_| record(pause_id.clone(), true),
+                onended: move |_| {
+                    record(ended_id.clone(), true);
+                    on_ended.call(());
+                },
+            }
+        }
+    }
+}
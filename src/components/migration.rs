@@ -0,0 +1,182 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::services::migration::{needs_migration, MigrationOptions, MigrationPlan, MigrationService};
+use crate::services::wallet::{use_wallet_state, WalletService};
+
+/// Banner offering to move anonymous data under a newly connected wallet
+#[component]
+pub fn MigrationPrompt() -> Element {
+    let wallet_state = use_wallet_state();
+    let mut dismissed = use_signal(|| false);
+
+    let Some(wallet) = wallet_state.read().address().map(str::to_string) else {
+        return rsx! {};
+    };
+    if *dismissed.read() || !needs_migration(&wallet) {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "bg-yellow-50 border-b border-yellow-200",
+            div {
+                class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-3 flex items-center justify-between gap-4 text-sm",
+                span { class: "text-yellow-900", "You saved items in this browser before connecting a wallet. Move them to your wallet?" }
+                div {
+                    class: "flex gap-3",
+                    Link { class: "font-medium text-green-700 hover:text-green-800", to: Route::MigrateLocalData {}, "Review" }
+                    button { class: "text-gray-500 hover:text-gray-700", onclick: move |_| dismissed.set(true), "Not now" }
+                }
+            }
+        }
+    }
+}
+
+/// Review screen for re-attributing local data to the connected wallet
+///
+/// Shows exactly what stays local and what would be published; nothing is
+/// signed until the user confirms.
+#[component]
+pub fn MigrateLocalData() -> Element {
+    let mut plan = use_signal(|| None::<Result<MigrationPlan, String>>);
+    let mut options = use_signal(|| MigrationOptions {
+        link_identity: true,
+        republish_reports: true,
+        backup_bookmarks: false,
+        delete_identity: false,
+    });
+    let mut is_applying = use_signal(|| false);
+    let mut result = use_signal(|| None::<Result<Vec<String>, String>>);
+
+    use_effect(move || {
+        spawn(async move {
+            plan.set(Some(MigrationService::plan().await.map_err(|e| e.to_string())));
+        });
+    });
+
+    let apply = move |_| {
+        let Some(Ok(reviewed)) = plan.read().clone() else {
+            return;
+        };
+        let chosen = *options.read();
+        spawn(async move {
+            is_applying.set(true);
+            result.set(Some(MigrationService::apply(&reviewed, chosen).await.map_err(|e| e.to_string())));
+            is_applying.set(false);
+        });
+    };
+
+    let content = match plan.read().clone() {
+        None => rsx! { p { class: "text-sm text-gray-500", "Checking this browser..." } },
+        Some(Err(message)) => rsx! { p { class: "text-sm text-red-700", "{message}" } },
+        Some(Ok(plan)) => {
+            let chosen = *options.read();
+            rsx! {
+                p {
+                    class: "text-sm text-gray-600",
+                    "Moving local data to wallet "
+                    span { class: "font-mono", "{WalletService::format_address(&plan.wallet)}" }
+                    ". Review what happens below."
+                }
+
+                h3 { class: "font-semibold text-gray-900 mt-6", "Stays in this browser" }
+                ul {
+                    class: "text-sm text-gray-700 list-disc list-inside mt-2",
+                    li { "{plan.bookmarks} saved items" }
+                    li { "{plan.notes} study notes (never published)" }
+                    li { "{plan.follows} follows" }
+                }
+
+                h3 { class: "font-semibold text-gray-900 mt-6", "Published and signed by your wallet" }
+                div {
+                    class: "space-y-2 mt-2",
+                    if let Some(pseudonym) = plan.pseudonym.as_ref() {
+                        if pseudonym.linked_wallet.is_none() {
+                            MigrationOption {
+                                label: format!("Link anonymous identity {} to this wallet", WalletService::format_address(&pseudonym.address)),
+                                checked: chosen.link_identity,
+                                on_change: move |checked| options.write().link_identity = checked,
+                            }
+                        }
+                    }
+                    if !plan.reports.is_empty() {
+                        MigrationOption {
+                            label: format!("Republish {} anonymous reports as verified", plan.reports.len()),
+                            checked: chosen.republish_reports,
+                            on_change: move |checked| options.write().republish_reports = checked,
+                        }
+                        ul {
+                            class: "ml-6 text-xs text-gray-500",
+                            for report in plan.reports.iter() {
+                                li { key: "{report.id}", "{report.reason} report on {WalletService::format_address(&report.target_tx_id)}" }
+                            }
+                        }
+                    }
+                    if plan.bookmarks > 0 {
+                        MigrationOption {
+                            label: "Back up saved items so they can be restored on other devices (public)".to_string(),
+                            checked: chosen.backup_bookmarks,
+                            on_change: move |checked| options.write().backup_bookmarks = checked,
+                        }
+                    }
+                }
+
+                if plan.pseudonym.is_some() {
+                    h3 { class: "font-semibold text-gray-900 mt-6", "Afterwards" }
+                    div {
+                        class: "mt-2",
+                        MigrationOption {
+                            label: "Delete the anonymous identity from this browser".to_string(),
+                            checked: chosen.delete_identity,
+                            on_change: move |checked| options.write().delete_identity = checked,
+                        }
+                    }
+                }
+
+                button {
+                    class: "mt-6 bg-green-600 hover:bg-green-700 disabled:bg-gray-300 text-white px-6 py-2 rounded-lg font-medium transition-colors",
+                    disabled: *is_applying.read(),
+                    onclick: apply,
+                    if *is_applying.read() { "Migrating..." } else { "Confirm and migrate" }
+                }
+            }
+        }
+    };
+
+    rsx! {
+        main {
+            class: "max-w-3xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-8",
+                h2 { class: "text-2xl font-bold text-gray-900 mb-4", "Move local data to your wallet" }
+
+                match result.read().as_ref() {
+                    Some(Ok(published)) => rsx! {
+                        p { class: "text-sm text-green-700", "Done. {published.len()} items were published from your wallet." }
+                        Link { class: "text-sm text-green-700 hover:text-green-800 font-medium underline", to: Route::Library {}, "Go to your Library" }
+                    },
+                    Some(Err(message)) => rsx! {
+                        p { class: "text-sm text-red-700 mb-4", "{message}" }
+                        {content}
+                    },
+                    None => content,
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn MigrationOption(label: String, checked: bool, on_change: EventHandler<bool>) -> Element {
+    rsx! {
+        label {
+            class: "flex items-center gap-2 text-sm text-gray-700",
+            input {
+                r#type: "checkbox",
+                checked,
+                onchange: move |evt| on_change.call(evt.checked()),
+            }
+            "{label}"
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use dioxus::prelude::*;
+use crate::services::ministry_verification::{
+    normalize_domain, publish_proof, resolve_verifications, use_verified_ministry, VerificationMethod,
+};
+use crate::services::wallet::WalletService;
+use crate::utils::citation::permanent_url;
+
+/// "Verified" pill for an uploader whose domain proof checks out in this
+/// browser; nothing otherwise
+#[component]
+pub fn VerifiedBadge(address: String, #[props(default)] compact: bool) -> Element {
+    let verified = use_verified_ministry(address.clone());
+
+    let lookup_address = address.clone();
+    use_effect(use_reactive!(|lookup_address| {
+        spawn(async move {
+            if let Err(e) = resolve_verifications(&[&lookup_address]).await {
+                log::warn!("Could not check verification of {}: {}", lookup_address, e);
+            }
+        });
+    }));
+
+    let Some(ministry) = verified.read().clone() else {
+        return rsx! {};
+    };
+    let title = format!("{} lists this address ({})", ministry.domain, ministry.method.display_name());
+
+    rsx! {
+        if compact {
+            span { class: "text-green-700", title: "{title}", aria_label: "Verified: {ministry.domain}", "✓" }
+        } else {
+            a {
+                class: "inline-flex items-center gap-1 px-2 py-0.5 rounded-full text-xs font-medium bg-green-100 text-green-800 hover:bg-green-200",
+                href: permanent_url(&ministry.record_tx_id),
+                target: "_blank",
+                rel: "noopener noreferrer",
+                title: "{title}",
+                "✓ Verified · {ministry.domain}"
+            }
+        }
+    }
+}
+
+/// Prove the connected wallet belongs to a ministry's domain
+///
+/// `donation_address` is the tip address from the profile; it's included
+/// in the proof when the domain lists it too.
+#[component]
+pub fn MinistryVerificationPanel(address: String, donation_address: Option<String>) -> Element {
+    let verified = use_verified_ministry(address.clone());
+    let mut domain = use_signal(String::new);
+    let mut method = use_signal(|| VerificationMethod::DnsTxt);
+    let mut busy = use_signal(|| false);
+    let mut message = use_signal(|| None::<Result<String, String>>);
+
+    let lookup_address = address.clone();
+    use_effect(use_reactive!(|lookup_address| {
+        spawn(async move {
+            if let Err(e) = resolve_verifications(&[&lookup_address]).await {
+                log::warn!("Could not check verification of {}: {}", lookup_address, e);
+            }
+        });
+    }));
+
+    let normalized = normalize_domain(&domain.read()).ok();
+    let listed_donation = donation_address.clone().filter(|donation| *donation != address);
+    let verify = {
+        let listed_donation = listed_donation.clone();
+        move |_| {
+            let domain = domain.read().clone();
+            let method = *method.read();
+            let donation = listed_donation.clone();
+            busy.set(true);
+            message.set(None);
+            spawn(async move {
+                let result = publish_proof(&domain, method, donation).await;
+                message.set(Some(match result {
+                    Ok(ministry) => Ok(format!("Verified {}. Proof published ({}).", ministry.domain, WalletService::format_address(&ministry.record_tx_id))),
+                    Err(e) => Err(e.to_string()),
+                }));
+                busy.set(false);
+            });
+        }
+    };
+
+    let input_class = "w-full px-3 py-2 border border-gray-300 rounded-md text-sm";
+
+    rsx! {
+        section {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 space-y-4 mt-6",
+            h3 { class: "text-lg font-semibold text-gray-900", "Verify your ministry" }
+            p {
+                class: "text-sm text-gray-600",
+                "List this wallet on your ministry's website or DNS to show a verified badge on your profile and uploads. "
+                "Readers check the listing themselves, so the badge stays only while the listing is up."
+            }
+            if let Some(ministry) = verified.read().as_ref() {
+                p {
+                    class: "text-sm text-green-800 bg-green-50 border border-green-200 rounded px-3 py-2",
+                    "Verified as {ministry.domain} by {ministry.method.display_name()}."
+                    if ministry.donation_address.is_some() {
+                        " Your tip address is verified too."
+                    }
+                }
+            }
+            div {
+                class: "grid md:grid-cols-2 gap-4",
+                label {
+                    class: "block",
+                    span { class: "block text-sm font-medium text-gray-700 mb-1", "Domain" }
+                    input {
+                        class: input_class,
+                        placeholder: "gracechurch.org",
+                        value: "{domain}",
+                        oninput: move |evt| domain.set(evt.value()),
+                    }
+                }
+                label {
+                    class: "block",
+                    span { class: "block text-sm font-medium text-gray-700 mb-1", "Proof" }
+                    select {
+                        class: input_class,
+                        value: method.read().tag_value(),
+                        onchange: move |evt| {
+                            if let Some(choice) = VerificationMethod::from_tag_value(&evt.value()) {
+                                method.set(choice);
+                            }
+                        },
+                        for option_method in VerificationMethod::ALL {
+                            option { value: option_method.tag_value(), "{option_method.display_name()}" }
+                        }
+                    }
+                }
+            }
+            if let Some(domain) = normalized.as_ref() {
+                div {
+                    class: "text-sm text-gray-700 bg-gray-50 border border-gray-200 rounded-lg p-3 space-y-1",
+                    p { "{method.read().instructions(domain, &address)}" }
+                    if let Some(donation) = listed_donation.as_ref() {
+                        p { "To verify your tip address as well, list {donation} the same way." }
+                    }
+                }
+            }
+            if let Some(result) = message.read().as_ref() {
+                match result {
+                    Ok(text) => rsx! { p { class: "text-sm text-green-700", "{text}" } },
+                    Err(text) => rsx! { p { class: "text-sm text-red-700", "{text}" } },
+                }
+            }
+            button {
+                class: "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium disabled:opacity-50",
+                r#type: "button",
+                disabled: normalized.is_none() || *busy.read(),
+                onclick: verify,
+                if *busy.read() { "Checking..." } else { "Check and publish proof" }
+            }
+        }
+    }
+}
@@ -2,11 +2,182 @@
 pub mod wallet_modal;
 pub mod wallet_connect;
 pub mod wallet_example;
+pub mod confirmation_badge;
+pub mod archive_health;
+pub mod rights_claim;
+pub mod reference_export;
+pub mod bookmark_buttons;
+pub mod follow_button;
+pub mod media_player;
+pub mod transcript_view;
+pub mod audio_trim;
+pub mod encrypted_content;
+pub mod toast;
+pub mod theme;
+pub mod modal;
+pub mod forms;
+pub mod skeleton;
+pub mod address_avatar;
+pub mod connection;
+pub mod tip;
+pub mod top_up;
+pub mod comments;
+pub mod reactions;
+pub mod qr_code;
+pub mod downloads;
+pub mod verification_badge;
+pub mod sync_status;
+pub mod debug_panel;
+pub mod review_checklist;
+pub mod ministry_verification;
+pub mod charts;
+
+// Pages and the components that link into them, which need the router
+// and so the `app` feature
+#[cfg(feature = "app")]
+pub mod settings;
+#[cfg(feature = "app")]
+pub mod digest;
+#[cfg(feature = "app")]
+pub mod search_box;
+#[cfg(feature = "app")]
+pub mod content_detail;
+#[cfg(feature = "app")]
+pub mod browse;
+#[cfg(feature = "app")]
+pub mod search_page;
+#[cfg(feature = "app")]
+pub mod study;
+#[cfg(feature = "app")]
+pub mod upload;
+#[cfg(feature = "app")]
+pub mod series_detail;
+#[cfg(feature = "app")]
+pub mod collections;
+#[cfg(feature = "app")]
+pub mod library;
+#[cfg(feature = "app")]
+pub mod migration;
+#[cfg(feature = "app")]
+pub mod whats_new;
+#[cfg(feature = "app")]
+pub mod arns;
+#[cfg(feature = "app")]
+pub mod profile;
+#[cfg(feature = "app")]
+pub mod following;
+#[cfg(feature = "app")]
+pub mod shortcuts;
+#[cfg(feature = "app")]
+pub mod network;
+#[cfg(feature = "app")]
+pub mod feed;
+#[cfg(feature = "app")]
+pub mod share;
+#[cfg(feature = "app")]
+pub mod embed;
+#[cfg(feature = "app")]
+pub mod usage_stats;
+#[cfg(feature = "app")]
+pub mod receipts;
+#[cfg(feature = "app")]
+pub mod bulk_import;
+#[cfg(feature = "app")]
+pub mod webhooks;
+#[cfg(feature = "app")]
+pub mod notifications;
+#[cfg(feature = "app")]
+pub mod upload_failure;
+#[cfg(feature = "app")]
+pub mod moderation_audit;
+#[cfg(feature = "app")]
+pub mod submission_queue;
+#[cfg(feature = "app")]
+pub mod platform_stats;
+#[cfg(feature = "app")]
+pub mod home_feed;
 
 // Re-export main components
-pub use wallet_modal::{WalletModal, WalletConnectButton};
+pub use wallet_modal::{WalletModal, WalletConnectButton, BeaconPairingDialog};
 pub use wallet_connect::{
     WalletConnect, WalletConnectCompact, WalletConnectWithAddress, WalletConnectFull,
     WalletConnectProps, WalletConnectSize, WalletConnectVariant, ConnectionChangeEvent
 };
-pub use wallet_example::WalletIntegrationExample;
\ No newline at end of file
+pub use wallet_example::WalletIntegrationExample;
+pub use confirmation_badge::ConfirmationBadge;
+pub use archive_health::ArchiveHealth;
+pub use rights_claim::{RightsClaimForm, ClaimReview};
+pub use toast::{AppErrorBoundary, CrashBoundary, ToastHost};
+pub use theme::{ThemeProvider, ThemeToggle};
+pub use modal::Modal;
+pub use address_avatar::AddressAvatar;
+pub use connection::ConnectionBanner;
+pub use tip::SupportButton;
+pub use top_up::{TopUpButton, TopUpDialog};
+pub use comments::CommentThread;
+pub use reactions::ReactionBar;
+pub use qr_code::QrCode;
+pub use downloads::{DownloadButton, DownloadsPanel};
+pub use verification_badge::VerificationBadge;
+pub use ministry_verification::{MinistryVerificationPanel, VerifiedBadge};
+pub use charts::{BarChart, DonutChart, LineChart};
+pub use sync_status::SyncIndicator;
+
+#[cfg(feature = "app")]
+pub use digest::WeeklyDigest;
+#[cfg(feature = "app")]
+pub use settings::Settings;
+#[cfg(feature = "app")]
+pub use search_box::SearchBox;
+#[cfg(feature = "app")]
+pub use content_detail::ContentDetail;
+#[cfg(feature = "app")]
+pub use browse::Browse;
+#[cfg(feature = "app")]
+pub use search_page::SearchPage;
+#[cfg(feature = "app")]
+pub use study::StudyWorkspace;
+#[cfg(feature = "app")]
+pub use upload::Upload;
+#[cfg(feature = "app")]
+pub use series_detail::SeriesDetail;
+#[cfg(feature = "app")]
+pub use collections::{AddToCollectionButton, CollectionDetail, Collections};
+#[cfg(feature = "app")]
+pub use library::Library;
+#[cfg(feature = "app")]
+pub use migration::{MigrateLocalData, MigrationPrompt};
+#[cfg(feature = "app")]
+pub use whats_new::{WhatsNew, WhatsNewBanner};
+#[cfg(feature = "app")]
+pub use arns::{ArnsSettings, NameRedirect};
+#[cfg(feature = "app")]
+pub use profile::{ProfileEditor, ProfileHeader, UploaderPage};
+#[cfg(feature = "app")]
+pub use following::FollowingFeed;
+#[cfg(feature = "app")]
+pub use shortcuts::GlobalShortcuts;
+#[cfg(feature = "app")]
+pub use network::NetworkBanner;
+#[cfg(feature = "app")]
+pub use feed::PodcastFeedPanel;
+#[cfg(feature = "app")]
+pub use share::{EmbedCodeButton, ShareButton, ShareHead, ShareQrButton};
+#[cfg(feature = "app")]
+pub use embed::EmbedPlayer;
+#[cfg(feature = "app")]
+pub use usage_stats::UsageStats;
+#[cfg(feature = "app")]
+pub use receipts::{ReceiptHistory, ReceiptView};
+#[cfg(feature = "app")]
+pub use bulk_import::BulkImport;
+#[cfg(feature = "app")]
+pub use notifications::NotificationBell;
+#[cfg(feature = "app")]
+pub use moderation_audit::ModerationAudit;
+#[cfg(feature = "app")]
+pub use submission_queue::SubmissionQueue;
+#[cfg(feature = "app")]
+pub use platform_stats::{AdminDashboard, HomeStats};
+#[cfg(feature = "app")]
+pub use home_feed::{FeatureSeriesButton, HomeFeed};
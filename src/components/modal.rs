@@ -0,0 +1,176 @@
+use dioxus::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Elements Tab can reach inside a dialog
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+/// Elements the arrow keys move between, such as a list of choices
+const ARROW_NAV: &str = "[data-arrow-nav]:not([disabled])";
+
+// Open dialogs, innermost last
+fn use_open_modals_state() -> &'static GlobalSignal<Vec<Signal<bool>>> {
+    static OPEN_MODALS: GlobalSignal<Vec<Signal<bool>>> = GlobalSignal::new(Vec::new);
+    &OPEN_MODALS
+}
+
+/// Close the innermost open dialog, if any; bound to Escape
+pub fn close_top_modal() {
+    let top = use_open_modals_state().peek().last().copied();
+    if let Some(mut open) = top {
+        open.set(false);
+    }
+}
+
+fn dialog_elements(dialog_id: &str, selector: &str) -> Vec<web_sys::HtmlElement> {
+    let Some(dialog) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(dialog_id))
+    else {
+        return Vec::new();
+    };
+    let Ok(nodes) = dialog.query_selector_all(selector) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
+fn active_element() -> Option<web_sys::Element> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.active_element())
+}
+
+fn focused_index(elements: &[web_sys::HtmlElement]) -> Option<usize> {
+    let active = active_element()?;
+    elements.iter().position(|element| element.is_same_node(Some(&active)))
+}
+
+/// Accessible dialog with a backdrop
+///
+/// While open, focus moves into the dialog and Tab cycles inside it; Escape
+/// (via `close_top_modal`), the close button or a backdrop click closes it
+/// and focus returns to whatever opened it. Arrow keys, Home and End move between children
+/// marked `"data-arrow-nav": "true"`.
+#[component]
+pub fn Modal(
+    open: Signal<bool>,
+    title: String,
+    /// Classes for the dialog panel
+    #[props(default = "bg-white rounded-2xl p-6 max-w-md w-full mx-4 relative shadow-2xl".to_string())]
+    class: String,
+    /// Classes for the title
+    #[props(default = "text-gray-900 text-xl font-semibold mb-6".to_string())]
+    title_class: String,
+    children: Element,
+) -> Element {
+    let dialog_id = use_hook(|| format!("modal-{}", uuid::Uuid::new_v4().simple()));
+    let title_id = format!("{}-title", dialog_id);
+    // Element focused before the dialog opened, to return focus to
+    let mut opener = use_signal(|| None::<web_sys::HtmlElement>);
+
+    // Track open dialogs, and return focus however this one was closed,
+    // including by its owner
+    use_effect(move || {
+        if *open.read() {
+            let mut open_modals = use_open_modals_state().write();
+            if !open_modals.contains(&open) {
+                open_modals.push(open);
+            }
+        } else {
+            use_open_modals_state().write().retain(|modal| *modal != open);
+            if let Some(element) = opener.write().take() {
+                let _ = element.focus();
+            }
+        }
+    });
+    use_drop(move || use_open_modals_state().write().retain(|modal| *modal != open));
+
+    let mut close = move || open.set(false);
+
+    if !*open.read() {
+        return rsx! {};
+    }
+
+    let mount_id = dialog_id.clone();
+    let on_mounted = move |_| {
+        opener.set(active_element().and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok()));
+        let first = dialog_elements(&mount_id, ARROW_NAV)
+            .into_iter()
+            .next()
+            .or_else(|| dialog_elements(&mount_id, FOCUSABLE).into_iter().next());
+        if let Some(first) = first {
+            let _ = first.focus();
+        }
+    };
+
+    let key_id = dialog_id.clone();
+    let on_keydown = move |evt: KeyboardEvent| match evt.key() {
+        Key::Tab => {
+            let focusable = dialog_elements(&key_id, FOCUSABLE);
+            let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+                evt.prevent_default();
+                return;
+            };
+            let current = focused_index(&focusable);
+            let backwards = evt.modifiers().shift();
+            if backwards && current.is_none_or(|index| index == 0) {
+                evt.prevent_default();
+                let _ = last.focus();
+            } else if !backwards && current.is_none_or(|index| index + 1 == focusable.len()) {
+                evt.prevent_default();
+                let _ = first.focus();
+            }
+        }
+        Key::ArrowDown | Key::ArrowUp | Key::Home | Key::End => {
+            let options = dialog_elements(&key_id, ARROW_NAV);
+            if options.is_empty() {
+                return;
+            }
+            let last = options.len() - 1;
+            let target = match (evt.key(), focused_index(&options)) {
+                (Key::Home, _) => 0,
+                (Key::End, _) => last,
+                (Key::ArrowDown, Some(index)) => (index + 1).min(last),
+                (Key::ArrowUp, Some(index)) => index.saturating_sub(1),
+                (Key::ArrowDown, None) => 0,
+                _ => last,
+            };
+            evt.prevent_default();
+            let _ = options[target].focus();
+        }
+        _ => {}
+    };
+
+    rsx! {
+        // Backdrop
+        div {
+            class: "fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50",
+            onclick: move |_| close(),
+
+            div {
+                id: "{dialog_id}",
+                class: "{class}",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "{title_id}",
+                onclick: |evt| evt.stop_propagation(),
+                onkeydown: on_keydown,
+                onmounted: on_mounted,
+
+                button {
+                    class: "absolute top-4 right-4 text-gray-400 hover:text-gray-600 transition-colors",
+                    aria_label: "Close",
+                    onclick: move |_| close(),
+                    "✕"
+                }
+
+                h2 { id: "{title_id}", class: "{title_class}", "{title}" }
+
+                {children}
+            }
+        }
+    }
+}
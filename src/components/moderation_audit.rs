@@ -0,0 +1,202 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::address_avatar::AddressAvatar;
+use crate::components::charts::BarChart;
+use crate::components::reference_export::MetadataExportButtons;
+use crate::components::skeleton::CardSkeleton;
+use crate::services::async_state::{use_async_resource, AsyncState};
+use crate::services::moderation_log::{
+    self, decisions_per_week, load_audit_log, reviewers, AuditFilter, Decision, ModerationDecision,
+};
+use crate::services::wallet::WalletService;
+use crate::utils::charts::ChartPoint;
+use crate::utils::citation::permanent_url;
+use crate::utils::metadata_export::MetadataFormat;
+
+/// Weeks shown in the decisions chart
+const THROUGHPUT_WEEKS: usize = 12;
+
+fn format_timestamp(timestamp: Option<i64>) -> String {
+    timestamp
+        .and_then(|timestamp| chrono::DateTime::from_timestamp(timestamp, 0))
+        .map(|date| date.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "Pending".to_string())
+}
+
+/// Public log of every moderation decision, so the community can check
+/// moderation is applied consistently
+#[component]
+pub fn ModerationAudit() -> Element {
+    let log = use_async_resource(load_audit_log);
+    let mut filter = use_signal(AuditFilter::default);
+    let mut export_error = use_signal(|| None::<String>);
+
+    let select_class = "px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-green-500";
+
+    rsx! {
+        main {
+            class: "max-w-6xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                h2 { class: "text-2xl font-bold text-gray-900", "Moderation Log" }
+                p {
+                    class: "text-sm text-gray-600 mt-1",
+                    "Every decision to hide or restore an item or to answer a rights claim is signed by a moderator's wallet and stored on Arweave. Each entry links to its signed record."
+                }
+            }
+
+            match log.state() {
+                AsyncState::Loading => rsx! { CardSkeleton {} },
+                AsyncState::Failed(message) => rsx! {
+                    div {
+                        class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                        "Could not load the moderation log: {message}"
+                    }
+                },
+                AsyncState::Ready(decisions) if decisions.is_empty() => rsx! {
+                    p { class: "text-sm text-gray-500", "No moderation decisions have been published yet." }
+                },
+                AsyncState::Ready(decisions) => {
+                    let shown: Vec<ModerationDecision> = decisions
+                        .iter()
+                        .filter(|decision| filter.read().matches(decision))
+                        .cloned()
+                        .collect();
+                    let all_reviewers = reviewers(&decisions);
+                    let per_week: Vec<ChartPoint> = decisions_per_week(&shown, THROUGHPUT_WEEKS, chrono::Local::now().date_naive())
+                        .into_iter()
+                        .map(|(week, count)| ChartPoint::new(format!("Week of {}", week.format("%b %-d")), count as f64))
+                        .collect();
+                    let current = filter.read().clone();
+                    rsx! {
+                        div {
+                            class: "bg-white rounded-xl shadow-sm border border-green-200 p-4 flex flex-wrap items-center gap-3",
+                            select {
+                                class: select_class,
+                                "aria-label": "Decision",
+                                value: current.decision.map(|decision| decision.display_name()).unwrap_or_default(),
+                                onchange: move |evt| {
+                                    let value = evt.value();
+                                    filter.write().decision = Decision::ALL.into_iter().find(|decision| decision.display_name() == value);
+                                },
+                                option { value: "", "All decisions" }
+                                for decision in Decision::ALL {
+                                    option { key: "{decision.display_name()}", value: decision.display_name(), "{decision.display_name()}" }
+                                }
+                            }
+                            select {
+                                class: select_class,
+                                "aria-label": "Reviewer",
+                                value: current.reviewer.clone().unwrap_or_default(),
+                                onchange: move |evt| {
+                                    let value = evt.value();
+                                    filter.write().reviewer = (!value.is_empty()).then_some(value);
+                                },
+                                option { value: "", "All reviewers" }
+                                for reviewer in all_reviewers {
+                                    option { key: "{reviewer}", value: "{reviewer}", "{WalletService::format_address(&reviewer)}" }
+                                }
+                            }
+                            input {
+                                class: "flex-1 min-w-48 {select_class}",
+                                r#type: "search",
+                                placeholder: "Item ID, tracking number or reason",
+                                value: "{current.text}",
+                                oninput: move |evt| filter.write().text = evt.value(),
+                            }
+                            if current != AuditFilter::default() {
+                                button {
+                                    class: "text-sm text-gray-600 underline",
+                                    onclick: move |_| filter.set(AuditFilter::default()),
+                                    "Clear filters"
+                                }
+                            }
+                        }
+
+                        div {
+                            class: "bg-white rounded-xl shadow-sm border border-green-200 p-5",
+                            h3 { class: "font-semibold text-gray-900", "Decisions per week" }
+                            BarChart { points: per_week, height: "h-24" }
+                        }
+
+                        div {
+                            class: "flex flex-wrap items-center justify-between gap-3",
+                            span { class: "text-sm text-gray-600", "Showing {shown.len()} of {decisions.len()} decisions" }
+                            if let Some(error) = export_error() {
+                                span { class: "text-sm text-red-700", "{error}" }
+                            }
+                            MetadataExportButtons {
+                                label: "Export shown:",
+                                busy: shown.is_empty(),
+                                on_export: {
+                                    let shown = shown.clone();
+                                    move |format: MetadataFormat| export_error.set(moderation_log::download(format, &shown).err())
+                                },
+                            }
+                        }
+
+                        div {
+                            class: "bg-white rounded-xl shadow-sm border border-green-200 divide-y divide-gray-100",
+                            for decision in shown {
+                                DecisionRow { key: "{decision.record_tx_id}", decision }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DecisionRow(decision: ModerationDecision) -> Element {
+    let badge = match decision.decision {
+        Decision::Hide | Decision::ClaimUpheld => "bg-red-100 text-red-800",
+        Decision::Restore | Decision::ClaimRejected => "bg-green-100 text-green-800",
+        Decision::ClaimReceived => "bg-yellow-100 text-yellow-800",
+    };
+
+    rsx! {
+        div {
+            class: "p-4 flex flex-wrap items-center gap-x-4 gap-y-1 text-sm text-gray-700",
+            span { class: "w-36 text-gray-500", "{format_timestamp(decision.timestamp)}" }
+            span {
+                class: "inline-flex items-center px-2 py-1 rounded-full text-xs font-medium {badge}",
+                "{decision.decision.display_name()}"
+            }
+            if decision.decision.is_claim() {
+                span { class: "font-mono text-gray-900", "{decision.subject}" }
+            } else {
+                Link {
+                    to: Route::ContentDetail { tx_id: decision.subject.clone() },
+                    class: "font-mono text-green-700 hover:underline",
+                    title: "{decision.subject}",
+                    "{WalletService::format_address(&decision.subject)}"
+                }
+            }
+            if let Some(claim_id) = decision.claim_id.as_ref() {
+                span { class: "font-mono text-gray-500", "{claim_id}" }
+            }
+            span {
+                class: "flex-1 min-w-48",
+                if decision.reason.is_empty() { "No reason given" } else { "{decision.reason}" }
+                if let Some(review) = decision.review.as_ref() {
+                    span { class: "block text-xs text-gray-500", "{review.summary()}" }
+                }
+            }
+            span {
+                class: "inline-flex items-center gap-1 text-gray-500",
+                "by"
+                AddressAvatar { address: decision.reviewer.clone(), size: "w-4 h-4" }
+                span { title: "{decision.reviewer}", "{WalletService::format_address(&decision.reviewer)}" }
+            }
+            a {
+                class: "text-green-700 hover:underline",
+                href: permanent_url(&decision.record_tx_id),
+                target: "_blank",
+                rel: "noopener noreferrer",
+                "Signed record"
+            }
+        }
+    }
+}
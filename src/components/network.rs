@@ -0,0 +1,34 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::config::use_app_config;
+use crate::i18n::t;
+
+/// Strip across the top of every page while off mainnet, so a dry run is
+/// never mistaken for a real upload
+#[component]
+pub fn NetworkBanner() -> Element {
+    let config = use_app_config();
+    if config.network.is_mainnet() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "bg-amber-100 dark:bg-amber-900 border-b border-amber-300 dark:border-amber-700",
+            role: "status",
+            div {
+                class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-2 flex items-center justify-between gap-4 text-sm",
+                span {
+                    class: "text-amber-900 dark:text-amber-100",
+                    span { class: "font-semibold mr-2", "🧪 {config.network.display_name()}" }
+                    {t!("network.banner")}
+                }
+                Link {
+                    class: "font-medium text-amber-800 dark:text-amber-200 hover:underline",
+                    to: Route::Settings {},
+                    {t!("network.change")}
+                }
+            }
+        }
+    }
+}
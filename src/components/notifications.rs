@@ -0,0 +1,120 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::i18n::t;
+use crate::services::notifications::{use_notifications, use_unread_count, Notification, NotificationKind, NotificationService};
+
+fn format_timestamp(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|date| date.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+fn message(notification: &Notification) -> String {
+    let title = notification.title.as_str();
+    match notification.kind {
+        NotificationKind::UploadConfirmed => t!("notifications.upload_confirmed", title = title),
+        NotificationKind::ContentApproved => t!("notifications.content_approved", title = title),
+        NotificationKind::ContentRejected => t!("notifications.content_rejected", title = title),
+        NotificationKind::NewFromFollowed => t!("notifications.new_from_followed", title = title),
+    }
+}
+
+/// Header bell with the unread count, opening the notification list
+#[component]
+pub fn NotificationBell() -> Element {
+    let notifications = use_notifications();
+    let unread = use_unread_count();
+    let mut is_open = use_signal(|| false);
+
+    let mark_all_read = move |_| async move {
+        if let Err(e) = NotificationService::mark_all_read().await {
+            log::warn!("Could not mark notifications read: {}", e);
+        }
+    };
+    let clear = move |_| async move {
+        if let Err(e) = NotificationService::clear().await {
+            log::warn!("Could not clear notifications: {}", e);
+        }
+    };
+
+    let unread = unread();
+    let title = t!("notifications.title");
+    let label = t!("notifications.unread", count = unread);
+
+    rsx! {
+        div {
+            class: "relative",
+            button {
+                class: "relative px-2 py-1 rounded-lg text-gray-500 dark:text-gray-400 hover:bg-green-50 dark:hover:bg-gray-800 transition-colors",
+                title: "{title}",
+                aria_label: "{label}",
+                aria_expanded: "{is_open}",
+                onclick: move |_| is_open.toggle(),
+                span { aria_hidden: "true", "🔔" }
+                if unread > 0 {
+                    span {
+                        class: "absolute -top-1 -right-1 min-w-[1.1rem] px-1 rounded-full bg-green-600 text-white text-[10px] font-semibold leading-4 text-center",
+                        if unread > 99 { "99+" } else { "{unread}" }
+                    }
+                }
+            }
+
+            if is_open() {
+                div {
+                    class: "absolute right-0 z-30 mt-2 w-80 bg-white dark:bg-gray-900 rounded-lg shadow-lg border border-gray-200 dark:border-gray-700",
+                    div {
+                        class: "flex items-center justify-between px-4 py-2 border-b border-gray-100 dark:border-gray-800",
+                        h3 { class: "text-sm font-semibold text-gray-900 dark:text-white", "{title}" }
+                        div {
+                            class: "flex gap-3 text-xs",
+                            button { class: "text-green-700 hover:text-green-800 disabled:opacity-50", disabled: unread == 0, onclick: mark_all_read, {t!("notifications.mark_all_read")} }
+                            button { class: "text-gray-500 hover:text-red-600", onclick: clear, {t!("notifications.clear")} }
+                        }
+                    }
+                    if notifications.read().is_empty() {
+                        p { class: "px-4 py-6 text-sm text-center text-gray-500", {t!("notifications.empty")} }
+                    } else {
+                        div {
+                            class: "max-h-96 overflow-y-auto divide-y divide-gray-100 dark:divide-gray-800",
+                            for notification in notifications.read().iter().cloned() {
+                                NotificationRow { key: "{notification.id}", notification, on_open: move |_| is_open.set(false) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn NotificationRow(notification: Notification, on_open: EventHandler<()>) -> Element {
+    let id = notification.id.clone();
+    let open = move |_| {
+        let id = id.clone();
+        on_open.call(());
+        spawn(async move {
+            if let Err(e) = NotificationService::mark_read(&id).await {
+                log::warn!("Could not mark notification read: {}", e);
+            }
+        });
+    };
+    let row_class = if notification.read { "" } else { "bg-green-50 dark:bg-gray-800" };
+
+    rsx! {
+        Link {
+            to: Route::ContentDetail { tx_id: notification.tx_id.clone() },
+            class: "flex gap-3 px-4 py-3 text-sm hover:bg-green-50 dark:hover:bg-gray-800 {row_class}",
+            onclick: open,
+            span { aria_hidden: "true", "{notification.kind.icon()}" }
+            span {
+                class: "flex-1 min-w-0",
+                span { class: "block text-gray-900 dark:text-gray-100", "{message(&notification)}" }
+                if let Some(detail) = notification.detail.as_ref() {
+                    span { class: "block text-xs text-gray-500 truncate", "{detail}" }
+                }
+                span { class: "block text-xs text-gray-400", "{format_timestamp(notification.created_at)}" }
+            }
+        }
+    }
+}
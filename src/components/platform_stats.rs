@@ -0,0 +1,166 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::charts::{BarChart, DonutChart};
+use crate::components::skeleton::CardSkeleton;
+use crate::services::async_state::{use_async_resource, AsyncState};
+use crate::services::hide_registry::is_moderator;
+use crate::services::platform_stats::{load_platform_stats, PlatformStats};
+use crate::services::wallet::use_wallet_state;
+use crate::utils::charts::ChartPoint;
+use crate::utils::display::format_size;
+
+/// Most recent months shown in the uploads chart
+const MONTHS_SHOWN: usize = 12;
+
+/// Archive-wide counts for the home page, with placeholders until they
+/// load
+#[component]
+pub fn HomeStats() -> Element {
+    let stats = use_async_resource(|| load_platform_stats(false));
+    let loaded = stats.ready();
+    let count = |value: fn(&PlatformStats) -> String| loaded.as_ref().map(value).unwrap_or_else(|| "…".to_string());
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-center",
+            div {
+                class: "grid grid-cols-2 md:grid-cols-4 gap-8",
+                HomeStat { value: count(|stats| stats.items.to_string()), label: "Items Archived" }
+                HomeStat { value: count(|stats| stats.uploaders.to_string()), label: "Churches Served" }
+                HomeStat { value: count(|stats| format_size(stats.bytes_stored)), label: "Preserved" }
+                HomeStat { value: "∞".to_string(), label: "Years Preserved" }
+            }
+        }
+    }
+}
+
+#[component]
+fn HomeStat(value: String, label: &'static str) -> Element {
+    rsx! {
+        div {
+            div { class: "text-3xl font-bold text-green-600", "{value}" }
+            div { class: "text-sm text-gray-600", "{label}" }
+        }
+    }
+}
+
+/// Moderator dashboard of archive-wide statistics
+#[component]
+pub fn AdminDashboard() -> Element {
+    let wallet_state = use_wallet_state();
+    let mut recount = use_signal(|| false);
+    let mut stats = use_async_resource(move || load_platform_stats(*recount.peek()));
+
+    if !wallet_state.read().address().is_some_and(is_moderator) {
+        return rsx! {
+            main {
+                class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+                div {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-gray-600",
+                    "Connect a moderation team wallet to see platform statistics."
+                }
+            }
+        };
+    }
+
+    rsx! {
+        main {
+            class: "max-w-6xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                class: "flex flex-wrap items-start justify-between gap-3",
+                div {
+                    h2 { class: "text-2xl font-bold text-gray-900", "Platform Statistics" }
+                    p { class: "text-sm text-gray-600 mt-1", "Every listed item on Arweave, counted from GraphQL. De-listed items are left out." }
+                }
+                div {
+                    class: "flex items-center gap-4 text-sm",
+                    Link { to: Route::SubmissionQueue {}, class: "text-green-700 hover:underline", "Review queue" }
+                    Link { to: Route::ArchiveHealth {}, class: "text-green-700 hover:underline", "Archive health" }
+                    button {
+                        class: "border border-green-600 text-green-700 hover:bg-green-50 px-3 py-1 rounded-lg font-medium",
+                        onclick: move |_| {
+                            recount.set(true);
+                            stats.restart();
+                        },
+                        "Count again"
+                    }
+                }
+            }
+
+            match stats.state() {
+                AsyncState::Loading => rsx! { CardSkeleton {} },
+                AsyncState::Failed(message) => rsx! {
+                    div {
+                        class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                        "Could not count the archive: {message}"
+                    }
+                },
+                AsyncState::Ready(stats) => rsx! { StatsReport { stats } },
+            }
+        }
+    }
+}
+
+#[component]
+fn StatsReport(stats: PlatformStats) -> Element {
+    let counted_at = chrono::DateTime::from_timestamp(stats.computed_at, 0)
+        .map(|date| date.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default();
+    let average = if stats.sized_items > 0 { format_size(stats.bytes_stored / stats.sized_items as u64) } else { "–".to_string() };
+    let categories: Vec<ChartPoint> = stats.by_category.iter().map(|(category, count)| ChartPoint::new(category, *count as f64)).collect();
+    let months: Vec<ChartPoint> = stats
+        .by_month
+        .iter()
+        .rev()
+        .take(MONTHS_SHOWN)
+        .rev()
+        .map(|(month, count)| ChartPoint::new(month, *count as f64))
+        .collect();
+
+    rsx! {
+        div {
+            class: "grid grid-cols-2 md:grid-cols-4 gap-4",
+            StatCard { label: "Items", value: stats.items.to_string() }
+            StatCard { label: "Uploaders", value: stats.uploaders.to_string() }
+            StatCard { label: "Stored", value: format_size(stats.bytes_stored) }
+            StatCard { label: "Average item", value: average }
+        }
+        if stats.sized_items < stats.items {
+            p { class: "text-xs text-gray-500", "{stats.items - stats.sized_items} older items don't record their size and aren't in the storage totals." }
+        }
+
+        div {
+            class: "grid md:grid-cols-2 gap-6",
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-5",
+                h3 { class: "font-semibold text-gray-900 mb-3", "By category" }
+                DonutChart { points: categories }
+            }
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-5",
+                h3 { class: "font-semibold text-gray-900 mb-3", "Uploads per month" }
+                if months.is_empty() {
+                    p { class: "text-sm text-gray-500", "Nothing uploaded yet." }
+                } else {
+                    BarChart { points: months }
+                }
+            }
+        }
+
+        p { class: "text-xs text-gray-500", "Counted {counted_at}. Counts are reused for a few hours; count again for the latest." }
+    }
+}
+
+#[component]
+fn StatCard(label: &'static str, value: String) -> Element {
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-5 text-center",
+            p { class: "text-3xl font-bold text-gray-900", "{value}" }
+            p { class: "text-sm text-gray-600 mt-1", "{label}" }
+        }
+    }
+}
@@ -0,0 +1,415 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::address_avatar::AddressAvatar;
+use crate::components::browse::BrowseCard;
+use crate::components::feed::PodcastFeedPanel;
+use crate::components::follow_button::FollowButton;
+use crate::components::ministry_verification::{MinistryVerificationPanel, VerifiedBadge};
+use crate::components::forms::{arweave_address, max_length, required, use_field, use_form, web_address, ErrorSummary, FormStatus, SubmitButton, TextField};
+use crate::components::reference_export::MetadataExportButtons;
+use crate::components::skeleton::CardSkeleton;
+use crate::models::{ContentMetadata, SocialLink, UploaderProfile, SPIRITUAL_CONTENT_TYPE};
+use crate::services::async_state::{use_async_resource, AsyncState};
+use crate::services::feed::FeedSource;
+use crate::services::follows::FollowTarget;
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, GraphQLError};
+use crate::services::hide_registry::is_hidden;
+use crate::services::image_prep::{optimize_image, ImageOptions, OptimizedImage};
+use crate::services::profile::{use_uploader_profile, ProfileService, PROFILE_LOGO_MAX_DIMENSION};
+use crate::services::upload::content_type_for;
+use crate::services::wallet::{use_wallet_state, WalletService};
+use crate::utils::metadata_export::MetadataFormat;
+
+/// Who uploaded an item, from their profile
+///
+/// `compact` is a one-line byline for cards; otherwise the full header
+/// with description and links. Addresses without a profile show the
+/// shortened address, or nothing when compact.
+#[component]
+pub fn ProfileHeader(address: String, #[props(default)] compact: bool) -> Element {
+    let profile = use_uploader_profile(address.clone());
+
+    let lookup_address = address.clone();
+    use_effect(use_reactive!(|lookup_address| {
+        spawn(async move {
+            if let Err(e) = ProfileService::new().resolve(&[&lookup_address]).await {
+                log::warn!("Could not load profile for {}: {}", lookup_address, e);
+            }
+        });
+    }));
+
+    let uploader_route = Route::UploaderPage { address: address.clone() };
+
+    if compact {
+        let Some(profile) = profile.read().clone() else {
+            return rsx! {};
+        };
+        return rsx! {
+            Link {
+                class: "flex items-center gap-1.5 text-xs text-gray-500 hover:text-green-700 mt-1",
+                to: uploader_route,
+                if let Some(logo_tx_id) = profile.logo_tx_id.as_ref() {
+                    img {
+                        class: "w-4 h-4 rounded-full object-cover",
+                        src: GatewayManager::new().media_url(logo_tx_id),
+                        alt: "",
+                        loading: "lazy",
+                    }
+                } else {
+                    AddressAvatar { address: address.clone(), size: "w-4 h-4" }
+                }
+                "{profile.ministry_name}"
+                VerifiedBadge { address: address.clone(), compact: true }
+            }
+        };
+    }
+
+    rsx! {
+        match profile.read().as_ref() {
+            Some(profile) => rsx! {
+                div {
+                    class: "flex items-start gap-3",
+                    if let Some(logo_tx_id) = profile.logo_tx_id.as_ref() {
+                        img {
+                            class: "w-12 h-12 rounded-full object-cover flex-shrink-0",
+                            src: GatewayManager::new().media_url(logo_tx_id),
+                            alt: "{profile.ministry_name}",
+                        }
+                    } else {
+                        AddressAvatar { address: address.clone(), size: "w-12 h-12" }
+                    }
+                    div {
+                        class: "space-y-1",
+                        div {
+                            class: "flex items-center gap-3",
+                            Link {
+                                class: "font-semibold text-gray-900 hover:text-green-700",
+                                to: uploader_route,
+                                "{profile.ministry_name}"
+                            }
+                            VerifiedBadge { address: address.clone() }
+                            FollowButton { target: FollowTarget::Uploader(address.clone()) }
+                        }
+                        if let Some(description) = profile.description.as_ref() {
+                            p { class: "text-sm text-gray-600 whitespace-pre-line", "{description}" }
+                        }
+                        ProfileLinks { profile: profile.clone() }
+                    }
+                }
+            },
+            None => rsx! {
+                div {
+                    class: "flex items-center gap-3",
+                    AddressAvatar { address: address.clone() }
+                    Link {
+                        class: "text-sm text-gray-500 hover:text-green-700 font-mono",
+                        to: uploader_route,
+                        title: "{address}",
+                        "Uploaded by {WalletService::format_address(&address)}"
+                    }
+                    FollowButton { target: FollowTarget::Uploader(address.clone()) }
+                }
+            },
+        }
+    }
+}
+
+#[component]
+fn ProfileLinks(profile: UploaderProfile) -> Element {
+    rsx! {
+        div {
+            class: "flex flex-wrap gap-3 text-sm",
+            if let Some(website) = profile.website.as_ref() {
+                a { class: "text-green-700 hover:text-green-800 underline", href: "{website}", target: "_blank", rel: "noopener", "Website" }
+            }
+            for social in profile.socials.iter() {
+                a {
+                    key: "{social.url}",
+                    class: "text-green-700 hover:text-green-800 underline",
+                    href: "{social.url}",
+                    target: "_blank",
+                    rel: "noopener",
+                    "{social.platform}"
+                }
+            }
+        }
+    }
+}
+
+/// Public page of an uploader: their profile and everything they archived
+#[component]
+pub fn UploaderPage(address: String) -> Element {
+    let lookup_address = address.clone();
+    let uploads = use_async_resource(use_reactive!(|lookup_address| async move {
+        let transactions = GraphQLClient::cached()
+            .find_transactions(&[&lookup_address], &[("Type", &[SPIRITUAL_CONTENT_TYPE])])
+            .await?;
+        Ok::<Vec<ContentMetadata>, GraphQLError>(
+            transactions
+                .iter()
+                .filter(|tx| !is_hidden(&tx.id))
+                .filter_map(|tx| ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp))
+                .collect(),
+        )
+    }));
+    let wallet_state = use_wallet_state();
+    let is_own = wallet_state.read().address() == Some(address.as_str());
+    let export_label = if is_own { "Export my uploads:" } else { "Export metadata:" };
+    let mut export_error = use_signal(|| None::<String>);
+
+    rsx! {
+        main {
+            class: "max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 space-y-4",
+                ProfileHeader { address: address.clone() }
+                PodcastFeedPanel { source: FeedSource::Uploader(address.clone()), owner: address.clone() }
+            }
+
+            match uploads.state() {
+                AsyncState::Loading => rsx! { CardSkeleton {} },
+                AsyncState::Failed(message) => rsx! {
+                    div {
+                        class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                        "{message}"
+                    }
+                },
+                AsyncState::Ready(items) if items.is_empty() => rsx! {
+                    p { class: "text-sm text-gray-500", "Nothing archived yet." }
+                },
+                AsyncState::Ready(items) => rsx! {
+                    div {
+                        class: "flex items-center justify-end gap-3",
+                        if let Some(error) = export_error() {
+                            span { class: "text-sm text-red-700", "{error}" }
+                        }
+                        MetadataExportButtons {
+                            label: export_label,
+                            on_export: {
+                                let items = items.clone();
+                                move |format: MetadataFormat| export_error.set(format.download("faithful-archive-uploads", &items).err())
+                            },
+                        }
+                    }
+                    div {
+                        class: "grid gap-4",
+                        for item in items {
+                            BrowseCard { key: "{item.tx_id}", item: item.clone() }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Edit and publish the connected wallet's profile
+#[component]
+pub fn ProfileEditor() -> Element {
+    let wallet_state = use_wallet_state();
+    let mut ministry_name = use_field("Ministry name", vec![required(), max_length(120)]);
+    let mut description = use_field("Description", vec![max_length(2000)]);
+    let mut website = use_field("Website", vec![web_address()]);
+    let mut payment_address = use_field("Tip address", vec![arweave_address()]);
+    let mut form = use_form(&[ministry_name, description, website, payment_address]);
+    let mut socials = use_signal(Vec::<SocialLink>::new);
+    let mut logo_tx_id = use_signal(|| None::<String>);
+    let mut new_logo = use_signal(|| None::<OptimizedImage>);
+
+    let owner = wallet_state.read().address().map(str::to_string);
+    use_effect(use_reactive!(|owner| {
+        let Some(owner) = owner else {
+            return;
+        };
+        spawn(async move {
+            match ProfileService::new().load(&owner).await {
+                Ok(Some(profile)) => {
+                    ministry_name.load(profile.ministry_name);
+                    description.load(profile.description.unwrap_or_default());
+                    website.load(profile.website.unwrap_or_default());
+                    payment_address.load(profile.payment_address.unwrap_or_default());
+                    socials.set(profile.socials);
+                    logo_tx_id.set(profile.logo_tx_id);
+                }
+                Ok(None) => {}
+                Err(e) => form.fail(e.to_string()),
+            }
+        });
+    }));
+
+    let select_logo = move |evt: FormEvent| async move {
+        let Some(file_engine) = evt.files() else {
+            return;
+        };
+        let Some(name) = file_engine.files().into_iter().next() else {
+            return;
+        };
+        let Some(bytes) = file_engine.read_file(&name).await else {
+            form.fail(format!("Could not read {}", name));
+            return;
+        };
+        let options = ImageOptions {
+            max_dimension: PROFILE_LOGO_MAX_DIMENSION,
+            ..ImageOptions::default()
+        };
+        match optimize_image(&bytes, content_type_for(&name), options).await {
+            Ok(optimized) => new_logo.set(Some(optimized)),
+            Err(e) => form.fail(e.to_string()),
+        }
+    };
+
+    let save = move |evt: FormEvent| {
+        evt.prevent_default();
+        if !form.validate() {
+            return;
+        }
+        let mut profile = UploaderProfile {
+            ministry_name: ministry_name.trimmed(),
+            logo_tx_id: logo_tx_id.read().clone(),
+            description: description.optional(),
+            website: website.optional(),
+            socials: socials
+                .read()
+                .iter()
+                .filter(|social| !social.url.trim().is_empty())
+                .map(|social| SocialLink {
+                    platform: social.platform.trim().to_string(),
+                    url: social.url.trim().to_string(),
+                })
+                .collect(),
+            payment_address: payment_address.optional(),
+            updated_at: chrono::Utc::now().timestamp(),
+        };
+        if let Err(message) = profile.validate() {
+            form.fail(message);
+            return;
+        }
+
+        form.submit(async move {
+            let service = ProfileService::new();
+            let result = async {
+                let logo = new_logo.read().clone();
+                if let Some(logo) = logo {
+                    let tx_id = service.publish_logo(&logo.content_type, logo.bytes).await?;
+                    logo_tx_id.set(Some(tx_id.clone()));
+                    new_logo.set(None);
+                    profile.logo_tx_id = Some(tx_id);
+                }
+                service.publish(&profile).await
+            }
+            .await;
+            match result {
+                Ok(tx_id) => form.succeed(format!("Profile published ({}).", WalletService::format_address(&tx_id))),
+                Err(e) => form.fail(e.to_string()),
+            }
+        });
+    };
+
+    let input_class = "w-full px-3 py-2 border border-gray-300 rounded-md text-sm";
+
+    rsx! {
+        main {
+            class: "max-w-3xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+
+            h2 { class: "text-2xl font-bold text-gray-900 mb-6", "Your profile" }
+
+            if let Some(address) = owner.as_ref() {
+                form {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 space-y-4",
+                    onsubmit: save,
+
+                    p {
+                        class: "text-sm text-gray-600",
+                        "Shown on everything you upload. Each save publishes a new profile signed by your wallet. "
+                        Link {
+                            class: "text-green-700 underline",
+                            to: Route::UploaderPage { address: address.clone() },
+                            "View your public page"
+                        }
+                    }
+
+                    TextField { field: ministry_name }
+
+                    div {
+                        class: "flex items-center gap-3",
+                        if let Some(logo) = new_logo.read().as_ref() {
+                            span { class: "text-xs text-gray-500", "New logo: {logo.width}×{logo.height}, {logo.bytes.len() / 1024} KB" }
+                        } else if let Some(tx_id) = logo_tx_id.read().as_ref() {
+                            img {
+                                class: "w-12 h-12 rounded-full object-cover",
+                                src: GatewayManager::new().media_url(tx_id),
+                                alt: "Current logo",
+                            }
+                        }
+                        label {
+                            class: "block text-sm font-medium text-gray-700",
+                            "Logo"
+                            input { class: "block text-sm", r#type: "file", accept: "image/*", onchange: select_logo }
+                        }
+                    }
+
+                    TextField { field: description, rows: 4 }
+                    TextField { field: website, input_type: "url", placeholder: "https://" }
+                    div {
+                        TextField { field: payment_address }
+                        p { class: "mt-1 text-xs text-gray-500", "Where “Support this ministry” tips go. Leave empty to turn tips off." }
+                    }
+
+                    div {
+                        class: "space-y-2",
+                        span { class: "block text-sm font-medium text-gray-700", "Social links" }
+                        for (index, social) in socials.read().iter().enumerate() {
+                            div {
+                                key: "{index}",
+                                class: "flex gap-2",
+                                input {
+                                    class: "w-1/3 px-3 py-2 border border-gray-300 rounded-md text-sm",
+                                    placeholder: "YouTube",
+                                    value: "{social.platform}",
+                                    oninput: move |evt| socials.write()[index].platform = evt.value(),
+                                }
+                                input {
+                                    class: input_class,
+                                    r#type: "url",
+                                    placeholder: "https://",
+                                    value: "{social.url}",
+                                    oninput: move |evt| socials.write()[index].url = evt.value(),
+                                }
+                                button {
+                                    class: "text-sm text-gray-500 hover:text-red-700",
+                                    r#type: "button",
+                                    onclick: move |_| {
+                                        socials.write().remove(index);
+                                    },
+                                    "Remove"
+                                }
+                            }
+                        }
+                        button {
+                            class: "text-sm text-green-700 hover:text-green-800 font-medium",
+                            r#type: "button",
+                            onclick: move |_| socials.write().push(SocialLink { platform: String::new(), url: String::new() }),
+                            "+ Add link"
+                        }
+                    }
+
+                    ErrorSummary { form }
+                    FormStatus { form }
+
+                    div {
+                        class: "flex items-center gap-3",
+                        SubmitButton { form, label: "Publish profile", busy_label: "Publishing..." }
+                        if form.is_dirty() || new_logo.read().is_some() {
+                            span { class: "text-sm text-gray-500", "Unsaved changes" }
+                        }
+                    }
+                }
+                MinistryVerificationPanel { address: address.clone(), donation_address: payment_address.optional() }
+            } else {
+                p { class: "text-sm text-gray-600", "Connect a wallet to edit the profile shown on your uploads." }
+            }
+        }
+    }
+}
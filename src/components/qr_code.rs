@@ -0,0 +1,31 @@
+use dioxus::prelude::*;
+use crate::utils::qr::qr_data_url;
+
+/// QR code for `text`, with `label` as its alt text
+///
+/// Always black on white, which scanners read most reliably, even in dark
+/// mode. Shows a note instead when the text is too long to encode.
+#[component]
+pub fn QrCode(
+    text: String,
+    label: String,
+    /// Classes for the image, including its size
+    #[props(default = "w-48 h-48".to_string())]
+    class: String,
+) -> Element {
+    let image = use_memo(use_reactive!(|text| qr_data_url(&text)));
+
+    match image() {
+        Some(src) => rsx! {
+            img {
+                class: "{class} rounded-lg bg-white",
+                src: "{src}",
+                alt: "{label}",
+                draggable: "false",
+            }
+        },
+        None => rsx! {
+            p { class: "text-sm text-gray-500", "This link is too long for a QR code." }
+        },
+    }
+}
@@ -0,0 +1,80 @@
+use dioxus::prelude::*;
+use crate::services::identity::use_local_identity;
+use crate::services::reactions::{use_reaction_counts, Reaction, ReactionService};
+use crate::services::toast::{show_toast, ToastKind};
+use crate::services::wallet::use_wallet_state;
+
+/// Reaction counters for an item
+///
+/// Compact counters are read-only and hidden until someone has reacted;
+/// the full bar lets the viewer add their own reaction, once per
+/// reaction and address.
+#[component]
+pub fn ReactionBar(tx_id: String, #[props(default)] compact: bool) -> Element {
+    let counts = use_reaction_counts(tx_id.clone());
+    let wallet_state = use_wallet_state();
+    let identity = use_local_identity();
+    let mut sending = use_signal(|| None::<Reaction>);
+
+    let counts = counts.read().clone().unwrap_or_default();
+    // The address a new reaction would be signed with
+    let signer = wallet_state
+        .read()
+        .address()
+        .map(str::to_string)
+        .or_else(|| identity.read().as_ref().map(|identity| identity.address.clone()));
+
+    if compact {
+        if counts.total() == 0 {
+            return rsx! {};
+        }
+        return rsx! {
+            div {
+                class: "flex gap-3 mt-2 text-xs text-gray-500",
+                for reaction in Reaction::ALL.into_iter().filter(|reaction| counts.count(*reaction) > 0) {
+                    span {
+                        key: "{reaction.tag_value()}",
+                        title: "{reaction.label()}",
+                        "{reaction.emoji()} {counts.count(reaction)}"
+                    }
+                }
+            }
+        };
+    }
+
+    rsx! {
+        div {
+            class: "flex flex-wrap gap-2",
+            for reaction in Reaction::ALL {
+                {
+                    let reacted = signer.as_deref().is_some_and(|address| counts.has_reacted(reaction, address));
+                    let tx_id = tx_id.clone();
+                    rsx! {
+                        button {
+                            key: "{reaction.tag_value()}",
+                            class: if reacted {
+                                "px-3 py-1 rounded-full text-sm border border-green-600 bg-green-50 text-green-800"
+                            } else {
+                                "px-3 py-1 rounded-full text-sm border border-gray-300 text-gray-700 hover:bg-gray-50 disabled:opacity-50"
+                            },
+                            title: if signer.is_none() { "Connect a wallet or enable an anonymous identity to react" } else { reaction.label() },
+                            aria_pressed: "{reacted}",
+                            disabled: reacted || signer.is_none() || sending.read().is_some(),
+                            onclick: move |_| {
+                                let tx_id = tx_id.clone();
+                                sending.set(Some(reaction));
+                                spawn(async move {
+                                    if let Err(e) = ReactionService::new().react(&tx_id, reaction).await {
+                                        show_toast(ToastKind::Error, e.to_string());
+                                    }
+                                    sending.set(None);
+                                });
+                            },
+                            "{reaction.emoji()} {reaction.label()} · {counts.count(reaction)}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::services::receipts::{ReceiptService, UploadReceipt};
+use crate::utils::display::{format_size, short_address, use_copy_feedback};
+
+fn format_timestamp(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|date| date.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+fn print_page() {
+    if let Some(window) = web_sys::window() {
+        let _ = window.print();
+    }
+}
+
+/// Receipts of everything uploaded from this browser, for a church's own
+/// records
+#[component]
+pub fn ReceiptHistory() -> Element {
+    let mut receipts = use_signal(|| None::<Result<Vec<UploadReceipt>, String>>);
+    let mut status = use_signal(|| None::<Result<String, String>>);
+
+    use_future(move || async move {
+        let result = ReceiptService::all().await.map_err(|e| e.to_string());
+        receipts.set(Some(result));
+        // Items get bundled after the upload finishes, so fill in what
+        // the gateway knows by now
+        let Some(Ok(mut loaded)) = receipts.peek().clone() else {
+            return;
+        };
+        match ReceiptService::fill_bundle_ids(&mut loaded).await {
+            Ok(0) => {}
+            Ok(_) => receipts.set(Some(Ok(loaded))),
+            Err(e) => log::warn!("Could not update receipts: {}", e),
+        }
+    });
+
+    let export = move |_| {
+        if let Some(Ok(all)) = receipts.read().as_ref() {
+            let result = ReceiptService::export(all).map(|()| format!("Exported {} receipts", all.len()));
+            status.set(Some(result));
+        }
+    };
+
+    rsx! {
+        main {
+            class: "max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                class: "flex items-center justify-between",
+                h2 { class: "text-2xl font-bold text-gray-900", "Upload Receipts" }
+                if matches!(receipts.read().as_ref(), Some(Ok(all)) if !all.is_empty()) {
+                    button {
+                        class: "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        onclick: export,
+                        "Export all (JSON)"
+                    }
+                }
+            }
+            p {
+                class: "text-sm text-gray-600",
+                "A receipt is kept for every item you upload from this browser. Each one records the item's ID, tags and your wallet's signature, so it can be checked against the archive later."
+            }
+            match status.read().as_ref() {
+                Some(Ok(message)) => rsx! { p { class: "text-sm text-green-700", "{message}" } },
+                Some(Err(error)) => rsx! { p { class: "text-sm text-red-700", "{error}" } },
+                None => rsx! {},
+            }
+
+            match receipts.read().as_ref() {
+                None => rsx! { p { class: "text-gray-500", "Loading receipts…" } },
+                Some(Err(error)) => rsx! { p { class: "text-red-700", "Could not load receipts: {error}" } },
+                Some(Ok(all)) if all.is_empty() => rsx! {
+                    div {
+                        class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-center text-gray-600",
+                        "No receipts yet. They appear here after you "
+                        Link { to: Route::Upload {}, class: "text-green-700 underline", "upload an item" }
+                        "."
+                    }
+                },
+                Some(Ok(all)) => rsx! {
+                    div {
+                        class: "bg-white rounded-xl shadow-sm border border-green-200 divide-y divide-gray-100",
+                        for receipt in all.iter() {
+                            Link {
+                                key: "{receipt.tx_id}",
+                                to: Route::ReceiptView { tx_id: receipt.tx_id.clone() },
+                                class: "flex items-center justify-between gap-4 p-4 hover:bg-green-50",
+                                div {
+                                    class: "min-w-0",
+                                    p { class: "font-medium text-gray-900 truncate", "{receipt.title}" }
+                                    p { class: "text-xs text-gray-500 font-mono truncate", "{receipt.tx_id}" }
+                                }
+                                div {
+                                    class: "text-right text-sm text-gray-600 shrink-0",
+                                    p { "{format_timestamp(receipt.submitted_at)}" }
+                                    p {
+                                        class: "text-xs",
+                                        if receipt.bundle_id.is_some() { "Bundled" } else { "Awaiting bundle" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// One receipt, laid out for printing
+#[component]
+pub fn ReceiptView(tx_id: String) -> Element {
+    let lookup_id = tx_id.clone();
+    let receipt = use_resource(use_reactive!(|lookup_id| async move {
+        ReceiptService::get(&lookup_id).await.map_err(|e| e.to_string())
+    }));
+    let copy = use_copy_feedback();
+    let mut download_error = use_signal(|| None::<String>);
+
+    let state = receipt.read().clone();
+    let receipt = match state {
+        None => return rsx! { main { class: "max-w-3xl mx-auto px-4 py-8 text-gray-500", "Loading receipt…" } },
+        Some(Err(error)) => return rsx! { main { class: "max-w-3xl mx-auto px-4 py-8 text-red-700", "Could not load the receipt: {error}" } },
+        Some(Ok(None)) => return rsx! {
+            main {
+                class: "max-w-3xl mx-auto px-4 py-8 text-gray-600",
+                "There is no receipt for {tx_id} in this browser. Receipts are only kept where the item was uploaded."
+            }
+        },
+        Some(Ok(Some(receipt))) => receipt,
+    };
+
+    let download = {
+        let receipt = receipt.clone();
+        move |_| download_error.set(receipt.download().err())
+    };
+    let json = String::from_utf8(receipt.to_json()).unwrap_or_default();
+    let row = "grid grid-cols-3 gap-4 py-2 text-sm";
+
+    rsx! {
+        main {
+            class: "max-w-3xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                class: "flex items-center justify-between print:hidden",
+                Link { to: Route::ReceiptHistory {}, class: "text-sm text-green-700 hover:text-green-800", "← All receipts" }
+                div {
+                    class: "flex gap-2",
+                    button {
+                        class: "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        onclick: download,
+                        "Download JSON"
+                    }
+                    button {
+                        class: "bg-white hover:bg-green-50 text-green-700 border border-green-300 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        onclick: move |_| copy.copy(json.clone()),
+                        if copy.copied() { "Copied" } else { "Copy JSON" }
+                    }
+                    button {
+                        class: "bg-white hover:bg-green-50 text-green-700 border border-green-300 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        onclick: move |_| print_page(),
+                        "Print"
+                    }
+                }
+            }
+            if let Some(error) = download_error() {
+                p { class: "text-sm text-red-700 print:hidden", "{error}" }
+            }
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 print:shadow-none print:border-0",
+                h2 { class: "text-2xl font-bold text-gray-900", "Upload Receipt" }
+                p { class: "text-gray-600 mb-6", "Faithful Archive · {receipt.title}" }
+
+                div {
+                    class: "divide-y divide-gray-100",
+                    div { class: row, span { class: "text-gray-500", "Item ID" } span { class: "col-span-2 font-mono break-all", "{receipt.tx_id}" } }
+                    div {
+                        class: row,
+                        span { class: "text-gray-500", "Bundle ID" }
+                        span {
+                            class: "col-span-2 font-mono break-all",
+                            {receipt.bundle_id.clone().unwrap_or_else(|| "Not bundled yet".to_string())}
+                        }
+                    }
+                    div { class: row, span { class: "text-gray-500", "Submitted" } span { class: "col-span-2", "{format_timestamp(receipt.submitted_at)}" } }
+                    div {
+                        class: row,
+                        span { class: "text-gray-500", "Uploaded by" }
+                        span { class: "col-span-2 font-mono break-all", title: "{receipt.owner}", "{short_address(&receipt.owner)}" }
+                    }
+                    div { class: row, span { class: "text-gray-500", "Size" } span { class: "col-span-2", "{format_size(receipt.size_bytes)}" } }
+                    div { class: row, span { class: "text-gray-500", "Bundler" } span { class: "col-span-2 break-all", "{receipt.bundler}" } }
+                    div { class: row, span { class: "text-gray-500", "Permanent link" } span { class: "col-span-2 break-all", "{receipt.permanent_url}" } }
+                }
+
+                h3 { class: "font-semibold text-gray-900 mt-6 mb-2", "Tags" }
+                div {
+                    class: "divide-y divide-gray-100",
+                    for (i, tag) in receipt.tags.iter().enumerate() {
+                        div {
+                            key: "{i}",
+                            class: row,
+                            span { class: "text-gray-500 break-all", "{tag.name}" }
+                            span { class: "col-span-2 break-all", "{tag.value}" }
+                        }
+                    }
+                }
+
+                h3 { class: "font-semibold text-gray-900 mt-6 mb-2", "Signature (type {receipt.signature_type})" }
+                p { class: "font-mono text-xs text-gray-700 break-all", "{receipt.signature}" }
+            }
+        }
+    }
+}
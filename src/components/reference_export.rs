@@ -0,0 +1,51 @@
+use dioxus::prelude::*;
+use crate::utils::citation::ReferenceFormat;
+use crate::utils::metadata_export::MetadataFormat;
+
+/// Row of RIS/BibTeX buttons for exporting to a reference manager
+#[component]
+pub fn ReferenceExportButtons(
+    label: &'static str,
+    #[props(default)] busy: bool,
+    on_export: EventHandler<ReferenceFormat>,
+) -> Element {
+    rsx! {
+        div {
+            class: "flex items-center gap-2 text-sm",
+            span { class: "text-gray-600", "{label}" }
+            for format in ReferenceFormat::ALL {
+                button {
+                    key: "{format.extension()}",
+                    class: "px-3 py-1 rounded border border-green-600 text-green-700 hover:bg-green-50 text-xs font-medium transition-colors disabled:opacity-50",
+                    disabled: busy,
+                    onclick: move |_| on_export.call(format),
+                    "{format.display_name()}"
+                }
+            }
+        }
+    }
+}
+
+/// Row of CSV/JSON buttons for exporting item metadata
+#[component]
+pub fn MetadataExportButtons(
+    label: &'static str,
+    #[props(default)] busy: bool,
+    on_export: EventHandler<MetadataFormat>,
+) -> Element {
+    rsx! {
+        div {
+            class: "flex items-center gap-2 text-sm",
+            span { class: "text-gray-600", "{label}" }
+            for format in MetadataFormat::ALL {
+                button {
+                    key: "{format.extension()}",
+                    class: "px-3 py-1 rounded border border-green-600 text-green-700 hover:bg-green-50 text-xs font-medium transition-colors disabled:opacity-50",
+                    disabled: busy,
+                    onclick: move |_| on_export.call(format),
+                    "{format.display_name()}"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use dioxus::prelude::*;
+use crate::models::{ReviewChecklist, ReviewCriterion, MAX_REVIEW_SCORE};
+
+/// Scores for each content guideline, published with a moderator's
+/// decision
+#[component]
+pub fn ReviewChecklistForm(checklist: Signal<ReviewChecklist>) -> Element {
+    rsx! {
+        fieldset {
+            class: "rounded-lg border border-gray-200 p-3 space-y-2",
+            legend { class: "px-1 text-sm font-medium text-gray-700", "Guideline checklist" }
+            p { class: "text-xs text-gray-500", "Score each from 1 (fails the guideline) to {MAX_REVIEW_SCORE} (fully meets it)." }
+            for criterion in ReviewCriterion::ALL {
+                div {
+                    key: "{criterion.tag_name()}",
+                    class: "flex flex-wrap items-center justify-between gap-2 text-sm",
+                    div {
+                        span { class: "font-medium text-gray-900", "{criterion.display_name()}" }
+                        span { class: "block text-xs text-gray-500", "{criterion.guidance()}" }
+                    }
+                    div {
+                        class: "flex gap-1",
+                        role: "radiogroup",
+                        "aria-label": "{criterion.display_name()}",
+                        for score in 1..=MAX_REVIEW_SCORE {
+                            button {
+                                key: "{score}",
+                                r#type: "button",
+                                role: "radio",
+                                "aria-checked": checklist.read().score(criterion) == Some(score),
+                                class: if checklist.read().score(criterion) == Some(score) {
+                                    "w-8 h-8 rounded border border-green-600 bg-green-600 text-white"
+                                } else {
+                                    "w-8 h-8 rounded border border-gray-300 text-gray-700 hover:bg-gray-50"
+                                },
+                                onclick: move |_| checklist.write().set_score(criterion, Some(score)),
+                                "{score}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,628 @@
+use dioxus::prelude::*;
+use crate::components::address_avatar::AddressAvatar;
+use crate::components::forms::{email, required, transaction_id, use_field, use_form, ErrorSummary, FormStatus, SubmitButton, TextField};
+use crate::components::review_checklist::ReviewChecklistForm;
+use crate::models::{ClaimReceipt, ClaimStatus, ReviewChecklist, RightsClaim};
+use crate::services::hide_registry::{is_moderator, use_hidden_ids, use_hide_entries, HideAction, HideRegistry};
+use crate::services::review_assignments::{
+    active_assignment, assignments_enabled, claim_review, refresh_assignments, release_review, use_queue_counts, STALE_CLAIM_SECS,
+};
+use crate::services::revisions::{use_revocations, RevisionRecord};
+use crate::services::rights_claims::{saved_receipts, ClaimSummary, ClaimTracking, RightsClaimService};
+use crate::services::wallet::{use_wallet_state, WalletService};
+
+/// Rights-claim intake form and claim tracker
+///
+/// Claims are encrypted to the moderation team before publishing; the
+/// claimant keeps a tracking number to follow moderator acknowledgments.
+#[component]
+pub fn RightsClaimForm() -> Element {
+    let target_tx_id = use_field("Item ID", vec![required(), transaction_id()]);
+    let claimant_name = use_field("Full name", vec![required()]);
+    let claimant_email = use_field("Email", vec![required(), email()]);
+    let organization = use_field("Organization (optional)", vec![]);
+    let work_description = use_field("Copyrighted work", vec![required()]);
+    let infringement_details = use_field("How it is infringed", vec![required()]);
+    let signature = use_field("Signature (type your full name)", vec![required()]);
+    let mut form = use_form(&[
+        target_tx_id,
+        claimant_name,
+        claimant_email,
+        organization,
+        work_description,
+        infringement_details,
+        signature,
+    ]);
+    let mut good_faith = use_signal(|| false);
+    let mut accuracy = use_signal(|| false);
+    let mut receipt = use_signal(|| None::<ClaimReceipt>);
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        if !form.validate() {
+            return;
+        }
+        let claim = RightsClaim {
+            tracking_number: RightsClaim::generate_tracking_number(),
+            target_tx_id: target_tx_id.trimmed(),
+            claimant_name: claimant_name.trimmed(),
+            claimant_email: claimant_email.trimmed(),
+            organization: organization.optional(),
+            work_description: work_description.value(),
+            infringement_details: infringement_details.value(),
+            good_faith_statement: *good_faith.read(),
+            accuracy_statement: *accuracy.read(),
+            signature: signature.trimmed(),
+            submitted_at: chrono::Utc::now().timestamp(),
+        };
+
+        if let Err(message) = claim.validate() {
+            form.fail(message);
+            return;
+        }
+
+        form.submit(async move {
+            match RightsClaimService::new().submit(&claim).await {
+                Ok(result) => {
+                    receipt.set(Some(result));
+                    form.succeed("");
+                }
+                Err(e) => form.fail(e.to_string()),
+            }
+        });
+    };
+
+    rsx! {
+        main {
+            class: "max-w-3xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-8",
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-8",
+
+                h2 {
+                    class: "text-2xl font-bold text-gray-900 mb-2",
+                    "Rights Claim"
+                }
+                p {
+                    class: "text-gray-600 mb-6",
+                    "If content on Faithful Archive infringes a work you own, submit a claim. Your details are encrypted so only our moderation team can read them."
+                }
+
+                if let Some(receipt) = receipt.read().as_ref() {
+                    div {
+                        class: "bg-green-50 border border-green-200 rounded-lg p-6",
+                        h3 { class: "text-lg font-semibold text-green-900 mb-2", "Claim submitted" }
+                        p { class: "text-sm text-green-800 mb-4", "Keep this tracking number to check the status of your claim." }
+                        div { class: "text-3xl font-mono font-bold text-green-900 mb-2", "{receipt.tracking_number}" }
+                        div {
+                            class: "text-xs text-gray-600 font-mono",
+                            title: "{receipt.claim_tx_id}",
+                            "Claim record: {WalletService::format_address(&receipt.claim_tx_id)}"
+                        }
+                    }
+                } else {
+                    form {
+                        class: "space-y-4",
+                        onsubmit: submit,
+
+                        TextField { field: target_tx_id, placeholder: "43-character transaction ID", class: "font-mono" }
+                        div {
+                            class: "grid md:grid-cols-2 gap-4",
+                            TextField { field: claimant_name }
+                            TextField { field: claimant_email, input_type: "email" }
+                        }
+                        TextField { field: organization }
+                        TextField { field: work_description, rows: 3, placeholder: "Identify the work you own or represent" }
+                        TextField { field: infringement_details, rows: 4 }
+
+                        label {
+                            class: "flex items-start gap-2 text-sm text-gray-700",
+                            input { r#type: "checkbox", class: "mt-1", checked: *good_faith.read(), onchange: move |evt| good_faith.set(evt.checked()) }
+                            "I have a good-faith belief that the use described is not authorized by the copyright owner, its agent, or the law."
+                        }
+                        label {
+                            class: "flex items-start gap-2 text-sm text-gray-700",
+                            input { r#type: "checkbox", class: "mt-1", checked: *accuracy.read(), onchange: move |evt| accuracy.set(evt.checked()) }
+                            "The information in this claim is accurate, and I am the owner or authorized to act on the owner's behalf."
+                        }
+
+                        TextField { field: signature }
+
+                        ErrorSummary { form }
+                        FormStatus { form }
+
+                        SubmitButton { form, label: "Submit Claim", busy_label: "Submitting..." }
+                    }
+                }
+            }
+
+            ClaimTracker {}
+        }
+    }
+}
+
+/// Look up a claim's status and signed acknowledgments by tracking number
+#[component]
+fn ClaimTracker() -> Element {
+    let mut tracking_number = use_signal(|| {
+        saved_receipts().last().map(|receipt| receipt.tracking_number.clone()).unwrap_or_default()
+    });
+    let mut tracking = use_signal(|| None::<ClaimTracking>);
+    let mut is_loading = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let lookup = move |_| {
+        let number = tracking_number.read().trim().to_string();
+        if number.is_empty() {
+            return;
+        }
+
+        spawn(async move {
+            is_loading.set(true);
+            error.set(None);
+            tracking.set(None);
+
+            match RightsClaimService::new().track(&number).await {
+                Ok(result) => tracking.set(Some(result)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+
+            is_loading.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-8",
+
+            h3 {
+                class: "text-lg font-semibold text-gray-900 mb-4",
+                "Track a Claim"
+            }
+
+            div {
+                class: "flex gap-3",
+                input {
+                    class: "flex-1 px-3 py-2 border border-gray-300 rounded-md font-mono text-sm focus:outline-none focus:ring-2 focus:ring-green-500",
+                    placeholder: "FA-XXXXXXXX",
+                    value: "{tracking_number}",
+                    oninput: move |evt| tracking_number.set(evt.value()),
+                }
+                button {
+                    class: "border border-green-600 text-green-700 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                    disabled: *is_loading.read(),
+                    onclick: lookup,
+                    if *is_loading.read() { "Looking up..." } else { "Check Status" }
+                }
+            }
+
+            if let Some(message) = error.read().as_ref() {
+                div { class: "mt-4 text-sm text-red-700", "{message}" }
+            }
+
+            if let Some(tracking) = tracking.read().as_ref() {
+                div {
+                    class: "mt-6",
+                    div {
+                        class: "flex items-center gap-3 mb-4",
+                        span { class: "font-mono text-gray-900", "{tracking.summary.tracking_number}" }
+                        ClaimStatusBadge { status: tracking.summary.status }
+                    }
+
+                    if tracking.acknowledgments.is_empty() {
+                        p { class: "text-sm text-gray-600", "Your claim has been received and is waiting for a moderator." }
+                    }
+                    ul {
+                        class: "space-y-2",
+                        for ack in tracking.acknowledgments.iter() {
+                            li {
+                                key: "{ack.tx_id}",
+                                class: "text-sm text-gray-700",
+                                "{ack.status.display_name()} — signed by "
+                                code { class: "font-mono", "{WalletService::format_address(&ack.moderator)}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ClaimStatusBadge(status: ClaimStatus) -> Element {
+    let class = match status {
+        ClaimStatus::Submitted => "bg-gray-100 text-gray-800",
+        ClaimStatus::UnderReview => "bg-yellow-100 text-yellow-800",
+        ClaimStatus::Validated => "bg-green-100 text-green-800",
+        ClaimStatus::Rejected => "bg-red-100 text-red-800",
+    };
+
+    rsx! {
+        span {
+            class: "inline-flex items-center px-2 py-1 rounded-full text-xs font-medium {class}",
+            "{status.display_name()}"
+        }
+    }
+}
+
+/// Moderator queue of rights claims and the hide-registry audit trail
+#[component]
+pub fn ClaimReview() -> Element {
+    let wallet_state = use_wallet_state();
+    let hide_entries = use_hide_entries();
+    let hidden_ids = use_hidden_ids();
+    let revocations = use_revocations();
+    let mut claims = use_signal(Vec::<ClaimSummary>::new);
+    let mut error = use_signal(|| None::<String>);
+
+    let is_moderator_wallet = wallet_state
+        .read()
+        .address()
+        .is_some_and(is_moderator);
+
+    let mut reload = move || {
+        spawn(async move {
+            match RightsClaimService::new().list_claims().await {
+                Ok(result) => claims.set(result),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            if assignments_enabled() {
+                if let Err(e) = refresh_assignments().await {
+                    error.set(Some(e.to_string()));
+                }
+            }
+        });
+    };
+
+    use_effect(reload);
+
+    if !is_moderator_wallet {
+        return rsx! {
+            main {
+                class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+                div {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-gray-600",
+                    "Connect a moderation team wallet to review rights claims."
+                }
+            }
+        };
+    }
+
+    rsx! {
+        main {
+            class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-8",
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-6",
+                h2 { class: "text-2xl font-bold text-gray-900 mb-4", "Rights Claims" }
+
+                if let Some(message) = error.read().as_ref() {
+                    div { class: "mb-4 text-sm text-red-700", "{message}" }
+                }
+
+                if assignments_enabled() {
+                    ReviewWorkload {}
+                }
+
+                div {
+                    class: "divide-y divide-gray-100",
+                    for claim in claims.read().iter() {
+                        ClaimReviewRow {
+                            key: "{claim.claim_tx_id}",
+                            claim: claim.clone(),
+                            on_decided: move |_| reload(),
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-6",
+                h3 { class: "text-lg font-semibold text-gray-900 mb-1", "Withdrawn by Uploaders" }
+                p { class: "text-sm text-gray-500 mb-4", "Withdrawn items stay listed until a moderator removes them." }
+
+                ul {
+                    class: "divide-y divide-gray-100 text-sm",
+                    for record in revocations.read().iter().filter(|record| !hidden_ids.read().contains(&record.target_id)) {
+                        WithdrawalRow { key: "{record.record_tx_id}", record: record.clone() }
+                    }
+                }
+            }
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-6",
+                h3 { class: "text-lg font-semibold text-gray-900 mb-4", "Hide Registry Audit Trail" }
+
+                ul {
+                    class: "divide-y divide-gray-100 text-sm",
+                    for entry in hide_entries.read().iter() {
+                        li {
+                            key: "{entry.entry_tx_id}",
+                            class: "py-2 flex flex-wrap gap-x-3 text-gray-700",
+                            span {
+                                class: if entry.action == HideAction::Hide { "font-medium text-red-700" } else { "font-medium text-green-700" },
+                                "{entry.action.tag_value()}"
+                            }
+                            code { class: "font-mono", title: "{entry.target_id}", "{WalletService::format_address(&entry.target_id)}" }
+                            span { "{entry.reason}" }
+                            if let Some(claim_id) = entry.claim_id.as_ref() {
+                                span { class: "font-mono text-gray-500", "{claim_id}" }
+                            }
+                            span {
+                                class: "inline-flex items-center gap-1 text-gray-500",
+                                "by"
+                                AddressAvatar { address: entry.moderator.clone(), size: "w-4 h-4" }
+                                span { title: "{entry.moderator}", "{WalletService::format_address(&entry.moderator)}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An uploader's withdrawal, with a button to de-list the item
+#[component]
+fn WithdrawalRow(record: RevisionRecord) -> Element {
+    let mut is_working = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let note = if record.reason.is_empty() {
+        "Withdrawn by the uploader".to_string()
+    } else {
+        format!("Withdrawn by the uploader: {}", record.reason)
+    };
+    let target_id = record.target_id.clone();
+    let claimed_by_other = use_claimed_by_other(&record.target_id);
+    let remove = move |_| {
+        let target_id = target_id.clone();
+        let note = note.clone();
+        spawn(async move {
+            is_working.set(true);
+            error.set(None);
+            match HideRegistry::new().publish(&target_id, HideAction::Hide, &note, None, None).await {
+                Ok(_) => release_after_decision(&target_id).await,
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            is_working.set(false);
+        });
+    };
+
+    rsx! {
+        li {
+            class: "py-2 flex flex-wrap items-center gap-x-3 gap-y-1 text-gray-700",
+            code { class: "font-mono", title: "{record.target_id}", "{WalletService::format_address(&record.target_id)}" }
+            span { class: "flex-1", if record.reason.is_empty() { "No reason given" } else { "{record.reason}" } }
+            span {
+                class: "inline-flex items-center gap-1 text-gray-500",
+                "by"
+                AddressAvatar { address: record.owner.clone(), size: "w-4 h-4" }
+                span { title: "{record.owner}", "{WalletService::format_address(&record.owner)}" }
+            }
+            ReviewClaimControls { subject: record.target_id.clone() }
+            button {
+                class: "text-sm border border-red-300 text-red-700 px-3 py-1 rounded hover:bg-red-50 disabled:opacity-50",
+                disabled: *is_working.read() || claimed_by_other,
+                onclick: remove,
+                if *is_working.read() { "Removing..." } else { "Remove from archive" }
+            }
+            if let Some(message) = error.read().as_ref() {
+                span { class: "w-full text-red-700", "{message}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn ClaimReviewRow(claim: ClaimSummary, on_decided: EventHandler<()>) -> Element {
+    let mut note = use_signal(String::new);
+    let mut is_working = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let checklist = use_signal(ReviewChecklist::default);
+    let is_open = matches!(claim.status, ClaimStatus::Submitted | ClaimStatus::UnderReview);
+    let claimed_by_other = use_claimed_by_other(&claim.tracking_number);
+    let is_busy = *is_working.read() || claimed_by_other;
+
+    let mut decide = move |status: ClaimStatus, claim: ClaimSummary| {
+        // Upholding or rejecting a claim needs the item scored first
+        let review = (status != ClaimStatus::UnderReview).then(|| checklist.read().clone());
+        if let Some(Err(message)) = review.as_ref().map(ReviewChecklist::validate) {
+            error.set(Some(message));
+            return;
+        }
+
+        spawn(async move {
+            is_working.set(true);
+            error.set(None);
+
+            let note_value = note.read().trim().to_string();
+            let service = RightsClaimService::new();
+            let result = match (status, review.as_ref()) {
+                (ClaimStatus::Validated, Some(review)) => service.validate(&claim, &note_value, review).await,
+                _ => service
+                    .acknowledge(&claim, status, &note_value, review.as_ref())
+                    .await
+                    .map(|_| ()),
+            };
+
+            match result {
+                Ok(()) => {
+                    if status != ClaimStatus::UnderReview {
+                        release_after_decision(&claim.tracking_number).await;
+                    }
+                    on_decided.call(())
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            is_working.set(false);
+        });
+    };
+
+    let acknowledge_claim = claim.clone();
+    let validate_claim = claim.clone();
+    let reject_claim = claim.clone();
+
+    rsx! {
+        div {
+            class: "py-4 space-y-2",
+
+            div {
+                class: "flex items-center gap-3",
+                span { class: "font-mono text-gray-900", "{claim.tracking_number}" }
+                ClaimStatusBadge { status: claim.status }
+                code {
+                    class: "text-xs font-mono text-gray-500",
+                    title: "{claim.target_tx_id}",
+                    "Item {WalletService::format_address(&claim.target_tx_id)}"
+                }
+                if is_open {
+                    ReviewClaimControls { subject: claim.tracking_number.clone() }
+                }
+            }
+
+            if is_open {
+                ReviewChecklistForm { checklist }
+                div {
+                    class: "flex flex-col md:flex-row gap-2",
+                    input {
+                        class: "flex-1 px-3 py-1 border border-gray-300 rounded-md text-sm",
+                        placeholder: "Decision note (published with the signed acknowledgment)",
+                        value: "{note}",
+                        oninput: move |evt| note.set(evt.value()),
+                    }
+                    if claim.status == ClaimStatus::Submitted {
+                        button {
+                            class: "text-sm border border-gray-300 px-3 py-1 rounded hover:bg-gray-50",
+                            disabled: is_busy,
+                            onclick: move |_| decide(ClaimStatus::UnderReview, acknowledge_claim.clone()),
+                            "Acknowledge"
+                        }
+                    }
+                    button {
+                        class: "text-sm bg-green-600 hover:bg-green-700 text-white px-3 py-1 rounded",
+                        disabled: is_busy,
+                        onclick: move |_| decide(ClaimStatus::Validated, validate_claim.clone()),
+                        "Validate & De-list"
+                    }
+                    button {
+                        class: "text-sm text-red-600 border border-red-200 px-3 py-1 rounded hover:bg-red-50",
+                        disabled: is_busy,
+                        onclick: move |_| decide(ClaimStatus::Rejected, reject_claim.clone()),
+                        "Reject"
+                    }
+                }
+            }
+
+            if let Some(message) = error.read().as_ref() {
+                div { class: "text-sm text-red-700", "{message}" }
+            }
+        }
+    }
+}
+
+/// Whether another moderator holds a live claim on `subject`
+fn use_claimed_by_other(subject: &str) -> bool {
+    let wallet_state = use_wallet_state();
+    let own_address = wallet_state.read().address().map(str::to_string);
+    active_assignment(subject).is_some_and(|assignment| Some(assignment.reviewer) != own_address)
+}
+
+/// Release the connected moderator's claim once they've decided
+async fn release_after_decision(subject: &str) {
+    if !assignments_enabled() || active_assignment(subject).is_none() {
+        return;
+    }
+    if let Err(e) = release_review(subject).await {
+        log::warn!("Could not release review of {}: {}", subject, e);
+    }
+}
+
+/// How many queue items each moderator is holding
+#[component]
+fn ReviewWorkload() -> Element {
+    let counts = use_queue_counts();
+
+    rsx! {
+        div {
+            class: "mb-4 p-3 rounded-lg bg-gray-50 text-sm text-gray-700",
+            div {
+                class: "flex flex-wrap items-center gap-x-4 gap-y-2",
+                span { class: "font-medium", "Being reviewed:" }
+                if counts.read().is_empty() {
+                    span { class: "text-gray-500", "nothing claimed" }
+                }
+                for (reviewer, count) in counts.read().iter() {
+                    span {
+                        key: "{reviewer}",
+                        class: "inline-flex items-center gap-1",
+                        AddressAvatar { address: reviewer.clone(), size: "w-4 h-4" }
+                        span { title: "{reviewer}", "{WalletService::format_address(reviewer)}" }
+                        span { class: "font-medium", "{count}" }
+                    }
+                }
+            }
+            p { class: "text-xs text-gray-500 mt-1", "Claims untouched for {STALE_CLAIM_SECS / 3600} hours lapse and can be taken over." }
+        }
+    }
+}
+
+/// Who is reviewing `subject`, with claim and release buttons
+#[component]
+fn ReviewClaimControls(subject: String) -> Element {
+    let wallet_state = use_wallet_state();
+    let mut is_working = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    if !assignments_enabled() {
+        return rsx! {};
+    }
+
+    let assignment = active_assignment(&subject);
+    let is_mine = assignment
+        .as_ref()
+        .is_some_and(|assignment| wallet_state.read().address() == Some(assignment.reviewer.as_str()));
+
+    let toggle = move |_| {
+        let subject = subject.clone();
+        spawn(async move {
+            is_working.set(true);
+            error.set(None);
+            let result = if is_mine { release_review(&subject).await } else { claim_review(&subject).await.map(|_| ()) };
+            if let Err(e) = result {
+                error.set(Some(e.to_string()));
+            }
+            is_working.set(false);
+        });
+    };
+
+    rsx! {
+        span {
+            class: "inline-flex items-center gap-2 text-xs",
+            match assignment.as_ref() {
+                Some(_) if is_mine => rsx! { span { class: "text-green-700", "You're reviewing this" } },
+                Some(assignment) => rsx! {
+                    span {
+                        class: "inline-flex items-center gap-1 text-amber-700",
+                        "Claimed by"
+                        AddressAvatar { address: assignment.reviewer.clone(), size: "w-4 h-4" }
+                        span { title: "{assignment.reviewer}", "{WalletService::format_address(&assignment.reviewer)}" }
+                    }
+                },
+                None => rsx! {},
+            }
+            if assignment.is_none() || is_mine {
+                button {
+                    class: "border border-gray-300 px-2 py-0.5 rounded hover:bg-gray-50 disabled:opacity-50",
+                    disabled: *is_working.read(),
+                    onclick: toggle,
+                    if is_mine { "Release" } else { "Claim" }
+                }
+            }
+            if let Some(message) = error.read().as_ref() {
+                span { class: "text-red-700", "{message}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use dioxus::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::app::Route;
+use crate::models::SearchQuery;
+use crate::services::search::use_search;
+use crate::services::shortcuts::{use_keyboard_shortcuts, Shortcut};
+
+const SEARCH_INPUT_ID: &str = "site-search";
+
+fn focus_search() {
+    let input = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(SEARCH_INPUT_ID))
+        .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok());
+    if let Some(input) = input {
+        let _ = input.focus();
+    }
+}
+
+/// Header search box with instant, ranked results from the local index
+#[component]
+pub fn SearchBox() -> Element {
+    let mut query = use_signal(String::new);
+    let mut is_open = use_signal(|| false);
+    let results = use_search(query);
+    let navigator = use_navigator();
+    use_keyboard_shortcuts(|| vec![Shortcut::new("/", "Search", focus_search)]);
+
+    rsx! {
+        form {
+            class: "relative w-full md:w-72",
+            onsubmit: move |evt| {
+                evt.prevent_default();
+                is_open.set(false);
+                navigator.push(Route::SearchPage { query: SearchQuery::new(&query.read()) });
+            },
+
+            input {
+                id: SEARCH_INPUT_ID,
+                class: "w-full px-3 py-2 border border-gray-300 rounded-lg text-sm focus:outline-none focus:ring-2 focus:ring-green-500",
+                r#type: "search",
+                aria_label: "Search",
+                placeholder: "Search titles, speakers, scripture...",
+                value: "{query}",
+                oninput: move |evt| {
+                    query.set(evt.value());
+                    is_open.set(true);
+                },
+                onfocus: move |_| is_open.set(true),
+                onblur: move |_| is_open.set(false),
+            }
+
+            if *is_open.read() && !query.read().trim().is_empty() {
+                div {
+                    class: "absolute z-20 mt-1 w-full bg-white rounded-lg shadow-lg border border-gray-200 max-h-96 overflow-y-auto",
+                    // Keep the input focused so a result click lands before blur closes the list
+                    onmousedown: move |evt| evt.prevent_default(),
+
+                    if results.read().is_empty() {
+                        div { class: "px-4 py-3 text-sm text-gray-500", "No results" }
+                    }
+
+                    for result in results.read().iter() {
+                        Link {
+                            key: "{result.metadata.tx_id}",
+                            class: "block px-4 py-3 hover:bg-green-50 border-b border-gray-100 last:border-0",
+                            to: Route::ContentDetail { tx_id: result.metadata.tx_id.clone() },
+                            onclick: move |_| is_open.set(false),
+
+                            div { class: "text-sm font-medium text-gray-900", "{result.metadata.title}" }
+                            if let Some(speaker) = result.metadata.speaker.as_ref() {
+                                div { class: "text-xs text-gray-600", "{speaker}" }
+                            }
+                            if !result.metadata.scripture_refs.is_empty() {
+                                div { class: "text-xs text-green-700", "{result.metadata.scripture_refs.join(\", \")}" }
+                            }
+                        }
+                    }
+
+                    if !results.read().is_empty() {
+                        Link {
+                            class: "block px-4 py-2 text-sm text-center text-green-700 hover:bg-green-50",
+                            to: Route::SearchPage { query: SearchQuery::new(&query.read()) },
+                            onclick: move |_| is_open.set(false),
+                            "See all results"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
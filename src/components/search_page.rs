@@ -0,0 +1,141 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::browse::{BrowseCard, FilterPanel};
+use crate::components::reference_export::ReferenceExportButtons;
+use crate::models::SearchQuery;
+use crate::services::analytics::{record_usage, UsageEvent};
+use crate::services::search::use_search_query;
+use crate::utils::citation::ReferenceFormat;
+
+/// Results shown per page
+const RESULTS_PER_PAGE: usize = 20;
+
+/// Full search results with filters; all state lives in the URL
+#[component]
+pub fn SearchPage(query: SearchQuery) -> Element {
+    let navigator = use_navigator();
+    let results = use_search_query(query.clone());
+    let mut text = use_signal(|| query.text.clone());
+
+    // Follow the URL when it changes through history navigation
+    let url_text = query.text.clone();
+    use_effect(use_reactive!(|url_text| text.set(url_text)));
+
+    // Count each new search, not filter or page changes
+    let searched = query.text.clone();
+    use_effect(use_reactive!(|searched| {
+        if !searched.is_empty() {
+            record_usage(UsageEvent::Search);
+        }
+    }));
+
+    let total = results.read().len();
+    let page_count = total.div_ceil(RESULTS_PER_PAGE).max(1);
+    let page = query.page.min(page_count - 1);
+    let start = page * RESULTS_PER_PAGE;
+
+    let submit_query = query.clone();
+    let filter_query = query.clone();
+
+    rsx! {
+        main {
+            class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+
+            form {
+                class: "flex gap-2 mb-6",
+                onsubmit: move |evt| {
+                    evt.prevent_default();
+                    let next = SearchQuery {
+                        text: text.read().trim().to_string(),
+                        filters: submit_query.filters.clone(),
+                        page: 0,
+                    };
+                    navigator.push(Route::SearchPage { query: next });
+                },
+                input {
+                    class: "flex-1 px-4 py-2 border border-gray-300 rounded-lg focus:outline-none focus:ring-2 focus:ring-green-500",
+                    r#type: "search",
+                    placeholder: "Search titles, speakers, scripture...",
+                    value: "{text}",
+                    oninput: move |evt| text.set(evt.value()),
+                }
+                button {
+                    class: "bg-green-600 hover:bg-green-700 text-white px-6 py-2 rounded-lg font-medium transition-colors",
+                    r#type: "submit",
+                    "Search"
+                }
+            }
+
+            div {
+                class: "grid lg:grid-cols-4 gap-8",
+
+                FilterPanel {
+                    filters: query.filters.clone(),
+                    on_apply: move |filters| {
+                        let next = SearchQuery { filters, page: 0, ..filter_query.clone() };
+                        navigator.push(Route::SearchPage { query: next });
+                    },
+                }
+
+                div {
+                    class: "lg:col-span-3 space-y-4",
+
+                    if query.text.is_empty() {
+                        div {
+                            class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-center text-gray-500",
+                            "Enter a search term"
+                        }
+                    } else {
+                        div {
+                            class: "flex items-center justify-between",
+                            p {
+                                class: "text-sm text-gray-600",
+                                "{total} results for \u{201c}{query.text}\u{201d}"
+                            }
+                            if total > 0 {
+                                ReferenceExportButtons {
+                                    label: "Export all:",
+                                    on_export: move |format: ReferenceFormat| {
+                                        let items: Vec<_> = results.read().iter().map(|result| result.metadata.clone()).collect();
+                                        if let Err(e) = format.download(&items) {
+                                            log::error!("Reference export failed: {}", e);
+                                        }
+                                    },
+                                }
+                            }
+                        }
+
+                        for result in results.read().iter().skip(start).take(RESULTS_PER_PAGE) {
+                            BrowseCard { key: "{result.metadata.tx_id}", item: result.metadata.clone() }
+                        }
+
+                        if page_count > 1 {
+                            div {
+                                class: "flex items-center justify-between",
+                                if page > 0 {
+                                    Link {
+                                        class: "border border-green-600 text-green-700 hover:bg-green-50 px-6 py-2 rounded-lg text-sm font-medium transition-colors",
+                                        to: Route::SearchPage { query: query.with_page(page - 1) },
+                                        "Previous"
+                                    }
+                                } else {
+                                    span {}
+                                }
+                                span { class: "text-sm text-gray-500", "Page {page + 1} of {page_count}" }
+                                if page + 1 < page_count {
+                                    Link {
+                                        class: "border border-green-600 text-green-700 hover:bg-green-50 px-6 py-2 rounded-lg text-sm font-medium transition-colors",
+                                        to: Route::SearchPage { query: query.with_page(page + 1) },
+                                        "Next"
+                                    }
+                                } else {
+                                    span {}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use dioxus::prelude::*;
+use std::collections::HashSet;
+use crate::app::Route;
+use crate::components::feed::PodcastFeedPanel;
+use crate::components::follow_button::FollowButton;
+use crate::components::home_feed::FeatureSeriesButton;
+use crate::components::media_player::MediaPlayer;
+use crate::components::skeleton::{CardSkeleton, DetailSkeleton};
+use crate::models::{ContentMetadata, SeriesManifest};
+use crate::services::async_state::{use_async_resource, AsyncState};
+use crate::services::feed::FeedSource;
+use crate::services::follows::FollowTarget;
+use crate::services::series::{mark_played, played_in_series, reset_progress, SeriesError, SeriesService};
+
+/// A series in order, with sequential playback and listening progress
+#[component]
+pub fn SeriesDetail(series_id: String) -> Element {
+    let mut played = use_signal(HashSet::<String>::new);
+    let mut current = use_signal(|| None::<String>);
+
+    let lookup_id = series_id.clone();
+    use_effect(use_reactive!(|lookup_id| played.set(played_in_series(&lookup_id))));
+    let lookup_id = series_id.clone();
+    let series = use_async_resource(use_reactive!(|lookup_id| async move {
+        let service = SeriesService::new();
+        let manifest = service.load(&lookup_id).await?;
+        let members = service.members(&manifest).await?;
+        Ok::<(SeriesManifest, Vec<ContentMetadata>), SeriesError>((manifest, members))
+    }));
+
+    // Advance to the next sermon once the current one finishes
+    let ended_series = series_id.clone();
+    let on_ended = move |_: ()| {
+        let Some(finished) = current.read().clone() else {
+            return;
+        };
+        mark_played(&ended_series, &finished);
+        played.write().insert(finished.clone());
+        let next = series.ready().and_then(|(manifest, _)| manifest.next_after(&finished).map(str::to_string));
+        current.set(next);
+    };
+
+    let reset_series = series_id.clone();
+    let reset = move |_| {
+        reset_progress(&reset_series);
+        played.set(HashSet::new());
+    };
+
+    let state = series.state();
+    let members = match &state {
+        AsyncState::Ready((_, members)) => members.as_slice(),
+        _ => &[],
+    };
+    let total = members.len();
+    let played_count = members.iter().filter(|m| played.read().contains(&m.tx_id)).count();
+    let progress_percent = (played_count * 100).checked_div(total).unwrap_or(0);
+    let next_unplayed = members.iter().find(|m| !played.read().contains(&m.tx_id)).map(|m| m.tx_id.clone());
+    let now_playing = current.read().as_ref().and_then(|id| members.iter().find(|m| &m.tx_id == id).cloned());
+
+    rsx! {
+        main {
+            class: "max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            if let AsyncState::Failed(message) = &state {
+                div {
+                    class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                    "{message}"
+                }
+            }
+
+            if let AsyncState::Ready((series, _)) = &state {
+                div {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-8",
+
+                    div {
+                        class: "flex items-center justify-between gap-4",
+                        h2 { class: "text-2xl font-bold text-gray-900", "{series.title}" }
+                        div {
+                            class: "flex items-center gap-3",
+                            FeatureSeriesButton { series_id: series.series_id.clone() }
+                            FollowButton { target: FollowTarget::Series(series.series_id.clone()) }
+                        }
+                    }
+                    if let Some(description) = series.description.as_ref() {
+                        p { class: "text-gray-600 mt-2", "{description}" }
+                    }
+                    if let Some(owner) = members.first().map(|item| item.owner.clone()) {
+                        div {
+                            class: "mt-4",
+                            PodcastFeedPanel { source: FeedSource::Series(series.series_id.clone()), owner }
+                        }
+                    }
+
+                    div {
+                        class: "mt-6",
+                        div {
+                            class: "flex justify-between text-sm text-gray-600 mb-1",
+                            span { "{played_count} of {total} played" }
+                            button { class: "text-xs text-gray-400 hover:text-gray-600", onclick: reset, "Reset progress" }
+                        }
+                        div {
+                            class: "w-full bg-gray-200 rounded-full h-2",
+                            div { class: "bg-green-600 h-2 rounded-full", style: "width: {progress_percent}%" }
+                        }
+                    }
+
+                    if let Some(item) = now_playing.as_ref() {
+                        div {
+                            class: "mt-6",
+                            p { class: "text-sm font-medium text-gray-900 mb-2", "Now playing: {item.title}" }
+                            MediaPlayer {
+                                key: "{item.tx_id}",
+                                item: item.clone(),
+                                autoplay: true,
+                                resume: true,
+                                on_ended: on_ended,
+                            }
+                        }
+                    }
+
+                    if let Some(next) = next_unplayed {
+                        button {
+                            class: "mt-4 bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                            onclick: move |_| current.set(Some(next.clone())),
+                            if played_count == 0 { "Start series" } else { "Play next sermon" }
+                        }
+                    }
+                }
+
+                ol {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 divide-y divide-gray-100",
+                    for (index, item) in members.iter().enumerate() {
+                        li {
+                            key: "{item.tx_id}",
+                            class: if current.read().as_deref() == Some(item.tx_id.as_str()) {
+                                "p-4 flex items-center gap-4 bg-green-50"
+                            } else {
+                                "p-4 flex items-center gap-4"
+                            },
+                            span { class: "w-6 text-sm text-gray-400", "{index + 1}" }
+                            div {
+                                class: "flex-1",
+                                Link {
+                                    class: "font-medium text-gray-900 hover:text-green-700",
+                                    to: Route::ContentDetail { tx_id: item.tx_id.clone() },
+                                    "{item.title}"
+                                }
+                                if let Some(speaker) = item.speaker.as_ref() {
+                                    div { class: "text-sm text-gray-600", "{speaker}" }
+                                }
+                            }
+                            if played.read().contains(&item.tx_id) {
+                                span { class: "text-xs text-green-700", "✓ Played" }
+                            }
+                            button {
+                                class: "text-sm text-green-700 hover:text-green-800 font-medium",
+                                onclick: {
+                                    let tx_id = item.tx_id.clone();
+                                    move |_| current.set(Some(tx_id.clone()))
+                                },
+                                "Play"
+                            }
+                        }
+                    }
+                }
+            } else if matches!(state, AsyncState::Loading) {
+                DetailSkeleton {}
+                CardSkeleton {}
+            }
+        }
+    }
+}
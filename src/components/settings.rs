@@ -0,0 +1,570 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::arns::ArnsSettings;
+use crate::components::top_up::TopUpButton;
+use crate::components::upload::LicenseFields;
+use crate::components::webhooks::WebhookSettings;
+use crate::config::{switch_network, use_app_config, NetworkMode};
+use crate::i18n::{t, use_locale, Locale};
+use crate::services::gateway::{use_gateways, use_pinned_gateway, Gateway, GatewayKind, GatewayManager};
+use crate::services::identity::{use_local_identity, IdentityService};
+use crate::services::settings::{update_settings, use_settings};
+use crate::services::theme::ThemeMode;
+use crate::services::transfer::UPLOAD_LIMIT_CHOICES;
+use crate::services::wallet::{idle::IDLE_LOCK_CHOICES, preference_order, remember_strategy, remembered_strategy, WalletService};
+
+/// Application settings page
+#[component]
+pub fn Settings() -> Element {
+    rsx! {
+        main {
+            class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+
+            h2 {
+                class: "text-2xl font-bold text-gray-900 mb-6",
+                {t!("settings.title")}
+            }
+
+            LanguageSettings {}
+            PreferenceSettings {}
+            WalletSettings {}
+            NetworkSettings {}
+            GatewaySettings {}
+            IdentitySettings {}
+            WebhookSettings {}
+            ArnsSettings {}
+        }
+    }
+}
+
+/// Interface language picker
+#[component]
+fn LanguageSettings() -> Element {
+    let locale = use_locale();
+    let settings = use_settings();
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 mb-6",
+            label {
+                class: "block text-lg font-semibold text-gray-900",
+                r#for: "language",
+                {t!("settings.language")}
+            }
+            p { class: "text-sm text-gray-600 mb-3", {t!("settings.language_help")} }
+            select {
+                id: "language",
+                class: "px-3 py-2 border border-gray-300 rounded-md text-sm",
+                value: "{locale.read().code()}",
+                onchange: move |evt| {
+                    if let Some(locale) = Locale::from_code(&evt.value()) {
+                        update_settings(|settings| settings.language = locale);
+                    }
+                },
+                for option_locale in Locale::ALL {
+                    option {
+                        key: "{option_locale.code()}",
+                        value: "{option_locale.code()}",
+                        lang: "{option_locale.code()}",
+                        "{option_locale.native_name()}"
+                    }
+                }
+            }
+            label {
+                class: "flex items-start gap-2 text-sm text-gray-700 mt-4",
+                input {
+                    r#type: "checkbox",
+                    class: "mt-1",
+                    checked: settings.read().browse_in_interface_language,
+                    onchange: move |evt| update_settings(|settings| settings.browse_in_interface_language = evt.checked()),
+                }
+                span {
+                    span { class: "block font-medium", {t!("settings.browse_in_language")} }
+                    span { class: "block text-gray-500", {t!("settings.browse_in_language_help")} }
+                }
+            }
+        }
+    }
+}
+
+/// Theme, playback, data use and upload defaults
+#[component]
+fn PreferenceSettings() -> Element {
+    let settings = use_settings();
+    let default_license = use_signal(|| settings.peek().default_license.clone());
+    use_effect(move || {
+        let license = default_license.read().clone();
+        update_settings(|settings| settings.default_license = license);
+    });
+
+    let current = settings.read().clone();
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 mb-6 space-y-4",
+            h3 { class: "text-lg font-semibold text-gray-900", {t!("settings.preferences")} }
+
+            div {
+                label { class: "block text-sm font-medium text-gray-700 mb-1", r#for: "theme", {t!("settings.theme")} }
+                select {
+                    id: "theme",
+                    class: "px-3 py-2 border border-gray-300 rounded-md text-sm",
+                    value: "{current.theme.storage_value()}",
+                    onchange: move |evt| {
+                        if let Some(mode) = ThemeMode::from_storage_value(&evt.value()) {
+                            update_settings(|settings| settings.theme = mode);
+                        }
+                    },
+                    for mode in ThemeMode::ALL {
+                        option { key: "{mode.storage_value()}", value: "{mode.storage_value()}", "{mode.display_name()}" }
+                    }
+                }
+            }
+
+            label {
+                class: "flex items-start gap-2 text-sm text-gray-700",
+                input {
+                    r#type: "checkbox",
+                    class: "mt-1",
+                    checked: current.autoplay,
+                    onchange: move |evt| update_settings(|settings| settings.autoplay = evt.checked()),
+                }
+                span {
+                    span { class: "block font-medium", {t!("settings.autoplay")} }
+                    span { class: "block text-gray-500", {t!("settings.autoplay_help")} }
+                }
+            }
+
+            label {
+                class: "flex items-start gap-2 text-sm text-gray-700",
+                input {
+                    r#type: "checkbox",
+                    class: "mt-1",
+                    checked: current.data_saver,
+                    onchange: move |evt| update_settings(|settings| settings.data_saver = evt.checked()),
+                }
+                span {
+                    span { class: "block font-medium", {t!("settings.data_saver")} }
+                    span { class: "block text-gray-500", {t!("settings.data_saver_help")} }
+                }
+            }
+
+            div {
+                label { class: "block text-sm font-medium text-gray-700 mb-1", r#for: "upload-limit", {t!("settings.upload_limit")} }
+                select {
+                    id: "upload-limit",
+                    class: "px-3 py-2 border border-gray-300 rounded-md text-sm",
+                    value: "{current.upload_limit_kbps.unwrap_or(0)}",
+                    onchange: move |evt| {
+                        let kbps = evt.value().parse::<u32>().ok().filter(|kbps| *kbps > 0);
+                        update_settings(|settings| settings.upload_limit_kbps = kbps);
+                    },
+                    option { value: "0", {t!("settings.upload_limit_off")} }
+                    for kbps in UPLOAD_LIMIT_CHOICES {
+                        option { key: "{kbps}", value: "{kbps}", {t!("settings.upload_limit_kbps", kbps = kbps)} }
+                    }
+                }
+                p { class: "mt-1 text-sm text-gray-500", {t!("settings.upload_limit_help")} }
+            }
+
+            label {
+                class: "flex items-start gap-2 text-sm text-gray-700",
+                input {
+                    r#type: "checkbox",
+                    class: "mt-1",
+                    checked: current.usage_stats,
+                    onchange: move |evt| update_settings(|settings| settings.usage_stats = evt.checked()),
+                }
+                span {
+                    span { class: "block font-medium", {t!("settings.usage_stats")} }
+                    span {
+                        class: "block text-gray-500",
+                        {t!("settings.usage_stats_help")}
+                        " "
+                        Link { class: "text-green-700 hover:text-green-800", to: Route::UsageStats {}, {t!("footer.your_stats")} }
+                    }
+                }
+            }
+
+            div {
+                label { class: "block text-sm font-medium text-gray-700 mb-1", r#for: "idle-lock", {t!("settings.idle_lock")} }
+                select {
+                    id: "idle-lock",
+                    class: "px-3 py-2 border border-gray-300 rounded-md text-sm",
+                    value: "{current.idle_lock_minutes.unwrap_or(0)}",
+                    onchange: move |evt| {
+                        let minutes = evt.value().parse::<u32>().ok().filter(|minutes| *minutes > 0);
+                        update_settings(|settings| settings.idle_lock_minutes = minutes);
+                    },
+                    option { value: "0", {t!("settings.idle_lock_off")} }
+                    for minutes in IDLE_LOCK_CHOICES {
+                        option { key: "{minutes}", value: "{minutes}", {t!("settings.idle_lock_minutes", minutes = minutes)} }
+                    }
+                }
+                p { class: "mt-1 text-sm text-gray-500", {t!("settings.idle_lock_help")} }
+            }
+
+            div {
+                class: "space-y-2",
+                p { class: "text-sm text-gray-600", {t!("settings.default_license_help")} }
+                LicenseFields { license: default_license }
+            }
+        }
+    }
+}
+
+/// Order wallets are selected in, and the one reconnected at startup
+#[component]
+fn WalletSettings() -> Element {
+    let settings = use_settings();
+    let mut remembered = use_signal(remembered_strategy);
+
+    let move_strategy = move |from: usize, to: usize| {
+        let mut order = preference_order(&settings.peek().wallet_order);
+        order.swap(from, to);
+        update_settings(|settings| settings.wallet_order = order);
+    };
+    let forget = move |_| {
+        remember_strategy(None);
+        remembered.set(None);
+    };
+
+    let order = preference_order(&settings.read().wallet_order);
+    let last = order.len() - 1;
+    let up = t!("settings.move_up");
+    let down = t!("settings.move_down");
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 mb-6 space-y-3",
+            h3 { class: "text-lg font-semibold text-gray-900", {t!("settings.wallets")} }
+            p { class: "text-sm text-gray-600", {t!("settings.wallets_help")} }
+            ol {
+                class: "divide-y divide-gray-100 border border-gray-200 rounded-md text-sm",
+                for (index, strategy) in order.into_iter().enumerate() {
+                    li {
+                        key: "{strategy}",
+                        class: "flex items-center gap-3 px-3 py-2",
+                        span { class: "w-5 text-gray-400", "{index + 1}" }
+                        span {
+                            class: "flex-1",
+                            span { class: "block font-medium text-gray-900", "{strategy.display_name()}" }
+                            span { class: "block text-xs text-gray-500", "{strategy.description()}" }
+                        }
+                        button {
+                            class: "px-2 text-gray-500 hover:text-green-700 disabled:opacity-30",
+                            title: "{up}",
+                            aria_label: "{up}",
+                            disabled: index == 0,
+                            onclick: move |_| move_strategy(index, index.saturating_sub(1)),
+                            "↑"
+                        }
+                        button {
+                            class: "px-2 text-gray-500 hover:text-green-700 disabled:opacity-30",
+                            title: "{down}",
+                            aria_label: "{down}",
+                            disabled: index == last,
+                            onclick: move |_| move_strategy(index, (index + 1).min(last)),
+                            "↓"
+                        }
+                    }
+                }
+            }
+            if let Some(strategy) = remembered() {
+                div {
+                    class: "flex items-center gap-3 text-sm text-gray-700",
+                    span { {t!("settings.last_wallet", wallet = strategy.display_name())} }
+                    button { class: "text-gray-500 hover:text-red-600", onclick: forget, {t!("settings.forget_wallet")} }
+                }
+            } else {
+                p { class: "text-sm text-gray-500", {t!("settings.no_last_wallet")} }
+            }
+            div {
+                class: "flex items-center justify-between gap-3 text-sm text-gray-700 pt-2 border-t border-gray-100",
+                span { {t!("settings.storage_credits")} }
+                TopUpButton {}
+            }
+        }
+    }
+}
+
+/// Mainnet, or a test network for dry-run uploads
+#[component]
+fn NetworkSettings() -> Element {
+    let config = use_app_config();
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 mb-6",
+            label {
+                class: "block text-lg font-semibold text-gray-900",
+                r#for: "network",
+                {t!("settings.network")}
+            }
+            p { class: "text-sm text-gray-600 mb-3", {t!("settings.network_help")} }
+            select {
+                id: "network",
+                class: "px-3 py-2 border border-gray-300 rounded-md text-sm",
+                value: "{config.network.storage_value()}",
+                onchange: move |evt| {
+                    if let Some(network) = NetworkMode::from_storage_value(&evt.value()) {
+                        switch_network(network);
+                    }
+                },
+                for network in NetworkMode::ALL {
+                    option { key: "{network.storage_value()}", value: "{network.storage_value()}", "{network.display_name()}" }
+                }
+            }
+            if !config.network.is_mainnet() {
+                p {
+                    class: "text-xs text-gray-500 mt-2 font-mono",
+                    "{config.primary_gateway()} · {config.bundler}"
+                }
+            }
+        }
+    }
+}
+
+/// Opt-in anonymous identity for visitors without a wallet
+#[component]
+pub fn IdentitySettings() -> Element {
+    let identity = use_local_identity();
+    let mut is_busy = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let mut run = move |action: IdentityAction| {
+        spawn(async move {
+            is_busy.set(true);
+            error.set(None);
+            let result = match action {
+                IdentityAction::Enable => IdentityService::enable().await.map(|_| ()),
+                IdentityAction::Remove => IdentityService::remove().await,
+            };
+            if let Err(e) = result {
+                error.set(Some(e.to_string()));
+            }
+            is_busy.set(false);
+        });
+    };
+
+    let button_class = "border border-green-600 text-green-700 hover:bg-green-50 disabled:opacity-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors";
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 mt-6",
+            h3 { class: "text-lg font-semibold text-gray-900", "Anonymous identity" }
+            p {
+                class: "text-sm text-gray-600 mt-1",
+                "Without a wallet you can still send reports, signed by a random key kept only in this browser. Anything it signs is public and labeled unverified."
+            }
+
+            match identity.read().as_ref() {
+                Some(local) => rsx! {
+                    div {
+                        class: "mt-4 flex items-center gap-2",
+                        span { class: "font-mono text-sm text-gray-800", title: "{local.address}", "{WalletService::format_address(&local.address)}" }
+                        span { class: "px-2 py-0.5 rounded-full text-xs font-medium bg-yellow-100 text-yellow-800", "Unverified" }
+                    }
+                    if let Some(wallet) = local.linked_wallet.as_ref() {
+                        p { class: "text-sm text-gray-600 mt-2", "Linked to wallet {WalletService::format_address(wallet)}" }
+                    }
+                    div {
+                        class: "flex gap-2 mt-4",
+                        if local.linked_wallet.is_none() {
+                            Link {
+                                class: button_class,
+                                title: "Review moving this identity's data to your wallet",
+                                to: Route::MigrateLocalData {},
+                                "Upgrade to wallet"
+                            }
+                        }
+                        button {
+                            class: "text-sm text-gray-500 hover:text-red-600 px-4 py-2",
+                            disabled: *is_busy.read(),
+                            onclick: move |_| run(IdentityAction::Remove),
+                            "Delete identity"
+                        }
+                    }
+                },
+                None => rsx! {
+                    button {
+                        class: "{button_class} mt-4",
+                        disabled: *is_busy.read(),
+                        onclick: move |_| run(IdentityAction::Enable),
+                        "Create anonymous identity"
+                    }
+                },
+            }
+
+            if let Some(message) = error.read().as_ref() {
+                p { class: "text-sm text-red-700 mt-3", "{message}" }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum IdentityAction {
+    Enable,
+    Remove,
+}
+
+/// Gateway selection panel
+///
+/// Shows measured latency and health of every gateway, lets users pin a
+/// preferred gateway or leave selection automatic, and manage custom ones.
+#[component]
+pub fn GatewaySettings() -> Element {
+    let gateways = use_gateways();
+    let pinned = use_pinned_gateway();
+    let fastest = GatewayManager::new().fastest().url;
+    let mut new_gateway = use_signal(String::new);
+    let mut is_benchmarking = use_signal(|| false);
+
+    let run_benchmark = move |_| {
+        spawn(async move {
+            is_benchmarking.set(true);
+            GatewayManager::new().health_check().await;
+            is_benchmarking.set(false);
+        });
+    };
+
+    let add_gateway = move |_| {
+        let url = new_gateway.read().trim().to_string();
+        if url.starts_with("http://") || url.starts_with("https://") {
+            GatewayManager::new().add_custom(&url);
+            new_gateway.set(String::new());
+        }
+    };
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6",
+
+            div {
+                class: "flex items-center justify-between mb-4",
+                div {
+                    h3 {
+                        class: "text-lg font-semibold text-gray-900",
+                        "Gateways"
+                    }
+                    p {
+                        class: "text-sm text-gray-600",
+                        "Media streams from the fastest gateway unless you pin one."
+                    }
+                }
+                button {
+                    class: if *is_benchmarking.read() {
+                        "bg-gray-300 cursor-not-allowed text-gray-600 px-4 py-2 rounded-lg text-sm font-medium"
+                    } else {
+                        "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors"
+                    },
+                    disabled: *is_benchmarking.read(),
+                    onclick: run_benchmark,
+                    if *is_benchmarking.read() { "Measuring..." } else { "Re-run Benchmark" }
+                }
+            }
+
+            div {
+                class: "divide-y divide-gray-100",
+
+                label {
+                    class: "flex items-center gap-3 py-3 cursor-pointer",
+                    input {
+                        r#type: "radio",
+                        name: "preferred-gateway",
+                        checked: pinned.is_none(),
+                        onchange: move |_| update_settings(|settings| settings.gateway = None),
+                    }
+                    span { class: "text-sm text-gray-900", "Automatic (fastest healthy gateway)" }
+                }
+
+                for gateway in gateways {
+                    GatewayRow {
+                        key: "{gateway.url}",
+                        is_pinned: pinned.as_deref() == Some(gateway.url.as_str()),
+                        is_fastest: gateway.url == fastest,
+                        gateway: gateway,
+                    }
+                }
+            }
+
+            div {
+                class: "flex gap-3 mt-4",
+                input {
+                    class: "flex-1 px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-green-500",
+                    placeholder: "https://my-gateway.example",
+                    value: "{new_gateway}",
+                    oninput: move |evt| new_gateway.set(evt.value()),
+                }
+                button {
+                    class: "border border-green-600 text-green-700 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                    onclick: add_gateway,
+                    "Add Gateway"
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn GatewayRow(gateway: Gateway, is_pinned: bool, is_fastest: bool) -> Element {
+    let url = gateway.url.clone();
+    let remove_url = gateway.url.clone();
+
+    let kind = match gateway.kind {
+        GatewayKind::Arweave => "arweave.net",
+        GatewayKind::ArIo => "ar.io",
+        GatewayKind::Custom => "Custom",
+    };
+
+    let latency = match (gateway.healthy, gateway.latency_ms) {
+        (false, _) => "Unreachable".to_string(),
+        (true, Some(ms)) => format!("{} ms", ms),
+        (true, None) => "Not measured".to_string(),
+    };
+
+    rsx! {
+        div {
+            class: "flex items-center justify-between py-3",
+
+            label {
+                class: "flex items-center gap-3 cursor-pointer",
+                input {
+                    r#type: "radio",
+                    name: "preferred-gateway",
+                    checked: is_pinned,
+                    onchange: move |_| {
+                        let url = url.clone();
+                        update_settings(|settings| settings.gateway = Some(url));
+                    },
+                }
+                div {
+                    div { class: "text-sm font-mono text-gray-900", "{gateway.url}" }
+                    div { class: "text-xs text-gray-500", "{kind}" }
+                }
+            }
+
+            div {
+                class: "flex items-center gap-3",
+                if is_fastest {
+                    span {
+                        class: "inline-flex items-center px-2 py-1 rounded-full text-xs font-medium bg-green-100 text-green-800",
+                        "Fastest"
+                    }
+                }
+                span {
+                    class: if gateway.healthy { "text-sm text-gray-600" } else { "text-sm text-red-600" },
+                    "{latency}"
+                }
+                if gateway.kind == GatewayKind::Custom {
+                    button {
+                        class: "text-sm text-red-600 hover:text-red-700",
+                        onclick: move |_| GatewayManager::new().remove_custom(&remove_url),
+                        "Remove"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use dioxus::prelude::*;
+use crate::components::modal::Modal;
+use crate::components::qr_code::QrCode;
+use crate::services::share::{embed_code, share_link, share_url, ShareMeta, ShareOutcome};
+use crate::services::toast::{show_toast, ToastKind};
+use crate::utils::display::use_copy_feedback;
+
+/// Shares a page's canonical link through the system share sheet, or
+/// copies it where the browser has none
+#[component]
+pub fn ShareButton(meta: ShareMeta) -> Element {
+    rsx! {
+        button {
+            class: "border border-green-600 text-green-700 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+            onclick: move |_| {
+                let meta = meta.clone();
+                spawn(async move {
+                    match share_link(&meta.title, &meta.description, &meta.url).await {
+                        Ok(ShareOutcome::Copied) => show_toast(ToastKind::Success, "Link copied to the clipboard"),
+                        Ok(_) => {}
+                        Err(e) => show_toast(ToastKind::Error, e),
+                    }
+                });
+            },
+            "Share"
+        }
+    }
+}
+
+/// Copies the iframe snippet that embeds an item's player in another site
+#[component]
+pub fn EmbedCodeButton(tx_id: String, title: String) -> Element {
+    let copy = use_copy_feedback();
+
+    rsx! {
+        button {
+            class: "border border-green-600 text-green-700 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+            title: copy.error().unwrap_or_else(|| "Copy HTML to put this player on your website".to_string()),
+            onclick: move |_| copy.copy(embed_code(&tx_id, &title)),
+            if copy.copied() { "Copied!" } else { "Embed" }
+        }
+    }
+}
+
+/// Shows a QR code of an item's link, for projecting during a service or
+/// printing in a bulletin
+#[component]
+pub fn ShareQrButton(tx_id: String, title: String) -> Element {
+    let mut open = use_signal(|| false);
+    let url = share_url(&tx_id);
+
+    rsx! {
+        button {
+            class: "border border-green-600 text-green-700 hover:bg-green-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+            title: "Show a QR code for this page",
+            onclick: move |_| open.set(true),
+            "QR code"
+        }
+        Modal {
+            open,
+            title: "Scan to open",
+            div {
+                class: "flex flex-col items-center gap-3 text-center",
+                QrCode { text: url.clone(), label: format!("QR code linking to {}", title), class: "w-64 h-64" }
+                p { class: "font-medium text-gray-900", "{title}" }
+                p { class: "text-xs text-gray-500 break-all", "{url}" }
+            }
+        }
+    }
+}
+
+/// Page title and Open Graph / Twitter card tags for a shared link
+///
+/// Crawlers that run the app (and prerendered hosted builds) pick these
+/// up so links unfurl with the item's title and cover; the defaults in
+/// `index.html` cover the rest.
+#[component]
+pub fn ShareHead(meta: ShareMeta) -> Element {
+    rsx! {
+        document::Title { "{meta.title} - Faithful Archive" }
+        document::Meta { name: "description", content: "{meta.description}" }
+        document::Link { rel: "canonical", href: "{meta.url}" }
+        document::Meta { property: "og:type", content: "article" }
+        document::Meta { property: "og:title", content: "{meta.title}" }
+        document::Meta { property: "og:description", content: "{meta.description}" }
+        document::Meta { property: "og:url", content: "{meta.url}" }
+        document::Meta { property: "og:image", content: "{meta.image_url}" }
+        document::Meta { name: "twitter:card", content: "summary_large_image" }
+        document::Meta { name: "twitter:title", content: "{meta.title}" }
+        document::Meta { name: "twitter:description", content: "{meta.description}" }
+        document::Meta { name: "twitter:image", content: "{meta.image_url}" }
+    }
+}
@@ -0,0 +1,69 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::debug_panel::DebugPanel;
+use crate::components::modal::{close_top_modal, Modal};
+use crate::services::shortcuts::{key_label, listen_for_shortcuts, use_keyboard_shortcuts, use_registered_shortcuts, Shortcut};
+
+/// App-wide bindings, the "?" help overlay and the "~" debug log
+///
+/// Render once inside the router. Pages add their own bindings with
+/// `use_keyboard_shortcuts`, e.g. search on "/" and the player on Space.
+#[component]
+pub fn GlobalShortcuts() -> Element {
+    let show_help = use_signal(|| false);
+    let show_debug = use_signal(|| false);
+    let navigator = use_navigator();
+
+    use_hook(listen_for_shortcuts);
+    use_keyboard_shortcuts(move || {
+        vec![
+            Shortcut::new("?", "Show keyboard shortcuts", move || {
+                // Handlers are `Fn`; signals are `Copy`, so toggle a copy
+                let mut show_help = show_help;
+                show_help.toggle();
+            }),
+            Shortcut::new("~", "Show debug log", move || {
+                let mut show_debug = show_debug;
+                show_debug.toggle();
+            }),
+            Shortcut::new("u", "Go to Upload", move || {
+                navigator.push(Route::Upload {});
+            }),
+            Shortcut::new("Escape", "Close dialog", close_top_modal),
+        ]
+    });
+
+    rsx! {
+        KeyboardHelp { open: show_help }
+        DebugPanel { open: show_debug }
+    }
+}
+
+/// Dialog listing every active binding
+#[component]
+fn KeyboardHelp(open: Signal<bool>) -> Element {
+    let shortcuts = use_registered_shortcuts();
+
+    rsx! {
+        Modal {
+            open,
+            title: "Keyboard shortcuts",
+            dl {
+                class: "space-y-2",
+                for shortcut in shortcuts.read().iter() {
+                    div {
+                        key: "{shortcut.key}",
+                        class: "flex items-center justify-between gap-4 text-sm",
+                        dt { class: "text-gray-700", "{shortcut.description}" }
+                        dd {
+                            kbd {
+                                class: "px-2 py-1 rounded border border-gray-300 bg-gray-50 font-mono text-xs text-gray-800",
+                                "{key_label(shortcut.key)}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use dioxus::prelude::*;
+
+/// Pulsing placeholder bar; size and shape come from `class`
+#[component]
+fn Bone(class: &'static str) -> Element {
+    rsx! {
+        div { class: "animate-pulse bg-gray-200 dark:bg-gray-700 rounded {class}" }
+    }
+}
+
+// Shorter last line, like a paragraph's
+fn line_class(line: usize, lines: usize) -> &'static str {
+    if line + 1 == lines && lines > 1 { "h-3 w-2/3" } else { "h-3 w-full" }
+}
+
+/// Stand-in for a few lines of text
+#[component]
+pub fn SkeletonLines(#[props(default = 3)] lines: usize) -> Element {
+    rsx! {
+        div {
+            class: "space-y-2",
+            role: "status",
+            aria_busy: "true",
+            span { class: "sr-only", "Loading..." }
+            for line in 0..lines {
+                Bone { key: "{line}", class: line_class(line, lines) }
+            }
+        }
+    }
+}
+
+/// Stand-in for a list of `BrowseCard`s
+#[component]
+pub fn CardSkeleton(#[props(default = 3)] count: usize) -> Element {
+    rsx! {
+        div {
+            class: "space-y-4",
+            role: "status",
+            aria_busy: "true",
+            span { class: "sr-only", "Loading..." }
+            for card in 0..count {
+                div {
+                    key: "{card}",
+                    class: "bg-white dark:bg-gray-900 rounded-xl shadow-sm border border-green-200 dark:border-gray-800 p-6 space-y-3",
+                    aria_hidden: "true",
+                    Bone { class: "h-5 w-1/2" }
+                    Bone { class: "h-3 w-1/3" }
+                    Bone { class: "h-3 w-full" }
+                }
+            }
+        }
+    }
+}
+
+/// Stand-in for a `MediaPlayer`
+#[component]
+pub fn PlayerSkeleton() -> Element {
+    rsx! {
+        div {
+            role: "status",
+            aria_busy: "true",
+            span { class: "sr-only", "Loading player..." }
+            Bone { class: "h-12 w-full" }
+        }
+    }
+}
+
+/// Stand-in for an item page: title, player and description
+#[component]
+pub fn DetailSkeleton() -> Element {
+    rsx! {
+        div {
+            class: "bg-white dark:bg-gray-900 rounded-xl shadow-sm border border-green-200 dark:border-gray-800 p-8 space-y-4",
+            Bone { class: "h-7 w-2/3" }
+            Bone { class: "h-4 w-1/4" }
+            PlayerSkeleton {}
+            SkeletonLines { lines: 4 }
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::models::ContentMetadata;
+use crate::services::graphql::GraphQLClient;
+use crate::services::notes::{NotesService, StudyNote};
+use crate::services::passage::{mentions, Passage, PassageService};
+use crate::services::transcript::{Transcript, TranscriptService};
+
+/// Split-pane study route: pinned passage, item transcript and notes
+///
+/// Pinning a passage highlights the transcript paragraphs and notes that
+/// mention it; clicking a highlighted paragraph or note pins its passage.
+#[component]
+pub fn StudyWorkspace(tx_id: String) -> Element {
+    let mut metadata = use_signal(|| None::<ContentMetadata>);
+    let mut transcript = use_signal(|| None::<Result<Transcript, String>>);
+    let mut notes = use_signal(Vec::<StudyNote>::new);
+    let mut pinned = use_signal(|| None::<String>);
+    let mut error = use_signal(|| None::<String>);
+
+    let lookup_id = tx_id.clone();
+    use_effect(use_reactive!(|lookup_id| {
+        spawn(async move {
+            let item = match GraphQLClient::cached().transaction_details(&lookup_id).await {
+                Ok(Some(details)) => details.metadata(),
+                Ok(None) => None,
+                Err(e) => {
+                    error.set(Some(e.to_string()));
+                    return;
+                }
+            };
+            let Some(item) = item else {
+                error.set(Some("Item not found".to_string()));
+                return;
+            };
+
+            pinned.set(item.scripture_refs.first().cloned());
+            match NotesService::notes_for(&item.tx_id).await {
+                Ok(saved) => notes.set(saved),
+                Err(e) => log::warn!("Could not load study notes: {}", e),
+            }
+            let transcript_tx_id = item.transcript_tx_id.clone();
+            metadata.set(Some(item));
+
+            if let Some(transcript_tx_id) = transcript_tx_id {
+                let loaded = TranscriptService::new()
+                    .load(&transcript_tx_id)
+                    .await
+                    .map_err(|e| e.to_string());
+                transcript.set(Some(loaded));
+            }
+        });
+    }));
+
+    let Some(item) = metadata.read().clone() else {
+        return rsx! {
+            main {
+                class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+                if let Some(message) = error.read().as_ref() {
+                    div {
+                        class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                        "{message}"
+                    }
+                } else {
+                    div { class: "text-center text-sm text-gray-500 py-8", "Loading..." }
+                }
+            }
+        };
+    };
+
+    let pinned_ref = pinned.read().clone();
+    let is_linked = move |text: &str| pinned_ref.as_deref().is_some_and(|reference| mentions(text, reference));
+
+    rsx! {
+        main {
+            class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+
+            div {
+                class: "flex items-center justify-between mb-6",
+                div {
+                    h2 { class: "text-2xl font-bold text-gray-900", "Study: {item.title}" }
+                    if let Some(speaker) = item.speaker.as_ref() {
+                        p { class: "text-gray-600", "{speaker}" }
+                    }
+                }
+                Link {
+                    class: "text-sm text-green-700 hover:text-green-800 font-medium",
+                    to: Route::ContentDetail { tx_id: item.tx_id.clone() },
+                    "Back to item"
+                }
+            }
+
+            div {
+                class: "grid lg:grid-cols-3 gap-6",
+
+                StudyPane {
+                    title: "Passage",
+                    PassagePane {
+                        references: item.scripture_refs.clone(),
+                        pinned: pinned.read().clone(),
+                        on_pin: move |reference| pinned.set(Some(reference)),
+                    }
+                }
+
+                StudyPane {
+                    title: "Transcript",
+                    match transcript.read().as_ref() {
+                        None if item.transcript_tx_id.is_none() => rsx! {
+                            p { class: "text-sm text-gray-500", "No transcript has been published for this item." }
+                        },
+                        None => rsx! {
+                            p { class: "text-sm text-gray-500", "Loading transcript..." }
+                        },
+                        Some(Err(message)) => rsx! {
+                            p { class: "text-sm text-red-700", "{message}" }
+                        },
+                        Some(Ok(loaded)) => rsx! {
+                            for (index, segment) in loaded.segments.iter().enumerate() {
+                                p {
+                                    key: "{index}",
+                                    class: if is_linked(&segment.text) {
+                                        "text-sm text-gray-800 mb-3 p-2 rounded bg-yellow-100"
+                                    } else {
+                                        "text-sm text-gray-800 mb-3 p-2 rounded cursor-pointer hover:bg-gray-50"
+                                    },
+                                    onclick: {
+                                        let reference = item.scripture_refs.iter().find(|r| mentions(&segment.text, r)).cloned();
+                                        move |_| {
+                                            if let Some(reference) = reference.clone() {
+                                                pinned.set(Some(reference));
+                                            }
+                                        }
+                                    },
+                                    "{segment.text}"
+                                }
+                            }
+                        },
+                    }
+                }
+
+                StudyPane {
+                    title: "Notes",
+                    NotesPane {
+                        item_tx_id: item.tx_id.clone(),
+                        notes: notes.read().clone(),
+                        pinned: pinned.read().clone(),
+                        on_change: move |updated| notes.set(updated),
+                        on_pin: move |reference| pinned.set(Some(reference)),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn StudyPane(title: &'static str, children: Element) -> Element {
+    rsx! {
+        section {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-5 lg:h-[70vh] overflow-y-auto",
+            h3 { class: "text-lg font-semibold text-gray-900 mb-4", "{title}" }
+            {children}
+        }
+    }
+}
+
+/// Item's scripture references with the text of the pinned one
+#[component]
+fn PassagePane(references: Vec<String>, pinned: Option<String>, on_pin: EventHandler<String>) -> Element {
+    let mut passage = use_signal(|| None::<Result<Passage, String>>);
+    let mut custom = use_signal(String::new);
+
+    use_effect(use_reactive!(|pinned| {
+        passage.set(None);
+        if let Some(reference) = pinned {
+            spawn(async move {
+                let result = PassageService::new().lookup(&reference).await.map_err(|e| e.to_string());
+                passage.set(Some(result));
+            });
+        }
+    }));
+
+    rsx! {
+        div {
+            class: "flex flex-wrap gap-2 mb-4",
+            for reference in references {
+                button {
+                    key: "{reference}",
+                    class: if pinned.as_deref() == Some(reference.as_str()) {
+                        "px-2 py-1 rounded text-xs font-medium bg-green-600 text-white"
+                    } else {
+                        "px-2 py-1 rounded text-xs font-medium bg-green-50 text-green-800 hover:bg-green-100"
+                    },
+                    onclick: {
+                        let reference = reference.clone();
+                        move |_| on_pin.call(reference.clone())
+                    },
+                    "{reference}"
+                }
+            }
+        }
+
+        form {
+            class: "flex gap-2 mb-4",
+            onsubmit: move |evt| {
+                evt.prevent_default();
+                let reference = custom.read().trim().to_string();
+                if !reference.is_empty() {
+                    on_pin.call(reference);
+                }
+            },
+            input {
+                class: "flex-1 px-3 py-1 border border-gray-300 rounded text-sm",
+                placeholder: "Pin another passage, e.g. Romans 8:28",
+                value: "{custom}",
+                oninput: move |evt| custom.set(evt.value()),
+            }
+        }
+
+        match passage.read().as_ref() {
+            None if pinned.is_none() => rsx! {
+                p { class: "text-sm text-gray-500", "Pin a passage to read it alongside the transcript." }
+            },
+            None => rsx! {
+                p { class: "text-sm text-gray-500", "Loading passage..." }
+            },
+            Some(Err(message)) => rsx! {
+                p { class: "text-sm text-red-700", "{message}" }
+            },
+            Some(Ok(loaded)) => rsx! {
+                h4 { class: "font-semibold text-gray-900 mb-2", "{loaded.reference}" }
+                for verse in loaded.verses.iter() {
+                    p {
+                        key: "{verse.chapter}:{verse.verse}",
+                        class: "text-sm text-gray-800 mb-1",
+                        sup { class: "text-green-700 mr-1", "{verse.verse}" }
+                        "{verse.text.trim()}"
+                    }
+                }
+                p { class: "text-xs text-gray-400 mt-3", "{loaded.translation_name}" }
+            },
+        }
+    }
+}
+
+/// Notes for the item; new notes are linked to the pinned passage
+#[component]
+fn NotesPane(
+    item_tx_id: String,
+    notes: Vec<StudyNote>,
+    pinned: Option<String>,
+    on_change: EventHandler<Vec<StudyNote>>,
+    on_pin: EventHandler<String>,
+) -> Element {
+    let mut draft = use_signal(String::new);
+
+    let add_notes = notes.clone();
+    let add_pinned = pinned.clone();
+    let add_note = move |evt: FormEvent| {
+        evt.prevent_default();
+        let text = draft.read().trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        let note = StudyNote::new(&item_tx_id, add_pinned.clone(), &text);
+        let mut updated = add_notes.clone();
+        spawn(async move {
+            match NotesService::save(&note).await {
+                Ok(()) => {
+                    updated.push(note);
+                    on_change.call(updated);
+                    draft.set(String::new());
+                }
+                Err(e) => log::error!("Failed to save study note: {}", e),
+            }
+        });
+    };
+
+    rsx! {
+        form {
+            class: "mb-4",
+            onsubmit: add_note,
+            textarea {
+                class: "w-full px-3 py-2 border border-gray-300 rounded text-sm",
+                rows: "3",
+                placeholder: match pinned.as_ref() {
+                    Some(reference) => format!("Note on {}...", reference),
+                    None => "Write a note...".to_string(),
+                },
+                value: "{draft}",
+                oninput: move |evt| draft.set(evt.value()),
+            }
+            button {
+                class: "mt-2 bg-green-600 hover:bg-green-700 text-white px-4 py-1 rounded text-sm font-medium transition-colors",
+                r#type: "submit",
+                "Add note"
+            }
+        }
+
+        for note in notes.iter() {
+            div {
+                key: "{note.id}",
+                class: if note_is_linked(note, pinned.as_deref()) {
+                    "mb-3 p-3 rounded border border-yellow-300 bg-yellow-50"
+                } else {
+                    "mb-3 p-3 rounded border border-gray-200"
+                },
+                if let Some(passage) = note.passage.as_ref() {
+                    button {
+                        class: "text-xs font-medium text-green-700 hover:underline mb-1",
+                        onclick: {
+                            let passage = passage.clone();
+                            move |_| on_pin.call(passage.clone())
+                        },
+                        "{passage}"
+                    }
+                }
+                p { class: "text-sm text-gray-800 whitespace-pre-line", "{note.text}" }
+                button {
+                    class: "text-xs text-gray-400 hover:text-red-600 mt-1",
+                    onclick: {
+                        let id = note.id.clone();
+                        let remaining: Vec<StudyNote> = notes.iter().filter(|n| n.id != note.id).cloned().collect();
+                        move |_| {
+                            let id = id.clone();
+                            let remaining = remaining.clone();
+                            spawn(async move {
+                                match NotesService::delete(&id).await {
+                                    Ok(()) => on_change.call(remaining),
+                                    Err(e) => log::error!("Failed to delete study note: {}", e),
+                                }
+                            });
+                        }
+                    },
+                    "Delete"
+                }
+            }
+        }
+    }
+}
+
+fn note_is_linked(note: &StudyNote, pinned: Option<&str>) -> bool {
+    let Some(reference) = pinned else {
+        return false;
+    };
+    note.passage.as_deref().is_some_and(|passage| mentions(passage, reference) || mentions(reference, passage))
+        || mentions(&note.text, reference)
+}
@@ -0,0 +1,136 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::address_avatar::AddressAvatar;
+use crate::components::skeleton::CardSkeleton;
+use crate::services::async_state::{use_async_resource, AsyncState};
+use crate::services::hide_registry::is_moderator;
+use crate::services::premoderation::{load_submission_queue, RiskAssessment, RiskFlag, RiskLevel, SUBMISSION_QUEUE_SIZE};
+use crate::services::wallet::{use_wallet_state, WalletService};
+
+/// Moderator queue of new submissions, riskiest first
+#[component]
+pub fn SubmissionQueue() -> Element {
+    let wallet_state = use_wallet_state();
+    let mut queue = use_async_resource(load_submission_queue);
+
+    if !wallet_state.read().address().is_some_and(is_moderator) {
+        return rsx! {
+            main {
+                class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+                div {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-8 text-gray-600",
+                    "Connect a moderation team wallet to review new submissions."
+                }
+            }
+        };
+    }
+
+    rsx! {
+        main {
+            class: "max-w-6xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                class: "flex flex-wrap items-start justify-between gap-3",
+                div {
+                    h2 { class: "text-2xl font-bold text-gray-900", "Review Queue" }
+                    p {
+                        class: "text-sm text-gray-600 mt-1",
+                        "The newest {SUBMISSION_QUEUE_SIZE} submissions no moderator has decided on, with the riskiest first. Flags are hints from automatic checks; nothing is hidden until a moderator decides."
+                    }
+                }
+                div {
+                    class: "flex items-center gap-4 text-sm",
+                    Link { to: Route::ClaimReview {}, class: "text-green-700 hover:underline", "Rights claims" }
+                    Link { to: Route::ModerationAudit {}, class: "text-green-700 hover:underline", "Moderation log" }
+                    button {
+                        class: "border border-green-600 text-green-700 hover:bg-green-50 px-3 py-1 rounded-lg font-medium",
+                        onclick: move |_| queue.restart(),
+                        "Refresh"
+                    }
+                }
+            }
+
+            match queue.state() {
+                AsyncState::Loading => rsx! { CardSkeleton {} },
+                AsyncState::Failed(message) => rsx! {
+                    div {
+                        class: "text-sm text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200",
+                        "Could not load submissions: {message}"
+                    }
+                },
+                AsyncState::Ready(assessments) if assessments.is_empty() => rsx! {
+                    p { class: "text-sm text-gray-500", "Every recent submission has been reviewed." }
+                },
+                AsyncState::Ready(assessments) => rsx! {
+                    div {
+                        class: "bg-white rounded-xl shadow-sm border border-green-200 divide-y divide-gray-100",
+                        for assessment in assessments {
+                            SubmissionRow { key: "{assessment.item.tx_id}", assessment }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn SubmissionRow(assessment: RiskAssessment) -> Element {
+    let badge = match assessment.level() {
+        RiskLevel::Low => "bg-gray-100 text-gray-700",
+        RiskLevel::Medium => "bg-yellow-100 text-yellow-800",
+        RiskLevel::High => "bg-red-100 text-red-800",
+    };
+    let item = &assessment.item;
+
+    rsx! {
+        div {
+            class: "p-4 space-y-2 text-sm",
+            div {
+                class: "flex flex-wrap items-center gap-x-3 gap-y-1",
+                span {
+                    class: "inline-flex items-center px-2 py-1 rounded-full text-xs font-medium {badge}",
+                    title: "Risk score {assessment.score}",
+                    "{assessment.level().display_name()}"
+                }
+                Link {
+                    to: Route::ContentDetail { tx_id: item.tx_id.clone() },
+                    class: "font-medium text-gray-900 hover:text-green-700 hover:underline",
+                    "{item.title}"
+                }
+                span { class: "text-gray-500", "{item.content_type}" }
+                span {
+                    class: "inline-flex items-center gap-1 text-gray-500",
+                    "by"
+                    AddressAvatar { address: item.owner.clone(), size: "w-4 h-4" }
+                    Link {
+                        to: Route::UploaderPage { address: item.owner.clone() },
+                        class: "hover:underline",
+                        title: "{item.owner}",
+                        "{WalletService::format_address(&item.owner)}"
+                    }
+                }
+            }
+            if !assessment.flags.is_empty() {
+                ul {
+                    class: "flex flex-wrap gap-2",
+                    for flag in assessment.flags.iter() {
+                        li {
+                            key: "{flag.description()}",
+                            class: "px-2 py-0.5 rounded bg-amber-50 border border-amber-200 text-amber-900 text-xs",
+                            "{flag.description()}"
+                            if let RiskFlag::DuplicateFile(original) = flag {
+                                " "
+                                Link {
+                                    to: Route::ContentDetail { tx_id: original.clone() },
+                                    class: "underline",
+                                    "(see original)"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use crate::i18n::t;
+use crate::services::sync::{sync_now, use_sync_status};
+
+/// How often the "synced … ago" label is refreshed (ms)
+const LABEL_REFRESH_MS: u32 = 30_000;
+
+/// "Synced 3 min ago" for a pass that finished `elapsed_ms` ago
+fn synced_label(elapsed_ms: f64) -> String {
+    let minutes = (elapsed_ms / 60_000.0).floor() as u64;
+    match minutes {
+        0 => t!("sync.just_now"),
+        1..=59 => t!("sync.minutes_ago", minutes = minutes),
+        _ => t!("sync.hours_ago", hours = minutes / 60),
+    }
+}
+
+/// Header indicator of when background sync last ran; clicking syncs now
+#[component]
+pub fn SyncIndicator() -> Element {
+    let status = use_sync_status();
+    let mut now = use_signal(js_sys::Date::now);
+
+    use_future(move || async move {
+        loop {
+            TimeoutFuture::new(LABEL_REFRESH_MS).await;
+            now.set(js_sys::Date::now());
+        }
+    });
+
+    let status = status();
+    let label = if status.running {
+        t!("sync.syncing")
+    } else {
+        match status.last_synced {
+            Some(finished) => synced_label(now().max(finished) - finished),
+            None => t!("sync.never"),
+        }
+    };
+    let failed = !status.errors.is_empty();
+    let details = status
+        .errors
+        .iter()
+        .map(|(task, error)| format!("{}: {}", task.display_name(), error))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let icon_class = if failed {
+        "text-amber-600"
+    } else if status.running {
+        "animate-spin"
+    } else {
+        ""
+    };
+    let title = if failed { format!("{}\n{}", t!("sync.failed"), details) } else { t!("sync.sync_now") };
+
+    rsx! {
+        button {
+            class: "hidden lg:flex items-center gap-1 px-2 py-1 rounded-lg text-xs text-gray-500 dark:text-gray-400 hover:bg-green-50 dark:hover:bg-gray-800 transition-colors disabled:opacity-60",
+            title: "{title}",
+            disabled: status.running,
+            onclick: move |_| sync_now(),
+            span {
+                class: icon_class,
+                aria_hidden: "true",
+                if failed { "⚠" } else { "⟳" }
+            }
+            "{label}"
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use dioxus::prelude::*;
+use crate::services::settings::update_settings;
+use crate::services::theme::{apply_theme, use_theme, watch_system_theme, ThemeMode};
+
+/// Keeps the page's color scheme in step with the chosen theme
+///
+/// Wrap the app in this once; components read the theme with `use_theme()`
+/// and style themselves with Tailwind `dark:` classes.
+#[component]
+pub fn ThemeProvider(children: Element) -> Element {
+    let theme = use_theme();
+    use_hook(watch_system_theme);
+    use_effect(move || apply_theme(*theme.read()));
+
+    rsx! { {children} }
+}
+
+/// Header button cycling light, dark and system themes
+#[component]
+pub fn ThemeToggle() -> Element {
+    let theme = use_theme();
+    let mode = theme.read().mode;
+    let icon = match mode {
+        ThemeMode::Light => "☀️",
+        ThemeMode::Dark => "🌙",
+        ThemeMode::System => "🖥️",
+    };
+    let next = mode.next();
+
+    rsx! {
+        button {
+            class: "px-3 py-2 rounded-lg text-sm hover:bg-green-50 dark:hover:bg-gray-800 transition-colors",
+            title: "Theme: {mode.display_name()} (switch to {next.display_name()})",
+            aria_label: "Theme: {mode.display_name()}",
+            onclick: move |_| update_settings(|settings| settings.theme = next),
+            "{icon}"
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use dioxus::prelude::*;
+use crate::components::modal::Modal;
+use crate::services::ministry_verification::use_verified_ministry;
+use crate::services::profile::use_uploader_profile;
+use crate::services::tips::{format_amount, parse_amount, TipAsset, TipService};
+use crate::services::toast::{show_toast, ToastKind};
+use crate::services::wallet::{use_wallet_state, WalletService};
+
+/// "Support this ministry" button for content from `uploader`
+///
+/// Hidden unless the uploader's profile has a payment address. Opens a
+/// dialog to pick AR or AO and an amount, shows the network fee, and
+/// reports the receipt in a toast once the tip is sent.
+#[component]
+pub fn SupportButton(uploader: String, content_id: String) -> Element {
+    let profile = use_uploader_profile(uploader.clone());
+    let verified = use_verified_ministry(uploader.clone());
+    let open = use_signal(|| false);
+
+    let Some(recipient) = profile.read().as_ref().and_then(|profile| profile.payment_address.clone()) else {
+        return rsx! {};
+    };
+    let ministry = profile.read().as_ref().map(|profile| profile.ministry_name.clone()).unwrap_or_default();
+    // The domain vouches for the tip address if it lists it, or if tips go
+    // to the uploading address it already lists
+    let verified_by = verified
+        .read()
+        .as_ref()
+        .filter(|verified| recipient == uploader || verified.donation_address.as_ref() == Some(&recipient))
+        .map(|verified| verified.domain.clone());
+
+    rsx! {
+        button {
+            class: "px-3 py-1 rounded-full text-xs font-medium border border-amber-500 text-amber-700 hover:bg-amber-50",
+            onclick: move |_| {
+                let mut open = open;
+                open.set(true);
+            },
+            "🙏 Support this ministry"
+        }
+        TipDialog { open, recipient, ministry, content_id, verified_by }
+    }
+}
+
+#[component]
+fn TipDialog(open: Signal<bool>, recipient: String, ministry: String, content_id: String, verified_by: Option<String>) -> Element {
+    let wallet_state = use_wallet_state();
+    let mut asset = use_signal(|| TipAsset::Ar);
+    let mut amount = use_signal(|| TipAsset::Ar.presets()[0].to_string());
+    let mut fee = use_signal(|| None::<Result<u128, String>>);
+    let mut sending = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+
+    let fee_recipient = recipient.clone();
+    use_effect(move || {
+        if !*open.read() {
+            return;
+        }
+        let asset = *asset.read();
+        let recipient = fee_recipient.clone();
+        fee.set(None);
+        spawn(async move {
+            let result = TipService::new().network_fee(asset, &recipient).await.map_err(|e| e.to_string());
+            fee.set(Some(result));
+        });
+    });
+
+    let parsed = parse_amount(&amount.read());
+    let symbol = asset.read().symbol();
+    let connected = wallet_state.read().is_connected();
+
+    let send = {
+        let recipient = recipient.clone();
+        let content_id = content_id.clone();
+        move |_| {
+            let Ok(units) = parse_amount(&amount.read()) else {
+                return;
+            };
+            let asset = *asset.read();
+            let recipient = recipient.clone();
+            let content_id = content_id.clone();
+            sending.set(true);
+            error.set(None);
+            spawn(async move {
+                match TipService::new().send(asset, &recipient, units, &content_id).await {
+                    Ok(receipt) => {
+                        open.set(false);
+                        show_toast(
+                            ToastKind::Success,
+                            format!(
+                                "Thank you! Sent {} {}. Receipt: {}",
+                                format_amount(units),
+                                asset.symbol(),
+                                WalletService::format_address(&receipt)
+                            ),
+                        );
+                    }
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+                sending.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        Modal {
+            open,
+            title: "Support {ministry}",
+            div {
+                class: "space-y-4 text-sm",
+
+                p { class: "text-gray-600", "Tips go straight from your wallet to the ministry's address, {WalletService::format_address(&recipient)}." }
+                match verified_by.as_ref() {
+                    Some(domain) => rsx! { p { class: "text-green-800", "✓ {domain} lists this address as its own." } },
+                    None => rsx! { p { class: "text-amber-800", "This address isn't verified by the ministry's website. Check it with them before sending a large gift." } },
+                }
+
+                div {
+                    class: "flex gap-2",
+                    for option in TipAsset::ALL {
+                        button {
+                            key: "{option.symbol()}",
+                            class: if *asset.read() == option {
+                                "flex-1 px-3 py-2 rounded-md bg-green-600 text-white font-medium"
+                            } else {
+                                "flex-1 px-3 py-2 rounded-md border border-gray-300 text-gray-700 hover:bg-gray-50"
+                            },
+                            "data-arrow-nav": "true",
+                            onclick: move |_| {
+                                asset.set(option);
+                                amount.set(option.presets()[0].to_string());
+                            },
+                            "{option.symbol()}"
+                        }
+                    }
+                }
+
+                div {
+                    label { class: "block font-medium text-gray-700 mb-1", r#for: "tip-amount", "Amount ({symbol})" }
+                    input {
+                        id: "tip-amount",
+                        class: "w-full px-3 py-2 border border-gray-300 rounded-md",
+                        value: "{amount}",
+                        oninput: move |evt| amount.set(evt.value()),
+                    }
+                    div {
+                        class: "flex gap-2 mt-2",
+                        for preset in asset.read().presets().iter().copied() {
+                            button {
+                                key: "{preset}",
+                                class: "px-2 py-1 rounded border border-gray-300 text-xs text-gray-700 hover:bg-gray-50",
+                                onclick: move |_| amount.set(preset.to_string()),
+                                "{preset} {symbol}"
+                            }
+                        }
+                    }
+                    if let Err(e) = &parsed {
+                        p { class: "mt-1 text-xs text-red-600", "{e}" }
+                    }
+                }
+
+                dl {
+                    class: "bg-gray-50 border border-gray-200 rounded-lg p-3 space-y-1",
+                    div {
+                        class: "flex justify-between",
+                        dt { class: "text-gray-600", "Network fee" }
+                        dd {
+                            class: "text-gray-900",
+                            match fee.read().as_ref() {
+                                None => rsx! { "Checking…" },
+                                Some(Ok(0)) => rsx! { "None" },
+                                Some(Ok(units)) => rsx! { "{format_amount(*units)} {symbol}" },
+                                Some(Err(e)) => rsx! { span { class: "text-red-600", "{e}" } },
+                            }
+                        }
+                    }
+                    if let (Ok(units), Some(Ok(fee_units))) = (&parsed, fee.read().as_ref()) {
+                        div {
+                            class: "flex justify-between font-medium",
+                            dt { class: "text-gray-700", "Total" }
+                            dd { class: "text-gray-900", "{format_amount(units + fee_units)} {symbol}" }
+                        }
+                    }
+                }
+
+                if let Some(e) = error.read().as_ref() {
+                    p { class: "text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200", "{e}" }
+                }
+                if !connected {
+                    p { class: "text-amber-800 bg-amber-50 px-3 py-2 rounded border border-amber-200", "Connect your wallet to send a tip." }
+                }
+
+                div {
+                    class: "flex justify-end gap-2",
+                    button {
+                        class: "px-4 py-2 rounded-md text-gray-700 hover:bg-gray-100",
+                        onclick: move |_| open.set(false),
+                        "Cancel"
+                    }
+                    button {
+                        class: "px-4 py-2 rounded-md bg-green-600 text-white font-medium hover:bg-green-700 disabled:opacity-50",
+                        disabled: !connected || parsed.is_err() || *sending.read() || !matches!(fee.read().as_ref(), Some(Ok(_))),
+                        onclick: send,
+                        if *sending.read() { "Sending…" } else { "Send tip" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use dioxus::prelude::*;
+use crate::error::AppError;
+use crate::services::recovery::{crash_report, preserve_drafts, reload_app};
+use crate::services::toast::{dismiss_toast, use_toasts, ToastKind};
+use crate::utils::display::use_copy_feedback;
+
+/// Stack of toasts in the bottom corner; rendered once by the layout
+#[component]
+pub fn ToastHost() -> Element {
+    let toasts = use_toasts();
+
+    rsx! {
+        div {
+            class: "fixed bottom-4 right-4 z-50 flex flex-col gap-2 max-w-sm",
+            for toast in toasts.read().iter() {
+                div {
+                    key: "{toast.id}",
+                    class: match toast.kind {
+                        ToastKind::Success => "flex items-start gap-3 rounded-lg shadow-md border px-4 py-3 text-sm bg-green-50 border-green-200 text-green-800",
+                        ToastKind::Info => "flex items-start gap-3 rounded-lg shadow-md border px-4 py-3 text-sm bg-white border-gray-200 text-gray-800",
+                        ToastKind::Error => "flex items-start gap-3 rounded-lg shadow-md border px-4 py-3 text-sm bg-red-50 border-red-200 text-red-800",
+                    },
+                    role: "status",
+                    div {
+                        class: "flex-1",
+                        p { "{toast.message}" }
+                        if let Some(code) = toast.code {
+                            p { class: "text-xs opacity-60 font-mono mt-1", "{code}" }
+                        }
+                        if let Some(action) = toast.action.clone() {
+                            button {
+                                class: "mt-2 font-medium underline hover:no-underline",
+                                onclick: {
+                                    let id = toast.id;
+                                    move |_| {
+                                        action.on_click.call(());
+                                        dismiss_toast(id);
+                                    }
+                                },
+                                "{action.label}"
+                            }
+                        }
+                    }
+                    button {
+                        class: "opacity-60 hover:opacity-100",
+                        aria_label: "Dismiss",
+                        onclick: {
+                            let id = toast.id;
+                            move |_| dismiss_toast(id)
+                        },
+                        "✕"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Catches errors thrown while rendering a page and shows them in place
+/// of the page, without taking down the header and footer
+#[component]
+pub fn AppErrorBoundary(children: Element) -> Element {
+    rsx! {
+        ErrorBoundary {
+            handle_error: |errors: ErrorContext| {
+                let error = errors.errors().first().cloned();
+                let app_error = error.as_ref().and_then(|error| error.downcast::<AppError>().cloned());
+                let (message, code) = match (&app_error, &error) {
+                    (Some(app_error), _) => (app_error.user_message(), app_error.code()),
+                    (None, Some(error)) => (error.to_string(), "UNEXPECTED"),
+                    (None, None) => ("Something went wrong".to_string(), "UNEXPECTED"),
+                };
+                if let Some(app_error) = &app_error {
+                    log::error!("❌ [{}] {}", code, app_error.debug_detail());
+                }
+                rsx! {
+                    main {
+                        class: "max-w-3xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+                        div {
+                            class: "bg-red-50 border border-red-200 rounded-lg p-6 space-y-2",
+                            h3 { class: "text-sm font-medium text-red-800", "This page couldn't be shown" }
+                            p { class: "text-sm text-red-700", "{message}" }
+                            p { class: "text-xs text-red-600 font-mono", "{code}" }
+                            button {
+                                class: "text-sm text-red-700 hover:text-red-800 underline",
+                                onclick: move |_| errors.clear_errors(),
+                                "Try again"
+                            }
+                        }
+                    }
+                }
+            },
+            {children}
+        }
+    }
+}
+
+/// Last line of defence around the router: an error that escapes every
+/// page boundary replaces the whole app with a recovery screen
+#[component]
+pub fn CrashBoundary(children: Element) -> Element {
+    rsx! {
+        ErrorBoundary {
+            handle_error: |errors: ErrorContext| {
+                let details = errors
+                    .errors()
+                    .iter()
+                    .map(|error| match error.downcast::<AppError>() {
+                        Some(app_error) => format!("[{}] {}", app_error.code(), app_error.debug_detail()),
+                        None => error.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                log::error!("💥 App crashed: {}", details);
+                // Save straight away in case the visitor closes the tab
+                // instead of reloading
+                preserve_drafts();
+                rsx! { CrashScreen { details } }
+            },
+            {children}
+        }
+    }
+}
+
+#[component]
+fn CrashScreen(details: String) -> Element {
+    let copy = use_copy_feedback();
+    let report = crash_report(&details);
+
+    rsx! {
+        div {
+            class: "min-h-screen flex items-center justify-center bg-gradient-to-br from-green-50 to-green-100 dark:from-gray-950 dark:to-gray-900 p-4",
+            div {
+                class: "bg-white dark:bg-gray-900 rounded-xl shadow-sm border border-green-200 dark:border-gray-800 p-8 max-w-lg space-y-4",
+                h2 { class: "text-2xl font-bold text-gray-900 dark:text-white", "Something went wrong" }
+                p {
+                    class: "text-gray-600 dark:text-gray-400",
+                    "Faithful Archive hit an unexpected error. Reloading usually fixes it, and any upload details you were typing will be restored."
+                }
+                div {
+                    class: "flex flex-wrap items-center gap-2",
+                    button {
+                        class: "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        onclick: move |_| reload_app(),
+                        "Reload"
+                    }
+                    button {
+                        class: "bg-white hover:bg-green-50 text-green-700 border border-green-300 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        onclick: move |_| copy.copy(report.clone()),
+                        if copy.copied() { "Copied" } else { "Copy error details" }
+                    }
+                    if let Some(error) = copy.error() {
+                        span { class: "text-sm text-red-700", "{error}" }
+                    }
+                }
+                details {
+                    class: "text-xs text-gray-500",
+                    summary { class: "cursor-pointer", "Error details" }
+                    pre { class: "mt-2 whitespace-pre-wrap break-all font-mono", "{details}" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use dioxus::prelude::*;
+use crate::components::modal::Modal;
+use crate::services::tips::{format_amount, parse_amount};
+use crate::services::toast::{show_toast, ToastKind};
+use crate::services::turbo::{
+    refresh_credit_balance, use_credit_balance, watch_for_top_up, TurboError, TurboService, CHECKOUT_PRESETS,
+    MIN_CHECKOUT_USD,
+};
+use crate::services::wallet::{use_wallet_state, WalletService};
+
+/// How a top-up is paid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PayWith {
+    Card,
+    Ar,
+}
+
+/// "Top up storage credits" button opening `TopUpDialog`
+#[component]
+pub fn TopUpButton(#[props(default)] needed_winc: Option<u128>) -> Element {
+    let mut open = use_signal(|| false);
+    if !TurboService::new().is_available() {
+        return rsx! {};
+    }
+    rsx! {
+        button {
+            class: "px-3 py-1 rounded-md text-sm font-medium bg-green-600 text-white hover:bg-green-700",
+            r#type: "button",
+            onclick: move |_| open.set(true),
+            "Top up storage credits"
+        }
+        TopUpDialog { open, needed_winc }
+    }
+}
+
+/// Buy upload credits for the connected wallet by card or with AR
+///
+/// Card payments open Turbo's checkout in a new tab and the balance is
+/// checked until it goes up; AR top-ups are a transfer from the wallet.
+#[component]
+pub fn TopUpDialog(open: Signal<bool>, #[props(default)] needed_winc: Option<u128>) -> Element {
+    let wallet_state = use_wallet_state();
+    let balance = use_credit_balance();
+    let mut pay_with = use_signal(|| PayWith::Card);
+    let mut usd = use_signal(|| CHECKOUT_PRESETS[0].to_string());
+    let mut ar_amount = use_signal(|| "0.1".to_string());
+    let mut credits_quote = use_signal(|| None::<Result<u128, String>>);
+    let mut busy = use_signal(|| false);
+    let mut checkout_opened = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    // AR transfer that went out but wasn't recorded for credits
+    let mut unclaimed = use_signal(|| None::<String>);
+
+    let address = wallet_state.read().address().map(str::to_string);
+
+    // Fresh balance each time the dialog opens
+    let balance_address = address.clone();
+    use_effect(move || {
+        if !*open.read() {
+            return;
+        }
+        checkout_opened.set(false);
+        if let Some(address) = balance_address.clone() {
+            spawn(async move {
+                if let Err(e) = refresh_credit_balance(&address).await {
+                    error.set(Some(e.to_string()));
+                }
+            });
+        }
+    });
+
+    // What the AR entered buys
+    use_effect(move || {
+        if *pay_with.read() != PayWith::Ar {
+            return;
+        }
+        let Ok(winston) = parse_amount(&ar_amount.read()) else {
+            credits_quote.set(None);
+            return;
+        };
+        spawn(async move {
+            let quote = TurboService::new().credits_for_ar(winston).await.map_err(|e| e.to_string());
+            credits_quote.set(Some(quote));
+        });
+    });
+
+    let balance_winc = balance
+        .as_ref()
+        .filter(|balance| Some(&balance.address) == address.as_ref())
+        .map(|balance| balance.winc);
+    let usd_amount = usd.read().trim().parse::<u32>().ok().filter(|usd| *usd >= MIN_CHECKOUT_USD);
+    let ar_winston = parse_amount(&ar_amount.read());
+    let ready = match *pay_with.read() {
+        PayWith::Card => usd_amount.is_some(),
+        PayWith::Ar => ar_winston.is_ok(),
+    };
+
+    let pay = {
+        let address = address.clone();
+        move |_| {
+            let Some(address) = address.clone() else {
+                return;
+            };
+            let method = *pay_with.read();
+            let before = balance_winc.unwrap_or_default();
+            busy.set(true);
+            error.set(None);
+            spawn(async move {
+                let service = TurboService::new();
+                match method {
+                    PayWith::Card => match service.checkout_url(&address, usd_amount.unwrap_or(MIN_CHECKOUT_USD)).await {
+                        Ok(url) => {
+                            if let Some(window) = web_sys::window() {
+                                let _ = window.open_with_url_and_target(&url, "_blank");
+                            }
+                            checkout_opened.set(true);
+                            watch_for_top_up(address, before);
+                        }
+                        Err(e) => error.set(Some(e.to_string())),
+                    },
+                    PayWith::Ar => {
+                        let Ok(winston) = parse_amount(&ar_amount.read()) else {
+                            busy.set(false);
+                            return;
+                        };
+                        match service.top_up_with_ar(winston).await {
+                            Ok(tx_id) => {
+                                open.set(false);
+                                show_toast(
+                                    ToastKind::Success,
+                                    format!(
+                                        "Sent {} AR for credits ({}). They're added once the transfer is mined.",
+                                        format_amount(winston),
+                                        WalletService::format_address(&tx_id)
+                                    ),
+                                );
+                                watch_for_top_up(address, before);
+                            }
+                            Err(e) => {
+                                if let TurboError::ClaimFailed { tx_id, .. } = &e {
+                                    unclaimed.set(Some(tx_id.clone()));
+                                }
+                                error.set(Some(e.to_string()));
+                            }
+                        }
+                    }
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let claim = {
+        let address = address.clone();
+        move |_| {
+            let (Some(address), Some(tx_id)) = (address.clone(), unclaimed()) else {
+                return;
+            };
+            let before = balance_winc.unwrap_or_default();
+            busy.set(true);
+            error.set(None);
+            spawn(async move {
+                match TurboService::new().claim_ar_transfer(&tx_id).await {
+                    Ok(()) => {
+                        unclaimed.set(None);
+                        open.set(false);
+                        show_toast(
+                            ToastKind::Success,
+                            format!(
+                                "Submitted transfer {} for credits. They're added once it's mined.",
+                                WalletService::format_address(&tx_id)
+                            ),
+                        );
+                        watch_for_top_up(address, before);
+                    }
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let refresh = {
+        let address = address.clone();
+        move |_| {
+            if let Some(address) = address.clone() {
+                spawn(async move {
+                    if let Err(e) = refresh_credit_balance(&address).await {
+                        error.set(Some(e.to_string()));
+                    }
+                });
+            }
+        }
+    };
+
+    rsx! {
+        Modal {
+            open,
+            title: "Top up storage credits",
+            div {
+                class: "space-y-4 text-sm",
+
+                p { class: "text-gray-600", "Uploads above the bundler's free allowance are paid from your wallet's credit balance." }
+
+                dl {
+                    class: "bg-gray-50 border border-gray-200 rounded-lg p-3 space-y-1",
+                    div {
+                        class: "flex justify-between",
+                        dt { class: "text-gray-600", "Balance" }
+                        dd {
+                            class: "text-gray-900",
+                            match balance_winc {
+                                Some(winc) => rsx! { "{format_amount(winc)} credits" },
+                                None => rsx! { "Checking…" },
+                            }
+                        }
+                    }
+                    if let Some(needed) = needed_winc {
+                        div {
+                            class: "flex justify-between font-medium",
+                            dt { class: "text-gray-700", "Still needed for this upload" }
+                            dd { class: "text-gray-900", "{format_amount(needed)} credits" }
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex gap-2",
+                    for (option, label) in [(PayWith::Card, "Card"), (PayWith::Ar, "AR")] {
+                        button {
+                            key: "{label}",
+                            class: if *pay_with.read() == option {
+                                "flex-1 px-3 py-2 rounded-md bg-green-600 text-white font-medium"
+                            } else {
+                                "flex-1 px-3 py-2 rounded-md border border-gray-300 text-gray-700 hover:bg-gray-50"
+                            },
+                            "data-arrow-nav": "true",
+                            onclick: move |_| pay_with.set(option),
+                            "{label}"
+                        }
+                    }
+                }
+
+                match *pay_with.read() {
+                    PayWith::Card => rsx! {
+                        div {
+                            label { class: "block font-medium text-gray-700 mb-1", r#for: "top-up-usd", "Amount (USD)" }
+                            input {
+                                id: "top-up-usd",
+                                class: "w-full px-3 py-2 border border-gray-300 rounded-md",
+                                inputmode: "numeric",
+                                value: "{usd}",
+                                oninput: move |evt| usd.set(evt.value()),
+                            }
+                            div {
+                                class: "flex gap-2 mt-2",
+                                for preset in CHECKOUT_PRESETS.iter().copied() {
+                                    button {
+                                        key: "{preset}",
+                                        class: "px-2 py-1 rounded border border-gray-300 text-xs text-gray-700 hover:bg-gray-50",
+                                        onclick: move |_| usd.set(preset.to_string()),
+                                        "${preset}"
+                                    }
+                                }
+                            }
+                            if usd_amount.is_none() {
+                                p { class: "mt-1 text-xs text-red-600", "Enter a whole number of dollars, at least ${MIN_CHECKOUT_USD}" }
+                            }
+                        }
+                        if *checkout_opened.read() {
+                            div {
+                                class: "text-blue-800 bg-blue-50 px-3 py-2 rounded border border-blue-200 flex items-center justify-between gap-2",
+                                "Finish paying in the new tab; the balance here updates on its own."
+                                button {
+                                    class: "text-blue-700 underline shrink-0",
+                                    onclick: refresh,
+                                    "Refresh"
+                                }
+                            }
+                        }
+                    },
+                    PayWith::Ar => rsx! {
+                        div {
+                            label { class: "block font-medium text-gray-700 mb-1", r#for: "top-up-ar", "Amount (AR)" }
+                            input {
+                                id: "top-up-ar",
+                                class: "w-full px-3 py-2 border border-gray-300 rounded-md",
+                                value: "{ar_amount}",
+                                oninput: move |evt| ar_amount.set(evt.value()),
+                            }
+                            match (&ar_winston, credits_quote.read().as_ref()) {
+                                (Err(e), _) => rsx! { p { class: "mt-1 text-xs text-red-600", "{e}" } },
+                                (Ok(_), Some(Ok(winc))) => rsx! { p { class: "mt-1 text-xs text-gray-500", "Buys about {format_amount(*winc)} credits, plus the network fee" } },
+                                (Ok(_), Some(Err(e))) => rsx! { p { class: "mt-1 text-xs text-red-600", "{e}" } },
+                                (Ok(_), None) => rsx! {},
+                            }
+                        }
+                    },
+                }
+
+                if let Some(e) = error.read().as_ref() {
+                    p { class: "text-red-700 bg-red-50 px-3 py-2 rounded border border-red-200", "{e}" }
+                }
+                if unclaimed.read().is_some() {
+                    div {
+                        class: "text-amber-800 bg-amber-50 px-3 py-2 rounded border border-amber-200 flex items-center justify-between gap-2",
+                        "Your AR is safe. Submit the transfer again to get the credits."
+                        button {
+                            class: "text-amber-900 underline shrink-0 disabled:opacity-50",
+                            disabled: *busy.read(),
+                            onclick: claim,
+                            "Submit again"
+                        }
+                    }
+                }
+                if address.is_none() {
+                    p { class: "text-amber-800 bg-amber-50 px-3 py-2 rounded border border-amber-200", "Connect your wallet to buy credits." }
+                }
+
+                div {
+                    class: "flex justify-end gap-2",
+                    button {
+                        class: "px-4 py-2 rounded-md text-gray-700 hover:bg-gray-100",
+                        onclick: move |_| open.set(false),
+                        if *checkout_opened.read() { "Done" } else { "Cancel" }
+                    }
+                    button {
+                        class: "px-4 py-2 rounded-md bg-green-600 text-white font-medium hover:bg-green-700 disabled:opacity-50",
+                        // Sending again would pay twice for the same credits
+                        disabled: address.is_none() || !ready || *busy.read() || unclaimed.read().is_some(),
+                        onclick: pay,
+                        match (*busy.read(), *pay_with.read()) {
+                            (true, _) => "Working…",
+                            (false, PayWith::Card) => "Continue to checkout",
+                            (false, PayWith::Ar) => "Send AR",
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
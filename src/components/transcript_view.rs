@@ -0,0 +1,85 @@
+use dioxus::prelude::*;
+use crate::components::media_player::seek_player;
+use crate::services::playback::format_position;
+use crate::services::transcript::{Transcript, TranscriptService};
+
+fn segment_id(index: usize) -> String {
+    format!("transcript-segment-{}", index)
+}
+
+/// Scroll the transcript pane so a segment is visible, without moving the page
+fn scroll_to_segment(index: usize) {
+    let Some(element) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(&segment_id(index)))
+    else {
+        return;
+    };
+    let options = web_sys::ScrollIntoViewOptions::new();
+    options.set_block(web_sys::ScrollLogicalPosition::Nearest);
+    element.scroll_into_view_with_scroll_into_view_options(&options);
+}
+
+/// Transcript shown next to the player, following playback
+///
+/// The segment being spoken is highlighted and kept in view; clicking a
+/// timed segment seeks the player of `media_tx_id` to it.
+#[component]
+pub fn TranscriptView(media_tx_id: String, transcript_tx_id: String, current_time: f64) -> Element {
+    let mut transcript = use_signal(|| None::<Result<Transcript, String>>);
+
+    use_effect(use_reactive!(|transcript_tx_id| {
+        spawn(async move {
+            let loaded = TranscriptService::new().load(&transcript_tx_id).await.map_err(|e| e.to_string());
+            transcript.set(Some(loaded));
+        });
+    }));
+
+    let active = match transcript.read().as_ref() {
+        Some(Ok(loaded)) => loaded.segment_at(current_time),
+        _ => None,
+    };
+    use_effect(use_reactive!(|active| {
+        if let Some(index) = active {
+            scroll_to_segment(index);
+        }
+    }));
+
+    rsx! {
+        section {
+            class: "bg-white rounded-xl border border-gray-200 p-4 max-h-80 overflow-y-auto",
+            h3 { class: "text-sm font-semibold text-gray-900 mb-2", "Transcript" }
+
+            match transcript.read().as_ref() {
+                None => rsx! { p { class: "text-sm text-gray-500", "Loading transcript..." } },
+                Some(Err(message)) => rsx! { p { class: "text-sm text-red-700", "{message}" } },
+                Some(Ok(loaded)) => rsx! {
+                    for (index, segment) in loaded.segments.iter().enumerate() {
+                        p {
+                            key: "{index}",
+                            id: segment_id(index),
+                            class: match (active == Some(index), segment.start.is_some()) {
+                                (true, _) => "text-sm text-gray-900 p-2 rounded bg-yellow-100",
+                                (false, true) => "text-sm text-gray-700 p-2 rounded cursor-pointer hover:bg-gray-50",
+                                (false, false) => "text-sm text-gray-700 p-2",
+                            },
+                            onclick: {
+                                let media_tx_id = media_tx_id.clone();
+                                let start = segment.start;
+                                move |_| {
+                                    if let Some(start) = start {
+                                        seek_player(&media_tx_id, start);
+                                    }
+                                }
+                            },
+                            if let Some(start) = segment.start {
+                                span { class: "text-xs text-green-700 font-mono mr-2", "{format_position(start)}" }
+                            }
+                            "{segment.text}"
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
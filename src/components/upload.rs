@@ -0,0 +1,1092 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::audio_trim::AudioTrimmer;
+use crate::components::forms::{ipfs_cid, language_tag, max_length, required, transaction_id, use_field, use_form, ErrorSummary, FormStatus, SubmitButton, TextField};
+use crate::components::top_up::TopUpButton;
+use crate::components::upload_failure::UploadFailureDetails;
+use crate::config::app_config;
+use crate::error::AppError;
+use crate::models::{CommercialUse, ContentMetadata, Derivation, FeeInterval, LicenseFee, RightsBasis, RightsDeclaration, UdlTerms};
+use crate::services::audio_prep::media_duration;
+use crate::services::analytics::{record_usage, UploadStep, UsageEvent};
+use crate::services::dedupe::DedupeService;
+use crate::services::encryption::ContentKey;
+use crate::services::file_reader::LocalFile;
+use crate::services::image_prep::{optimize_image, ImageOptions, OptimizedImage};
+use crate::services::playback::format_position;
+use crate::services::pricing::PricingService;
+use crate::services::recovery::{take_upload_draft, track_upload_draft, UploadDraft};
+use crate::services::scanning::{ScanFile, ScanReport, ScanService, ScanSeverity};
+use crate::services::series::{SeriesService, SeriesSummary};
+use crate::services::settings::use_settings;
+use crate::services::tips::format_amount;
+use crate::services::toast::show_error;
+use crate::services::transfer::TransferControl;
+use crate::services::turbo::{estimate_upload, use_credit_balance, UploadEstimate};
+use crate::services::upload::{content_type_for, PublishedParts, SeriesChoice, UploadMethod, UploadRequest, UploadService};
+use crate::services::upload_failure::UploadFailure;
+use crate::services::wallet::{use_network_mismatch, use_wallet_state, WalletService};
+use crate::services::worker::FileDigest;
+use crate::utils::constants::{DISPATCH_FREE_LIMIT, SCAN_READ_LIMIT};
+use crate::utils::display::format_size;
+use crate::utils::language::{browser_language, language_name, normalize_language_tag};
+
+/// Value of the series selector's "new series" option
+const NEW_SERIES: &str = "__new__";
+
+/// Values of the privacy selector's encrypted options
+const ENCRYPT_PASSPHRASE: &str = "passphrase";
+const ENCRYPT_WALLET: &str = "wallet";
+
+/// Upload form for publishing a new item from the connected wallet
+#[component]
+pub fn Upload() -> Element {
+    let wallet_state = use_wallet_state();
+    // Details kept from before the app crashed and was reloaded
+    let restored = use_hook(take_upload_draft);
+    let suggested_language = use_hook(browser_language);
+    let mut title = use_field("Title", vec![required(), max_length(200)]);
+    let mut description = use_field("Description", vec![max_length(5000)]);
+    let mut speaker = use_field("Speaker", vec![max_length(120)]);
+    let mut ministry = use_field("Ministry", vec![max_length(120)]);
+    let mut scripture_refs = use_field("Scripture references", vec![]);
+    let mut language = use_field("Language", vec![max_length(35), language_tag()]);
+    let mirror_cid = use_field("IPFS CID (optional)", vec![ipfs_cid()]);
+    let previous_version = use_field("Replaces an earlier upload (optional)", vec![transaction_id()]);
+    let mut form = use_form(&[title, description, speaker, ministry, scripture_refs, language, mirror_cid, previous_version]);
+    let mut series_choice = use_signal(|| restored.as_ref().map(|draft| draft.series_choice.clone()).unwrap_or_default());
+    let mut new_series_title = use_signal(|| restored.as_ref().map(|draft| draft.new_series_title.clone()).unwrap_or_default());
+    let mut file = use_signal(|| None::<LocalFile>);
+    let mut duration = use_signal(|| None::<u32>);
+    let mut file_digest = use_signal(|| None::<FileDigest>);
+    let mut hash_progress = use_signal(|| None::<f64>);
+    let mut existing = use_signal(|| None::<ContentMetadata>);
+    let mut upload_anyway = use_signal(|| false);
+    let mut privacy = use_signal(|| restored.as_ref().map(|draft| draft.privacy.clone()).unwrap_or_default());
+    let mut passphrase = use_signal(String::new);
+    let settings = use_settings();
+    let license = use_signal(|| match restored.as_ref() {
+        Some(draft) => draft.license.clone(),
+        None => settings.peek().default_license.clone(),
+    });
+    let rights = use_signal(|| restored.as_ref().and_then(|draft| draft.rights.clone()).unwrap_or_default());
+    let rights_confirmed = use_signal(|| false);
+    let mut transcript_file = use_signal(|| None::<(String, Vec<u8>)>);
+    let mut cover = use_signal(|| None::<OptimizedImage>);
+    let mut cover_note = use_signal(|| None::<String>);
+    let mut scan_report = use_signal(|| None::<ScanReport>);
+    let mut accept_scan_warnings = use_signal(|| false);
+    let method = use_signal(UploadMethod::default);
+    let mut is_linking = use_signal(|| false);
+    let mut uploaded = use_signal(|| None::<String>);
+    // What the last attempt got out before failing, carried into a retry
+    let mut published = use_signal(PublishedParts::default);
+    let mut failure = use_signal(|| None::<UploadFailure>);
+
+    let owner = wallet_state.read().address().map(str::to_string);
+    let wrong_network = use_network_mismatch();
+    // Each item is published and paid for separately
+    let item_sizes: Vec<usize> = file
+        .read()
+        .iter()
+        .map(|chosen| chosen.size() as usize)
+        .chain(transcript_file.read().iter().map(|(_, bytes)| bytes.len()))
+        .chain(cover.read().iter().map(|image| image.bytes.len()))
+        .collect();
+
+    use_effect(|| record_usage(UsageEvent::Upload(UploadStep::Opened)));
+
+    // Fill the fields from a restored draft once, or else suggest the
+    // browser's language
+    let restored_fields = restored.clone();
+    let suggestion = suggested_language.clone();
+    use_effect(move || {
+        if let Some(draft) = restored_fields.clone() {
+            title.set(draft.title);
+            description.set(draft.description);
+            speaker.set(draft.speaker);
+            ministry.set(draft.ministry);
+            scripture_refs.set(draft.scripture_refs);
+            language.set(draft.language);
+        } else if let Some(suggested) = suggestion.clone() {
+            language.load(suggested);
+        }
+    });
+
+    // Keep a copy of unsaved details for the crash screen to preserve
+    use_effect(move || {
+        let published = uploaded.read().is_some();
+        track_upload_draft((!published).then(|| UploadDraft {
+            title: title.value(),
+            description: description.value(),
+            speaker: speaker.value(),
+            ministry: ministry.value(),
+            scripture_refs: scripture_refs.value(),
+            language: language.value(),
+            series_choice: series_choice.read().clone(),
+            new_series_title: new_series_title.read().clone(),
+            privacy: privacy.read().clone(),
+            license: license.read().clone(),
+            rights: Some(rights.read().clone()),
+            file_name: file.read().as_ref().map(|chosen| chosen.name().to_string()),
+        }));
+    });
+    use_drop(|| track_upload_draft(None));
+
+    // Hash the chosen (or trimmed) file a slice at a time
+    use_effect(move || {
+        file_digest.set(None);
+        existing.set(None);
+        upload_anyway.set(false);
+        let Some(chosen) = file.read().clone() else {
+            return;
+        };
+        spawn(async move {
+            hash_progress.set(Some(0.0));
+            let result = chosen.digest(move |fraction| hash_progress.set(Some(fraction))).await;
+            hash_progress.set(None);
+            match result {
+                // Ignore digests of a file that has since been replaced
+                Ok(digest) if file.peek().as_ref() == Some(&chosen) => {
+                    match DedupeService::new().find_existing(&digest.sha256).await {
+                        Ok(found) => existing.set(found),
+                        Err(e) => log::warn!("Could not check for an existing copy: {}", e),
+                    }
+                    file_digest.set(Some(digest));
+                }
+                Ok(_) => {}
+                Err(e) => log::warn!("Could not hash upload: {}", e),
+            }
+        });
+    });
+
+    // Read the running time of audio and video from the file's metadata
+    use_effect(move || {
+        duration.set(None);
+        let Some(chosen) = file.read().clone() else {
+            return;
+        };
+        let content_type = content_type_for(chosen.name());
+        if !content_type.starts_with("audio/") && !content_type.starts_with("video/") {
+            return;
+        }
+        spawn(async move {
+            let secs = media_duration(&chosen, content_type).await;
+            // Ignore the result for a file that has since been replaced
+            if file.peek().as_ref() == Some(&chosen) {
+                duration.set(secs);
+            }
+        });
+    });
+
+    // Scan the files as they're chosen, so problems show before submitting;
+    // publishing scans them again
+    use_effect(move || {
+        scan_report.set(None);
+        accept_scan_warnings.set(false);
+        let main = file.read().clone();
+        let transcript = transcript_file.read().clone();
+        let cover_image = cover.read().clone();
+        if main.is_none() && transcript.is_none() && cover_image.is_none() {
+            return;
+        }
+        spawn(async move {
+            // Large recordings are only checked by their header
+            let main = match main {
+                Some(chosen) => match chosen.read_head(SCAN_READ_LIMIT).await {
+                    Ok(bytes) => Some((chosen.name().to_string(), bytes)),
+                    Err(e) => {
+                        log::warn!("Could not read {} to scan it: {}", chosen.name(), e);
+                        None
+                    }
+                },
+                None => None,
+            };
+            let mut files = Vec::new();
+            if let Some((name, bytes)) = &main {
+                files.push(ScanFile { label: "Main file", content_type: content_type_for(name), data: bytes });
+            }
+            if let Some((name, bytes)) = &transcript {
+                files.push(ScanFile { label: "Transcript", content_type: content_type_for(name), data: bytes });
+            }
+            if let Some(image) = &cover_image {
+                files.push(ScanFile { label: "Cover image", content_type: &image.content_type, data: &image.bytes });
+            }
+            let report = ScanService::new().scan(&files).await;
+            scan_report.set(Some(report));
+        });
+    });
+
+    let select_file = move |evt: FormEvent| async move {
+        let Some(file_engine) = evt.files() else {
+            return;
+        };
+        if let Some(name) = file_engine.files().into_iter().next() {
+            match LocalFile::from_engine(&file_engine, &name).await {
+                Some(chosen) => {
+                    file.set(Some(chosen));
+                    published.set(PublishedParts::default());
+                    failure.set(None);
+                    record_usage(UsageEvent::Upload(UploadStep::FileChosen));
+                }
+                None => form.fail(format!("Could not read {}", name)),
+            }
+        }
+    };
+
+    let select_transcript = move |evt: FormEvent| async move {
+        let Some(file_engine) = evt.files() else {
+            return;
+        };
+        if let Some(name) = file_engine.files().into_iter().next() {
+            match file_engine.read_file(&name).await {
+                Some(bytes) => transcript_file.set(Some((name, bytes))),
+                None => form.fail(format!("Could not read {}", name)),
+            }
+        }
+    };
+
+    let select_cover = move |evt: FormEvent| async move {
+        let Some(file_engine) = evt.files() else {
+            return;
+        };
+        let Some(name) = file_engine.files().into_iter().next() else {
+            return;
+        };
+        let Some(bytes) = file_engine.read_file(&name).await else {
+            form.fail(format!("Could not read {}", name));
+            return;
+        };
+        match optimize_image(&bytes, content_type_for(&name), ImageOptions::default()).await {
+            Ok(optimized) => {
+                cover_note.set(Some(format!(
+                    "{} KB → {} KB, {}×{}",
+                    bytes.len() / 1024,
+                    optimized.bytes.len() / 1024,
+                    optimized.width,
+                    optimized.height,
+                )));
+                cover.set(Some(optimized));
+            }
+            Err(e) => form.fail(e.to_string()),
+        }
+    };
+
+    let submit = move |evt: FormEvent| {
+        evt.prevent_default();
+        if !form.validate() {
+            return;
+        }
+        if hash_progress.read().is_some() {
+            form.fail("Wait for the file to finish hashing");
+            return;
+        }
+        if wrong_network.read().is_some() {
+            form.fail("Switch your wallet to the archive's network before uploading");
+            return;
+        }
+        let encryption = match privacy.read().as_str() {
+            ENCRYPT_PASSPHRASE => Some(ContentKey::Passphrase(passphrase.read().clone())),
+            ENCRYPT_WALLET => Some(ContentKey::Wallet),
+            _ => None,
+        };
+        if encryption.is_none() && existing.read().is_some() && !*upload_anyway.read() {
+            form.fail("This file is already in the archive. Link to it, or confirm uploading another copy.");
+            return;
+        }
+        if let Some(report) = scan_report.read().as_ref() {
+            if report.is_blocked() {
+                form.fail("Remove the flagged file before uploading");
+                return;
+            }
+            if report.has_warnings() && !*accept_scan_warnings.read() {
+                form.fail("Review the scan warnings and confirm to upload anyway");
+                return;
+            }
+        }
+        if !*rights_confirmed.read() {
+            form.fail("Confirm the rights declaration before uploading");
+            return;
+        }
+        let chosen = file.read().clone();
+        let filename = chosen.as_ref().map(|chosen| chosen.name().to_string()).unwrap_or_default();
+        let series = series_choice_for(&series_choice.read(), &new_series_title.read());
+
+        let request = UploadRequest {
+            title: title.trimmed(),
+            description: description.optional(),
+            speaker: speaker.optional(),
+            ministry: ministry.optional(),
+            scripture_refs: scripture_refs
+                .value()
+                .split(';')
+                .map(str::trim)
+                .filter(|r| !r.is_empty())
+                .map(str::to_string)
+                .collect(),
+            language: language.optional().and_then(|tag| normalize_language_tag(&tag)),
+            series,
+            content_type: content_type_for(&filename).to_string(),
+            file: chosen,
+            duration_secs: *duration.read(),
+            transcript: transcript_file
+                .read()
+                .clone()
+                .map(|(name, bytes)| (content_type_for(&name).to_string(), bytes)),
+            cover: cover
+                .read()
+                .clone()
+                .map(|image| (image.content_type, image.bytes)),
+            file_hash: file_digest.read().as_ref().map(|digest| digest.sha256.clone()),
+            encryption,
+            license: license.read().clone(),
+            rights: Some(rights.read().clone()),
+            ipfs_cid: mirror_cid.optional(),
+            previous_version: previous_version.optional(),
+            accept_scan_warnings: *accept_scan_warnings.read(),
+            method: *method.read(),
+            transfer: TransferControl::default(),
+            published: published.read().clone(),
+        };
+
+        if let Err(message) = request.validate() {
+            form.fail(message);
+            return;
+        }
+
+        record_usage(UsageEvent::Upload(UploadStep::Submitted));
+        form.submit(async move {
+            let mut request = request;
+            failure.set(None);
+            match UploadService::publish_resuming(&mut request).await {
+                Ok(tx_id) => {
+                    record_usage(UsageEvent::Upload(UploadStep::Published));
+                    published.set(PublishedParts::default());
+                    form.succeed("");
+                    uploaded.set(Some(tx_id));
+                }
+                Err(e) => {
+                    record_usage(UsageEvent::Upload(UploadStep::Failed));
+                    log::warn!("Upload failed: {}", e);
+                    published.set(request.published.clone());
+                    failure.set(Some(UploadFailure::new(&request, &e)));
+                }
+            }
+        });
+    };
+
+    // Use the existing copy: attach it to the chosen series, if any, and
+    // open it
+    let navigator = use_navigator();
+    let link_existing = move |_| {
+        let Some(item) = existing.read().clone() else {
+            return;
+        };
+        let series = series_choice_for(&series_choice.read(), &new_series_title.read());
+        spawn(async move {
+            is_linking.set(true);
+            match UploadService::link_existing(&item.tx_id, &series).await {
+                Ok(()) => {
+                    navigator.push(Route::ContentDetail { tx_id: item.tx_id });
+                }
+                Err(e) => show_error(&AppError::from(e)),
+            }
+            is_linking.set(false);
+        });
+    };
+
+    // Parts already published are skipped on a retry
+    let submit_label = if failure.read().is_some() { "Retry upload" } else { "Upload" };
+    let input_class = "w-full px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-green-500";
+
+    rsx! {
+        main {
+            class: "max-w-3xl mx-auto px-4 sm:px-6 lg:px-8 py-8",
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-8",
+
+                div {
+                    class: "flex items-center justify-between mb-6",
+                    h2 { class: "text-2xl font-bold text-gray-900", "Upload Content" }
+                    Link { to: Route::BulkImport {}, class: "text-sm text-green-700 hover:text-green-800", "Import many items" }
+                }
+
+                if let Some(tx_id) = uploaded.read().as_ref() {
+                    div {
+                        class: "bg-green-50 border border-green-200 rounded-lg p-6 space-y-3",
+                        h3 { class: "text-lg font-semibold text-green-900", "Upload submitted" }
+                        p { class: "text-sm text-green-800", "Your item is being bundled onto Arweave. Confirmation may take a few minutes." }
+                        div {
+                            class: "flex gap-4",
+                            Link {
+                                class: "text-sm text-green-700 hover:text-green-800 font-medium underline",
+                                to: Route::ContentDetail { tx_id: tx_id.clone() },
+                                "View item"
+                            }
+                            Link {
+                                class: "text-sm text-green-700 hover:text-green-800 font-medium underline",
+                                to: Route::ReceiptView { tx_id: tx_id.clone() },
+                                "View receipt"
+                            }
+                        }
+                        p { class: "text-xs text-green-700", "Keep the receipt with your records; you can download or print it, and find it later under Upload receipts." }
+                    }
+                } else if owner.is_none() {
+                    p { class: "text-gray-600", "Connect your wallet to upload content." }
+                } else {
+                    if let Some(network) = wrong_network() {
+                        div {
+                            class: "bg-amber-50 border border-amber-300 rounded-lg p-4 mb-4 text-sm text-amber-900",
+                            "Your wallet is on {network.display_name()} ({network.gateway}) but the archive publishes to {app_config().network.display_name()}. "
+                            "Switch your wallet's gateway, or change the network in Settings, to upload."
+                        }
+                    }
+                    if let Some(draft) = restored.as_ref() {
+                        div {
+                            class: "bg-yellow-50 border border-yellow-200 rounded-lg p-4 mb-4 text-sm text-yellow-900",
+                            "Restored the details you were entering before the app reloaded. "
+                            match draft.file_name.as_ref() {
+                                Some(name) => rsx! { "Choose {name} again to finish the upload." },
+                                None => rsx! { "Choose the file again to finish the upload." },
+                            }
+                        }
+                    }
+                    form {
+                        class: "space-y-4",
+                        onsubmit: submit,
+
+                        UploadField { label: "File",
+                            input { class: "block text-sm", r#type: "file", onchange: select_file }
+                            if let Some(chosen) = file.read().as_ref() {
+                                p {
+                                    class: "text-xs text-gray-500 mt-1",
+                                    match *duration.read() {
+                                        Some(secs) => rsx! { "{chosen.name()} ({format_size(chosen.size())}, {format_position(secs as f64)})" },
+                                        None => rsx! { "{chosen.name()} ({format_size(chosen.size())})" },
+                                    }
+                                }
+                            }
+                            if let Some(fraction) = *hash_progress.read() {
+                                p { class: "text-xs text-gray-500 mt-1", "Hashing... {(fraction * 100.0) as u32}%" }
+                            } else if let Some(digest) = file_digest.read().as_ref() {
+                                p { class: "text-xs text-gray-400 mt-1 font-mono", "SHA-256 {digest.sha256}" }
+                            }
+                            AudioTrimmer { file }
+                            if let Some(item) = existing.read().as_ref() {
+                                div {
+                                    class: "mt-3 bg-yellow-50 border border-yellow-200 rounded-lg p-4 space-y-2 text-sm",
+                                    p { class: "font-medium text-yellow-900", "This already exists in the archive" }
+                                    Link {
+                                        class: "text-green-700 hover:text-green-800 underline",
+                                        to: Route::ContentDetail { tx_id: item.tx_id.clone() },
+                                        "{item.title}"
+                                    }
+                                    div {
+                                        class: "flex items-center gap-4",
+                                        button {
+                                            class: "bg-green-600 hover:bg-green-700 text-white px-3 py-1 rounded text-sm font-medium transition-colors",
+                                            r#type: "button",
+                                            disabled: *is_linking.read() || form.is_submitting(),
+                                            onclick: link_existing,
+                                            if series_choice.read().is_empty() { "Link to it instead" } else { "Add it to the series instead" }
+                                        }
+                                        label {
+                                            class: "flex items-center gap-1 text-gray-600",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: *upload_anyway.read(),
+                                                onchange: move |evt| upload_anyway.set(evt.checked()),
+                                            }
+                                            "Upload another copy anyway"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        UploadField { label: "Transcript (optional)",
+                            input { class: "block text-sm", r#type: "file", accept: ".vtt,.json,.txt", onchange: select_transcript }
+                            p { class: "text-xs text-gray-500 mt-1", "WebVTT or JSON with timestamps syncs with playback; plain text is shown as paragraphs." }
+                        }
+                        UploadField { label: "Cover image (optional)",
+                            input { class: "block text-sm", r#type: "file", accept: "image/*", onchange: select_cover }
+                            if let Some(note) = cover_note.read().as_ref() {
+                                p { class: "text-xs text-gray-500 mt-1", "Optimized: {note}" }
+                            }
+                        }
+                        if let Some(report) = scan_report.read().clone().filter(|report| !report.findings.is_empty()) {
+                            ScanFindings { report, accepted: accept_scan_warnings }
+                        }
+                        TextField { field: title }
+                        TextField { field: description, rows: 3 }
+                        div {
+                            class: "grid md:grid-cols-2 gap-4",
+                            TextField { field: speaker }
+                            TextField { field: ministry }
+                        }
+                        div {
+                            class: "grid md:grid-cols-2 gap-4",
+                            TextField { field: scripture_refs, placeholder: "John 3:16; Romans 8:28" }
+                            div {
+                                TextField { field: language, placeholder: "en" }
+                                if let Some(tag) = language.optional().filter(|tag| normalize_language_tag(tag).is_some()) {
+                                    p {
+                                        class: "text-xs text-gray-500 mt-1",
+                                        if suggested_language.as_deref() == Some(tag.as_str()) {
+                                            "{language_name(&tag)}, suggested from your browser's language"
+                                        } else {
+                                            "{language_name(&tag)}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        div {
+                            TextField { field: mirror_cid, placeholder: "Qm… or bafy…" }
+                            p {
+                                class: "text-xs text-gray-500 mt-1",
+                                "Already published this file to IPFS? Its CID is checked against the file before upload, and the item links to the IPFS copy too."
+                            }
+                        }
+                        div {
+                            TextField { field: previous_version, placeholder: "Transaction ID of the version this corrects" }
+                            p {
+                                class: "text-xs text-gray-500 mt-1",
+                                "For a corrected or re-edited upload. The earlier item must be yours; readers of it are pointed here, and Browse shows only the newest version."
+                            }
+                        }
+
+                        UploadField { label: "Privacy",
+                            select {
+                                class: input_class,
+                                value: "{privacy}",
+                                onchange: move |evt| privacy.set(evt.value()),
+                                option { value: "", "Public" }
+                                option { value: ENCRYPT_PASSPHRASE, "Encrypted with a passphrase" }
+                                option { value: ENCRYPT_WALLET, "Encrypted to my wallet" }
+                            }
+                            if privacy.read().as_str() == ENCRYPT_PASSPHRASE {
+                                input {
+                                    class: "{input_class} mt-2",
+                                    r#type: "password",
+                                    placeholder: "Passphrase to share with readers",
+                                    value: "{passphrase}",
+                                    oninput: move |evt| passphrase.set(evt.value()),
+                                }
+                            }
+                            if !privacy.read().is_empty() {
+                                p {
+                                    class: "text-xs text-gray-500 mt-1",
+                                    "The file is encrypted in your browser before upload. Title, description and other details stay public. A lost passphrase or wallet cannot be recovered."
+                                }
+                            }
+                        }
+
+                        RightsFields { rights, confirmed: rights_confirmed }
+                        LicenseFields { license }
+
+                        SeriesSelector {
+                            owner: owner.clone().unwrap_or_default(),
+                            selected: series_choice.read().clone(),
+                            new_title: new_series_title.read().clone(),
+                            on_select: move |value| series_choice.set(value),
+                            on_new_title: move |value| new_series_title.set(value),
+                        }
+
+                        FundingSelector { method, item_sizes, owner: owner.clone() }
+
+                        ErrorSummary { form }
+                        FormStatus { form }
+                        if let Some(failure) = failure.read().clone() {
+                            UploadFailureDetails { failure }
+                        }
+
+                        SubmitButton { form, label: submit_label, busy_label: "Uploading..." }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Universal Data License terms for an upload
+#[component]
+pub fn LicenseFields(license: Signal<Option<UdlTerms>>) -> Element {
+    let mut fee_amount = use_signal(|| {
+        license
+            .peek()
+            .as_ref()
+            .and_then(|terms| terms.fee.as_ref())
+            .map(|fee| fee.amount.to_string())
+            .unwrap_or_default()
+    });
+    let input_class = "w-full px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-green-500";
+
+    let terms = license.read().clone();
+    let mut set_fee = move |amount: String, interval: FeeInterval, currency: String| {
+        fee_amount.set(amount.clone());
+        if let Some(terms) = license.write().as_mut() {
+            terms.fee = amount
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|amount| *amount > 0.0)
+                .map(|amount| LicenseFee { interval, amount, currency });
+        }
+    };
+    let fee_interval = terms.as_ref().and_then(|t| t.fee.as_ref()).map(|fee| fee.interval).unwrap_or(FeeInterval::OneTime);
+    let fee_currency = terms.as_ref().and_then(|t| t.fee.as_ref()).map(|fee| fee.currency.clone()).unwrap_or_else(|| "U".to_string());
+
+    rsx! {
+        UploadField { label: "License",
+            select {
+                class: input_class,
+                value: if terms.is_some() { "udl" } else { "" },
+                onchange: move |evt| {
+                    fee_amount.set(String::new());
+                    license.set((evt.value() == "udl").then(UdlTerms::default));
+                },
+                option { value: "", "No license terms" }
+                option { value: "udl", "Universal Data License" }
+            }
+        }
+        if let Some(terms) = terms {
+            div {
+                class: "grid md:grid-cols-2 gap-4 pl-4 border-l-2 border-green-100",
+                UploadField { label: "Commercial use",
+                    select {
+                        class: input_class,
+                        value: terms.commercial_use.map(|t| t.tag_value()).unwrap_or_default(),
+                        onchange: move |evt| {
+                            if let Some(terms) = license.write().as_mut() {
+                                terms.commercial_use = CommercialUse::from_tag_value(&evt.value());
+                            }
+                        },
+                        option { value: "", "Not allowed" }
+                        for option_terms in CommercialUse::ALL {
+                            option { value: option_terms.tag_value(), "{option_terms.display_name()}" }
+                        }
+                    }
+                }
+                UploadField { label: "Derivative works",
+                    select {
+                        class: input_class,
+                        value: terms.derivation.map(|t| t.tag_value()).unwrap_or_default(),
+                        onchange: move |evt| {
+                            if let Some(terms) = license.write().as_mut() {
+                                terms.derivation = Derivation::from_tag_value(&evt.value());
+                            }
+                        },
+                        option { value: "", "Not allowed" }
+                        for option_terms in Derivation::ALL {
+                            option { value: option_terms.tag_value(), "{option_terms.display_name()}" }
+                        }
+                    }
+                }
+                UploadField { label: "License fee (optional)",
+                    div {
+                        class: "flex gap-2",
+                        input {
+                            class: input_class,
+                            r#type: "number",
+                            min: "0",
+                            step: "any",
+                            value: "{fee_amount}",
+                            oninput: {
+                                let currency = fee_currency.clone();
+                                move |evt: FormEvent| set_fee(evt.value(), fee_interval, currency.clone())
+                            },
+                        }
+                        select {
+                            class: "px-2 border border-gray-300 rounded-md text-sm",
+                            aria_label: "Fee interval",
+                            value: if fee_interval == FeeInterval::Monthly { "monthly" } else { "one-time" },
+                            onchange: {
+                                let currency = fee_currency.clone();
+                                move |evt: FormEvent| {
+                                    let interval = if evt.value() == "monthly" { FeeInterval::Monthly } else { FeeInterval::OneTime };
+                                    set_fee(fee_amount.read().clone(), interval, currency.clone());
+                                }
+                            },
+                            option { value: "one-time", "one-time" }
+                            option { value: "monthly", "monthly" }
+                        }
+                        select {
+                            class: "px-2 border border-gray-300 rounded-md text-sm",
+                            aria_label: "Fee currency",
+                            value: "{fee_currency}",
+                            onchange: move |evt: FormEvent| set_fee(fee_amount.read().clone(), fee_interval, evt.value()),
+                            option { value: "U", "U" }
+                            option { value: "AR", "AR" }
+                        }
+                    }
+                }
+                UploadField { label: "Payment address (optional)",
+                    input {
+                        class: input_class,
+                        placeholder: "Defaults to your wallet",
+                        value: terms.payment_address.clone().unwrap_or_default(),
+                        oninput: move |evt| {
+                            if let Some(terms) = license.write().as_mut() {
+                                let address = evt.value().trim().to_string();
+                                terms.payment_address = (!address.is_empty()).then_some(address);
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Declaration of who holds the rights to an upload and on what basis
+/// it's shared
+#[component]
+fn RightsFields(rights: Signal<RightsDeclaration>, confirmed: Signal<bool>) -> Element {
+    let input_class = "w-full px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-green-500";
+    let declaration = rights.read().clone();
+    // Any change needs confirming again
+    let mut update = move |change: &dyn Fn(&mut RightsDeclaration)| {
+        change(&mut rights.write());
+        confirmed.set(false);
+    };
+    let optional = |value: String| Some(value.trim().to_string()).filter(|value| !value.is_empty());
+
+    rsx! {
+        div {
+            class: "space-y-4 border border-gray-200 rounded-lg p-4",
+            div {
+                class: "grid md:grid-cols-2 gap-4",
+                UploadField { label: "Rights",
+                    select {
+                        class: input_class,
+                        value: declaration.basis.tag_value(),
+                        onchange: move |evt| {
+                            let basis = RightsBasis::from_tag_value(&evt.value()).unwrap_or(RightsBasis::OwnWork);
+                            update(&|rights| rights.basis = basis);
+                        },
+                        for basis in RightsBasis::ALL {
+                            option { value: basis.tag_value(), "{basis.display_name()}" }
+                        }
+                    }
+                }
+                UploadField { label: "Rights holder",
+                    input {
+                        class: input_class,
+                        placeholder: "Person or ministry holding the copyright",
+                        value: "{declaration.rights_holder}",
+                        oninput: move |evt| {
+                            let holder = evt.value();
+                            update(&|rights| rights.rights_holder = holder.clone());
+                        },
+                    }
+                }
+            }
+            label {
+                class: "flex items-center gap-2 text-sm text-gray-700",
+                input {
+                    r#type: "checkbox",
+                    checked: declaration.contains_music,
+                    onchange: move |evt| {
+                        let checked = evt.checked();
+                        update(&|rights| rights.contains_music = checked);
+                    },
+                }
+                "The recording includes worship music or other songs"
+            }
+            if declaration.contains_music || declaration.basis == RightsBasis::Licensed {
+                div {
+                    class: "grid md:grid-cols-2 gap-4 pl-4 border-l-2 border-green-100",
+                    UploadField { label: "CCLI license number",
+                        input {
+                            class: input_class,
+                            inputmode: "numeric",
+                            placeholder: "Church copyright or streaming license",
+                            value: declaration.ccli_license.clone().unwrap_or_default(),
+                            oninput: move |evt| {
+                                let number = optional(evt.value());
+                                update(&|rights| rights.ccli_license = number.clone());
+                            },
+                        }
+                    }
+                    UploadField { label: "Other license or permission",
+                        input {
+                            class: input_class,
+                            placeholder: "License name, number or who gave permission",
+                            value: declaration.license_reference.clone().unwrap_or_default(),
+                            oninput: move |evt| {
+                                let reference = optional(evt.value());
+                                update(&|rights| rights.license_reference = reference.clone());
+                            },
+                        }
+                    }
+                }
+                if declaration.missing_music_license() {
+                    p { class: "text-xs text-amber-700", "Licensed music needs a CCLI or other license number. Moderators check it before the item is reviewed." }
+                }
+            }
+            label {
+                class: "flex items-start gap-2 text-sm text-gray-700",
+                input {
+                    class: "mt-1",
+                    r#type: "checkbox",
+                    checked: *confirmed.read(),
+                    onchange: move |evt| confirmed.set(evt.checked()),
+                }
+                "I declare that this is accurate and that I may publish this content. The declaration is stored with the item and shown to readers and moderators."
+            }
+        }
+    }
+}
+
+/// What the content scan flagged, with a confirmation when nothing blocks
+/// the upload
+#[component]
+fn ScanFindings(report: ScanReport, accepted: Signal<bool>) -> Element {
+    let blocked = report.is_blocked();
+    let (box_class, heading) = if blocked {
+        ("bg-red-50 border-red-200 text-red-900", "This upload can't be published")
+    } else {
+        ("bg-yellow-50 border-yellow-200 text-yellow-900", "Check these files before uploading")
+    };
+
+    rsx! {
+        div {
+            class: "border rounded-lg p-4 space-y-2 text-sm {box_class}",
+            role: "alert",
+            p { class: "font-medium", "{heading}" }
+            ul {
+                class: "list-disc pl-5 space-y-1",
+                for finding in report.findings.iter() {
+                    li {
+                        class: if finding.severity == ScanSeverity::Block { "text-red-800" } else { "" },
+                        title: "{finding.provider}",
+                        "{finding}"
+                    }
+                }
+            }
+            if !blocked {
+                label {
+                    class: "flex items-center gap-1",
+                    input {
+                        r#type: "checkbox",
+                        checked: accepted(),
+                        onchange: move |evt| accepted.set(evt.checked()),
+                    }
+                    "I've checked these files and want to upload them"
+                }
+            }
+        }
+    }
+}
+
+fn series_choice_for(selected: &str, new_title: &str) -> SeriesChoice {
+    match selected {
+        "" => SeriesChoice::None,
+        NEW_SERIES => SeriesChoice::New(new_title.to_string()),
+        series_id => SeriesChoice::Existing(series_id.to_string()),
+    }
+}
+
+/// Series dropdown listing the uploader's series plus "new series"
+#[component]
+fn SeriesSelector(
+    owner: String,
+    selected: String,
+    new_title: String,
+    on_select: EventHandler<String>,
+    on_new_title: EventHandler<String>,
+) -> Element {
+    let mut series = use_signal(Vec::<SeriesSummary>::new);
+
+    use_effect(use_reactive!(|owner| {
+        spawn(async move {
+            match SeriesService::new().list_for_owner(&owner).await {
+                Ok(found) => series.set(found),
+                Err(e) => log::warn!("Could not load series: {}", e),
+            }
+        });
+    }));
+
+    let input_class = "w-full px-3 py-2 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-green-500";
+
+    rsx! {
+        UploadField { label: "Series",
+            select {
+                class: input_class,
+                value: "{selected}",
+                onchange: move |evt| on_select.call(evt.value()),
+                option { value: "", "Not part of a series" }
+                for summary in series.read().iter() {
+                    option { key: "{summary.series_id}", value: "{summary.series_id}", "{summary.title}" }
+                }
+                option { value: NEW_SERIES, "New series..." }
+            }
+            if selected == NEW_SERIES {
+                input {
+                    class: "{input_class} mt-2",
+                    placeholder: "Series title",
+                    value: "{new_title}",
+                    oninput: move |evt| on_new_title.call(evt.value()),
+                }
+            }
+        }
+    }
+}
+
+/// How the upload is paid for, with what each way would cost
+///
+/// Credits and AR are priced against the wallet's balances whenever the
+/// files change. Dispatch is free but only takes small items, and only
+/// some wallets offer it.
+#[component]
+fn FundingSelector(method: Signal<UploadMethod>, item_sizes: Vec<usize>, owner: Option<String>) -> Element {
+    let credit_balance = use_credit_balance();
+    let mut credits_quote = use_signal(|| None::<Result<UploadEstimate, String>>);
+    let mut ar_quote = use_signal(|| None::<Result<(u128, u128), String>>);
+    let can_dispatch = WalletService::get_extended_state().read().capabilities.can_dispatch;
+
+    use_effect(use_reactive!(|item_sizes, owner| {
+        credits_quote.set(None);
+        ar_quote.set(None);
+        let Some(owner) = owner else {
+            return;
+        };
+        if item_sizes.is_empty() {
+            return;
+        }
+        let total = item_sizes.iter().sum::<usize>() as u64;
+        let credits_owner = owner.clone();
+        spawn(async move {
+            let quote = estimate_upload(&credits_owner, total).await.map_err(|e| e.to_string());
+            credits_quote.set(Some(quote));
+        });
+        spawn(async move {
+            let pricing = PricingService::new();
+            let mut price = 0;
+            for size in &item_sizes {
+                match pricing.storage_price_winston(*size as u64).await {
+                    Ok(winston) => price += winston,
+                    Err(e) => {
+                        ar_quote.set(Some(Err(e.to_string())));
+                        return;
+                    }
+                }
+            }
+            let quote = pricing.wallet_balance_winston(&owner).await.map(|balance| (price, balance));
+            ar_quote.set(Some(quote.map_err(|e| e.to_string())));
+        });
+    }));
+
+    // Against the live balance, so a top-up clears the shortfall
+    let credits_needed = credits_quote.read().as_ref().and_then(|quote| quote.as_ref().ok()).and_then(|estimate| {
+        let balance_winc = credit_balance
+            .as_ref()
+            .filter(|balance| Some(&balance.address) == owner.as_ref())
+            .map_or(estimate.balance_winc, |balance| balance.winc);
+        let estimate = UploadEstimate { balance_winc, ..*estimate };
+        estimate.is_insufficient().then(|| estimate.shortfall())
+    });
+    let largest = item_sizes.iter().copied().max().unwrap_or_default();
+    let checking = if owner.is_some() && !item_sizes.is_empty() { "Checking…" } else { "—" };
+
+    // (method, cost, note, selectable)
+    let options: Vec<(UploadMethod, String, Option<String>, bool)> = UploadMethod::ALL
+        .into_iter()
+        .map(|option| match option {
+            UploadMethod::Bundler => {
+                let (cost, note) = match credits_quote.read().as_ref() {
+                    Some(Ok(estimate)) if estimate.cost_winc == 0 => ("Free".to_string(), None),
+                    Some(Ok(estimate)) => (
+                        format!("{} credits", format_amount(estimate.cost_winc)),
+                        credits_needed.map(|shortfall| format!("{} more credits needed", format_amount(shortfall))),
+                    ),
+                    Some(Err(e)) => ("Price unavailable".to_string(), Some(e.clone())),
+                    None => (checking.to_string(), None),
+                };
+                (option, cost, note, true)
+            }
+            UploadMethod::Native => {
+                let (cost, note) = match ar_quote.read().as_ref() {
+                    Some(Ok((price, balance))) => (
+                        format!("{} AR", format_amount(*price)),
+                        (balance < price).then(|| format!("Your wallet holds {} AR", format_amount(*balance))),
+                    ),
+                    Some(Err(e)) => ("Price unavailable".to_string(), Some(e.clone())),
+                    None => (checking.to_string(), None),
+                };
+                (option, cost, note, true)
+            }
+            UploadMethod::Dispatch => {
+                let note = if !can_dispatch {
+                    Some("Your wallet can't dispatch uploads".to_string())
+                } else if largest > DISPATCH_FREE_LIMIT {
+                    Some(format!("Only for files up to {} KiB", DISPATCH_FREE_LIMIT / 1024))
+                } else {
+                    None
+                };
+                let selectable = note.is_none();
+                (option, "Free".to_string(), note, selectable)
+            }
+        })
+        .collect();
+
+    rsx! {
+        fieldset {
+            class: "space-y-2",
+            legend { class: "block text-sm font-medium text-gray-700 mb-1", "Pay for this upload with" }
+            for (option, cost, note, selectable) in options {
+                label {
+                    key: "{option.display_name()}",
+                    class: option_class(selectable, *method.read() == option),
+                    input {
+                        class: "mt-1",
+                        r#type: "radio",
+                        name: "funding-source",
+                        checked: *method.read() == option,
+                        disabled: !selectable,
+                        onchange: move |_| method.set(option),
+                    }
+                    div {
+                        class: "flex-1 text-sm",
+                        div {
+                            class: "flex justify-between gap-4",
+                            span { class: "font-medium text-gray-900", "{option.display_name()}" }
+                            span { class: "text-gray-900", "{cost}" }
+                        }
+                        p { class: "text-xs text-gray-500 mt-0.5", "{option.description()}" }
+                        if let Some(note) = note {
+                            p { class: "text-xs text-amber-700 mt-1", "{note}" }
+                        }
+                        if option == UploadMethod::Bundler {
+                            if let Some(shortfall) = credits_needed {
+                                div { class: "mt-2", TopUpButton { needed_winc: shortfall } }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Row style of a funding option
+fn option_class(selectable: bool, selected: bool) -> &'static str {
+    match (selectable, selected) {
+        (false, _) => "flex items-start gap-3 p-3 rounded-lg border border-gray-200 opacity-60 cursor-not-allowed",
+        (true, true) => "flex items-start gap-3 p-3 rounded-lg border border-green-500 bg-green-50 cursor-pointer",
+        (true, false) => "flex items-start gap-3 p-3 rounded-lg border border-gray-200 hover:bg-gray-50 cursor-pointer",
+    }
+}
+
+#[component]
+fn UploadField(label: &'static str, children: Element) -> Element {
+    // Wrapping the control in the label ties the two together for screen readers
+    rsx! {
+        label {
+            class: "block",
+            span { class: "block text-sm font-medium text-gray-700 mb-1", "{label}" }
+            {children}
+        }
+    }
+}
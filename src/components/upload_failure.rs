@@ -0,0 +1,89 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::top_up::TopUpButton;
+use crate::services::upload::UploadPart;
+use crate::services::upload_failure::{FailureKind, UploadFailure};
+
+/// What failed in an upload, why, and what already went out
+///
+/// Each part of the upload is listed with a link to those already
+/// published, so it's clear a retry carries on from the failed part.
+/// `on_retry` is offered when trying again might work.
+#[component]
+pub fn UploadFailureDetails(failure: UploadFailure, #[props(default)] on_retry: Option<EventHandler<()>>) -> Element {
+    let mut show_detail = use_signal(|| false);
+    let failed_at = match failure.chunk {
+        Some((index, total)) => format!("{}, chunk {} of {}", failure.part.label(), index + 1, total),
+        None => failure.part.label().to_string(),
+    };
+    let retry_label = match failure.part {
+        UploadPart::Preparation => "Retry".to_string(),
+        part => format!("Retry from {}", part.label().to_lowercase()),
+    };
+
+    rsx! {
+        div {
+            class: "rounded-md border border-red-200 bg-red-50 p-4 space-y-3 text-sm",
+            role: "alert",
+            div {
+                p { class: "font-semibold text-red-800", "{failure.kind.title()}" }
+                p { class: "text-red-700", "Failed at: {failed_at}" }
+                p { class: "text-gray-700 mt-1", "{failure.kind.advice()}" }
+            }
+            if !failure.parts.is_empty() {
+                ul {
+                    class: "space-y-1",
+                    for (part, tx_id) in failure.parts.clone() {
+                        li {
+                            key: "{part.label()}",
+                            class: "flex items-center gap-2",
+                            match tx_id {
+                                Some(tx_id) => rsx! {
+                                    span { class: "text-green-700", "✓" }
+                                    span { class: "text-gray-900", "{part.label()}" }
+                                    Link { to: Route::ContentDetail { tx_id }, class: "text-green-700 underline", "published" }
+                                },
+                                None if part == failure.part => rsx! {
+                                    span { class: "text-red-700", "✗" }
+                                    span { class: "text-gray-900", "{part.label()}" }
+                                    span { class: "text-red-700", "failed" }
+                                },
+                                None => rsx! {
+                                    span { class: "text-gray-400", "○" }
+                                    span { class: "text-gray-500", "{part.label()} not yet sent" }
+                                },
+                            }
+                        }
+                    }
+                }
+            }
+            div {
+                class: "flex flex-wrap items-center gap-3",
+                if let Some(on_retry) = on_retry.filter(|_| failure.kind.is_retryable()) {
+                    button {
+                        class: "px-3 py-1 rounded-md text-sm font-medium bg-green-600 text-white hover:bg-green-700",
+                        r#type: "button",
+                        onclick: move |_| on_retry.call(()),
+                        "{retry_label}"
+                    }
+                }
+                match failure.kind {
+                    FailureKind::InsufficientFunds => rsx! { TopUpButton {} },
+                    FailureKind::WrongNetwork | FailureKind::ServerError(_) => rsx! {
+                        Link { to: Route::Settings {}, class: "text-green-700 underline", "Open Settings" }
+                    },
+                    _ => rsx! {},
+                }
+                button {
+                    class: "text-gray-600 underline",
+                    r#type: "button",
+                    onclick: move |_| show_detail.toggle(),
+                    if *show_detail.read() { "Hide details" } else { "Show details" }
+                }
+            }
+            if *show_detail.read() {
+                pre { class: "whitespace-pre-wrap break-words text-xs text-gray-700 bg-white rounded p-2 border border-gray-200", "{failure.detail}" }
+            }
+        }
+    }
+}
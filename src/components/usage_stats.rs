@@ -0,0 +1,200 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::components::charts::LineChart;
+use crate::services::analytics::{use_usage_summary, AnalyticsService, SharedTotals, UploadStep};
+use crate::services::search::find_local;
+use crate::services::settings::{update_settings, use_settings};
+use crate::utils::charts::ChartPoint;
+use crate::utils::display::short_address;
+
+/// Periods the stats page can show (days)
+const PERIODS: [u64; 3] = [7, 30, 90];
+
+/// Personal usage stats counted in this browser
+///
+/// Nothing is counted until the visitor turns stats on. The totals can be
+/// shared anonymously, and the counts cleared at any time.
+#[component]
+pub fn UsageStats() -> Element {
+    let settings = use_settings();
+    let mut days = use_signal(|| PERIODS[1]);
+    let summary = use_usage_summary(days);
+    let mut is_busy = use_signal(|| false);
+    let mut status = use_signal(|| None::<Result<String, String>>);
+
+    let share = move |_| {
+        let summary = summary.read().clone();
+        spawn(async move {
+            is_busy.set(true);
+            let result = AnalyticsService::share_totals(&summary)
+                .await
+                .map(|tx_id| format!("Shared anonymous totals ({})", tx_id))
+                .map_err(|e| e.to_string());
+            status.set(Some(result));
+            is_busy.set(false);
+        });
+    };
+
+    let clear = move |_| {
+        spawn(async move {
+            is_busy.set(true);
+            let result = AnalyticsService::clear()
+                .await
+                .map(|()| "Cleared your usage stats".to_string())
+                .map_err(|e| e.to_string());
+            status.set(Some(result));
+            is_busy.set(false);
+        });
+    };
+
+    let enabled = settings.read().usage_stats;
+    let current = summary.read().clone();
+    let shared = SharedTotals::from_summary(&current);
+    let daily_plays: Vec<ChartPoint> = current
+        .daily_plays
+        .iter()
+        .map(|(day, plays)| ChartPoint::new(day.format("%b %-d").to_string(), *plays as f64))
+        .collect();
+
+    rsx! {
+        main {
+            class: "max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+
+            div {
+                class: "flex items-center justify-between",
+                h2 { class: "text-2xl font-bold text-gray-900", "Your Stats" }
+                div {
+                    class: "flex gap-2",
+                    for period in PERIODS {
+                        button {
+                            key: "{period}",
+                            class: if period == days() {
+                                "px-4 py-2 rounded-lg text-sm font-medium bg-green-600 text-white"
+                            } else {
+                                "px-4 py-2 rounded-lg text-sm font-medium bg-white text-gray-700 border border-gray-300 hover:bg-green-50"
+                            },
+                            onclick: move |_| days.set(period),
+                            "{period} days"
+                        }
+                    }
+                }
+            }
+
+            if !enabled {
+                div {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-5",
+                    h3 { class: "font-semibold text-gray-900", "Usage stats are off" }
+                    p {
+                        class: "text-sm text-gray-600 mt-1",
+                        "Turn them on to count your plays, searches and uploads in this browser. Only counts are kept, never search terms, and nothing leaves this device unless you share it."
+                    }
+                    button {
+                        class: "mt-4 bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        onclick: move |_| update_settings(|settings| settings.usage_stats = true),
+                        "Turn on usage stats"
+                    }
+                }
+            }
+
+            div {
+                class: "grid grid-cols-3 gap-4",
+                StatCard { label: "Plays", value: current.plays }
+                StatCard { label: "Searches", value: current.searches }
+                StatCard { label: "Uploads published", value: current.upload_step(UploadStep::Published) }
+            }
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-5",
+                h3 { class: "font-semibold text-gray-900 mb-3", "Plays per day" }
+                LineChart { points: daily_plays, height: "h-24" }
+            }
+
+            div {
+                class: "grid md:grid-cols-2 gap-6",
+
+                div {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-5",
+                    h3 { class: "font-semibold text-gray-900 mb-3", "Upload steps" }
+                    ul {
+                        class: "space-y-1 text-sm",
+                        for step in UploadStep::ALL {
+                            li {
+                                key: "{step.key()}",
+                                class: "flex justify-between text-gray-700",
+                                span { "{step.display_name()}" }
+                                span { class: "font-medium", "{current.upload_step(step)}" }
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "bg-white rounded-xl shadow-sm border border-green-200 p-5",
+                    h3 { class: "font-semibold text-gray-900 mb-3", "Most played" }
+                    if current.top_items.is_empty() {
+                        p { class: "text-sm text-gray-500", "Nothing played yet." }
+                    }
+                    ul {
+                        class: "space-y-1 text-sm",
+                        for (tx_id, plays) in current.top_items.clone() {
+                            li {
+                                key: "{tx_id}",
+                                class: "flex justify-between gap-2",
+                                Link {
+                                    class: "text-green-700 hover:text-green-800 truncate",
+                                    to: Route::ContentDetail { tx_id: tx_id.clone() },
+                                    {find_local(&tx_id).map(|item| item.title).unwrap_or_else(|| short_address(&tx_id))}
+                                }
+                                span { class: "text-gray-500 shrink-0", "{plays}" }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "bg-white rounded-xl shadow-sm border border-green-200 p-5",
+                h3 { class: "font-semibold text-gray-900", "Share or clear" }
+                p {
+                    class: "text-sm text-gray-600 mt-1",
+                    "Sharing publishes this period's totals to Arweave to help the archive see how it is used. Counts are rounded down, item IDs are left out, and the upload is signed by a one-time key, so it can't be traced back to you. Shared totals are public and permanent."
+                }
+                p {
+                    class: "text-xs text-gray-500 mt-2",
+                    "Would share: {shared.plays} plays, {shared.searches} searches over {shared.period_days} days"
+                }
+                div {
+                    class: "flex gap-2 mt-4",
+                    button {
+                        class: "bg-green-600 hover:bg-green-700 disabled:bg-gray-300 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        disabled: is_busy() || shared.is_empty(),
+                        onclick: share,
+                        "Share anonymous totals"
+                    }
+                    button {
+                        class: "bg-white hover:bg-green-50 disabled:bg-gray-100 text-green-700 border border-green-300 px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                        disabled: is_busy() || current.is_empty(),
+                        onclick: clear,
+                        "Clear stats"
+                    }
+                }
+                match status.read().as_ref() {
+                    Some(Ok(message)) => rsx! { p { class: "text-sm text-green-700 mt-3", "{message}" } },
+                    Some(Err(message)) => rsx! { p { class: "text-sm text-red-700 mt-3", "{message}" } },
+                    None => rsx! {},
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn StatCard(label: &'static str, value: u32) -> Element {
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-5 text-center",
+            p { class: "text-3xl font-bold text-gray-900", "{value}" }
+            p { class: "text-sm text-gray-600", "{label}" }
+        }
+    }
+}
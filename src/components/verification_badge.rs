@@ -0,0 +1,58 @@
+use dioxus::prelude::*;
+use crate::services::verification::{VerificationError, VerificationService};
+use crate::utils::constants::AUTO_VERIFY_MAX_BYTES;
+use crate::utils::display::short_address;
+
+/// Pill showing whether an item's data matches its owner's signature
+///
+/// Small items are checked when the page opens; larger ones show a button
+/// so nobody downloads a whole video just to see the badge.
+#[component]
+pub fn VerificationBadge(tx_id: String) -> Element {
+    // Raised to unlimited when the viewer asks to check a large item
+    let mut max_bytes = use_signal(|| Some(AUTO_VERIFY_MAX_BYTES));
+    let lookup_id = tx_id.clone();
+    let mut result = use_resource(use_reactive!(|lookup_id| {
+        let limit = max_bytes();
+        async move { VerificationService::new().verify(&lookup_id, limit).await }
+    }));
+
+    let pill = "inline-flex items-center gap-1 px-2 py-1 rounded-full text-xs font-medium";
+
+    let state = result.read().clone();
+    match state {
+        None => rsx! {
+            span { class: "{pill} bg-gray-100 text-gray-600 dark:bg-gray-800 dark:text-gray-300", "Checking signature…" }
+        },
+        Some(Ok(verified)) => rsx! {
+            span {
+                class: "{pill} bg-green-100 text-green-800 dark:bg-green-900 dark:text-green-200",
+                title: "The data and tags served match the signature of {verified.owner}",
+                "Verified ✓ signed by {short_address(&verified.owner)}"
+            }
+        },
+        Some(Err(e @ VerificationError::TooLarge(_))) => rsx! {
+            button {
+                class: "{pill} border border-gray-300 text-gray-700 hover:bg-gray-50 dark:border-gray-600 dark:text-gray-300",
+                title: "{e}",
+                onclick: move |_| max_bytes.set(None),
+                "Verify signature"
+            }
+        },
+        Some(Err(e)) if e.is_tampered() => rsx! {
+            span {
+                class: "{pill} bg-red-100 text-red-800 dark:bg-red-900 dark:text-red-200",
+                title: "{e}",
+                "⚠ Doesn't match its signature"
+            }
+        },
+        Some(Err(e)) => rsx! {
+            button {
+                class: "{pill} bg-gray-100 text-gray-600 hover:bg-gray-200 dark:bg-gray-800 dark:text-gray-300",
+                title: "{e} (click to try again)",
+                onclick: move |_| result.restart(),
+                "Unverified"
+            }
+        },
+    }
+}
@@ -1,7 +1,9 @@
 use dioxus::prelude::*;
+use crate::error::AppError;
+use crate::services::toast::show_error;
+use crate::utils::display::{address_chunks, short_address, use_copy_feedback};
 use crate::services::wallet::{
-    WalletService, WalletStrategyType, WalletError, 
-    ExtendedWalletState, WalletCapabilities
+    WalletService, WalletStrategyType, ExtendedWalletState, ConnectionState
 };
 
 /// Props for the main wallet connect component
@@ -76,9 +78,9 @@ pub struct ConnectionChangeEvent {
 /// 
 /// # Example Usage
 /// 
-/// ```rust
+/// ```rust,ignore
 /// use dioxus::prelude::*;
-/// use faithful_archive::components::WalletConnect;
+/// use faithful_archive::components::{WalletConnect, WalletConnectVariant};
 /// 
 /// #[component]
 /// fn MyApp() -> Element {
@@ -97,7 +99,7 @@ pub struct ConnectionChangeEvent {
 #[component]
 pub fn WalletConnect(props: WalletConnectProps) -> Element {
     let extended_state = WalletService::get_extended_state();
-    let mut wallet_service = use_signal(|| WalletService::new());
+    let mut wallet_service = use_signal(WalletService::new);
     
     // Initialize wallet service on mount
     use_effect(move || {
@@ -108,41 +110,36 @@ pub fn WalletConnect(props: WalletConnectProps) -> Element {
     });
     
     // Connection handler
-    let connect_handler = {
-        let mut wallet_service = wallet_service.clone();
-        let on_connection_change = props.on_connection_change.clone();
-        move |_| {
-            let mut wallet_service = wallet_service.clone();
-            let on_connection_change = on_connection_change.clone();
-            spawn(async move {
-                let state = extended_state();
-                let result = if state.base_state.connected {
-                    let mut temp_service = WalletService::new();
-                    let res = temp_service.disconnect().await;
-                    wallet_service.set(temp_service);
-                    res
-                } else {
-                    let mut temp_service = WalletService::new();
-                    let res = temp_service.connect().await.map(|_| ());
-                    wallet_service.set(temp_service);
-                    res
-                };
-                
-                // Trigger callback if provided
-                if let Some(callback) = on_connection_change {
-                    let new_state = extended_state();
-                    callback.call(ConnectionChangeEvent {
-                        connected: new_state.base_state.connected,
-                        address: new_state.base_state.address.clone(),
-                        strategy: new_state.strategy,
-                    });
-                }
-                
-                if let Err(e) = result {
-                    web_sys::console::log_1(&format!("Wallet operation failed: {}", e).into());
-                }
-            });
-        }
+    let on_connection_change = props.on_connection_change;
+    let connect_handler = move |_| {
+        spawn(async move {
+            let state = extended_state();
+            let result = if state.base_state.is_connected() {
+                let mut temp_service = WalletService::new();
+                let res = temp_service.disconnect().await;
+                wallet_service.set(temp_service);
+                res
+            } else {
+                let mut temp_service = WalletService::new();
+                let res = temp_service.connect().await.map(|_| ());
+                wallet_service.set(temp_service);
+                res
+            };
+            
+            // Trigger callback if provided
+            if let Some(callback) = on_connection_change {
+                let new_state = extended_state();
+                callback.call(ConnectionChangeEvent {
+                    connected: new_state.base_state.is_connected(),
+                    address: new_state.base_state.address().map(str::to_string),
+                    strategy: new_state.strategy,
+                });
+            }
+            
+            if let Err(e) = result {
+                show_error(&AppError::from(e));
+            }
+        });
     };
     
     let base_class = format!("wallet-connect {}", props.class);
@@ -157,8 +154,8 @@ pub fn WalletConnect(props: WalletConnectProps) -> Element {
                 WalletStrategySelector {
                     current_strategy: state.strategy,
                     available_strategies: state.available_strategies.clone(),
-                    on_strategy_change: props.on_strategy_change.clone(),
-                    wallet_service: wallet_service.clone(),
+                    on_strategy_change: props.on_strategy_change,
+                    wallet_service,
                 }
             }
             
@@ -192,26 +189,21 @@ fn WalletStrategySelector(
     on_strategy_change: Option<EventHandler<WalletStrategyType>>,
     wallet_service: Signal<WalletService>,
 ) -> Element {
-    let strategy_change_handler = {
-        let mut wallet_service = wallet_service.clone();
-        let on_strategy_change = on_strategy_change.clone();
-        move |evt: Event<FormData>| {
-            let strategy_str = evt.value();
-            if let Ok(strategy) = strategy_str.parse::<WalletStrategyType>() {
-                let mut wallet_service = wallet_service.clone();
-                let on_strategy_change = on_strategy_change.clone();
-                spawn(async move {
-                    let mut temp_service = WalletService::new();
-                    if let Err(e) = temp_service.set_strategy(strategy).await {
-                        web_sys::console::log_1(&format!("Failed to set strategy: {}", e).into());
-                    } else {
-                        wallet_service.set(temp_service);
-                        if let Some(callback) = on_strategy_change {
-                            callback.call(strategy);
-                        }
+    let strategy_change_handler = move |evt: Event<FormData>| {
+        let strategy_str = evt.value();
+        if let Ok(strategy) = strategy_str.parse::<WalletStrategyType>() {
+            let mut wallet_service = wallet_service;
+            spawn(async move {
+                let mut temp_service = WalletService::new();
+                if let Err(e) = temp_service.set_strategy(strategy).await {
+                    show_error(&AppError::from(e));
+                } else {
+                    wallet_service.set(temp_service);
+                    if let Some(callback) = on_strategy_change {
+                        callback.call(strategy);
                     }
-                });
-            }
+                }
+            });
         }
     };
     
@@ -221,10 +213,12 @@ fn WalletStrategySelector(
             
             label {
                 class: "block text-sm font-medium text-gray-700 dark:text-gray-300 mb-1",
+                r#for: "wallet-strategy",
                 "Wallet Strategy"
             }
             
             select {
+                id: "wallet-strategy",
                 class: "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-md shadow-sm bg-white dark:bg-gray-800 text-gray-900 dark:text-gray-100 focus:outline-none focus:ring-2 focus:ring-green-500 focus:border-green-500",
                 value: "{current_strategy}",
                 onchange: strategy_change_handler,
@@ -251,15 +245,15 @@ fn WalletConnectButton(
     variant: WalletConnectVariant,
     onclick: EventHandler<MouseEvent>,
 ) -> Element {
-    let button_text = if state.base_state.connecting {
-        "Connecting..."
-    } else if state.base_state.connected {
-        disconnect_text
-    } else if !state.base_state.available {
-        "No Wallet Available"
-    } else {
-        connect_text
+    let connection = &state.base_state.connection;
+    let button_text = match connection {
+        ConnectionState::Connecting | ConnectionState::Reconnecting { .. } => "Connecting...",
+        ConnectionState::Connected { .. } => disconnect_text,
+        ConnectionState::Unavailable => "No Wallet Available",
+        ConnectionState::Idle | ConnectionState::Error { .. } => connect_text,
     };
+    let connected = connection.is_connected();
+    let unavailable = *connection == ConnectionState::Unavailable;
     
     let size_classes = match size {
         WalletConnectSize::Small => "px-3 py-1.5 text-sm",
@@ -269,36 +263,36 @@ fn WalletConnectButton(
     
     let variant_classes = match variant {
         WalletConnectVariant::Primary => {
-            if state.base_state.connected {
+            if connected {
                 "bg-red-600 hover:bg-red-700 text-white border-red-600"
-            } else if !state.base_state.available {
+            } else if unavailable {
                 "bg-gray-400 text-white border-gray-400 cursor-not-allowed"
             } else {
                 "bg-green-600 hover:bg-green-700 text-white border-green-600"
             }
         },
         WalletConnectVariant::Secondary => {
-            if state.base_state.connected {
+            if connected {
                 "bg-gray-600 hover:bg-gray-700 text-white border-gray-600"
-            } else if !state.base_state.available {
+            } else if unavailable {
                 "bg-gray-300 text-gray-500 border-gray-300 cursor-not-allowed"
             } else {
                 "bg-blue-600 hover:bg-blue-700 text-white border-blue-600"
             }
         },
         WalletConnectVariant::Outline => {
-            if state.base_state.connected {
+            if connected {
                 "border-red-600 text-red-600 hover:bg-red-50 dark:hover:bg-red-900/20"
-            } else if !state.base_state.available {
+            } else if unavailable {
                 "border-gray-300 text-gray-400 cursor-not-allowed"
             } else {
                 "border-green-600 text-green-600 hover:bg-green-50 dark:hover:bg-green-900/20"
             }
         },
         WalletConnectVariant::Ghost => {
-            if state.base_state.connected {
+            if connected {
                 "text-red-600 hover:bg-red-50 dark:hover:bg-red-900/20"
-            } else if !state.base_state.available {
+            } else if unavailable {
                 "text-gray-400 cursor-not-allowed"
             } else {
                 "text-green-600 hover:bg-green-50 dark:hover:bg-green-900/20"
@@ -312,11 +306,11 @@ fn WalletConnectButton(
     rsx! {
         button {
             class: "{button_class}",
-            disabled: state.base_state.connecting || !state.base_state.available,
+            disabled: connection.is_busy() || unavailable,
             onclick: move |evt| onclick.call(evt),
             
             // Loading spinner for connecting state
-            if state.base_state.connecting {
+            if connection.is_busy() {
                 svg {
                     class: "animate-spin -ml-1 mr-2 h-4 w-4",
                     fill: "none",
@@ -356,14 +350,15 @@ fn WalletStatus(
         WalletConnectSize::Medium => "text-sm",
         WalletConnectSize::Large => "text-base",
     };
+    let copy_feedback = use_copy_feedback();
     
     rsx! {
         div {
             class: "wallet-status mt-2 space-y-1",
             
             // Connected address
-            if state.base_state.connected && show_address {
-                if let Some(address) = state.base_state.address.clone() {
+            if show_address {
+                if let ConnectionState::Connected { address } = state.base_state.connection.clone() {
                     div {
                         class: "flex items-center space-x-2 {text_size} text-gray-600 dark:text-gray-400",
                         
@@ -378,25 +373,17 @@ fn WalletStatus(
                         
                         code {
                             class: "bg-gray-100 dark:bg-gray-800 px-1 py-0.5 rounded font-mono",
-                            "{WalletService::format_address(&address)}"
+                            // Full address in groups of four, for comparing by eye
+                            title: address_chunks(&address, 4).join(" "),
+                            "{short_address(&address)}"
                         }
                         
                         // Copy button
                         button {
                             class: "ml-1 p-1 hover:bg-gray-200 dark:hover:bg-gray-700 rounded",
-                            onclick: move |_| {
-                                if let Some(window) = web_sys::window() {
-                                    let navigator = window.navigator();
-                                    let clipboard = navigator.clipboard();
-                                    let address = address.clone();
-                                    spawn(async move {
-                                        let _ = wasm_bindgen_futures::JsFuture::from(
-                                            clipboard.write_text(&address)
-                                        ).await;
-                                    });
-                                }
-                            },
-                            title: "Copy address",
+                            onclick: move |_| copy_feedback.copy(address.clone()),
+                            title: if copy_feedback.copied() { "Copied" } else { "Copy address" },
+                            aria_label: if copy_feedback.copied() { "Copied" } else { "Copy address" },
                             
                             svg {
                                 class: "w-3 h-3",
@@ -434,7 +421,7 @@ fn WalletStatus(
             }
             
             // Error display
-            if let Some(error) = &state.base_state.error {
+            if let Some(error) = state.base_state.error() {
                 div {
                     class: "flex items-start space-x-2 {text_size} text-red-600 dark:text-red-400",
                     
@@ -527,6 +514,8 @@ impl std::str::FromStr for WalletStrategyType {
             "Wander" => Ok(WalletStrategyType::Wander),
             "WalletKit" => Ok(WalletStrategyType::WalletKit),
             "WebWallet" => Ok(WalletStrategyType::WebWallet),
+            #[cfg(feature = "mock-wallet")]
+            "Mock" => Ok(WalletStrategyType::Mock),
             _ => Err(()),
         }
     }
@@ -540,6 +529,8 @@ impl std::fmt::Display for WalletStrategyType {
             WalletStrategyType::Wander => "Wander",
             WalletStrategyType::WalletKit => "WalletKit", 
             WalletStrategyType::WebWallet => "WebWallet",
+            #[cfg(feature = "mock-wallet")]
+            WalletStrategyType::Mock => "Mock",
         };
         write!(f, "{}", name)
     }
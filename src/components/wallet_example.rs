@@ -9,7 +9,7 @@ can be adapted to different use cases.
 
 The simplest way to add wallet connectivity:
 
-```rust
+```rust,ignore
 use dioxus::prelude::*;
 use faithful_archive::components::WalletConnectCompact;
 
@@ -29,9 +29,9 @@ fn SimpleApp() -> Element {
 
 For applications that need comprehensive wallet functionality:
 
-```rust
+```rust,ignore
 use dioxus::prelude::*;
-use faithful_archive::services::wallet::{WalletProvider, use_wallet_context};
+use faithful_archive::services::wallet::{WalletProvider, WalletStrategyType};
 use faithful_archive::components::WalletConnectFull;
 
 #[component]
@@ -65,7 +65,7 @@ use dioxus::prelude::*;
 use std::collections::HashMap;
 use crate::services::wallet::{
     WalletProvider, WalletGated, WalletErrorBoundary,
-    use_wallet_context, use_wallet_connection, use_wallet_features,
+    use_wallet_connection, use_wallet_features,
     use_wallet_status, use_wallet_reconnect, use_wallet_persistence,
     use_wallet_signing, WalletStrategyType, get_strategy_icon
 };
@@ -1,13 +1,29 @@
 use dioxus::prelude::*;
-use crate::services::wallet::{WalletService, WalletStrategyType};
+use crate::components::address_avatar::AddressAvatar;
+use crate::components::modal::Modal;
+use crate::components::qr_code::QrCode;
+use crate::config::app_config;
+use crate::services::settings::use_settings;
+use crate::services::wallet::{
+    cancel_beacon_pairing, cancel_wallet_operations, preference_order, ConnectionState, remembered_strategy, use_beacon_pairing, use_network_mismatch, WalletService, WalletStrategyType,
+};
+
+fn option_icon(strategy: WalletStrategyType) -> &'static str {
+    match strategy {
+        WalletStrategyType::Beacon => "🔴",
+        WalletStrategyType::Wander => "🟣",
+        _ => "💼",
+    }
+}
 
 #[component]
 pub fn WalletModal(show: Signal<bool>, on_connect: EventHandler<WalletStrategyType>) -> Element {
-    let mut available_strategies = use_signal(|| Vec::<WalletStrategyType>::new());
+    let mut available_strategies = use_signal(Vec::<WalletStrategyType>::new);
+    let settings = use_settings();
     
     // Load available strategies when modal opens
     use_effect(move || {
-        if show.read().clone() {
+        if show() {
             log::info!("🪟 Wallet modal opened, loading strategies...");
             spawn(async move {
                 let service = WalletService::init().await; // Use init() instead of new() to get proper initialization
@@ -18,132 +34,85 @@ pub fn WalletModal(show: Signal<bool>, on_connect: EventHandler<WalletStrategyTy
         }
     });
     
-    let close_modal = move |_| {
-        show.set(false);
-    };
-    
     let mut connect_wallet = move |strategy: WalletStrategyType| {
         on_connect.call(strategy);
         show.set(false);
     };
-    
-    if !show.read().clone() {
-        return rsx! {};
-    }
+
+    // The wallet last used on top, then the order set in Settings
+    let remembered = remembered_strategy();
+    let ordered_strategies: Vec<WalletStrategyType> = remembered
+        .into_iter()
+        .chain(preference_order(&settings.read().wallet_order))
+        .chain(available_strategies.read().iter().copied())
+        .fold(Vec::new(), |mut ordered, strategy| {
+            if available_strategies.read().contains(&strategy) && !ordered.contains(&strategy) {
+                ordered.push(strategy);
+            }
+            ordered
+        });
     
     rsx! {
-        // Modal backdrop
-        div {
-            class: "fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50",
-            onclick: close_modal,
-            
-            // Modal content
+        Modal {
+            open: show,
+            title: "Connect wallet",
+            class: "bg-gray-800 rounded-2xl p-6 max-w-md w-full mx-4 relative shadow-2xl transform transition-all",
+            title_class: "text-white text-xl font-semibold mb-6",
+
+            // Wallet options
             div {
-                class: "bg-gray-800 rounded-2xl p-6 max-w-md w-full mx-4 relative shadow-2xl transform transition-all",
-                onclick: |e| e.stop_propagation(), // Prevent backdrop close when clicking modal content
-                
-                // Close button
-                button {
-                    class: "absolute top-4 right-4 text-gray-400 hover:text-white transition-colors",
-                    onclick: close_modal,
-                    "✕"
-                }
-                
-                // Modal header
-                h2 {
-                    class: "text-white text-xl font-semibold mb-6",
-                    "Connect wallet"
-                }
+                class: "space-y-3",
                 
-                // Wallet options
-                div {
-                    class: "space-y-3",
-                    
-                    // Beacon wallet
-                    if available_strategies.read().contains(&WalletStrategyType::Beacon) {
-                        WalletOption {
-                            strategy: WalletStrategyType::Beacon,
-                            icon: "🔴", // Blue circle like in the image
-                            name: "Beacon",
-                            description: "iOS based agent first wallet for AO", 
-                            on_click: move |_| connect_wallet(WalletStrategyType::Beacon),
-                        }
-                    }
-                    
-                    // Wander wallet
-                    if available_strategies.read().contains(&WalletStrategyType::Wander) {
-                        WalletOption {
-                            strategy: WalletStrategyType::Wander,
-                            icon: "🟣", // Purple butterfly-like icon
-                            name: "Wander",
-                            description: "Non-custodial Arweave & AO wallet for your favorite browser",
-                            on_click: move |_| connect_wallet(WalletStrategyType::Wander),
-                        }
-                    }
-                    
-                    // Other available strategies
-                    for strategy in available_strategies.read().iter() {
-                        if !matches!(strategy, WalletStrategyType::Beacon | WalletStrategyType::Wander) {
-                            WalletOption {
-                                strategy: *strategy,
-                                icon: "💼",
-                                name: match strategy {
-                                    WalletStrategyType::WalletKit => "Arweave Wallet Kit",
-                                    WalletStrategyType::WebWallet => "Web Wallet",
-                                    _ => "Unknown Wallet",
-                                },
-                                description: match strategy {
-                                    WalletStrategyType::WalletKit => "Universal wallet connection library",
-                                    WalletStrategyType::WebWallet => "Web-based wallet connection",
-                                    _ => "Unknown wallet type",
-                                },
-                                on_click: {
-                                    let current_strategy = *strategy;
-                                    move |_| connect_wallet(current_strategy)
-                                },
-                            }
-                        }
+                for strategy in ordered_strategies {
+                    WalletOption {
+                        key: "{strategy}",
+                        strategy,
+                        icon: option_icon(strategy),
+                        name: strategy.display_name(),
+                        description: strategy.description(),
+                        last_used: remembered == Some(strategy),
+                        on_click: move |_| connect_wallet(strategy),
                     }
                 }
+            }
+            
+            // Don't have a wallet section
+            div {
+                class: "mt-6 pt-4 border-t border-gray-700",
                 
-                // Don't have a wallet section
                 div {
-                    class: "mt-6 pt-4 border-t border-gray-700",
+                    class: "flex items-center justify-between",
                     
                     div {
-                        class: "flex items-center justify-between",
-                        
-                        div {
-                            h3 {
-                                class: "text-white font-medium mb-1",
-                                "Don't have a wallet?"
-                            }
-                            p {
-                                class: "text-gray-400 text-sm",
-                                "Click to learn more about the permaweb & wallets."
-                            }
+                        h3 {
+                            class: "text-white font-medium mb-1",
+                            "Don't have a wallet?"
                         }
-                        
-                        button {
-                            class: "bg-white text-black px-4 py-2 rounded-lg font-medium hover:bg-gray-100 transition-colors",
-                            onclick: move |_| {
-                                // Open wallet information page
-                                web_sys::window()
-                                    .unwrap()
-                                    .open_with_url_and_target("https://arweave.org/wallet", "_blank")
-                                    .unwrap();
-                            },
-                            "GET"
+                        p {
+                            class: "text-gray-400 text-sm",
+                            "Click to learn more about the permaweb & wallets."
                         }
                     }
-                }
-                
-                // Footer text
-                div {
-                    class: "mt-4 text-center text-xs text-gray-500",
-                    "Faithful Archive Wallet Connection"
+                    
+                    button {
+                        class: "bg-white text-black px-4 py-2 rounded-lg font-medium hover:bg-gray-100 transition-colors",
+                        onclick: move |_| {
+                            // Open wallet information page
+                            web_sys::window()
+                                .unwrap()
+                                .open_with_url_and_target("https://arweave.org/wallet", "_blank")
+                                .unwrap();
+                        },
+                        "GET"
+                    }
                 }
             }
+            
+            // Footer text
+            div {
+                class: "mt-4 text-center text-xs text-gray-500",
+                "Faithful Archive Wallet Connection"
+            }
         }
     }
 }
@@ -154,11 +123,14 @@ fn WalletOption(
     icon: &'static str,
     name: &'static str, 
     description: &'static str,
+    #[props(default)]
+    last_used: bool,
     on_click: EventHandler<MouseEvent>,
 ) -> Element {
     rsx! {
         button {
-            class: "w-full flex items-center justify-between p-4 bg-gray-700 hover:bg-gray-600 rounded-xl transition-colors group",
+            class: "w-full flex items-center justify-between p-4 bg-gray-700 hover:bg-gray-600 focus:outline-none focus:ring-2 focus:ring-green-500 rounded-xl transition-colors group",
+            "data-arrow-nav": "true",
             onclick: on_click,
             
             div {
@@ -200,6 +172,9 @@ fn WalletOption(
                     h3 {
                         class: "text-white font-medium text-base mb-1",
                         "{name}"
+                        if last_used {
+                            span { class: "ml-2 text-xs font-normal text-green-400", "Last used" }
+                        }
                     }
                     p {
                         class: "text-gray-400 text-sm",
@@ -231,9 +206,8 @@ pub fn WalletConnectButton() -> Element {
         });
     };
     
-    let wallet_state_clone = wallet_state.clone();
     let button_click = move |_| {
-        if wallet_state_clone.read().connected {
+        if wallet_state.read().is_connected() {
             // Disconnect if already connected
             spawn(async move {
                 let mut service = WalletService::new();
@@ -245,20 +219,25 @@ pub fn WalletConnectButton() -> Element {
         }
     };
     
-    let button_text = if wallet_state.read().connecting {
-        "Connecting..."
-    } else if wallet_state.read().connected {
-        "Disconnect"
-    } else {
-        "Connect"
-    };
-    
-    let button_class = if wallet_state.read().connected {
-        "bg-red-600 hover:bg-red-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors shadow-sm hover:shadow-md"
-    } else if !wallet_state.read().available && !show_modal.read().clone() {
-        "bg-gray-300 cursor-not-allowed text-gray-600 px-4 py-2 rounded-lg text-sm font-medium border border-gray-200"
-    } else {
-        "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors shadow-sm hover:shadow-md"
+    let connection = wallet_state.read().connection.clone();
+    let is_busy = connection.is_busy();
+    let (button_text, button_class) = match &connection {
+        ConnectionState::Connecting | ConnectionState::Reconnecting { .. } => (
+            "Connecting...",
+            "bg-green-600 text-white px-4 py-2 rounded-lg text-sm font-medium opacity-75",
+        ),
+        ConnectionState::Connected { .. } => (
+            "Disconnect",
+            "bg-red-600 hover:bg-red-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors shadow-sm hover:shadow-md",
+        ),
+        ConnectionState::Unavailable if !show_modal() => (
+            "Connect",
+            "bg-gray-300 cursor-not-allowed text-gray-600 px-4 py-2 rounded-lg text-sm font-medium border border-gray-200",
+        ),
+        _ => (
+            "Connect",
+            "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors shadow-sm hover:shadow-md",
+        ),
     };
     
     rsx! {
@@ -267,25 +246,35 @@ pub fn WalletConnectButton() -> Element {
             
             button {
                 class: button_class,
-                disabled: wallet_state.read().connecting,
+                disabled: is_busy,
                 onclick: button_click,
                 "{button_text}"
             }
-            
-            // Connected address display
-            if wallet_state.read().connected {
-                div {
-                    class: "mt-2 text-xs text-green-700 bg-green-50 px-2 py-1 rounded border border-green-200",
-                    "Connected: {crate::services::wallet::WalletService::format_address(wallet_state.read().address.as_ref().unwrap_or(&\"Unknown\".to_string()))}"
-                }
-            }
-            
-            // Error display
-            if let Some(error) = &wallet_state.read().error {
-                div {
-                    class: "mt-2 text-xs text-red-700 bg-red-50 px-2 py-1 rounded border border-red-200 max-w-xs",
-                    "{error}"
-                }
+
+            NetworkPill {}
+
+            match connection {
+                ConnectionState::Connecting | ConnectionState::Reconnecting { .. } => rsx! {
+                    button {
+                        class: "ml-2 text-sm text-gray-500 hover:text-red-600",
+                        onclick: move |_| cancel_wallet_operations(),
+                        "Cancel"
+                    }
+                },
+                ConnectionState::Connected { address } => rsx! {
+                    div {
+                        class: "mt-2 flex items-center gap-1.5 text-xs text-green-700 bg-green-50 px-2 py-1 rounded border border-green-200",
+                        AddressAvatar { address: address.clone(), size: "w-4 h-4" }
+                        "Connected: {WalletService::format_address(&address)}"
+                    }
+                },
+                ConnectionState::Error { message, .. } => rsx! {
+                    div {
+                        class: "mt-2 text-xs text-red-700 bg-red-50 px-2 py-1 rounded border border-red-200 max-w-xs",
+                        "{message}"
+                    }
+                },
+                ConnectionState::Unavailable | ConnectionState::Idle => rsx! {},
             }
             
             // Wallet selection modal
@@ -295,4 +284,74 @@ pub fn WalletConnectButton() -> Element {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Network the app publishes to, in amber when the connected wallet is on
+/// another one
+#[component]
+fn NetworkPill() -> Element {
+    let app_network = app_config().network;
+    let mismatch = use_network_mismatch();
+
+    match mismatch() {
+        Some(wallet_network) => rsx! {
+            span {
+                class: "ml-2 inline-flex items-center gap-1 text-xs font-medium text-amber-800 bg-amber-50 px-2 py-0.5 rounded-full border border-amber-300",
+                title: "Your wallet uses {wallet_network.gateway}. Uploads are blocked until it matches the archive's network.",
+                span { class: "w-1.5 h-1.5 rounded-full bg-amber-500" }
+                "Wallet on {wallet_network.display_name()}, archive on {app_network.display_name()}"
+            }
+        },
+        None => rsx! {
+            span {
+                class: "ml-2 inline-flex items-center gap-1 text-xs text-gray-600 bg-gray-50 px-2 py-0.5 rounded-full border border-gray-200",
+                title: "Network the archive publishes to",
+                span { class: if app_network.is_mainnet() { "w-1.5 h-1.5 rounded-full bg-green-500" } else { "w-1.5 h-1.5 rounded-full bg-blue-500" } }
+                "{app_network.display_name()}"
+            }
+        },
+    }
+}
+
+/// Pairing QR code for a Beacon connect started on a desktop
+///
+/// Scanning it in the Beacon app completes the pending connect; closing the
+/// dialog cancels it.
+#[component]
+pub fn BeaconPairingDialog() -> Element {
+    let pairing = use_beacon_pairing();
+    let mut open = use_signal(|| false);
+
+    let pending = pairing.is_some();
+    use_effect(use_reactive!(|pending| open.set(pending)));
+    // Escape, the close button or the backdrop abandon the pairing
+    use_effect(move || {
+        if !*open.read() {
+            cancel_beacon_pairing();
+        }
+    });
+
+    rsx! {
+        Modal {
+            open,
+            title: "Scan with Beacon",
+            class: "bg-gray-800 rounded-2xl p-6 max-w-sm w-full mx-4 relative shadow-2xl",
+            title_class: "text-white text-xl font-semibold mb-4",
+            div {
+                class: "flex flex-col items-center gap-4 text-center",
+                if let Some(uri) = pairing {
+                    QrCode { text: uri, label: "Beacon pairing code", class: "w-60 h-60 p-2" }
+                }
+                p {
+                    class: "text-gray-400 text-sm",
+                    "Open Beacon on your phone, tap the scan button and point it at this code."
+                }
+                button {
+                    class: "bg-gray-700 hover:bg-gray-600 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                    onclick: move |_| open.set(false),
+                    "Cancel"
+                }
+            }
+        }
+    }
+}
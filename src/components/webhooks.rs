@@ -0,0 +1,188 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::services::outbox::{
+    regenerate_webhook_secret, remove_webhook, save_webhook, use_outbox, use_webhook, Outbox, OutboxEntry, OutboxError, SIGNATURE_HEADER,
+    TIMESTAMP_HEADER,
+};
+use crate::services::toast::{show_toast, ToastKind};
+use crate::utils::display::use_copy_feedback;
+
+fn format_timestamp(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|date| date.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// Webhook that hears when moderators approve the connected wallet's items
+#[component]
+pub fn WebhookSettings() -> Element {
+    let webhook = use_webhook();
+    let outbox = use_outbox();
+    let mut url = use_signal(|| webhook.peek().as_ref().map(|config| config.url.clone()).unwrap_or_default());
+    let mut error = use_signal(|| None::<String>);
+    let mut is_busy = use_signal(|| false);
+    let copy_feedback = use_copy_feedback();
+
+    use_future(|| async {
+        if let Err(e) = Outbox::load().await {
+            log::warn!("Could not load the webhook outbox: {}", e);
+        }
+    });
+
+    let mut report = move |result: Result<(), OutboxError>, success: &'static str| match result {
+        Ok(()) => {
+            error.set(None);
+            if !success.is_empty() {
+                show_toast(ToastKind::Success, success);
+            }
+        }
+        Err(e) => error.set(Some(e.to_string())),
+    };
+
+    let save = move |_| {
+        report(save_webhook(&url.read()).map(|_| ()), "Webhook saved");
+    };
+    let send_test = move |_| {
+        spawn(async move {
+            is_busy.set(true);
+            report(Outbox::send_test().await, "Test delivered");
+            is_busy.set(false);
+        });
+    };
+    let remove = move |_| {
+        remove_webhook();
+        url.set(String::new());
+        error.set(None);
+    };
+
+    let config = webhook.read().clone();
+    let entries = outbox.read().clone();
+    let button_class = "border border-green-600 text-green-700 hover:bg-green-50 disabled:opacity-50 px-4 py-2 rounded-lg text-sm font-medium transition-colors";
+
+    rsx! {
+        div {
+            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6 mt-6 space-y-4",
+            div {
+                h3 { class: "text-lg font-semibold text-gray-900", "Approval webhook" }
+                p {
+                    class: "text-sm text-gray-600 mt-1",
+                    "Post a JSON message to your church website or a Discord channel when moderators approve one of your items. Deliveries are sent from this browser while the archive is open, and retried if they fail."
+                }
+            }
+
+            div {
+                class: "flex flex-col sm:flex-row gap-2",
+                input {
+                    class: "flex-1 px-3 py-2 border border-gray-300 rounded-md text-sm",
+                    r#type: "url",
+                    placeholder: "https://discord.com/api/webhooks/…",
+                    value: "{url}",
+                    oninput: move |evt| url.set(evt.value()),
+                }
+                button {
+                    class: "bg-green-600 hover:bg-green-700 disabled:opacity-50 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                    disabled: url.read().trim().is_empty(),
+                    onclick: save,
+                    "Save"
+                }
+            }
+
+            if let Some(config) = config {
+                div {
+                    class: "text-sm text-gray-700 space-y-2",
+                    p {
+                        "Each delivery has a "
+                        code { class: "text-xs", "{SIGNATURE_HEADER}" }
+                        " header: the HMAC-SHA256 of the "
+                        code { class: "text-xs", "{TIMESTAMP_HEADER}" }
+                        " value, a dot and the body, keyed with this secret."
+                    }
+                    div {
+                        class: "flex flex-wrap items-center gap-2",
+                        code { class: "font-mono text-xs bg-gray-50 border border-gray-200 rounded px-2 py-1 break-all", "{config.secret}" }
+                        button {
+                            class: "text-sm text-green-700 hover:text-green-800",
+                            onclick: move |_| copy_feedback.copy(config.secret.clone()),
+                            if copy_feedback.copied() { "Copied" } else { "Copy" }
+                        }
+                        button {
+                            class: "text-sm text-gray-500 hover:text-red-600",
+                            onclick: move |_| report(regenerate_webhook_secret(), "New secret made; update your receiver"),
+                            "Regenerate"
+                        }
+                    }
+                    p { class: "text-xs text-gray-500", "Approvals from {format_timestamp(config.since)} onward are sent." }
+                }
+                div {
+                    class: "flex gap-2",
+                    button { class: button_class, disabled: *is_busy.read(), onclick: send_test, "Send test" }
+                    button { class: "text-sm text-gray-500 hover:text-red-600 px-4 py-2", onclick: remove, "Remove webhook" }
+                }
+            }
+
+            if let Some(message) = error.read().as_ref() {
+                p { class: "text-sm text-red-700", "{message}" }
+            }
+
+            if !entries.is_empty() {
+                div {
+                    class: "border-t border-gray-100 pt-4",
+                    div {
+                        class: "flex items-center justify-between mb-2",
+                        h4 { class: "text-sm font-semibold text-gray-900", "Outbox" }
+                        button {
+                            class: "text-sm text-green-700 hover:text-green-800",
+                            onclick: move |_| async move { report(Outbox::clear_finished().await, "") },
+                            "Clear finished"
+                        }
+                    }
+                    div {
+                        class: "max-h-64 overflow-y-auto divide-y divide-gray-100 text-sm",
+                        for entry in entries {
+                            OutboxRow { key: "{entry.delivery_id()}", entry: entry.clone() }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One delivery in the outbox with its status
+#[component]
+fn OutboxRow(entry: OutboxEntry) -> Element {
+    let title = entry.payload.item.as_ref().map(|item| item.title.clone()).unwrap_or_default();
+    let tx_id = entry.payload.item.as_ref().map(|item| item.tx_id.clone()).unwrap_or_default();
+    let delivery_id = entry.delivery_id().to_string();
+    let retry = move |_| {
+        let delivery_id = delivery_id.clone();
+        async move {
+            if let Err(e) = Outbox::retry(&delivery_id).await {
+                show_toast(ToastKind::Error, e.to_string());
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex items-center justify-between gap-3 py-2",
+            Link { to: Route::ContentDetail { tx_id }, class: "truncate text-gray-900 hover:text-green-700", "{title}" }
+            if let Some(delivered_at) = entry.delivered_at {
+                span { class: "text-green-700 shrink-0", "Sent {format_timestamp(delivered_at)}" }
+            } else {
+                span {
+                    class: "flex items-center gap-2 shrink-0",
+                    if let Some(last_error) = entry.last_error.as_ref() {
+                        span { class: "text-red-700 text-right", title: "{last_error}", "Failed {entry.attempts}×" }
+                    }
+                    if entry.gave_up() {
+                        span { class: "text-gray-500", "Gave up" }
+                    } else {
+                        span { class: "text-gray-500", "Next try {format_timestamp(entry.next_attempt_at)}" }
+                    }
+                    button { class: "text-green-700 underline", onclick: retry, "Retry" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use dioxus::prelude::*;
+use crate::app::Route;
+use crate::services::releases::{
+    is_release_publisher, mark_release_seen, use_upgraded_from, ReleaseHighlight, ReleaseNotes, ReleaseService,
+};
+use crate::services::wallet::use_wallet_state;
+use crate::utils::constants::APP_VERSION;
+
+/// Banner shown after the app was updated, until its notes are read
+#[component]
+pub fn WhatsNewBanner() -> Element {
+    let upgraded_from = use_upgraded_from();
+    if upgraded_from.read().is_none() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "bg-green-50 border-b border-green-200",
+            div {
+                class: "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-3 flex items-center justify-between gap-4 text-sm",
+                span { class: "text-green-900", "Faithful Archive was updated to {APP_VERSION}." }
+                div {
+                    class: "flex gap-3",
+                    Link { class: "font-medium text-green-700 hover:text-green-800", to: Route::WhatsNew {}, "See what's new" }
+                    button { class: "text-gray-500 hover:text-gray-700", onclick: move |_| mark_release_seen(), "Dismiss" }
+                }
+            }
+        }
+    }
+}
+
+/// Release notes since the version the user upgraded from
+///
+/// Opening the page marks the running version as seen. Without a recent
+/// upgrade, every release up to the running one is listed.
+#[component]
+pub fn WhatsNew() -> Element {
+    let upgraded_from = use_upgraded_from();
+    let wallet_state = use_wallet_state();
+    let mut notes = use_signal(|| None::<Result<Vec<ReleaseNotes>, String>>);
+
+    use_effect(move || {
+        let since = upgraded_from.peek().clone();
+        spawn(async move {
+            let loaded = ReleaseService::new().notes_between(since.as_deref()).await.map_err(|e| e.to_string());
+            notes.set(Some(loaded));
+            mark_release_seen();
+        });
+    });
+
+    let can_publish = wallet_state.read().address().is_some_and(is_release_publisher);
+
+    rsx! {
+        main {
+            class: "max-w-3xl mx-auto px-4 sm:px-6 lg:px-8 py-8 space-y-6",
+            h2 { class: "text-2xl font-bold text-gray-900", "What's new" }
+
+            match notes.read().as_ref() {
+                None => rsx! { p { class: "text-sm text-gray-500", "Loading release notes..." } },
+                Some(Err(message)) => rsx! { p { class: "text-sm text-red-700", "{message}" } },
+                Some(Ok(releases)) if releases.is_empty() => rsx! {
+                    p { class: "text-sm text-gray-500", "No release notes have been published for version {APP_VERSION} yet." }
+                },
+                Some(Ok(releases)) => rsx! {
+                    for release in releases.iter() {
+                        section {
+                            key: "{release.version}",
+                            class: "bg-white rounded-xl shadow-sm border border-green-200 p-6",
+                            h3 { class: "text-lg font-semibold text-gray-900", "Version {release.version}" }
+                            p { class: "text-gray-600 mt-1", "{release.summary}" }
+                            ul {
+                                class: "mt-4 space-y-3",
+                                for (index, highlight) in release.highlights.iter().enumerate() {
+                                    li {
+                                        key: "{index}",
+                                        class: "border-l-4 border-green-500 pl-3",
+                                        div { class: "font-medium text-gray-900", "{highlight.title}" }
+                                        p { class: "text-sm text-gray-600", "{highlight.description}" }
+                                        if let Some(link) = highlight.link.as_ref() {
+                                            Link {
+                                                class: "text-sm text-green-700 hover:text-green-800 font-medium",
+                                                to: link.clone(),
+                                                "Try it →"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+
+            if can_publish {
+                PublishReleaseNotes {}
+            }
+        }
+    }
+}
+
+/// Form for release publishers; highlights are one per line as
+/// "Title | Description | /link"
+#[component]
+fn PublishReleaseNotes() -> Element {
+    let mut version = use_signal(|| APP_VERSION.to_string());
+    let mut summary = use_signal(String::new);
+    let mut highlights = use_signal(String::new);
+    let mut status = use_signal(|| None::<Result<String, String>>);
+
+    let publish = move |evt: FormEvent| {
+        evt.prevent_default();
+        let notes = ReleaseNotes {
+            version: version.read().trim().to_string(),
+            summary: summary.read().trim().to_string(),
+            highlights: highlights
+                .read()
+                .lines()
+                .filter_map(|line| {
+                    let mut fields = line.split('|').map(str::trim);
+                    let title = fields.next().filter(|title| !title.is_empty())?.to_string();
+                    Some(ReleaseHighlight {
+                        title,
+                        description: fields.next().unwrap_or_default().to_string(),
+                        link: fields.next().filter(|link| link.starts_with('/')).map(str::to_string),
+                    })
+                })
+                .collect(),
+            published_at: chrono::Utc::now().timestamp(),
+        };
+        spawn(async move {
+            status.set(Some(ReleaseService::new().publish(&notes).await.map_err(|e| e.to_string())));
+        });
+    };
+
+    let input_class = "w-full px-3 py-2 border border-gray-300 rounded-md text-sm";
+
+    rsx! {
+        form {
+            class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6 space-y-3",
+            onsubmit: publish,
+            h3 { class: "text-lg font-semibold text-gray-900", "Publish release notes" }
+            input { class: input_class, placeholder: "Version", value: "{version}", oninput: move |evt| version.set(evt.value()) }
+            textarea { class: input_class, rows: "2", placeholder: "Summary", value: "{summary}", oninput: move |evt| summary.set(evt.value()) }
+            textarea {
+                class: "{input_class} font-mono",
+                rows: "5",
+                placeholder: "Library | Save favorites and watch later | /library",
+                value: "{highlights}",
+                oninput: move |evt| highlights.set(evt.value()),
+            }
+            match status.read().as_ref() {
+                Some(Ok(tx_id)) => rsx! { p { class: "text-sm text-green-700", "Published as {tx_id}" } },
+                Some(Err(message)) => rsx! { p { class: "text-sm text-red-700", "{message}" } },
+                None => rsx! {},
+            }
+            button {
+                class: "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors",
+                r#type: "submit",
+                "Sign and publish"
+            }
+        }
+    }
+}
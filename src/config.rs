@@ -0,0 +1,307 @@
+//! Endpoints and deployment settings: compiled defaults, `FA_*` environment
+//! variables, then an optional `/config.json`, read with `app_config()`
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::services::flags::parse_flag_list;
+use crate::utils::constants::{
+    ALTERNATE_BUNDLERS, AO_CU_URL, AO_MODERATION_PROCESS, AO_MU_URL, AO_TOKEN_PROCESS, ARNS_DOMAIN, ARNS_REGISTRATION_URL, DEFAULT_BUNDLER, DEFAULT_GATEWAYS,
+    DNS_OVER_HTTPS_URL, IPFS_GATEWAY, PASSAGE_API_URL, TURBO_PAYMENT_URL,
+};
+
+/// Path of the runtime overrides, relative to the app's origin
+const CONFIG_PATH: &str = "/config.json";
+
+/// localStorage key for the network picked in Settings; read before launch,
+/// so it isn't part of `AppSettings`
+const NETWORK_KEY: &str = "faithful_archive_network";
+
+static CONFIG: OnceLock<AppConfig> = OnceLock::new();
+
+/// Arweave network uploads and queries go to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkMode {
+    #[default]
+    Mainnet,
+    /// Public test gateway and bundler; uploads may be wiped
+    Testnet,
+    /// An arlocal node on this machine
+    ArLocal,
+}
+
+impl NetworkMode {
+    pub const ALL: [NetworkMode; 3] = [NetworkMode::Mainnet, NetworkMode::Testnet, NetworkMode::ArLocal];
+
+    pub fn storage_value(&self) -> &'static str {
+        match self {
+            NetworkMode::Mainnet => "mainnet",
+            NetworkMode::Testnet => "testnet",
+            NetworkMode::ArLocal => "arlocal",
+        }
+    }
+
+    pub fn from_storage_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.storage_value() == value)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            NetworkMode::Mainnet => "Mainnet",
+            NetworkMode::Testnet => "Testnet",
+            NetworkMode::ArLocal => "ArLocal",
+        }
+    }
+
+    pub fn is_mainnet(&self) -> bool {
+        *self == NetworkMode::Mainnet
+    }
+
+    /// Identifier compared with the network a wallet reports
+    pub fn chain_id(&self) -> &'static str {
+        match self {
+            NetworkMode::Mainnet => "arweave-mainnet",
+            NetworkMode::Testnet => "arweave-testnet",
+            NetworkMode::ArLocal => "arweave-local",
+        }
+    }
+}
+
+/// Gateways and bundler for a non-mainnet network
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NetworkEndpoints {
+    pub gateways: Vec<String>,
+    #[serde(default = "default_graphql_path")]
+    pub graphql_path: String,
+    pub bundler: String,
+    /// Turbo payment service for the bundler's credits; empty where
+    /// uploads can't be paid for with credits
+    #[serde(default)]
+    pub payment_url: String,
+}
+
+fn default_graphql_path() -> String {
+    "/graphql".to_string()
+}
+
+/// Where the app sends its requests
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    /// Deployment name, e.g. "production" or "staging"
+    pub environment: String,
+    /// Network used until the user picks one in Settings
+    pub network: NetworkMode,
+    /// Gateways in priority order; the first is used for citations
+    pub gateways: Vec<String>,
+    /// Path of the GraphQL endpoint on each gateway
+    pub graphql_path: String,
+    /// Bundler that new signed DataItems are posted to
+    pub bundler: String,
+    /// Bundlers that accept re-seeded signed DataItems
+    pub alternate_bundlers: Vec<String>,
+    /// Turbo payment service that sells credits for `bundler`; empty to
+    /// hide top-ups
+    pub turbo_payment_url: String,
+    /// AO messenger unit that signed AO messages are posted to
+    pub ao_mu_url: String,
+    /// AO compute unit process state is read from
+    pub ao_cu_url: String,
+    /// AO token process tips in AO are sent on
+    pub ao_token_process: String,
+    /// AO process recording moderators' review assignments; empty to turn
+    /// assignments off
+    pub ao_moderation_process: String,
+    /// Broker Beacon wallet connects through
+    pub beacon_broker_url: String,
+    /// Logo shown in wallet connection prompts
+    pub logo_url: String,
+    /// Public address of the hosted app, used for canonical share links
+    pub site_url: String,
+    /// Domain ArNS names resolve under
+    pub arns_domain: String,
+    /// Where new ArNS names are bought
+    pub arns_registration_url: String,
+    /// Bible text API used by the passage viewer
+    pub passage_api_url: String,
+    /// DNS-over-HTTPS resolver used to check ministries' domain proofs
+    pub dns_over_https_url: String,
+    /// Malware-scanning API uploads are checked with; empty to rely on the
+    /// built-in checks alone
+    pub scan_api_url: String,
+    /// IPFS gateway that mirror links of items with an `IPFS-CID` open on
+    pub ipfs_gateway: String,
+    /// Experimental features switched on or off by key, e.g.
+    /// `{"tipping": false}`; see `services::flags`
+    pub flags: BTreeMap<String, bool>,
+    pub testnet: NetworkEndpoints,
+    pub arlocal: NetworkEndpoints,
+}
+
+// Comma-separated list from a compile-time variable
+fn env_list(value: Option<&str>, default: &[&str]) -> Vec<String> {
+    match value {
+        Some(list) => list.split(',').map(|url| url.trim().to_string()).filter(|url| !url.is_empty()).collect(),
+        None => default.iter().map(|url| url.to_string()).collect(),
+    }
+}
+
+impl Default for AppConfig {
+    /// Built-in endpoints with any `FA_*` overrides from the build
+    fn default() -> Self {
+        Self {
+            environment: option_env!("FA_ENVIRONMENT").unwrap_or("production").to_string(),
+            network: option_env!("FA_NETWORK").and_then(NetworkMode::from_storage_value).unwrap_or_default(),
+            gateways: env_list(option_env!("FA_GATEWAYS"), DEFAULT_GATEWAYS),
+            graphql_path: option_env!("FA_GRAPHQL_PATH").unwrap_or("/graphql").to_string(),
+            bundler: option_env!("FA_BUNDLER").unwrap_or(DEFAULT_BUNDLER).to_string(),
+            alternate_bundlers: env_list(option_env!("FA_ALTERNATE_BUNDLERS"), ALTERNATE_BUNDLERS),
+            turbo_payment_url: option_env!("FA_TURBO_PAYMENT_URL").unwrap_or(TURBO_PAYMENT_URL).to_string(),
+            ao_mu_url: option_env!("FA_AO_MU_URL").unwrap_or(AO_MU_URL).to_string(),
+            ao_cu_url: option_env!("FA_AO_CU_URL").unwrap_or(AO_CU_URL).to_string(),
+            ao_token_process: option_env!("FA_AO_TOKEN_PROCESS").unwrap_or(AO_TOKEN_PROCESS).to_string(),
+            ao_moderation_process: option_env!("FA_AO_MODERATION_PROCESS").unwrap_or(AO_MODERATION_PROCESS).to_string(),
+            beacon_broker_url: option_env!("FA_BEACON_BROKER_URL")
+                .unwrap_or("wss://aosync-broker-eu.beaconwallet.dev:8081")
+                .to_string(),
+            logo_url: option_env!("FA_LOGO_URL").unwrap_or("https://faithfularchive.org/logo.png").to_string(),
+            site_url: option_env!("FA_SITE_URL").unwrap_or("https://faithful-archive.org").to_string(),
+            arns_domain: option_env!("FA_ARNS_DOMAIN").unwrap_or(ARNS_DOMAIN).to_string(),
+            arns_registration_url: option_env!("FA_ARNS_REGISTRATION_URL").unwrap_or(ARNS_REGISTRATION_URL).to_string(),
+            passage_api_url: option_env!("FA_PASSAGE_API_URL").unwrap_or(PASSAGE_API_URL).to_string(),
+            dns_over_https_url: option_env!("FA_DNS_OVER_HTTPS_URL").unwrap_or(DNS_OVER_HTTPS_URL).to_string(),
+            scan_api_url: option_env!("FA_SCAN_API_URL").unwrap_or_default().to_string(),
+            ipfs_gateway: option_env!("FA_IPFS_GATEWAY").unwrap_or(IPFS_GATEWAY).to_string(),
+            flags: option_env!("FA_FLAGS").map(parse_flag_list).unwrap_or_default(),
+            testnet: NetworkEndpoints {
+                gateways: env_list(option_env!("FA_TESTNET_GATEWAYS"), &["https://ar-io.dev"]),
+                graphql_path: default_graphql_path(),
+                bundler: option_env!("FA_TESTNET_BUNDLER").unwrap_or("https://upload.ardrive.dev/v1/tx").to_string(),
+                payment_url: option_env!("FA_TESTNET_PAYMENT_URL").unwrap_or("https://payment.ardrive.dev").to_string(),
+            },
+            arlocal: NetworkEndpoints {
+                gateways: env_list(option_env!("FA_ARLOCAL_URL"), &["http://localhost:1984"]),
+                graphql_path: default_graphql_path(),
+                bundler: option_env!("FA_ARLOCAL_BUNDLER").unwrap_or("http://localhost:1984/tx").to_string(),
+                payment_url: String::new(),
+            },
+        }
+    }
+}
+
+impl AppConfig {
+    /// First configured gateway, used where a stable URL is needed
+    pub fn primary_gateway(&self) -> &str {
+        self.gateways.first().map(String::as_str).unwrap_or(DEFAULT_GATEWAYS[0])
+    }
+
+    /// Point the top-level endpoints at `network`
+    fn on_network(mut self, network: NetworkMode) -> Self {
+        let endpoints = match network {
+            NetworkMode::Mainnet => None,
+            NetworkMode::Testnet => Some(self.testnet.clone()),
+            NetworkMode::ArLocal => Some(self.arlocal.clone()),
+        };
+        if let Some(endpoints) = endpoints {
+            self.gateways = endpoints.gateways;
+            self.graphql_path = endpoints.graphql_path;
+            // Re-seeding to mainnet bundlers would publish test uploads
+            self.alternate_bundlers = vec![endpoints.bundler.clone()];
+            self.bundler = endpoints.bundler;
+            self.turbo_payment_url = endpoints.payment_url;
+        }
+        self.network = network;
+        self
+    }
+
+    // Trailing slashes would double up when paths are appended
+    fn normalized(mut self) -> Self {
+        self.gateways.retain(|url| !url.trim().is_empty());
+        if self.gateways.is_empty() {
+            self.gateways = env_list(None, DEFAULT_GATEWAYS);
+        }
+        for url in self.gateways.iter_mut().chain(self.alternate_bundlers.iter_mut()).chain([&mut self.site_url, &mut self.ipfs_gateway, &mut self.turbo_payment_url]) {
+            *url = url.trim().trim_end_matches('/').to_string();
+        }
+        self
+    }
+}
+
+// Compiled config with `config.json` applied over it
+async fn fetch_runtime_config() -> Result<AppConfig, String> {
+    let origin = web_sys::window()
+        .ok_or("No window")?
+        .location()
+        .origin()
+        .map_err(|_| "No origin".to_string())?;
+    let response = reqwest::get(format!("{}{}", origin, CONFIG_PATH))
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP {}", response.status().as_u16()));
+    }
+    response.json::<AppConfig>().await.map_err(|e| e.to_string())
+}
+
+/// Load `config.json`, if the deployment has one; call once before launch
+pub async fn load_config() {
+    let config = match fetch_runtime_config().await {
+        Ok(config) => {
+            log::info!("🔧 Loaded {} config from {}", config.environment, CONFIG_PATH);
+            config
+        }
+        Err(e) => {
+            log::info!("🔧 Using built-in config ({} unavailable: {})", CONFIG_PATH, e);
+            AppConfig::default()
+        }
+    };
+    let network = stored_network().unwrap_or(config.network);
+    if !network.is_mainnet() {
+        log::info!("🧪 Running on {}", network.display_name());
+    }
+    let _ = CONFIG.set(config.on_network(network).normalized());
+}
+
+/// The active config; the built-in one if `load_config` hasn't run
+pub fn app_config() -> &'static AppConfig {
+    CONFIG.get_or_init(|| {
+        let config = AppConfig::default();
+        let network = stored_network().unwrap_or(config.network);
+        config.on_network(network).normalized()
+    })
+}
+
+fn stored_network() -> Option<NetworkMode> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(NETWORK_KEY).ok().flatten())
+        .and_then(|value| NetworkMode::from_storage_value(&value))
+}
+
+/// Remember `network` and reload so every service starts over on it
+pub fn switch_network(network: NetworkMode) {
+    if network == app_config().network {
+        return;
+    }
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    if let Ok(Some(storage)) = window.local_storage() {
+        let _ = storage.set_item(NETWORK_KEY, network.storage_value());
+    }
+    let _ = window.location().reload();
+}
+
+/// Make the config available to components; call once in `App`
+pub fn provide_app_config() {
+    use_context_provider(|| app_config().clone());
+}
+
+/// Hook returning the config provided by `App`
+pub fn use_app_config() -> AppConfig {
+    use_context::<AppConfig>()
+}
@@ -0,0 +1,178 @@
+//! Crate-wide error type shown to users
+//!
+//! Services keep their own error enums; anything that reaches the UI is
+//! converted into an `AppError`, which separates the message a user sees
+//! from the detail that goes to the console and a stable code for support.
+
+use crate::services::arweave::ArweaveError;
+use crate::services::bundler::BundlerError;
+use crate::services::encryption::EncryptionError;
+use crate::services::gateway::GatewayError;
+use crate::services::graphql::GraphQLError;
+use crate::services::native_tx::NativeTxError;
+use crate::services::series::SeriesError;
+use crate::services::storage::StorageError;
+use crate::services::upload::{PublishError, UploadError};
+use crate::services::wallet::WalletError;
+
+#[derive(Debug, Clone)]
+pub enum AppError {
+    Wallet(WalletError),
+    Arweave(ArweaveError),
+    /// Gateway, GraphQL or bundler request; the string is the underlying error
+    Network(String),
+    Storage(StorageError),
+    /// Bad input; the message is already written for the user
+    Validation(String),
+}
+
+impl AppError {
+    /// Stable identifier to quote in bug reports
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::Wallet(WalletError::NotInstalled) => "WALLET_NOT_INSTALLED",
+            AppError::Wallet(WalletError::UserDenied) => "WALLET_USER_DENIED",
+            AppError::Wallet(WalletError::NetworkError(_)) => "WALLET_NETWORK",
+            AppError::Wallet(WalletError::InvalidPermissions) => "WALLET_PERMISSIONS",
+            AppError::Wallet(WalletError::TransactionFailed(_)) => "WALLET_TRANSACTION",
+            AppError::Wallet(WalletError::ConnectionFailed(_)) => "WALLET_CONNECTION",
+            AppError::Wallet(WalletError::SigningFailed(_)) => "WALLET_SIGNING",
+            AppError::Wallet(WalletError::TimedOut) => "WALLET_TIMEOUT",
+            AppError::Wallet(WalletError::Cancelled) => "WALLET_CANCELLED",
+            AppError::Arweave(ArweaveError::SignerUnavailable(_)) => "ARWEAVE_SIGNER",
+            AppError::Arweave(ArweaveError::SigningFailed(_)) => "ARWEAVE_SIGNING",
+            AppError::Arweave(ArweaveError::SerializationFailed(_)) => "ARWEAVE_SERIALIZATION",
+            AppError::Network(_) => "NETWORK",
+            AppError::Storage(_) => "STORAGE",
+            AppError::Validation(_) => "VALIDATION",
+        }
+    }
+
+    /// What went wrong and what to do about it, in plain language
+    pub fn user_message(&self) -> String {
+        match self {
+            AppError::Wallet(WalletError::NotInstalled) => {
+                "No Arweave wallet was found. Install Wander or another supported wallet and try again.".to_string()
+            }
+            AppError::Wallet(WalletError::UserDenied) => "The request was declined in your wallet.".to_string(),
+            AppError::Wallet(WalletError::InvalidPermissions) => {
+                "Your wallet hasn't granted the permissions this needs. Reconnect it and approve them.".to_string()
+            }
+            AppError::Wallet(WalletError::SigningFailed(_) | WalletError::TransactionFailed(_)) => {
+                "Your wallet couldn't sign this. Check that it is unlocked and try again.".to_string()
+            }
+            AppError::Wallet(WalletError::NetworkError(_) | WalletError::ConnectionFailed(_)) => {
+                "Couldn't talk to your wallet. Reconnect it and try again.".to_string()
+            }
+            AppError::Wallet(WalletError::TimedOut) => {
+                "Your wallet didn't respond. Check that it is open and unlocked, then try again.".to_string()
+            }
+            AppError::Wallet(WalletError::Cancelled) => "The wallet request was cancelled.".to_string(),
+            AppError::Arweave(_) => "Couldn't prepare the item for Arweave. Please try again.".to_string(),
+            AppError::Network(_) => {
+                "Couldn't reach the Arweave network. Check your connection or pick another gateway in Settings.".to_string()
+            }
+            AppError::Storage(_) => {
+                "Couldn't save to this browser's storage. Private browsing or a full disk can cause this.".to_string()
+            }
+            AppError::Validation(msg) => msg.clone(),
+        }
+    }
+
+    /// Full detail for the console and bug reports
+    pub fn debug_detail(&self) -> String {
+        match self {
+            AppError::Wallet(e) => e.to_string(),
+            AppError::Arweave(e) => e.to_string(),
+            AppError::Network(detail) => detail.clone(),
+            AppError::Storage(e) => e.to_string(),
+            AppError::Validation(msg) => msg.clone(),
+        }
+    }
+
+    /// Whether trying the same thing again might work
+    pub fn is_retryable(&self) -> bool {
+        !matches!(self, AppError::Validation(_) | AppError::Wallet(WalletError::NotInstalled))
+    }
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.user_message())
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<WalletError> for AppError {
+    fn from(error: WalletError) -> Self {
+        AppError::Wallet(error)
+    }
+}
+
+impl From<ArweaveError> for AppError {
+    fn from(error: ArweaveError) -> Self {
+        AppError::Arweave(error)
+    }
+}
+
+impl From<StorageError> for AppError {
+    fn from(error: StorageError) -> Self {
+        AppError::Storage(error)
+    }
+}
+
+impl From<GraphQLError> for AppError {
+    fn from(error: GraphQLError) -> Self {
+        AppError::Network(error.to_string())
+    }
+}
+
+impl From<GatewayError> for AppError {
+    fn from(error: GatewayError) -> Self {
+        AppError::Network(error.to_string())
+    }
+}
+
+impl From<BundlerError> for AppError {
+    fn from(error: BundlerError) -> Self {
+        AppError::Network(error.to_string())
+    }
+}
+
+impl From<reqwest::Error> for AppError {
+    fn from(error: reqwest::Error) -> Self {
+        AppError::Network(error.to_string())
+    }
+}
+
+impl From<PublishError> for AppError {
+    fn from(error: PublishError) -> Self {
+        match error {
+            PublishError::SigningFailed(e) => AppError::Wallet(e),
+            PublishError::SubmitFailed(e) => e.into(),
+            PublishError::TransactionFailed(NativeTxError::Signing(e)) => AppError::Wallet(e),
+            PublishError::TransactionFailed(e) => AppError::Network(e.to_string()),
+            PublishError::DataItemFailed(e) => AppError::Arweave(e),
+            PublishError::WrongNetwork { .. } | PublishError::ReadFailed(_) | PublishError::Cancelled(_) => AppError::Validation(error.to_string()),
+        }
+    }
+}
+
+impl From<UploadError> for AppError {
+    fn from(error: UploadError) -> Self {
+        match error {
+            UploadError::Invalid(msg) => AppError::Validation(msg),
+            UploadError::PublishFailed(e) => e.into(),
+            UploadError::EncryptionFailed(EncryptionError::Wallet(e)) => AppError::Wallet(e),
+            // A missing passphrase or a browser without WebCrypto; the
+            // message is already worded for the uploader
+            UploadError::EncryptionFailed(_) => AppError::Validation(error.to_string()),
+            UploadError::SeriesFailed(SeriesError::PublishFailed(e)) => e.into(),
+            UploadError::SeriesFailed(e) => AppError::Network(e.to_string()),
+            UploadError::ScanBlocked(_) | UploadError::ScanWarnings(_) | UploadError::IpfsMismatch(_) | UploadError::ReadFailed(_) => {
+                AppError::Validation(error.to_string())
+            }
+        }
+    }
+}
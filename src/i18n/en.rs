@@ -0,0 +1,99 @@
+//! English messages
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    ("app.tagline", "Christ-honoring content on Arweave"),
+    ("nav.browse", "Browse"),
+    ("nav.upload", "Upload"),
+    ("nav.library", "Library"),
+    ("nav.about", "About"),
+    ("footer.about", "Preserving Christ-honoring content on the blockchain for future generations."),
+    ("footer.platform", "Platform"),
+    ("footer.browse_content", "Browse Content"),
+    ("footer.weekly_digest", "Weekly Digest"),
+    ("footer.collections", "Collections"),
+    ("footer.moderation", "Moderation"),
+    ("footer.moderation_log", "Moderation log"),
+    ("footer.review_queue", "Review queue"),
+    ("footer.platform_stats", "Platform stats"),
+    ("footer.archive_health", "Archive Health"),
+    ("footer.resources", "Resources"),
+    ("footer.settings", "Settings"),
+    ("footer.your_profile", "Your profile"),
+    ("footer.your_stats", "Your stats"),
+    ("footer.upload_receipts", "Upload receipts"),
+    ("footer.whats_new", "What's new"),
+    ("footer.documentation", "Documentation"),
+    ("footer.connect", "Connect"),
+    ("footer.contact", "Contact"),
+    ("footer.rights_claims", "Rights Claims"),
+    ("footer.support", "Support"),
+    ("footer.community", "Community"),
+    ("footer.copyright", "© 2025 Faithful Archive. Built with ❤️ and ⚡ Rust for the glory of God."),
+    ("home.title", "Preserve Spiritual Content Forever"),
+    ("home.subtitle", "Sermons, worship resources and Bible studies kept on Arweave's permanent storage."),
+    ("home.start_uploading", "Start Uploading"),
+    ("home.browse_content", "Browse Content"),
+    ("browse.title", "Browse the Archive"),
+    ("browse.no_matches", "No items match these filters"),
+    ("browse.previous", "Previous"),
+    ("browse.next", "Next"),
+    ("browse.page", "Page {page}"),
+    ("feed.all", "All"),
+    ("feed.following", "Following"),
+    ("settings.title", "Settings"),
+    ("settings.language", "Language"),
+    ("settings.language_help", "Used for menus and messages. Archived items keep the language they were uploaded in."),
+    ("settings.browse_in_language", "Browse in this language by default"),
+    ("settings.browse_in_language_help", "Browse opens showing items in this language. Clear the filter there to see everything."),
+    ("settings.preferences", "Preferences"),
+    ("settings.theme", "Theme"),
+    ("settings.autoplay", "Autoplay"),
+    ("settings.autoplay_help", "Start playing audio and video when you open an item."),
+    ("settings.data_saver", "Data saver"),
+    ("settings.data_saver_help", "Hide cover images and only load media when you press play."),
+    ("settings.upload_limit", "Upload speed limit"),
+    ("settings.upload_limit_off", "Unlimited"),
+    ("settings.upload_limit_kbps", "{kbps} KB/s"),
+    ("settings.upload_limit_help", "Slows uploads down so they don't take over a shared connection, such as church Wi-Fi. Large uploads can also be paused from the upload queue."),
+    ("settings.usage_stats", "Usage stats"),
+    ("settings.usage_stats_help", "Count your plays, searches and uploads in this browser for your stats page. Nothing is sent anywhere unless you share it."),
+    ("settings.default_license_help", "License new uploads start with. You can still change it on each upload."),
+    ("settings.network", "Network"),
+    ("settings.network_help", "Testnet and ArLocal let you try uploads without publishing them permanently. They keep their own history, and switching reloads the app."),
+    ("network.banner", "Uploads go to a test network and are not part of the permanent archive."),
+    ("network.change", "Change network"),
+    ("wallet.reconnecting", "Reconnecting to your wallet… (attempt {attempt} of {max})"),
+    ("wallet.connection_lost", "Lost the connection to your wallet. Reconnect before signing anything."),
+    ("wallet.reconnect", "Reconnect"),
+    ("settings.idle_lock", "Lock wallet when idle"),
+    ("settings.idle_lock_off", "Never"),
+    ("settings.idle_lock_minutes", "After {minutes} minutes"),
+    ("settings.idle_lock_help", "Disconnects your wallet if nobody uses this computer for a while. Turn this on for shared or church computers."),
+    ("wallet.idle_warning", "Your wallet will disconnect in a minute because this computer has been idle."),
+    ("wallet.stay_connected", "Stay connected"),
+    ("wallet.idle_locked", "Wallet disconnected after {minutes} minutes of inactivity."),
+    ("sync.syncing", "Syncing…"),
+    ("sync.never", "Not synced yet"),
+    ("sync.just_now", "Synced just now"),
+    ("sync.minutes_ago", "Synced {minutes} min ago"),
+    ("sync.hours_ago", "Synced {hours} h ago"),
+    ("sync.sync_now", "Sync now"),
+    ("sync.failed", "Some updates couldn't be fetched. Click to try again."),
+    ("notifications.title", "Notifications"),
+    ("notifications.unread", "{count} unread notifications"),
+    ("notifications.empty", "Nothing new yet"),
+    ("notifications.mark_all_read", "Mark all read"),
+    ("notifications.clear", "Clear"),
+    ("notifications.upload_confirmed", "\"{title}\" is confirmed on Arweave"),
+    ("notifications.content_approved", "Moderators approved \"{title}\""),
+    ("notifications.content_rejected", "Moderators hid \"{title}\" from discovery"),
+    ("notifications.new_from_followed", "New from what you follow: \"{title}\""),
+    ("settings.wallets", "Wallets"),
+    ("settings.wallets_help", "When more than one wallet is installed, the archive selects them in this order."),
+    ("settings.move_up", "Move up"),
+    ("settings.move_down", "Move down"),
+    ("settings.last_wallet", "Reconnects to {wallet} when you open the archive."),
+    ("settings.no_last_wallet", "No wallet remembered. You'll be asked which one to connect."),
+    ("settings.forget_wallet", "Forget"),
+    ("settings.storage_credits", "Uploads above the bundler's free allowance are paid with storage credits."),
+];
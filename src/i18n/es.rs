@@ -0,0 +1,99 @@
+//! Spanish messages
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    ("app.tagline", "Contenido que honra a Cristo en Arweave"),
+    ("nav.browse", "Explorar"),
+    ("nav.upload", "Subir"),
+    ("nav.library", "Biblioteca"),
+    ("nav.about", "Acerca de"),
+    ("footer.about", "Preservando contenido que honra a Cristo en la blockchain para las generaciones futuras."),
+    ("footer.platform", "Plataforma"),
+    ("footer.browse_content", "Explorar contenido"),
+    ("footer.weekly_digest", "Resumen semanal"),
+    ("footer.collections", "Colecciones"),
+    ("footer.moderation", "Moderación"),
+    ("footer.moderation_log", "Registro de moderación"),
+    ("footer.review_queue", "Cola de revisión"),
+    ("footer.platform_stats", "Estadísticas de la plataforma"),
+    ("footer.archive_health", "Estado del archivo"),
+    ("footer.resources", "Recursos"),
+    ("footer.settings", "Configuración"),
+    ("footer.your_profile", "Tu perfil"),
+    ("footer.your_stats", "Tus estadísticas"),
+    ("footer.upload_receipts", "Recibos de subida"),
+    ("footer.whats_new", "Novedades"),
+    ("footer.documentation", "Documentación"),
+    ("footer.connect", "Conecta"),
+    ("footer.contact", "Contacto"),
+    ("footer.rights_claims", "Reclamaciones de derechos"),
+    ("footer.support", "Soporte"),
+    ("footer.community", "Comunidad"),
+    ("footer.copyright", "© 2025 Faithful Archive. Hecho con ❤️ y ⚡ Rust para la gloria de Dios."),
+    ("home.title", "Preserva contenido espiritual para siempre"),
+    ("home.subtitle", "Sermones, recursos de alabanza y estudios bíblicos guardados en el almacenamiento permanente de Arweave."),
+    ("home.start_uploading", "Empezar a subir"),
+    ("home.browse_content", "Explorar contenido"),
+    ("browse.title", "Explorar el archivo"),
+    ("browse.no_matches", "Ningún elemento coincide con estos filtros"),
+    ("browse.previous", "Anterior"),
+    ("browse.next", "Siguiente"),
+    ("browse.page", "Página {page}"),
+    ("feed.all", "Todo"),
+    ("feed.following", "Siguiendo"),
+    ("settings.title", "Configuración"),
+    ("settings.language", "Idioma"),
+    ("settings.language_help", "Se usa en menús y mensajes. El contenido archivado conserva el idioma en que se subió."),
+    ("settings.browse_in_language", "Explorar en este idioma de forma predeterminada"),
+    ("settings.browse_in_language_help", "Explorar se abre mostrando el contenido en este idioma. Quite el filtro allí para verlo todo."),
+    ("settings.preferences", "Preferencias"),
+    ("settings.theme", "Tema"),
+    ("settings.autoplay", "Reproducción automática"),
+    ("settings.autoplay_help", "Reproducir audio y video al abrir un elemento."),
+    ("settings.data_saver", "Ahorro de datos"),
+    ("settings.data_saver_help", "Ocultar imágenes de portada y cargar los medios solo al pulsar reproducir."),
+    ("settings.upload_limit", "Límite de velocidad de subida"),
+    ("settings.upload_limit_off", "Sin límite"),
+    ("settings.upload_limit_kbps", "{kbps} KB/s"),
+    ("settings.upload_limit_help", "Ralentiza las subidas para que no acaparen una conexión compartida, como el wifi de la iglesia. Las subidas grandes también se pueden pausar desde la cola de subidas."),
+    ("settings.usage_stats", "Estadísticas de uso"),
+    ("settings.usage_stats_help", "Contar tus reproducciones, búsquedas y subidas en este navegador para tu página de estadísticas. No se envía nada a menos que lo compartas."),
+    ("settings.default_license_help", "Licencia con la que empiezan las nuevas subidas. Puedes cambiarla en cada subida."),
+    ("settings.network", "Red"),
+    ("settings.network_help", "Testnet y ArLocal permiten probar subidas sin publicarlas de forma permanente. Tienen su propio historial y al cambiar se recarga la aplicación."),
+    ("network.banner", "Las subidas van a una red de prueba y no forman parte del archivo permanente."),
+    ("network.change", "Cambiar de red"),
+    ("wallet.reconnecting", "Reconectando con tu billetera… (intento {attempt} de {max})"),
+    ("wallet.connection_lost", "Se perdió la conexión con tu billetera. Vuelve a conectarla antes de firmar."),
+    ("wallet.reconnect", "Reconectar"),
+    ("settings.idle_lock", "Bloquear la billetera por inactividad"),
+    ("settings.idle_lock_off", "Nunca"),
+    ("settings.idle_lock_minutes", "Después de {minutes} minutos"),
+    ("settings.idle_lock_help", "Desconecta tu billetera si nadie usa este equipo durante un tiempo. Actívalo en equipos compartidos o de la iglesia."),
+    ("wallet.idle_warning", "Tu billetera se desconectará en un minuto porque este equipo ha estado inactivo."),
+    ("wallet.stay_connected", "Seguir conectado"),
+    ("wallet.idle_locked", "Billetera desconectada tras {minutes} minutos de inactividad."),
+    ("sync.syncing", "Sincronizando…"),
+    ("sync.never", "Aún sin sincronizar"),
+    ("sync.just_now", "Sincronizado hace un momento"),
+    ("sync.minutes_ago", "Sincronizado hace {minutes} min"),
+    ("sync.hours_ago", "Sincronizado hace {hours} h"),
+    ("sync.sync_now", "Sincronizar ahora"),
+    ("sync.failed", "No se pudieron obtener algunas actualizaciones. Haz clic para reintentar."),
+    ("notifications.title", "Notificaciones"),
+    ("notifications.unread", "{count} notificaciones sin leer"),
+    ("notifications.empty", "Nada nuevo todavía"),
+    ("notifications.mark_all_read", "Marcar todo como leído"),
+    ("notifications.clear", "Borrar"),
+    ("notifications.upload_confirmed", "\"{title}\" está confirmado en Arweave"),
+    ("notifications.content_approved", "Los moderadores aprobaron \"{title}\""),
+    ("notifications.content_rejected", "Los moderadores ocultaron \"{title}\" de las búsquedas"),
+    ("notifications.new_from_followed", "Nuevo de lo que sigues: \"{title}\""),
+    ("settings.wallets", "Billeteras"),
+    ("settings.wallets_help", "Cuando hay más de una billetera instalada, el archivo las elige en este orden."),
+    ("settings.move_up", "Subir"),
+    ("settings.move_down", "Bajar"),
+    ("settings.last_wallet", "Se vuelve a conectar a {wallet} al abrir el archivo."),
+    ("settings.no_last_wallet", "No hay billetera recordada. Se te preguntará cuál conectar."),
+    ("settings.forget_wallet", "Olvidar"),
+    ("settings.storage_credits", "Las subidas que superan la franquicia gratuita del bundler se pagan con créditos de almacenamiento."),
+];
@@ -0,0 +1,161 @@
+//! Interface translations
+//!
+//! Each locale is a flat table of message keys to strings in its own
+//! module. Look strings up with `t!("nav.browse")`, or
+//! `t!("browse.page", page = 2)` to fill `{page}` placeholders. Keys a
+//! locale hasn't translated yet fall back to English, then to the key.
+//!
+//! Lookups read the current locale signal, so calling `t!` while rendering
+//! re-renders the component when the language changes.
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+mod en;
+mod es;
+mod pt;
+
+/// localStorage key for a language picked in Settings
+const LOCALE_KEY: &str = "faithful_archive_locale";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "es")]
+    Spanish,
+    #[serde(rename = "pt")]
+    Portuguese,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::English, Locale::Spanish, Locale::Portuguese];
+
+    /// BCP 47 language code, as used for `<html lang>`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Spanish => "es",
+            Locale::Portuguese => "pt",
+        }
+    }
+
+    /// The language's name in itself, for the language picker
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::Spanish => "Español",
+            Locale::Portuguese => "Português",
+        }
+    }
+
+    /// Locale for a language tag such as "pt-BR"; regions are ignored
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code.split(['-', '_']).next()?.to_lowercase();
+        Self::ALL.into_iter().find(|locale| locale.code() == language)
+    }
+
+    fn messages(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::English => en::MESSAGES,
+            Locale::Spanish => es::MESSAGES,
+            Locale::Portuguese => pt::MESSAGES,
+        }
+    }
+}
+
+fn lookup(locale: Locale, key: &str) -> Option<&'static str> {
+    locale
+        .messages()
+        .iter()
+        .find(|(message_key, _)| *message_key == key)
+        .map(|(_, message)| *message)
+}
+
+/// First of the browser's preferred languages the app has a bundle for
+fn detect_locale() -> Option<Locale> {
+    let navigator = web_sys::window()?.navigator();
+    navigator
+        .languages()
+        .iter()
+        .filter_map(|language| language.as_string())
+        .chain(navigator.language())
+        .find_map(|language| Locale::from_code(&language))
+}
+
+fn saved_locale() -> Option<Locale> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(LOCALE_KEY).ok().flatten())
+        .and_then(|code| Locale::from_code(&code))
+}
+
+fn set_document_lang(locale: Locale) {
+    let root = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element());
+    if let Some(root) = root {
+        let _ = root.set_attribute("lang", locale.code());
+    }
+}
+
+fn use_locale_state() -> &'static GlobalSignal<Locale> {
+    static LOCALE: GlobalSignal<Locale> = GlobalSignal::new(|| saved_locale().or_else(detect_locale).unwrap_or_default());
+    &LOCALE
+}
+
+/// Message for `key` in the current locale, with `{name}` placeholders
+/// replaced from `args`
+pub fn translate(key: &str, args: &[(&str, String)]) -> String {
+    let locale = *use_locale_state().read();
+    let mut message = lookup(locale, key)
+        .or_else(|| lookup(Locale::English, key))
+        .unwrap_or(key)
+        .to_string();
+    for (name, value) in args {
+        message = message.replace(&format!("{{{}}}", name), value);
+    }
+    message
+}
+
+/// The current locale, without subscribing to changes
+pub fn current_locale() -> Locale {
+    *use_locale_state().peek()
+}
+
+/// Switch language now and remember the choice
+///
+/// UI should go through `update_settings` so the choice is saved with the
+/// other preferences.
+pub fn set_locale(locale: Locale) {
+    if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+        let _ = storage.set_item(LOCALE_KEY, locale.code());
+    }
+    set_document_lang(locale);
+    *use_locale_state().write() = locale;
+}
+
+/// Set `<html lang>` to the saved or detected language
+pub fn init_i18n() {
+    let locale = *use_locale_state().peek();
+    log::info!("🌐 Using locale {}", locale.code());
+    set_document_lang(locale);
+}
+
+/// Hook returning the current locale
+pub fn use_locale() -> Memo<Locale> {
+    use_memo(move || *use_locale_state().read())
+}
+
+/// Translate a message key, optionally filling named placeholders
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::translate($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::translate($key, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
+
+pub(crate) use t;
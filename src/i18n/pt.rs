@@ -0,0 +1,99 @@
+//! Portuguese messages
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    ("app.tagline", "Conteúdo que honra a Cristo no Arweave"),
+    ("nav.browse", "Explorar"),
+    ("nav.upload", "Enviar"),
+    ("nav.library", "Biblioteca"),
+    ("nav.about", "Sobre"),
+    ("footer.about", "Preservando conteúdo que honra a Cristo na blockchain para as gerações futuras."),
+    ("footer.platform", "Plataforma"),
+    ("footer.browse_content", "Explorar conteúdo"),
+    ("footer.weekly_digest", "Resumo semanal"),
+    ("footer.collections", "Coleções"),
+    ("footer.moderation", "Moderação"),
+    ("footer.moderation_log", "Registro de moderação"),
+    ("footer.review_queue", "Fila de revisão"),
+    ("footer.platform_stats", "Estatísticas da plataforma"),
+    ("footer.archive_health", "Saúde do arquivo"),
+    ("footer.resources", "Recursos"),
+    ("footer.settings", "Configurações"),
+    ("footer.your_profile", "Seu perfil"),
+    ("footer.your_stats", "Suas estatísticas"),
+    ("footer.upload_receipts", "Recibos de envio"),
+    ("footer.whats_new", "Novidades"),
+    ("footer.documentation", "Documentação"),
+    ("footer.connect", "Conecte-se"),
+    ("footer.contact", "Contato"),
+    ("footer.rights_claims", "Reivindicações de direitos"),
+    ("footer.support", "Suporte"),
+    ("footer.community", "Comunidade"),
+    ("footer.copyright", "© 2025 Faithful Archive. Feito com ❤️ e ⚡ Rust para a glória de Deus."),
+    ("home.title", "Preserve conteúdo espiritual para sempre"),
+    ("home.subtitle", "Sermões, recursos de louvor e estudos bíblicos guardados no armazenamento permanente do Arweave."),
+    ("home.start_uploading", "Começar a enviar"),
+    ("home.browse_content", "Explorar conteúdo"),
+    ("browse.title", "Explorar o arquivo"),
+    ("browse.no_matches", "Nenhum item corresponde a estes filtros"),
+    ("browse.previous", "Anterior"),
+    ("browse.next", "Próxima"),
+    ("browse.page", "Página {page}"),
+    ("feed.all", "Tudo"),
+    ("feed.following", "Seguindo"),
+    ("settings.title", "Configurações"),
+    ("settings.language", "Idioma"),
+    ("settings.language_help", "Usado em menus e mensagens. O conteúdo arquivado mantém o idioma em que foi enviado."),
+    ("settings.browse_in_language", "Explorar neste idioma por padrão"),
+    ("settings.browse_in_language_help", "Explorar abre mostrando o conteúdo neste idioma. Limpe o filtro lá para ver tudo."),
+    ("settings.preferences", "Preferências"),
+    ("settings.theme", "Tema"),
+    ("settings.autoplay", "Reprodução automática"),
+    ("settings.autoplay_help", "Reproduzir áudio e vídeo ao abrir um item."),
+    ("settings.data_saver", "Economia de dados"),
+    ("settings.data_saver_help", "Ocultar imagens de capa e carregar a mídia só ao tocar em reproduzir."),
+    ("settings.upload_limit", "Limite de velocidade de envio"),
+    ("settings.upload_limit_off", "Sem limite"),
+    ("settings.upload_limit_kbps", "{kbps} KB/s"),
+    ("settings.upload_limit_help", "Deixa os envios mais lentos para não tomarem conta de uma conexão compartilhada, como o Wi-Fi da igreja. Envios grandes também podem ser pausados na fila de envios."),
+    ("settings.usage_stats", "Estatísticas de uso"),
+    ("settings.usage_stats_help", "Contar suas reproduções, buscas e envios neste navegador para sua página de estatísticas. Nada é enviado a menos que você compartilhe."),
+    ("settings.default_license_help", "Licença com que os novos envios começam. Você ainda pode alterá-la em cada envio."),
+    ("settings.network", "Rede"),
+    ("settings.network_help", "Testnet e ArLocal permitem testar envios sem publicá-los permanentemente. Eles têm seu próprio histórico e a troca recarrega o aplicativo."),
+    ("network.banner", "Os envios vão para uma rede de teste e não fazem parte do arquivo permanente."),
+    ("network.change", "Mudar de rede"),
+    ("wallet.reconnecting", "Reconectando à sua carteira… (tentativa {attempt} de {max})"),
+    ("wallet.connection_lost", "A conexão com a sua carteira foi perdida. Reconecte antes de assinar."),
+    ("wallet.reconnect", "Reconectar"),
+    ("settings.idle_lock", "Bloquear a carteira por inatividade"),
+    ("settings.idle_lock_off", "Nunca"),
+    ("settings.idle_lock_minutes", "Após {minutes} minutos"),
+    ("settings.idle_lock_help", "Desconecta sua carteira se ninguém usar este computador por um tempo. Ative em computadores compartilhados ou da igreja."),
+    ("wallet.idle_warning", "Sua carteira será desconectada em um minuto porque este computador está ocioso."),
+    ("wallet.stay_connected", "Continuar conectado"),
+    ("wallet.idle_locked", "Carteira desconectada após {minutes} minutos de inatividade."),
+    ("sync.syncing", "Sincronizando…"),
+    ("sync.never", "Ainda não sincronizado"),
+    ("sync.just_now", "Sincronizado agora mesmo"),
+    ("sync.minutes_ago", "Sincronizado há {minutes} min"),
+    ("sync.hours_ago", "Sincronizado há {hours} h"),
+    ("sync.sync_now", "Sincronizar agora"),
+    ("sync.failed", "Não foi possível obter algumas atualizações. Clique para tentar de novo."),
+    ("notifications.title", "Notificações"),
+    ("notifications.unread", "{count} notificações não lidas"),
+    ("notifications.empty", "Nada de novo ainda"),
+    ("notifications.mark_all_read", "Marcar tudo como lido"),
+    ("notifications.clear", "Limpar"),
+    ("notifications.upload_confirmed", "\"{title}\" está confirmado no Arweave"),
+    ("notifications.content_approved", "Os moderadores aprovaram \"{title}\""),
+    ("notifications.content_rejected", "Os moderadores ocultaram \"{title}\" das buscas"),
+    ("notifications.new_from_followed", "Novo do que você segue: \"{title}\""),
+    ("settings.wallets", "Carteiras"),
+    ("settings.wallets_help", "Quando há mais de uma carteira instalada, o arquivo as escolhe nesta ordem."),
+    ("settings.move_up", "Subir"),
+    ("settings.move_down", "Descer"),
+    ("settings.last_wallet", "Reconecta a {wallet} quando você abre o arquivo."),
+    ("settings.no_last_wallet", "Nenhuma carteira lembrada. Você escolherá qual conectar."),
+    ("settings.forget_wallet", "Esquecer"),
+    ("settings.storage_credits", "Envios acima da franquia gratuita do bundler são pagos com créditos de armazenamento."),
+];
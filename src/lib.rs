@@ -0,0 +1,27 @@
+//! Faithful Archive: sharing Christ-honoring spiritual content on Arweave
+//!
+//! The crate is the web app (the `faithful_archive` binary, behind the
+//! default `app` feature) and a library of the parts other Dioxus and
+//! Arweave projects can reuse. Without `app` it builds the wallet strategy
+//! layer (`services::wallet`), the wallet connect components, and the
+//! services, models and utilities they rest on; the pages, routes and the
+//! services that link into them need `app`.
+//!
+//! ```toml
+//! faithful_archive = { git = "https://github.com/dpshade/faithful-archive-dioxus", default-features = false }
+//! ```
+
+#![allow(non_snake_case)]
+
+#[cfg(feature = "app")]
+pub mod app;
+pub mod components;
+pub mod config;
+pub mod error;
+pub mod i18n;
+pub mod models;
+pub mod services;
+pub mod utils;
+
+#[cfg(test)]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
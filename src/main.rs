@@ -1,17 +1,16 @@
-#![allow(non_snake_case)]
-
 use dioxus::prelude::*;
-
-mod app;
-mod components;
-mod services;
+use faithful_archive::{app, config, services};
 
 fn main() {
-    // Initialize logging for web console
-    console_log::init_with_level(log::Level::Info).expect("Failed to initialize logger");
-    
+    // Console logging plus the buffer behind the debug panel
+    services::logging::init_logging();
+    services::recovery::install_panic_hook();
+
     log::info!("Starting Faithful Archive application");
 
-    // Launch the Dioxus web app  
-    launch(app::App);
+    // Endpoints have to be settled before any service reads them
+    wasm_bindgen_futures::spawn_local(async {
+        config::load_config().await;
+        launch(app::App);
+    });
 }
@@ -0,0 +1,417 @@
+use chrono::NaiveDate;
+
+use crate::models::license::{CommercialUse, Derivation, UDL_LICENSE_TX};
+use crate::models::metadata::{scripture_book, ContentMetadata};
+use crate::utils::language::{language_matches, language_query_values, normalize_language_tag};
+use crate::utils::query_string::{query_segment, QueryParams};
+
+/// Broad media category, mapped to the MIME types it covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    Audio,
+    Video,
+    Document,
+    Text,
+}
+
+impl ContentKind {
+    pub const ALL: [ContentKind; 4] = [ContentKind::Audio, ContentKind::Video, ContentKind::Document, ContentKind::Text];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ContentKind::Audio => "Audio",
+            ContentKind::Video => "Video",
+            ContentKind::Document => "Document",
+            ContentKind::Text => "Text",
+        }
+    }
+
+    pub fn query_value(&self) -> &'static str {
+        match self {
+            ContentKind::Audio => "audio",
+            ContentKind::Video => "video",
+            ContentKind::Document => "document",
+            ContentKind::Text => "text",
+        }
+    }
+
+    pub fn from_query_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.query_value() == value)
+    }
+
+    /// `Content-Type` tag values in this category
+    pub fn mime_types(&self) -> &'static [&'static str] {
+        match self {
+            ContentKind::Audio => &["audio/mpeg", "audio/mp4", "audio/x-m4a", "audio/wav", "audio/x-wav", "audio/ogg"],
+            ContentKind::Video => &["video/mp4", "video/webm", "video/ogg"],
+            ContentKind::Document => &["application/pdf"],
+            ContentKind::Text => &["text/plain", "text/markdown"],
+        }
+    }
+
+    pub fn matches(&self, content_type: &str) -> bool {
+        let mime = content_type.split(';').next().unwrap_or_default().trim();
+        self.mime_types().contains(&mime)
+    }
+}
+
+/// Running-time ranges offered in the filter panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DurationBucket {
+    /// Under 15 minutes
+    Short,
+    /// 15 to 45 minutes
+    Medium,
+    /// Over 45 minutes
+    Long,
+}
+
+impl DurationBucket {
+    pub const ALL: [DurationBucket; 3] = [DurationBucket::Short, DurationBucket::Medium, DurationBucket::Long];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DurationBucket::Short => "Under 15 min",
+            DurationBucket::Medium => "15–45 min",
+            DurationBucket::Long => "Over 45 min",
+        }
+    }
+
+    pub fn query_value(&self) -> &'static str {
+        match self {
+            DurationBucket::Short => "short",
+            DurationBucket::Medium => "medium",
+            DurationBucket::Long => "long",
+        }
+    }
+
+    pub fn from_query_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|bucket| bucket.query_value() == value)
+    }
+
+    pub fn contains(&self, duration_secs: u32) -> bool {
+        match self {
+            DurationBucket::Short => duration_secs < 15 * 60,
+            DurationBucket::Medium => (15 * 60..=45 * 60).contains(&duration_secs),
+            DurationBucket::Long => duration_secs > 45 * 60,
+        }
+    }
+}
+
+/// License terms offered in the filter panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LicenseFilter {
+    /// Any Universal Data License
+    Udl,
+    /// UDL allowing commercial use
+    CommercialUse,
+    /// UDL allowing derivative works
+    Derivatives,
+}
+
+impl LicenseFilter {
+    pub const ALL: [LicenseFilter; 3] = [LicenseFilter::Udl, LicenseFilter::CommercialUse, LicenseFilter::Derivatives];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LicenseFilter::Udl => "Universal Data License",
+            LicenseFilter::CommercialUse => "Commercial use allowed",
+            LicenseFilter::Derivatives => "Derivatives allowed",
+        }
+    }
+
+    pub fn query_value(&self) -> &'static str {
+        match self {
+            LicenseFilter::Udl => "udl",
+            LicenseFilter::CommercialUse => "commercial",
+            LicenseFilter::Derivatives => "derivatives",
+        }
+    }
+
+    pub fn from_query_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|filter| filter.query_value() == value)
+    }
+
+    /// GraphQL tag filters besides `License` itself
+    fn term_filter(&self) -> Option<(&'static str, Vec<&'static str>)> {
+        match self {
+            LicenseFilter::Udl => None,
+            LicenseFilter::CommercialUse => Some(("Commercial-Use", CommercialUse::ALL.iter().map(|t| t.tag_value()).collect())),
+            LicenseFilter::Derivatives => Some(("Derivation", Derivation::ALL.iter().map(|t| t.tag_value()).collect())),
+        }
+    }
+
+    pub fn matches(&self, item: &ContentMetadata) -> bool {
+        let Some(license) = &item.license else {
+            return false;
+        };
+        match self {
+            LicenseFilter::Udl => true,
+            LicenseFilter::CommercialUse => license.commercial_use.is_some(),
+            LicenseFilter::Derivatives => license.derivation.is_some(),
+        }
+    }
+}
+
+/// Active filters on the Browse page
+///
+/// Exact-match tags (content type, speaker, language) are sent to the
+/// gateway as GraphQL tag filters; everything is also checked locally by
+/// `matches` since GraphQL cannot express ranges or partial matches. A
+/// bare language such as "pt" asks for its common regional variants too.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BrowseFilters {
+    /// Archived on or after this date
+    pub date_from: Option<NaiveDate>,
+    /// Archived on or before this date
+    pub date_to: Option<NaiveDate>,
+    pub kind: Option<ContentKind>,
+    pub speaker: Option<String>,
+    /// Book name such as "Romans" or "1 John"
+    pub scripture_book: Option<String>,
+    pub language: Option<String>,
+    pub duration: Option<DurationBucket>,
+    pub license: Option<LicenseFilter>,
+    /// List earlier versions of items too, not just the newest
+    pub all_versions: bool,
+}
+
+impl BrowseFilters {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Number of filters set, for the panel badge
+    pub fn active_count(&self) -> usize {
+        [
+            self.date_from.is_some(),
+            self.date_to.is_some(),
+            self.kind.is_some(),
+            self.speaker.is_some(),
+            self.scripture_book.is_some(),
+            self.language.is_some(),
+            self.duration.is_some(),
+            self.license.is_some(),
+            self.all_versions,
+        ]
+        .into_iter()
+        .filter(|set| *set)
+        .count()
+    }
+
+    /// GraphQL tag filters as (tag name, accepted values)
+    pub fn tag_filters(&self) -> Vec<(&'static str, Vec<&str>)> {
+        let mut filters = Vec::new();
+        if let Some(kind) = self.kind {
+            filters.push(("Content-Type", kind.mime_types().to_vec()));
+        }
+        if let Some(speaker) = &self.speaker {
+            filters.push(("Speaker", vec![speaker.as_str()]));
+        }
+        if let Some(language) = &self.language {
+            filters.push(("Language", language_query_values(language)));
+        }
+        if let Some(license) = self.license {
+            filters.push(("License", vec![UDL_LICENSE_TX]));
+            filters.extend(license.term_filter());
+        }
+        filters
+    }
+
+    /// Whether an item passes every active filter
+    pub fn matches(&self, item: &ContentMetadata) -> bool {
+        if let Some(kind) = self.kind {
+            if !kind.matches(&item.content_type) {
+                return false;
+            }
+        }
+
+        if let Some(speaker) = &self.speaker {
+            let matched = item.speaker.as_ref().is_some_and(|s| s.eq_ignore_ascii_case(speaker));
+            if !matched {
+                return false;
+            }
+        }
+
+        if let Some(language) = &self.language {
+            let matched = item.language.as_ref().is_some_and(|l| language_matches(language, l));
+            if !matched {
+                return false;
+            }
+        }
+
+        if let Some(book) = &self.scripture_book {
+            let matched = item.scripture_refs.iter().any(|r| scripture_book(r).eq_ignore_ascii_case(book));
+            if !matched {
+                return false;
+            }
+        }
+
+        if let Some(bucket) = self.duration {
+            if !item.duration_secs.is_some_and(|secs| bucket.contains(secs)) {
+                return false;
+            }
+        }
+
+        if let Some(license) = self.license {
+            if !license.matches(item) {
+                return false;
+            }
+        }
+
+        if self.date_from.is_some() || self.date_to.is_some() {
+            let Some(date) = item
+                .block_timestamp
+                .or(item.created_at)
+                .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+                .map(|dt| dt.date_naive())
+            else {
+                return false;
+            };
+            if self.date_from.is_some_and(|from| date < from) || self.date_to.is_some_and(|to| date > to) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl QueryParams for BrowseFilters {
+    fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        let date = |d: Option<NaiveDate>| d.map(|d| d.format("%Y-%m-%d").to_string()).unwrap_or_default();
+        vec![
+            ("from", date(self.date_from)),
+            ("to", date(self.date_to)),
+            ("type", self.kind.map(|k| k.query_value().to_string()).unwrap_or_default()),
+            ("speaker", self.speaker.clone().unwrap_or_default()),
+            ("book", self.scripture_book.clone().unwrap_or_default()),
+            ("lang", self.language.clone().unwrap_or_default()),
+            ("duration", self.duration.map(|d| d.query_value().to_string()).unwrap_or_default()),
+            ("license", self.license.map(|l| l.query_value().to_string()).unwrap_or_default()),
+            ("versions", if self.all_versions { "all".to_string() } else { String::new() }),
+        ]
+    }
+
+    fn from_pairs(pairs: &[(String, String)]) -> Self {
+        let mut filters = Self::default();
+        for (key, value) in pairs {
+            let value = value.trim().to_string();
+            if value.is_empty() {
+                continue;
+            }
+            match key.as_str() {
+                "from" => filters.date_from = NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok(),
+                "to" => filters.date_to = NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok(),
+                "type" => filters.kind = ContentKind::from_query_value(&value),
+                "speaker" => filters.speaker = Some(value),
+                "book" => filters.scripture_book = Some(value),
+                "lang" => filters.language = Some(normalize_language_tag(&value).unwrap_or(value)),
+                "duration" => filters.duration = DurationBucket::from_query_value(&value),
+                "license" => filters.license = LicenseFilter::from_query_value(&value),
+                "versions" => filters.all_versions = value == "all",
+                _ => {}
+            }
+        }
+        filters
+    }
+}
+
+/// Browse route state: filters plus the cursors of the pages walked so far
+///
+/// The last cursor is where the current page starts; keeping the whole
+/// trail lets "Previous" work on a freshly opened shared link.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BrowseQuery {
+    pub filters: BrowseFilters,
+    pub cursors: Vec<String>,
+}
+
+impl BrowseQuery {
+    pub fn new(filters: BrowseFilters) -> Self {
+        Self { filters, cursors: Vec::new() }
+    }
+
+    /// Cursor the current page starts after
+    pub fn after(&self) -> Option<&str> {
+        self.cursors.last().map(String::as_str)
+    }
+
+    pub fn page_number(&self) -> usize {
+        self.cursors.len() + 1
+    }
+
+    pub fn next_page(&self, cursor: String) -> Self {
+        let mut next = self.clone();
+        next.cursors.push(cursor);
+        next
+    }
+
+    pub fn previous_page(&self) -> Self {
+        let mut previous = self.clone();
+        previous.cursors.pop();
+        previous
+    }
+}
+
+impl QueryParams for BrowseQuery {
+    fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = self.filters.to_pairs();
+        pairs.extend(self.cursors.iter().map(|cursor| ("cursor", cursor.clone())));
+        pairs
+    }
+
+    fn from_pairs(pairs: &[(String, String)]) -> Self {
+        Self {
+            filters: BrowseFilters::from_pairs(pairs),
+            cursors: pairs
+                .iter()
+                .filter(|(key, value)| key == "cursor" && !value.is_empty())
+                .map(|(_, value)| value.clone())
+                .collect(),
+        }
+    }
+}
+
+query_segment!(BrowseQuery);
+
+/// Search route state: the query text, filters and result page
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    pub text: String,
+    pub filters: BrowseFilters,
+    /// Zero-based result page
+    pub page: usize,
+}
+
+impl SearchQuery {
+    pub fn new(text: &str) -> Self {
+        Self { text: text.trim().to_string(), ..Self::default() }
+    }
+
+    pub fn with_page(&self, page: usize) -> Self {
+        Self { page, ..self.clone() }
+    }
+}
+
+impl QueryParams for SearchQuery {
+    fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = vec![("q", self.text.clone())];
+        pairs.extend(self.filters.to_pairs());
+        if self.page > 0 {
+            pairs.push(("page", self.page.to_string()));
+        }
+        pairs
+    }
+
+    fn from_pairs(pairs: &[(String, String)]) -> Self {
+        let value = |name: &str| pairs.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str());
+        Self {
+            text: value("q").unwrap_or_default().trim().to_string(),
+            filters: BrowseFilters::from_pairs(pairs),
+            page: value("page").and_then(|page| page.parse().ok()).unwrap_or(0),
+        }
+    }
+}
+
+query_segment!(SearchQuery);
@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+/// `Type` tag value of collection manifest DataItems
+pub const COLLECTION_MANIFEST_TYPE: &str = "Collection-Manifest";
+
+/// A compilation of archived items curated by any user
+///
+/// Unlike a series, a collection doesn't belong to the uploader of its
+/// items: anyone can gather other people's uploads under a topic such as
+/// "Easter sermons". It is published and edited the same way as a series
+/// manifest, and only the curator (the signer of its first manifest) can
+/// publish new versions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionManifest {
+    pub collection_id: String,
+    pub title: String,
+    pub description: Option<String>,
+    /// Item IDs in the curator's order
+    pub items: Vec<String>,
+    pub updated_at: i64,
+    /// Edit counter, so a writer can tell whether someone else published
+    /// since it loaded the manifest
+    pub version: u64,
+}
+
+impl CollectionManifest {
+    pub fn new(title: &str) -> Self {
+        Self {
+            collection_id: uuid::Uuid::new_v4().to_string(),
+            title: title.trim().to_string(),
+            description: None,
+            items: Vec::new(),
+            updated_at: chrono::Utc::now().timestamp(),
+            version: 0,
+        }
+    }
+
+    pub fn contains(&self, tx_id: &str) -> bool {
+        self.items.iter().any(|item| item == tx_id)
+    }
+
+    /// Append an item, ignoring duplicates
+    pub fn add_item(&mut self, tx_id: &str) {
+        if !self.contains(tx_id) {
+            self.items.push(tx_id.to_string());
+        }
+        self.updated_at = chrono::Utc::now().timestamp();
+    }
+
+    pub fn remove_item(&mut self, tx_id: &str) {
+        self.items.retain(|item| item != tx_id);
+        self.updated_at = chrono::Utc::now().timestamp();
+    }
+
+    /// Move an item one place up (`up`) or down the list
+    pub fn move_item(&mut self, tx_id: &str, up: bool) {
+        let Some(position) = self.items.iter().position(|item| item == tx_id) else {
+            return;
+        };
+        let target = if up { position.checked_sub(1) } else { Some(position + 1) };
+        if let Some(target) = target.filter(|target| *target < self.items.len()) {
+            self.items.swap(position, target);
+            self.updated_at = chrono::Utc::now().timestamp();
+        }
+    }
+
+    /// Whether `latest` was published after this copy was loaded
+    pub fn conflicts_with(&self, latest: &CollectionManifest) -> bool {
+        latest.version > self.version
+    }
+
+    /// Fold in a concurrent edit: their order is kept and our additions
+    /// are appended, while our title and description win
+    pub fn merge_from(&mut self, theirs: &CollectionManifest) {
+        let mut items = theirs.items.clone();
+        for item in &self.items {
+            if !items.contains(item) {
+                items.push(item.clone());
+            }
+        }
+        self.items = items;
+        self.version = theirs.version;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn items_keep_the_curators_order() {
+        let mut collection = CollectionManifest::new("  Easter sermons ");
+        assert_eq!(collection.title, "Easter sermons");
+        collection.add_item("a");
+        collection.add_item("b");
+        collection.add_item("a");
+        collection.add_item("c");
+        assert_eq!(collection.items, ["a", "b", "c"]);
+
+        collection.move_item("c", true);
+        collection.move_item("a", true);
+        collection.move_item("b", false);
+        assert_eq!(collection.items, ["a", "c", "b"]);
+
+        collection.remove_item("c");
+        assert_eq!(collection.items, ["a", "b"]);
+    }
+}
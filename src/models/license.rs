@@ -0,0 +1,228 @@
+use serde::{Deserialize, Serialize};
+
+/// Arweave transaction holding the Universal Data License text; the
+/// `License` tag of UDL-licensed items points at it
+pub const UDL_LICENSE_TX: &str = "yRj4a5KMctX_uOmKWCFJIjmY8DeJcusVk6-HzLiM_t8";
+
+/// `Commercial-Use` terms; absent means commercial use is not allowed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommercialUse {
+    Allowed,
+    AllowedWithCredit,
+}
+
+impl CommercialUse {
+    pub const ALL: [CommercialUse; 2] = [CommercialUse::Allowed, CommercialUse::AllowedWithCredit];
+
+    pub fn tag_value(&self) -> &'static str {
+        match self {
+            CommercialUse::Allowed => "Allowed",
+            CommercialUse::AllowedWithCredit => "Allowed-With-Credit",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CommercialUse::Allowed => "Commercial use allowed",
+            CommercialUse::AllowedWithCredit => "Commercial use allowed with credit",
+        }
+    }
+
+    pub fn from_tag_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|terms| terms.tag_value() == value)
+    }
+}
+
+/// `Derivation` terms; absent means derivative works are not allowed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Derivation {
+    AllowedWithCredit,
+    AllowedWithIndication,
+    AllowedWithLicensePassthrough,
+}
+
+impl Derivation {
+    pub const ALL: [Derivation; 3] = [
+        Derivation::AllowedWithCredit,
+        Derivation::AllowedWithIndication,
+        Derivation::AllowedWithLicensePassthrough,
+    ];
+
+    pub fn tag_value(&self) -> &'static str {
+        match self {
+            Derivation::AllowedWithCredit => "Allowed-With-Credit",
+            Derivation::AllowedWithIndication => "Allowed-With-Indication",
+            Derivation::AllowedWithLicensePassthrough => "Allowed-With-License-Passthrough",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Derivation::AllowedWithCredit => "Derivatives allowed with credit",
+            Derivation::AllowedWithIndication => "Derivatives allowed if changes are indicated",
+            Derivation::AllowedWithLicensePassthrough => "Derivatives allowed under the same license",
+        }
+    }
+
+    pub fn from_tag_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|terms| terms.tag_value() == value)
+    }
+}
+
+/// How often a `License-Fee` is charged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FeeInterval {
+    OneTime,
+    Monthly,
+}
+
+impl FeeInterval {
+    fn tag_prefix(&self) -> &'static str {
+        match self {
+            FeeInterval::OneTime => "One-Time",
+            FeeInterval::Monthly => "Monthly",
+        }
+    }
+}
+
+/// A fee for the licensed uses, e.g. `License-Fee: One-Time-0.5`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LicenseFee {
+    pub interval: FeeInterval,
+    pub amount: f64,
+    /// Token the fee is paid in, from the `Currency` tag
+    pub currency: String,
+}
+
+impl LicenseFee {
+    fn tag_value(&self) -> String {
+        format!("{}-{}", self.interval.tag_prefix(), self.amount)
+    }
+
+    fn from_tag_value(value: &str, currency: Option<String>) -> Option<Self> {
+        let (interval, amount) = if let Some(amount) = value.strip_prefix("One-Time-") {
+            (FeeInterval::OneTime, amount)
+        } else {
+            (FeeInterval::Monthly, value.strip_prefix("Monthly-")?)
+        };
+        Some(Self {
+            interval,
+            amount: amount.parse().ok().filter(|amount: &f64| amount.is_finite() && *amount >= 0.0)?,
+            // The UDL's default currency
+            currency: currency.unwrap_or_else(|| "U".to_string()),
+        })
+    }
+
+    pub fn display(&self) -> String {
+        match self.interval {
+            FeeInterval::OneTime => format!("{} {} one-time fee", self.amount, self.currency),
+            FeeInterval::Monthly => format!("{} {} per month", self.amount, self.currency),
+        }
+    }
+}
+
+/// Universal Data License terms of an item, as tags
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UdlTerms {
+    pub commercial_use: Option<CommercialUse>,
+    pub derivation: Option<Derivation>,
+    pub fee: Option<LicenseFee>,
+    /// Where fees are paid; defaults to the uploader
+    pub payment_address: Option<String>,
+}
+
+impl UdlTerms {
+    pub fn to_tags(&self) -> Vec<(String, String)> {
+        let mut tags = vec![("License".to_string(), UDL_LICENSE_TX.to_string())];
+        if let Some(commercial_use) = self.commercial_use {
+            tags.push(("Commercial-Use".to_string(), commercial_use.tag_value().to_string()));
+        }
+        if let Some(derivation) = self.derivation {
+            tags.push(("Derivation".to_string(), derivation.tag_value().to_string()));
+        }
+        if let Some(fee) = &self.fee {
+            tags.push(("License-Fee".to_string(), fee.tag_value()));
+            tags.push(("Currency".to_string(), fee.currency.clone()));
+        }
+        if let Some(address) = self.payment_address.as_ref().filter(|address| !address.trim().is_empty()) {
+            tags.push(("Payment-Address".to_string(), address.trim().to_string()));
+        }
+        tags
+    }
+
+    /// Terms from an item's tags; `None` unless it carries the UDL
+    pub fn from_tags(tags: &[(String, String)]) -> Option<Self> {
+        let tag = |name: &str| tags.iter().find(|(tag_name, _)| tag_name == name).map(|(_, value)| value.clone());
+        if tag("License").as_deref() != Some(UDL_LICENSE_TX) {
+            return None;
+        }
+
+        Some(Self {
+            commercial_use: tag("Commercial-Use").and_then(|value| CommercialUse::from_tag_value(&value)),
+            derivation: tag("Derivation").and_then(|value| Derivation::from_tag_value(&value)),
+            fee: tag("License-Fee").and_then(|value| LicenseFee::from_tag_value(&value, tag("Currency"))),
+            payment_address: tag("Payment-Address"),
+        })
+    }
+
+    /// One line per term, for the detail page
+    pub fn summary(&self) -> Vec<String> {
+        let mut lines = vec![
+            self.commercial_use
+                .map(|terms| terms.display_name().to_string())
+                .unwrap_or_else(|| "No commercial use".to_string()),
+            self.derivation
+                .map(|terms| terms.display_name().to_string())
+                .unwrap_or_else(|| "No derivative works".to_string()),
+        ];
+        if let Some(fee) = &self.fee {
+            lines.push(fee.display());
+        }
+        if let Some(address) = &self.payment_address {
+            lines.push(format!("Fees paid to {}", address));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn terms_round_trip_through_tags() {
+        let terms = UdlTerms {
+            commercial_use: Some(CommercialUse::AllowedWithCredit),
+            derivation: Some(Derivation::AllowedWithLicensePassthrough),
+            fee: Some(LicenseFee { interval: FeeInterval::Monthly, amount: 2.5, currency: "AR".to_string() }),
+            payment_address: Some("vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JI".to_string()),
+        };
+
+        assert_eq!(UdlTerms::from_tags(&terms.to_tags()), Some(terms));
+    }
+
+    #[wasm_bindgen_test]
+    fn bare_license_round_trips() {
+        let terms = UdlTerms::default();
+        assert_eq!(UdlTerms::from_tags(&terms.to_tags()), Some(terms));
+    }
+
+    #[wasm_bindgen_test]
+    fn fee_without_currency_uses_the_udl_default() {
+        let tags = vec![
+            ("License".to_string(), UDL_LICENSE_TX.to_string()),
+            ("License-Fee".to_string(), "One-Time-0.5".to_string()),
+        ];
+        let fee = UdlTerms::from_tags(&tags).unwrap().fee.unwrap();
+
+        assert_eq!(fee.interval, FeeInterval::OneTime);
+        assert_eq!(fee.currency, "U");
+    }
+
+    #[wasm_bindgen_test]
+    fn other_licenses_are_ignored() {
+        let tags = vec![("License".to_string(), "some-other-license".to_string())];
+        assert_eq!(UdlTerms::from_tags(&tags), None);
+    }
+}
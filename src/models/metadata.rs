@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::license::UdlTerms;
+use crate::models::rights::RightsDeclaration;
+
+/// `Type` tag value of published spiritual content
+pub const SPIRITUAL_CONTENT_TYPE: &str = "Spiritual-Content";
+
+/// How an encrypted item's content key is obtained
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeySource {
+    /// Derived from a passphrase shared with readers
+    Passphrase,
+    /// Random, wrapped to the uploader's wallet
+    Wallet,
+}
+
+impl KeySource {
+    pub fn tag_value(self) -> &'static str {
+        match self {
+            KeySource::Passphrase => "Passphrase",
+            KeySource::Wallet => "Wallet",
+        }
+    }
+
+    pub fn from_tag_value(value: &str) -> Option<Self> {
+        match value {
+            "Passphrase" => Some(KeySource::Passphrase),
+            "Wallet" => Some(KeySource::Wallet),
+            _ => None,
+        }
+    }
+}
+
+/// Encryption parameters of a private item, from its `Encryption-*` tags
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncryptionInfo {
+    pub key_source: KeySource,
+    /// Base64 PBKDF2 salt for passphrase keys
+    pub salt: Option<String>,
+    /// Base64 content key encrypted to the uploader's wallet
+    pub wrapped_key: Option<String>,
+    /// MIME type of the decrypted content
+    pub content_type: String,
+}
+
+/// Metadata of a published item, read from its Arweave tags
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContentMetadata {
+    pub tx_id: String,
+    pub owner: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub content_type: String,
+    pub speaker: Option<String>,
+    pub ministry: Option<String>,
+    pub series: Option<String>,
+    /// `series_id` of the series manifest the item was uploaded into
+    #[serde(default)]
+    pub series_id: Option<String>,
+    pub scripture_refs: Vec<String>,
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Running time in seconds from the `Duration` tag
+    #[serde(default)]
+    pub duration_secs: Option<u32>,
+    /// Size of the item's data in bytes from the `File-Size` tag
+    #[serde(default)]
+    pub file_size: Option<u64>,
+    /// DataItem holding the transcript, from the `Transcript-Tx` tag
+    #[serde(default)]
+    pub transcript_tx_id: Option<String>,
+    /// DataItem holding the cover image, from the `Cover-Tx` tag
+    #[serde(default)]
+    pub cover_tx_id: Option<String>,
+    /// Hex SHA-256 of the item's data from the `File-Hash` tag
+    #[serde(default)]
+    pub file_hash: Option<String>,
+    /// CID of the same file on IPFS from the `IPFS-CID` tag, checked
+    /// against the data when it was uploaded
+    #[serde(default)]
+    pub ipfs_cid: Option<String>,
+    /// Set when the item's data is encrypted
+    #[serde(default)]
+    pub encryption: Option<EncryptionInfo>,
+    /// Universal Data License terms, if the item is UDL-licensed
+    #[serde(default)]
+    pub license: Option<UdlTerms>,
+    /// Who holds the rights and on what basis it's shared, as the uploader
+    /// declared
+    #[serde(default)]
+    pub rights: Option<RightsDeclaration>,
+    /// Earlier version this item replaces, from the `Previous-Version` tag;
+    /// only honoured when both have the same uploader
+    #[serde(default)]
+    pub previous_version: Option<String>,
+    /// Unix timestamp from the `Created-At` tag
+    pub created_at: Option<i64>,
+    /// Block timestamp; `None` while still pending
+    pub block_timestamp: Option<i64>,
+    pub block_height: Option<u64>,
+}
+
+impl ContentMetadata {
+    /// Build from transaction tags; `None` unless the item has a title
+    pub fn from_tags(
+        tx_id: &str,
+        owner: &str,
+        tags: &[(String, String)],
+        block_height: Option<u64>,
+        block_timestamp: Option<i64>,
+    ) -> Option<Self> {
+        let tag = |name: &str| {
+            tags.iter()
+                .find(|(tag_name, _)| tag_name == name)
+                .map(|(_, value)| value.clone())
+        };
+
+        let mut scripture_refs: Vec<(usize, String)> = tags
+            .iter()
+            .filter_map(|(name, value)| {
+                let index = name.strip_prefix("Scripture-Ref-")?.parse().ok()?;
+                Some((index, value.clone()))
+            })
+            .collect();
+        scripture_refs.sort_by_key(|(index, _)| *index);
+
+        Some(Self {
+            tx_id: tx_id.to_string(),
+            owner: owner.to_string(),
+            title: tag("Title")?,
+            description: tag("Description"),
+            content_type: tag("Content-Type").unwrap_or_else(|| "application/octet-stream".to_string()),
+            speaker: tag("Speaker"),
+            ministry: tag("Ministry"),
+            series: tag("Series"),
+            series_id: tag("Series-Id"),
+            scripture_refs: scripture_refs.into_iter().map(|(_, value)| value).collect(),
+            language: tag("Language"),
+            duration_secs: tag("Duration").and_then(|value| value.parse().ok()),
+            file_size: tag("File-Size").and_then(|value| value.parse().ok()),
+            transcript_tx_id: tag("Transcript-Tx"),
+            cover_tx_id: tag("Cover-Tx"),
+            file_hash: tag("File-Hash"),
+            ipfs_cid: tag("IPFS-CID"),
+            encryption: tag("Encryption-Key").and_then(|value| KeySource::from_tag_value(&value)).map(|key_source| EncryptionInfo {
+                key_source,
+                salt: tag("Encryption-Salt"),
+                wrapped_key: tag("Encryption-Wrapped-Key"),
+                content_type: tag("Original-Content-Type").unwrap_or_else(|| "application/octet-stream".to_string()),
+            }),
+            license: UdlTerms::from_tags(tags),
+            rights: RightsDeclaration::from_tags(tags),
+            previous_version: tag("Previous-Version"),
+            created_at: tag("Created-At").and_then(|value| value.parse().ok()),
+            block_timestamp,
+            block_height,
+        })
+    }
+}
+
+/// Book portion of a scripture reference: "1 John 2:1" -> "1 John"
+pub fn scripture_book(scripture_ref: &str) -> &str {
+    let trimmed = scripture_ref.trim();
+    match trimmed.rsplit_once(' ') {
+        Some((book, chapter)) if chapter.starts_with(|c: char| c.is_ascii_digit()) => book.trim(),
+        _ => trimmed,
+    }
+}
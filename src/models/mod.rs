@@ -0,0 +1,23 @@
+// Data models for Faithful Archive
+pub mod transaction;
+pub mod rights_claim;
+pub mod metadata;
+pub mod browse_filters;
+pub mod series;
+pub mod collection;
+pub mod license;
+pub mod profile;
+pub mod review;
+pub mod rights;
+
+// Re-export main types
+pub use transaction::{SubmittedItem, TxStatus, BlockInfo};
+pub use rights_claim::{RightsClaim, ClaimStatus, ClaimReceipt, ClaimAcknowledgment};
+pub use metadata::{ContentMetadata, EncryptionInfo, KeySource, SPIRITUAL_CONTENT_TYPE};
+pub use browse_filters::{BrowseFilters, BrowseQuery, SearchQuery, ContentKind, DurationBucket, LicenseFilter};
+pub use series::{SeriesManifest, SERIES_MANIFEST_TYPE};
+pub use collection::{CollectionManifest, COLLECTION_MANIFEST_TYPE};
+pub use license::{CommercialUse, Derivation, FeeInterval, LicenseFee, UdlTerms, UDL_LICENSE_TX};
+pub use profile::{SocialLink, UploaderProfile, UPLOADER_PROFILE_TYPE};
+pub use review::{ReviewChecklist, ReviewCriterion, MAX_REVIEW_SCORE};
+pub use rights::{RightsBasis, RightsDeclaration};
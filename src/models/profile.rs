@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+/// `Type` tag value of uploader profile DataItems
+pub const UPLOADER_PROFILE_TYPE: &str = "Uploader-Profile";
+
+/// A link to one of a ministry's social accounts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SocialLink {
+    /// Display label, such as "YouTube"
+    pub platform: String,
+    pub url: String,
+}
+
+/// Public profile of an uploading address
+///
+/// Published as a JSON DataItem signed by the address it describes;
+/// editing publishes a new one and the newest wins. Profiles from any
+/// other signer are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UploaderProfile {
+    pub ministry_name: String,
+    /// Transaction of an uploaded logo image
+    #[serde(default)]
+    pub logo_tx_id: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub website: Option<String>,
+    #[serde(default)]
+    pub socials: Vec<SocialLink>,
+    /// Arweave address that tips go to; may differ from the uploading one
+    #[serde(default)]
+    pub payment_address: Option<String>,
+    pub updated_at: i64,
+}
+
+impl UploaderProfile {
+    /// Check the profile before publishing, returning the first problem found
+    pub fn validate(&self) -> Result<(), String> {
+        if self.ministry_name.trim().is_empty() {
+            return Err("Ministry name is required".to_string());
+        }
+        if self.logo_tx_id.as_ref().is_some_and(|tx_id| tx_id.len() != 43) {
+            return Err("The logo must be a 43-character transaction ID".to_string());
+        }
+        let valid_address = |address: &String| {
+            address.len() == 43 && address.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        };
+        if self.payment_address.as_ref().is_some_and(|address| !valid_address(address)) {
+            return Err("The payment address must be a 43-character Arweave address".to_string());
+        }
+        let links = self.website.iter().chain(self.socials.iter().map(|social| &social.url));
+        for link in links {
+            if !link.starts_with("https://") && !link.starts_with("http://") {
+                return Err(format!("{} is not a web address", link));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+
+/// Version of the checklist in `Review-Checklist` tags, bumped if the
+/// criteria or scale change so old scores aren't compared with new ones
+pub const REVIEW_CHECKLIST_VERSION: &str = "1";
+
+/// Highest score on the checklist's scale; 1 is the lowest
+pub const MAX_REVIEW_SCORE: u8 = 5;
+
+/// One of the content guidelines a moderator scores an item against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReviewCriterion {
+    Doctrine,
+    Copyright,
+    AudioQuality,
+    Metadata,
+}
+
+impl ReviewCriterion {
+    pub const ALL: [ReviewCriterion; 4] = [
+        ReviewCriterion::Doctrine,
+        ReviewCriterion::Copyright,
+        ReviewCriterion::AudioQuality,
+        ReviewCriterion::Metadata,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ReviewCriterion::Doctrine => "Doctrinal soundness",
+            ReviewCriterion::Copyright => "Copyright",
+            ReviewCriterion::AudioQuality => "Audio quality",
+            ReviewCriterion::Metadata => "Metadata",
+        }
+    }
+
+    /// What a moderator checks, as shown beside the score
+    pub fn guidance(&self) -> &'static str {
+        match self {
+            ReviewCriterion::Doctrine => "Christ-honoring and faithful to Scripture",
+            ReviewCriterion::Copyright => "The uploader owns it or has permission to share it",
+            ReviewCriterion::AudioQuality => "Clear, audible and free of long silences or noise",
+            ReviewCriterion::Metadata => "Title, speaker, passages and language are filled in and correct",
+        }
+    }
+
+    pub fn tag_name(&self) -> &'static str {
+        match self {
+            ReviewCriterion::Doctrine => "Score-Doctrine",
+            ReviewCriterion::Copyright => "Score-Copyright",
+            ReviewCriterion::AudioQuality => "Score-Audio-Quality",
+            ReviewCriterion::Metadata => "Score-Metadata",
+        }
+    }
+}
+
+/// A moderator's scores for an item against the content guidelines
+///
+/// Published as tags on the decision it backs, so decisions can be
+/// compared and analysed later rather than read one note at a time.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReviewChecklist {
+    pub doctrine: Option<u8>,
+    pub copyright: Option<u8>,
+    pub audio_quality: Option<u8>,
+    pub metadata: Option<u8>,
+}
+
+impl ReviewChecklist {
+    pub fn score(&self, criterion: ReviewCriterion) -> Option<u8> {
+        match criterion {
+            ReviewCriterion::Doctrine => self.doctrine,
+            ReviewCriterion::Copyright => self.copyright,
+            ReviewCriterion::AudioQuality => self.audio_quality,
+            ReviewCriterion::Metadata => self.metadata,
+        }
+    }
+
+    /// Set a score, clamped to the scale
+    pub fn set_score(&mut self, criterion: ReviewCriterion, score: Option<u8>) {
+        let score = score.map(|score| score.clamp(1, MAX_REVIEW_SCORE));
+        match criterion {
+            ReviewCriterion::Doctrine => self.doctrine = score,
+            ReviewCriterion::Copyright => self.copyright = score,
+            ReviewCriterion::AudioQuality => self.audio_quality = score,
+            ReviewCriterion::Metadata => self.metadata = score,
+        }
+    }
+
+    /// Whether every criterion has been scored
+    pub fn is_complete(&self) -> bool {
+        ReviewCriterion::ALL.iter().all(|criterion| self.score(*criterion).is_some())
+    }
+
+    /// Check every criterion is scored, naming the first that isn't
+    pub fn validate(&self) -> Result<(), String> {
+        match ReviewCriterion::ALL.iter().find(|criterion| self.score(**criterion).is_none()) {
+            Some(criterion) => Err(format!("Score {} before deciding", criterion.display_name().to_lowercase())),
+            None => Ok(()),
+        }
+    }
+
+    pub fn to_tags(&self) -> Vec<(String, String)> {
+        let mut tags = vec![("Review-Checklist".to_string(), REVIEW_CHECKLIST_VERSION.to_string())];
+        for criterion in ReviewCriterion::ALL {
+            if let Some(score) = self.score(criterion) {
+                tags.push((criterion.tag_name().to_string(), score.to_string()));
+            }
+        }
+        tags
+    }
+
+    /// Scores from a decision's tags; `None` unless it carries a checklist
+    /// of this version
+    pub fn from_tags(tags: &[(String, String)]) -> Option<Self> {
+        let tag = |name: &str| tags.iter().find(|(tag_name, _)| tag_name == name).map(|(_, value)| value.as_str());
+        if tag("Review-Checklist") != Some(REVIEW_CHECKLIST_VERSION) {
+            return None;
+        }
+
+        let mut checklist = Self::default();
+        for criterion in ReviewCriterion::ALL {
+            let score = tag(criterion.tag_name())
+                .and_then(|value| value.parse::<u8>().ok())
+                .filter(|score| (1..=MAX_REVIEW_SCORE).contains(score));
+            checklist.set_score(criterion, score);
+        }
+        Some(checklist)
+    }
+
+    /// "Doctrinal soundness 5/5 · Copyright 2/5 · …", skipping unscored
+    /// criteria
+    pub fn summary(&self) -> String {
+        ReviewCriterion::ALL
+            .iter()
+            .filter_map(|criterion| {
+                let score = self.score(*criterion)?;
+                Some(format!("{} {}/{}", criterion.display_name(), score, MAX_REVIEW_SCORE))
+            })
+            .collect::<Vec<_>>()
+            .join(" · ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn checklist_round_trips_through_tags() {
+        let mut checklist = ReviewChecklist::default();
+        assert_eq!(checklist.validate(), Err("Score doctrinal soundness before deciding".to_string()));
+        checklist.set_score(ReviewCriterion::Doctrine, Some(5));
+        checklist.set_score(ReviewCriterion::Copyright, Some(1));
+        checklist.set_score(ReviewCriterion::AudioQuality, Some(9));
+        checklist.set_score(ReviewCriterion::Metadata, Some(3));
+
+        assert!(checklist.is_complete());
+        assert_eq!(checklist.audio_quality, Some(MAX_REVIEW_SCORE));
+        assert_eq!(ReviewChecklist::from_tags(&checklist.to_tags()), Some(checklist));
+        assert_eq!(ReviewChecklist::from_tags(&[("Reason".to_string(), "Spam".to_string())]), None);
+    }
+}
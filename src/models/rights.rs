@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+
+/// Why the uploader may publish an item, from the `Rights-Basis` tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RightsBasis {
+    /// The uploader or their ministry holds the copyright
+    OwnWork,
+    /// Published under a license or with the rights holder's permission
+    Licensed,
+    PublicDomain,
+}
+
+impl RightsBasis {
+    pub const ALL: [RightsBasis; 3] = [RightsBasis::OwnWork, RightsBasis::Licensed, RightsBasis::PublicDomain];
+
+    pub fn tag_value(&self) -> &'static str {
+        match self {
+            RightsBasis::OwnWork => "Own-Work",
+            RightsBasis::Licensed => "Licensed",
+            RightsBasis::PublicDomain => "Public-Domain",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            RightsBasis::OwnWork => "We hold the copyright",
+            RightsBasis::Licensed => "Shared under a license or with permission",
+            RightsBasis::PublicDomain => "Public domain",
+        }
+    }
+
+    pub fn from_tag_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|basis| basis.tag_value() == value)
+    }
+}
+
+/// The uploader's declaration of who holds the rights to an item and on
+/// what basis it's shared
+///
+/// Worship recordings usually include songs licensed through CCLI, so a
+/// licensed item that contains music has to give a CCLI or other license
+/// number that moderators can check.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RightsDeclaration {
+    pub basis: RightsBasis,
+    /// Person or ministry holding the copyright
+    pub rights_holder: String,
+    /// The recording includes worship music or other songs
+    pub contains_music: bool,
+    /// CCLI copyright or streaming license number
+    pub ccli_license: Option<String>,
+    /// Any other license or permission reference
+    pub license_reference: Option<String>,
+}
+
+impl Default for RightsDeclaration {
+    fn default() -> Self {
+        Self {
+            basis: RightsBasis::OwnWork,
+            rights_holder: String::new(),
+            contains_music: false,
+            ccli_license: None,
+            license_reference: None,
+        }
+    }
+}
+
+impl RightsDeclaration {
+    /// Licensed music given without a license number to check it against
+    pub fn missing_music_license(&self) -> bool {
+        self.contains_music && self.basis == RightsBasis::Licensed && self.ccli_license.is_none() && self.license_reference.is_none()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.rights_holder.trim().is_empty() {
+            return Err("Name the rights holder".to_string());
+        }
+        if let Some(number) = &self.ccli_license {
+            if !number.chars().all(|c| c.is_ascii_digit()) {
+                return Err("CCLI license numbers contain only digits".to_string());
+            }
+        }
+        if self.missing_music_license() {
+            return Err("Give the CCLI or other license number covering the music".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn to_tags(&self) -> Vec<(String, String)> {
+        let mut tags = vec![
+            ("Rights-Basis".to_string(), self.basis.tag_value().to_string()),
+            ("Rights-Holder".to_string(), self.rights_holder.trim().to_string()),
+        ];
+        if self.contains_music {
+            tags.push(("Contains-Music".to_string(), "true".to_string()));
+        }
+        if let Some(number) = &self.ccli_license {
+            tags.push(("CCLI-License".to_string(), number.clone()));
+        }
+        if let Some(reference) = &self.license_reference {
+            tags.push(("License-Reference".to_string(), reference.clone()));
+        }
+        tags
+    }
+
+    /// Declaration from an item's tags; `None` unless it carries one
+    pub fn from_tags(tags: &[(String, String)]) -> Option<Self> {
+        let tag = |name: &str| tags.iter().find(|(tag_name, _)| tag_name == name).map(|(_, value)| value.clone());
+        Some(Self {
+            basis: RightsBasis::from_tag_value(&tag("Rights-Basis")?)?,
+            rights_holder: tag("Rights-Holder").unwrap_or_default(),
+            contains_music: tag("Contains-Music").as_deref() == Some("true"),
+            ccli_license: tag("CCLI-License"),
+            license_reference: tag("License-Reference"),
+        })
+    }
+
+    /// One line per part of the declaration, for the detail page
+    pub fn summary(&self) -> Vec<String> {
+        let mut lines = vec![self.basis.display_name().to_string()];
+        if !self.rights_holder.is_empty() {
+            lines.push(format!("Rights holder: {}", self.rights_holder));
+        }
+        if self.contains_music {
+            lines.push("Includes music".to_string());
+        }
+        if let Some(number) = &self.ccli_license {
+            lines.push(format!("CCLI license #{}", number));
+        }
+        if let Some(reference) = &self.license_reference {
+            lines.push(format!("License: {}", reference));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn licensed_music_needs_a_license_number() {
+        let mut declaration = RightsDeclaration {
+            basis: RightsBasis::Licensed,
+            rights_holder: "Grace Fellowship".to_string(),
+            contains_music: true,
+            ..RightsDeclaration::default()
+        };
+        assert!(declaration.missing_music_license());
+        assert!(declaration.validate().is_err());
+
+        declaration.ccli_license = Some("1234567".to_string());
+        assert_eq!(declaration.validate(), Ok(()));
+        assert_eq!(RightsDeclaration::from_tags(&declaration.to_tags()), Some(declaration));
+        assert_eq!(RightsDeclaration::from_tags(&[("Title".to_string(), "Psalm 23".to_string())]), None);
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ReviewChecklist;
+
+/// A copyright / rights-holder claim against a published item
+///
+/// Serialized to JSON and encrypted to the moderation team before it is
+/// published, so claimant details never appear on-chain in the clear.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RightsClaim {
+    pub tracking_number: String,
+    pub target_tx_id: String,
+    pub claimant_name: String,
+    pub claimant_email: String,
+    pub organization: Option<String>,
+    /// The copyrighted work the claimant owns or represents
+    pub work_description: String,
+    /// How the target item infringes the work
+    pub infringement_details: String,
+    /// Claimant affirms a good-faith belief the use is unauthorized
+    pub good_faith_statement: bool,
+    /// Claimant affirms the claim is accurate and they are authorized to act
+    pub accuracy_statement: bool,
+    /// Typed full name acting as the claimant's signature
+    pub signature: String,
+    pub submitted_at: i64,
+}
+
+impl RightsClaim {
+    /// New tracking number of the form `FA-XXXXXXXX`
+    pub fn generate_tracking_number() -> String {
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        format!("FA-{}", id[..8].to_uppercase())
+    }
+
+    /// Check required fields, returning the first problem found
+    pub fn validate(&self) -> Result<(), String> {
+        if self.target_tx_id.trim().len() != 43 {
+            return Err("Enter the 43-character ID of the item you are claiming".to_string());
+        }
+        if self.claimant_name.trim().is_empty() {
+            return Err("Your name is required".to_string());
+        }
+        if !self.claimant_email.contains('@') {
+            return Err("A valid contact email is required".to_string());
+        }
+        if self.work_description.trim().is_empty() || self.infringement_details.trim().is_empty() {
+            return Err("Describe the work and how it is infringed".to_string());
+        }
+        if !self.good_faith_statement || !self.accuracy_statement {
+            return Err("Both statements must be affirmed".to_string());
+        }
+        if self.signature.trim() != self.claimant_name.trim() {
+            return Err("Type your full name exactly as entered above to sign".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Where a claim is in the review process
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClaimStatus {
+    /// Published, not yet acknowledged by a moderator
+    Submitted,
+    /// A moderator has acknowledged receipt
+    UnderReview,
+    /// Claim upheld and the item de-listed
+    Validated,
+    Rejected,
+}
+
+impl ClaimStatus {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ClaimStatus::Submitted => "Submitted",
+            ClaimStatus::UnderReview => "Under Review",
+            ClaimStatus::Validated => "Validated — Item De-listed",
+            ClaimStatus::Rejected => "Rejected",
+        }
+    }
+
+    /// Value of the `Decision` tag on acknowledgment DataItems
+    pub fn tag_value(&self) -> &'static str {
+        match self {
+            ClaimStatus::Submitted => "Submitted",
+            ClaimStatus::UnderReview => "Received",
+            ClaimStatus::Validated => "Validated",
+            ClaimStatus::Rejected => "Rejected",
+        }
+    }
+
+    pub fn from_tag_value(value: &str) -> Option<Self> {
+        match value {
+            "Submitted" => Some(ClaimStatus::Submitted),
+            "Received" => Some(ClaimStatus::UnderReview),
+            "Validated" => Some(ClaimStatus::Validated),
+            "Rejected" => Some(ClaimStatus::Rejected),
+            _ => None,
+        }
+    }
+}
+
+/// Receipt shown to the claimant after submission
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClaimReceipt {
+    pub tracking_number: String,
+    /// DataItem ID of the encrypted claim
+    pub claim_tx_id: String,
+    pub submitted_at: i64,
+}
+
+/// A signed moderator acknowledgment or decision on a claim
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClaimAcknowledgment {
+    pub tracking_number: String,
+    /// DataItem ID of the claim answered, from the `Claim-Tx` tag; `None`
+    /// on entries published before it was tagged
+    #[serde(default)]
+    pub claim_tx_id: Option<String>,
+    pub status: ClaimStatus,
+    pub moderator: String,
+    /// The moderator's note, from the `Reason` tag; empty on entries
+    /// published before it was tagged
+    #[serde(default)]
+    pub note: String,
+    /// Guideline scores the decision was made on, if any
+    #[serde(default)]
+    pub review: Option<ReviewChecklist>,
+    /// DataItem ID of the signed acknowledgment
+    pub tx_id: String,
+    pub timestamp: Option<i64>,
+}
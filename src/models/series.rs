@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// `Type` tag value of series manifest DataItems
+pub const SERIES_MANIFEST_TYPE: &str = "Series-Manifest";
+
+/// An ordered sermon series or playlist
+///
+/// Published as a JSON DataItem; editing a series publishes a new manifest
+/// with the same `series_id`, and the newest one from the owner wins.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeriesManifest {
+    pub series_id: String,
+    pub title: String,
+    pub description: Option<String>,
+    /// Member item IDs in playback order
+    pub members: Vec<String>,
+    pub updated_at: i64,
+}
+
+impl SeriesManifest {
+    pub fn new(title: &str) -> Self {
+        Self {
+            series_id: uuid::Uuid::new_v4().to_string(),
+            title: title.trim().to_string(),
+            description: None,
+            members: Vec::new(),
+            updated_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    /// Append an item, ignoring duplicates
+    pub fn add_member(&mut self, tx_id: &str) {
+        if !self.members.iter().any(|member| member == tx_id) {
+            self.members.push(tx_id.to_string());
+        }
+        self.updated_at = chrono::Utc::now().timestamp();
+    }
+
+    /// The member after `tx_id`, if any
+    pub fn next_after(&self, tx_id: &str) -> Option<&str> {
+        let position = self.members.iter().position(|member| member == tx_id)?;
+        self.members.get(position + 1).map(String::as_str)
+    }
+}
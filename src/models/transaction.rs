@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+/// Lifecycle of a submitted transaction or DataItem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TxStatus {
+    /// Accepted by the bundler/gateway but not yet in a block
+    Pending,
+    /// Included in a block, waiting for enough confirmations
+    Confirming,
+    /// Reached the required number of confirmations; polling stops
+    Confirmed,
+}
+
+impl TxStatus {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            TxStatus::Pending => "Pending",
+            TxStatus::Confirming => "Confirming",
+            TxStatus::Confirmed => "Confirmed",
+        }
+    }
+}
+
+/// Block inclusion info for a transaction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlockInfo {
+    pub height: u64,
+    pub timestamp: Option<i64>,
+    pub confirmations: u64,
+}
+
+/// A transaction or DataItem recently submitted from this browser
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubmittedItem {
+    pub tx_id: String,
+    pub title: String,
+    pub owner: Option<String>,
+    pub submitted_at: i64,
+    pub status: TxStatus,
+    pub confirmations: u64,
+    pub block_height: Option<u64>,
+    pub last_checked: Option<i64>,
+}
+
+impl SubmittedItem {
+    pub fn new(tx_id: &str, title: &str, owner: Option<String>) -> Self {
+        Self {
+            tx_id: tx_id.to_string(),
+            title: title.to_string(),
+            owner,
+            submitted_at: chrono::Utc::now().timestamp(),
+            status: TxStatus::Pending,
+            confirmations: 0,
+            block_height: None,
+            last_checked: None,
+        }
+    }
+
+    /// Whether the poller still needs to check this item
+    pub fn needs_polling(&self) -> bool {
+        self.status != TxStatus::Confirmed
+    }
+
+    /// Apply fresh block info, returning true if the status changed
+    pub fn apply_block_info(&mut self, info: Option<&BlockInfo>, required_confirmations: u64) -> bool {
+        let previous = self.status;
+        self.last_checked = Some(chrono::Utc::now().timestamp());
+
+        match info {
+            Some(info) => {
+                self.block_height = Some(info.height);
+                self.confirmations = info.confirmations;
+                self.status = if info.confirmations >= required_confirmations {
+                    TxStatus::Confirmed
+                } else {
+                    TxStatus::Confirming
+                };
+            }
+            None => {
+                self.status = TxStatus::Pending;
+            }
+        }
+
+        previous != self.status
+    }
+}
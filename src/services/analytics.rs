@@ -0,0 +1,432 @@
+//! Opt-in usage stats
+//!
+//! With `AppSettings::usage_stats` on, the app counts plays, searches and
+//! steps through the upload form in one record per day in
+//! `USAGE_STATS_STORE`. Only counts are kept: no search terms, no timings,
+//! and the IDs of items played never leave the browser. There are no
+//! third-party trackers; the stats page is the only reader.
+//!
+//! Sharing is a separate, explicit step. `AnalyticsService::share_totals`
+//! publishes a period's totals without item IDs, rounded down to a
+//! multiple of `SHARED_COUNT_BUCKET`, and signed by a key made for that one
+//! DataItem so it can't be tied to a wallet, the local pseudonym or an
+//! earlier share.
+
+use std::cell::Cell;
+use std::collections::{BTreeMap, HashMap};
+
+use bundles_rs::crypto::ethereum::EthereumSigner;
+use chrono::{Days, NaiveDate};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::services::arweave::ArweaveService;
+use crate::services::bundler::{BundlerClient, BundlerError};
+use crate::services::settings::current_settings;
+use crate::services::storage::{StorageError, StorageService, USAGE_STATS_STORE};
+use crate::utils::constants::{SHARED_COUNT_BUCKET, USAGE_STATS_RETENTION_DAYS};
+
+/// `Type` tag of shared usage totals
+pub const USAGE_TOTALS_TYPE: &str = "Usage-Totals";
+
+/// Most played items listed in a summary
+const MAX_TOP_ITEMS: usize = 10;
+
+thread_local! {
+    // Days are only saved once the stored ones have been merged in, so an
+    // early save can't overwrite a day counted in an earlier session
+    static LOADED: Cell<bool> = const { Cell::new(false) };
+}
+
+#[derive(Debug, Clone)]
+pub enum AnalyticsError {
+    Storage(StorageError),
+    NothingToShare,
+    SigningFailed(String),
+    SubmitFailed(BundlerError),
+}
+
+impl std::fmt::Display for AnalyticsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnalyticsError::Storage(e) => write!(f, "{}", e),
+            AnalyticsError::NothingToShare => write!(f, "There are no totals large enough to share yet"),
+            AnalyticsError::SigningFailed(msg) => write!(f, "Could not sign the usage totals: {}", msg),
+            AnalyticsError::SubmitFailed(e) => write!(f, "Could not publish to the bundler: {}", e),
+        }
+    }
+}
+
+impl From<StorageError> for AnalyticsError {
+    fn from(error: StorageError) -> Self {
+        AnalyticsError::Storage(error)
+    }
+}
+
+/// Steps through the upload form, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UploadStep {
+    Opened,
+    FileChosen,
+    Submitted,
+    Published,
+    Failed,
+}
+
+impl UploadStep {
+    pub const ALL: [UploadStep; 5] = [
+        UploadStep::Opened,
+        UploadStep::FileChosen,
+        UploadStep::Submitted,
+        UploadStep::Published,
+        UploadStep::Failed,
+    ];
+
+    /// Key in stored and shared counts
+    pub fn key(&self) -> &'static str {
+        match self {
+            UploadStep::Opened => "opened",
+            UploadStep::FileChosen => "file_chosen",
+            UploadStep::Submitted => "submitted",
+            UploadStep::Published => "published",
+            UploadStep::Failed => "failed",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            UploadStep::Opened => "Opened the upload form",
+            UploadStep::FileChosen => "Chose a file",
+            UploadStep::Submitted => "Submitted",
+            UploadStep::Published => "Published",
+            UploadStep::Failed => "Failed",
+        }
+    }
+}
+
+/// Something usage stats count
+#[derive(Debug, Clone, PartialEq)]
+pub enum UsageEvent {
+    /// Playback of an item started
+    Play(String),
+    Search,
+    Upload(UploadStep),
+}
+
+/// One day's counts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyUsage {
+    pub day: NaiveDate,
+    pub plays: u32,
+    pub searches: u32,
+    /// Upload form steps by `UploadStep::key`
+    #[serde(default)]
+    pub upload_steps: BTreeMap<String, u32>,
+    /// Plays by item ID; never shared
+    #[serde(default)]
+    pub item_plays: BTreeMap<String, u32>,
+}
+
+impl DailyUsage {
+    pub fn new(day: NaiveDate) -> Self {
+        Self {
+            day,
+            plays: 0,
+            searches: 0,
+            upload_steps: BTreeMap::new(),
+            item_plays: BTreeMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, event: &UsageEvent) {
+        match event {
+            UsageEvent::Play(tx_id) => {
+                self.plays += 1;
+                *self.item_plays.entry(tx_id.clone()).or_default() += 1;
+            }
+            UsageEvent::Search => self.searches += 1,
+            UsageEvent::Upload(step) => *self.upload_steps.entry(step.key().to_string()).or_default() += 1,
+        }
+    }
+
+    fn merge(&mut self, other: &DailyUsage) {
+        self.plays += other.plays;
+        self.searches += other.searches;
+        for (step, count) in &other.upload_steps {
+            *self.upload_steps.entry(step.clone()).or_default() += count;
+        }
+        for (tx_id, count) in &other.item_plays {
+            *self.item_plays.entry(tx_id.clone()).or_default() += count;
+        }
+    }
+}
+
+/// Totals over the last few days
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UsageSummary {
+    pub days: u64,
+    pub plays: u32,
+    pub searches: u32,
+    /// Upload form steps by `UploadStep::key`
+    pub upload_steps: BTreeMap<String, u32>,
+    /// Most played items, most plays first
+    pub top_items: Vec<(String, u32)>,
+    /// Plays per day, oldest first, including days without any
+    pub daily_plays: Vec<(NaiveDate, u32)>,
+}
+
+impl UsageSummary {
+    /// Sum the `days` days of `records` up to and including `today`
+    pub fn from_days<'a>(records: impl IntoIterator<Item = &'a DailyUsage>, today: NaiveDate, days: u64) -> Self {
+        let first = today - Days::new(days.saturating_sub(1));
+        let mut total = DailyUsage::new(today);
+        let mut plays_by_day: HashMap<NaiveDate, u32> = HashMap::new();
+        for record in records {
+            if record.day < first || record.day > today {
+                continue;
+            }
+            total.merge(record);
+            *plays_by_day.entry(record.day).or_default() += record.plays;
+        }
+
+        let mut top_items: Vec<(String, u32)> = total.item_plays.into_iter().collect();
+        top_items.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        top_items.truncate(MAX_TOP_ITEMS);
+
+        let daily_plays = first
+            .iter_days()
+            .take_while(|day| *day <= today)
+            .map(|day| (day, plays_by_day.get(&day).copied().unwrap_or(0)))
+            .collect();
+
+        Self {
+            days,
+            plays: total.plays,
+            searches: total.searches,
+            upload_steps: total.upload_steps,
+            top_items,
+            daily_plays,
+        }
+    }
+
+    pub fn upload_step(&self, step: UploadStep) -> u32 {
+        self.upload_steps.get(step.key()).copied().unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.plays == 0 && self.searches == 0 && self.upload_steps.values().all(|count| *count == 0)
+    }
+}
+
+/// The anonymized totals `share_totals` publishes
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SharedTotals {
+    pub period_days: u64,
+    pub plays: u32,
+    pub searches: u32,
+    pub upload_steps: BTreeMap<String, u32>,
+}
+
+impl SharedTotals {
+    pub fn from_summary(summary: &UsageSummary) -> Self {
+        Self {
+            period_days: summary.days,
+            plays: bucket(summary.plays),
+            searches: bucket(summary.searches),
+            upload_steps: summary
+                .upload_steps
+                .iter()
+                .map(|(step, count)| (step.clone(), bucket(*count)))
+                .filter(|(_, count)| *count > 0)
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.plays == 0 && self.searches == 0 && self.upload_steps.is_empty()
+    }
+}
+
+fn bucket(count: u32) -> u32 {
+    count / SHARED_COUNT_BUCKET * SHARED_COUNT_BUCKET
+}
+
+fn today() -> NaiveDate {
+    chrono::Local::now().date_naive()
+}
+
+// Counted days in memory, by day
+fn use_usage_state() -> &'static GlobalSignal<HashMap<NaiveDate, DailyUsage>> {
+    static USAGE: GlobalSignal<HashMap<NaiveDate, DailyUsage>> = GlobalSignal::new(HashMap::new);
+    &USAGE
+}
+
+async fn save_day(day: NaiveDate) -> Result<(), StorageError> {
+    if !LOADED.with(Cell::get) {
+        return Ok(());
+    }
+    let storage = StorageService::open().await?;
+    // Read the counts only once the database is open, so when saves
+    // overlap the last one written has the newest counts
+    let Some(record) = use_usage_state().peek().get(&day).cloned() else {
+        return Ok(());
+    };
+    storage.put(USAGE_STATS_STORE, &day.to_string(), &record).await
+}
+
+/// Count `event`, if usage stats are turned on
+pub fn record_usage(event: UsageEvent) {
+    if !current_settings().usage_stats {
+        return;
+    }
+    let day = today();
+    use_usage_state()
+        .write()
+        .entry(day)
+        .or_insert_with(|| DailyUsage::new(day))
+        .add(&event);
+
+    spawn_forever(async move {
+        if let Err(e) = save_day(day).await {
+            log::warn!("Failed to save usage stats: {}", e);
+        }
+    });
+}
+
+/// Loads, clears and shares the local usage stats
+pub struct AnalyticsService;
+
+impl AnalyticsService {
+    /// Load saved days into memory, adding anything counted before they
+    /// arrived, and drop days older than `USAGE_STATS_RETENTION_DAYS`
+    pub async fn load() -> Result<(), StorageError> {
+        let storage = StorageService::open().await?;
+        let oldest = today() - Days::new(USAGE_STATS_RETENTION_DAYS);
+        let (kept, expired): (Vec<DailyUsage>, Vec<DailyUsage>) = storage
+            .get_all::<DailyUsage>(USAGE_STATS_STORE)
+            .await?
+            .into_iter()
+            .partition(|record| record.day >= oldest);
+        for record in expired {
+            storage.delete(USAGE_STATS_STORE, &record.day.to_string()).await?;
+        }
+
+        let counted_early: Vec<NaiveDate> = {
+            let mut state = use_usage_state().write();
+            let counted_early = state.keys().copied().collect();
+            for record in kept {
+                match state.get_mut(&record.day) {
+                    Some(counted) => counted.merge(&record),
+                    None => {
+                        state.insert(record.day, record);
+                    }
+                }
+            }
+            counted_early
+        };
+        LOADED.with(|loaded| loaded.set(true));
+
+        for day in counted_early {
+            save_day(day).await?;
+        }
+        Ok(())
+    }
+
+    /// Delete every counted day
+    pub async fn clear() -> Result<(), StorageError> {
+        StorageService::open().await?.clear(USAGE_STATS_STORE).await?;
+        use_usage_state().write().clear();
+        Ok(())
+    }
+
+    /// Publish `summary`'s anonymized totals, returning the DataItem ID
+    pub async fn share_totals(summary: &UsageSummary) -> Result<String, AnalyticsError> {
+        let totals = SharedTotals::from_summary(summary);
+        if totals.is_empty() {
+            return Err(AnalyticsError::NothingToShare);
+        }
+        let data = serde_json::to_vec(&totals).map_err(|e| AnalyticsError::SigningFailed(e.to_string()))?;
+
+        // A key for this item alone, thrown away once it is signed
+        let mut secret = [0u8; 32];
+        getrandom::getrandom(&mut secret).map_err(|e| AnalyticsError::SigningFailed(e.to_string()))?;
+        let signer = EthereumSigner::from_bytes(&secret).map_err(|e| AnalyticsError::SigningFailed(e.to_string()))?;
+        let arweave = ArweaveService::new_with_signer(signer);
+
+        let period = totals.period_days.to_string();
+        let tags = [
+            ("Content-Type", "application/json"),
+            ("Type", USAGE_TOTALS_TYPE),
+            ("Period-Days", period.as_str()),
+        ];
+        let item = arweave
+            .create_tagged_item(&tags, data)
+            .map_err(|e| AnalyticsError::SigningFailed(e.to_string()))?;
+        let bytes = arweave
+            .serialize_item(&item)
+            .map_err(|e| AnalyticsError::SigningFailed(e.to_string()))?;
+        let tx_id = BundlerClient::new().submit(bytes).await.map_err(AnalyticsError::SubmitFailed)?;
+
+        log::info!("📊 Shared usage totals for {} days in {}", totals.period_days, tx_id);
+        Ok(tx_id)
+    }
+}
+
+/// Load saved usage stats in the background
+pub fn init_usage_stats() {
+    spawn(async {
+        if let Err(e) = AnalyticsService::load().await {
+            log::warn!("Failed to load usage stats: {}", e);
+        }
+    });
+}
+
+/// Hook returning totals for the last `days` days, today included
+pub fn use_usage_summary(days: Signal<u64>) -> Memo<UsageSummary> {
+    use_memo(move || UsageSummary::from_days(use_usage_state().read().values(), today(), days()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, d).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn summaries_cover_only_the_requested_days() {
+        let mut early = DailyUsage::new(day(1));
+        early.add(&UsageEvent::Search);
+        let mut recent = DailyUsage::new(day(9));
+        recent.add(&UsageEvent::Play("a".to_string()));
+        recent.add(&UsageEvent::Play("b".to_string()));
+        recent.add(&UsageEvent::Play("b".to_string()));
+        recent.add(&UsageEvent::Upload(UploadStep::Opened));
+
+        let summary = UsageSummary::from_days([&early, &recent], day(10), 7);
+        assert_eq!(summary.plays, 3);
+        assert_eq!(summary.searches, 0);
+        assert_eq!(summary.upload_step(UploadStep::Opened), 1);
+        assert_eq!(summary.top_items, vec![("b".to_string(), 2), ("a".to_string(), 1)]);
+        assert_eq!(summary.daily_plays.len(), 7);
+        assert_eq!(summary.daily_plays[5], (day(9), 3));
+    }
+
+    #[wasm_bindgen_test]
+    fn shared_totals_are_bucketed_without_item_ids() {
+        let mut record = DailyUsage::new(day(1));
+        for _ in 0..12 {
+            record.add(&UsageEvent::Play("a".to_string()));
+        }
+        record.add(&UsageEvent::Search);
+        record.add(&UsageEvent::Upload(UploadStep::Opened));
+
+        let totals = SharedTotals::from_summary(&UsageSummary::from_days([&record], day(1), 30));
+        assert_eq!(totals.plays, 10);
+        assert_eq!(totals.searches, 0);
+        assert!(totals.upload_steps.is_empty());
+        assert!(!serde_json::to_string(&totals).unwrap().contains("\"a\""));
+    }
+}
@@ -0,0 +1,128 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::config::app_config;
+use crate::services::wallet::{connected_wallet, WalletError};
+
+#[derive(Debug, Clone)]
+pub enum AoError {
+    SigningFailed(WalletError),
+    SendFailed(String),
+    ReadFailed(String),
+}
+
+impl std::fmt::Display for AoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AoError::SigningFailed(e) => write!(f, "Could not sign the AO message: {}", e),
+            AoError::SendFailed(msg) => write!(f, "Could not send the AO message: {}", msg),
+            AoError::ReadFailed(msg) => write!(f, "Could not read the AO process: {}", msg),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct MessageReceipt {
+    id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DryRunResult {
+    #[serde(default)]
+    messages: Vec<DryRunMessage>,
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DryRunMessage {
+    #[serde(default)]
+    data: String,
+}
+
+/// Sign a message to `process_id` with the connected wallet and post it to
+/// the messenger unit, returning the message ID
+///
+/// The `Data-Protocol`, `Variant` and `Type` tags are added; `tags` holds
+/// the rest, usually starting with `Action`.
+pub async fn send_message(process_id: &str, tags: Vec<(String, String)>) -> Result<String, AoError> {
+    let mut all_tags = vec![
+        ("Data-Protocol".to_string(), "ao".to_string()),
+        ("Variant".to_string(), "ao.TN.1".to_string()),
+        ("Type".to_string(), "Message".to_string()),
+    ];
+    all_tags.extend(tags);
+
+    let signed = connected_wallet()
+        .await
+        .map_err(AoError::SigningFailed)?
+        .sign_data_item_to(process_id, Vec::new(), all_tags)
+        .await
+        .map_err(AoError::SigningFailed)?;
+
+    let response = reqwest::Client::new()
+        .post(&app_config().ao_mu_url)
+        .header("Content-Type", "application/octet-stream")
+        .body(signed)
+        .send()
+        .await
+        .map_err(|e| AoError::SendFailed(e.to_string()))?;
+    if !response.status().is_success() {
+        let status = response.status().as_u16();
+        let body = response.text().await.unwrap_or_default();
+        return Err(AoError::SendFailed(format!("HTTP {}: {}", status, body)));
+    }
+    let receipt: MessageReceipt = response
+        .json()
+        .await
+        .map_err(|e| AoError::SendFailed(e.to_string()))?;
+    Ok(receipt.id)
+}
+
+/// Ask `process_id` a question without sending a message, returning the
+/// `Data` of its first reply
+///
+/// The compute unit evaluates the message against the process's current
+/// state and throws the result away, so nothing is signed or stored.
+/// `tags` usually starts with `Action`.
+pub async fn dry_run(process_id: &str, tags: &[(&str, &str)]) -> Result<String, AoError> {
+    let tags: Vec<_> = tags
+        .iter()
+        .map(|(name, value)| json!({ "name": name, "value": value }))
+        .collect();
+    let body = json!({
+        "Id": "1234",
+        "Target": process_id,
+        "Owner": "1234",
+        "Anchor": "0",
+        "Data": "1234",
+        "Tags": tags,
+    });
+
+    let response = reqwest::Client::new()
+        .post(format!("{}/dry-run?process-id={}", app_config().ao_cu_url, process_id))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| AoError::ReadFailed(e.to_string()))?;
+    if !response.status().is_success() {
+        let status = response.status().as_u16();
+        let body = response.text().await.unwrap_or_default();
+        return Err(AoError::ReadFailed(format!("HTTP {}: {}", status, body)));
+    }
+    let result: DryRunResult = response
+        .json()
+        .await
+        .map_err(|e| AoError::ReadFailed(e.to_string()))?;
+    if let Some(error) = result.error {
+        return Err(AoError::ReadFailed(error.to_string()));
+    }
+    result
+        .messages
+        .into_iter()
+        .next()
+        .map(|message| message.data)
+        .ok_or_else(|| AoError::ReadFailed("The process did not reply".to_string()))
+}
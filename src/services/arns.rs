@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+use crate::models::{SERIES_MANIFEST_TYPE, SPIRITUAL_CONTENT_TYPE};
+use crate::services::graphql::GraphQLClient;
+use crate::services::http::HttpCache;
+use crate::services::auth::AuthError;
+use crate::services::token::authenticated_tags;
+use crate::services::ao::{send_message, AoError};
+use crate::services::wallet::WalletError;
+use crate::config::app_config;
+
+/// Default time gateways may cache a record set from the app (seconds)
+pub const DEFAULT_RECORD_TTL_SECS: u32 = 3600;
+
+#[derive(Debug, Clone)]
+pub enum ArnsError {
+    InvalidName(String),
+    NotFound(String),
+    LookupFailed(String),
+    AuthFailed(AuthError),
+    SigningFailed(WalletError),
+    SendFailed(String),
+}
+
+impl std::fmt::Display for ArnsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArnsError::InvalidName(msg) => write!(f, "{}", msg),
+            ArnsError::NotFound(name) => write!(f, "The ArNS name {} is not registered", name),
+            ArnsError::LookupFailed(msg) => write!(f, "Could not resolve ArNS name: {}", msg),
+            ArnsError::AuthFailed(e) => write!(f, "{}", e),
+            ArnsError::SigningFailed(e) => write!(f, "Could not sign the record update: {}", e),
+            ArnsError::SendFailed(msg) => write!(f, "Could not send the record update: {}", msg),
+        }
+    }
+}
+
+/// What an ArNS name currently points at, from an ar.io resolver
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArnsRecord {
+    #[serde(skip)]
+    pub name: String,
+    pub tx_id: String,
+    pub ttl_seconds: u32,
+    /// ANT process that owns the name and accepts record updates
+    pub process_id: String,
+}
+
+impl ArnsRecord {
+    /// Human-readable gateway link, e.g. `https://gracechurch.ar.io`
+    pub fn url(&self) -> String {
+        name_url(&self.name)
+    }
+}
+
+/// Where an ArNS name leads inside the app
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArnsTarget {
+    Item(String),
+    Series(String),
+    /// Anything else is opened on the gateway
+    External(String),
+}
+
+/// "GraceChurch.ar" -> "gracechurch"; undernames ("sermons_gracechurch")
+/// are kept whole
+pub fn normalize_name(name: &str) -> Result<String, ArnsError> {
+    let name = name.trim().to_lowercase();
+    let name = name
+        .strip_prefix("https://")
+        .unwrap_or(&name)
+        .trim_end_matches('/')
+        .trim_end_matches(&format!(".{}", app_config().arns_domain))
+        .trim_end_matches(".ar")
+        .to_string();
+
+    let valid = !name.is_empty()
+        && name.len() <= 51
+        && !name.starts_with(['-', '_'])
+        && !name.ends_with(['-', '_'])
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(name)
+    } else {
+        Err(ArnsError::InvalidName(
+            "ArNS names are 1 to 51 letters, numbers or dashes".to_string(),
+        ))
+    }
+}
+
+/// Human-readable gateway link for a name
+pub fn name_url(name: &str) -> String {
+    format!("https://{}.{}", name, app_config().arns_domain)
+}
+
+// Resolved records and when they expire (unix seconds)
+fn use_arns_cache() -> &'static GlobalSignal<HashMap<String, (ArnsRecord, i64)>> {
+    static ARNS_CACHE: GlobalSignal<HashMap<String, (ArnsRecord, i64)>> = GlobalSignal::new(HashMap::new);
+    &ARNS_CACHE
+}
+
+/// Resolves ArNS names through ar.io gateways and updates their records
+/// from the connected wallet
+pub struct ArnsService {
+    http: HttpCache,
+}
+
+impl ArnsService {
+    pub fn new() -> Self {
+        Self {
+            http: HttpCache::new(),
+        }
+    }
+
+    /// Current record of a name, cached for its TTL
+    pub async fn resolve(&self, name: &str) -> Result<ArnsRecord, ArnsError> {
+        let name = normalize_name(name)?;
+        let now = chrono::Utc::now().timestamp();
+        if let Some((record, expires)) = use_arns_cache().read().get(&name) {
+            if *expires > now {
+                return Ok(record.clone());
+            }
+        }
+
+        let response = self.http
+            .get(&format!("/ar-io/resolver/{}", name))
+            .await
+            .map_err(|e| ArnsError::LookupFailed(e.to_string()))?;
+        if response.status == 404 {
+            return Err(ArnsError::NotFound(name));
+        }
+        if !response.is_success() {
+            return Err(ArnsError::LookupFailed(format!("HTTP {}", response.status)));
+        }
+
+        let mut record: ArnsRecord = response
+            .json()
+            .map_err(|e| ArnsError::LookupFailed(e.to_string()))?;
+        record.name = name.clone();
+        use_arns_cache()
+            .write()
+            .insert(name, (record.clone(), now + record.ttl_seconds as i64));
+        Ok(record)
+    }
+
+    /// Resolve a name and work out which page it should open
+    pub async fn resolve_target(&self, name: &str) -> Result<ArnsTarget, ArnsError> {
+        let record = self.resolve(name).await?;
+        let details = GraphQLClient::cached()
+            .transaction_details(&record.tx_id)
+            .await
+            .map_err(|e| ArnsError::LookupFailed(e.to_string()))?;
+
+        let tags = details.map(|details| details.tag_pairs()).unwrap_or_default();
+        let tag = |name: &str| tags.iter().find(|(tag_name, _)| tag_name == name).map(|(_, value)| value.as_str());
+        Ok(match (tag("Type"), tag("Series-Id")) {
+            (Some(SPIRITUAL_CONTENT_TYPE), _) => ArnsTarget::Item(record.tx_id),
+            (Some(SERIES_MANIFEST_TYPE), Some(series_id)) => ArnsTarget::Series(series_id.to_string()),
+            _ => ArnsTarget::External(record.url()),
+        })
+    }
+
+    /// Point a name at `tx_id` by messaging its ANT process
+    ///
+    /// The process only accepts the update from the name's owner or a
+    /// controller it has approved. Gateways pick it up once the old
+    /// record's TTL runs out. The message carries an auth token addressed
+    /// to the process.
+    pub async fn set_record(&self, record: &ArnsRecord, tx_id: &str, ttl_seconds: u32) -> Result<String, ArnsError> {
+        let mut tags = vec![
+            ("Action".to_string(), "Set-Record".to_string()),
+            ("Sub-Domain".to_string(), "@".to_string()),
+            ("Transaction-Id".to_string(), tx_id.to_string()),
+            ("TTL-Seconds".to_string(), ttl_seconds.to_string()),
+        ];
+        tags.extend(
+            authenticated_tags(&record.process_id)
+                .await
+                .map_err(ArnsError::AuthFailed)?,
+        );
+        let message_id = send_message(&record.process_id, tags).await.map_err(|e| match e {
+            AoError::SigningFailed(e) => ArnsError::SigningFailed(e),
+            AoError::SendFailed(msg) | AoError::ReadFailed(msg) => ArnsError::SendFailed(msg),
+        })?;
+
+        use_arns_cache().write().remove(&record.name);
+        log::info!("🏷️ Pointed {} at {} (message {})", record.name, tx_id, message_id);
+        Ok(message_id)
+    }
+}
+
+impl Default for ArnsService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,8 +1,35 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use bundles_rs::{
     ans104::{data_item::DataItem, tags::Tag},
     crypto::ethereum::EthereumSigner,
 };
-use anyhow::Result;
+use sha2::{Digest, Sha256, Sha384};
+
+use crate::services::file_reader::{FileReadError, LocalFile};
+use crate::services::worker::{self, HashInput};
+use crate::utils::deep_hash::{encode_tags, DataItemParts};
+
+/// ANS-104 signature type for Arweave (RSA-PSS 4096) keys
+const ARWEAVE_SIGNATURE_TYPE: u16 = 1;
+/// Length of an Arweave signature and of its owner key (the modulus)
+const ARWEAVE_KEY_LENGTH: usize = 512;
+
+#[derive(Debug, Clone)]
+pub enum ArweaveError {
+    SignerUnavailable(String),
+    SigningFailed(String),
+    SerializationFailed(String),
+}
+
+impl std::fmt::Display for ArweaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArweaveError::SignerUnavailable(msg) => write!(f, "Could not create a signer: {}", msg),
+            ArweaveError::SigningFailed(msg) => write!(f, "Could not build and sign the DataItem: {}", msg),
+            ArweaveError::SerializationFailed(msg) => write!(f, "Could not serialize the DataItem: {}", msg),
+        }
+    }
+}
 
 /// Basic Arweave service using bundles-rs for DataItem creation
 pub struct ArweaveService {
@@ -11,8 +38,8 @@ pub struct ArweaveService {
 
 impl ArweaveService {
     /// Create a new ArweaveService with a random signer (for development)
-    pub fn new_random() -> Result<Self> {
-        let signer = EthereumSigner::random()?;
+    pub fn new_random() -> Result<Self, ArweaveError> {
+        let signer = EthereumSigner::random().map_err(|e| ArweaveError::SignerUnavailable(e.to_string()))?;
         Ok(Self { signer })
     }
 
@@ -29,7 +56,7 @@ impl ArweaveService {
         content_type: &str,
         description: Option<&str>,
         scripture_refs: Option<Vec<&str>>,
-    ) -> Result<DataItem> {
+    ) -> Result<DataItem, ArweaveError> {
         let mut tags = vec![
             Tag::new("Content-Type", content_type),
             Tag::new("App-Name", "Faithful-Archive"),
@@ -43,7 +70,7 @@ impl ArweaveService {
 
         if let Some(refs) = scripture_refs {
             for (i, scripture_ref) in refs.iter().enumerate() {
-                tags.push(Tag::new(&format!("Scripture-Ref-{}", i + 1), *scripture_ref));
+                tags.push(Tag::new(&format!("Scripture-Ref-{}", i + 1), scripture_ref));
             }
         }
 
@@ -52,7 +79,19 @@ impl ArweaveService {
         tags.push(Tag::new("Created-At", &timestamp));
 
         // Create and sign the DataItem
-        let item = DataItem::build_and_sign(&self.signer, None, None, tags, content)?;
+        let item = DataItem::build_and_sign(&self.signer, None, None, tags, content)
+            .map_err(|e| ArweaveError::SigningFailed(e.to_string()))?;
+
+        Ok(item)
+    }
+
+    /// Create a DataItem with arbitrary tags; `App-Name` is added automatically
+    pub fn create_tagged_item(&self, tags: &[(&str, &str)], data: Vec<u8>) -> Result<DataItem, ArweaveError> {
+        let mut all_tags = vec![Tag::new("App-Name", "Faithful-Archive")];
+        all_tags.extend(tags.iter().map(|(name, value)| Tag::new(name, value)));
+
+        let item = DataItem::build_and_sign(&self.signer, None, None, all_tags, data)
+            .map_err(|e| ArweaveError::SigningFailed(e.to_string()))?;
 
         Ok(item)
     }
@@ -63,7 +102,7 @@ impl ArweaveService {
     }
 
     /// Create a simple text DataItem for testing
-    pub fn create_test_item(&self, message: &str) -> Result<DataItem> {
+    pub fn create_test_item(&self, message: &str) -> Result<DataItem, ArweaveError> {
         let tags = vec![
             Tag::new("Content-Type", "text/plain"),
             Tag::new("App-Name", "Faithful-Archive"),
@@ -71,18 +110,136 @@ impl ArweaveService {
         ];
 
         let data = message.as_bytes().to_vec();
-        let item = DataItem::build_and_sign(&self.signer, None, None, tags, data)?;
+        let item = DataItem::build_and_sign(&self.signer, None, None, tags, data)
+            .map_err(|e| ArweaveError::SigningFailed(e.to_string()))?;
 
         Ok(item)
     }
 
     /// Serialize DataItem for upload
-    pub fn serialize_item(&self, item: &DataItem) -> Result<Vec<u8>> {
-        Ok(item.to_bytes()?)
+    pub fn serialize_item(&self, item: &DataItem) -> Result<Vec<u8>, ArweaveError> {
+        item.to_bytes().map_err(|e| ArweaveError::SerializationFailed(e.to_string()))
     }
 
     /// Get DataItem ID
     pub fn get_item_id(&self, item: &DataItem) -> String {
         item.arweave_id()
     }
-}
\ No newline at end of file
+}
+/// A DataItem whose data stays in a file on the uploader's device
+///
+/// Wallets' `signDataItem` takes the whole payload, so a 2 GB video would
+/// have to sit in memory just to be signed. Instead the ANS-104 deep hash
+/// is computed here a slice at a time, the wallet signs only that (see
+/// `WalletStrategy::sign_deep_hash`), and the bundler is sent the header
+/// followed by the file as one Blob. Only Arweave keys with no target or
+/// anchor are supported, which is all uploads use.
+pub struct StreamedDataItem {
+    parts: DataItemParts,
+    file: LocalFile,
+}
+
+impl StreamedDataItem {
+    /// `owner` is the signer's base64url public key, as
+    /// `WalletService::get_public_key` returns it
+    pub fn new(owner: &str, tags: Vec<(String, String)>, file: LocalFile) -> Result<Self, ArweaveError> {
+        let owner = URL_SAFE_NO_PAD
+            .decode(owner)
+            .map_err(|e| ArweaveError::SignerUnavailable(format!("public key is not base64url: {}", e)))?;
+        if owner.len() != ARWEAVE_KEY_LENGTH {
+            return Err(ArweaveError::SignerUnavailable(format!(
+                "public key is {} bytes, expected an Arweave key of {}",
+                owner.len(),
+                ARWEAVE_KEY_LENGTH
+            )));
+        }
+        let parts = DataItemParts { signature_type: ARWEAVE_SIGNATURE_TYPE, owner, target: None, anchor: None, tags };
+        Ok(Self { parts, file })
+    }
+
+    /// The message the wallet signs. The file is hashed in the data worker
+    /// when it fits, otherwise a slice at a time on the main thread.
+    pub async fn deep_hash(&self, mut on_progress: impl FnMut(f64)) -> Result<[u8; 48], FileReadError> {
+        if self.file.fits_worker() {
+            match worker::sha384(HashInput::Blob(self.file.blob())).await {
+                Ok(digest) => {
+                    on_progress(1.0);
+                    return Ok(self.parts.deep_hash(self.file.size(), &digest));
+                }
+                Err(e) => log::warn!("Hashing {} on the main thread instead: {}", self.file.name(), e),
+            }
+        }
+
+        let mut data = Sha384::new();
+        self.file
+            .stream(|slice, fraction| {
+                data.update(slice);
+                on_progress(fraction);
+            })
+            .await?;
+
+        Ok(self.parts.deep_hash(self.file.size(), &data.finalize()))
+    }
+
+    /// Everything before the data: signature, owner and tags
+    pub fn header(&self, signature: &[u8]) -> Result<Vec<u8>, ArweaveError> {
+        if signature.len() != ARWEAVE_KEY_LENGTH {
+            return Err(ArweaveError::SerializationFailed(format!(
+                "signature is {} bytes, expected {}",
+                signature.len(),
+                ARWEAVE_KEY_LENGTH
+            )));
+        }
+        let tags = encode_tags(&self.parts.tags);
+        let mut header = Vec::with_capacity(2 + 2 * ARWEAVE_KEY_LENGTH + 2 + 16 + tags.len());
+        header.extend_from_slice(&ARWEAVE_SIGNATURE_TYPE.to_le_bytes());
+        header.extend_from_slice(signature);
+        header.extend_from_slice(&self.parts.owner);
+        // No target, no anchor
+        header.extend_from_slice(&[0, 0]);
+        header.extend_from_slice(&(self.parts.tags.len() as u64).to_le_bytes());
+        header.extend_from_slice(&(tags.len() as u64).to_le_bytes());
+        header.extend_from_slice(&tags);
+        Ok(header)
+    }
+
+    /// The signed item, the header followed by the file, for posting
+    pub fn to_blob(&self, signature: &[u8]) -> Result<web_sys::Blob, ArweaveError> {
+        let parts = js_sys::Array::new();
+        parts.push(&js_sys::Uint8Array::from(self.header(signature)?.as_slice()));
+        parts.push(self.file.blob());
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type("application/octet-stream");
+        web_sys::Blob::new_with_blob_sequence_and_options(&parts, &options)
+            .map_err(|e| ArweaveError::SerializationFailed(format!("{:?}", e)))
+    }
+}
+
+/// A DataItem's ID, derived from its signature
+pub fn data_item_id(signature: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(signature))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::constants::FILE_READ_CHUNK_SIZE;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn streamed_deep_hash_matches_hashing_in_memory() {
+        let data: Vec<u8> = (0..FILE_READ_CHUNK_SIZE as usize + 11).map(|i| (i * 7) as u8).collect();
+        let file = LocalFile::from_bytes("sermon.mp4", &data, "video/mp4").unwrap();
+        let owner = URL_SAFE_NO_PAD.encode([9u8; ARWEAVE_KEY_LENGTH]);
+        let tags = vec![("Content-Type".to_string(), "video/mp4".to_string())];
+        let item = StreamedDataItem::new(&owner, tags.clone(), file).unwrap();
+
+        let expected = item.parts.deep_hash(data.len() as u64, &Sha384::digest(&data));
+        assert_eq!(item.deep_hash(|_| {}).await.unwrap(), expected);
+
+        let header = item.header(&[7u8; ARWEAVE_KEY_LENGTH]).unwrap();
+        assert_eq!(&header[..2], &[1, 0]);
+        assert_eq!(header.len(), 2 + 2 * ARWEAVE_KEY_LENGTH + 2 + 16 + encode_tags(&tags).len());
+        assert!(item.header(&[7u8; 65]).is_err());
+    }
+}
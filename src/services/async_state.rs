@@ -0,0 +1,70 @@
+use std::fmt::Display;
+use std::future::Future;
+
+use dioxus::prelude::*;
+
+/// Where a fetch for page data stands
+#[derive(Debug, Clone, PartialEq)]
+pub enum AsyncState<T> {
+    Loading,
+    Ready(T),
+    Failed(String),
+}
+
+/// Data fetched by `use_async_resource`
+pub struct AsyncResource<T: 'static> {
+    resource: Resource<Result<T, String>>,
+}
+
+impl<T> Clone for AsyncResource<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for AsyncResource<T> {}
+
+impl<T: Clone> AsyncResource<T> {
+    /// Current state; `Loading` again while a re-fetch is in flight
+    pub fn state(&self) -> AsyncState<T> {
+        if *self.resource.state().read() == UseResourceState::Pending {
+            return AsyncState::Loading;
+        }
+        match &*self.resource.read() {
+            None => AsyncState::Loading,
+            Some(Ok(value)) => AsyncState::Ready(value.clone()),
+            Some(Err(message)) => AsyncState::Failed(message.clone()),
+        }
+    }
+
+    /// The value, once loaded
+    pub fn ready(&self) -> Option<T> {
+        match self.state() {
+            AsyncState::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Fetch again
+    pub fn restart(&mut self) {
+        self.resource.restart();
+    }
+}
+
+/// Hook running `fetch` on mount and again whenever a signal it reads
+/// changes
+///
+/// Wrap it in `use_reactive!` to re-fetch when props change. Pages match on
+/// `state()` to show a skeleton while loading and the error if it fails.
+pub fn use_async_resource<T, E, F>(mut fetch: impl FnMut() -> F + 'static) -> AsyncResource<T>
+where
+    T: 'static,
+    E: Display,
+    F: Future<Output = Result<T, E>> + 'static,
+{
+    let resource = use_resource(move || {
+        let pending = fetch();
+        async move { pending.await.map_err(|e| e.to_string()) }
+    });
+    AsyncResource { resource }
+}
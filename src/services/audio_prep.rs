@@ -0,0 +1,124 @@
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::services::content_fetch::ContentFetchService;
+use crate::services::file_reader::LocalFile;
+use crate::utils::audio::{audible_bounds, encode_wav, wav_size};
+
+#[derive(Debug, Clone)]
+pub enum AudioPrepError {
+    Unsupported,
+    DecodeFailed(String),
+}
+
+impl std::fmt::Display for AudioPrepError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AudioPrepError::Unsupported => write!(f, "This browser cannot decode audio"),
+            AudioPrepError::DecodeFailed(msg) => write!(f, "Could not decode audio: {}", msg),
+        }
+    }
+}
+
+/// Uncompressed audio decoded with the Web Audio API
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedAudio {
+    /// Planar samples, one `Vec` per channel
+    pub channels: Vec<Vec<f32>>,
+    pub sample_rate: f32,
+}
+
+impl DecodedAudio {
+    /// Decode any format the browser can play
+    pub async fn decode(bytes: &[u8]) -> Result<Self, AudioPrepError> {
+        let context = web_sys::AudioContext::new().map_err(|_| AudioPrepError::Unsupported)?;
+        let buffer = js_sys::Uint8Array::from(bytes).buffer();
+        let promise = context
+            .decode_audio_data(&buffer)
+            .map_err(|e| AudioPrepError::DecodeFailed(format!("{:?}", e)))?;
+        let decoded: web_sys::AudioBuffer = JsFuture::from(promise)
+            .await
+            .map_err(|e| AudioPrepError::DecodeFailed(format!("{:?}", e)))?
+            .dyn_into()
+            .map_err(|_| AudioPrepError::DecodeFailed("unexpected decoder result".to_string()))?;
+        let _ = context.close();
+
+        let channels = (0..decoded.number_of_channels())
+            .map(|channel| decoded.get_channel_data(channel).unwrap_or_default())
+            .collect();
+        Ok(Self {
+            channels,
+            sample_rate: decoded.sample_rate(),
+        })
+    }
+
+    pub fn duration_secs(&self) -> f32 {
+        self.frames() as f32 / self.sample_rate
+    }
+
+    fn frames(&self) -> usize {
+        self.channels.first().map_or(0, Vec::len)
+    }
+
+    /// First channel, used for analysis and the waveform
+    pub fn mono(&self) -> &[f32] {
+        self.channels.first().map_or(&[], Vec::as_slice)
+    }
+
+    /// Suggested trim range that drops leading and trailing silence
+    pub fn audible_range(&self) -> (f32, f32) {
+        audible_bounds(self.mono(), self.sample_rate).unwrap_or((0.0, self.duration_secs()))
+    }
+
+    fn frame_range(&self, start_secs: f32, end_secs: f32) -> std::ops::Range<usize> {
+        let to_frame = |secs: f32| ((secs.max(0.0) * self.sample_rate) as usize).min(self.frames());
+        let (start, end) = (to_frame(start_secs), to_frame(end_secs));
+        start..end.max(start)
+    }
+
+    /// Size of the WAV that `trim` would produce
+    pub fn trimmed_size(&self, start_secs: f32, end_secs: f32) -> usize {
+        wav_size(self.frame_range(start_secs, end_secs).len(), self.channels.len())
+    }
+
+    /// Re-encode the range as 16-bit PCM WAV
+    pub fn trim(&self, start_secs: f32, end_secs: f32) -> Vec<u8> {
+        let range = self.frame_range(start_secs, end_secs);
+        let channels: Vec<Vec<f32>> = self.channels.iter().map(|c| c[range.clone()].to_vec()).collect();
+        encode_wav(&channels, self.sample_rate as u32)
+    }
+}
+
+/// Running time of audio or video in whole seconds
+///
+/// A detached media element loads only the file's metadata straight from
+/// the file, so this is quick even for long recordings and works for
+/// video, which `decode` can't read. `None` when the browser can't tell,
+/// such as for an unsupported format or a live stream.
+pub async fn media_duration(file: &LocalFile, content_type: &str) -> Option<u32> {
+    let tag = if content_type.starts_with("video/") { "video" } else { "audio" };
+    let media: web_sys::HtmlMediaElement = web_sys::window()?
+        .document()?
+        .create_element(tag)
+        .ok()?
+        .dyn_into()
+        .ok()?;
+    let url = web_sys::Url::create_object_url_with_blob(file.blob()).ok()?;
+
+    let loaded = js_sys::Promise::new(&mut |resolve, reject| {
+        media.set_onloadedmetadata(Some(&resolve));
+        media.set_onerror(Some(&reject));
+    });
+    media.set_preload("metadata");
+    media.set_src(&url);
+    let result = JsFuture::from(loaded).await;
+    let duration = media.duration();
+
+    media.set_onloadedmetadata(None);
+    media.set_onerror(None);
+    let _ = media.remove_attribute("src");
+    ContentFetchService::revoke_object_url(&url);
+
+    result.ok()?;
+    (duration.is_finite() && duration > 0.0).then(|| duration.round() as u32)
+}
@@ -0,0 +1,215 @@
+//! Proving control of an address
+//!
+//! A service that wants to know who is calling, such as the moderation AO
+//! process, can't trust an address the app merely states. Instead the
+//! wallet signs a short challenge naming the address, the service it is
+//! for and a fresh nonce. The service rebuilds `LoginChallenge::message`,
+//! verifies the signature against the public key, and checks the key
+//! hashes to the address (see `owner_address`).
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::services::wallet::{connected_wallet, WalletError};
+
+/// Bumped whenever the challenge text changes
+pub const CHALLENGE_VERSION: &str = "1";
+
+#[derive(Debug, Clone)]
+pub enum AuthError {
+    NotConnected,
+    Wallet(WalletError),
+    /// The wallet's public key doesn't hash to the address it reported
+    KeyMismatch(String),
+}
+
+impl std::fmt::Display for AuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthError::NotConnected => write!(f, "Connect a wallet to sign in"),
+            AuthError::Wallet(e) => write!(f, "Could not sign the login challenge: {}", e),
+            AuthError::KeyMismatch(address) => write!(f, "The wallet's public key doesn't belong to {}", address),
+        }
+    }
+}
+
+/// What the wallet is asked to sign
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoginChallenge {
+    /// Host the app runs on, so a signature can't be reused by another site
+    pub domain: String,
+    pub address: String,
+    /// Service the proof is for, e.g. "moderation"
+    pub audience: String,
+    pub nonce: String,
+    /// Unix seconds
+    pub issued_at: i64,
+    /// Unix seconds after which verifiers should refuse the proof
+    #[serde(default)]
+    pub expires_at: Option<i64>,
+}
+
+impl LoginChallenge {
+    pub fn new(domain: &str, address: &str, audience: &str) -> Self {
+        Self {
+            domain: domain.to_string(),
+            address: address.to_string(),
+            audience: audience.to_string(),
+            nonce: uuid::Uuid::new_v4().simple().to_string(),
+            issued_at: chrono::Utc::now().timestamp(),
+            expires_at: None,
+        }
+    }
+
+    /// Text the wallet signs; verifiers rebuild it from the fields
+    pub fn message(&self) -> String {
+        let mut message = format!(
+            "{} wants you to prove you control this Arweave address:\n{}\n\nAudience: {}\nNonce: {}\nIssued At: {}",
+            self.domain, self.address, self.audience, self.nonce, self.issued_at,
+        );
+        if let Some(expires_at) = self.expires_at {
+            message.push_str(&format!("\nExpires At: {}", expires_at));
+        }
+        message.push_str(&format!("\nVersion: {}", CHALLENGE_VERSION));
+        message
+    }
+}
+
+/// A challenge with the wallet's signature over its message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignedChallenge {
+    pub challenge: LoginChallenge,
+    /// Public key of `challenge.address`, base64url
+    pub public_key: String,
+    /// Signature over `challenge.message()`, base64url
+    pub signature: String,
+}
+
+impl SignedChallenge {
+    /// Tags carrying the proof on an AO message or DataItem
+    pub fn to_tags(&self) -> Vec<(String, String)> {
+        let mut tags = vec![
+            ("Auth-Version".to_string(), CHALLENGE_VERSION.to_string()),
+            ("Auth-Domain".to_string(), self.challenge.domain.clone()),
+            ("Auth-Address".to_string(), self.challenge.address.clone()),
+            ("Auth-Audience".to_string(), self.challenge.audience.clone()),
+            ("Auth-Nonce".to_string(), self.challenge.nonce.clone()),
+            ("Auth-Issued-At".to_string(), self.challenge.issued_at.to_string()),
+            ("Auth-Public-Key".to_string(), self.public_key.clone()),
+            ("Auth-Signature".to_string(), self.signature.clone()),
+        ];
+        if let Some(expires_at) = self.challenge.expires_at {
+            tags.push(("Auth-Expires-At".to_string(), expires_at.to_string()));
+        }
+        tags
+    }
+}
+
+/// Arweave address belonging to a base64url public key: the base64url
+/// SHA-256 of the key's bytes
+pub fn owner_address(public_key: &str) -> Option<String> {
+    let key = URL_SAFE_NO_PAD.decode(public_key).ok()?;
+    Some(URL_SAFE_NO_PAD.encode(Sha256::digest(&key)))
+}
+
+/// Check that `public_key` is the key of `address`
+pub fn check_key(address: &str, public_key: &str) -> Result<(), AuthError> {
+    if owner_address(public_key).as_deref() == Some(address) {
+        Ok(())
+    } else {
+        Err(AuthError::KeyMismatch(address.to_string()))
+    }
+}
+
+fn current_domain() -> String {
+    web_sys::window()
+        .and_then(|window| window.location().host().ok())
+        .unwrap_or_default()
+}
+
+/// Have the connected wallet sign a fresh challenge for `audience`
+pub async fn sign_login_challenge(audience: &str) -> Result<SignedChallenge, AuthError> {
+    sign_challenge(audience, None).await
+}
+
+/// `sign_login_challenge` with an expiry, for proofs that get reused
+pub async fn sign_challenge(audience: &str, expires_at: Option<i64>) -> Result<SignedChallenge, AuthError> {
+    let wallet = connected_wallet().await.map_err(AuthError::Wallet)?;
+    let address = wallet.get_active_address().await.map_err(|_| AuthError::NotConnected)?;
+    let public_key = wallet.get_public_key().await.map_err(AuthError::Wallet)?;
+    // Verifiers would reject the proof anyway; fail before asking to sign
+    check_key(&address, &public_key)?;
+
+    let challenge = LoginChallenge {
+        expires_at,
+        ..LoginChallenge::new(&current_domain(), &address, audience)
+    };
+    let signature = wallet
+        .sign_message(challenge.message().as_bytes())
+        .await
+        .map_err(AuthError::Wallet)?;
+    log::info!("🔏 Signed {} login challenge for {}", audience, address);
+
+    Ok(SignedChallenge {
+        challenge,
+        public_key,
+        signature: URL_SAFE_NO_PAD.encode(signature),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn challenge() -> LoginChallenge {
+        LoginChallenge {
+            domain: "faithfularchive.org".to_string(),
+            address: "vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JI".to_string(),
+            audience: "moderation".to_string(),
+            nonce: "0123456789abcdef".to_string(),
+            issued_at: 1_700_000_000,
+            expires_at: None,
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn message_is_stable() {
+        assert_eq!(
+            challenge().message(),
+            "faithfularchive.org wants you to prove you control this Arweave address:\n\
+             vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JI\n\n\
+             Audience: moderation\nNonce: 0123456789abcdef\nIssued At: 1700000000\nVersion: 1"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn expiry_is_part_of_the_message() {
+        let challenge = LoginChallenge { expires_at: Some(1_700_000_600), ..challenge() };
+        assert!(challenge.message().ends_with("Issued At: 1700000000\nExpires At: 1700000600\nVersion: 1"));
+    }
+
+    #[wasm_bindgen_test]
+    fn nonces_are_fresh() {
+        let first = LoginChallenge::new("example.org", "address", "moderation");
+        let second = LoginChallenge::new("example.org", "address", "moderation");
+        assert_ne!(first.nonce, second.nonce);
+    }
+
+    #[wasm_bindgen_test]
+    fn owner_address_hashes_the_key() {
+        assert_eq!(owner_address("AQID").as_deref(), Some("A5BYxvLAy0ksUzsKTRTvd8wPeKvMztUofYShogEc-4E"));
+        assert_eq!(owner_address("not base64!"), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn keys_must_belong_to_the_address() {
+        assert!(check_key("A5BYxvLAy0ksUzsKTRTvd8wPeKvMztUofYShogEc-4E", "AQID").is_ok());
+        assert!(matches!(
+            check_key("vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JI", "AQID"),
+            Err(AuthError::KeyMismatch(_))
+        ));
+        assert!(check_key("A5BYxvLAy0ksUzsKTRTvd8wPeKvMztUofYShogEc-4E", "not base64!").is_err());
+    }
+}
@@ -0,0 +1,221 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::ContentMetadata;
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, GraphQLError};
+use crate::services::storage::{StorageError, StorageService, BOOKMARKS_STORE};
+use crate::services::upload::{publish_with_wallet, PublishError};
+use crate::services::wallet::{WalletError, WalletService};
+
+/// `Type` tag of bookmark backup DataItems
+const BOOKMARKS_BACKUP_TYPE: &str = "Bookmarks-Backup";
+
+/// Which list a bookmark belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BookmarkKind {
+    Favorite,
+    WatchLater,
+}
+
+impl BookmarkKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            BookmarkKind::Favorite => "Favorites",
+            BookmarkKind::WatchLater => "Watch Later",
+        }
+    }
+
+    fn key_prefix(&self) -> &'static str {
+        match self {
+            BookmarkKind::Favorite => "favorite",
+            BookmarkKind::WatchLater => "watch_later",
+        }
+    }
+}
+
+/// A saved item, with enough metadata to list it offline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub kind: BookmarkKind,
+    pub item: ContentMetadata,
+    pub saved_at: i64,
+}
+
+impl Bookmark {
+    fn key(&self) -> String {
+        bookmark_key(self.kind, &self.item.tx_id)
+    }
+}
+
+fn bookmark_key(kind: BookmarkKind, tx_id: &str) -> String {
+    format!("{}:{}", kind.key_prefix(), tx_id)
+}
+
+#[derive(Debug, Clone)]
+pub enum BookmarkError {
+    Storage(StorageError),
+    NotConnected(WalletError),
+    LookupFailed(GraphQLError),
+    NoBackup,
+    InvalidBackup(String),
+    PublishFailed(PublishError),
+}
+
+impl std::fmt::Display for BookmarkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BookmarkError::Storage(e) => write!(f, "{}", e),
+            BookmarkError::NotConnected(e) => write!(f, "Connect your wallet first: {}", e),
+            BookmarkError::LookupFailed(e) => write!(f, "Could not look up backups: {}", e),
+            BookmarkError::NoBackup => write!(f, "No bookmark backup found for this wallet"),
+            BookmarkError::InvalidBackup(msg) => write!(f, "Bookmark backup is invalid: {}", msg),
+            BookmarkError::PublishFailed(e) => write!(f, "Could not publish backup: {}", e),
+        }
+    }
+}
+
+impl From<StorageError> for BookmarkError {
+    fn from(error: StorageError) -> Self {
+        BookmarkError::Storage(error)
+    }
+}
+
+// Global bookmarks using Dioxus signals, keyed like the IndexedDB store
+fn use_bookmarks_state() -> &'static GlobalSignal<HashMap<String, Bookmark>> {
+    static BOOKMARKS: GlobalSignal<HashMap<String, Bookmark>> = GlobalSignal::new(HashMap::new);
+    &BOOKMARKS
+}
+
+/// Favorites and watch-later lists kept in IndexedDB
+pub struct BookmarkService;
+
+impl BookmarkService {
+    /// Load saved bookmarks into memory
+    pub async fn load() -> Result<(), BookmarkError> {
+        let storage = StorageService::open().await?;
+        let bookmarks = storage.get_all::<Bookmark>(BOOKMARKS_STORE).await?;
+        let mut state = use_bookmarks_state().write();
+        for bookmark in bookmarks {
+            state.insert(bookmark.key(), bookmark);
+        }
+        log::info!("🔖 Loaded {} bookmarks", state.len());
+        Ok(())
+    }
+
+    /// Add the item to a list, or remove it if already there. Returns
+    /// whether the item is now saved.
+    pub async fn toggle(item: &ContentMetadata, kind: BookmarkKind) -> Result<bool, BookmarkError> {
+        let storage = StorageService::open().await?;
+        let key = bookmark_key(kind, &item.tx_id);
+
+        if use_bookmarks_state().read().contains_key(&key) {
+            storage.delete(BOOKMARKS_STORE, &key).await?;
+            use_bookmarks_state().write().remove(&key);
+            return Ok(false);
+        }
+
+        let bookmark = Bookmark {
+            kind,
+            item: item.clone(),
+            saved_at: chrono::Utc::now().timestamp(),
+        };
+        storage.put(BOOKMARKS_STORE, &key, &bookmark).await?;
+        use_bookmarks_state().write().insert(key, bookmark);
+        Ok(true)
+    }
+
+    /// Publish every bookmark as a DataItem signed by the connected wallet
+    ///
+    /// Backups are public like any Arweave data, so they hold only item
+    /// metadata that is already public.
+    pub async fn backup() -> Result<String, BookmarkError> {
+        let bookmarks: Vec<Bookmark> = use_bookmarks_state().read().values().cloned().collect();
+        let data = serde_json::to_vec(&bookmarks)
+            .map_err(|e| BookmarkError::InvalidBackup(e.to_string()))?;
+        let tags = vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Type".to_string(), BOOKMARKS_BACKUP_TYPE.to_string()),
+        ];
+
+        let (_, tx_id) = publish_with_wallet(data, tags)
+            .await
+            .map_err(BookmarkError::PublishFailed)?;
+        log::info!("🔖 Backed up {} bookmarks to {}", bookmarks.len(), tx_id);
+        Ok(tx_id)
+    }
+
+    /// Merge the connected wallet's latest backup into local bookmarks,
+    /// returning how many were added
+    pub async fn restore() -> Result<usize, BookmarkError> {
+        let owner = WalletService::init()
+            .await
+            .get_active_address()
+            .await
+            .map_err(BookmarkError::NotConnected)?;
+
+        let backups = GraphQLClient::new()
+            .find_transactions(&[owner.as_str()], &[("Type", &[BOOKMARKS_BACKUP_TYPE])])
+            .await
+            .map_err(BookmarkError::LookupFailed)?;
+        let latest = backups
+            .into_iter()
+            .max_by_key(|tx| tx.timestamp.unwrap_or(i64::MAX))
+            .ok_or(BookmarkError::NoBackup)?;
+
+        let response = GatewayManager::new()
+            .get(&format!("/{}", latest.id))
+            .await
+            .map_err(|e| BookmarkError::InvalidBackup(e.to_string()))?;
+        let bookmarks: Vec<Bookmark> = response
+            .json()
+            .await
+            .map_err(|e| BookmarkError::InvalidBackup(e.to_string()))?;
+
+        let storage = StorageService::open().await?;
+        let mut added = 0;
+        for bookmark in bookmarks {
+            let key = bookmark.key();
+            if use_bookmarks_state().read().contains_key(&key) {
+                continue;
+            }
+            storage.put(BOOKMARKS_STORE, &key, &bookmark).await?;
+            use_bookmarks_state().write().insert(key, bookmark);
+            added += 1;
+        }
+
+        log::info!("🔖 Restored {} bookmarks from {}", added, latest.id);
+        Ok(added)
+    }
+}
+
+/// Load bookmarks in the background
+pub fn init_bookmarks() {
+    spawn(async {
+        if let Err(e) = BookmarkService::load().await {
+            log::warn!("Failed to load bookmarks: {}", e);
+        }
+    });
+}
+
+/// Items in any bookmark list, from the in-memory state
+pub fn saved_items() -> Vec<ContentMetadata> {
+    use_bookmarks_state().read().values().map(|bookmark| bookmark.item.clone()).collect()
+}
+
+/// Hook returning all bookmarks, newest first
+pub fn use_bookmarks() -> Memo<Vec<Bookmark>> {
+    use_memo(move || {
+        let mut bookmarks: Vec<Bookmark> = use_bookmarks_state().read().values().cloned().collect();
+        bookmarks.sort_by_key(|bookmark| std::cmp::Reverse(bookmark.saved_at));
+        bookmarks
+    })
+}
+
+/// Hook returning whether an item is in a list
+pub fn use_is_bookmarked(tx_id: String, kind: BookmarkKind) -> Memo<bool> {
+    use_memo(use_reactive!(|tx_id| {
+        use_bookmarks_state().read().contains_key(&bookmark_key(kind, &tx_id))
+    }))
+}
@@ -0,0 +1,414 @@
+//! Bulk import of existing archives
+//!
+//! Ministries often already keep their sermon details in a spreadsheet.
+//! A manifest of those details, as CSV or as a JSON array of objects, is
+//! matched up with a folder of the files it describes: each column is
+//! mapped to a metadata field, every row is checked, and the rows that
+//! pass are handed to the upload queue as one batch.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::models::UdlTerms;
+use crate::services::series::SeriesSummary;
+use crate::services::transfer::TransferControl;
+use crate::services::upload::{content_type_for, PublishedParts, SeriesChoice, UploadMethod, UploadRequest};
+use crate::utils::language::normalize_language_tag;
+
+/// Longest title the upload form accepts
+const MAX_TITLE_LENGTH: usize = 200;
+
+/// Metadata a manifest column can fill
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ImportField {
+    File,
+    Title,
+    Description,
+    Speaker,
+    Ministry,
+    ScriptureRefs,
+    Language,
+    Series,
+}
+
+impl ImportField {
+    pub const ALL: [ImportField; 8] = [
+        ImportField::File,
+        ImportField::Title,
+        ImportField::Description,
+        ImportField::Speaker,
+        ImportField::Ministry,
+        ImportField::ScriptureRefs,
+        ImportField::Language,
+        ImportField::Series,
+    ];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            ImportField::File => "file",
+            ImportField::Title => "title",
+            ImportField::Description => "description",
+            ImportField::Speaker => "speaker",
+            ImportField::Ministry => "ministry",
+            ImportField::ScriptureRefs => "scripture_refs",
+            ImportField::Language => "language",
+            ImportField::Series => "series",
+        }
+    }
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            ImportField::File => "File name",
+            ImportField::Title => "Title",
+            ImportField::Description => "Description",
+            ImportField::Speaker => "Speaker",
+            ImportField::Ministry => "Ministry",
+            ImportField::ScriptureRefs => "Scripture references",
+            ImportField::Language => "Language",
+            ImportField::Series => "Series",
+        }
+    }
+
+    pub fn is_required(self) -> bool {
+        matches!(self, ImportField::File | ImportField::Title)
+    }
+
+    /// The field a column header most likely means, e.g. "Preacher" is
+    /// the speaker
+    pub fn guess(header: &str) -> Option<Self> {
+        let normalized: String = header.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+        let field = match normalized.as_str() {
+            "file" | "filename" | "path" | "filepath" | "media" | "audio" | "video" => ImportField::File,
+            "title" | "name" | "sermon" | "sermontitle" => ImportField::Title,
+            "description" | "summary" | "notes" | "abstract" => ImportField::Description,
+            "speaker" | "preacher" | "pastor" | "author" => ImportField::Speaker,
+            "ministry" | "church" | "congregation" | "organization" => ImportField::Ministry,
+            "scripture" | "scriptures" | "scripturerefs" | "scripturereferences" | "passage" | "passages" | "bible" => {
+                ImportField::ScriptureRefs
+            }
+            "language" | "lang" => ImportField::Language,
+            "series" | "seriestitle" => ImportField::Series,
+            _ => return None,
+        };
+        Some(field)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportError {
+    Csv(String),
+    Json(String),
+    Empty,
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::Csv(msg) => write!(f, "Could not read the CSV: {}", msg),
+            ImportError::Json(msg) => write!(f, "Could not read the JSON: {}", msg),
+            ImportError::Empty => write!(f, "The manifest has no rows"),
+        }
+    }
+}
+
+/// Column index for each mapped field
+pub type ColumnMapping = BTreeMap<ImportField, usize>;
+
+/// A manifest as a table of strings
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportManifest {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl ImportManifest {
+    /// Parse `text` as JSON if `filename` ends in `.json`, else as CSV
+    pub fn parse(filename: &str, text: &str) -> Result<Self, ImportError> {
+        let manifest = if filename.to_lowercase().ends_with(".json") {
+            Self::from_json(text)?
+        } else {
+            Self::from_csv(text)?
+        };
+        if manifest.rows.is_empty() {
+            return Err(ImportError::Empty);
+        }
+        Ok(manifest)
+    }
+
+    /// CSV with a header row; fields may be quoted, with `""` for a quote
+    pub fn from_csv(text: &str) -> Result<Self, ImportError> {
+        let mut records = parse_csv(text.trim_start_matches('\u{feff}'))?.into_iter();
+        let headers: Vec<String> = records
+            .next()
+            .ok_or(ImportError::Empty)?
+            .into_iter()
+            .map(|header| header.trim().to_string())
+            .collect();
+        let rows = records
+            .filter(|record| record.iter().any(|value| !value.trim().is_empty()))
+            .map(|mut record| {
+                record.resize(headers.len(), String::new());
+                record
+            })
+            .collect();
+        Ok(Self { headers, rows })
+    }
+
+    /// An array of objects; lists, such as scripture references, are
+    /// joined with `;`
+    pub fn from_json(text: &str) -> Result<Self, ImportError> {
+        let objects: Vec<serde_json::Map<String, serde_json::Value>> =
+            serde_json::from_str(text).map_err(|e| ImportError::Json(e.to_string()))?;
+
+        let mut headers: Vec<String> = Vec::new();
+        for key in objects.iter().flat_map(|object| object.keys()) {
+            if !headers.contains(key) {
+                headers.push(key.clone());
+            }
+        }
+        let rows = objects
+            .iter()
+            .map(|object| {
+                headers
+                    .iter()
+                    .map(|header| object.get(header).map(json_text).unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+        Ok(Self { headers, rows })
+    }
+
+    /// Map each column whose header is recognised, first column wins
+    pub fn guess_mapping(&self) -> ColumnMapping {
+        let mut mapping = ColumnMapping::new();
+        for (column, header) in self.headers.iter().enumerate() {
+            if let Some(field) = ImportField::guess(header) {
+                mapping.entry(field).or_insert(column);
+            }
+        }
+        mapping
+    }
+
+    /// Rows with their values picked out by `mapping`
+    pub fn import_rows(&self, mapping: &ColumnMapping) -> Vec<ImportRow> {
+        self.rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let value = |field| {
+                    mapping
+                        .get(&field)
+                        .and_then(|&column| row.get(column))
+                        .map(|value| value.trim().to_string())
+                        .unwrap_or_default()
+                };
+                ImportRow {
+                    number: i + 1,
+                    file: value(ImportField::File),
+                    title: value(ImportField::Title),
+                    description: value(ImportField::Description),
+                    speaker: value(ImportField::Speaker),
+                    ministry: value(ImportField::Ministry),
+                    scripture_refs: value(ImportField::ScriptureRefs),
+                    language: value(ImportField::Language),
+                    series: value(ImportField::Series),
+                }
+            })
+            .collect()
+    }
+}
+
+fn json_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Array(values) => values.iter().map(json_text).collect::<Vec<_>>().join("; "),
+        other => other.to_string(),
+    }
+}
+
+fn parse_csv(text: &str) -> Result<Vec<Vec<String>>, ImportError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n' | '\r', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(ImportError::Csv("a quoted field is never closed".to_string()));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// One manifest row's metadata
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportRow {
+    /// 1-based position in the manifest
+    pub number: usize,
+    pub file: String,
+    pub title: String,
+    pub description: String,
+    pub speaker: String,
+    pub ministry: String,
+    /// `;`-separated, as in the upload form
+    pub scripture_refs: String,
+    pub language: String,
+    /// Title of an existing series to add the item to
+    pub series: String,
+}
+
+impl ImportRow {
+    /// The file name without any folders, as the browser reports it
+    pub fn file_name(&self) -> &str {
+        self.file.rsplit(['/', '\\']).next().unwrap_or_default()
+    }
+
+    /// Upload request without the file, which the queue opens
+    pub fn to_request(&self, series: SeriesChoice, license: Option<UdlTerms>, accept_scan_warnings: bool) -> UploadRequest {
+        let optional = |value: &str| Some(value.to_string()).filter(|value| !value.is_empty());
+        UploadRequest {
+            title: self.title.clone(),
+            description: optional(&self.description),
+            speaker: optional(&self.speaker),
+            ministry: optional(&self.ministry),
+            scripture_refs: self
+                .scripture_refs
+                .split(';')
+                .map(str::trim)
+                .filter(|r| !r.is_empty())
+                .map(str::to_string)
+                .collect(),
+            language: normalize_language_tag(&self.language),
+            series,
+            content_type: content_type_for(self.file_name()).to_string(),
+            file: None,
+            duration_secs: None,
+            transcript: None,
+            cover: None,
+            file_hash: None,
+            encryption: None,
+            license,
+            rights: None,
+            ipfs_cid: None,
+            previous_version: None,
+            accept_scan_warnings,
+            method: UploadMethod::Bundler,
+            transfer: TransferControl::default(),
+            published: PublishedParts::default(),
+        }
+    }
+}
+
+/// A row that passed validation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidRow {
+    pub row: ImportRow,
+    /// Name of the chosen file the row refers to
+    pub file_name: String,
+    pub series: SeriesChoice,
+}
+
+/// Check every row against the chosen files and the uploader's series
+///
+/// File names match without regard to case or folders. Series must
+/// already exist: items in a batch publish one after another, faster than
+/// a new series manifest shows up on the gateway.
+pub fn validate_rows(rows: &[ImportRow], files: &[String], series: &[SeriesSummary]) -> Vec<Result<ValidRow, String>> {
+    let files_by_name: HashMap<String, &String> = files.iter().map(|name| (name.to_lowercase(), name)).collect();
+    let series_by_title: HashMap<String, &SeriesSummary> =
+        series.iter().map(|summary| (summary.title.trim().to_lowercase(), summary)).collect();
+    let mut first_use: HashMap<String, usize> = HashMap::new();
+
+    rows.iter()
+        .map(|row| {
+            if row.title.is_empty() {
+                return Err("A title is required".to_string());
+            }
+            if row.title.chars().count() > MAX_TITLE_LENGTH {
+                return Err(format!("Titles can be at most {} characters", MAX_TITLE_LENGTH));
+            }
+            if !row.language.is_empty() && normalize_language_tag(&row.language).is_none() {
+                return Err(format!("\"{}\" isn't a language code such as en or pt-BR", row.language));
+            }
+            if row.file.is_empty() {
+                return Err("No file name".to_string());
+            }
+            let key = row.file_name().to_lowercase();
+            let Some(file_name) = files_by_name.get(&key) else {
+                return Err(format!("{} isn't among the chosen files", row.file_name()));
+            };
+            if let Some(first) = first_use.get(&key) {
+                return Err(format!("{} is already used by row {}", row.file_name(), first));
+            }
+            first_use.insert(key, row.number);
+
+            let series = if row.series.is_empty() {
+                SeriesChoice::None
+            } else {
+                match series_by_title.get(&row.series.to_lowercase()) {
+                    Some(summary) => SeriesChoice::Existing(summary.series_id.clone()),
+                    None => return Err(format!("You have no series named \"{}\"; create it with a single upload first", row.series)),
+                }
+            };
+            Ok(ValidRow { row: row.clone(), file_name: file_name.to_string(), series })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn csv_manifests_are_mapped_by_header() {
+        let csv = "Sermon Title,File,Preacher,Passages\r\n\"Grace, Alone\",audio/Grace.MP3,Ann Lee,\"Eph 2:8; \"\"Rom 3\"\"\"\n,,,\nSecond,second.mp3,,\n";
+        let manifest = ImportManifest::parse("sermons.csv", csv).unwrap();
+        assert_eq!(manifest.rows.len(), 2);
+
+        let mapping = manifest.guess_mapping();
+        assert_eq!(mapping.get(&ImportField::Title), Some(&0));
+        assert_eq!(mapping.get(&ImportField::ScriptureRefs), Some(&3));
+
+        let rows = manifest.import_rows(&mapping);
+        assert_eq!(rows[0].title, "Grace, Alone");
+        assert_eq!(rows[0].file_name(), "Grace.MP3");
+        assert_eq!(rows[0].scripture_refs, "Eph 2:8; \"Rom 3\"");
+
+        let files = vec!["grace.mp3".to_string()];
+        let checked = validate_rows(&rows, &files, &[]);
+        assert_eq!(checked[0].as_ref().unwrap().file_name, "grace.mp3");
+        assert!(checked[1].is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn json_manifests_join_lists() {
+        let json = r#"[{"title": "Psalm 23", "file": "ps23.mp3", "scripture": ["Ps 23:1", "Ps 23:4"]}, {"title": "Hope", "year": 2019}]"#;
+        let manifest = ImportManifest::parse("sermons.json", json).unwrap();
+        assert_eq!(manifest.headers, vec!["file", "scripture", "title", "year"]);
+        assert_eq!(manifest.rows[0][1], "Ps 23:1; Ps 23:4");
+        assert_eq!(manifest.rows[1][3], "2019");
+        assert!(matches!(ImportManifest::parse("x.json", "[]"), Err(ImportError::Empty)));
+    }
+}
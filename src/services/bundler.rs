@@ -0,0 +1,256 @@
+use gloo_timers::future::TimeoutFuture;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::config::app_config;
+use crate::services::file_reader::LocalFile;
+use crate::services::transfer::{Cancelled, TransferControl};
+
+/// Items larger than this are posted in pieces of this size where the
+/// bundler takes chunked uploads; Turbo's smallest chunk (bytes)
+const BUNDLER_CHUNK_SIZE: u64 = 5 * 1024 * 1024;
+/// Turbo's chunked upload protocol version
+const CHUNKING_VERSION: &str = "2";
+/// Time between checks on a chunked upload being assembled (ms)
+const ASSEMBLY_POLL_MS: u32 = 2_000;
+/// Checks before leaving a chunked upload to finish on its own
+const ASSEMBLY_POLL_LIMIT: u32 = 300;
+
+#[derive(Debug, Clone)]
+pub enum BundlerError {
+    RequestFailed(String),
+    Rejected(u16, String),
+    InvalidResponse(String),
+    /// Cancelled from the upload queue before every chunk was posted
+    Cancelled(Cancelled),
+    /// Chunk `index` (from 0) of `total` couldn't be read or posted
+    ChunkFailed { index: u64, total: u64, error: Box<BundlerError> },
+}
+
+impl std::fmt::Display for BundlerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BundlerError::RequestFailed(msg) => write!(f, "Bundler request failed: {}", msg),
+            BundlerError::Rejected(status, msg) => write!(f, "Bundler rejected item (HTTP {}): {}", status, msg),
+            BundlerError::InvalidResponse(msg) => write!(f, "Invalid bundler response: {}", msg),
+            BundlerError::Cancelled(e) => write!(f, "{}", e),
+            BundlerError::ChunkFailed { index, total, error } => write!(f, "Chunk {} of {} failed: {}", index + 1, total, error),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct BundlerReceipt {
+    id: String,
+}
+
+/// A chunked upload the bundler has opened
+#[derive(Deserialize)]
+struct ChunkedUpload {
+    id: String,
+    #[serde(default, rename = "chunkSize")]
+    chunk_size: u64,
+}
+
+#[derive(Deserialize)]
+struct ChunkedUploadStatus {
+    status: String,
+    receipt: Option<BundlerReceipt>,
+}
+
+/// Posts signed ANS-104 DataItems to a bundler
+pub struct BundlerClient {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl BundlerClient {
+    pub fn new() -> Self {
+        Self::with_url(&app_config().bundler)
+    }
+
+    pub fn with_url(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Submit a serialized, signed DataItem and return its ID
+    pub async fn submit(&self, signed_item: Vec<u8>) -> Result<String, BundlerError> {
+        let response = self.client
+            .post(&self.url)
+            .header("Content-Type", "application/octet-stream")
+            .body(signed_item)
+            .send()
+            .await
+            .map_err(|e| BundlerError::RequestFailed(e.to_string()))?;
+
+        let status = response.status().as_u16();
+        if !response.status().is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(BundlerError::Rejected(status, body));
+        }
+
+        let receipt: BundlerReceipt = response
+            .json()
+            .await
+            .map_err(|e| BundlerError::InvalidResponse(e.to_string()))?;
+
+        log::info!("📦 Bundler accepted DataItem {}", receipt.id);
+        Ok(receipt.id)
+    }
+
+    /// Submit a signed DataItem assembled as a Blob, such as a header in
+    /// front of a file on disk, and return its ID
+    ///
+    /// reqwest only sends bodies already in memory, so this goes through
+    /// `fetch`, which streams the Blob from disk.
+    pub async fn submit_blob(&self, signed_item: &web_sys::Blob) -> Result<String, BundlerError> {
+        let window = web_sys::window().ok_or_else(|| BundlerError::RequestFailed("No window".to_string()))?;
+        let init = web_sys::RequestInit::new();
+        init.set_method("POST");
+        init.set_body(signed_item);
+
+        let response: web_sys::Response = JsFuture::from(window.fetch_with_str_and_init(&self.url, &init))
+            .await
+            .map_err(|e| BundlerError::RequestFailed(format!("{:?}", e)))?
+            .unchecked_into();
+        let body = match response.text() {
+            Ok(text) => JsFuture::from(text).await.ok().and_then(|text| text.as_string()).unwrap_or_default(),
+            Err(_) => String::new(),
+        };
+
+        if !response.ok() {
+            return Err(BundlerError::Rejected(response.status(), body));
+        }
+
+        let receipt: BundlerReceipt =
+            serde_json::from_str(&body).map_err(|e| BundlerError::InvalidResponse(e.to_string()))?;
+
+        log::info!("📦 Bundler accepted DataItem {}", receipt.id);
+        Ok(receipt.id)
+    }
+
+    /// Post a signed DataItem, pausing, stopping or slowing down as
+    /// `transfer` says, and return its ID
+    ///
+    /// Items over `BUNDLER_CHUNK_SIZE` go through Turbo's chunked upload
+    /// API, which leaves room to pause between pieces. Smaller items, and
+    /// bundlers without that API, are posted in one request.
+    pub async fn upload(&self, signed_item: &LocalFile, transfer: &TransferControl) -> Result<String, BundlerError> {
+        transfer.checkpoint().await.map_err(BundlerError::Cancelled)?;
+        if let Some(chunks_url) = self.chunks_url().filter(|_| signed_item.size() > BUNDLER_CHUNK_SIZE) {
+            match self.start_chunked_upload(&chunks_url).await {
+                Ok(upload) => return self.upload_chunks(&chunks_url, upload, signed_item, transfer).await,
+                Err(e) => log::info!("Bundler didn't open a chunked upload, posting in one request: {}", e),
+            }
+        }
+        let id = self.submit_blob(signed_item.blob()).await?;
+        transfer.pace(signed_item.size()).await;
+        Ok(id)
+    }
+
+    /// Turbo's chunked upload endpoint, beside its `/tx`
+    fn chunks_url(&self) -> Option<String> {
+        self.url.strip_suffix("/tx").map(|base| format!("{}/chunks/arweave", base))
+    }
+
+    async fn start_chunked_upload(&self, chunks_url: &str) -> Result<ChunkedUpload, BundlerError> {
+        let response = self.client
+            .get(format!("{}/-1/-1?chunkSize={}", chunks_url, BUNDLER_CHUNK_SIZE))
+            .header("x-chunking-version", CHUNKING_VERSION)
+            .send()
+            .await
+            .map_err(|e| BundlerError::RequestFailed(e.to_string()))?;
+        read_json(response).await
+    }
+
+    async fn upload_chunks(&self, chunks_url: &str, upload: ChunkedUpload, signed_item: &LocalFile, transfer: &TransferControl) -> Result<String, BundlerError> {
+        let upload_url = format!("{}/{}", chunks_url, upload.id);
+        let chunk_size = if upload.chunk_size > 0 { upload.chunk_size } else { BUNDLER_CHUNK_SIZE };
+
+        let total = signed_item.size().div_ceil(chunk_size);
+        let mut offset = 0;
+        while offset < signed_item.size() {
+            transfer.checkpoint().await.map_err(BundlerError::Cancelled)?;
+            let failed = |error| BundlerError::ChunkFailed { index: offset / chunk_size, total, error: Box::new(error) };
+            let chunk = signed_item
+                .read_range(offset, offset + chunk_size)
+                .await
+                .map_err(|e| failed(BundlerError::RequestFailed(e.to_string())))?;
+            let len = chunk.len() as u64;
+            let response = self.client
+                .post(format!("{}/{}", upload_url, offset))
+                .header("Content-Type", "application/octet-stream")
+                .header("x-chunking-version", CHUNKING_VERSION)
+                .body(chunk)
+                .send()
+                .await
+                .map_err(|e| failed(BundlerError::RequestFailed(e.to_string())))?;
+            check_status(response).await.map_err(failed)?;
+            offset += len;
+            transfer.pace(len).await;
+        }
+
+        let response = self.client
+            .post(format!("{}/finalize", upload_url))
+            .header("x-chunking-version", CHUNKING_VERSION)
+            .send()
+            .await
+            .map_err(|e| BundlerError::RequestFailed(e.to_string()))?;
+        check_status(response).await?;
+
+        // The bundler assembles and verifies the pieces before it issues
+        // a receipt
+        for _ in 0..ASSEMBLY_POLL_LIMIT {
+            TimeoutFuture::new(ASSEMBLY_POLL_MS).await;
+            let response = self.client
+                .get(format!("{}/status", upload_url))
+                .header("x-chunking-version", CHUNKING_VERSION)
+                .send()
+                .await
+                .map_err(|e| BundlerError::RequestFailed(e.to_string()))?;
+            let status: ChunkedUploadStatus = read_json(response).await?;
+            match (status.status.as_str(), status.receipt) {
+                ("FINALIZED", Some(receipt)) => {
+                    log::info!("📦 Bundler accepted DataItem {} in pieces", receipt.id);
+                    return Ok(receipt.id);
+                }
+                ("FINALIZED", None) => return Err(BundlerError::InvalidResponse("finalized without a receipt".to_string())),
+                // Reported as the statuses a single post would have got
+                ("UNDERFUNDED", _) => return Err(BundlerError::Rejected(402, "Insufficient balance".to_string())),
+                ("INVALID" | "APPROVAL_FAILED" | "REVERTED", _) => {
+                    return Err(BundlerError::Rejected(400, format!("upload {}", status.status.to_lowercase())));
+                }
+                _ => {}
+            }
+        }
+        Err(BundlerError::InvalidResponse(format!("upload {} was still being assembled", upload.id)))
+    }
+}
+
+async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, BundlerError> {
+    if !response.status().is_success() {
+        let status = response.status().as_u16();
+        let body = response.text().await.unwrap_or_default();
+        return Err(BundlerError::Rejected(status, body));
+    }
+    Ok(response)
+}
+
+async fn read_json<T: DeserializeOwned>(response: reqwest::Response) -> Result<T, BundlerError> {
+    check_status(response)
+        .await?
+        .json()
+        .await
+        .map_err(|e| BundlerError::InvalidResponse(e.to_string()))
+}
+
+impl Default for BundlerClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,249 @@
+//! User-curated collections
+//!
+//! A collection is a signed manifest listing items in order, published by
+//! any connected wallet. Each new version is another manifest with the
+//! same `Collection-Id`; as with series, only manifests signed by the
+//! curator (the signer of the first one) count, so a shared collection
+//! can't be rewritten by whoever finds its link.
+
+use std::collections::HashMap;
+
+use crate::models::{CollectionManifest, ContentMetadata, COLLECTION_MANIFEST_TYPE};
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, GraphQLError, TransactionSummary};
+use crate::services::hide_registry::is_hidden;
+use crate::services::upload::{publish_with_wallet, PublishError};
+use crate::services::wallet::connected_address;
+
+#[derive(Debug, Clone)]
+pub enum CollectionError {
+    LookupFailed(GraphQLError),
+    NotFound,
+    InvalidManifest(String),
+    /// Someone other than the curator tried to publish a new version
+    NotCurator,
+    PublishFailed(PublishError),
+}
+
+impl std::fmt::Display for CollectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CollectionError::LookupFailed(e) => write!(f, "Could not look up collection: {}", e),
+            CollectionError::NotFound => write!(f, "Collection not found"),
+            CollectionError::InvalidManifest(msg) => write!(f, "Collection manifest is invalid: {}", msg),
+            CollectionError::NotCurator => write!(f, "Only the wallet that created this collection can change it"),
+            CollectionError::PublishFailed(e) => write!(f, "Could not publish collection: {}", e),
+        }
+    }
+}
+
+/// A collection as listed on the Collections page and in pickers
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollectionSummary {
+    pub collection_id: String,
+    pub title: String,
+    pub curator: String,
+    pub item_count: Option<usize>,
+    /// When the latest version was mined; `None` while pending
+    pub updated_at: Option<i64>,
+    /// Latest manifest DataItem
+    pub manifest_tx_id: String,
+}
+
+/// A loaded collection and who curates it
+#[derive(Debug, Clone, PartialEq)]
+pub struct CuratedCollection {
+    pub manifest: CollectionManifest,
+    pub curator: String,
+}
+
+/// Publishes and resolves collection manifests
+pub struct CollectionService {
+    graphql: GraphQLClient,
+}
+
+impl CollectionService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+        }
+    }
+
+    /// Current version of a collection, from its curator
+    pub async fn load(&self, collection_id: &str) -> Result<CuratedCollection, CollectionError> {
+        let manifests = self.graphql
+            .find_transactions(&[], &[("Type", &[COLLECTION_MANIFEST_TYPE]), ("Collection-Id", &[collection_id])])
+            .await
+            .map_err(CollectionError::LookupFailed)?;
+        let latest = latest_from_curator(&manifests).ok_or(CollectionError::NotFound)?;
+
+        let response = GatewayManager::new()
+            .get(&format!("/{}", latest.id))
+            .await
+            .map_err(|e| CollectionError::InvalidManifest(e.to_string()))?;
+        let manifest = response
+            .json()
+            .await
+            .map_err(|e| CollectionError::InvalidManifest(e.to_string()))?;
+        Ok(CuratedCollection { manifest, curator: latest.owner.clone() })
+    }
+
+    /// Every collection, most recently updated first
+    pub async fn list(&self) -> Result<Vec<CollectionSummary>, CollectionError> {
+        self.summaries(&[]).await
+    }
+
+    /// Collections curated by `owner`, for the "Add to collection" picker
+    pub async fn list_for_owner(&self, owner: &str) -> Result<Vec<CollectionSummary>, CollectionError> {
+        self.summaries(&[owner]).await
+    }
+
+    async fn summaries(&self, owners: &[&str]) -> Result<Vec<CollectionSummary>, CollectionError> {
+        let manifests = self.graphql
+            .find_transactions(owners, &[("Type", &[COLLECTION_MANIFEST_TYPE])])
+            .await
+            .map_err(CollectionError::LookupFailed)?;
+        Ok(summarize(&manifests))
+    }
+
+    /// Publish a new version of a collection from the connected wallet
+    ///
+    /// Concurrent edits from another tab or device are merged in.
+    /// `collection` is updated to what was published.
+    pub async fn publish(&self, collection: &mut CollectionManifest) -> Result<String, CollectionError> {
+        match self.load(&collection.collection_id).await {
+            Ok(latest) => {
+                if connected_address().as_deref() != Some(latest.curator.as_str()) {
+                    return Err(CollectionError::NotCurator);
+                }
+                if collection.conflicts_with(&latest.manifest) {
+                    log::warn!(
+                        "⚠️ Collection {} changed since it was loaded (v{} -> v{}), merging",
+                        collection.collection_id, collection.version, latest.manifest.version
+                    );
+                    collection.merge_from(&latest.manifest);
+                }
+            }
+            Err(CollectionError::NotFound) => {}
+            Err(e) => return Err(e),
+        }
+        collection.version += 1;
+
+        let data = serde_json::to_vec(collection)
+            .map_err(|e| CollectionError::InvalidManifest(e.to_string()))?;
+        let tags = vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Type".to_string(), COLLECTION_MANIFEST_TYPE.to_string()),
+            ("Collection-Id".to_string(), collection.collection_id.clone()),
+            ("Title".to_string(), collection.title.clone()),
+            ("Item-Count".to_string(), collection.items.len().to_string()),
+            ("Config-Version".to_string(), collection.version.to_string()),
+        ];
+
+        let (_, tx_id) = publish_with_wallet(data, tags)
+            .await
+            .map_err(CollectionError::PublishFailed)?;
+        log::info!("🗂️ Published collection {} v{} ({} items)", collection.collection_id, collection.version, collection.items.len());
+        Ok(tx_id)
+    }
+
+    /// Metadata of each item in the curator's order; items the gateway
+    /// can't find or moderators have de-listed are skipped
+    pub async fn items(&self, collection: &CollectionManifest) -> Result<Vec<ContentMetadata>, CollectionError> {
+        let ids: Vec<&str> = collection.items.iter().map(String::as_str).collect();
+        let found = self.graphql
+            .transactions_by_ids(&ids)
+            .await
+            .map_err(CollectionError::LookupFailed)?;
+
+        let mut by_id: HashMap<String, ContentMetadata> = found
+            .iter()
+            .filter(|tx| !is_hidden(&tx.id))
+            .filter_map(|tx| ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp))
+            .map(|metadata| (metadata.tx_id.clone(), metadata))
+            .collect();
+
+        Ok(collection.items.iter().filter_map(|id| by_id.remove(id)).collect())
+    }
+}
+
+impl Default for CollectionService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Newest manifest signed by the curator of a collection
+fn latest_from_curator(manifests: &[TransactionSummary]) -> Option<&TransactionSummary> {
+    // Pending manifests have no timestamp yet and are the newest
+    let order = |tx: &&TransactionSummary| tx.timestamp.unwrap_or(i64::MAX);
+    let curator = &manifests.iter().min_by_key(order)?.owner;
+    manifests
+        .iter()
+        .filter(|tx| &tx.owner == curator)
+        .max_by_key(order)
+}
+
+/// One summary per collection from any mix of manifests, most recently
+/// updated first
+fn summarize(manifests: &[TransactionSummary]) -> Vec<CollectionSummary> {
+    let mut by_collection: HashMap<&str, Vec<TransactionSummary>> = HashMap::new();
+    for manifest in manifests {
+        if let Some(collection_id) = manifest.tag("Collection-Id") {
+            by_collection.entry(collection_id).or_default().push(manifest.clone());
+        }
+    }
+
+    let mut collections: Vec<CollectionSummary> = by_collection
+        .into_iter()
+        .filter_map(|(collection_id, manifests)| {
+            let latest = latest_from_curator(&manifests)?;
+            Some(CollectionSummary {
+                collection_id: collection_id.to_string(),
+                title: latest.tag("Title").unwrap_or(collection_id).to_string(),
+                curator: latest.owner.clone(),
+                item_count: latest.tag("Item-Count").and_then(|count| count.parse().ok()),
+                updated_at: latest.timestamp,
+                manifest_tx_id: latest.id.clone(),
+            })
+        })
+        .collect();
+    collections.sort_by_key(|collection| std::cmp::Reverse(collection.updated_at.unwrap_or(i64::MAX)));
+    collections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn manifest(id: &str, owner: &str, collection_id: &str, title: &str, timestamp: Option<i64>) -> TransactionSummary {
+        TransactionSummary {
+            id: id.to_string(),
+            owner: owner.to_string(),
+            tags: vec![
+                ("Collection-Id".to_string(), collection_id.to_string()),
+                ("Title".to_string(), title.to_string()),
+            ],
+            block_height: timestamp.map(|_| 1),
+            timestamp,
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn only_the_curator_can_rename_a_collection() {
+        let manifests = vec![
+            manifest("m1", "curator", "easter", "Easter sermons", Some(100)),
+            manifest("m2", "someone-else", "easter", "Spam", Some(300)),
+            manifest("m3", "curator", "easter", "Easter sermons 2026", Some(200)),
+            manifest("m4", "other", "advent", "Advent", None),
+        ];
+        let collections = summarize(&manifests);
+        assert_eq!(collections.len(), 2);
+        // Pending versions sort as the newest
+        assert_eq!(collections[0].collection_id, "advent");
+        assert_eq!(collections[1].title, "Easter sermons 2026");
+        assert_eq!(collections[1].curator, "curator");
+        assert_eq!(collections[1].manifest_tx_id, "m3");
+    }
+}
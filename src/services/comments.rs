@@ -0,0 +1,299 @@
+//! Comments on archive items
+//!
+//! A comment is a small text DataItem tagged with the item it belongs to
+//! (`Parent-Tx`) and, for replies, the comment it answers (`Reply-To`).
+//! Anyone can publish one, so every reader filters spam locally before
+//! threading, and comments the moderation team lists in the hide registry
+//! are dropped together with their replies.
+
+use std::collections::{HashMap, HashSet};
+
+use futures::future::join_all;
+
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, GraphQLError, TransactionSummary};
+use crate::services::hide_registry::is_hidden;
+use crate::services::identity::{publish_low_stakes, IdentityError, Signer};
+
+/// `Type` tag of a comment
+pub const COMMENT_TYPE: &str = "Comment";
+/// Longest comment accepted, in characters
+pub const MAX_COMMENT_CHARS: usize = 2000;
+/// More links than this marks a comment as spam
+const MAX_LINKS: usize = 2;
+/// Comments one author may leave on an item within `RATE_WINDOW_SECS`
+const RATE_LIMIT: usize = 5;
+const RATE_WINDOW_SECS: i64 = 10 * 60;
+
+#[derive(Debug, Clone)]
+pub enum CommentError {
+    Empty,
+    TooLong,
+    LookupFailed(GraphQLError),
+    PublishFailed(IdentityError),
+}
+
+impl std::fmt::Display for CommentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommentError::Empty => write!(f, "Write something first"),
+            CommentError::TooLong => write!(f, "Comments can be at most {} characters", MAX_COMMENT_CHARS),
+            CommentError::LookupFailed(e) => write!(f, "Could not load comments: {}", e),
+            CommentError::PublishFailed(e) => write!(f, "Could not post the comment: {}", e),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    pub id: String,
+    /// Item the comment is on
+    pub parent_tx: String,
+    /// Comment this one replies to, if any
+    pub reply_to: Option<String>,
+    pub author: String,
+    pub body: String,
+    /// Block timestamp; `None` while the comment is still pending
+    pub timestamp: Option<i64>,
+}
+
+/// A comment with its replies, oldest first
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentNode {
+    pub comment: Comment,
+    pub replies: Vec<CommentNode>,
+}
+
+impl CommentNode {
+    /// This comment plus all replies below it
+    pub fn count(&self) -> usize {
+        1 + self.replies.iter().map(CommentNode::count).sum::<usize>()
+    }
+}
+
+/// Trimmed comment text, or why it can't be posted
+pub fn validate_body(body: &str) -> Result<&str, CommentError> {
+    let body = body.trim();
+    if body.is_empty() {
+        return Err(CommentError::Empty);
+    }
+    if body.chars().count() > MAX_COMMENT_CHARS {
+        return Err(CommentError::TooLong);
+    }
+    Ok(body)
+}
+
+fn link_count(body: &str) -> usize {
+    body.matches("http://").count() + body.matches("https://").count() + body.matches("www.").count()
+}
+
+// Case and whitespace don't make a repeated comment new
+fn normalized(body: &str) -> String {
+    body.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Drop comments that look like spam, oldest first so the original of a
+/// repeated message survives
+///
+/// Removes empty or overlong comments, ones with more than a couple of
+/// links, an author repeating the same text, and authors posting more than
+/// `RATE_LIMIT` comments within `RATE_WINDOW_SECS`.
+pub fn filter_spam(mut comments: Vec<Comment>) -> Vec<Comment> {
+    // Pending comments are the newest
+    comments.sort_by_key(|comment| comment.timestamp.unwrap_or(i64::MAX));
+
+    let mut seen_texts: HashSet<(String, String)> = HashSet::new();
+    let mut recent: HashMap<String, Vec<i64>> = HashMap::new();
+
+    comments
+        .into_iter()
+        .filter(|comment| {
+            if validate_body(&comment.body).is_err() || link_count(&comment.body) > MAX_LINKS {
+                return false;
+            }
+            if !seen_texts.insert((comment.author.clone(), normalized(&comment.body))) {
+                return false;
+            }
+            let Some(timestamp) = comment.timestamp else {
+                return true;
+            };
+            let times = recent.entry(comment.author.clone()).or_default();
+            times.retain(|earlier| timestamp - earlier < RATE_WINDOW_SECS);
+            if times.len() >= RATE_LIMIT {
+                return false;
+            }
+            times.push(timestamp);
+            true
+        })
+        .collect()
+}
+
+/// Arrange comments into threads, oldest first
+///
+/// Replies whose parent is missing (filtered out or hidden) are dropped
+/// along with it rather than shown out of context.
+pub fn build_threads(comments: Vec<Comment>) -> Vec<CommentNode> {
+    let ids: HashSet<String> = comments.iter().map(|comment| comment.id.clone()).collect();
+    let mut children: HashMap<Option<String>, Vec<Comment>> = HashMap::new();
+    for comment in comments {
+        match &comment.reply_to {
+            Some(parent) if !ids.contains(parent) => continue,
+            _ => children.entry(comment.reply_to.clone()).or_default().push(comment),
+        }
+    }
+
+    fn attach(parent: Option<String>, children: &mut HashMap<Option<String>, Vec<Comment>>) -> Vec<CommentNode> {
+        let mut level = children.remove(&parent).unwrap_or_default();
+        level.sort_by_key(|comment| comment.timestamp.unwrap_or(i64::MAX));
+        level
+            .into_iter()
+            .map(|comment| {
+                let replies = attach(Some(comment.id.clone()), children);
+                CommentNode { comment, replies }
+            })
+            .collect()
+    }
+
+    attach(None, &mut children)
+}
+
+/// Loads and posts comments
+pub struct CommentService {
+    graphql: GraphQLClient,
+    gateways: GatewayManager,
+}
+
+impl CommentService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+            gateways: GatewayManager::new(),
+        }
+    }
+
+    /// Comments on `tx_id`, spam-filtered and without hidden ones; pass
+    /// them to `build_threads` for display
+    pub async fn load(&self, tx_id: &str) -> Result<Vec<Comment>, CommentError> {
+        let transactions = self.graphql
+            .find_transactions(&[], &[("Type", &[COMMENT_TYPE]), ("Parent-Tx", &[tx_id])])
+            .await
+            .map_err(CommentError::LookupFailed)?;
+
+        let visible: Vec<TransactionSummary> = transactions
+            .into_iter()
+            .filter(|tx| !is_hidden(&tx.id))
+            .collect();
+        let comments = join_all(visible.iter().map(|tx| self.load_comment(tx))).await;
+        let comments = filter_spam(comments.into_iter().flatten().collect());
+
+        log::info!("💬 Loaded {} comments on {}", comments.len(), tx_id);
+        Ok(comments)
+    }
+
+    // Bodies live in the DataItem's data; comments the gateway can't serve
+    // yet are skipped
+    async fn load_comment(&self, tx: &TransactionSummary) -> Option<Comment> {
+        let response = self.gateways.get(&format!("/{}", tx.id)).await.ok()?;
+        if !response.status().is_success() {
+            return None;
+        }
+        let body = response.text().await.ok()?;
+
+        Some(Comment {
+            id: tx.id.clone(),
+            parent_tx: tx.tag("Parent-Tx")?.to_string(),
+            reply_to: tx.tag("Reply-To").map(|id| id.to_string()),
+            author: tx.owner.clone(),
+            body,
+            timestamp: tx.timestamp,
+        })
+    }
+
+    /// Publish a comment on `tx_id`, optionally replying to another comment
+    ///
+    /// Signed by the connected wallet, or the anonymous identity when none
+    /// is connected.
+    pub async fn post(&self, tx_id: &str, reply_to: Option<&str>, body: &str) -> Result<(Signer, Comment), CommentError> {
+        let body = validate_body(body)?;
+        let mut tags = vec![
+            ("Content-Type".to_string(), "text/plain".to_string()),
+            ("Type".to_string(), COMMENT_TYPE.to_string()),
+            ("Parent-Tx".to_string(), tx_id.to_string()),
+        ];
+        if let Some(reply_to) = reply_to {
+            tags.push(("Reply-To".to_string(), reply_to.to_string()));
+        }
+
+        let (signer, id) = publish_low_stakes(body.as_bytes().to_vec(), tags)
+            .await
+            .map_err(CommentError::PublishFailed)?;
+        log::info!("💬 Commented on {} ({})", tx_id, id);
+
+        let comment = Comment {
+            id,
+            parent_tx: tx_id.to_string(),
+            reply_to: reply_to.map(|id| id.to_string()),
+            author: signer.address.clone(),
+            body: body.to_string(),
+            timestamp: None,
+        };
+        Ok((signer, comment))
+    }
+}
+
+impl Default for CommentService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn comment(id: &str, reply_to: Option<&str>, author: &str, body: &str, timestamp: i64) -> Comment {
+        Comment {
+            id: id.to_string(),
+            parent_tx: "item".to_string(),
+            reply_to: reply_to.map(|id| id.to_string()),
+            author: author.to_string(),
+            body: body.to_string(),
+            timestamp: Some(timestamp),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn threads_replies_under_parents() {
+        let threads = build_threads(vec![
+            comment("b", Some("a"), "bob", "Amen", 20),
+            comment("a", None, "ann", "Great sermon", 10),
+            comment("c", Some("b"), "ann", "Indeed", 30),
+            comment("d", None, "cal", "Thanks", 15),
+            comment("orphan", Some("missing"), "eve", "Out of context", 40),
+        ]);
+
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].comment.id, "a");
+        assert_eq!(threads[0].count(), 3);
+        assert_eq!(threads[0].replies[0].replies[0].comment.id, "c");
+        assert_eq!(threads[1].comment.id, "d");
+    }
+
+    #[wasm_bindgen_test]
+    fn filters_links_repeats_and_floods() {
+        let mut comments = vec![
+            comment("a", None, "ann", "Great sermon", 10),
+            comment("b", None, "ann", "great   SERMON", 20),
+            comment("c", None, "bob", "Great sermon", 30),
+            comment("d", None, "spam", "https://a.example https://b.example www.c.example", 40),
+            comment("e", None, "bob", "   ", 50),
+        ];
+        for i in 0..7 {
+            comments.push(comment(&format!("flood{}", i), None, "eve", &format!("Message {}", i), 100 + i));
+        }
+
+        let kept: Vec<String> = filter_spam(comments).into_iter().map(|comment| comment.id).collect();
+        assert_eq!(kept, ["a", "c", "flood0", "flood1", "flood2", "flood3", "flood4"]);
+    }
+}
@@ -0,0 +1,213 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::models::{BlockInfo, SubmittedItem, TxStatus};
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::GraphQLClient;
+use crate::services::notifications::NotificationService;
+use crate::services::storage::{StorageService, SUBMITTED_ITEMS_STORE};
+use crate::utils::constants::REQUIRED_CONFIRMATIONS;
+
+/// Maximum number of events kept for late subscribers
+const MAX_EVENT_LOG: usize = 50;
+
+/// Event emitted whenever a tracked item changes status
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfirmationEvent {
+    pub sequence: u64,
+    pub tx_id: String,
+    pub status: TxStatus,
+    pub confirmations: u64,
+}
+
+/// Response body of the gateway `/tx/{id}/status` endpoint
+#[derive(Deserialize)]
+struct TxStatusResponse {
+    block_height: u64,
+    number_of_confirmations: u64,
+}
+
+// Global confirmation state using Dioxus signals
+fn use_submitted_items_state() -> &'static GlobalSignal<HashMap<String, SubmittedItem>> {
+    static SUBMITTED_ITEMS: GlobalSignal<HashMap<String, SubmittedItem>> = GlobalSignal::new(HashMap::new);
+    &SUBMITTED_ITEMS
+}
+
+fn use_confirmation_event_log() -> &'static GlobalSignal<Vec<ConfirmationEvent>> {
+    static CONFIRMATION_EVENTS: GlobalSignal<Vec<ConfirmationEvent>> = GlobalSignal::new(Vec::new);
+    &CONFIRMATION_EVENTS
+}
+
+/// Polls the gateway for recently submitted items until they are final
+pub struct ConfirmationService {
+    gateways: GatewayManager,
+    graphql: GraphQLClient,
+    required_confirmations: u64,
+}
+
+impl ConfirmationService {
+    pub fn new() -> Self {
+        Self {
+            gateways: GatewayManager::new(),
+            graphql: GraphQLClient::new(),
+            required_confirmations: REQUIRED_CONFIRMATIONS,
+        }
+    }
+
+    /// Override the number of confirmations after which polling stops
+    pub fn with_required_confirmations(mut self, required_confirmations: u64) -> Self {
+        self.required_confirmations = required_confirmations;
+        self
+    }
+
+    /// Start tracking a newly submitted item
+    pub async fn track(item: SubmittedItem) {
+        log::info!("📡 Tracking confirmations for {}", item.tx_id);
+
+        match StorageService::open().await {
+            Ok(storage) => {
+                if let Err(e) = storage.put(SUBMITTED_ITEMS_STORE, &item.tx_id, &item).await {
+                    log::warn!("Failed to persist submitted item {}: {}", item.tx_id, e);
+                }
+            }
+            Err(e) => log::warn!("Failed to open storage: {}", e),
+        }
+
+        use_submitted_items_state().write().insert(item.tx_id.clone(), item);
+    }
+
+    /// Fetch current block info, trying the status endpoint first and falling
+    /// back to GraphQL (bundled DataItems are not known to `/tx/{id}/status`).
+    pub async fn fetch_block_info(&self, tx_id: &str) -> Option<BlockInfo> {
+        if let Ok(response) = self.gateways.get(&format!("/tx/{}/status", tx_id)).await {
+            if response.status().as_u16() == 200 {
+                if let Ok(status) = response.json::<TxStatusResponse>().await {
+                    return Some(BlockInfo {
+                        height: status.block_height,
+                        timestamp: None,
+                        confirmations: status.number_of_confirmations,
+                    });
+                }
+            }
+        }
+
+        match self.graphql.block_info(tx_id).await {
+            Ok(info) => info,
+            Err(e) => {
+                log::warn!("Block lookup failed for {}: {}", tx_id, e);
+                None
+            }
+        }
+    }
+
+    /// Check every item that still needs polling once
+    pub async fn poll_once(&self) {
+        let storage = match StorageService::open().await {
+            Ok(storage) => storage,
+            Err(e) => {
+                log::warn!("Confirmation poller could not open storage: {}", e);
+                return;
+            }
+        };
+
+        let items: Vec<SubmittedItem> = match storage.get_all(SUBMITTED_ITEMS_STORE).await {
+            Ok(items) => items,
+            Err(e) => {
+                log::warn!("Confirmation poller could not load items: {}", e);
+                return;
+            }
+        };
+
+        for mut item in items.into_iter().filter(|item| item.needs_polling()) {
+            let info = self.fetch_block_info(&item.tx_id).await;
+            let previous_confirmations = item.confirmations;
+            let status_changed = item.apply_block_info(info.as_ref(), self.required_confirmations);
+
+            if let Err(e) = storage.put(SUBMITTED_ITEMS_STORE, &item.tx_id, &item).await {
+                log::warn!("Failed to update submitted item {}: {}", item.tx_id, e);
+            }
+
+            if status_changed || previous_confirmations != item.confirmations {
+                log::info!("🔔 {} is now {} ({} confirmations)", item.tx_id, item.status.display_name(), item.confirmations);
+                emit_event(&item);
+            }
+            if status_changed && item.status == TxStatus::Confirmed {
+                NotificationService::upload_confirmed(&item).await;
+            }
+
+            use_submitted_items_state().write().insert(item.tx_id.clone(), item);
+        }
+    }
+
+    /// Load persisted items into the global signal
+    pub async fn load_persisted() {
+        if let Ok(storage) = StorageService::open().await {
+            if let Ok(items) = storage.get_all::<SubmittedItem>(SUBMITTED_ITEMS_STORE).await {
+                let mut state = use_submitted_items_state().write();
+                for item in items {
+                    state.insert(item.tx_id.clone(), item);
+                }
+            }
+        }
+    }
+}
+
+impl Default for ConfirmationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn emit_event(item: &SubmittedItem) {
+    let mut events = use_confirmation_event_log().write();
+    let sequence = events.last().map(|event| event.sequence + 1).unwrap_or(1);
+
+    events.push(ConfirmationEvent {
+        sequence,
+        tx_id: item.tx_id.clone(),
+        status: item.status,
+        confirmations: item.confirmations,
+    });
+
+    if events.len() > MAX_EVENT_LOG {
+        let overflow = events.len() - MAX_EVENT_LOG;
+        events.drain(..overflow);
+    }
+}
+
+/// Hook returning the tracked state of a single item
+pub fn use_confirmation_status(tx_id: String) -> Memo<Option<SubmittedItem>> {
+    use_memo(move || use_submitted_items_state().read().get(&tx_id).cloned())
+}
+
+/// Hook returning all tracked items, newest first
+pub fn use_submitted_items() -> Memo<Vec<SubmittedItem>> {
+    use_memo(move || {
+        let mut items: Vec<SubmittedItem> = use_submitted_items_state().read().values().cloned().collect();
+        items.sort_by_key(|item| std::cmp::Reverse(item.submitted_at));
+        items
+    })
+}
+
+/// Hook for subscribing to confirmation events
+///
+/// Calls `on_event` once for every event emitted after the hook mounts.
+pub fn use_confirmation_events(on_event: Callback<ConfirmationEvent>) {
+    let mut last_seen = use_signal(|| {
+        use_confirmation_event_log().peek().last().map(|event| event.sequence).unwrap_or(0)
+    });
+
+    use_effect(move || {
+        let events = use_confirmation_event_log().read();
+        let seen = *last_seen.peek();
+
+        for event in events.iter().filter(|event| event.sequence > seen) {
+            on_event.call(event.clone());
+        }
+
+        if let Some(latest) = events.last() {
+            last_seen.set(latest.sequence);
+        }
+    });
+}
@@ -0,0 +1,308 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::services::gateway::{GatewayError, GatewayManager};
+use crate::services::storage::{StorageService, CONTENT_CACHE_INDEX_STORE, CONTENT_CACHE_STORE};
+use crate::utils::constants::{CONTENT_CACHE_ITEM_LIMIT, CONTENT_CACHE_MAX_BYTES, FETCH_CHUNK_SIZE};
+
+/// Download progress of a single item
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DownloadProgress {
+    pub loaded: u64,
+    pub total: Option<u64>,
+    pub complete: bool,
+}
+
+impl DownloadProgress {
+    /// Fraction downloaded, 0.0 to 1.0, when the total size is known
+    pub fn fraction(&self) -> Option<f64> {
+        match self.total {
+            Some(0) => Some(1.0),
+            Some(total) => Some(self.loaded as f64 / total as f64),
+            None => None,
+        }
+    }
+}
+
+/// Downloaded item data
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchedContent {
+    pub tx_id: String,
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedContent {
+    content_type: String,
+    data: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    tx_id: String,
+    size: u64,
+    last_accessed: i64,
+}
+
+#[derive(Debug, Clone)]
+pub enum ContentFetchError {
+    Gateway(GatewayError),
+    BadStatus(u16),
+    ReadFailed(String),
+    BlobFailed(String),
+}
+
+impl std::fmt::Display for ContentFetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentFetchError::Gateway(e) => write!(f, "Content download failed: {}", e),
+            ContentFetchError::BadStatus(status) => write!(f, "Gateway returned HTTP {} for content", status),
+            ContentFetchError::ReadFailed(msg) => write!(f, "Failed to read content: {}", msg),
+            ContentFetchError::BlobFailed(msg) => write!(f, "Failed to prepare content for playback: {}", msg),
+        }
+    }
+}
+
+impl From<GatewayError> for ContentFetchError {
+    fn from(error: GatewayError) -> Self {
+        ContentFetchError::Gateway(error)
+    }
+}
+
+// Global download progress keyed by transaction ID
+fn use_download_progress_state() -> &'static GlobalSignal<HashMap<String, DownloadProgress>> {
+    static DOWNLOAD_PROGRESS: GlobalSignal<HashMap<String, DownloadProgress>> = GlobalSignal::new(HashMap::new);
+    &DOWNLOAD_PROGRESS
+}
+
+fn set_progress(tx_id: &str, progress: DownloadProgress) {
+    use_download_progress_state().write().insert(tx_id.to_string(), progress);
+}
+
+/// Downloads Arweave data in Range-request chunks
+///
+/// Chunks are handed to the caller as they arrive so media players can start
+/// before the download finishes. Items up to `CONTENT_CACHE_ITEM_LIMIT` are
+/// cached in IndexedDB and evicted least-recently-used first once the cache
+/// exceeds `CONTENT_CACHE_MAX_BYTES`.
+pub struct ContentFetchService {
+    gateways: GatewayManager,
+}
+
+impl ContentFetchService {
+    pub fn new() -> Self {
+        Self {
+            gateways: GatewayManager::new(),
+        }
+    }
+
+    /// Stream an item chunk by chunk, returning its content type
+    pub async fn stream<F>(&self, tx_id: &str, mut on_chunk: F) -> Result<String, ContentFetchError>
+    where
+        F: FnMut(&[u8]),
+    {
+        let path = format!("/{}", tx_id);
+        let mut loaded: u64 = 0;
+        let mut total: Option<u64> = None;
+        let mut content_type = String::from("application/octet-stream");
+
+        set_progress(tx_id, DownloadProgress { loaded, total, complete: false });
+
+        loop {
+            let end = loaded + FETCH_CHUNK_SIZE - 1;
+            let response = self.gateways.get_range(&path, loaded, end).await?;
+            let status = response.status().as_u16();
+
+            if let Some(value) = response.headers().get("content-type").and_then(|v| v.to_str().ok()) {
+                content_type = value.to_string();
+            }
+
+            match status {
+                206 => {
+                    if total.is_none() {
+                        total = response
+                            .headers()
+                            .get("content-range")
+                            .and_then(|v| v.to_str().ok())
+                            .and_then(parse_content_range_total);
+                    }
+                }
+                // Gateway ignored the Range header and sent the whole item
+                200 => total = response.content_length(),
+                // Requested range starts past the end: zero-length item
+                416 if loaded == 0 => total = Some(0),
+                _ => return Err(ContentFetchError::BadStatus(status)),
+            }
+
+            let chunk = if status == 416 {
+                Vec::new()
+            } else {
+                response
+                    .bytes()
+                    .await
+                    .map_err(|e| ContentFetchError::ReadFailed(e.to_string()))?
+                    .to_vec()
+            };
+
+            loaded += chunk.len() as u64;
+            on_chunk(&chunk);
+
+            let complete = status != 206 || chunk.is_empty() || total.is_some_and(|total| loaded >= total);
+            if complete && total.is_none() {
+                total = Some(loaded);
+            }
+            set_progress(tx_id, DownloadProgress { loaded, total, complete });
+
+            if complete {
+                return Ok(content_type);
+            }
+        }
+    }
+
+    /// Fetch an item fully, serving from and filling the IndexedDB cache
+    pub async fn fetch(&self, tx_id: &str) -> Result<FetchedContent, ContentFetchError> {
+        if let Some(cached) = cache_get(tx_id).await {
+            let size = cached.bytes.len() as u64;
+            set_progress(tx_id, DownloadProgress { loaded: size, total: Some(size), complete: true });
+            return Ok(cached);
+        }
+
+        let mut bytes = Vec::new();
+        let content_type = self.stream(tx_id, |chunk| bytes.extend_from_slice(chunk)).await?;
+
+        let content = FetchedContent {
+            tx_id: tx_id.to_string(),
+            content_type,
+            bytes,
+        };
+
+        if content.bytes.len() as u64 <= CONTENT_CACHE_ITEM_LIMIT {
+            cache_put(&content).await;
+        }
+
+        Ok(content)
+    }
+
+    /// Fetch an item and wrap it in a Blob object URL for `audio`, `video`
+    /// or `img` elements. Callers should revoke it with `revoke_object_url`.
+    pub async fn object_url(&self, tx_id: &str) -> Result<String, ContentFetchError> {
+        let content = self.fetch(tx_id).await?;
+        Self::blob_url(&content.bytes, &content.content_type)
+    }
+
+    /// Wrap bytes already in memory, such as decrypted content, in a Blob
+    /// object URL. Callers should revoke it with `revoke_object_url`.
+    pub fn blob_url(bytes: &[u8], content_type: &str) -> Result<String, ContentFetchError> {
+        let parts = js_sys::Array::new();
+        parts.push(&js_sys::Uint8Array::from(bytes));
+
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type(content_type);
+
+        let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+            .map_err(|e| ContentFetchError::BlobFailed(format!("{:?}", e)))?;
+
+        web_sys::Url::create_object_url_with_blob(&blob)
+            .map_err(|e| ContentFetchError::BlobFailed(format!("{:?}", e)))
+    }
+
+    /// Release an object URL created by `object_url`
+    pub fn revoke_object_url(url: &str) {
+        let _ = web_sys::Url::revoke_object_url(url);
+    }
+}
+
+impl Default for ContentFetchService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Total size from a `Content-Range: bytes 0-1023/4096` header
+fn parse_content_range_total(value: &str) -> Option<u64> {
+    value.rsplit('/').next().and_then(|total| total.trim().parse().ok())
+}
+
+async fn cache_get(tx_id: &str) -> Option<FetchedContent> {
+    let storage = StorageService::open().await.ok()?;
+    let cached: CachedContent = storage.get(CONTENT_CACHE_STORE, tx_id).await.ok()??;
+    let bytes = STANDARD.decode(&cached.data).ok()?;
+
+    let entry = CacheEntry {
+        tx_id: tx_id.to_string(),
+        size: bytes.len() as u64,
+        last_accessed: chrono::Utc::now().timestamp_millis(),
+    };
+    if let Err(e) = storage.put(CONTENT_CACHE_INDEX_STORE, tx_id, &entry).await {
+        log::warn!("Failed to update cache access time for {}: {}", tx_id, e);
+    }
+
+    Some(FetchedContent {
+        tx_id: tx_id.to_string(),
+        content_type: cached.content_type,
+        bytes,
+    })
+}
+
+async fn cache_put(content: &FetchedContent) {
+    let storage = match StorageService::open().await {
+        Ok(storage) => storage,
+        Err(e) => {
+            log::warn!("Content cache unavailable: {}", e);
+            return;
+        }
+    };
+
+    let cached = CachedContent {
+        content_type: content.content_type.clone(),
+        data: STANDARD.encode(&content.bytes),
+    };
+    let entry = CacheEntry {
+        tx_id: content.tx_id.clone(),
+        size: content.bytes.len() as u64,
+        last_accessed: chrono::Utc::now().timestamp_millis(),
+    };
+
+    if let Err(e) = storage.put(CONTENT_CACHE_STORE, &content.tx_id, &cached).await {
+        log::warn!("Failed to cache {}: {}", content.tx_id, e);
+        return;
+    }
+    if let Err(e) = storage.put(CONTENT_CACHE_INDEX_STORE, &content.tx_id, &entry).await {
+        log::warn!("Failed to index cached {}: {}", content.tx_id, e);
+    }
+
+    evict_lru(&storage).await;
+}
+
+/// Drop least-recently-used items until the cache fits its budget
+async fn evict_lru(storage: &StorageService) {
+    let mut entries: Vec<CacheEntry> = match storage.get_all(CONTENT_CACHE_INDEX_STORE).await {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    let mut total: u64 = entries.iter().map(|entry| entry.size).sum();
+    if total <= CONTENT_CACHE_MAX_BYTES {
+        return;
+    }
+
+    entries.sort_by_key(|entry| entry.last_accessed);
+    for entry in entries {
+        if total <= CONTENT_CACHE_MAX_BYTES {
+            break;
+        }
+
+        let _ = storage.delete(CONTENT_CACHE_STORE, &entry.tx_id).await;
+        let _ = storage.delete(CONTENT_CACHE_INDEX_STORE, &entry.tx_id).await;
+        total = total.saturating_sub(entry.size);
+        log::info!("🧹 Evicted {} from content cache", entry.tx_id);
+    }
+}
+
+/// Hook returning the download progress of an item
+pub fn use_download_progress(tx_id: String) -> Memo<Option<DownloadProgress>> {
+    use_memo(move || use_download_progress_state().read().get(&tx_id).copied())
+}
@@ -0,0 +1,39 @@
+use crate::models::{ContentMetadata, SPIRITUAL_CONTENT_TYPE};
+use crate::services::graphql::{GraphQLClient, GraphQLError};
+use crate::services::hide_registry::is_hidden;
+
+/// Finds archive items already holding a file, by its `File-Hash` tag
+pub struct DedupeService {
+    graphql: GraphQLClient,
+}
+
+impl DedupeService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+        }
+    }
+
+    /// Oldest visible item whose data has SHA-256 `sha256` (hex), if any
+    ///
+    /// Only items uploaded since `File-Hash` was added to the tag schema
+    /// can be found.
+    pub async fn find_existing(&self, sha256: &str) -> Result<Option<ContentMetadata>, GraphQLError> {
+        let found = self.graphql
+            .find_transactions(&[], &[("Type", &[SPIRITUAL_CONTENT_TYPE]), ("File-Hash", &[sha256])])
+            .await?;
+
+        Ok(found
+            .iter()
+            .filter(|tx| !is_hidden(&tx.id))
+            .filter_map(|tx| ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp))
+            // Pending items have no timestamp yet and are the newest
+            .min_by_key(|item| item.block_timestamp.unwrap_or(i64::MAX)))
+    }
+}
+
+impl Default for DedupeService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,167 @@
+use chrono::{Days, NaiveDate};
+use std::collections::{HashMap, HashSet};
+
+use crate::models::metadata::scripture_book;
+use crate::models::{BrowseFilters, ContentMetadata};
+use crate::services::bookmarks::saved_items;
+use crate::services::follows::followed;
+use crate::services::search::{filter_local, find_local};
+use crate::services::series::all_played;
+use crate::utils::citation::permanent_url;
+
+/// Days covered by a digest, including today
+const DIGEST_DAYS: u64 = 7;
+
+/// Most topics and items per topic shown under "Trending"
+const MAX_TRENDING_TOPICS: usize = 5;
+const MAX_ITEMS_PER_TOPIC: usize = 5;
+
+/// New items in one scripture book the listener tends to hear
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrendingTopic {
+    pub book: String,
+    pub items: Vec<ContentMetadata>,
+}
+
+/// A week of new content for this browser's follows and listening habits
+#[derive(Debug, Clone, PartialEq)]
+pub struct Digest {
+    pub week_start: NaiveDate,
+    pub week_end: NaiveDate,
+    /// New items from followed speakers and series, newest first
+    pub followed: Vec<ContentMetadata>,
+    pub trending: Vec<TrendingTopic>,
+}
+
+impl Digest {
+    pub fn is_empty(&self) -> bool {
+        self.followed.is_empty() && self.trending.is_empty()
+    }
+
+    pub fn title(&self) -> String {
+        format!(
+            "Faithful Archive digest: {} – {}",
+            self.week_start.format("%b %-d"),
+            self.week_end.format("%b %-d, %Y"),
+        )
+    }
+
+    pub fn filename(&self) -> String {
+        format!("faithful-archive-digest-{}.md", self.week_end.format("%Y-%m-%d"))
+    }
+
+    /// Markdown rendering for export and sharing
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("# {}\n", self.title());
+
+        if !self.followed.is_empty() {
+            out.push_str("\n## From people and series you follow\n\n");
+            for item in &self.followed {
+                out.push_str(&markdown_item(item));
+            }
+        }
+
+        for topic in &self.trending {
+            out.push_str(&format!("\n## Trending in {}\n\n", topic.book));
+            for item in &topic.items {
+                out.push_str(&markdown_item(item));
+            }
+        }
+
+        if self.is_empty() {
+            out.push_str("\nNothing new this week.\n");
+        }
+        out
+    }
+}
+
+fn markdown_item(item: &ContentMetadata) -> String {
+    match item.speaker.as_ref() {
+        Some(speaker) => format!("- [{}]({}) — {}\n", item.title, permanent_url(&item.tx_id), speaker),
+        None => format!("- [{}]({})\n", item.title, permanent_url(&item.tx_id)),
+    }
+}
+
+/// Assembles weekly digests from the local search index
+///
+/// Nothing is fetched: the digest reflects whatever the background index
+/// sync has already cached, so it works offline and stays private.
+pub struct DigestService;
+
+impl DigestService {
+    /// Digest for the seven days ending on `today`
+    pub fn generate(today: NaiveDate) -> Digest {
+        let week_start = today.checked_sub_days(Days::new(DIGEST_DAYS - 1)).unwrap_or(today);
+        let new_items = filter_local(&BrowseFilters {
+            date_from: Some(week_start),
+            date_to: Some(today),
+            ..BrowseFilters::default()
+        });
+
+        let follows = followed();
+        let (followed, others): (Vec<ContentMetadata>, Vec<ContentMetadata>) = new_items
+            .into_iter()
+            .partition(|item| follows.iter().any(|target| target.matches(item)));
+
+        Digest {
+            week_start,
+            week_end: today,
+            followed,
+            trending: trending_topics(&others, &listening_topics()),
+        }
+    }
+}
+
+/// Scripture books of saved and played items, weighted by how often
+/// each appears
+fn listening_topics() -> HashMap<String, usize> {
+    let saved = saved_items();
+    let played = all_played().into_iter().filter_map(|tx_id| find_local(&tx_id));
+
+    let mut seen = HashSet::new();
+    let mut topics = HashMap::new();
+    for item in saved.into_iter().chain(played) {
+        if !seen.insert(item.tx_id.clone()) {
+            continue;
+        }
+        let books: HashSet<&str> = item.scripture_refs.iter().map(|r| scripture_book(r)).collect();
+        for book in books {
+            *topics.entry(book.to_string()).or_insert(0) += 1;
+        }
+    }
+    topics
+}
+
+/// Listening topics with new items, busiest first; ties go to the topic
+/// listened to most
+fn trending_topics(items: &[ContentMetadata], topics: &HashMap<String, usize>) -> Vec<TrendingTopic> {
+    let mut trending: Vec<(usize, TrendingTopic)> = topics
+        .iter()
+        .filter_map(|(book, weight)| {
+            let matching: Vec<ContentMetadata> = items
+                .iter()
+                .filter(|item| item.scripture_refs.iter().any(|r| scripture_book(r).eq_ignore_ascii_case(book)))
+                .cloned()
+                .collect();
+            if matching.is_empty() {
+                return None;
+            }
+            Some((*weight, TrendingTopic { book: book.clone(), items: matching }))
+        })
+        .collect();
+
+    trending.sort_by(|(a_weight, a), (b_weight, b)| {
+        b.items.len()
+            .cmp(&a.items.len())
+            .then(b_weight.cmp(a_weight))
+            .then(a.book.cmp(&b.book))
+    });
+    trending
+        .into_iter()
+        .take(MAX_TRENDING_TOPICS)
+        .map(|(_, mut topic)| {
+            topic.items.truncate(MAX_ITEMS_PER_TOPIC);
+            topic
+        })
+        .collect()
+}
@@ -0,0 +1,303 @@
+//! Download manager with integrity checks
+//!
+//! Queued items download one at a time through the gateways and are
+//! checked against what was recorded on chain before the browser's save
+//! dialog sees them: the `File-Hash` tag when the uploader set one, or the
+//! `data_root` of a base-layer transaction. Bundled items uploaded before
+//! `File-Hash` existed have nothing to check against and are saved as
+//! unchecked. Data that doesn't match is never saved.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use dioxus::prelude::*;
+use std::cell::Cell;
+
+use crate::models::ContentMetadata;
+use crate::services::content_fetch::{ContentFetchError, ContentFetchService};
+use crate::services::gateway::GatewayManager;
+use crate::services::upload::extension_for;
+use crate::services::verification::transaction_record;
+use crate::services::worker::digest_file;
+use crate::utils::crypto::sha256_hex;
+use crate::utils::merkle::data_root;
+use crate::utils::download::download_bytes;
+
+/// What a download's data was checked against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Integrity {
+    /// SHA-256 of the data equals the item's `File-Hash` tag
+    FileHash,
+    /// Merkle root of the data equals the transaction's `data_root`
+    DataRoot,
+    /// Nothing on chain to check against
+    Unchecked,
+}
+
+impl Integrity {
+    pub fn label(self) -> &'static str {
+        match self {
+            Integrity::FileHash => "Verified against the uploader's file hash",
+            Integrity::DataRoot => "Verified against the transaction's data root",
+            Integrity::Unchecked => "Saved without verification; no hash was recorded",
+        }
+    }
+}
+
+/// Where a queued download is up to
+#[derive(Debug, Clone, PartialEq)]
+pub enum DownloadStatus {
+    Queued,
+    Downloading,
+    Verifying,
+    Saved(Integrity),
+    Failed(String),
+}
+
+impl DownloadStatus {
+    /// Saved or failed, so no longer waiting on the queue
+    pub fn is_finished(&self) -> bool {
+        matches!(self, DownloadStatus::Saved(_) | DownloadStatus::Failed(_))
+    }
+}
+
+/// An item in the downloads panel
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadEntry {
+    pub tx_id: String,
+    pub title: String,
+    pub filename: String,
+    pub content_type: String,
+    pub file_hash: Option<String>,
+    pub status: DownloadStatus,
+}
+
+impl DownloadEntry {
+    pub fn for_item(item: &ContentMetadata) -> Self {
+        Self {
+            tx_id: item.tx_id.clone(),
+            title: item.title.clone(),
+            filename: download_filename(&item.title, &item.content_type),
+            content_type: item.content_type.clone(),
+            file_hash: item.file_hash.clone(),
+            status: DownloadStatus::Queued,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum DownloadError {
+    Fetch(ContentFetchError),
+    /// The data differs from what was recorded on chain
+    Mismatch(Integrity),
+    SaveFailed(String),
+}
+
+impl std::fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DownloadError::Fetch(e) => write!(f, "{}", e),
+            DownloadError::Mismatch(Integrity::FileHash) => write!(f, "The downloaded data doesn't match the uploader's file hash, so it wasn't saved"),
+            DownloadError::Mismatch(_) => write!(f, "The downloaded data doesn't match the transaction's data root, so it wasn't saved"),
+            DownloadError::SaveFailed(msg) => write!(f, "Failed to save the file: {}", msg),
+        }
+    }
+}
+
+impl From<ContentFetchError> for DownloadError {
+    fn from(error: ContentFetchError) -> Self {
+        DownloadError::Fetch(error)
+    }
+}
+
+// Downloads in the order they were queued
+fn use_downloads_state() -> &'static GlobalSignal<Vec<DownloadEntry>> {
+    static DOWNLOADS: GlobalSignal<Vec<DownloadEntry>> = GlobalSignal::new(Vec::new);
+    &DOWNLOADS
+}
+
+thread_local! {
+    static QUEUE_RUNNING: Cell<bool> = const { Cell::new(false) };
+}
+
+fn set_status(tx_id: &str, status: DownloadStatus) {
+    if let Some(entry) = use_downloads_state().write().iter_mut().find(|entry| entry.tx_id == tx_id) {
+        entry.status = status;
+    }
+}
+
+/// Downloads, verifies and saves a single item
+pub struct DownloadService {
+    fetcher: ContentFetchService,
+    gateways: GatewayManager,
+}
+
+impl DownloadService {
+    pub fn new() -> Self {
+        Self {
+            fetcher: ContentFetchService::new(),
+            gateways: GatewayManager::new(),
+        }
+    }
+
+    /// Fetch `entry`'s data, check it and offer it to the browser
+    pub async fn download(&self, entry: &DownloadEntry) -> Result<Integrity, DownloadError> {
+        set_status(&entry.tx_id, DownloadStatus::Downloading);
+        // Straight from the gateway rather than the content cache, so the
+        // check covers what the network serves
+        let mut bytes = Vec::new();
+        let content_type = self.fetcher.stream(&entry.tx_id, |chunk| bytes.extend_from_slice(chunk)).await?;
+
+        set_status(&entry.tx_id, DownloadStatus::Verifying);
+        let integrity = self.verify(&entry.tx_id, entry.file_hash.as_deref(), &bytes).await?;
+
+        let content_type = if entry.content_type.is_empty() { content_type } else { entry.content_type.clone() };
+        download_bytes(&entry.filename, &bytes, &content_type).map_err(DownloadError::SaveFailed)?;
+        log::info!("💾 Saved {} ({:?})", entry.tx_id, integrity);
+        Ok(integrity)
+    }
+
+    /// Check `data` against the item's `File-Hash`, or failing that the
+    /// transaction's `data_root`
+    pub async fn verify(&self, tx_id: &str, file_hash: Option<&str>, data: &[u8]) -> Result<Integrity, DownloadError> {
+        if let Some(expected) = file_hash {
+            let actual = match digest_file(data, |_| {}).await {
+                Ok(digest) => digest.sha256,
+                Err(e) => {
+                    log::warn!("Hashing {} on the main thread: {}", tx_id, e);
+                    sha256_hex(data)
+                }
+            };
+            return if actual.eq_ignore_ascii_case(expected) {
+                Ok(Integrity::FileHash)
+            } else {
+                Err(DownloadError::Mismatch(Integrity::FileHash))
+            };
+        }
+
+        match self.data_root(tx_id).await {
+            Some(expected) if data_root(data).is_ok_and(|root| URL_SAFE_NO_PAD.encode(root) == expected) => Ok(Integrity::DataRoot),
+            Some(_) => Err(DownloadError::Mismatch(Integrity::DataRoot)),
+            None => Ok(Integrity::Unchecked),
+        }
+    }
+
+    // `data_root` of a base-layer transaction; bundled DataItems have none
+    async fn data_root(&self, tx_id: &str) -> Option<String> {
+        let record = match transaction_record(&self.gateways, tx_id).await {
+            Ok(record) => record?,
+            Err(e) => {
+                log::warn!("Couldn't look up the data root of {}: {}", tx_id, e);
+                return None;
+            }
+        };
+        Some(record.data_root).filter(|root| !root.is_empty())
+    }
+}
+
+impl Default for DownloadService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Add `item` to the downloads queue, or queue it again if it finished
+pub fn queue_download(item: &ContentMetadata) {
+    {
+        let mut downloads = use_downloads_state().write();
+        match downloads.iter_mut().find(|entry| entry.tx_id == item.tx_id) {
+            Some(entry) if !entry.status.is_finished() => return,
+            Some(entry) => entry.status = DownloadStatus::Queued,
+            None => downloads.push(DownloadEntry::for_item(item)),
+        }
+    }
+    run_queue();
+}
+
+/// Try a failed download again
+pub fn retry_download(tx_id: &str) {
+    set_status(tx_id, DownloadStatus::Queued);
+    run_queue();
+}
+
+/// Drop a download from the panel; one in progress still finishes
+pub fn remove_download(tx_id: &str) {
+    use_downloads_state().write().retain(|entry| entry.tx_id != tx_id);
+}
+
+/// Drop every saved or failed download from the panel
+pub fn clear_finished_downloads() {
+    use_downloads_state().write().retain(|entry| !entry.status.is_finished());
+}
+
+// Work through queued downloads one at a time, outliving whichever
+// component queued them
+fn run_queue() {
+    if QUEUE_RUNNING.with(|running| running.replace(true)) {
+        return;
+    }
+    spawn_forever(async {
+        let service = DownloadService::new();
+        loop {
+            let next = use_downloads_state()
+                .peek()
+                .iter()
+                .find(|entry| entry.status == DownloadStatus::Queued)
+                .cloned();
+            let Some(entry) = next else {
+                break;
+            };
+            let status = match service.download(&entry).await {
+                Ok(integrity) => DownloadStatus::Saved(integrity),
+                Err(e) => {
+                    log::warn!("Download of {} failed: {}", entry.tx_id, e);
+                    DownloadStatus::Failed(e.to_string())
+                }
+            };
+            set_status(&entry.tx_id, status);
+        }
+        QUEUE_RUNNING.with(|running| running.set(false));
+    });
+}
+
+/// Every download in the panel, oldest first
+pub fn use_downloads() -> Vec<DownloadEntry> {
+    use_downloads_state().read().clone()
+}
+
+/// Suggested name for a saved item: its title plus an extension for its
+/// content type
+pub fn download_filename(title: &str, content_type: &str) -> String {
+    let stem: String = title
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-");
+    let stem = if stem.is_empty() { "faithful-archive-item".to_string() } else { stem };
+    format!("{}.{}", stem, extension_for(content_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn filenames_come_from_titles() {
+        assert_eq!(download_filename("The Sower: Mark 4", "audio/mpeg"), "The-Sower-Mark-4.mp3");
+        assert_eq!(download_filename("  ¿Qué? ", "application/pdf"), "Qué.pdf");
+        assert_eq!(download_filename("/../", "application/x-unknown"), "faithful-archive-item.bin");
+    }
+
+    #[wasm_bindgen_test]
+    async fn file_hash_mismatches_are_rejected() {
+        let service = DownloadService::new();
+        let data = b"In the beginning";
+        let hash = sha256_hex(data);
+        assert_eq!(service.verify("tx", Some(&hash), data).await.unwrap(), Integrity::FileHash);
+        assert!(matches!(
+            service.verify("tx", Some(&hash), b"In the end").await,
+            Err(DownloadError::Mismatch(Integrity::FileHash))
+        ));
+    }
+}
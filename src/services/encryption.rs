@@ -0,0 +1,205 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::models::{EncryptionInfo, KeySource};
+use crate::services::wallet::{connected_wallet, WalletError};
+use crate::utils::constants::MIN_PASSPHRASE_CHARS;
+use crate::utils::crypto::{decrypt_with_key, derive_key, encrypt_with_key, CryptoError};
+
+/// `Encryption` tag value of encrypted items
+pub const ENCRYPTION_ALGORITHM: &str = "AES-256-GCM";
+
+#[derive(Debug, Clone)]
+pub enum EncryptionError {
+    Crypto(CryptoError),
+    Wallet(WalletError),
+    MissingKey(String),
+}
+
+impl std::fmt::Display for EncryptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncryptionError::Crypto(CryptoError::DecryptionFailed(_)) => {
+                write!(f, "Could not decrypt: the key is wrong or the data is damaged")
+            }
+            EncryptionError::Crypto(e) => write!(f, "{}", e),
+            EncryptionError::Wallet(e) => write!(f, "The wallet could not handle the content key: {}", e),
+            EncryptionError::MissingKey(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl From<CryptoError> for EncryptionError {
+    fn from(e: CryptoError) -> Self {
+        EncryptionError::Crypto(e)
+    }
+}
+
+impl From<WalletError> for EncryptionError {
+    fn from(e: WalletError) -> Self {
+        EncryptionError::Wallet(e)
+    }
+}
+
+/// Key chosen for an encrypted upload
+#[derive(Clone, PartialEq)]
+pub enum ContentKey {
+    Passphrase(String),
+    /// A random key only the connected wallet can unwrap
+    Wallet,
+}
+
+impl std::fmt::Debug for ContentKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContentKey::Passphrase(_) => write!(f, "Passphrase(..)"),
+            ContentKey::Wallet => write!(f, "Wallet"),
+        }
+    }
+}
+
+/// Ciphertext plus the tags a reader needs to decrypt it
+#[derive(Debug, Clone)]
+pub struct EncryptedContent {
+    pub data: Vec<u8>,
+    pub tags: Vec<(String, String)>,
+}
+
+fn random_bytes<const N: usize>() -> Result<[u8; N], EncryptionError> {
+    let mut bytes = [0u8; N];
+    getrandom::getrandom(&mut bytes).map_err(|e| CryptoError::EncryptionFailed(e.to_string()))?;
+    Ok(bytes)
+}
+
+/// Encrypt an upload's data before it is signed
+///
+/// Only the data is encrypted; title, description and other tags stay
+/// public.
+pub async fn encrypt_content(plaintext: &[u8], content_type: &str, key: &ContentKey) -> Result<EncryptedContent, EncryptionError> {
+    let mut tags = vec![
+        ("Encryption".to_string(), ENCRYPTION_ALGORITHM.to_string()),
+        ("Original-Content-Type".to_string(), content_type.to_string()),
+    ];
+
+    let raw_key = match key {
+        ContentKey::Passphrase(passphrase) => {
+            if passphrase.is_empty() {
+                return Err(EncryptionError::MissingKey("Enter a passphrase".to_string()));
+            }
+            if passphrase.chars().count() < MIN_PASSPHRASE_CHARS {
+                return Err(EncryptionError::MissingKey(format!(
+                    "Use a passphrase of at least {} characters",
+                    MIN_PASSPHRASE_CHARS
+                )));
+            }
+            let salt = random_bytes::<16>()?;
+            tags.push(("Encryption-Key".to_string(), KeySource::Passphrase.tag_value().to_string()));
+            tags.push(("Encryption-Salt".to_string(), STANDARD.encode(salt)));
+            derive_key(passphrase, &salt).await?
+        }
+        ContentKey::Wallet => {
+            let raw_key = random_bytes::<32>()?;
+            let wrapped = connected_wallet().await?.encrypt(&raw_key).await?;
+            tags.push(("Encryption-Key".to_string(), KeySource::Wallet.tag_value().to_string()));
+            tags.push(("Encryption-Wrapped-Key".to_string(), STANDARD.encode(wrapped)));
+            raw_key.to_vec()
+        }
+    };
+
+    let data = encrypt_with_key(plaintext, &raw_key).await?;
+    Ok(EncryptedContent { data, tags })
+}
+
+/// Decrypt an item's data with a passphrase, or with the connected wallet
+/// for wallet-wrapped keys
+pub async fn decrypt_content(envelope: &[u8], info: &EncryptionInfo, passphrase: Option<&str>) -> Result<Vec<u8>, EncryptionError> {
+    let decode = |value: &Option<String>, name: &str| {
+        value
+            .as_deref()
+            .and_then(|value| STANDARD.decode(value).ok())
+            .ok_or_else(|| EncryptionError::MissingKey(format!("The item's {} tag is missing or invalid", name)))
+    };
+
+    let raw_key = match info.key_source {
+        KeySource::Passphrase => {
+            let passphrase = passphrase
+                .filter(|passphrase| !passphrase.is_empty())
+                .ok_or_else(|| EncryptionError::MissingKey("Enter the passphrase".to_string()))?;
+            derive_key(passphrase, &decode(&info.salt, "Encryption-Salt")?).await?
+        }
+        KeySource::Wallet => {
+            let wrapped = decode(&info.wrapped_key, "Encryption-Wrapped-Key")?;
+            connected_wallet().await?.decrypt(&wrapped).await?
+        }
+    };
+
+    Ok(decrypt_with_key(envelope, &raw_key).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ContentMetadata;
+    use wasm_bindgen_test::*;
+
+    const PASSPHRASE: &str = "grace upon grace";
+
+    fn load_bridge() {
+        js_sys::eval(include_str!("../../public/crypto-bridge.js")).unwrap();
+    }
+
+    async fn encrypted(plaintext: &[u8]) -> (EncryptedContent, EncryptionInfo) {
+        let content = encrypt_content(plaintext, "audio/mpeg", &ContentKey::Passphrase(PASSPHRASE.to_string()))
+            .await
+            .unwrap();
+        let mut tags = vec![("Title".to_string(), "Sermon".to_string())];
+        tags.extend(content.tags.iter().cloned());
+        let info = ContentMetadata::from_tags("tx", "owner", &tags, None, None)
+            .and_then(|metadata| metadata.encryption)
+            .unwrap();
+        (content, info)
+    }
+
+    #[wasm_bindgen_test]
+    async fn passphrase_content_round_trips() {
+        load_bridge();
+        let plaintext = b"The word of the Lord endures forever";
+        let (content, info) = encrypted(plaintext).await;
+
+        assert_ne!(content.data, plaintext.to_vec());
+        let decrypted = decrypt_content(&content.data, &info, Some(PASSPHRASE)).await.unwrap();
+        assert_eq!(decrypted, plaintext.to_vec());
+    }
+
+    #[wasm_bindgen_test]
+    async fn tags_describe_how_to_decrypt() {
+        load_bridge();
+        let (content, info) = encrypted(b"psalm").await;
+
+        let tag = |name: &str| content.tags.iter().find(|(tag_name, _)| tag_name == name).map(|(_, value)| value.as_str());
+        assert_eq!(tag("Encryption"), Some(ENCRYPTION_ALGORITHM));
+        assert_eq!(info.key_source, KeySource::Passphrase);
+        assert_eq!(info.content_type, "audio/mpeg");
+        assert_eq!(info.salt.as_deref().map(|salt| STANDARD.decode(salt).unwrap().len()), Some(16));
+        assert_eq!(info.wrapped_key, None);
+    }
+
+    #[wasm_bindgen_test]
+    async fn wrong_passphrase_fails() {
+        load_bridge();
+        let (content, info) = encrypted(b"hidden manna").await;
+
+        let result = decrypt_content(&content.data, &info, Some("grace upon grass")).await;
+        assert!(matches!(result, Err(EncryptionError::Crypto(CryptoError::DecryptionFailed(_)))));
+        let result = decrypt_content(&content.data, &info, None).await;
+        assert!(matches!(result, Err(EncryptionError::MissingKey(_))));
+    }
+
+    #[wasm_bindgen_test]
+    async fn empty_and_short_passphrases_are_rejected() {
+        load_bridge();
+        for passphrase in ["", "amen"] {
+            let result = encrypt_content(b"data", "text/plain", &ContentKey::Passphrase(passphrase.to_string())).await;
+            assert!(matches!(result, Err(EncryptionError::MissingKey(_))), "{:?} was accepted", passphrase);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use serde::Serialize;
+
+use crate::models::SubmittedItem;
+use crate::services::content_fetch::{ContentFetchError, ContentFetchService};
+use crate::services::graphql::{GraphQLClient, GraphQLError, TransactionDetails};
+use crate::services::health::{HealthMonitor, ItemHealth};
+use crate::services::hide_registry::{entries_for, HideEntry};
+use crate::services::rights_claims::{ClaimTracking, RightsClaimService};
+use crate::services::storage::{StorageService, SUBMITTED_ITEMS_STORE};
+use crate::services::upload::extension_for;
+use crate::utils::citation::{citations_text, permanent_url};
+use crate::utils::constants::APP_NAME;
+use crate::services::worker::digest_file;
+use crate::utils::crypto::sha256_hex;
+use crate::utils::tar::TarBuilder;
+
+#[derive(Debug, Clone)]
+pub enum EvidenceError {
+    LookupFailed(GraphQLError),
+    NotFound,
+    DataFailed(ContentFetchError),
+    SerializationFailed(String),
+}
+
+impl std::fmt::Display for EvidenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvidenceError::LookupFailed(e) => write!(f, "Could not look up item: {}", e),
+            EvidenceError::NotFound => write!(f, "Item not found on the gateway"),
+            EvidenceError::DataFailed(e) => write!(f, "Could not download item data: {}", e),
+            EvidenceError::SerializationFailed(msg) => write!(f, "Could not write evidence bundle: {}", msg),
+        }
+    }
+}
+
+/// Top-level description of an evidence bundle
+#[derive(Serialize)]
+struct Manifest<'a> {
+    generator: &'a str,
+    tx_id: &'a str,
+    exported_at: String,
+    permanent_url: String,
+    data_file: &'a str,
+    data_sha256: String,
+    data_size: usize,
+    files: &'a [&'a str],
+}
+
+/// Moderation history of the item
+#[derive(Serialize)]
+struct ModerationRecord {
+    hide_registry: Vec<HideEntry>,
+    rights_claims: Vec<ClaimTracking>,
+}
+
+/// Independent checks that the item is on-chain and retrievable
+#[derive(Serialize)]
+struct VerificationRecord {
+    gateway_availability: ItemHealth,
+    /// Confirmation tracking from this browser, if it submitted the item
+    local_submission: Option<SubmittedItem>,
+}
+
+/// Packages an item and everything needed to cite or verify it
+///
+/// The bundle is a tar archive holding the raw data byte-for-byte plus JSON
+/// records of the signed transaction, block inclusion, moderation verdicts
+/// and verification receipts.
+pub struct EvidenceService {
+    graphql: GraphQLClient,
+}
+
+impl EvidenceService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+        }
+    }
+
+    /// Build the evidence bundle for `tx_id`
+    pub async fn build_bundle(&self, tx_id: &str) -> Result<Vec<u8>, EvidenceError> {
+        let details = self.graphql
+            .transaction_details(tx_id)
+            .await
+            .map_err(EvidenceError::LookupFailed)?
+            .ok_or(EvidenceError::NotFound)?;
+
+        let content = ContentFetchService::new()
+            .fetch(tx_id)
+            .await
+            .map_err(EvidenceError::DataFailed)?;
+
+        let rights_claims = match RightsClaimService::new().claims_for_item(tx_id).await {
+            Ok(claims) => claims,
+            Err(e) => {
+                log::warn!("Evidence bundle without rights claims: {}", e);
+                Vec::new()
+            }
+        };
+        let moderation = ModerationRecord {
+            hide_registry: entries_for(tx_id),
+            rights_claims,
+        };
+
+        let verification = VerificationRecord {
+            gateway_availability: HealthMonitor::new().check_item(tx_id).await,
+            local_submission: local_submission(tx_id).await,
+        };
+
+        let now = chrono::Utc::now();
+        let data_file = format!("data.{}", extension_for(&content.content_type));
+        let metadata = details.metadata();
+        let mut files = vec![data_file.as_str(), "transaction.json", "moderation.json", "verification.json"];
+        if metadata.is_some() {
+            files.push("citation.txt");
+        }
+        let data_sha256 = match digest_file(&content.bytes, |_| {}).await {
+            Ok(digest) => digest.sha256,
+            Err(e) => {
+                log::warn!("Hashing in the data worker failed, hashing on the main thread: {}", e);
+                sha256_hex(&content.bytes)
+            }
+        };
+        let manifest = Manifest {
+            generator: APP_NAME,
+            tx_id,
+            exported_at: now.to_rfc3339(),
+            permanent_url: permanent_url(tx_id),
+            data_file: &data_file,
+            data_sha256,
+            data_size: content.bytes.len(),
+            files: &files,
+        };
+
+        let mut tar = TarBuilder::new();
+        let modified = now.timestamp();
+        let prefix = format!("{}-evidence", tx_id);
+        tar.add_file(&format!("{}/manifest.json", prefix), &to_json(&manifest)?, modified);
+        tar.add_file(&format!("{}/{}", prefix, data_file), &content.bytes, modified);
+        tar.add_file(&format!("{}/transaction.json", prefix), &to_json::<TransactionDetails>(&details)?, modified);
+        tar.add_file(&format!("{}/moderation.json", prefix), &to_json(&moderation)?, modified);
+        tar.add_file(&format!("{}/verification.json", prefix), &to_json(&verification)?, modified);
+        if let Some(metadata) = metadata.as_ref() {
+            tar.add_file(&format!("{}/citation.txt", prefix), citations_text(metadata).as_bytes(), modified);
+        }
+
+        log::info!("🧾 Built evidence bundle for {}", tx_id);
+        Ok(tar.finish())
+    }
+
+    /// Suggested download name for a bundle
+    pub fn bundle_filename(tx_id: &str) -> String {
+        format!("{}-evidence.tar", tx_id)
+    }
+}
+
+impl Default for EvidenceService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn local_submission(tx_id: &str) -> Option<SubmittedItem> {
+    let storage = StorageService::open().await.ok()?;
+    storage.get(SUBMITTED_ITEMS_STORE, tx_id).await.ok()?
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<Vec<u8>, EvidenceError> {
+    serde_json::to_vec_pretty(value).map_err(|e| EvidenceError::SerializationFailed(e.to_string()))
+}
@@ -0,0 +1,368 @@
+//! Podcast feeds for uploaders and series
+//!
+//! Builds an RSS 2.0 feed with the iTunes and Podcasting 2.0 extensions
+//! from an uploader's audio and video, or a series in order, with
+//! enclosures pointing at the primary gateway. The app has no server to
+//! host the feed, so it can be copied into a podcast host as is or
+//! published as a DataItem, whose gateway URL then serves as the feed
+//! address. A published feed is a snapshot: republish it after new uploads.
+
+use chrono::DateTime;
+
+use crate::models::{ContentMetadata, SPIRITUAL_CONTENT_TYPE};
+use crate::services::graphql::{GraphQLClient, GraphQLError};
+use crate::services::hide_registry::is_hidden;
+use crate::services::profile::{ProfileError, ProfileService};
+use crate::services::series::{SeriesError, SeriesService};
+use crate::services::upload::{publish_with_wallet, PublishError};
+use crate::utils::citation::permanent_url;
+use crate::utils::constants::APP_NAME;
+
+/// `Type` tag of a published feed
+pub const PODCAST_FEED_TYPE: &str = "Podcast-Feed";
+
+#[derive(Debug, Clone)]
+pub enum FeedError {
+    LookupFailed(String),
+    Empty,
+    PublishFailed(PublishError),
+}
+
+impl std::fmt::Display for FeedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FeedError::LookupFailed(msg) => write!(f, "Could not build the feed: {}", msg),
+            FeedError::Empty => write!(f, "There is no audio or video to put in a feed yet"),
+            FeedError::PublishFailed(e) => write!(f, "Could not publish the feed: {}", e),
+        }
+    }
+}
+
+impl From<GraphQLError> for FeedError {
+    fn from(error: GraphQLError) -> Self {
+        FeedError::LookupFailed(error.to_string())
+    }
+}
+
+impl From<ProfileError> for FeedError {
+    fn from(error: ProfileError) -> Self {
+        FeedError::LookupFailed(error.to_string())
+    }
+}
+
+impl From<SeriesError> for FeedError {
+    fn from(error: SeriesError) -> Self {
+        FeedError::LookupFailed(error.to_string())
+    }
+}
+
+/// What a feed collects
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeedSource {
+    /// Everything an address uploaded, newest first
+    Uploader(String),
+    /// A series in playback order
+    Series(String),
+}
+
+impl FeedSource {
+    /// Value of the `Feed-Source` tag on a published feed
+    pub fn tag_value(&self) -> String {
+        match self {
+            FeedSource::Uploader(address) => format!("uploader:{}", address),
+            FeedSource::Series(series_id) => format!("series:{}", series_id),
+        }
+    }
+}
+
+/// A feed ready to render
+#[derive(Debug, Clone, PartialEq)]
+pub struct PodcastFeed {
+    pub title: String,
+    pub description: String,
+    /// Page in the app the feed belongs to
+    pub link: String,
+    pub author: String,
+    pub image_url: Option<String>,
+    pub language: Option<String>,
+    /// Series feeds are numbered episodes meant to be played in order
+    pub serial: bool,
+    pub items: Vec<ContentMetadata>,
+}
+
+/// Whether `item` can go in a feed: audio or video that isn't encrypted
+pub fn is_feed_item(item: &ContentMetadata) -> bool {
+    item.encryption.is_none() && (item.content_type.starts_with("audio/") || item.content_type.starts_with("video/"))
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn rfc2822(timestamp: i64) -> Option<String> {
+    DateTime::from_timestamp(timestamp, 0).map(|date| date.to_rfc2822())
+}
+
+impl PodcastFeed {
+    /// The feed as an RSS 2.0 document
+    pub fn to_rss(&self) -> String {
+        let mut rss = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <rss version=\"2.0\" xmlns:itunes=\"http://www.itunes.com/dtds/podcast-1.0.dtd\" \
+             xmlns:podcast=\"https://podcastindex.org/namespace/1.0\">\n<channel>\n",
+        );
+        rss.push_str(&format!("<title>{}</title>\n", xml_escape(&self.title)));
+        rss.push_str(&format!("<link>{}</link>\n", xml_escape(&self.link)));
+        rss.push_str(&format!("<description>{}</description>\n", xml_escape(&self.description)));
+        if let Some(language) = self.language.as_ref() {
+            rss.push_str(&format!("<language>{}</language>\n", xml_escape(language)));
+        }
+        rss.push_str(&format!("<generator>{}</generator>\n", APP_NAME));
+        rss.push_str(&format!("<itunes:author>{}</itunes:author>\n", xml_escape(&self.author)));
+        rss.push_str(&format!("<itunes:summary>{}</itunes:summary>\n", xml_escape(&self.description)));
+        rss.push_str("<itunes:category text=\"Religion &amp; Spirituality\">\n<itunes:category text=\"Christianity\"/>\n</itunes:category>\n");
+        rss.push_str("<itunes:explicit>false</itunes:explicit>\n");
+        rss.push_str(&format!("<itunes:type>{}</itunes:type>\n", if self.serial { "serial" } else { "episodic" }));
+        if let Some(image_url) = self.image_url.as_ref() {
+            rss.push_str(&format!("<itunes:image href=\"{}\"/>\n", xml_escape(image_url)));
+            rss.push_str(&format!(
+                "<image>\n<url>{}</url>\n<title>{}</title>\n<link>{}</link>\n</image>\n",
+                xml_escape(image_url),
+                xml_escape(&self.title),
+                xml_escape(&self.link),
+            ));
+        }
+
+        for (index, item) in self.items.iter().enumerate() {
+            rss.push_str(&self.item_xml(index, item));
+        }
+
+        rss.push_str("</channel>\n</rss>\n");
+        rss
+    }
+
+    fn item_xml(&self, index: usize, item: &ContentMetadata) -> String {
+        let url = permanent_url(&item.tx_id);
+        let mut xml = String::from("<item>\n");
+        xml.push_str(&format!("<title>{}</title>\n", xml_escape(&item.title)));
+        xml.push_str(&format!("<link>{}</link>\n", xml_escape(&url)));
+        xml.push_str(&format!("<guid isPermaLink=\"false\">{}</guid>\n", item.tx_id));
+        if let Some(description) = item.description.as_ref() {
+            xml.push_str(&format!("<description>{}</description>\n", xml_escape(description)));
+        }
+        if let Some(date) = item.created_at.or(item.block_timestamp).and_then(rfc2822) {
+            xml.push_str(&format!("<pubDate>{}</pubDate>\n", date));
+        }
+        // Older items have no File-Size tag; podcast apps accept 0 as unknown
+        xml.push_str(&format!(
+            "<enclosure url=\"{}\" length=\"{}\" type=\"{}\"/>\n",
+            xml_escape(&url),
+            item.file_size.unwrap_or(0),
+            xml_escape(&item.content_type),
+        ));
+        if let Some(speaker) = item.speaker.as_ref() {
+            xml.push_str(&format!("<itunes:author>{}</itunes:author>\n", xml_escape(speaker)));
+        }
+        if let Some(duration) = item.duration_secs {
+            xml.push_str(&format!("<itunes:duration>{}</itunes:duration>\n", duration));
+        }
+        if let Some(cover_tx_id) = item.cover_tx_id.as_ref() {
+            xml.push_str(&format!("<itunes:image href=\"{}\"/>\n", xml_escape(&permanent_url(cover_tx_id))));
+        }
+        if self.serial {
+            xml.push_str(&format!("<itunes:episode>{}</itunes:episode>\n", index + 1));
+        }
+        if !item.scripture_refs.is_empty() {
+            xml.push_str(&format!("<itunes:keywords>{}</itunes:keywords>\n", xml_escape(&item.scripture_refs.join(","))));
+        }
+        xml.push_str("</item>\n");
+        xml
+    }
+}
+
+/// Builds and publishes podcast feeds
+pub struct FeedService {
+    graphql: GraphQLClient,
+}
+
+impl FeedService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+        }
+    }
+
+    /// Collect the items and channel details for `source`; `link` is the
+    /// app page the feed points listeners to
+    pub async fn build(&self, source: &FeedSource, link: &str) -> Result<PodcastFeed, FeedError> {
+        let feed = match source {
+            FeedSource::Uploader(address) => self.uploader_feed(address, link).await?,
+            FeedSource::Series(series_id) => self.series_feed(series_id, link).await?,
+        };
+        if feed.items.is_empty() {
+            return Err(FeedError::Empty);
+        }
+        Ok(feed)
+    }
+
+    async fn uploader_feed(&self, address: &str, link: &str) -> Result<PodcastFeed, FeedError> {
+        let profile = ProfileService::new().load(address).await?;
+        let transactions = self.graphql
+            .find_transactions(&[address], &[("Type", &[SPIRITUAL_CONTENT_TYPE])])
+            .await?;
+
+        let mut items: Vec<ContentMetadata> = transactions
+            .iter()
+            .filter(|tx| !is_hidden(&tx.id))
+            .filter_map(|tx| ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp))
+            .filter(is_feed_item)
+            .collect();
+        items.sort_by_key(|item| std::cmp::Reverse(item.created_at.or(item.block_timestamp).unwrap_or(i64::MAX)));
+
+        let author = profile
+            .as_ref()
+            .map(|profile| profile.ministry_name.clone())
+            .or_else(|| items.iter().find_map(|item| item.ministry.clone()))
+            .unwrap_or_else(|| address.to_string());
+        Ok(PodcastFeed {
+            title: author.clone(),
+            description: profile
+                .as_ref()
+                .and_then(|profile| profile.description.clone())
+                .unwrap_or_else(|| format!("Sermons and teaching from {}, archived on {}", author, APP_NAME)),
+            link: link.to_string(),
+            image_url: profile.and_then(|profile| profile.logo_tx_id).map(|tx_id| permanent_url(&tx_id)),
+            language: items.iter().find_map(|item| item.language.clone()),
+            serial: false,
+            author,
+            items,
+        })
+    }
+
+    async fn series_feed(&self, series_id: &str, link: &str) -> Result<PodcastFeed, FeedError> {
+        let series = SeriesService::new();
+        let manifest = series.load(series_id).await?;
+        let items: Vec<ContentMetadata> = series
+            .members(&manifest)
+            .await?
+            .into_iter()
+            .filter(|item| !is_hidden(&item.tx_id))
+            .filter(is_feed_item)
+            .collect();
+
+        let author = items
+            .iter()
+            .find_map(|item| item.ministry.clone().or_else(|| item.speaker.clone()))
+            .unwrap_or_else(|| APP_NAME.to_string());
+        Ok(PodcastFeed {
+            description: manifest
+                .description
+                .clone()
+                .unwrap_or_else(|| format!("{}, archived on {}", manifest.title, APP_NAME)),
+            title: manifest.title,
+            link: link.to_string(),
+            image_url: items.iter().find_map(|item| item.cover_tx_id.as_deref()).map(permanent_url),
+            language: items.iter().find_map(|item| item.language.clone()),
+            serial: true,
+            author,
+            items,
+        })
+    }
+
+    /// Publish `feed` from the connected wallet, returning its gateway URL
+    pub async fn publish(&self, source: &FeedSource, feed: &PodcastFeed) -> Result<String, FeedError> {
+        let tags = vec![
+            ("Content-Type".to_string(), "application/rss+xml".to_string()),
+            ("Type".to_string(), PODCAST_FEED_TYPE.to_string()),
+            ("Feed-Source".to_string(), source.tag_value()),
+            ("Title".to_string(), feed.title.clone()),
+        ];
+        let (_, tx_id) = publish_with_wallet(feed.to_rss().into_bytes(), tags)
+            .await
+            .map_err(FeedError::PublishFailed)?;
+        log::info!("🎙️ Published podcast feed for {} ({})", source.tag_value(), tx_id);
+        Ok(permanent_url(&tx_id))
+    }
+
+    /// Gateway URL of the newest feed `owner` published for `source`
+    pub async fn latest_published(&self, source: &FeedSource, owner: &str) -> Result<Option<String>, FeedError> {
+        let source_tag = source.tag_value();
+        let transactions = self.graphql
+            .find_transactions(&[owner], &[("Type", &[PODCAST_FEED_TYPE]), ("Feed-Source", &[source_tag.as_str()])])
+            .await?;
+        // Pending feeds have no timestamp yet and are the newest
+        Ok(transactions
+            .iter()
+            .max_by_key(|tx| tx.timestamp.unwrap_or(i64::MAX))
+            .map(|tx| permanent_url(&tx.id)))
+    }
+}
+
+impl Default for FeedService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn sermon(tx_id: &str, title: &str, content_type: &str) -> ContentMetadata {
+        let tags = vec![
+            ("Title".to_string(), title.to_string()),
+            ("Content-Type".to_string(), content_type.to_string()),
+            ("Speaker".to_string(), "Pastor Ann".to_string()),
+            ("Duration".to_string(), "1800".to_string()),
+            ("Created-At".to_string(), "1700000000".to_string()),
+        ];
+        ContentMetadata::from_tags(tx_id, "owner", &tags, None, None).unwrap()
+    }
+
+    fn feed(items: Vec<ContentMetadata>, serial: bool) -> PodcastFeed {
+        PodcastFeed {
+            title: "Grace & Truth".to_string(),
+            description: "Sunday sermons".to_string(),
+            link: "https://faithfularchive.org/series/abc".to_string(),
+            author: "Grace Church".to_string(),
+            image_url: None,
+            language: Some("en".to_string()),
+            serial,
+            items,
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn renders_items_with_enclosures() {
+        let rss = feed(vec![sermon("tx1", "Faith <and> Works", "audio/mpeg")], false).to_rss();
+
+        assert!(rss.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\""));
+        assert!(rss.contains("<title>Grace &amp; Truth</title>"));
+        assert!(rss.contains("<title>Faith &lt;and&gt; Works</title>"));
+        assert!(rss.contains(&format!("<enclosure url=\"{}\" length=\"0\" type=\"audio/mpeg\"/>", permanent_url("tx1"))));
+        assert!(rss.contains("<guid isPermaLink=\"false\">tx1</guid>"));
+        assert!(rss.contains("<pubDate>Tue, 14 Nov 2023 22:13:20 +0000</pubDate>"));
+        assert!(rss.contains("<itunes:duration>1800</itunes:duration>"));
+        assert!(rss.contains("<itunes:type>episodic</itunes:type>"));
+        assert!(!rss.contains("<itunes:episode>"));
+        assert!(rss.ends_with("</channel>\n</rss>\n"));
+    }
+
+    #[wasm_bindgen_test]
+    fn series_feeds_number_episodes() {
+        let rss = feed(vec![sermon("tx1", "Part 1", "audio/mpeg"), sermon("tx2", "Part 2", "video/mp4")], true).to_rss();
+        assert!(rss.contains("<itunes:type>serial</itunes:type>"));
+        assert!(rss.contains("<itunes:episode>2</itunes:episode>"));
+    }
+
+    #[wasm_bindgen_test]
+    fn only_plain_media_goes_in_feeds() {
+        assert!(is_feed_item(&sermon("tx1", "Sermon", "audio/mpeg")));
+        assert!(!is_feed_item(&sermon("tx2", "Notes", "application/pdf")));
+    }
+}
@@ -0,0 +1,187 @@
+//! Reading chosen files in slices
+//!
+//! A file from a file input is kept as the browser's `Blob` and read a
+//! slice at a time with `Blob.slice`, so hashing, previews and layer-1
+//! chunk uploads of a multi-gigabyte video never hold more than one slice
+//! in WASM memory. Files up to a limit are hashed by the data worker. Only paths that need every byte at once, such as
+//! encryption, read the whole file.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use dioxus::html::FileEngine;
+use sha2::{Digest, Sha256};
+use wasm_bindgen_futures::JsFuture;
+
+use crate::services::worker::{self, worker_available, FileDigest, HashInput, HASH_CHUNK_SIZE};
+use crate::utils::constants::{FILE_READ_CHUNK_SIZE, WORKER_HASH_MAX_BYTES};
+use crate::utils::crypto::sha256_hex;
+
+#[derive(Debug, Clone)]
+pub enum FileReadError {
+    /// The file couldn't be wrapped for reading
+    BlobFailed(String),
+    /// The browser couldn't read the file, e.g. it changed or was moved
+    /// after it was chosen
+    ReadFailed(String),
+}
+
+impl std::fmt::Display for FileReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileReadError::BlobFailed(msg) => write!(f, "Could not prepare the file: {}", msg),
+            FileReadError::ReadFailed(msg) => write!(f, "Could not read the file: {}", msg),
+        }
+    }
+}
+
+/// A file on the uploader's device, read on demand
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalFile {
+    name: String,
+    blob: web_sys::Blob,
+}
+
+impl LocalFile {
+    /// A file chosen in a file input, without reading it
+    pub async fn from_engine(files: &Arc<dyn FileEngine>, name: &str) -> Option<Self> {
+        match files.get_native_file(name).await?.downcast::<web_sys::File>() {
+            Ok(file) => Some(Self { name: name.to_string(), blob: web_sys::Blob::from(*file) }),
+            // Renderers without browser files can only hand over the bytes
+            Err(_) => Self::from_bytes(name, &files.read_file(name).await?, "").ok(),
+        }
+    }
+
+    /// Wrap bytes already in memory, such as a trimmed recording
+    pub fn from_bytes(name: &str, bytes: &[u8], content_type: &str) -> Result<Self, FileReadError> {
+        let parts = js_sys::Array::new();
+        parts.push(&js_sys::Uint8Array::from(bytes));
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type(content_type);
+        let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+            .map_err(|e| FileReadError::BlobFailed(format!("{:?}", e)))?;
+        Ok(Self { name: name.to_string(), blob })
+    }
+
+    /// Wrap a Blob put together in the page, such as a signed DataItem
+    pub fn from_blob(name: &str, blob: web_sys::Blob) -> Self {
+        Self { name: name.to_string(), blob }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn size(&self) -> u64 {
+        self.blob.size() as u64
+    }
+
+    /// The underlying Blob, e.g. for an object URL
+    pub fn blob(&self) -> &web_sys::Blob {
+        &self.blob
+    }
+
+    /// Bytes `start..end`, clamped to the file
+    pub async fn read_range(&self, start: u64, end: u64) -> Result<Vec<u8>, FileReadError> {
+        let end = end.min(self.size());
+        let start = start.min(end);
+        let slice = self
+            .blob
+            .slice_with_f64_and_f64(start as f64, end as f64)
+            .map_err(|e| FileReadError::ReadFailed(format!("{:?}", e)))?;
+        let buffer = JsFuture::from(slice.array_buffer())
+            .await
+            .map_err(|e| FileReadError::ReadFailed(format!("{:?}", e)))?;
+        Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+    }
+
+    /// Up to the first `len` bytes
+    pub async fn read_head(&self, len: u64) -> Result<Vec<u8>, FileReadError> {
+        self.read_range(0, len).await
+    }
+
+    /// Every byte, for the few paths that can't work in slices
+    pub async fn read_all(&self) -> Result<Vec<u8>, FileReadError> {
+        self.read_range(0, self.size()).await
+    }
+
+    /// Read the file front to back in `FILE_READ_CHUNK_SIZE` slices,
+    /// handing each to `on_slice` along with the fraction read so far
+    pub async fn stream<F>(&self, mut on_slice: F) -> Result<(), FileReadError>
+    where
+        F: FnMut(&[u8], f64),
+    {
+        let size = self.size();
+        let mut offset = 0;
+        while offset < size {
+            let end = (offset + FILE_READ_CHUNK_SIZE).min(size);
+            let slice = self.read_range(offset, end).await?;
+            offset = end;
+            on_slice(&slice, offset as f64 / size as f64);
+        }
+        Ok(())
+    }
+
+    /// Whether the data worker can hash the file, which it reads whole
+    pub fn fits_worker(&self) -> bool {
+        worker_available() && self.size() <= WORKER_HASH_MAX_BYTES
+    }
+
+    /// SHA-256 of the file and of each of its `HASH_CHUNK_SIZE` chunks
+    ///
+    /// Hashed in the data worker when the file fits. Otherwise it is hashed
+    /// incrementally on the main thread, since WebCrypto can only digest a
+    /// whole buffer; each slice read yields to the page.
+    pub async fn digest(&self, on_progress: impl FnMut(f64) + 'static) -> Result<FileDigest, FileReadError> {
+        let on_progress = Rc::new(RefCell::new(on_progress));
+        if self.fits_worker() {
+            let report = on_progress.clone();
+            match worker::digest(HashInput::Blob(&self.blob), move |fraction| (report.borrow_mut())(fraction)).await {
+                Ok(digest) => return Ok(digest),
+                Err(e) => log::warn!("Hashing {} on the main thread instead: {}", self.name, e),
+            }
+        }
+
+        let on_progress = move |fraction| (on_progress.borrow_mut())(fraction);
+        let mut hasher = Sha256::new();
+        let mut chunk_hashes = Vec::new();
+        // Slices are whole hash chunks, so only the last one can be short
+        self.stream(|slice, fraction| {
+            hasher.update(slice);
+            chunk_hashes.extend(slice.chunks(HASH_CHUNK_SIZE).map(sha256_hex));
+            on_progress(fraction);
+        })
+        .await?;
+        on_progress(1.0);
+
+        Ok(FileDigest {
+            sha256: hex(&hasher.finalize()),
+            chunk_hashes,
+            size: self.size() as usize,
+        })
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    async fn digests_match_hashing_in_memory() {
+        let data: Vec<u8> = (0..FILE_READ_CHUNK_SIZE as usize + HASH_CHUNK_SIZE + 7).map(|i| i as u8).collect();
+        let file = LocalFile::from_bytes("sermon.mp3", &data, "audio/mpeg").unwrap();
+        assert_eq!(file.size(), data.len() as u64);
+        assert_eq!(file.read_range(10, 20).await.unwrap(), &data[10..20]);
+        assert_eq!(file.read_head(u64::MAX).await.unwrap(), data);
+
+        let digest = file.digest(|_| {}).await.unwrap();
+        assert_eq!(digest.sha256, sha256_hex(&data));
+        assert_eq!(digest.chunk_hashes, data.chunks(HASH_CHUNK_SIZE).map(sha256_hex).collect::<Vec<_>>());
+    }
+}
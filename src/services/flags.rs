@@ -0,0 +1,161 @@
+//! Runtime feature flags
+//!
+//! Experimental subsystems check a `Flag` before they show up, so they can
+//! ship dark and be turned on per deployment without a separate build. A
+//! flag's value comes from, in order:
+//!
+//! 1. a local override set from the debug panel (this browser only)
+//! 2. the `flags` map of `AppConfig`, from `config.json` or `FA_FLAGS`
+//! 3. the flag's built-in default
+
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::config::app_config;
+
+/// localStorage key for flag overrides set in the debug panel
+const FLAG_OVERRIDES_KEY: &str = "faithful_archive_flag_overrides";
+
+/// An experimental subsystem that can be switched on or off at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Flag {
+    /// Comment threads on item pages, posted as AO messages
+    AoComments,
+    /// Tipping uploaders in AR or AO
+    Tipping,
+    /// Beacon wallet as a connection option
+    Beacon,
+}
+
+impl Flag {
+    pub const ALL: [Flag; 3] = [Flag::AoComments, Flag::Tipping, Flag::Beacon];
+
+    /// Name in `config.json`, `FA_FLAGS` and the overrides
+    pub fn key(&self) -> &'static str {
+        match self {
+            Flag::AoComments => "ao_comments",
+            Flag::Tipping => "tipping",
+            Flag::Beacon => "beacon",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|flag| flag.key() == key)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Flag::AoComments => "AO comments",
+            Flag::Tipping => "Tipping",
+            Flag::Beacon => "Beacon wallet",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Flag::AoComments => "Comment threads under each item",
+            Flag::Tipping => "Support button for uploaders with a payment address",
+            Flag::Beacon => "Beacon in the wallet picker; applies after a reload",
+        }
+    }
+
+    /// Value when neither the config nor an override says
+    pub fn default_enabled(&self) -> bool {
+        match self {
+            Flag::AoComments | Flag::Tipping | Flag::Beacon => true,
+        }
+    }
+
+    /// Value from the config, falling back to the default
+    pub fn configured(&self) -> bool {
+        app_config().flags.get(self.key()).copied().unwrap_or(self.default_enabled())
+    }
+}
+
+/// Parse a `FA_FLAGS` list such as `tipping=off,beacon=on`; unknown values
+/// are skipped
+pub fn parse_flag_list(list: &str) -> BTreeMap<String, bool> {
+    list.split(',')
+        .filter_map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            let enabled = match value.trim() {
+                "on" | "true" | "1" => true,
+                "off" | "false" | "0" => false,
+                _ => return None,
+            };
+            Some((key.trim().to_string(), enabled))
+        })
+        .collect()
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+}
+
+fn stored_overrides() -> BTreeMap<Flag, bool> {
+    local_storage()
+        .and_then(|storage| storage.get_item(FLAG_OVERRIDES_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str::<BTreeMap<String, bool>>(&json).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|(key, enabled)| Some((Flag::from_key(&key)?, enabled)))
+        .collect()
+}
+
+// Global flag overrides using Dioxus signals
+fn use_flag_overrides_state() -> &'static GlobalSignal<BTreeMap<Flag, bool>> {
+    static FLAG_OVERRIDES: GlobalSignal<BTreeMap<Flag, bool>> = GlobalSignal::new(stored_overrides);
+    &FLAG_OVERRIDES
+}
+
+/// Whether `flag` is on
+pub fn is_enabled(flag: Flag) -> bool {
+    use_flag_overrides_state().peek().get(&flag).copied().unwrap_or_else(|| flag.configured())
+}
+
+/// This browser's override of `flag`, if any
+pub fn flag_override(flag: Flag) -> Option<bool> {
+    use_flag_overrides_state().peek().get(&flag).copied()
+}
+
+/// Override `flag` in this browser, or go back to the configured value
+/// with `None`
+pub fn set_flag_override(flag: Flag, enabled: Option<bool>) {
+    let mut overrides = use_flag_overrides_state().write();
+    match enabled {
+        Some(enabled) => overrides.insert(flag, enabled),
+        None => overrides.remove(&flag),
+    };
+    let stored: BTreeMap<&str, bool> = overrides.iter().map(|(flag, enabled)| (flag.key(), *enabled)).collect();
+    if let (Some(storage), Ok(json)) = (local_storage(), serde_json::to_string(&stored)) {
+        let _ = storage.set_item(FLAG_OVERRIDES_KEY, &json);
+    }
+}
+
+/// Every flag's current value as `key=on|off`, for diagnostic bundles
+pub fn flag_summary() -> String {
+    Flag::ALL
+        .iter()
+        .map(|flag| format!("{}={}", flag.key(), if is_enabled(*flag) { "on" } else { "off" }))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Hook returning whether `flag` is on, updating when it is overridden
+pub fn use_flag(flag: Flag) -> Memo<bool> {
+    use_memo(move || use_flag_overrides_state().read().get(&flag).copied().unwrap_or_else(|| flag.configured()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn flag_lists_parse_on_and_off() {
+        let flags = parse_flag_list("tipping=off, beacon=on,ao_comments=maybe,broken");
+        assert_eq!(flags.get("tipping"), Some(&false));
+        assert_eq!(flags.get("beacon"), Some(&true));
+        assert_eq!(flags.len(), 2);
+    }
+}
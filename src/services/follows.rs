@@ -0,0 +1,185 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+
+use crate::models::{ContentMetadata, SPIRITUAL_CONTENT_TYPE};
+use crate::services::collections::CollectionService;
+use crate::services::graphql::{GraphQLClient, GraphQLError};
+use crate::services::hide_registry::is_hidden;
+
+/// localStorage key for followed speakers, series, uploaders and collections
+const FOLLOWS_KEY: &str = "faithful_archive_follows";
+
+/// localStorage key for when the Following feed was last opened (unix seconds)
+const FEED_SEEN_KEY: &str = "faithful_archive_following_seen";
+
+/// Most items shown in the Following feed
+const FEED_LIMIT: usize = 100;
+
+/// Something whose new uploads appear in the Following feed and weekly digest
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum FollowTarget {
+    Speaker(String),
+    /// Series by `series_id`
+    Series(String),
+    /// Uploader by wallet address
+    Uploader(String),
+    /// User-curated collection by `collection_id`
+    Collection(String),
+}
+
+impl FollowTarget {
+    /// Whether `item` is new content from this target
+    ///
+    /// Collection membership isn't in an item's tags, so collections never
+    /// match here; `following_feed` looks their items up instead.
+    pub fn matches(&self, item: &ContentMetadata) -> bool {
+        match self {
+            FollowTarget::Speaker(speaker) => item.speaker.as_ref().is_some_and(|s| s.eq_ignore_ascii_case(speaker)),
+            FollowTarget::Series(series_id) => item.series_id.as_deref() == Some(series_id.as_str()),
+            FollowTarget::Uploader(address) => &item.owner == address,
+            FollowTarget::Collection(_) => false,
+        }
+    }
+}
+
+fn load_follows() -> BTreeSet<FollowTarget> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(FOLLOWS_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_follows(follows: &BTreeSet<FollowTarget>) {
+    if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+        if let Ok(json) = serde_json::to_string(follows) {
+            let _ = storage.set_item(FOLLOWS_KEY, &json);
+        }
+    }
+}
+
+// Global follow list using Dioxus signals, read from localStorage on first use
+fn use_follows_state() -> &'static GlobalSignal<BTreeSet<FollowTarget>> {
+    static FOLLOWS: GlobalSignal<BTreeSet<FollowTarget>> = GlobalSignal::new(load_follows);
+    &FOLLOWS
+}
+
+/// Follow or unfollow a target, returning whether it is now followed
+pub fn toggle_follow(target: &FollowTarget) -> bool {
+    let mut follows = use_follows_state().write();
+    let followed = if follows.remove(target) {
+        false
+    } else {
+        follows.insert(target.clone());
+        true
+    };
+    save_follows(&follows);
+    followed
+}
+
+/// Everything currently followed
+pub fn followed() -> BTreeSet<FollowTarget> {
+    use_follows_state().read().clone()
+}
+
+/// Hook returning whether a target is followed
+pub fn use_is_followed(target: FollowTarget) -> Memo<bool> {
+    use_memo(use_reactive!(|target| use_follows_state().read().contains(&target)))
+}
+
+// New items in the Following feed since it was last opened
+fn use_feed_new_count_state() -> &'static GlobalSignal<usize> {
+    static FEED_NEW_COUNT: GlobalSignal<usize> = GlobalSignal::new(|| 0);
+    &FEED_NEW_COUNT
+}
+
+/// When the Following feed was last opened, if ever
+pub fn feed_last_seen() -> Option<i64> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(FEED_SEEN_KEY).ok().flatten())
+        .and_then(|value| value.parse().ok())
+}
+
+/// Record that the feed was opened, clearing its badge
+pub fn mark_feed_seen() {
+    if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+        let _ = storage.set_item(FEED_SEEN_KEY, &chrono::Utc::now().timestamp().to_string());
+    }
+    *use_feed_new_count_state().write() = 0;
+}
+
+/// Whether `item` arrived after `since`; pending items always count
+pub fn is_new_since(item: &ContentMetadata, since: Option<i64>) -> bool {
+    match (since, item.block_timestamp) {
+        (None, _) => false,
+        (Some(since), Some(timestamp)) => timestamp > since,
+        (Some(_), None) => true,
+    }
+}
+
+/// Recent items from everything followed, newest first
+///
+/// Uploaders are matched by signer and series and speakers by tag, one
+/// query each. Speaker tags must match exactly here, unlike the digest.
+/// Followed collections contribute their current items; one that can't be
+/// loaded is skipped rather than failing the whole feed.
+pub async fn following_feed() -> Result<Vec<ContentMetadata>, GraphQLError> {
+    let follows = followed();
+    let mut uploaders = Vec::new();
+    let mut series = Vec::new();
+    let mut speakers = Vec::new();
+    let mut collections = Vec::new();
+    for target in &follows {
+        match target {
+            FollowTarget::Uploader(address) => uploaders.push(address.as_str()),
+            FollowTarget::Series(series_id) => series.push(series_id.as_str()),
+            FollowTarget::Speaker(speaker) => speakers.push(speaker.as_str()),
+            FollowTarget::Collection(collection_id) => collections.push(collection_id.as_str()),
+        }
+    }
+
+    let graphql = GraphQLClient::new();
+    let mut transactions = Vec::new();
+    if !uploaders.is_empty() {
+        transactions.extend(graphql.find_transactions(&uploaders, &[("Type", &[SPIRITUAL_CONTENT_TYPE])]).await?);
+    }
+    if !series.is_empty() {
+        transactions.extend(graphql.find_transactions(&[], &[("Type", &[SPIRITUAL_CONTENT_TYPE]), ("Series-Id", &series)]).await?);
+    }
+    if !speakers.is_empty() {
+        transactions.extend(graphql.find_transactions(&[], &[("Type", &[SPIRITUAL_CONTENT_TYPE]), ("Speaker", &speakers)]).await?);
+    }
+    let mut collected = HashSet::new();
+    for collection_id in collections {
+        match CollectionService::new().load(collection_id).await {
+            Ok(collection) => collected.extend(collection.manifest.items),
+            Err(e) => log::warn!("Could not load followed collection {}: {}", collection_id, e),
+        }
+    }
+    if !collected.is_empty() {
+        let ids: Vec<&str> = collected.iter().map(String::as_str).collect();
+        transactions.extend(graphql.transactions_by_ids(&ids).await?);
+    }
+
+    let mut seen = HashSet::new();
+    let mut items: Vec<ContentMetadata> = transactions
+        .iter()
+        .filter(|tx| seen.insert(tx.id.clone()) && !is_hidden(&tx.id))
+        .filter_map(|tx| ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp))
+        .filter(|item| collected.contains(&item.tx_id) || follows.iter().any(|target| target.matches(item)))
+        .collect();
+    // Pending items have no timestamp yet and are the newest
+    items.sort_by_key(|item| std::cmp::Reverse(item.block_timestamp.unwrap_or(i64::MAX)));
+    items.truncate(FEED_LIMIT);
+
+    let since = feed_last_seen();
+    *use_feed_new_count_state().write() = items.iter().filter(|item| is_new_since(item, since)).count();
+    Ok(items)
+}
+
+/// Hook returning how many feed items arrived since it was last opened
+pub fn use_feed_new_count() -> Memo<usize> {
+    use_memo(move || *use_feed_new_count_state().read())
+}
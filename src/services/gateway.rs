@@ -0,0 +1,364 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::app_config;
+use crate::services::scheduler::schedule;
+
+/// localStorage key for user-added gateways
+const CUSTOM_GATEWAYS_KEY: &str = "faithful_archive_custom_gateways";
+
+/// localStorage key for the gateway the user pinned as preferred
+const PINNED_GATEWAY_KEY: &str = "faithful_archive_pinned_gateway";
+
+/// Where a gateway entry came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GatewayKind {
+    /// The reference arweave.net gateway
+    Arweave,
+    /// A gateway operated on the ar.io network
+    ArIo,
+    /// A gateway added by the user
+    Custom,
+}
+
+/// A single Arweave gateway and its last known health
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Gateway {
+    pub url: String,
+    pub kind: GatewayKind,
+    pub healthy: bool,
+    pub last_checked: Option<i64>,
+    /// Round-trip time of the last `/info` probe
+    #[serde(default)]
+    pub latency_ms: Option<u32>,
+}
+
+impl Gateway {
+    pub fn new(url: &str, kind: GatewayKind) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            kind,
+            healthy: true,
+            last_checked: None,
+            latency_ms: None,
+        }
+    }
+
+    /// Host name without scheme or port
+    pub fn host(&self) -> String {
+        reqwest::Url::parse(&self.url)
+            .ok()
+            .and_then(|url| url.host_str().map(|host| host.to_string()))
+            .unwrap_or_else(|| self.url.clone())
+    }
+
+    /// Port, defaulting to the scheme's well-known port
+    pub fn port(&self) -> u16 {
+        reqwest::Url::parse(&self.url)
+            .ok()
+            .and_then(|url| url.port_or_known_default())
+            .unwrap_or(443)
+    }
+
+    /// Scheme without the trailing colon (`https`)
+    pub fn protocol(&self) -> String {
+        reqwest::Url::parse(&self.url)
+            .map(|url| url.scheme().to_string())
+            .unwrap_or_else(|_| "https".to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum GatewayError {
+    NoGateways,
+    AllGatewaysFailed(String),
+}
+
+impl std::fmt::Display for GatewayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GatewayError::NoGateways => write!(f, "No gateways configured"),
+            GatewayError::AllGatewaysFailed(msg) => write!(f, "All gateways failed: {}", msg),
+        }
+    }
+}
+
+// Global gateway list shared by every service using Dioxus signals
+fn use_gateway_state() -> &'static GlobalSignal<Vec<Gateway>> {
+    static GATEWAYS: GlobalSignal<Vec<Gateway>> = GlobalSignal::new(GatewayManager::default_gateways);
+    &GATEWAYS
+}
+
+fn use_pinned_gateway_state() -> &'static GlobalSignal<Option<String>> {
+    static PINNED_GATEWAY: GlobalSignal<Option<String>> = GlobalSignal::new(load_pinned_gateway);
+    &PINNED_GATEWAY
+}
+
+/// Ordered list of gateways with health tracking and failover
+///
+/// Requests go to the pinned gateway if the user chose one, then the first
+/// healthy gateway; on network errors or 5xx responses the gateway is
+/// marked unhealthy and the next one is tried. A gateway still rate
+/// limiting after the scheduler's retries is skipped but stays healthy.
+/// Media is served from the fastest gateway measured by `health_check`.
+pub struct GatewayManager {
+    client: reqwest::Client,
+}
+
+impl GatewayManager {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Built-in gateways followed by any persisted custom gateways
+    fn default_gateways() -> Vec<Gateway> {
+        let mut gateways: Vec<Gateway> = app_config()
+            .gateways
+            .iter()
+            .enumerate()
+            .map(|(i, url)| Gateway::new(url, if i == 0 { GatewayKind::Arweave } else { GatewayKind::ArIo }))
+            .collect();
+
+        // Custom gateways serve mainnet data
+        let custom = if app_config().network.is_mainnet() { load_custom_gateways() } else { Vec::new() };
+        for url in custom {
+            if !gateways.iter().any(|g| g.url == url) {
+                gateways.push(Gateway::new(&url, GatewayKind::Custom));
+            }
+        }
+
+        gateways
+    }
+
+    /// Snapshot of all configured gateways in priority order
+    pub fn gateways(&self) -> Vec<Gateway> {
+        use_gateway_state().read().clone()
+    }
+
+    /// Gateways in the order requests should try them: pinned, then healthy
+    fn ordered(&self) -> Vec<Gateway> {
+        let pinned = self.pinned();
+        let mut gateways = self.gateways();
+        gateways.sort_by_key(|gateway| (!gateway.healthy, pinned.as_deref() != Some(gateway.url.as_str())));
+        gateways
+    }
+
+    /// Gateway for media streaming: the pinned gateway if set, otherwise the
+    /// healthy gateway with the lowest measured latency
+    pub fn fastest(&self) -> Gateway {
+        if let Some(pinned) = self.pinned() {
+            if let Some(gateway) = self.gateways().into_iter().find(|g| g.url == pinned && g.healthy) {
+                return gateway;
+            }
+        }
+
+        self.gateways()
+            .into_iter()
+            .filter(|gateway| gateway.healthy && gateway.latency_ms.is_some())
+            .min_by_key(|gateway| gateway.latency_ms)
+            .unwrap_or_else(|| self.primary())
+    }
+
+    /// URL to stream a transaction's data from the fastest gateway
+    pub fn media_url(&self, tx_id: &str) -> String {
+        format!("{}/{}", self.fastest().url, tx_id)
+    }
+
+    /// URL of the gateway the user pinned, if any
+    pub fn pinned(&self) -> Option<String> {
+        use_pinned_gateway_state().read().clone()
+    }
+
+    /// Prefer `url` for all requests; `None` returns to automatic selection
+    pub fn pin(&self, url: Option<&str>) {
+        let pinned = url.map(|url| url.trim_end_matches('/').to_string());
+
+        if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+            let _ = match &pinned {
+                Some(url) => storage.set_item(PINNED_GATEWAY_KEY, url),
+                None => storage.remove_item(PINNED_GATEWAY_KEY),
+            };
+        }
+
+        *use_pinned_gateway_state().write() = pinned;
+    }
+
+    /// The gateway requests currently go to
+    pub fn primary(&self) -> Gateway {
+        self.ordered()
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| Gateway::new(app_config().primary_gateway(), GatewayKind::Arweave))
+    }
+
+    /// Base URL of the primary gateway
+    pub fn primary_url(&self) -> String {
+        self.primary().url
+    }
+
+    /// Add and persist a user-provided gateway
+    pub fn add_custom(&self, url: &str) {
+        let gateway = Gateway::new(url, GatewayKind::Custom);
+        let mut gateways = use_gateway_state().write();
+        if !gateways.iter().any(|g| g.url == gateway.url) {
+            gateways.push(gateway);
+        }
+        save_custom_gateways(&gateways);
+    }
+
+    /// Remove a user-provided gateway
+    pub fn remove_custom(&self, url: &str) {
+        let mut gateways = use_gateway_state().write();
+        gateways.retain(|g| !(g.kind == GatewayKind::Custom && g.url == url));
+        save_custom_gateways(&gateways);
+    }
+
+    fn set_health(&self, url: &str, healthy: bool) {
+        if let Some(gateway) = use_gateway_state().write().iter_mut().find(|g| g.url == url) {
+            gateway.healthy = healthy;
+            gateway.last_checked = Some(chrono::Utc::now().timestamp());
+        }
+    }
+
+    /// Probe `/info` on every gateway, recording health and latency
+    pub async fn health_check(&self) {
+        for gateway in self.gateways() {
+            let started = js_sys::Date::now();
+            let healthy = match schedule(&self.client, || self.client.get(format!("{}/info", gateway.url))).await {
+                Ok(response) => response.status().is_success(),
+                Err(_) => false,
+            };
+            let elapsed = (js_sys::Date::now() - started) as u32;
+
+            if !healthy {
+                log::warn!("⚠️ Gateway {} failed health check", gateway.url);
+            }
+            self.set_health(&gateway.url, healthy);
+
+            if let Some(entry) = use_gateway_state().write().iter_mut().find(|g| g.url == gateway.url) {
+                entry.latency_ms = if healthy { Some(elapsed) } else { None };
+            }
+        }
+
+        log::info!("⚡ Fastest gateway: {}", self.fastest().url);
+    }
+
+    /// GET a gateway path with failover
+    pub async fn get(&self, path: &str) -> Result<reqwest::Response, GatewayError> {
+        self.send_with_failover(|client, base| client.get(format!("{}{}", base, path))).await
+    }
+
+    /// GET an inclusive byte range of a gateway path with failover
+    pub async fn get_range(&self, path: &str, start: u64, end: u64) -> Result<reqwest::Response, GatewayError> {
+        self.send_with_failover(|client, base| {
+            client
+                .get(format!("{}{}", base, path))
+                .header("Range", format!("bytes={}-{}", start, end))
+        })
+        .await
+    }
+
+    /// HEAD a gateway path with failover
+    pub async fn head(&self, path: &str) -> Result<reqwest::Response, GatewayError> {
+        self.send_with_failover(|client, base| client.head(format!("{}{}", base, path))).await
+    }
+
+    /// POST a JSON body to a gateway path with failover
+    pub async fn post_json<T: Serialize>(&self, path: &str, body: &T) -> Result<reqwest::Response, GatewayError> {
+        self.send_with_failover(|client, base| client.post(format!("{}{}", base, path)).json(body)).await
+    }
+
+    /// Send the request `build` makes against each gateway's base URL,
+    /// failing over as above
+    pub async fn send_with_failover<F>(&self, build: F) -> Result<reqwest::Response, GatewayError>
+    where
+        F: Fn(&reqwest::Client, &str) -> reqwest::RequestBuilder,
+    {
+        let gateways = self.ordered();
+        if gateways.is_empty() {
+            return Err(GatewayError::NoGateways);
+        }
+
+        let mut last_error = String::new();
+        for gateway in gateways {
+            match schedule(&self.client, || build(&self.client, &gateway.url)).await {
+                Ok(response) if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                    // Busy rather than broken, so it stays healthy
+                    log::warn!("🔀 Failing over from gateway: {} is rate limiting", gateway.url);
+                    last_error = format!("{} returned HTTP 429", gateway.url);
+                    continue;
+                }
+                Ok(response) if !response.status().is_server_error() => {
+                    if !gateway.healthy {
+                        self.set_health(&gateway.url, true);
+                    }
+                    return Ok(response);
+                }
+                Ok(response) => {
+                    last_error = format!("{} returned HTTP {}", gateway.url, response.status().as_u16());
+                }
+                Err(e) => {
+                    last_error = format!("{}: {}", gateway.url, e);
+                }
+            }
+
+            log::warn!("🔀 Failing over from gateway: {}", last_error);
+            self.set_health(&gateway.url, false);
+        }
+
+        Err(GatewayError::AllGatewaysFailed(last_error))
+    }
+}
+
+impl Default for GatewayManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn load_custom_gateways() -> Vec<String> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(CUSTOM_GATEWAYS_KEY).ok().flatten())
+        .and_then(|stored| serde_json::from_str(&stored).ok())
+        .unwrap_or_default()
+}
+
+fn load_pinned_gateway() -> Option<String> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(PINNED_GATEWAY_KEY).ok().flatten())
+}
+
+fn save_custom_gateways(gateways: &[Gateway]) {
+    let custom: Vec<&str> = gateways
+        .iter()
+        .filter(|g| g.kind == GatewayKind::Custom)
+        .map(|g| g.url.as_str())
+        .collect();
+
+    if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+        if let Ok(serialized) = serde_json::to_string(&custom) {
+            let _ = storage.set_item(CUSTOM_GATEWAYS_KEY, &serialized);
+        }
+    }
+}
+
+/// Benchmark every gateway once at startup so media uses the fastest one
+pub fn init_gateway_benchmark() {
+    spawn(async {
+        GatewayManager::new().health_check().await;
+    });
+}
+
+/// Hook returning the live gateway list with health status
+pub fn use_gateways() -> Vec<Gateway> {
+    use_gateway_state().read().clone()
+}
+
+/// Hook returning the URL of the pinned gateway, if any
+pub fn use_pinned_gateway() -> Option<String> {
+    use_pinned_gateway_state().read().clone()
+}
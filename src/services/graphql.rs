@@ -0,0 +1,562 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::models::{BlockInfo, ContentMetadata};
+use crate::services::gateway::{GatewayError, GatewayManager};
+use crate::services::http::{HttpCache, HttpError};
+use crate::config::app_config;
+use crate::utils::constants::APP_NAME;
+
+#[derive(Debug, Clone)]
+pub enum GraphQLError {
+    RequestFailed(String),
+    BadStatus(u16),
+    InvalidResponse(String),
+    QueryErrors(Vec<String>),
+}
+
+impl From<GatewayError> for GraphQLError {
+    fn from(error: GatewayError) -> Self {
+        GraphQLError::RequestFailed(error.to_string())
+    }
+}
+
+impl From<HttpError> for GraphQLError {
+    fn from(error: HttpError) -> Self {
+        match error {
+            HttpError::Gateway(e) => e.into(),
+            HttpError::InvalidBody(msg) => GraphQLError::InvalidResponse(msg),
+        }
+    }
+}
+
+impl std::fmt::Display for GraphQLError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphQLError::RequestFailed(msg) => write!(f, "GraphQL request failed: {}", msg),
+            GraphQLError::BadStatus(status) => write!(f, "GraphQL endpoint returned HTTP {}", status),
+            GraphQLError::InvalidResponse(msg) => write!(f, "Invalid GraphQL response: {}", msg),
+            GraphQLError::QueryErrors(errors) => write!(f, "GraphQL query errors: {}", errors.join("; ")),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct GraphQLRequest<'a> {
+    query: &'a str,
+    variables: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct GraphQLResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphQLResponseError>,
+}
+
+#[derive(Deserialize)]
+struct GraphQLResponseError {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct NetworkInfo {
+    height: u64,
+}
+
+#[derive(Deserialize)]
+struct TransactionBlockData {
+    transaction: Option<TransactionBlockNode>,
+}
+
+#[derive(Deserialize)]
+struct TransactionBlockNode {
+    block: Option<BlockNode>,
+}
+
+#[derive(Deserialize)]
+struct BlockNode {
+    height: u64,
+    timestamp: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct TransactionsData {
+    transactions: TransactionConnection,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionConnection {
+    page_info: PageInfo,
+    edges: Vec<TransactionEdge>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+}
+
+#[derive(Deserialize)]
+struct TransactionEdge {
+    cursor: String,
+    node: TransactionIdNode,
+}
+
+#[derive(Deserialize)]
+struct TransactionIdNode {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct TaggedTransactionsData {
+    transactions: TaggedTransactionConnection,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TaggedTransactionConnection {
+    page_info: PageInfo,
+    edges: Vec<TaggedTransactionEdge>,
+}
+
+#[derive(Deserialize)]
+struct TaggedTransactionEdge {
+    cursor: String,
+    node: TaggedTransactionNode,
+}
+
+#[derive(Deserialize)]
+struct TaggedTransactionNode {
+    id: String,
+    owner: OwnerNode,
+    tags: Vec<TagNode>,
+    block: Option<BlockNode>,
+}
+
+#[derive(Deserialize)]
+struct OwnerNode {
+    address: String,
+}
+
+#[derive(Deserialize)]
+struct TagNode {
+    name: String,
+    value: String,
+}
+
+/// A page of tag search results
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionPage {
+    pub transactions: Vec<TransactionSummary>,
+    /// Cursor for the following page; `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// A transaction returned by a tag search
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionSummary {
+    pub id: String,
+    pub owner: String,
+    pub tags: Vec<(String, String)>,
+    /// Block height; `None` while the item is still pending
+    pub block_height: Option<u64>,
+    /// Block timestamp; `None` while the item is still pending
+    pub timestamp: Option<i64>,
+}
+
+impl TransactionSummary {
+    /// First value of a tag by name
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag_name, _)| tag_name == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl From<TaggedTransactionNode> for TransactionSummary {
+    fn from(node: TaggedTransactionNode) -> Self {
+        Self {
+            id: node.id,
+            owner: node.owner.address,
+            tags: node.tags.into_iter().map(|tag| (tag.name, tag.value)).collect(),
+            block_height: node.block.as_ref().map(|block| block.height),
+            timestamp: node.block.and_then(|block| block.timestamp),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TransactionDetailsData {
+    transaction: Option<TransactionDetails>,
+}
+
+/// Full record of a transaction as reported by the gateway
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDetails {
+    pub id: String,
+    pub signature: String,
+    pub owner: TransactionOwner,
+    /// Target address, empty when there is none
+    #[serde(default)]
+    pub recipient: String,
+    /// Base64url anchor (`last_tx` for base-layer transactions), may be empty
+    #[serde(default)]
+    pub anchor: String,
+    pub tags: Vec<TransactionTag>,
+    pub data: TransactionData,
+    pub block: Option<TransactionBlock>,
+    /// Bundle transaction carrying this DataItem, if bundled
+    pub bundled_in: Option<BundleRef>,
+}
+
+impl TransactionDetails {
+    /// Tags as name/value pairs
+    pub fn tag_pairs(&self) -> Vec<(String, String)> {
+        self.tags.iter().map(|tag| (tag.name.clone(), tag.value.clone())).collect()
+    }
+
+    /// Archive metadata, or `None` if this isn't a Faithful Archive item
+    pub fn metadata(&self) -> Option<ContentMetadata> {
+        ContentMetadata::from_tags(
+            &self.id,
+            &self.owner.address,
+            &self.tag_pairs(),
+            self.block.as_ref().map(|block| block.height),
+            self.block.as_ref().map(|block| block.timestamp),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionOwner {
+    pub address: String,
+    pub key: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionTag {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionData {
+    pub size: String,
+    #[serde(rename = "type")]
+    pub content_type: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionBlock {
+    pub id: String,
+    pub height: u64,
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleRef {
+    pub id: String,
+}
+
+const TRANSACTION_DETAILS_QUERY: &str = r#"
+query($id: ID!) {
+  transaction(id: $id) {
+    id
+    signature
+    owner { address key }
+    recipient
+    anchor
+    tags { name value }
+    data { size type }
+    block { id height timestamp }
+    bundledIn { id }
+  }
+}
+"#;
+
+const TRANSACTION_BLOCK_QUERY: &str = r#"
+query($id: ID!) {
+  transaction(id: $id) {
+    block { height timestamp }
+  }
+}
+"#;
+
+const OWNER_TRANSACTIONS_QUERY: &str = r#"
+query($owners: [String!], $appName: String!, $after: String) {
+  transactions(owners: $owners, tags: [{ name: "App-Name", values: [$appName] }], first: 100, after: $after) {
+    pageInfo { hasNextPage }
+    edges { cursor node { id } }
+  }
+}
+"#;
+
+const TAGGED_TRANSACTIONS_QUERY: &str = r#"
+query($owners: [String!], $tags: [TagFilter!], $block: BlockFilter, $first: Int, $after: String) {
+  transactions(owners: $owners, tags: $tags, block: $block, first: $first, after: $after) {
+    pageInfo { hasNextPage }
+    edges { cursor node { id owner { address } tags { name value } block { height timestamp } } }
+  }
+}
+"#;
+
+const TRANSACTIONS_BY_IDS_QUERY: &str = r#"
+query($ids: [ID!], $after: String) {
+  transactions(ids: $ids, first: 100, after: $after) {
+    pageInfo { hasNextPage }
+    edges { cursor node { id owner { address } tags { name value } block { height timestamp } } }
+  }
+}
+"#;
+
+/// Minimal client for the Arweave gateway GraphQL endpoint
+///
+/// Requests are routed through the `GatewayManager` so a failing gateway
+/// transparently fails over to the next configured one. Clients made with
+/// `cached()` also go through the `HttpCache`, for metadata that can be a
+/// minute old; anything tracking new uploads should use `new()`.
+pub struct GraphQLClient {
+    gateways: GatewayManager,
+    cache: Option<HttpCache>,
+}
+
+impl GraphQLClient {
+    pub fn new() -> Self {
+        Self {
+            gateways: GatewayManager::new(),
+            cache: None,
+        }
+    }
+
+    /// Client whose query results are cached
+    pub fn cached() -> Self {
+        Self {
+            gateways: GatewayManager::new(),
+            cache: Some(HttpCache::new()),
+        }
+    }
+
+    /// Run a GraphQL query and deserialize its `data` field
+    pub async fn query<T: DeserializeOwned>(&self, query: &str, variables: serde_json::Value) -> Result<T, GraphQLError> {
+        let request = GraphQLRequest { query, variables };
+        let path = &app_config().graphql_path;
+        let (status, text) = match &self.cache {
+            Some(cache) => {
+                let response = cache.post_json(path, &request).await?;
+                (response.status, response.body)
+            }
+            None => {
+                let response = self.gateways.post_json(path, &request).await?;
+                let status = response.status().as_u16();
+                let text = response
+                    .text()
+                    .await
+                    .map_err(|e| GraphQLError::InvalidResponse(e.to_string()))?;
+                (status, text)
+            }
+        };
+
+        if !(200..300).contains(&status) {
+            return Err(GraphQLError::BadStatus(status));
+        }
+
+        let body: GraphQLResponse<T> =
+            serde_json::from_str(&text).map_err(|e| GraphQLError::InvalidResponse(e.to_string()))?;
+
+        if !body.errors.is_empty() {
+            self.forget(&request).await;
+            return Err(GraphQLError::QueryErrors(body.errors.into_iter().map(|e| e.message).collect()));
+        }
+
+        body.data.ok_or_else(|| GraphQLError::InvalidResponse("Missing data field".to_string()))
+    }
+
+    // Keep a cached client from serving an answer again
+    async fn forget(&self, request: &GraphQLRequest<'_>) {
+        if let Some(cache) = &self.cache {
+            cache.forget_post(&app_config().graphql_path, request).await;
+        }
+    }
+
+    /// Get the current network height from the gateway
+    pub async fn network_height(&self) -> Result<u64, GraphQLError> {
+        let response = self.gateways.get("/info").await?;
+
+        if !response.status().is_success() {
+            return Err(GraphQLError::BadStatus(response.status().as_u16()));
+        }
+
+        let info: NetworkInfo = response
+            .json()
+            .await
+            .map_err(|e| GraphQLError::InvalidResponse(e.to_string()))?;
+
+        Ok(info.height)
+    }
+
+    /// Look up block inclusion for a transaction or DataItem.
+    ///
+    /// Returns `None` while the item has not been mined into a block yet.
+    pub async fn block_info(&self, tx_id: &str) -> Result<Option<BlockInfo>, GraphQLError> {
+        let data: TransactionBlockData = self
+            .query(TRANSACTION_BLOCK_QUERY, serde_json::json!({ "id": tx_id }))
+            .await?;
+
+        let block = match data.transaction.and_then(|tx| tx.block) {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+
+        let height = self.network_height().await?;
+
+        Ok(Some(BlockInfo {
+            height: block.height,
+            timestamp: block.timestamp,
+            confirmations: height.saturating_sub(block.height) + 1,
+        }))
+    }
+
+    /// Full transaction record, or `None` if the gateway doesn't know it yet
+    pub async fn transaction_details(&self, tx_id: &str) -> Result<Option<TransactionDetails>, GraphQLError> {
+        let variables = serde_json::json!({ "id": tx_id });
+        let data: TransactionDetailsData = self.query(TRANSACTION_DETAILS_QUERY, variables.clone()).await?;
+        // Usually an upload that isn't indexed yet, so don't remember it
+        if data.transaction.is_none() {
+            self.forget(&GraphQLRequest { query: TRANSACTION_DETAILS_QUERY, variables }).await;
+        }
+        Ok(data.transaction)
+    }
+
+    /// List every Faithful Archive transaction published by an owner address
+    pub async fn published_tx_ids(&self, owner: &str) -> Result<Vec<String>, GraphQLError> {
+        let mut tx_ids = Vec::new();
+        let mut after: Option<String> = None;
+
+        loop {
+            let data: TransactionsData = self
+                .query(
+                    OWNER_TRANSACTIONS_QUERY,
+                    serde_json::json!({ "owners": [owner], "appName": APP_NAME, "after": after }),
+                )
+                .await?;
+
+            let connection = data.transactions;
+            after = connection.edges.last().map(|edge| edge.cursor.clone());
+            tx_ids.extend(connection.edges.into_iter().map(|edge| edge.node.id));
+
+            if !connection.page_info.has_next_page || after.is_none() {
+                break;
+            }
+        }
+
+        Ok(tx_ids)
+    }
+
+    /// Find Faithful Archive transactions matching every tag filter
+    ///
+    /// `owners` restricts results to those signers; pass an empty slice to
+    /// search all owners. Each filter matches any of its values.
+    pub async fn find_transactions(&self, owners: &[&str], tags: &[(&str, &[&str])]) -> Result<Vec<TransactionSummary>, GraphQLError> {
+        self.find_transactions_since(owners, tags, None).await
+    }
+
+    /// Like `find_transactions`, restricted to items mined at or after
+    /// `min_height`
+    pub async fn find_transactions_since(
+        &self,
+        owners: &[&str],
+        tags: &[(&str, &[&str])],
+        min_height: Option<u64>,
+    ) -> Result<Vec<TransactionSummary>, GraphQLError> {
+        let mut filters = vec![serde_json::json!({ "name": "App-Name", "values": [APP_NAME] })];
+        filters.extend(tags.iter().map(|(name, values)| serde_json::json!({ "name": name, "values": values })));
+        let owners = if owners.is_empty() { None } else { Some(owners) };
+        let block = min_height.map(|min| serde_json::json!({ "min": min }));
+
+        let mut transactions = Vec::new();
+        let mut after: Option<String> = None;
+
+        loop {
+            let data: TaggedTransactionsData = self
+                .query(
+                    TAGGED_TRANSACTIONS_QUERY,
+                    serde_json::json!({ "owners": owners, "tags": filters, "block": block, "first": 100, "after": after }),
+                )
+                .await?;
+
+            let connection = data.transactions;
+            after = connection.edges.last().map(|edge| edge.cursor.clone());
+            transactions.extend(connection.edges.into_iter().map(|edge| TransactionSummary::from(edge.node)));
+
+            if !connection.page_info.has_next_page || after.is_none() {
+                break;
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    /// Look up specific transactions; IDs the gateway doesn't know are
+    /// missing from the result
+    pub async fn transactions_by_ids(&self, ids: &[&str]) -> Result<Vec<TransactionSummary>, GraphQLError> {
+        let mut transactions = Vec::new();
+        let mut after: Option<String> = None;
+
+        loop {
+            let data: TaggedTransactionsData = self
+                .query(TRANSACTIONS_BY_IDS_QUERY, serde_json::json!({ "ids": ids, "after": after }))
+                .await?;
+
+            let connection = data.transactions;
+            after = connection.edges.last().map(|edge| edge.cursor.clone());
+            transactions.extend(connection.edges.into_iter().map(|edge| TransactionSummary::from(edge.node)));
+
+            if !connection.page_info.has_next_page || after.is_none() {
+                break;
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    /// One page of tagged transactions, newest first
+    ///
+    /// Pass the returned cursor back as `after` to fetch the next page.
+    pub async fn find_transactions_page(
+        &self,
+        tags: &[(&str, &[&str])],
+        first: u32,
+        after: Option<&str>,
+    ) -> Result<TransactionPage, GraphQLError> {
+        let mut filters = vec![serde_json::json!({ "name": "App-Name", "values": [APP_NAME] })];
+        filters.extend(tags.iter().map(|(name, values)| serde_json::json!({ "name": name, "values": values })));
+
+        let data: TaggedTransactionsData = self
+            .query(
+                TAGGED_TRANSACTIONS_QUERY,
+                serde_json::json!({ "tags": filters, "first": first, "after": after }),
+            )
+            .await?;
+
+        let connection = data.transactions;
+        let next_cursor = if connection.page_info.has_next_page {
+            connection.edges.last().map(|edge| edge.cursor.clone())
+        } else {
+            None
+        };
+
+        Ok(TransactionPage {
+            transactions: connection.edges.into_iter().map(|edge| TransactionSummary::from(edge.node)).collect(),
+            next_cursor,
+        })
+    }
+}
+
+impl Default for GraphQLClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, GraphQLError};
+use crate::services::scheduler::schedule;
+use crate::services::storage::{StorageError, StorageService, HEALTH_HISTORY_STORE};
+use crate::config::app_config;
+
+/// Availability of a single published item across probed gateways
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemHealth {
+    pub tx_id: String,
+    pub available_on: Vec<String>,
+    pub unavailable_on: Vec<String>,
+    pub checked_at: i64,
+}
+
+impl ItemHealth {
+    /// Item is not served by any probed gateway
+    pub fn is_missing(&self) -> bool {
+        self.available_on.is_empty()
+    }
+
+    /// Item is served by some, but not all, probed gateways
+    pub fn is_degraded(&self) -> bool {
+        !self.available_on.is_empty() && !self.unavailable_on.is_empty()
+    }
+}
+
+/// Summary of a full archive health check
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthReport {
+    pub owner: String,
+    pub items: Vec<ItemHealth>,
+    pub checked_at: i64,
+}
+
+impl HealthReport {
+    pub fn missing_count(&self) -> usize {
+        self.items.iter().filter(|item| item.is_missing()).count()
+    }
+
+    pub fn degraded_count(&self) -> usize {
+        self.items.iter().filter(|item| item.is_degraded()).count()
+    }
+
+    pub fn healthy_count(&self) -> usize {
+        self.items.len() - self.missing_count() - self.degraded_count()
+    }
+}
+
+/// Condensed result of one health check, kept to show trends over time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthRun {
+    pub owner: String,
+    pub checked_at: i64,
+    /// Whether only a sample of the archive was checked
+    pub sampled: bool,
+    pub total_items: usize,
+    pub missing_tx_ids: Vec<String>,
+    pub degraded_count: usize,
+    /// Number of checked items each gateway failed to serve
+    pub gateway_failures: HashMap<String, usize>,
+}
+
+impl HealthRun {
+    pub fn from_report(report: &HealthReport, sampled: bool) -> Self {
+        let mut gateway_failures: HashMap<String, usize> = HashMap::new();
+        for item in &report.items {
+            for gateway in &item.unavailable_on {
+                *gateway_failures.entry(gateway.clone()).or_insert(0) += 1;
+            }
+        }
+
+        Self {
+            owner: report.owner.clone(),
+            checked_at: report.checked_at,
+            sampled,
+            total_items: report.items.len(),
+            missing_tx_ids: report.items.iter().filter(|item| item.is_missing()).map(|item| item.tx_id.clone()).collect(),
+            degraded_count: report.degraded_count(),
+            gateway_failures,
+        }
+    }
+
+    /// Share of checked items a gateway failed to serve, 0.0 to 1.0
+    pub fn failure_rate(&self, gateway: &str) -> f64 {
+        if self.total_items == 0 {
+            return 0.0;
+        }
+        *self.gateway_failures.get(gateway).unwrap_or(&0) as f64 / self.total_items as f64
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum HealthError {
+    LookupFailed(GraphQLError),
+    ReseedFailed(String),
+    HistoryFailed(StorageError),
+}
+
+impl std::fmt::Display for HealthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HealthError::LookupFailed(e) => write!(f, "Could not list published items: {}", e),
+            HealthError::ReseedFailed(msg) => write!(f, "Re-seeding failed: {}", msg),
+            HealthError::HistoryFailed(e) => write!(f, "Could not access health history: {}", e),
+        }
+    }
+}
+
+/// Checks gateway availability of an organization's published archive
+pub struct HealthMonitor {
+    gateways: Vec<String>,
+    graphql: GraphQLClient,
+    client: reqwest::Client,
+}
+
+impl HealthMonitor {
+    pub fn new() -> Self {
+        Self {
+            gateways: GatewayManager::new().gateways().into_iter().map(|g| g.url).collect(),
+            graphql: GraphQLClient::new(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Gateways this monitor probes
+    pub fn gateways(&self) -> &[String] {
+        &self.gateways
+    }
+
+    /// Probe a single item on every configured gateway (no failover here:
+    /// the point is to learn which gateways serve it)
+    pub async fn check_item(&self, tx_id: &str) -> ItemHealth {
+        let mut available_on = Vec::new();
+        let mut unavailable_on = Vec::new();
+
+        for gateway in &self.gateways {
+            let url = format!("{}/{}", gateway, tx_id);
+            let served = match schedule(&self.client, || self.client.head(&url)).await {
+                Ok(response) => response.status().is_success(),
+                Err(_) => false,
+            };
+
+            if served {
+                available_on.push(gateway.clone());
+            } else {
+                unavailable_on.push(gateway.clone());
+            }
+        }
+
+        ItemHealth {
+            tx_id: tx_id.to_string(),
+            available_on,
+            unavailable_on,
+            checked_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    /// Check every item published by `owner`
+    pub async fn check_archive(&self, owner: &str) -> Result<HealthReport, HealthError> {
+        let tx_ids = self.graphql
+            .published_tx_ids(owner)
+            .await
+            .map_err(HealthError::LookupFailed)?;
+
+        self.check_items(owner, tx_ids).await
+    }
+
+    /// Check a random sample of at most `sample_size` items published by `owner`
+    pub async fn check_sample(&self, owner: &str, sample_size: usize) -> Result<HealthReport, HealthError> {
+        let mut tx_ids = self.graphql
+            .published_tx_ids(owner)
+            .await
+            .map_err(HealthError::LookupFailed)?;
+
+        // Partial Fisher-Yates shuffle: the first `sample_size` entries end up random
+        let len = tx_ids.len();
+        let sample_size = sample_size.min(len);
+        for i in 0..sample_size {
+            let j = i + (js_sys::Math::random() * (len - i) as f64) as usize;
+            tx_ids.swap(i, j.min(len - 1));
+        }
+        tx_ids.truncate(sample_size);
+
+        self.check_items(owner, tx_ids).await
+    }
+
+    async fn check_items(&self, owner: &str, tx_ids: Vec<String>) -> Result<HealthReport, HealthError> {
+        log::info!("🩺 Checking health of {} items for {}", tx_ids.len(), owner);
+
+        let mut items = Vec::with_capacity(tx_ids.len());
+        for tx_id in &tx_ids {
+            items.push(self.check_item(tx_id).await);
+        }
+
+        Ok(HealthReport {
+            owner: owner.to_string(),
+            items,
+            checked_at: chrono::Utc::now().timestamp(),
+        })
+    }
+
+    /// Re-post a signed DataItem to the first alternate bundler that accepts it
+    ///
+    /// Returns the bundler URL that accepted the item.
+    pub async fn reseed(&self, signed_item: Vec<u8>) -> Result<String, HealthError> {
+        let mut last_error = String::from("No alternate bundlers configured");
+
+        for bundler in &app_config().alternate_bundlers {
+            let result = self.client
+                .post(bundler)
+                .header("Content-Type", "application/octet-stream")
+                .body(signed_item.clone())
+                .send()
+                .await;
+
+            match result {
+                Ok(response) if response.status().is_success() => {
+                    log::info!("🌱 Re-seeded item via {}", bundler);
+                    return Ok(bundler.to_string());
+                }
+                Ok(response) => {
+                    last_error = format!("{} returned HTTP {}", bundler, response.status().as_u16());
+                }
+                Err(e) => {
+                    last_error = format!("{}: {}", bundler, e);
+                }
+            }
+            log::warn!("Re-seed attempt failed: {}", last_error);
+        }
+
+        Err(HealthError::ReseedFailed(last_error))
+    }
+}
+
+/// Persist a health check so it shows up in the report history
+pub async fn record_run(run: &HealthRun) -> Result<(), HealthError> {
+    let storage = StorageService::open().await.map_err(HealthError::HistoryFailed)?;
+    let key = format!("{}:{}", run.checked_at, run.owner);
+    storage
+        .put(HEALTH_HISTORY_STORE, &key, run)
+        .await
+        .map_err(HealthError::HistoryFailed)
+}
+
+/// All recorded health checks, newest first
+pub async fn load_history() -> Result<Vec<HealthRun>, HealthError> {
+    let storage = StorageService::open().await.map_err(HealthError::HistoryFailed)?;
+    let mut runs: Vec<HealthRun> = storage
+        .get_all(HEALTH_HISTORY_STORE)
+        .await
+        .map_err(HealthError::HistoryFailed)?;
+    runs.sort_by_key(|run| std::cmp::Reverse(run.checked_at));
+    Ok(runs)
+}
+
+impl Default for HealthMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,295 @@
+use dioxus::prelude::*;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+use crate::models::ReviewChecklist;
+use crate::services::auth::AuthError;
+use crate::services::bundler::{BundlerClient, BundlerError};
+use crate::services::graphql::{GraphQLClient, GraphQLError};
+use crate::services::token::authenticated_tags;
+use crate::services::wallet::{connected_wallet, WalletError};
+use crate::utils::constants::{APP_NAME, MODERATOR_ADDRESSES};
+
+/// `Type` tag of hide-registry DataItems
+const HIDE_ENTRY_TYPE: &str = "Hide-Entry";
+
+/// Audience of the auth tokens moderation entries carry
+const MODERATION_AUDIENCE: &str = "moderation";
+
+/// Whether an entry de-lists or restores its target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum HideAction {
+    Hide,
+    Restore,
+}
+
+impl HideAction {
+    pub fn tag_value(&self) -> &'static str {
+        match self {
+            HideAction::Hide => "Hide",
+            HideAction::Restore => "Restore",
+        }
+    }
+}
+
+/// A signed moderator decision to de-list (or restore) an item
+///
+/// Entries are permanent DataItems, so the registry doubles as an audit
+/// trail of who hid what, when, and why.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HideEntry {
+    pub target_id: String,
+    pub action: HideAction,
+    pub reason: String,
+    /// Rights-claim tracking number when the entry resolves a claim
+    pub claim_id: Option<String>,
+    pub moderator: String,
+    /// Guideline scores the decision was made on, if any
+    pub review: Option<ReviewChecklist>,
+    pub entry_tx_id: String,
+    /// Block timestamp; `None` while still pending
+    pub timestamp: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub enum HideRegistryError {
+    LookupFailed(GraphQLError),
+    NotModerator,
+    AuthFailed(AuthError),
+    SigningFailed(WalletError),
+    SubmitFailed(BundlerError),
+}
+
+impl std::fmt::Display for HideRegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HideRegistryError::LookupFailed(e) => write!(f, "Could not load hide registry: {}", e),
+            HideRegistryError::NotModerator => write!(f, "Only moderation team wallets can perform this action"),
+            HideRegistryError::AuthFailed(e) => write!(f, "{}", e),
+            HideRegistryError::SigningFailed(e) => write!(f, "Could not sign moderation entry: {}", e),
+            HideRegistryError::SubmitFailed(e) => write!(f, "Could not publish moderation entry: {}", e),
+        }
+    }
+}
+
+// Global hide registry state using Dioxus signals
+fn use_hide_entries_state() -> &'static GlobalSignal<Vec<HideEntry>> {
+    static HIDE_ENTRIES: GlobalSignal<Vec<HideEntry>> = GlobalSignal::new(Vec::new);
+    &HIDE_ENTRIES
+}
+
+/// Whether `address` belongs to the moderation team
+pub fn is_moderator(address: &str) -> bool {
+    MODERATOR_ADDRESSES.contains(&address)
+}
+
+/// Client for the on-chain registry of de-listed items
+///
+/// Arweave data can't be deleted, so the frontend hides items listed here
+/// from discovery instead. Only entries signed by `MODERATOR_ADDRESSES`
+/// are honoured; for each target the most recent entry wins.
+pub struct HideRegistry {
+    graphql: GraphQLClient,
+}
+
+impl HideRegistry {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+        }
+    }
+
+    /// Load every registry entry from the gateway, oldest first
+    pub async fn load(&self) -> Result<Vec<HideEntry>, HideRegistryError> {
+        if MODERATOR_ADDRESSES.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let transactions = self.graphql
+            .find_transactions(MODERATOR_ADDRESSES, &[("Type", &[HIDE_ENTRY_TYPE])])
+            .await
+            .map_err(HideRegistryError::LookupFailed)?;
+
+        let mut entries: Vec<HideEntry> = transactions
+            .into_iter()
+            .filter_map(|tx| {
+                let action = match tx.tag("Action")? {
+                    "Hide" => HideAction::Hide,
+                    "Restore" => HideAction::Restore,
+                    _ => return None,
+                };
+
+                Some(HideEntry {
+                    target_id: tx.tag("Target-Id")?.to_string(),
+                    action,
+                    reason: tx.tag("Reason").unwrap_or_default().to_string(),
+                    claim_id: tx.tag("Claim-Id").map(|id| id.to_string()),
+                    moderator: tx.owner.clone(),
+                    review: ReviewChecklist::from_tags(&tx.tags),
+                    entry_tx_id: tx.id.clone(),
+                    timestamp: tx.timestamp,
+                })
+            })
+            .collect();
+
+        // Pending entries are the newest
+        entries.sort_by_key(|entry| entry.timestamp.unwrap_or(i64::MAX));
+        Ok(entries)
+    }
+
+    /// Reload entries into the global registry state
+    pub async fn refresh(&self) -> Result<(), HideRegistryError> {
+        let entries = self.load().await?;
+        log::info!("🙈 Loaded {} hide registry entries", entries.len());
+        *use_hide_entries_state().write() = entries;
+        Ok(())
+    }
+
+    /// Sign and publish a registry entry with the connected moderator wallet,
+    /// with the guideline scores behind it if there are any
+    pub async fn publish(
+        &self,
+        target_id: &str,
+        action: HideAction,
+        reason: &str,
+        claim_id: Option<&str>,
+        review: Option<&ReviewChecklist>,
+    ) -> Result<HideEntry, HideRegistryError> {
+        let mut tags = vec![
+            ("Type".to_string(), HIDE_ENTRY_TYPE.to_string()),
+            ("Target-Id".to_string(), target_id.to_string()),
+            ("Action".to_string(), action.tag_value().to_string()),
+            ("Reason".to_string(), reason.to_string()),
+        ];
+        if let Some(claim_id) = claim_id {
+            tags.push(("Claim-Id".to_string(), claim_id.to_string()));
+        }
+        if let Some(review) = review {
+            tags.extend(review.to_tags());
+        }
+
+        let (moderator, entry_tx_id) = publish_as_moderator(reason.as_bytes().to_vec(), tags).await?;
+
+        let entry = HideEntry {
+            target_id: target_id.to_string(),
+            action,
+            reason: reason.to_string(),
+            claim_id: claim_id.map(|id| id.to_string()),
+            moderator,
+            review: review.cloned(),
+            entry_tx_id,
+            timestamp: None,
+        };
+
+        use_hide_entries_state().write().push(entry.clone());
+        Ok(entry)
+    }
+}
+
+impl Default for HideRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sign a DataItem with the connected wallet, which must be a moderator's,
+/// and post it to the bundler. `App-Name` and a moderation auth token are
+/// added to `tags`.
+///
+/// Returns the moderator address and the new DataItem ID.
+pub async fn publish_as_moderator(data: Vec<u8>, tags: Vec<(String, String)>) -> Result<(String, String), HideRegistryError> {
+    let wallet = connected_wallet().await.map_err(HideRegistryError::SigningFailed)?;
+    let moderator = wallet
+        .get_active_address()
+        .await
+        .map_err(HideRegistryError::SigningFailed)?;
+    if !is_moderator(&moderator) {
+        return Err(HideRegistryError::NotModerator);
+    }
+
+    let mut all_tags = vec![("App-Name".to_string(), APP_NAME.to_string())];
+    all_tags.extend(tags);
+    all_tags.extend(
+        authenticated_tags(MODERATION_AUDIENCE)
+            .await
+            .map_err(HideRegistryError::AuthFailed)?,
+    );
+
+    let signed = wallet
+        .sign_data_item(data, all_tags)
+        .await
+        .map_err(HideRegistryError::SigningFailed)?;
+    let tx_id = BundlerClient::new()
+        .submit(signed)
+        .await
+        .map_err(HideRegistryError::SubmitFailed)?;
+
+    Ok((moderator, tx_id))
+}
+
+/// IDs currently de-listed: targets whose latest entry is a hide
+fn hidden_ids(entries: &[HideEntry]) -> HashSet<String> {
+    let mut latest: HashMap<&str, HideAction> = HashMap::new();
+    for entry in entries {
+        latest.insert(&entry.target_id, entry.action);
+    }
+
+    latest
+        .into_iter()
+        .filter(|(_, action)| *action == HideAction::Hide)
+        .map(|(target, _)| target.to_string())
+        .collect()
+}
+
+/// Every registry entry targeting an item, oldest first
+pub fn entries_for(target_id: &str) -> Vec<HideEntry> {
+    use_hide_entries_state()
+        .read()
+        .iter()
+        .filter(|entry| entry.target_id == target_id)
+        .cloned()
+        .collect()
+}
+
+/// Every loaded registry entry, oldest first
+pub fn all_entries() -> Vec<HideEntry> {
+    use_hide_entries_state().peek().clone()
+}
+
+/// Those of `entries` targeting items uploaded by `owner`, each with the
+/// item's title
+pub async fn entries_on_items_of(owner: &str, entries: Vec<HideEntry>) -> Result<Vec<(HideEntry, String)>, GraphQLError> {
+    if entries.is_empty() {
+        return Ok(Vec::new());
+    }
+    let ids: Vec<&str> = entries.iter().map(|entry| entry.target_id.as_str()).collect();
+    let targets = GraphQLClient::new().transactions_by_ids(&ids).await?;
+
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| {
+            let target = targets.iter().find(|tx| tx.id == entry.target_id && tx.owner == owner)?;
+            let title = target.tag("Title").unwrap_or("Untitled").to_string();
+            Some((entry, title))
+        })
+        .collect())
+}
+
+/// Whether an item is currently de-listed
+pub fn is_hidden(tx_id: &str) -> bool {
+    hidden_ids(&use_hide_entries_state().read()).contains(tx_id)
+}
+
+/// Hook returning the set of de-listed item IDs
+pub fn use_hidden_ids() -> Memo<HashSet<String>> {
+    use_memo(move || hidden_ids(&use_hide_entries_state().read()))
+}
+
+/// Hook returning the full registry audit trail, newest first
+pub fn use_hide_entries() -> Memo<Vec<HideEntry>> {
+    use_memo(move || {
+        let mut entries = use_hide_entries_state().read().clone();
+        entries.reverse();
+        entries
+    })
+}
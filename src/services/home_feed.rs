@@ -0,0 +1,284 @@
+//! Sections of the home page
+//!
+//! The home page shows three lists drawn from the archive itself: items
+//! moderators recently approved, series the moderation team features, and
+//! items drawing the most reactions lately. Each loads on its own, so a
+//! slow query holds up only its section.
+//!
+//! As for webhooks, an item is approved when its latest hide-registry entry
+//! restores it. Featured series are kept in a collection manifest with the
+//! id `FEATURED_COLLECTION_ID` whose items are series IDs. Only manifests
+//! signed by the moderation team count, and the newest from any of them
+//! wins, so the whole team can curate it.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{CollectionManifest, ContentMetadata, SeriesManifest, COLLECTION_MANIFEST_TYPE, SPIRITUAL_CONTENT_TYPE};
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, GraphQLError, TransactionSummary};
+use crate::services::hide_registry::{all_entries, is_hidden, publish_as_moderator, HideAction, HideEntry, HideRegistry, HideRegistryError};
+use crate::services::reactions::{tally, REACTION_TYPE};
+use crate::services::series::SeriesService;
+use crate::utils::constants::MODERATOR_ADDRESSES;
+
+/// Items or series shown in each section
+pub const HOME_SECTION_SIZE: usize = 6;
+
+/// `Collection-Id` of the moderation team's featured series
+pub const FEATURED_COLLECTION_ID: &str = "featured-series";
+
+/// Newest reactions looked at for trending items
+const TRENDING_REACTION_SAMPLE: u32 = 100;
+
+/// Reactions older than this don't count towards trending (seconds)
+const TRENDING_WINDOW_SECS: i64 = 7 * 24 * 60 * 60;
+
+#[derive(Debug, Clone)]
+pub enum HomeFeedError {
+    LookupFailed(GraphQLError),
+    InvalidRecord(String),
+    PublishFailed(HideRegistryError),
+}
+
+impl std::fmt::Display for HomeFeedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HomeFeedError::LookupFailed(e) => write!(f, "Could not load this section: {}", e),
+            HomeFeedError::InvalidRecord(msg) => write!(f, "Featured series record is invalid: {}", msg),
+            HomeFeedError::PublishFailed(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<GraphQLError> for HomeFeedError {
+    fn from(error: GraphQLError) -> Self {
+        HomeFeedError::LookupFailed(error)
+    }
+}
+
+/// Newest items for the first section
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecentItems {
+    pub items: Vec<ContentMetadata>,
+    /// Whether these are moderator approvals; when nothing has been
+    /// approved yet they're the newest uploads instead
+    pub approved: bool,
+}
+
+/// Targets whose latest entry in `entries` (oldest first) is a restore,
+/// newest approval first
+pub fn approved_ids(entries: &[HideEntry]) -> Vec<String> {
+    let mut seen: HashSet<&str> = HashSet::new();
+    entries
+        .iter()
+        .rev()
+        .filter(|entry| seen.insert(&entry.target_id))
+        .filter(|entry| entry.action == HideAction::Restore)
+        .map(|entry| entry.target_id.clone())
+        .collect()
+}
+
+/// Items reacted to in `reactions` since `since`, most reactions first
+///
+/// Each address counts once per reaction, as on item pages; pending
+/// reactions count as recent.
+pub fn trending_ids(reactions: &[TransactionSummary], since: i64) -> Vec<String> {
+    let recent: Vec<TransactionSummary> = reactions
+        .iter()
+        .filter(|tx| tx.timestamp.is_none_or(|timestamp| timestamp >= since))
+        .cloned()
+        .collect();
+    let mut ranked: Vec<(String, usize)> = tally(&recent)
+        .into_iter()
+        .map(|(tx_id, counts)| (tx_id, counts.total()))
+        .filter(|(_, total)| *total > 0)
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked.into_iter().map(|(tx_id, _)| tx_id).collect()
+}
+
+/// Metadata of the listed archive items among `ids`, in the same order
+async fn listed_items(graphql: &GraphQLClient, ids: &[String]) -> Result<Vec<ContentMetadata>, GraphQLError> {
+    let ids: Vec<&str> = ids.iter().map(String::as_str).filter(|id| !is_hidden(id)).collect();
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let found = graphql.transactions_by_ids(&ids).await?;
+
+    let mut by_id: HashMap<String, ContentMetadata> = found
+        .iter()
+        .filter(|tx| tx.tag("Type") == Some(SPIRITUAL_CONTENT_TYPE))
+        .filter_map(|tx| ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp))
+        .map(|metadata| (metadata.tx_id.clone(), metadata))
+        .collect();
+    Ok(ids.iter().filter_map(|id| by_id.remove(*id)).take(HOME_SECTION_SIZE).collect())
+}
+
+/// Recently approved items, or the newest listed uploads when nothing has
+/// been approved yet
+pub async fn load_recent_items() -> Result<RecentItems, HomeFeedError> {
+    // The registry may not have loaded yet on a first visit
+    if all_entries().is_empty() {
+        if let Err(e) = HideRegistry::new().refresh().await {
+            log::warn!("{}", e);
+        }
+    }
+
+    let graphql = GraphQLClient::new();
+    let approved = listed_items(&graphql, &approved_ids(&all_entries())).await?;
+    if !approved.is_empty() {
+        return Ok(RecentItems { items: approved, approved: true });
+    }
+
+    let page = graphql
+        .find_transactions_page(&[("Type", &[SPIRITUAL_CONTENT_TYPE])], HOME_SECTION_SIZE as u32 * 2, None)
+        .await?;
+    let items = page
+        .transactions
+        .iter()
+        .filter(|tx| !is_hidden(&tx.id))
+        .filter_map(|tx| ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp))
+        .take(HOME_SECTION_SIZE)
+        .collect();
+    Ok(RecentItems { items, approved: false })
+}
+
+/// Items with the most reactions over the last week
+pub async fn load_trending_items() -> Result<Vec<ContentMetadata>, HomeFeedError> {
+    let graphql = GraphQLClient::new();
+    let page = graphql
+        .find_transactions_page(&[("Type", &[REACTION_TYPE])], TRENDING_REACTION_SAMPLE, None)
+        .await?;
+    let since = chrono::Utc::now().timestamp() - TRENDING_WINDOW_SECS;
+    // Reactions to comments rank too; leave room for them to drop out
+    let ids: Vec<String> = trending_ids(&page.transactions, since).into_iter().take(HOME_SECTION_SIZE * 2).collect();
+    listed_items(&graphql, &ids).await.map_err(HomeFeedError::from)
+}
+
+/// The team's current featured series record, if it has published one
+async fn featured_record() -> Result<Option<CollectionManifest>, HomeFeedError> {
+    if MODERATOR_ADDRESSES.is_empty() {
+        return Ok(None);
+    }
+    let manifests = GraphQLClient::new()
+        .find_transactions(
+            MODERATOR_ADDRESSES,
+            &[("Type", &[COLLECTION_MANIFEST_TYPE]), ("Collection-Id", &[FEATURED_COLLECTION_ID])],
+        )
+        .await?;
+    // Pending manifests have no timestamp yet and are the newest
+    let Some(latest) = manifests.iter().max_by_key(|tx| tx.timestamp.unwrap_or(i64::MAX)) else {
+        return Ok(None);
+    };
+
+    let response = GatewayManager::new()
+        .get(&format!("/{}", latest.id))
+        .await
+        .map_err(|e| HomeFeedError::InvalidRecord(e.to_string()))?;
+    let record = response
+        .json()
+        .await
+        .map_err(|e| HomeFeedError::InvalidRecord(e.to_string()))?;
+    Ok(Some(record))
+}
+
+/// IDs of the featured series in the team's order
+pub async fn featured_series_ids() -> Result<Vec<String>, HomeFeedError> {
+    Ok(featured_record().await?.map(|record| record.items).unwrap_or_default())
+}
+
+/// Featured series in the team's order; series that can't be loaded are
+/// skipped
+pub async fn load_featured_series() -> Result<Vec<SeriesManifest>, HomeFeedError> {
+    let service = SeriesService::new();
+    let mut series = Vec::new();
+    for series_id in featured_series_ids().await?.iter().take(HOME_SECTION_SIZE) {
+        match service.load(series_id).await {
+            Ok(manifest) => series.push(manifest),
+            Err(e) => log::warn!("Skipping featured series {}: {}", series_id, e),
+        }
+    }
+    Ok(series)
+}
+
+/// Add a series to the featured record, or take it off, with the connected
+/// moderator wallet
+pub async fn set_featured(series_id: &str, featured: bool) -> Result<(), HomeFeedError> {
+    let mut record = featured_record().await?.unwrap_or_else(|| CollectionManifest {
+        collection_id: FEATURED_COLLECTION_ID.to_string(),
+        ..CollectionManifest::new("Featured series")
+    });
+    if featured {
+        record.add_item(series_id);
+    } else {
+        record.remove_item(series_id);
+    }
+    record.version += 1;
+
+    let data = serde_json::to_vec(&record).map_err(|e| HomeFeedError::InvalidRecord(e.to_string()))?;
+    let tags = vec![
+        ("Content-Type".to_string(), "application/json".to_string()),
+        ("Type".to_string(), COLLECTION_MANIFEST_TYPE.to_string()),
+        ("Collection-Id".to_string(), FEATURED_COLLECTION_ID.to_string()),
+        ("Title".to_string(), record.title.clone()),
+        ("Item-Count".to_string(), record.items.len().to_string()),
+        ("Config-Version".to_string(), record.version.to_string()),
+    ];
+    let (_, tx_id) = publish_as_moderator(data, tags).await.map_err(HomeFeedError::PublishFailed)?;
+    log::info!("⭐ Published featured series v{} ({} series, {})", record.version, record.items.len(), tx_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn entry(target_id: &str, action: HideAction) -> HideEntry {
+        HideEntry {
+            target_id: target_id.to_string(),
+            action,
+            reason: String::new(),
+            claim_id: None,
+            moderator: "mod-1".to_string(),
+            review: None,
+            entry_tx_id: format!("entry-{}", target_id),
+            timestamp: Some(1_700_000_000),
+        }
+    }
+
+    fn reaction(owner: &str, target: &str, timestamp: Option<i64>) -> TransactionSummary {
+        TransactionSummary {
+            id: format!("{}-{}", owner, target),
+            owner: owner.to_string(),
+            tags: vec![
+                ("Target-Tx".to_string(), target.to_string()),
+                ("Reaction".to_string(), "Amen".to_string()),
+            ],
+            block_height: timestamp.map(|_| 1),
+            timestamp,
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn ranks_approvals_and_trending_items() {
+        let entries = vec![
+            entry("item-a", HideAction::Restore),
+            entry("item-b", HideAction::Hide),
+            entry("item-c", HideAction::Restore),
+            entry("item-b", HideAction::Restore),
+            entry("item-c", HideAction::Hide),
+        ];
+        assert_eq!(approved_ids(&entries), vec!["item-b".to_string(), "item-a".to_string()]);
+
+        let since = 1_000;
+        let reactions = vec![
+            reaction("alice", "item-a", Some(2_000)),
+            reaction("bob", "item-b", Some(2_000)),
+            reaction("carol", "item-b", None),
+            reaction("dave", "item-c", Some(500)),
+            reaction("erin", "item-c", Some(500)),
+        ];
+        assert_eq!(trending_ids(&reactions, since), vec!["item-b".to_string(), "item-a".to_string()]);
+    }
+}
@@ -0,0 +1,364 @@
+//! Caching HTTP for gateway metadata, kept in IndexedDB and reused by
+//! `Cache-Control` rules the way a browser cache would
+
+use dioxus::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+
+use crate::services::gateway::{GatewayError, GatewayManager};
+use crate::services::storage::{StorageService, HTTP_CACHE_STORE};
+use crate::utils::constants::{HTTP_CACHE_MAX_AGE_SECS, HTTP_CACHE_RETENTION_SECS, HTTP_CACHE_STALE_SECS};
+use crate::utils::crypto::sha256_hex;
+
+thread_local! {
+    /// Keys with a background revalidation in flight
+    static REVALIDATING: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+    static PRUNED: Cell<bool> = const { Cell::new(false) };
+}
+
+#[derive(Debug, Clone)]
+pub enum HttpError {
+    Gateway(GatewayError),
+    InvalidBody(String),
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpError::Gateway(e) => write!(f, "{}", e),
+            HttpError::InvalidBody(msg) => write!(f, "Invalid response body: {}", msg),
+        }
+    }
+}
+
+impl From<GatewayError> for HttpError {
+    fn from(error: GatewayError) -> Self {
+        HttpError::Gateway(error)
+    }
+}
+
+/// A response from the network or the cache
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, HttpError> {
+        serde_json::from_str(&self.body).map_err(|e| HttpError::InvalidBody(e.to_string()))
+    }
+}
+
+/// How long a response may be reused, from its `Cache-Control` header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CachePolicy {
+    no_store: bool,
+    max_age_secs: i64,
+    stale_secs: i64,
+}
+
+impl CachePolicy {
+    fn parse(cache_control: Option<&str>) -> Self {
+        let mut policy = CachePolicy {
+            no_store: false,
+            max_age_secs: HTTP_CACHE_MAX_AGE_SECS,
+            stale_secs: HTTP_CACHE_STALE_SECS,
+        };
+        for directive in cache_control.unwrap_or_default().split(',') {
+            let (name, value) = directive.split_once('=').unwrap_or((directive, ""));
+            let seconds = value.trim().trim_matches('"').parse::<i64>().ok();
+            match (name.trim().to_ascii_lowercase().as_str(), seconds) {
+                ("no-store", _) => policy.no_store = true,
+                ("no-cache", _) => policy.max_age_secs = 0,
+                ("max-age", Some(seconds)) => policy.max_age_secs = seconds.max(0),
+                ("stale-while-revalidate", Some(seconds)) => policy.stale_secs = seconds.max(0),
+                _ => {}
+            }
+        }
+        policy
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Freshness {
+    Fresh,
+    /// Servable while it's refreshed in the background
+    Stale,
+    /// Must be fetched again before use, unless the network is down
+    Expired,
+}
+
+/// A response body as stored in IndexedDB
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedResponse {
+    key: String,
+    body: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// When the response was received or last revalidated (unix seconds)
+    stored_at: i64,
+    max_age_secs: i64,
+    stale_secs: i64,
+}
+
+impl CachedResponse {
+    fn freshness(&self, now: i64) -> Freshness {
+        let age = now - self.stored_at;
+        if age < self.max_age_secs {
+            Freshness::Fresh
+        } else if age < self.max_age_secs + self.stale_secs {
+            Freshness::Stale
+        } else {
+            Freshness::Expired
+        }
+    }
+
+    fn response(&self) -> HttpResponse {
+        HttpResponse {
+            status: 200,
+            body: self.body.clone(),
+        }
+    }
+}
+
+/// What to send, kept so a background revalidation can send it again
+#[derive(Debug, Clone)]
+enum CachedRequest {
+    Get(String),
+    PostJson { path: String, body: String },
+}
+
+impl CachedRequest {
+    fn key(&self) -> String {
+        match self {
+            CachedRequest::Get(path) => format!("GET {}", path),
+            CachedRequest::PostJson { path, body } => format!("POST {} {}", path, sha256_hex(body.as_bytes())),
+        }
+    }
+}
+
+/// GETs and JSON POSTs through the gateways, cached in IndexedDB
+pub struct HttpCache {
+    gateways: GatewayManager,
+}
+
+impl HttpCache {
+    pub fn new() -> Self {
+        Self {
+            gateways: GatewayManager::new(),
+        }
+    }
+
+    /// GET a gateway path
+    pub async fn get(&self, path: &str) -> Result<HttpResponse, HttpError> {
+        self.send(CachedRequest::Get(path.to_string())).await
+    }
+
+    /// POST a JSON body to a gateway path
+    pub async fn post_json<T: Serialize>(&self, path: &str, body: &T) -> Result<HttpResponse, HttpError> {
+        let body = serde_json::to_string(body).map_err(|e| HttpError::InvalidBody(e.to_string()))?;
+        self.send(CachedRequest::PostJson { path: path.to_string(), body }).await
+    }
+
+    /// Drop the cached response to a POST, e.g. when its body turned out to
+    /// report an error
+    pub async fn forget_post<T: Serialize>(&self, path: &str, body: &T) {
+        let Ok(body) = serde_json::to_string(body) else {
+            return;
+        };
+        let key = CachedRequest::PostJson { path: path.to_string(), body }.key();
+        if let Ok(storage) = StorageService::open().await {
+            let _ = storage.delete(HTTP_CACHE_STORE, &key).await;
+        }
+    }
+
+    async fn send(&self, request: CachedRequest) -> Result<HttpResponse, HttpError> {
+        prune_once();
+        let key = request.key();
+        let cached = load(&key).await;
+        if let Some(cached) = &cached {
+            match cached.freshness(chrono::Utc::now().timestamp()) {
+                Freshness::Fresh => return Ok(cached.response()),
+                Freshness::Stale => {
+                    revalidate_in_background(request, cached.clone());
+                    return Ok(cached.response());
+                }
+                Freshness::Expired => {}
+            }
+        }
+
+        match self.fetch(&request, cached.clone()).await {
+            Ok(response) => Ok(response),
+            Err(e) => match cached {
+                Some(cached) => {
+                    log::warn!("Serving expired {} from the cache: {}", key, e);
+                    Ok(cached.response())
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    // Send `request`, conditionally when there is a cached copy, and store
+    // what comes back
+    async fn fetch(&self, request: &CachedRequest, cached: Option<CachedResponse>) -> Result<HttpResponse, HttpError> {
+        let response = self
+            .gateways
+            .send_with_failover(|client, base| {
+                let mut builder = match request {
+                    CachedRequest::Get(path) => client.get(format!("{}{}", base, path)),
+                    CachedRequest::PostJson { path, body } => client
+                        .post(format!("{}{}", base, path))
+                        .header("Content-Type", "application/json")
+                        .body(body.clone()),
+                };
+                if let Some(cached) = &cached {
+                    if let Some(etag) = &cached.etag {
+                        builder = builder.header("If-None-Match", etag);
+                    }
+                    if let Some(last_modified) = &cached.last_modified {
+                        builder = builder.header("If-Modified-Since", last_modified);
+                    }
+                }
+                builder
+            })
+            .await?;
+
+        let header = |name: &str| response.headers().get(name).and_then(|value| value.to_str().ok()).map(str::to_string);
+        let policy = CachePolicy::parse(header("Cache-Control").as_deref());
+        let now = chrono::Utc::now().timestamp();
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(mut cached) = cached {
+                cached.stored_at = now;
+                cached.max_age_secs = policy.max_age_secs;
+                cached.stale_secs = policy.stale_secs;
+                store(&cached).await;
+                return Ok(cached.response());
+            }
+        }
+
+        let status = response.status().as_u16();
+        let etag = header("ETag");
+        let last_modified = header("Last-Modified");
+        let body = response.text().await.map_err(|e| HttpError::InvalidBody(e.to_string()))?;
+        let fetched = HttpResponse { status, body };
+
+        if fetched.is_success() && !policy.no_store {
+            store(&CachedResponse {
+                key: request.key(),
+                body: fetched.body.clone(),
+                etag,
+                last_modified,
+                stored_at: now,
+                max_age_secs: policy.max_age_secs,
+                stale_secs: policy.stale_secs,
+            })
+            .await;
+        }
+        Ok(fetched)
+    }
+}
+
+impl Default for HttpCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn load(key: &str) -> Option<CachedResponse> {
+    let storage = StorageService::open().await.ok()?;
+    storage.get(HTTP_CACHE_STORE, key).await.ok()?
+}
+
+async fn store(cached: &CachedResponse) {
+    let storage = match StorageService::open().await {
+        Ok(storage) => storage,
+        Err(e) => {
+            log::warn!("HTTP cache unavailable: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = storage.put(HTTP_CACHE_STORE, &cached.key, cached).await {
+        log::warn!("Failed to cache {}: {}", cached.key, e);
+    }
+}
+
+// Refresh a stale entry without holding up the caller; one request per key
+fn revalidate_in_background(request: CachedRequest, cached: CachedResponse) {
+    let key = cached.key.clone();
+    if !REVALIDATING.with(|revalidating| revalidating.borrow_mut().insert(key.clone())) {
+        return;
+    }
+    spawn_forever(async move {
+        if let Err(e) = HttpCache::new().fetch(&request, Some(cached)).await {
+            log::warn!("Could not revalidate {}: {}", key, e);
+        }
+        REVALIDATING.with(|revalidating| revalidating.borrow_mut().remove(&key));
+    });
+}
+
+// Once per session, drop entries that have been expired for longer than
+// `HTTP_CACHE_RETENTION_SECS`; until then they're the offline fallback
+fn prune_once() {
+    if PRUNED.with(|pruned| pruned.replace(true)) {
+        return;
+    }
+    spawn_forever(async {
+        let Ok(storage) = StorageService::open().await else {
+            return;
+        };
+        let Ok(entries) = storage.get_all::<CachedResponse>(HTTP_CACHE_STORE).await else {
+            return;
+        };
+        let cutoff = chrono::Utc::now().timestamp() - HTTP_CACHE_RETENTION_SECS;
+        let mut removed = 0;
+        for entry in entries.iter().filter(|entry| entry.freshness(cutoff) == Freshness::Expired) {
+            if storage.delete(HTTP_CACHE_STORE, &entry.key).await.is_ok() {
+                removed += 1;
+            }
+        }
+        if removed > 0 {
+            log::info!("🧹 Pruned {} old responses from the HTTP cache", removed);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn cache_control_sets_the_policy() {
+        let policy = CachePolicy::parse(Some("public, max-age=300, stale-while-revalidate=60"));
+        assert_eq!((policy.no_store, policy.max_age_secs, policy.stale_secs), (false, 300, 60));
+
+        assert!(CachePolicy::parse(Some("no-store")).no_store);
+        assert_eq!(CachePolicy::parse(Some("No-Cache")).max_age_secs, 0);
+        let default = CachePolicy::parse(None);
+        assert_eq!((default.max_age_secs, default.stale_secs), (HTTP_CACHE_MAX_AGE_SECS, HTTP_CACHE_STALE_SECS));
+    }
+
+    #[wasm_bindgen_test]
+    fn entries_go_stale_then_expire() {
+        let cached = CachedResponse {
+            key: CachedRequest::Get("/tx".to_string()).key(),
+            body: "{}".to_string(),
+            etag: None,
+            last_modified: None,
+            stored_at: 1000,
+            max_age_secs: 60,
+            stale_secs: 30,
+        };
+        assert_eq!(cached.freshness(1059), Freshness::Fresh);
+        assert_eq!(cached.freshness(1060), Freshness::Stale);
+        assert_eq!(cached.freshness(1090), Freshness::Expired);
+    }
+}
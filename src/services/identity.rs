@@ -0,0 +1,324 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bundles_rs::crypto::ethereum::EthereumSigner;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::services::arweave::ArweaveService;
+use crate::services::bundler::{BundlerClient, BundlerError};
+use crate::services::graphql::{GraphQLClient, GraphQLError, TransactionSummary};
+use crate::services::storage::{StorageError, StorageService, IDENTITY_STORE};
+use crate::services::upload::{publish_with_wallet, signing_wallet, PublishError};
+use crate::services::wallet::connected_wallet;
+
+/// `Type` tag of the DataItem a wallet signs to claim a pseudonym
+pub const IDENTITY_LINK_TYPE: &str = "Identity-Link";
+
+/// `Type` tag of the DataItem a pseudonym signs to accept a wallet's claim
+pub const IDENTITY_ATTESTATION_TYPE: &str = "Identity-Attestation";
+
+/// `Signer-Kind` tag value on DataItems signed by a local pseudonym, so
+/// readers can tell them apart from wallet-signed data
+pub const PSEUDONYMOUS_SIGNER: &str = "Pseudonymous";
+
+/// Key of the single record in `IDENTITY_STORE`
+const IDENTITY_KEY: &str = "local";
+
+#[derive(Debug, Clone)]
+pub enum IdentityError {
+    Storage(StorageError),
+    KeyFailed(String),
+    NotEnabled,
+    SigningFailed(String),
+    SubmitFailed(BundlerError),
+    PublishFailed(PublishError),
+    WalletChanged,
+}
+
+impl std::fmt::Display for IdentityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdentityError::Storage(e) => write!(f, "{}", e),
+            IdentityError::KeyFailed(msg) => write!(f, "Local identity key is unusable: {}", msg),
+            IdentityError::NotEnabled => write!(f, "Connect a wallet or turn on an anonymous identity in Settings"),
+            IdentityError::SigningFailed(msg) => write!(f, "Could not sign with the anonymous identity: {}", msg),
+            IdentityError::SubmitFailed(e) => write!(f, "Could not publish to the bundler: {}", e),
+            IdentityError::PublishFailed(e) => write!(f, "{}", e),
+            IdentityError::WalletChanged => write!(f, "The wallet switched accounts while linking, please try again"),
+        }
+    }
+}
+
+impl From<StorageError> for IdentityError {
+    fn from(error: StorageError) -> Self {
+        IdentityError::Storage(error)
+    }
+}
+
+/// Persisted form of the local identity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredIdentity {
+    /// Base64 secp256k1 secret key
+    secret_key: String,
+    address: String,
+    created_at: i64,
+    /// Wallet that signed an identity link for this pseudonym
+    #[serde(default)]
+    linked_wallet: Option<String>,
+}
+
+impl StoredIdentity {
+    fn signer(&self) -> Result<EthereumSigner, IdentityError> {
+        let secret = STANDARD
+            .decode(&self.secret_key)
+            .map_err(|e| IdentityError::KeyFailed(e.to_string()))?;
+        EthereumSigner::from_bytes(&secret).map_err(|e| IdentityError::KeyFailed(e.to_string()))
+    }
+}
+
+/// Public view of the local identity
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalIdentity {
+    pub address: String,
+    pub created_at: i64,
+    pub linked_wallet: Option<String>,
+}
+
+impl From<&StoredIdentity> for LocalIdentity {
+    fn from(stored: &StoredIdentity) -> Self {
+        Self {
+            address: stored.address.clone(),
+            created_at: stored.created_at,
+            linked_wallet: stored.linked_wallet.clone(),
+        }
+    }
+}
+
+/// Who signed a low-stakes DataItem
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signer {
+    pub address: String,
+    /// `false` for the local pseudonym, which proves nothing about its owner
+    pub verified: bool,
+}
+
+// Global local identity using Dioxus signals
+fn use_identity_state() -> &'static GlobalSignal<Option<LocalIdentity>> {
+    static IDENTITY: GlobalSignal<Option<LocalIdentity>> = GlobalSignal::new(|| None);
+    &IDENTITY
+}
+
+/// Opt-in pseudonymous keypair for visitors without a wallet
+///
+/// The key never leaves IndexedDB. It only signs low-stakes items such as
+/// reports, and everything it signs is tagged as pseudonymous.
+pub struct IdentityService;
+
+impl IdentityService {
+    async fn stored() -> Result<Option<StoredIdentity>, IdentityError> {
+        let storage = StorageService::open().await?;
+        Ok(storage.get(IDENTITY_STORE, IDENTITY_KEY).await?)
+    }
+
+    async fn store(stored: &StoredIdentity) -> Result<(), IdentityError> {
+        StorageService::open().await?.put(IDENTITY_STORE, IDENTITY_KEY, stored).await?;
+        *use_identity_state().write() = Some(LocalIdentity::from(stored));
+        Ok(())
+    }
+
+    /// Load the identity, if the user has opted in
+    pub async fn load() -> Result<(), IdentityError> {
+        *use_identity_state().write() = Self::stored().await?.as_ref().map(LocalIdentity::from);
+        Ok(())
+    }
+
+    /// Create the local keypair, keeping any existing one
+    pub async fn enable() -> Result<LocalIdentity, IdentityError> {
+        if let Some(stored) = Self::stored().await? {
+            return Ok(LocalIdentity::from(&stored));
+        }
+
+        let mut secret = [0u8; 32];
+        getrandom::getrandom(&mut secret).map_err(|e| IdentityError::KeyFailed(e.to_string()))?;
+        let signer = EthereumSigner::from_bytes(&secret).map_err(|e| IdentityError::KeyFailed(e.to_string()))?;
+        let stored = StoredIdentity {
+            secret_key: STANDARD.encode(secret),
+            address: signer.address_string(),
+            created_at: chrono::Utc::now().timestamp(),
+            linked_wallet: None,
+        };
+        Self::store(&stored).await?;
+
+        log::info!("🎭 Created anonymous identity {}", stored.address);
+        Ok(LocalIdentity::from(&stored))
+    }
+
+    /// Delete the keypair; items it already signed stay published
+    pub async fn remove() -> Result<(), IdentityError> {
+        StorageService::open().await?.delete(IDENTITY_STORE, IDENTITY_KEY).await?;
+        *use_identity_state().write() = None;
+        Ok(())
+    }
+
+    /// Sign and publish a DataItem with the pseudonym, returning its ID
+    pub async fn publish(data: Vec<u8>, tags: Vec<(String, String)>) -> Result<String, IdentityError> {
+        let stored = Self::stored().await?.ok_or(IdentityError::NotEnabled)?;
+        let arweave = ArweaveService::new_with_signer(stored.signer()?);
+
+        let mut all_tags = tags;
+        all_tags.push(("Signer-Kind".to_string(), PSEUDONYMOUS_SIGNER.to_string()));
+        let tag_refs: Vec<(&str, &str)> = all_tags.iter().map(|(n, v)| (n.as_str(), v.as_str())).collect();
+
+        let item = arweave
+            .create_tagged_item(&tag_refs, data)
+            .map_err(|e| IdentityError::SigningFailed(e.to_string()))?;
+        let bytes = arweave
+            .serialize_item(&item)
+            .map_err(|e| IdentityError::SigningFailed(e.to_string()))?;
+        BundlerClient::new().submit(bytes).await.map_err(IdentityError::SubmitFailed)
+    }
+
+    /// Claim the pseudonym with the connected wallet
+    ///
+    /// The pseudonym first signs an `Identity-Attestation` naming the
+    /// wallet, then the wallet signs an `Identity-Link` naming the
+    /// pseudonym and that attestation. Readers only accept the pair, so
+    /// neither key can claim the other on its own.
+    pub async fn link_to_wallet() -> Result<String, IdentityError> {
+        let mut stored = Self::stored().await?.ok_or(IdentityError::NotEnabled)?;
+        let (_, wallet) = signing_wallet().await.map_err(IdentityError::PublishFailed)?;
+
+        let attestation_tags = vec![
+            ("Content-Type".to_string(), "text/plain".to_string()),
+            ("Type".to_string(), IDENTITY_ATTESTATION_TYPE.to_string()),
+            ("Wallet".to_string(), wallet.clone()),
+        ];
+        let attestation_id = Self::publish(wallet.as_bytes().to_vec(), attestation_tags).await?;
+
+        let tags = vec![
+            ("Content-Type".to_string(), "text/plain".to_string()),
+            ("Type".to_string(), IDENTITY_LINK_TYPE.to_string()),
+            ("Pseudonym".to_string(), stored.address.clone()),
+            ("Attestation".to_string(), attestation_id),
+        ];
+
+        let (owner, tx_id) = publish_with_wallet(Vec::new(), tags)
+            .await
+            .map_err(IdentityError::PublishFailed)?;
+        if owner != wallet {
+            return Err(IdentityError::WalletChanged);
+        }
+        stored.linked_wallet = Some(owner);
+        Self::store(&stored).await?;
+
+        log::info!("🎭 Linked anonymous identity {} in {}", stored.address, tx_id);
+        Ok(tx_id)
+    }
+
+    /// Wallet that claimed `pseudonym`, if the pseudonym accepted the claim
+    pub async fn linked_wallet(pseudonym: &str) -> Result<Option<String>, GraphQLError> {
+        let graphql = GraphQLClient::new();
+        let links = graphql
+            .find_transactions(&[], &[("Type", &[IDENTITY_LINK_TYPE]), ("Pseudonym", &[pseudonym])])
+            .await?;
+        if links.is_empty() {
+            return Ok(None);
+        }
+
+        let attestations = graphql
+            .find_transactions(&[pseudonym], &[("Type", &[IDENTITY_ATTESTATION_TYPE])])
+            .await?;
+        Ok(counter_signed_wallet(pseudonym, &links, &attestations))
+    }
+}
+
+/// Owner of the first link whose referenced attestation was signed by
+/// `pseudonym` and names that same owner
+fn counter_signed_wallet(pseudonym: &str, links: &[TransactionSummary], attestations: &[TransactionSummary]) -> Option<String> {
+    links
+        .iter()
+        .filter(|link| link.tag("Pseudonym") == Some(pseudonym))
+        .find(|link| {
+            attestations.iter().any(|attestation| {
+                attestation.owner == pseudonym
+                    && link.tag("Attestation") == Some(attestation.id.as_str())
+                    && attestation.tag("Wallet") == Some(link.owner.as_str())
+            })
+        })
+        .map(|link| link.owner.clone())
+}
+
+/// Publish a low-stakes DataItem with the connected wallet, falling back
+/// to the local pseudonym when no wallet is connected
+pub async fn publish_low_stakes(data: Vec<u8>, tags: Vec<(String, String)>) -> Result<(Signer, String), IdentityError> {
+    let wallet = connected_wallet()
+        .await
+        .map_err(|e| IdentityError::PublishFailed(PublishError::SigningFailed(e)))?;
+    if wallet.get_active_address().await.is_ok() {
+        let (address, tx_id) = publish_with_wallet(data, tags)
+            .await
+            .map_err(IdentityError::PublishFailed)?;
+        return Ok((Signer { address, verified: true }, tx_id));
+    }
+
+    let address = use_identity_state()
+        .read()
+        .as_ref()
+        .map(|identity| identity.address.clone())
+        .ok_or(IdentityError::NotEnabled)?;
+    let tx_id = IdentityService::publish(data, tags).await?;
+    Ok((Signer { address, verified: false }, tx_id))
+}
+
+/// Load the local identity in the background
+pub fn init_identity() {
+    spawn(async {
+        if let Err(e) = IdentityService::load().await {
+            log::warn!("Failed to load anonymous identity: {}", e);
+        }
+    });
+}
+
+/// The local identity from memory, if enabled
+pub fn local_identity() -> Option<LocalIdentity> {
+    use_identity_state().read().clone()
+}
+
+/// Hook returning the local identity, if enabled
+pub fn use_local_identity() -> Memo<Option<LocalIdentity>> {
+    use_memo(move || use_identity_state().read().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn item(id: &str, owner: &str, tags: &[(&str, &str)]) -> TransactionSummary {
+        TransactionSummary {
+            id: id.to_string(),
+            owner: owner.to_string(),
+            tags: tags.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+            block_height: None,
+            timestamp: None,
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn links_need_the_pseudonyms_attestation() {
+        let link = item("link", "wallet", &[("Pseudonym", "0xanon"), ("Attestation", "attest")]);
+        let attestation = item("attest", "0xanon", &[("Wallet", "wallet")]);
+
+        let both = counter_signed_wallet("0xanon", std::slice::from_ref(&link), std::slice::from_ref(&attestation));
+        assert_eq!(both, Some("wallet".to_string()));
+        assert_eq!(counter_signed_wallet("0xanon", std::slice::from_ref(&link), &[]), None);
+
+        let other_wallet = item("attest", "0xanon", &[("Wallet", "someone-else")]);
+        assert_eq!(counter_signed_wallet("0xanon", std::slice::from_ref(&link), &[other_wallet]), None);
+
+        let forged = item("attest", "0xforger", &[("Wallet", "wallet")]);
+        assert_eq!(counter_signed_wallet("0xanon", std::slice::from_ref(&link), &[forged]), None);
+
+        let unreferenced = item("link", "wallet", &[("Pseudonym", "0xanon"), ("Attestation", "other")]);
+        assert_eq!(counter_signed_wallet("0xanon", &[unreferenced], &[attestation]), None);
+    }
+}
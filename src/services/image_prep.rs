@@ -0,0 +1,146 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::utils::constants::{COVER_IMAGE_QUALITY, COVER_MAX_DIMENSION};
+
+#[derive(Debug, Clone)]
+pub enum ImagePrepError {
+    Unsupported,
+    DecodeFailed(String),
+    EncodeFailed(String),
+}
+
+impl std::fmt::Display for ImagePrepError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImagePrepError::Unsupported => write!(f, "This browser cannot process images"),
+            ImagePrepError::DecodeFailed(msg) => write!(f, "Could not read image: {}", msg),
+            ImagePrepError::EncodeFailed(msg) => write!(f, "Could not compress image: {}", msg),
+        }
+    }
+}
+
+/// Limits applied when optimizing an image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageOptions {
+    /// Longest side in pixels; smaller images are not upscaled
+    pub max_dimension: u32,
+    /// Encoder quality from 0.0 to 1.0
+    pub quality: f64,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        Self {
+            max_dimension: COVER_MAX_DIMENSION,
+            quality: COVER_IMAGE_QUALITY,
+        }
+    }
+}
+
+/// An image ready to publish
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptimizedImage {
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Resize and re-encode `bytes` as WebP using a canvas
+///
+/// Browsers that can't encode WebP fall back to JPEG. If the result is no
+/// smaller than the input, the original is kept.
+pub async fn optimize_image(bytes: &[u8], content_type: &str, options: ImageOptions) -> Result<OptimizedImage, ImagePrepError> {
+    let window = web_sys::window().ok_or(ImagePrepError::Unsupported)?;
+    let document = window.document().ok_or(ImagePrepError::Unsupported)?;
+
+    let parts = js_sys::Array::new();
+    parts.push(&js_sys::Uint8Array::from(bytes));
+    let blob = web_sys::Blob::new_with_u8_array_sequence(&parts)
+        .map_err(|e| ImagePrepError::DecodeFailed(format!("{:?}", e)))?;
+    let promise = window
+        .create_image_bitmap_with_blob(&blob)
+        .map_err(|e| ImagePrepError::DecodeFailed(format!("{:?}", e)))?;
+    let bitmap: web_sys::ImageBitmap = JsFuture::from(promise)
+        .await
+        .map_err(|e| ImagePrepError::DecodeFailed(format!("{:?}", e)))?
+        .dyn_into()
+        .map_err(|_| ImagePrepError::DecodeFailed("unexpected decoder result".to_string()))?;
+
+    let original = (bitmap.width(), bitmap.height());
+    let (width, height) = fit_within(original.0, original.1, options.max_dimension);
+    let canvas: web_sys::HtmlCanvasElement = document
+        .create_element("canvas")
+        .map_err(|_| ImagePrepError::Unsupported)?
+        .dyn_into()
+        .map_err(|_| ImagePrepError::Unsupported)?;
+    canvas.set_width(width);
+    canvas.set_height(height);
+    let context: web_sys::CanvasRenderingContext2d = canvas
+        .get_context("2d")
+        .ok()
+        .flatten()
+        .and_then(|context| context.dyn_into().ok())
+        .ok_or(ImagePrepError::Unsupported)?;
+    context
+        .draw_image_with_image_bitmap_and_dw_and_dh(&bitmap, 0.0, 0.0, width as f64, height as f64)
+        .map_err(|e| ImagePrepError::EncodeFailed(format!("{:?}", e)))?;
+    bitmap.close();
+
+    let mut encoded = encode_canvas(&canvas, "image/webp", options.quality)?;
+    if encoded.is_none() {
+        encoded = encode_canvas(&canvas, "image/jpeg", options.quality)?;
+    }
+    let (encoded_type, encoded_bytes) = encoded
+        .ok_or_else(|| ImagePrepError::EncodeFailed("no supported output format".to_string()))?;
+
+    if encoded_bytes.len() >= bytes.len() && (width, height) == original {
+        return Ok(OptimizedImage {
+            content_type: content_type.to_string(),
+            bytes: bytes.to_vec(),
+            width,
+            height,
+        });
+    }
+
+    log::info!("🖼️ Optimized image {} KB -> {} KB ({}x{})", bytes.len() / 1024, encoded_bytes.len() / 1024, width, height);
+    Ok(OptimizedImage {
+        content_type: encoded_type.to_string(),
+        bytes: encoded_bytes,
+        width,
+        height,
+    })
+}
+
+/// Encode the canvas, or `None` if the browser ignored `mime_type`
+fn encode_canvas(
+    canvas: &web_sys::HtmlCanvasElement,
+    mime_type: &'static str,
+    quality: f64,
+) -> Result<Option<(&'static str, Vec<u8>)>, ImagePrepError> {
+    let data_url = canvas
+        .to_data_url_with_type_and_encoder_options(mime_type, &quality.into())
+        .map_err(|e| ImagePrepError::EncodeFailed(format!("{:?}", e)))?;
+    let Some(encoded) = data_url.strip_prefix(&format!("data:{};base64,", mime_type)) else {
+        return Ok(None);
+    };
+    let bytes = STANDARD
+        .decode(encoded)
+        .map_err(|e| ImagePrepError::EncodeFailed(e.to_string()))?;
+    Ok(Some((mime_type, bytes)))
+}
+
+/// Scale `width` x `height` down so the longest side is at most `max`
+fn fit_within(width: u32, height: u32, max: u32) -> (u32, u32) {
+    let longest = width.max(height);
+    if longest <= max || longest == 0 {
+        return (width, height);
+    }
+    let scale = max as f64 / longest as f64;
+    (
+        ((width as f64 * scale).round() as u32).max(1),
+        ((height as f64 * scale).round() as u32).max(1),
+    )
+}
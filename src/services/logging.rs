@@ -0,0 +1,248 @@
+//! App logging
+//!
+//! `init_logging` installs `AppLogger` as the `log` backend. Records that
+//! pass the filter go to the browser console as before, and into a ring
+//! buffer of the last `LOG_BUFFER_CAPACITY` entries that the debug panel
+//! shows and `diagnostic_bundle` copies into bug reports.
+//!
+//! The filter is a comma-separated list of `level` and `target=level`
+//! directives, e.g. `info,faithful_archive::services::wallet=debug`. It is
+//! kept in localStorage, so verbose wallet logging survives the reload
+//! that often makes the reported problem go away.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+use crate::config::app_config;
+use crate::services::flags::flag_summary;
+use crate::services::gateway::GatewayManager;
+use crate::services::wallet::wallet_diagnostics;
+use crate::utils::constants::APP_VERSION;
+
+/// localStorage key for the log filter
+const LOG_FILTER_KEY: &str = "faithful_archive_log_filter";
+
+/// Entries kept for the debug panel and diagnostic bundles
+const LOG_BUFFER_CAPACITY: usize = 500;
+
+thread_local! {
+    static BUFFER: RefCell<VecDeque<LogEntry>> = RefCell::new(VecDeque::with_capacity(LOG_BUFFER_CAPACITY));
+    static FILTER: RefCell<LogFilter> = RefCell::new(LogFilter::default());
+}
+
+/// One recorded log line
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    /// ms since the epoch
+    pub timestamp: f64,
+    pub level: Level,
+    /// Module the record came from, e.g. `faithful_archive::services::gateway`
+    pub target: String,
+    pub message: String,
+}
+
+impl LogEntry {
+    /// Time of day the entry was logged, e.g. "14:03:07.215"
+    pub fn time(&self) -> String {
+        chrono::DateTime::from_timestamp_millis(self.timestamp as i64)
+            .map(|time| time.with_timezone(&chrono::Local).format("%H:%M:%S%.3f").to_string())
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:<5} {}: {}", self.time(), self.level, self.target, self.message)
+    }
+}
+
+/// Which records are kept, by target
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogFilter {
+    /// Level for targets without a directive of their own
+    pub default: LevelFilter,
+    /// `(target prefix, level)` directives
+    pub targets: Vec<(String, LevelFilter)>,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self { default: LevelFilter::Info, targets: Vec::new() }
+    }
+}
+
+impl LogFilter {
+    /// Parse directives like `warn,faithful_archive::services::wallet=debug`
+    ///
+    /// Directives that don't parse are skipped rather than failing the
+    /// whole filter.
+    pub fn parse(spec: &str) -> Self {
+        let mut filter = Self::default();
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    if let Ok(level) = LevelFilter::from_str(level.trim()) {
+                        filter.targets.push((target.trim().to_string(), level));
+                    }
+                }
+                None => {
+                    if let Ok(level) = LevelFilter::from_str(directive) {
+                        filter.default = level;
+                    }
+                }
+            }
+        }
+        filter
+    }
+
+    /// Level allowed for `target`; the longest matching prefix wins
+    pub fn level_for(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| target.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    /// Most verbose level any target allows
+    pub fn max_level(&self) -> LevelFilter {
+        self.targets.iter().map(|(_, level)| *level).fold(self.default, Ord::max)
+    }
+}
+
+impl fmt::Display for LogFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.default.as_str().to_lowercase())?;
+        for (target, level) in &self.targets {
+            write!(f, ",{}={}", target, level.as_str().to_lowercase())?;
+        }
+        Ok(())
+    }
+}
+
+/// `log` backend writing to the console and the ring buffer
+struct AppLogger;
+
+static LOGGER: AppLogger = AppLogger;
+
+impl Log for AppLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        FILTER.with(|filter| metadata.level() <= filter.borrow().level_for(metadata.target()))
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        console_log::log(record);
+        let entry = LogEntry {
+            timestamp: js_sys::Date::now(),
+            level: record.level(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+        };
+        BUFFER.with(|buffer| {
+            let mut buffer = buffer.borrow_mut();
+            if buffer.len() == LOG_BUFFER_CAPACITY {
+                buffer.pop_front();
+            }
+            buffer.push_back(entry);
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+}
+
+/// Install the logger with the saved filter; call before anything logs
+pub fn init_logging() {
+    let saved = local_storage()
+        .and_then(|storage| storage.get_item(LOG_FILTER_KEY).ok().flatten())
+        .map(|spec| LogFilter::parse(&spec))
+        .unwrap_or_default();
+    log::set_max_level(saved.max_level());
+    FILTER.with(|filter| *filter.borrow_mut() = saved);
+    if log::set_logger(&LOGGER).is_err() {
+        web_sys::console::warn_1(&"A logger was already installed".into());
+    }
+}
+
+/// The filter in use
+pub fn log_filter() -> LogFilter {
+    FILTER.with(|filter| filter.borrow().clone())
+}
+
+/// Change and save the filter
+pub fn set_log_filter(new_filter: LogFilter) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(LOG_FILTER_KEY, &new_filter.to_string());
+    }
+    log::set_max_level(new_filter.max_level());
+    FILTER.with(|filter| *filter.borrow_mut() = new_filter);
+}
+
+/// Buffered entries, oldest first
+pub fn recent_logs() -> Vec<LogEntry> {
+    BUFFER.with(|buffer| buffer.borrow().iter().cloned().collect())
+}
+
+pub fn clear_logs() {
+    BUFFER.with(|buffer| buffer.borrow_mut().clear());
+}
+
+/// Plain-text report to paste into a bug report: app and browser details,
+/// wallet state and the buffered log
+pub fn diagnostic_bundle() -> String {
+    let user_agent = web_sys::window()
+        .and_then(|window| window.navigator().user_agent().ok())
+        .unwrap_or_default();
+    let path = web_sys::window()
+        .and_then(|window| window.location().pathname().ok())
+        .unwrap_or_default();
+    let gateway = GatewayManager::new().pinned().unwrap_or_else(|| "fastest".to_string());
+
+    let mut bundle = String::from("Faithful Archive diagnostics\n");
+    bundle.push_str(&format!("Version: {}\n", APP_VERSION));
+    bundle.push_str(&format!("Generated: {}\n", chrono::Utc::now().to_rfc3339()));
+    bundle.push_str(&format!("Browser: {}\n", user_agent));
+    bundle.push_str(&format!("Page: {}\n", path));
+    bundle.push_str(&format!("Network: {}\n", app_config().network.display_name()));
+    bundle.push_str(&format!("Gateway: {}\n", gateway));
+    bundle.push_str(&format!("Log filter: {}\n", log_filter()));
+    bundle.push_str(&format!("Flags: {}\n", flag_summary()));
+    for (name, value) in wallet_diagnostics() {
+        bundle.push_str(&format!("{}: {}\n", name, value));
+    }
+
+    let entries = recent_logs();
+    bundle.push_str(&format!("\nLast {} log entries:\n", entries.len()));
+    for entry in entries {
+        bundle.push_str(&entry.to_string());
+        bundle.push('\n');
+    }
+    bundle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn filters_pick_the_most_specific_target() {
+        let filter = LogFilter::parse("warn, faithful_archive::services=info,faithful_archive::services::wallet=debug,bogus=loud");
+        assert_eq!(filter.level_for("reqwest"), LevelFilter::Warn);
+        assert_eq!(filter.level_for("faithful_archive::services::gateway"), LevelFilter::Info);
+        assert_eq!(filter.level_for("faithful_archive::services::wallet::beacon"), LevelFilter::Debug);
+        assert_eq!(filter.max_level(), LevelFilter::Debug);
+        assert_eq!(LogFilter::parse(&filter.to_string()), filter);
+        assert_eq!(LogFilter::parse(""), LogFilter::default());
+    }
+}
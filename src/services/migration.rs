@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+
+use crate::services::bookmarks::{saved_items, BookmarkError, BookmarkService};
+use crate::services::follows::followed;
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, GraphQLError};
+use crate::services::identity::{local_identity, IdentityError, IdentityService, LocalIdentity};
+use crate::services::notes::NotesService;
+use crate::services::reports::CONTENT_REPORT_TYPE;
+use crate::services::storage::StorageError;
+use crate::services::upload::{publish_with_wallet, PublishError};
+use crate::services::wallet::{WalletError, WalletService};
+
+/// localStorage key recording which wallet local data belongs to
+const LOCAL_PROFILE_KEY: &str = "faithful_archive_local_profile";
+
+#[derive(Debug, Clone)]
+pub enum MigrationError {
+    NotConnected(WalletError),
+    Storage(StorageError),
+    LookupFailed(GraphQLError),
+    Identity(IdentityError),
+    Bookmarks(BookmarkError),
+    PublishFailed(PublishError),
+}
+
+impl std::fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MigrationError::NotConnected(e) => write!(f, "Connect a wallet first: {}", e),
+            MigrationError::Storage(e) => write!(f, "{}", e),
+            MigrationError::LookupFailed(e) => write!(f, "Could not look up anonymous reports: {}", e),
+            MigrationError::Identity(e) => write!(f, "{}", e),
+            MigrationError::Bookmarks(e) => write!(f, "{}", e),
+            MigrationError::PublishFailed(e) => write!(f, "Could not republish a report: {}", e),
+        }
+    }
+}
+
+/// Which wallet the data in this browser has been attributed to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocalProfile {
+    pub wallet: String,
+    pub migrated_at: i64,
+}
+
+/// Wallet that local bookmarks, notes and follows belong to, if migrated
+pub fn local_profile() -> Option<LocalProfile> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(LOCAL_PROFILE_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+fn save_local_profile(profile: &LocalProfile) {
+    if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+        if let Ok(json) = serde_json::to_string(profile) {
+            let _ = storage.set_item(LOCAL_PROFILE_KEY, &json);
+        }
+    }
+}
+
+/// Whether this browser holds data not yet attributed to `wallet`
+pub fn needs_migration(wallet: &str) -> bool {
+    let has_local_data = !saved_items().is_empty() || !followed().is_empty() || local_identity().is_some();
+    has_local_data && local_profile().is_none_or(|profile| profile.wallet != wallet)
+}
+
+/// A report signed by the local pseudonym
+#[derive(Debug, Clone, PartialEq)]
+pub struct PseudonymousReport {
+    pub id: String,
+    pub target_tx_id: String,
+    pub reason: String,
+}
+
+/// Everything the migration would touch, shown for review first
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationPlan {
+    pub wallet: String,
+    pub pseudonym: Option<LocalIdentity>,
+    pub bookmarks: usize,
+    pub notes: usize,
+    pub follows: usize,
+    pub reports: Vec<PseudonymousReport>,
+}
+
+/// What the user agreed to on the review screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MigrationOptions {
+    /// Publish a wallet-signed Identity-Link for the pseudonym
+    pub link_identity: bool,
+    /// Republish pseudonymous reports signed by the wallet
+    pub republish_reports: bool,
+    /// Publish a bookmark backup restorable from the wallet
+    pub backup_bookmarks: bool,
+    /// Delete the anonymous key once everything succeeded
+    pub delete_identity: bool,
+}
+
+/// Moves data created anonymously in this browser under a wallet
+///
+/// Bookmarks, notes and follows stay local and are only re-attributed;
+/// nothing is published except what the options allow.
+pub struct MigrationService;
+
+impl MigrationService {
+    /// Gather what the connected wallet would take over
+    pub async fn plan() -> Result<MigrationPlan, MigrationError> {
+        let wallet = WalletService::init()
+            .await
+            .get_active_address()
+            .await
+            .map_err(MigrationError::NotConnected)?;
+        let notes = NotesService::all().await.map_err(MigrationError::Storage)?;
+        let pseudonym = local_identity();
+
+        let reports = match &pseudonym {
+            Some(identity) => GraphQLClient::new()
+                .find_transactions(&[identity.address.as_str()], &[("Type", &[CONTENT_REPORT_TYPE])])
+                .await
+                .map_err(MigrationError::LookupFailed)?
+                .into_iter()
+                .filter_map(|tx| {
+                    Some(PseudonymousReport {
+                        target_tx_id: tx.tag("Target-Tx")?.to_string(),
+                        reason: tx.tag("Reason").unwrap_or("other").to_string(),
+                        id: tx.id,
+                    })
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Ok(MigrationPlan {
+            wallet,
+            pseudonym,
+            bookmarks: saved_items().len(),
+            notes: notes.len(),
+            follows: followed().len(),
+            reports,
+        })
+    }
+
+    /// Carry out a reviewed plan, returning the IDs of everything published
+    pub async fn apply(plan: &MigrationPlan, options: MigrationOptions) -> Result<Vec<String>, MigrationError> {
+        let mut published = Vec::new();
+
+        if options.link_identity && plan.pseudonym.as_ref().is_some_and(|p| p.linked_wallet.is_none()) {
+            published.push(IdentityService::link_to_wallet().await.map_err(MigrationError::Identity)?);
+        }
+
+        if options.republish_reports {
+            for report in &plan.reports {
+                published.push(republish_report(report).await?);
+            }
+        }
+
+        if options.backup_bookmarks && plan.bookmarks > 0 {
+            published.push(BookmarkService::backup().await.map_err(MigrationError::Bookmarks)?);
+        }
+
+        save_local_profile(&LocalProfile {
+            wallet: plan.wallet.clone(),
+            migrated_at: chrono::Utc::now().timestamp(),
+        });
+
+        if options.delete_identity && plan.pseudonym.is_some() {
+            IdentityService::remove().await.map_err(MigrationError::Identity)?;
+        }
+
+        log::info!("🔀 Migrated local data to {} ({} items published)", plan.wallet, published.len());
+        Ok(published)
+    }
+}
+
+/// Sign an existing pseudonymous report again with the wallet
+async fn republish_report(report: &PseudonymousReport) -> Result<String, MigrationError> {
+    // A missing body only loses the optional note
+    let note = match GatewayManager::new().get(&format!("/{}", report.id)).await {
+        Ok(response) => response.bytes().await.map(|bytes| bytes.to_vec()).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+    let tags = vec![
+        ("Content-Type".to_string(), "text/plain".to_string()),
+        ("Type".to_string(), CONTENT_REPORT_TYPE.to_string()),
+        ("Target-Tx".to_string(), report.target_tx_id.clone()),
+        ("Reason".to_string(), report.reason.clone()),
+        ("Republished-From".to_string(), report.id.clone()),
+    ];
+
+    let (_, tx_id) = publish_with_wallet(note, tags)
+        .await
+        .map_err(MigrationError::PublishFailed)?;
+    Ok(tx_id)
+}
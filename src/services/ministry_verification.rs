@@ -0,0 +1,378 @@
+//! Verified ministries and their donation addresses
+//!
+//! A ministry proves it runs a web domain by listing its Arweave addresses
+//! there, in a DNS TXT record (`faithful-archive=<address>`) or one per line
+//! in `/.well-known/faithful-archive.txt`. Once the listing is up, the
+//! uploading wallet signs a `Ministry-Verification` DataItem naming the
+//! domain, how it's proved and, optionally, the address donations should
+//! go to, with the listing as fetched as its data.
+//!
+//! The record alone proves nothing, since anyone can sign one naming any
+//! domain. Every reader checks the domain again before showing a badge,
+//! so a badge disappears once the domain stops listing the address, and a
+//! donation address only counts as verified if the domain lists it too.
+
+use std::collections::{HashMap, HashSet};
+
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+use crate::config::app_config;
+use crate::services::graphql::{GraphQLClient, GraphQLError, TransactionSummary};
+use crate::services::upload::{publish_with_wallet, PublishError};
+use crate::services::wallet::{connected_wallet, WalletError};
+use crate::utils::query_string::encode_component;
+
+/// `Type` tag of a ministry's domain proof
+pub const MINISTRY_VERIFICATION_TYPE: &str = "Ministry-Verification";
+
+/// Path of the address listing on a ministry's domain
+pub const WELL_KNOWN_PATH: &str = "/.well-known/faithful-archive.txt";
+
+/// Prefix of a listed address in a TXT record
+pub const TXT_RECORD_PREFIX: &str = "faithful-archive=";
+
+/// Where a domain lists its addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationMethod {
+    DnsTxt,
+    WellKnown,
+}
+
+impl VerificationMethod {
+    pub const ALL: [VerificationMethod; 2] = [VerificationMethod::DnsTxt, VerificationMethod::WellKnown];
+
+    pub fn tag_value(&self) -> &'static str {
+        match self {
+            VerificationMethod::DnsTxt => "DNS-TXT",
+            VerificationMethod::WellKnown => "Well-Known",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            VerificationMethod::DnsTxt => "DNS TXT record",
+            VerificationMethod::WellKnown => "File on the website",
+        }
+    }
+
+    pub fn from_tag_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|method| method.tag_value() == value)
+    }
+
+    /// What to publish on `domain` so it lists `address`
+    pub fn instructions(&self, domain: &str, address: &str) -> String {
+        match self {
+            VerificationMethod::DnsTxt => format!("Add a TXT record to {} with the value {}{}", domain, TXT_RECORD_PREFIX, address),
+            VerificationMethod::WellKnown => format!(
+                "Serve https://{}{} containing the line {}, with the header Access-Control-Allow-Origin: *",
+                domain, WELL_KNOWN_PATH, address
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum MinistryVerificationError {
+    InvalidDomain(String),
+    /// The domain's listing couldn't be read
+    LookupFailed(String),
+    /// The domain doesn't list the address
+    NotListed { domain: String, address: String },
+    WalletUnavailable(WalletError),
+    RecordsUnavailable(GraphQLError),
+    PublishFailed(PublishError),
+}
+
+impl std::fmt::Display for MinistryVerificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MinistryVerificationError::InvalidDomain(domain) => write!(f, "{} is not a domain name", domain),
+            MinistryVerificationError::LookupFailed(msg) => write!(f, "Could not read the domain's listing: {}", msg),
+            MinistryVerificationError::NotListed { domain, address } => write!(f, "{} does not list {}", domain, address),
+            MinistryVerificationError::WalletUnavailable(e) => write!(f, "{}", e),
+            MinistryVerificationError::RecordsUnavailable(e) => write!(f, "Could not look up verifications: {}", e),
+            MinistryVerificationError::PublishFailed(e) => write!(f, "Could not publish the proof: {}", e),
+        }
+    }
+}
+
+/// A signed claim that a domain lists the signer's address
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomainProof {
+    pub address: String,
+    pub domain: String,
+    pub method: VerificationMethod,
+    pub donation_address: Option<String>,
+    /// DataItem the claim was published in
+    pub record_tx_id: String,
+}
+
+impl DomainProof {
+    fn from_transaction(tx: &TransactionSummary) -> Option<Self> {
+        Some(Self {
+            address: tx.owner.clone(),
+            domain: normalize_domain(tx.tag("Domain")?).ok()?,
+            method: VerificationMethod::from_tag_value(tx.tag("Verification-Method")?)?,
+            donation_address: tx.tag("Donation-Address").map(str::to_string),
+            record_tx_id: tx.id.clone(),
+        })
+    }
+}
+
+/// A proof that checked out when this client last looked
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedMinistry {
+    pub domain: String,
+    pub method: VerificationMethod,
+    /// Set only when the domain lists the donation address as well
+    pub donation_address: Option<String>,
+    pub record_tx_id: String,
+}
+
+// Checked proofs by address; `None` when the address has none that holds
+fn use_verification_cache() -> &'static GlobalSignal<HashMap<String, Option<VerifiedMinistry>>> {
+    static VERIFICATION_CACHE: GlobalSignal<HashMap<String, Option<VerifiedMinistry>>> = GlobalSignal::new(HashMap::new);
+    &VERIFICATION_CACHE
+}
+
+// Addresses with a check in flight, so cards sharing an uploader check once
+fn use_pending_verifications() -> &'static GlobalSignal<HashSet<String>> {
+    static PENDING_VERIFICATIONS: GlobalSignal<HashSet<String>> = GlobalSignal::new(HashSet::new);
+    &PENDING_VERIFICATIONS
+}
+
+fn is_address(value: &str) -> bool {
+    value.len() == 43 && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// The bare host of `input`, which may be given as a URL
+pub fn normalize_domain(input: &str) -> Result<String, MinistryVerificationError> {
+    let trimmed = input.trim();
+    let host = trimmed
+        .split_once("://")
+        .map_or(trimmed, |(_, rest)| rest)
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches('.')
+        .to_lowercase();
+    let valid = host.contains('.')
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    if !valid {
+        return Err(MinistryVerificationError::InvalidDomain(trimmed.to_string()));
+    }
+    Ok(host)
+}
+
+/// Addresses in a listing: one per line or TXT record, with or without
+/// the `faithful-archive=` prefix; anything else is ignored
+pub fn parse_listing<'a>(records: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    records
+        .into_iter()
+        .flat_map(str::lines)
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix(TXT_RECORD_PREFIX).unwrap_or(line).trim()
+        })
+        .filter(|line| is_address(line))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Text of a TXT record as DNS-over-HTTPS returns it: quoted strings,
+/// split into parts when long
+fn txt_record_text(data: &str) -> String {
+    if !data.contains('"') {
+        return data.to_string();
+    }
+    data.split('"').skip(1).step_by(2).collect()
+}
+
+#[derive(Deserialize)]
+struct DnsResponse {
+    #[serde(rename = "Answer", default)]
+    answer: Vec<DnsAnswer>,
+}
+
+#[derive(Deserialize)]
+struct DnsAnswer {
+    data: String,
+}
+
+/// What `domain` currently publishes for `method`, as raw text
+async fn fetch_listing(domain: &str, method: VerificationMethod) -> Result<String, MinistryVerificationError> {
+    let lookup_failed = |e: reqwest::Error| MinistryVerificationError::LookupFailed(e.to_string());
+    let client = reqwest::Client::new();
+    let request = match method {
+        VerificationMethod::DnsTxt => client
+            .get(format!("{}?name={}&type=TXT", app_config().dns_over_https_url, encode_component(domain)))
+            .header("Accept", "application/dns-json"),
+        VerificationMethod::WellKnown => client.get(format!("https://{}{}", domain, WELL_KNOWN_PATH)),
+    };
+    let response = request.send().await.map_err(lookup_failed)?;
+    if !response.status().is_success() {
+        return Err(MinistryVerificationError::LookupFailed(format!("HTTP {}", response.status().as_u16())));
+    }
+
+    match method {
+        VerificationMethod::DnsTxt => {
+            let dns: DnsResponse = response.json().await.map_err(lookup_failed)?;
+            let records: Vec<String> = dns.answer.iter().map(|answer| txt_record_text(&answer.data)).collect();
+            Ok(records.join("\n"))
+        }
+        VerificationMethod::WellKnown => response.text().await.map_err(lookup_failed),
+    }
+}
+
+/// Check `proof` against what its domain lists right now
+pub async fn check_proof(proof: &DomainProof) -> Result<VerifiedMinistry, MinistryVerificationError> {
+    let listing = fetch_listing(&proof.domain, proof.method).await?;
+    let listed = parse_listing([listing.as_str()]);
+    if !listed.contains(&proof.address) {
+        return Err(MinistryVerificationError::NotListed { domain: proof.domain.clone(), address: proof.address.clone() });
+    }
+    Ok(VerifiedMinistry {
+        domain: proof.domain.clone(),
+        method: proof.method,
+        donation_address: proof.donation_address.clone().filter(|donation| listed.contains(donation)),
+        record_tx_id: proof.record_tx_id.clone(),
+    })
+}
+
+/// Check the newest proof of each of `addresses` not checked yet, looking
+/// the proofs up in one query
+///
+/// Only proofs signed by the address they name are read, so nobody can
+/// claim a domain for someone else.
+pub async fn resolve_verifications(addresses: &[&str]) -> Result<(), MinistryVerificationError> {
+    let wanted: Vec<&str> = {
+        let cache = use_verification_cache().read();
+        let pending = use_pending_verifications().read();
+        let mut wanted: Vec<&str> = addresses
+            .iter()
+            .copied()
+            .filter(|address| !cache.contains_key(*address) && !pending.contains(*address))
+            .collect();
+        wanted.sort_unstable();
+        wanted.dedup();
+        wanted
+    };
+    if wanted.is_empty() {
+        return Ok(());
+    }
+    use_pending_verifications().write().extend(wanted.iter().map(|address| address.to_string()));
+
+    let result = GraphQLClient::cached()
+        .find_transactions(&wanted, &[("Type", &[MINISTRY_VERIFICATION_TYPE])])
+        .await
+        .map_err(MinistryVerificationError::RecordsUnavailable);
+    let transactions = match result {
+        Ok(transactions) => transactions,
+        Err(e) => {
+            use_pending_verifications().write().retain(|address| !wanted.contains(&address.as_str()));
+            return Err(e);
+        }
+    };
+
+    // Pending proofs have no timestamp yet and are the newest
+    let mut latest: HashMap<&str, &TransactionSummary> = HashMap::new();
+    for tx in &transactions {
+        let newer = latest
+            .get(tx.owner.as_str())
+            .is_none_or(|current| tx.timestamp.unwrap_or(i64::MAX) > current.timestamp.unwrap_or(i64::MAX));
+        if newer {
+            latest.insert(&tx.owner, tx);
+        }
+    }
+
+    for address in wanted {
+        let verified = match latest.get(address).and_then(|tx| DomainProof::from_transaction(tx)) {
+            Some(proof) => match check_proof(&proof).await {
+                Ok(verified) => Some(verified),
+                Err(e) => {
+                    log::info!("Ministry proof {} no longer holds: {}", proof.record_tx_id, e);
+                    None
+                }
+            },
+            None => None,
+        };
+        use_pending_verifications().write().remove(address);
+        use_verification_cache().write().insert(address.to_string(), verified);
+    }
+    Ok(())
+}
+
+/// Check that `domain` lists the connected wallet, then sign and publish
+/// the proof, naming `donation_address` too if the domain lists it
+pub async fn publish_proof(
+    domain: &str,
+    method: VerificationMethod,
+    donation_address: Option<String>,
+) -> Result<VerifiedMinistry, MinistryVerificationError> {
+    let domain = normalize_domain(domain)?;
+    let address = connected_wallet()
+        .await
+        .map_err(MinistryVerificationError::WalletUnavailable)?
+        .get_active_address()
+        .await
+        .map_err(MinistryVerificationError::WalletUnavailable)?;
+    let listing = fetch_listing(&domain, method).await?;
+    let listed = parse_listing([listing.as_str()]);
+    if !listed.contains(&address) {
+        return Err(MinistryVerificationError::NotListed { domain, address });
+    }
+    // A donation address the domain doesn't list is left out of the proof
+    let donation_address = donation_address.filter(|donation| listed.contains(donation));
+
+    let mut tags = vec![
+        ("Content-Type".to_string(), "text/plain".to_string()),
+        ("Type".to_string(), MINISTRY_VERIFICATION_TYPE.to_string()),
+        ("Domain".to_string(), domain.clone()),
+        ("Verification-Method".to_string(), method.tag_value().to_string()),
+    ];
+    if let Some(donation_address) = &donation_address {
+        tags.push(("Donation-Address".to_string(), donation_address.clone()));
+    }
+    let (owner, record_tx_id) = publish_with_wallet(listing.into_bytes(), tags)
+        .await
+        .map_err(MinistryVerificationError::PublishFailed)?;
+
+    let verified = VerifiedMinistry { domain, method, donation_address, record_tx_id };
+    use_verification_cache().write().insert(owner, Some(verified.clone()));
+    log::info!("✅ Published proof of {} ({})", verified.domain, verified.record_tx_id);
+    Ok(verified)
+}
+
+/// Checked proof of `address`, once something has resolved it
+pub fn use_verified_ministry(address: String) -> Memo<Option<VerifiedMinistry>> {
+    use_memo(use_reactive!(|address| use_verification_cache().read().get(&address).cloned().flatten()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    const UPLOADER: &str = "vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JI";
+    const DONATIONS: &str = "yRj4a5KMctX_uOmKWCFJIjmY8DeJcusVk6-HzLiM_t8";
+
+    #[wasm_bindgen_test]
+    fn listings_and_domains_are_read_leniently() {
+        let well_known = format!("# Faithful Archive addresses\n{}\n\n  {}{}  \n", UPLOADER, TXT_RECORD_PREFIX, DONATIONS);
+        assert_eq!(parse_listing([well_known.as_str()]), vec![UPLOADER.to_string(), DONATIONS.to_string()]);
+
+        let split = format!("\"{}{}\" \"{}\"", TXT_RECORD_PREFIX, &UPLOADER[..20], &UPLOADER[20..]);
+        let records = [txt_record_text(&split), txt_record_text("\"v=spf1 -all\"")];
+        assert_eq!(parse_listing(records.iter().map(String::as_str)), vec![UPLOADER.to_string()]);
+
+        assert_eq!(normalize_domain("https://Grace-Church.org/about").unwrap(), "grace-church.org");
+        assert!(normalize_domain("localhost").is_err());
+        assert!(normalize_domain("bad_domain.org").is_err());
+    }
+}
@@ -1,2 +1,77 @@
 pub mod arweave;
-pub mod wallet;
\ No newline at end of file
+pub mod wallet;
+pub mod storage;
+pub mod graphql;
+pub mod confirmation;
+pub mod health;
+pub mod gateway;
+pub mod pricing;
+pub mod reverify;
+pub mod content_fetch;
+pub mod bundler;
+pub mod native_tx;
+pub mod hide_registry;
+pub mod revisions;
+pub mod rights_claims;
+pub mod moderation_log;
+pub mod review_assignments;
+pub mod premoderation;
+pub mod ministry_verification;
+pub mod platform_stats;
+pub mod home_feed;
+pub mod search;
+pub mod evidence;
+pub mod notes;
+pub mod transcript;
+pub mod passage;
+pub mod upload;
+pub mod series;
+pub mod collections;
+pub mod bookmarks;
+pub mod follows;
+pub mod digest;
+pub mod identity;
+pub mod reports;
+pub mod playback;
+pub mod migration;
+pub mod audio_prep;
+pub mod image_prep;
+pub mod releases;
+pub mod worker;
+pub mod file_reader;
+pub mod dedupe;
+pub mod encryption;
+pub mod arns;
+pub mod profile;
+pub mod toast;
+pub mod theme;
+pub mod shortcuts;
+pub mod async_state;
+pub mod settings;
+pub mod auth;
+pub mod token;
+pub mod ao;
+pub mod tips;
+pub mod turbo;
+pub mod comments;
+pub mod reactions;
+pub mod feed;
+#[cfg(feature = "app")]
+pub mod share;
+pub mod downloads;
+pub mod verification;
+pub mod scanning;
+pub mod scheduler;
+pub mod http;
+pub mod sync;
+pub mod analytics;
+pub mod logging;
+pub mod recovery;
+pub mod receipts;
+pub mod upload_queue;
+pub mod transfer;
+pub mod upload_failure;
+pub mod bulk_import;
+pub mod outbox;
+pub mod notifications;
+pub mod flags;
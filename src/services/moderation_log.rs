@@ -0,0 +1,293 @@
+//! The public record of moderation decisions
+//!
+//! Every decision a moderator makes is a signed DataItem: hide-registry
+//! entries de-listing or restoring an item, and acknowledgments answering
+//! rights claims. This gathers both into one log, newest first, so anyone
+//! can check who decided what, when and why, and export it to compare
+//! decisions over time.
+
+use chrono::{DateTime, Datelike, Days, NaiveDate};
+use serde::Serialize;
+
+use crate::models::{ClaimStatus, ReviewChecklist, ReviewCriterion};
+use crate::services::hide_registry::{HideAction, HideRegistry};
+use crate::services::rights_claims::{RightsClaimError, RightsClaimService};
+use crate::utils::download::download_bytes;
+use crate::utils::metadata_export::{csv_field, MetadataFormat};
+
+/// What a moderator decided
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Decision {
+    /// Item de-listed
+    Hide,
+    /// De-listed item listed again
+    Restore,
+    ClaimReceived,
+    /// Rights claim upheld; the item is hidden by a separate entry
+    ClaimUpheld,
+    ClaimRejected,
+}
+
+impl Decision {
+    pub const ALL: [Decision; 5] = [
+        Decision::Hide,
+        Decision::Restore,
+        Decision::ClaimReceived,
+        Decision::ClaimUpheld,
+        Decision::ClaimRejected,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Decision::Hide => "Hidden",
+            Decision::Restore => "Restored",
+            Decision::ClaimReceived => "Claim received",
+            Decision::ClaimUpheld => "Claim upheld",
+            Decision::ClaimRejected => "Claim rejected",
+        }
+    }
+
+    /// Whether the decision is about a rights claim rather than an item
+    pub fn is_claim(&self) -> bool {
+        matches!(self, Decision::ClaimReceived | Decision::ClaimUpheld | Decision::ClaimRejected)
+    }
+
+    fn of_claim(status: ClaimStatus) -> Option<Self> {
+        match status {
+            ClaimStatus::Submitted => None,
+            ClaimStatus::UnderReview => Some(Decision::ClaimReceived),
+            ClaimStatus::Validated => Some(Decision::ClaimUpheld),
+            ClaimStatus::Rejected => Some(Decision::ClaimRejected),
+        }
+    }
+}
+
+/// One signed moderation decision
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ModerationDecision {
+    pub decision: Decision,
+    /// The item ID, or the claim's tracking number for claim decisions
+    pub subject: String,
+    /// Tracking number of the claim a hide or restore resolves
+    pub claim_id: Option<String>,
+    pub reason: String,
+    /// Guideline scores the decision was made on, if any
+    pub review: Option<ReviewChecklist>,
+    /// Address of the moderator who signed it
+    pub reviewer: String,
+    /// DataItem ID of the signed decision
+    pub record_tx_id: String,
+    /// Block timestamp; `None` while still pending
+    pub timestamp: Option<i64>,
+}
+
+impl ModerationDecision {
+    /// RFC 3339, or empty while pending
+    pub fn date(&self) -> String {
+        self.timestamp
+            .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+            .map(|date| date.to_rfc3339())
+            .unwrap_or_default()
+    }
+}
+
+/// What to show of the log
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditFilter {
+    pub decision: Option<Decision>,
+    pub reviewer: Option<String>,
+    /// Matched against the subject, claim and reason, ignoring case
+    pub text: String,
+}
+
+impl AuditFilter {
+    pub fn matches(&self, entry: &ModerationDecision) -> bool {
+        let text = self.text.trim().to_lowercase();
+        self.decision.is_none_or(|decision| entry.decision == decision)
+            && self.reviewer.as_ref().is_none_or(|reviewer| &entry.reviewer == reviewer)
+            && (text.is_empty()
+                || entry.subject.to_lowercase().contains(&text)
+                || entry.reason.to_lowercase().contains(&text)
+                || entry.claim_id.as_ref().is_some_and(|claim| claim.to_lowercase().contains(&text)))
+    }
+}
+
+/// Every moderation decision on record, newest first
+pub async fn load_audit_log() -> Result<Vec<ModerationDecision>, RightsClaimError> {
+    let entries = HideRegistry::new().load().await?;
+    let acknowledgments = RightsClaimService::new().acknowledgments(&[]).await?;
+
+    let mut decisions: Vec<ModerationDecision> = entries
+        .into_iter()
+        .map(|entry| ModerationDecision {
+            decision: match entry.action {
+                HideAction::Hide => Decision::Hide,
+                HideAction::Restore => Decision::Restore,
+            },
+            subject: entry.target_id,
+            claim_id: entry.claim_id,
+            reason: entry.reason,
+            review: entry.review,
+            reviewer: entry.moderator,
+            record_tx_id: entry.entry_tx_id,
+            timestamp: entry.timestamp,
+        })
+        .collect();
+    decisions.extend(acknowledgments.into_iter().filter_map(|ack| {
+        Some(ModerationDecision {
+            decision: Decision::of_claim(ack.status)?,
+            subject: ack.tracking_number,
+            claim_id: None,
+            reason: ack.note,
+            review: ack.review,
+            reviewer: ack.moderator,
+            record_tx_id: ack.tx_id,
+            timestamp: ack.timestamp,
+        })
+    }));
+
+    // Pending decisions are the newest
+    decisions.sort_by_key(|decision| std::cmp::Reverse(decision.timestamp.unwrap_or(i64::MAX)));
+    Ok(decisions)
+}
+
+/// Reviewers who appear in `decisions`, in order of first appearance
+pub fn reviewers(decisions: &[ModerationDecision]) -> Vec<String> {
+    let mut reviewers: Vec<String> = Vec::new();
+    for decision in decisions {
+        if !reviewers.contains(&decision.reviewer) {
+            reviewers.push(decision.reviewer.clone());
+        }
+    }
+    reviewers
+}
+
+/// Decisions made each week, for the `weeks` weeks up to the one holding
+/// `today`, oldest first and starting on Mondays
+///
+/// Pending decisions count in the current week; older ones are left out.
+pub fn decisions_per_week(decisions: &[ModerationDecision], weeks: usize, today: NaiveDate) -> Vec<(NaiveDate, usize)> {
+    let week_of = |day: NaiveDate| day - Days::new(day.weekday().num_days_from_monday() as u64);
+    let this_week = week_of(today);
+    let mut counts: Vec<(NaiveDate, usize)> = (0..weeks)
+        .rev()
+        .map(|back| (this_week - Days::new(back as u64 * 7), 0))
+        .collect();
+    for decision in decisions {
+        let day = match decision.timestamp {
+            Some(timestamp) => match DateTime::from_timestamp(timestamp, 0) {
+                Some(date) => date.date_naive(),
+                None => continue,
+            },
+            None => today,
+        };
+        if let Some((_, count)) = counts.iter_mut().find(|(week, _)| *week == week_of(day)) {
+            *count += 1;
+        }
+    }
+    counts
+}
+
+const CSV_HEADERS: [&str; 7] = ["Date", "Decision", "Subject", "Claim", "Reason", "Reviewer", "Record"];
+
+/// The log as CSV or JSON
+pub fn export(format: MetadataFormat, decisions: &[ModerationDecision]) -> String {
+    match format {
+        MetadataFormat::Json => serde_json::to_string_pretty(decisions).unwrap_or_default(),
+        MetadataFormat::Csv => {
+            // A column per checklist score, empty where a decision wasn't scored
+            let headers = CSV_HEADERS
+                .iter()
+                .copied()
+                .chain(ReviewCriterion::ALL.iter().map(|criterion| criterion.display_name()));
+            let mut lines = vec![headers.map(csv_field).collect::<Vec<_>>().join(",")];
+            for decision in decisions {
+                let scores = ReviewCriterion::ALL.map(|criterion| {
+                    decision
+                        .review
+                        .as_ref()
+                        .and_then(|review| review.score(criterion))
+                        .map(|score| score.to_string())
+                        .unwrap_or_default()
+                });
+                let fields = [
+                    decision.date(),
+                    decision.decision.display_name().to_string(),
+                    decision.subject.clone(),
+                    decision.claim_id.clone().unwrap_or_default(),
+                    decision.reason.clone(),
+                    decision.reviewer.clone(),
+                    decision.record_tx_id.clone(),
+                ];
+                let fields = fields.iter().chain(scores.iter());
+                lines.push(fields.map(|field| csv_field(field)).collect::<Vec<_>>().join(","));
+            }
+            lines.join("\r\n") + "\r\n"
+        }
+    }
+}
+
+/// Download `decisions` as a dated moderation log file
+pub fn download(format: MetadataFormat, decisions: &[ModerationDecision]) -> Result<(), String> {
+    let date = chrono::Local::now().format("%Y-%m-%d");
+    let filename = format!("faithful-archive-moderation-log-{}.{}", date, format.extension());
+    download_bytes(&filename, export(format, decisions).as_bytes(), format.mime_type())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn decision(decision: Decision, subject: &str, reason: &str, reviewer: &str) -> ModerationDecision {
+        ModerationDecision {
+            decision,
+            subject: subject.to_string(),
+            claim_id: None,
+            reason: reason.to_string(),
+            review: None,
+            reviewer: reviewer.to_string(),
+            record_tx_id: format!("record-{}", subject),
+            timestamp: Some(1_700_000_000),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn filters_combine_and_exports_keep_every_decision() {
+        let log = vec![
+            decision(Decision::Hide, "item-a", "Spam, reported twice", "mod-1"),
+            decision(Decision::ClaimRejected, "FA-1234ABCD", "Sermon is the uploader's own", "mod-2"),
+            decision(Decision::Restore, "item-a", "Reviewed again", "mod-2"),
+        ];
+
+        let by_reviewer = AuditFilter { reviewer: Some("mod-2".to_string()), ..Default::default() };
+        assert_eq!(log.iter().filter(|entry| by_reviewer.matches(entry)).count(), 2);
+        let hidden_spam = AuditFilter { decision: Some(Decision::Hide), text: "SPAM".to_string(), ..Default::default() };
+        assert_eq!(log.iter().filter(|entry| hidden_spam.matches(entry)).count(), 1);
+        assert_eq!(reviewers(&log), vec!["mod-1".to_string(), "mod-2".to_string()]);
+
+        let csv = export(MetadataFormat::Csv, &log);
+        assert_eq!(csv.lines().count(), 4);
+        assert!(csv.contains("\"Spam, reported twice\""));
+    }
+
+    #[wasm_bindgen_test]
+    fn counts_decisions_per_week() {
+        // Thursday, so the current week started on Monday the 13th
+        let today = NaiveDate::from_ymd_opt(2023, 11, 16).unwrap();
+        let mut log = vec![
+            decision(Decision::Hide, "item-a", "", "mod-1"),
+            decision(Decision::Restore, "item-b", "", "mod-1"),
+            decision(Decision::Hide, "item-c", "", "mod-1"),
+            decision(Decision::Hide, "item-d", "", "mod-1"),
+        ];
+        log[0].timestamp = Some(1_700_438_400); // Monday 2023-11-20, after today
+        log[1].timestamp = None;
+        log[2].timestamp = Some(1_700_092_800); // Thursday 2023-11-16
+        log[3].timestamp = Some(1_699_833_600); // Monday 2023-11-13
+
+        let weeks = decisions_per_week(&log, 3, today);
+        let monday = |month, day| NaiveDate::from_ymd_opt(2023, month, day).unwrap();
+        assert_eq!(weeks, vec![(monday(10, 30), 0), (monday(11, 6), 0), (monday(11, 13), 3)]);
+    }
+}
@@ -0,0 +1,422 @@
+//! Layer-1 Arweave transactions
+//!
+//! Uploads normally go to a bundler as ANS-104 DataItems and the bundler
+//! pays the network fee. Some uploaders would rather pay it themselves, so
+//! this builds a format 2 transaction instead: the data is split into
+//! chunks committed to by a Merkle root (`data_root`), laid out exactly as
+//! arweave-js does, and the wallet signs only the header. The header is
+//! posted to `/tx`, then each chunk with its proof to `/chunk`. The file
+//! is read a slice at a time for both, so its size isn't bounded by memory.
+//!
+//! Mining is tracked like any other upload, by `ConfirmationService`.
+
+use std::collections::HashMap;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::services::file_reader::{FileReadError, LocalFile};
+use crate::services::gateway::{GatewayError, GatewayManager};
+use crate::services::pricing::{PricingError, PricingService};
+use crate::services::transfer::{Cancelled, TransferControl};
+use crate::services::wallet::{WalletError, WalletService};
+use crate::utils::constants::FILE_READ_CHUNK_SIZE;
+use crate::utils::merkle::{chunk_bounds, merkle_tree, Chunk, ChunkedData, MerkleError, MAX_CHUNK_SIZE};
+
+#[derive(Debug, Clone)]
+pub enum NativeTxError {
+    Gateway(GatewayError),
+    Pricing(PricingError),
+    Signing(WalletError),
+    /// The gateway refused the header or a chunk
+    Rejected(u16, String),
+    ReadFailed(FileReadError),
+    Merkle(MerkleError),
+    InvalidResponse(String),
+    /// Cancelled from the upload queue before every chunk was posted
+    Cancelled(Cancelled),
+    /// Chunk `index` (from 0) of `total` couldn't be read or posted
+    ChunkFailed { index: usize, total: usize, error: Box<NativeTxError> },
+}
+
+impl std::fmt::Display for NativeTxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NativeTxError::Gateway(e) => write!(f, "Could not reach a gateway: {}", e),
+            NativeTxError::Pricing(e) => write!(f, "Could not price the transaction: {}", e),
+            NativeTxError::Signing(e) => write!(f, "Could not sign the transaction: {}", e),
+            NativeTxError::Rejected(status, msg) => write!(f, "Gateway rejected the transaction (HTTP {}): {}", status, msg),
+            NativeTxError::InvalidResponse(msg) => write!(f, "Invalid gateway response: {}", msg),
+            NativeTxError::ReadFailed(e) => write!(f, "{}", e),
+            NativeTxError::Merkle(e) => write!(f, "{}", e),
+            NativeTxError::Cancelled(e) => write!(f, "{}", e),
+            NativeTxError::ChunkFailed { index, total, error } => write!(f, "Chunk {} of {} failed: {}", index + 1, total, error),
+        }
+    }
+}
+
+/// A tag as it appears in a transaction: name and value base64url-encoded
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncodedTag {
+    pub name: String,
+    pub value: String,
+}
+
+impl EncodedTag {
+    pub fn new(name: &str, value: &str) -> Self {
+        Self {
+            name: URL_SAFE_NO_PAD.encode(name),
+            value: URL_SAFE_NO_PAD.encode(value),
+        }
+    }
+}
+
+/// A format 2 transaction in the JSON form gateways and wallets use.
+/// `data` stays empty; the data is posted separately in chunks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NativeTransaction {
+    pub format: u8,
+    pub id: String,
+    pub last_tx: String,
+    pub owner: String,
+    pub tags: Vec<EncodedTag>,
+    pub target: String,
+    pub quantity: String,
+    pub data: String,
+    pub data_size: String,
+    pub data_root: String,
+    /// Fee in winston
+    pub reward: String,
+    pub signature: String,
+}
+
+impl NativeTransaction {
+    /// Unsigned transaction carrying `chunked` data; `owner`, `signature`
+    /// and `id` are filled in when it's signed
+    pub fn new(chunked: &ChunkedData, tags: &[(String, String)], last_tx: &str, reward: u128) -> Self {
+        Self {
+            format: 2,
+            id: String::new(),
+            last_tx: last_tx.to_string(),
+            owner: String::new(),
+            tags: tags.iter().map(|(name, value)| EncodedTag::new(name, value)).collect(),
+            target: String::new(),
+            quantity: "0".to_string(),
+            data: String::new(),
+            data_size: chunked.data_size.to_string(),
+            data_root: URL_SAFE_NO_PAD.encode(chunked.data_root),
+            reward: reward.to_string(),
+            signature: String::new(),
+        }
+    }
+
+    /// Unsigned transfer of `quantity` winston to `target`, with no data
+    pub fn transfer(target: &str, quantity: u128, tags: &[(String, String)], last_tx: &str, reward: u128) -> Self {
+        Self {
+            format: 2,
+            id: String::new(),
+            last_tx: last_tx.to_string(),
+            owner: String::new(),
+            tags: tags.iter().map(|(name, value)| EncodedTag::new(name, value)).collect(),
+            target: target.to_string(),
+            quantity: quantity.to_string(),
+            data: String::new(),
+            data_size: "0".to_string(),
+            data_root: String::new(),
+            reward: reward.to_string(),
+            signature: String::new(),
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        !self.signature.is_empty() && !self.owner.is_empty()
+    }
+}
+
+/// Body of a `/chunk` upload
+#[derive(Serialize)]
+struct ChunkUpload {
+    data_root: String,
+    data_size: String,
+    data_path: String,
+    offset: String,
+    chunk: String,
+}
+
+/// `merkle::chunk_data` for a file on the uploader's device, reading it a
+/// slice at a time
+pub async fn chunk_file(file: &LocalFile) -> Result<ChunkedData, NativeTxError> {
+    let size = file.size() as usize;
+    let per_read = (FILE_READ_CHUNK_SIZE as usize / MAX_CHUNK_SIZE).max(1);
+    let mut chunks = Vec::new();
+    for group in chunk_bounds(size).chunks(per_read) {
+        let (start, end) = (group[0].0, group[group.len() - 1].1);
+        let slice = file.read_range(start as u64, end as u64).await.map_err(NativeTxError::ReadFailed)?;
+        chunks.extend(group.iter().map(|&(min, max)| Chunk::new(&slice[min - start..max - start], min)));
+    }
+    merkle_tree(chunks, size).map_err(NativeTxError::Merkle)
+}
+
+/// Builds, signs and posts layer-1 transactions
+pub struct NativeTxService {
+    gateways: GatewayManager,
+}
+
+impl NativeTxService {
+    pub fn new() -> Self {
+        Self {
+            gateways: GatewayManager::new(),
+        }
+    }
+
+    /// Recent block the transaction is anchored to
+    async fn anchor(&self) -> Result<String, NativeTxError> {
+        let response = self.gateways.get("/tx_anchor").await.map_err(NativeTxError::Gateway)?;
+        if !response.status().is_success() {
+            return Err(NativeTxError::Rejected(response.status().as_u16(), "no anchor".to_string()));
+        }
+        let anchor = response.text().await.map_err(|e| NativeTxError::InvalidResponse(e.to_string()))?;
+        Ok(anchor.trim().to_string())
+    }
+
+    /// Unsigned transaction for `file`, priced and anchored by the gateway
+    pub async fn prepare(&self, file: &LocalFile, tags: &[(String, String)]) -> Result<(NativeTransaction, ChunkedData), NativeTxError> {
+        let chunked = chunk_file(file).await?;
+        let reward = PricingService::new()
+            .storage_price_winston(file.size())
+            .await
+            .map_err(NativeTxError::Pricing)?;
+        let last_tx = self.anchor().await?;
+        Ok((NativeTransaction::new(&chunked, tags, &last_tx, reward), chunked))
+    }
+
+    /// Have the connected wallet sign `tx`; the ID follows from the
+    /// signature
+    pub async fn sign(&self, wallet: &WalletService, tx: &NativeTransaction) -> Result<NativeTransaction, NativeTxError> {
+        // Not every wallet fills in the owner key itself
+        let mut tx = tx.clone();
+        if tx.owner.is_empty() {
+            tx.owner = wallet.get_public_key().await.map_err(NativeTxError::Signing)?;
+        }
+        let fields: HashMap<String, serde_json::Value> = match serde_json::to_value(&tx) {
+            Ok(serde_json::Value::Object(fields)) => fields.into_iter().collect(),
+            _ => return Err(NativeTxError::InvalidResponse("transaction did not serialize to an object".to_string())),
+        };
+        let signed = wallet.sign_transaction(fields).await.map_err(NativeTxError::Signing)?;
+        signed_transaction(&tx, signed)
+    }
+
+    /// Post the signed header, then every chunk of `file`, reading each
+    /// as it goes and pausing, stopping or slowing down as `transfer` says
+    pub async fn post(&self, tx: &NativeTransaction, chunked: &ChunkedData, file: &LocalFile, transfer: &TransferControl) -> Result<(), NativeTxError> {
+        self.post_json("/tx", tx).await?;
+        let total = chunked.chunks.len();
+        for (index, (chunk, proof)) in chunked.chunks.iter().zip(&chunked.proofs).enumerate() {
+            transfer.checkpoint().await.map_err(NativeTxError::Cancelled)?;
+            let failed = |error| NativeTxError::ChunkFailed { index, total, error: Box::new(error) };
+            let data = file
+                .read_range(chunk.min_byte_range as u64, chunk.max_byte_range as u64)
+                .await
+                .map_err(|e| failed(NativeTxError::ReadFailed(e)))?;
+            let upload = ChunkUpload {
+                data_root: tx.data_root.clone(),
+                data_size: tx.data_size.clone(),
+                data_path: URL_SAFE_NO_PAD.encode(&proof.proof),
+                offset: proof.offset.to_string(),
+                chunk: URL_SAFE_NO_PAD.encode(&data),
+            };
+            self.post_json("/chunk", &upload).await.map_err(failed)?;
+            transfer.pace(data.len() as u64).await;
+        }
+        log::info!("⛓️ Posted transaction {} in {} chunks", tx.id, chunked.chunks.len());
+        Ok(())
+    }
+
+    /// Send `quantity` winston from the connected wallet to `target`,
+    /// returning the transfer as posted
+    pub async fn transfer(&self, wallet: &WalletService, target: &str, quantity: u128, tags: &[(String, String)]) -> Result<NativeTransaction, NativeTxError> {
+        let reward = PricingService::new()
+            .transfer_fee_winston(target)
+            .await
+            .map_err(NativeTxError::Pricing)?;
+        let last_tx = self.anchor().await?;
+        let signed = self.sign(wallet, &NativeTransaction::transfer(target, quantity, tags, &last_tx, reward)).await?;
+        self.post_json("/tx", &signed).await?;
+        log::info!("⛓️ Posted transfer {} to {}", signed.id, target);
+        Ok(signed)
+    }
+
+    async fn post_json<T: Serialize>(&self, path: &str, body: &T) -> Result<(), NativeTxError> {
+        let response = self.gateways.post_json(path, body).await.map_err(NativeTxError::Gateway)?;
+        let status = response.status().as_u16();
+        if !response.status().is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(NativeTxError::Rejected(status, body));
+        }
+        Ok(())
+    }
+
+    /// Price, sign and post `file` as a transaction from the connected
+    /// wallet, returning it as posted
+    pub async fn publish(&self, wallet: &WalletService, file: &LocalFile, tags: &[(String, String)], transfer: &TransferControl) -> Result<NativeTransaction, NativeTxError> {
+        let (unsigned, chunked) = self.prepare(file, tags).await?;
+        let signed = self.sign(wallet, &unsigned).await?;
+        self.post(&signed, &chunked, file, transfer).await?;
+        Ok(signed)
+    }
+}
+
+impl Default for NativeTxService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Header fields the signature covers, plus the signature and ID
+const SIGNED_FIELDS: [&str; 11] = [
+    "format", "last_tx", "owner", "tags", "target", "quantity", "data_size", "data_root", "reward", "signature", "id",
+];
+
+/// `tx` as the wallet signed it
+///
+/// Wallets may reprice, re-anchor or retag a transaction before signing,
+/// so every signed field is taken from their answer; posting the fields we
+/// sent would not match the signature. Only the data has to stay as it
+/// was, since the chunks were built from it.
+fn signed_transaction(tx: &NativeTransaction, signed: HashMap<String, serde_json::Value>) -> Result<NativeTransaction, NativeTxError> {
+    let invalid = |e: serde_json::Error| NativeTxError::InvalidResponse(format!("signed transaction: {}", e));
+    let mut fields = match serde_json::to_value(tx).map_err(invalid)? {
+        serde_json::Value::Object(fields) => fields,
+        _ => return Err(NativeTxError::InvalidResponse("transaction did not serialize to an object".to_string())),
+    };
+    for (name, value) in signed {
+        // Some wallets leave fields they didn't touch empty
+        let empty = value.is_null() || value.as_str().is_some_and(str::is_empty);
+        if SIGNED_FIELDS.contains(&name.as_str()) && !empty {
+            fields.insert(name, value);
+        }
+    }
+    let mut result: NativeTransaction = serde_json::from_value(serde_json::Value::Object(fields)).map_err(invalid)?;
+
+    if result.data_root != tx.data_root || result.data_size != tx.data_size {
+        return Err(NativeTxError::Signing(WalletError::SigningFailed("The wallet changed the data".to_string())));
+    }
+    if !result.is_signed() {
+        return Err(NativeTxError::Signing(WalletError::SigningFailed("The wallet returned no signature".to_string())));
+    }
+    let id = transaction_id(&result.signature)?;
+    if !result.id.is_empty() && result.id != id {
+        return Err(NativeTxError::Signing(WalletError::SigningFailed("The wallet's ID doesn't match its signature".to_string())));
+    }
+    result.id = id;
+    result.data = String::new();
+    Ok(result)
+}
+
+/// A transaction's ID: the SHA-256 of its signature, base64url
+pub fn transaction_id(signature: &str) -> Result<String, NativeTxError> {
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|e| NativeTxError::InvalidResponse(format!("signature is not base64url: {}", e)))?;
+    Ok(URL_SAFE_NO_PAD.encode(Sha256::digest(&signature)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::wallet::{WalletCapabilities, WalletStrategy, WalletStrategyType};
+    use crate::utils::merkle::{chunk_data, MIN_CHUNK_SIZE};
+    use async_trait::async_trait;
+    use wasm_bindgen_test::*;
+
+    // Wallet that signs whatever it's given after overwriting `field`
+    struct RewritingWallet {
+        field: &'static str,
+        value: &'static str,
+    }
+
+    #[async_trait(?Send)]
+    impl WalletStrategy for RewritingWallet {
+        fn strategy_type(&self) -> WalletStrategyType {
+            WalletStrategyType::Wander
+        }
+
+        async fn is_available(&self) -> Result<bool, WalletError> {
+            Ok(true)
+        }
+
+        fn get_capabilities(&self) -> WalletCapabilities {
+            WalletCapabilities::default()
+        }
+
+        async fn connect(&mut self, _permissions: Vec<&str>) -> Result<String, WalletError> {
+            Ok("address".to_string())
+        }
+
+        async fn disconnect(&mut self) -> Result<(), WalletError> {
+            Ok(())
+        }
+
+        async fn get_active_address(&self) -> Result<String, WalletError> {
+            Ok("address".to_string())
+        }
+
+        async fn get_permissions(&self) -> Result<Vec<String>, WalletError> {
+            Ok(vec![])
+        }
+
+        async fn sign_transaction(&self, mut transaction_data: HashMap<String, serde_json::Value>) -> Result<HashMap<String, serde_json::Value>, WalletError> {
+            transaction_data.insert(self.field.to_string(), self.value.into());
+            transaction_data.insert("signature".to_string(), URL_SAFE_NO_PAD.encode([3u8; 512]).into());
+            Ok(transaction_data)
+        }
+
+        async fn check_connection(&self) -> Result<bool, WalletError> {
+            Ok(true)
+        }
+
+        async fn get_public_key(&self) -> Result<String, WalletError> {
+            Ok(URL_SAFE_NO_PAD.encode([9u8; 512]))
+        }
+    }
+
+    fn rewriting_wallet(field: &'static str, value: &'static str) -> WalletService {
+        WalletService::with_strategy(Box::new(RewritingWallet { field, value }))
+    }
+
+    #[wasm_bindgen_test]
+    async fn files_chunk_as_their_bytes_do() {
+        // Spans several reads and ends on an evenly split pair
+        let data: Vec<u8> = (0..FILE_READ_CHUNK_SIZE as usize + MAX_CHUNK_SIZE + MIN_CHUNK_SIZE - 1).map(|i| (i % 251) as u8).collect();
+        let file = LocalFile::from_bytes("sermon.mp4", &data, "video/mp4").unwrap();
+        assert_eq!(chunk_file(&file).await.unwrap(), chunk_data(&data).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    async fn signed_headers_are_what_the_wallet_signed() {
+        let chunked = chunk_data(b"In the beginning was the Word").unwrap();
+        let tx = NativeTransaction::new(&chunked, &[("Type".to_string(), "Sermon".to_string())], "anchor", 1_000);
+        let service = NativeTxService::new();
+
+        // A repriced transaction is posted at the wallet's price
+        let signed = service.sign(&rewriting_wallet("reward", "2500"), &tx).await.unwrap();
+        assert_eq!(signed.reward, "2500");
+        assert_eq!(signed.owner, URL_SAFE_NO_PAD.encode([9u8; 512]));
+        assert_eq!(signed.id, transaction_id(&signed.signature).unwrap());
+        assert_eq!((signed.last_tx.as_str(), &signed.tags), ("anchor", &tx.tags));
+
+        // The chunks only fit the data root they were built for
+        let swapped = service.sign(&rewriting_wallet("data_root", "c29tZXRoaW5nIGVsc2U"), &tx).await;
+        assert!(matches!(swapped, Err(NativeTxError::Signing(_))));
+        let misnamed = service.sign(&rewriting_wallet("id", "not-the-signature-hash"), &tx).await;
+        assert!(matches!(misnamed, Err(NativeTxError::Signing(_))));
+    }
+
+    #[wasm_bindgen_test]
+    fn ids_are_the_hash_of_the_signature() {
+        let signature = URL_SAFE_NO_PAD.encode([1u8; 512]);
+        assert_eq!(transaction_id(&signature).unwrap(), URL_SAFE_NO_PAD.encode(Sha256::digest([1u8; 512])));
+        assert!(transaction_id("not base64!").is_err());
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::services::storage::{StorageError, StorageService, STUDY_NOTES_STORE};
+
+/// A private study note attached to an archived item
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StudyNote {
+    pub id: String,
+    pub item_tx_id: String,
+    /// Scripture reference the note is about, if any
+    pub passage: Option<String>,
+    pub text: String,
+    pub created_at: i64,
+}
+
+impl StudyNote {
+    pub fn new(item_tx_id: &str, passage: Option<String>, text: &str) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            item_tx_id: item_tx_id.to_string(),
+            passage,
+            text: text.trim().to_string(),
+            created_at: chrono::Utc::now().timestamp(),
+        }
+    }
+}
+
+/// Study notes kept in IndexedDB; they never leave the browser
+pub struct NotesService;
+
+impl NotesService {
+    /// Notes for an item, oldest first
+    pub async fn notes_for(item_tx_id: &str) -> Result<Vec<StudyNote>, StorageError> {
+        let storage = StorageService::open().await?;
+        let mut notes: Vec<StudyNote> = storage
+            .get_all::<StudyNote>(STUDY_NOTES_STORE)
+            .await?
+            .into_iter()
+            .filter(|note| note.item_tx_id == item_tx_id)
+            .collect();
+        notes.sort_by_key(|note| note.created_at);
+        Ok(notes)
+    }
+
+    /// Every note in this browser
+    pub async fn all() -> Result<Vec<StudyNote>, StorageError> {
+        let storage = StorageService::open().await?;
+        storage.get_all::<StudyNote>(STUDY_NOTES_STORE).await
+    }
+
+    pub async fn save(note: &StudyNote) -> Result<(), StorageError> {
+        let storage = StorageService::open().await?;
+        storage.put(STUDY_NOTES_STORE, &note.id, note).await
+    }
+
+    pub async fn delete(id: &str) -> Result<(), StorageError> {
+        let storage = StorageService::open().await?;
+        storage.delete(STUDY_NOTES_STORE, id).await
+    }
+}
@@ -0,0 +1,284 @@
+//! In-app notification center
+//!
+//! Things worth telling the user about while they were elsewhere: their
+//! uploads confirming, moderators approving or hiding their items, and new
+//! items from what they follow. Notifications live in IndexedDB until
+//! cleared, so they survive reloads without any email or push service.
+//!
+//! Moderation and Following notifications come from background sync. Each
+//! source keeps a watermark in localStorage, the newest block timestamp it
+//! has notified about, so clearing the list doesn't bring old news back.
+//! The first sync after install only sets the watermark.
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{ContentMetadata, SubmittedItem};
+use crate::services::graphql::GraphQLError;
+use crate::services::hide_registry::{all_entries, entries_on_items_of, HideAction};
+use crate::services::storage::{StorageError, StorageService, NOTIFICATIONS_STORE};
+use crate::utils::constants::MAX_NOTIFICATIONS;
+
+/// localStorage key for the newest moderation decision notified about
+const MODERATION_WATERMARK_KEY: &str = "faithful_archive_notified_moderation";
+
+/// localStorage key for the newest followed item notified about
+const FOLLOWING_WATERMARK_KEY: &str = "faithful_archive_notified_following";
+
+/// What a notification is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotificationKind {
+    UploadConfirmed,
+    ContentApproved,
+    /// A moderator hid the item from discovery
+    ContentRejected,
+    NewFromFollowed,
+}
+
+impl NotificationKind {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            NotificationKind::UploadConfirmed => "✅",
+            NotificationKind::ContentApproved => "👍",
+            NotificationKind::ContentRejected => "🙈",
+            NotificationKind::NewFromFollowed => "🆕",
+        }
+    }
+}
+
+/// Something that happened to an item the user cares about
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Notification {
+    /// Derived from the event, so the same event is never stored twice
+    pub id: String,
+    pub kind: NotificationKind,
+    pub tx_id: String,
+    pub title: String,
+    /// Moderator's reason, or the speaker of a followed item
+    pub detail: Option<String>,
+    /// Unix timestamp the notification was created
+    pub created_at: i64,
+    pub read: bool,
+}
+
+impl Notification {
+    fn new(kind: NotificationKind, id: String, tx_id: &str, title: &str, detail: Option<String>) -> Self {
+        Self {
+            id,
+            kind,
+            tx_id: tx_id.to_string(),
+            title: title.to_string(),
+            detail: detail.filter(|detail| !detail.is_empty()),
+            created_at: chrono::Utc::now().timestamp(),
+            read: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum NotificationError {
+    Storage(StorageError),
+    LookupFailed(GraphQLError),
+}
+
+impl std::fmt::Display for NotificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationError::Storage(e) => write!(f, "{}", e),
+            NotificationError::LookupFailed(e) => write!(f, "Could not look up notified items: {}", e),
+        }
+    }
+}
+
+impl From<StorageError> for NotificationError {
+    fn from(error: StorageError) -> Self {
+        NotificationError::Storage(error)
+    }
+}
+
+// Notifications, newest first
+fn use_notifications_state() -> &'static GlobalSignal<Vec<Notification>> {
+    static NOTIFICATIONS: GlobalSignal<Vec<Notification>> = GlobalSignal::new(Vec::new);
+    &NOTIFICATIONS
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+}
+
+fn watermark(key: &str) -> Option<i64> {
+    local_storage()
+        .and_then(|storage| storage.get_item(key).ok().flatten())
+        .and_then(|value| value.parse().ok())
+}
+
+fn set_watermark(key: &str, timestamp: i64) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(key, &timestamp.to_string());
+    }
+}
+
+/// Timestamps newer than the watermark under `key`, then moves it to the
+/// newest of them. Pending items (no timestamp) are left for a later pass.
+///
+/// Returns `None` on the first pass, when there is nothing to compare
+/// against yet.
+fn advance_watermark(key: &str, timestamps: impl Iterator<Item = Option<i64>>) -> Option<i64> {
+    let previous = watermark(key);
+    let newest = timestamps.flatten().max();
+    let now = chrono::Utc::now().timestamp();
+    match (previous, newest) {
+        (Some(previous), Some(newest)) if newest > previous => set_watermark(key, newest),
+        (None, _) => set_watermark(key, newest.unwrap_or(now)),
+        _ => {}
+    }
+    previous
+}
+
+/// Whether a block `timestamp` falls after `watermark`
+pub fn is_after(timestamp: Option<i64>, watermark: i64) -> bool {
+    timestamp.is_some_and(|timestamp| timestamp > watermark)
+}
+
+/// Notifications stored in this browser
+pub struct NotificationService;
+
+impl NotificationService {
+    /// Load saved notifications into the global state
+    pub async fn load() -> Result<(), NotificationError> {
+        let mut notifications: Vec<Notification> = StorageService::open().await?.get_all(NOTIFICATIONS_STORE).await?;
+        notifications.sort_by_key(|notification| std::cmp::Reverse(notification.created_at));
+        *use_notifications_state().write() = notifications;
+        Ok(())
+    }
+
+    /// Store a notification unless one with its ID already exists; the
+    /// oldest are dropped past `MAX_NOTIFICATIONS`
+    pub async fn push(notification: Notification) -> Result<(), NotificationError> {
+        if use_notifications_state().peek().iter().any(|existing| existing.id == notification.id) {
+            return Ok(());
+        }
+        let storage = StorageService::open().await?;
+        storage.put(NOTIFICATIONS_STORE, &notification.id, &notification).await?;
+
+        let overflow: Vec<Notification> = {
+            let mut notifications = use_notifications_state().write();
+            notifications.insert(0, notification);
+            let keep = notifications.len().min(MAX_NOTIFICATIONS);
+            notifications.split_off(keep)
+        };
+        for old in overflow {
+            storage.delete(NOTIFICATIONS_STORE, &old.id).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn mark_read(id: &str) -> Result<(), NotificationError> {
+        let Some(mut notification) = use_notifications_state().peek().iter().find(|n| n.id == id).cloned() else {
+            return Ok(());
+        };
+        notification.read = true;
+        StorageService::open().await?.put(NOTIFICATIONS_STORE, id, &notification).await?;
+        if let Some(existing) = use_notifications_state().write().iter_mut().find(|n| n.id == id) {
+            existing.read = true;
+        }
+        Ok(())
+    }
+
+    pub async fn mark_all_read() -> Result<(), NotificationError> {
+        let storage = StorageService::open().await?;
+        let unread: Vec<Notification> = use_notifications_state().peek().iter().filter(|n| !n.read).cloned().collect();
+        for mut notification in unread {
+            notification.read = true;
+            storage.put(NOTIFICATIONS_STORE, &notification.id, &notification).await?;
+        }
+        for notification in use_notifications_state().write().iter_mut() {
+            notification.read = true;
+        }
+        Ok(())
+    }
+
+    /// Delete every notification
+    pub async fn clear() -> Result<(), NotificationError> {
+        StorageService::open().await?.clear(NOTIFICATIONS_STORE).await?;
+        use_notifications_state().write().clear();
+        Ok(())
+    }
+
+    /// Tell the user a tracked upload reached its required confirmations
+    pub async fn upload_confirmed(item: &SubmittedItem) {
+        let id = format!("confirmed:{}", item.tx_id);
+        let notification = Notification::new(NotificationKind::UploadConfirmed, id, &item.tx_id, &item.title, None);
+        if let Err(e) = Self::push(notification).await {
+            log::warn!("Could not save notification: {}", e);
+        }
+    }
+
+    /// Notify about moderation decisions on `owner`'s items made since the
+    /// last pass; reads the registry as last refreshed
+    pub async fn moderation_decisions(owner: &str) -> Result<(), NotificationError> {
+        let entries = all_entries();
+        let Some(since) = advance_watermark(MODERATION_WATERMARK_KEY, entries.iter().map(|entry| entry.timestamp)) else {
+            return Ok(());
+        };
+        let recent = entries.into_iter().filter(|entry| is_after(entry.timestamp, since)).collect();
+        let owned = entries_on_items_of(owner, recent).await.map_err(NotificationError::LookupFailed)?;
+
+        for (entry, title) in owned {
+            let kind = match entry.action {
+                HideAction::Restore => NotificationKind::ContentApproved,
+                HideAction::Hide => NotificationKind::ContentRejected,
+            };
+            let id = format!("moderation:{}", entry.entry_tx_id);
+            Self::push(Notification::new(kind, id, &entry.target_id, &title, Some(entry.reason))).await?;
+        }
+        Ok(())
+    }
+
+    /// Notify about items in the Following feed that arrived since the
+    /// last pass
+    pub async fn followed_items(items: &[ContentMetadata]) -> Result<(), NotificationError> {
+        let Some(since) = advance_watermark(FOLLOWING_WATERMARK_KEY, items.iter().map(|item| item.block_timestamp)) else {
+            return Ok(());
+        };
+        for item in items.iter().filter(|item| is_after(item.block_timestamp, since)) {
+            let id = format!("following:{}", item.tx_id);
+            let notification = Notification::new(NotificationKind::NewFromFollowed, id, &item.tx_id, &item.title, item.speaker.clone());
+            Self::push(notification).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Load saved notifications in the background
+pub fn init_notifications() {
+    spawn(async {
+        if let Err(e) = NotificationService::load().await {
+            log::warn!("Failed to load notifications: {}", e);
+        }
+    });
+}
+
+/// Hook returning every notification, newest first
+pub fn use_notifications() -> Memo<Vec<Notification>> {
+    use_memo(move || use_notifications_state().read().clone())
+}
+
+/// Hook returning how many notifications are unread
+pub fn use_unread_count() -> Memo<usize> {
+    use_memo(move || use_notifications_state().read().iter().filter(|notification| !notification.read).count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn only_confirmed_items_after_the_watermark_count() {
+        assert!(is_after(Some(101), 100));
+        assert!(!is_after(Some(100), 100));
+        // Pending items wait until they have a block timestamp
+        assert!(!is_after(None, 100));
+    }
+}
@@ -0,0 +1,431 @@
+//! Webhook outbox: approvals of the connected wallet's items, kept in
+//! IndexedDB and delivered with retries by background sync
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+
+use crate::services::graphql::GraphQLError;
+use crate::services::hide_registry::{all_entries, entries_on_items_of, HideAction, HideEntry};
+use crate::services::storage::{StorageError, StorageService, OUTBOX_STORE};
+use crate::services::wallet::connected_address;
+use crate::utils::citation::permanent_url;
+use crate::utils::constants::{WEBHOOK_MAX_ATTEMPTS, WEBHOOK_RETRY_BASE_SECS, WEBHOOK_RETRY_MAX_SECS};
+
+/// localStorage key for the webhook URL and secret
+const WEBHOOK_KEY: &str = "faithful_archive_webhook";
+
+/// `event` of approval payloads
+const APPROVED_EVENT: &str = "item.approved";
+
+/// `event` of the payload sent by "Send test"
+const TEST_EVENT: &str = "webhook.test";
+
+/// Header carrying `sha256=<hex HMAC>` of the signed content
+pub const SIGNATURE_HEADER: &str = "X-Faithful-Archive-Signature";
+
+/// Header carrying the unix timestamp included in the signature
+pub const TIMESTAMP_HEADER: &str = "X-Faithful-Archive-Timestamp";
+
+#[derive(Debug, Clone)]
+pub enum OutboxError {
+    InvalidUrl(String),
+    NotConfigured,
+    Storage(StorageError),
+    LookupFailed(GraphQLError),
+    DeliveryFailed(String),
+}
+
+impl std::fmt::Display for OutboxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutboxError::InvalidUrl(url) => write!(f, "Not a valid https webhook URL: {}", url),
+            OutboxError::NotConfigured => write!(f, "No webhook is set up"),
+            OutboxError::Storage(e) => write!(f, "{}", e),
+            OutboxError::LookupFailed(e) => write!(f, "Could not look up approved items: {}", e),
+            OutboxError::DeliveryFailed(msg) => write!(f, "Webhook delivery failed: {}", msg),
+        }
+    }
+}
+
+impl From<StorageError> for OutboxError {
+    fn from(error: StorageError) -> Self {
+        OutboxError::Storage(error)
+    }
+}
+
+/// Where approvals are sent, and the secret deliveries are signed with
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    pub url: String,
+    /// Hex-encoded HMAC key, shown in settings for the receiver to use
+    pub secret: String,
+    /// Unix timestamp the webhook was set up; earlier approvals aren't sent
+    pub since: i64,
+}
+
+/// The item an approval is about
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApprovedItem {
+    pub tx_id: String,
+    pub title: String,
+    pub url: String,
+}
+
+/// JSON body POSTed to the webhook
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookPayload {
+    pub event: String,
+    /// Same for every attempt at one delivery, so receivers can ignore
+    /// repeats
+    pub delivery_id: String,
+    pub item: Option<ApprovedItem>,
+    pub owner: String,
+    pub moderator: Option<String>,
+    /// Registry entry recording the approval
+    pub entry_tx_id: Option<String>,
+    pub reason: Option<String>,
+    /// Block timestamp of the registry entry; `None` while pending
+    pub approved_at: Option<i64>,
+    /// One-line summary; also the message Discord webhooks post
+    pub content: String,
+}
+
+impl WebhookPayload {
+    fn approved(entry: &HideEntry, owner: &str, title: &str) -> Self {
+        let url = permanent_url(&entry.target_id);
+        Self {
+            event: APPROVED_EVENT.to_string(),
+            delivery_id: entry.entry_tx_id.clone(),
+            item: Some(ApprovedItem { tx_id: entry.target_id.clone(), title: title.to_string(), url: url.clone() }),
+            owner: owner.to_string(),
+            moderator: Some(entry.moderator.clone()),
+            entry_tx_id: Some(entry.entry_tx_id.clone()),
+            reason: Some(entry.reason.clone()).filter(|reason| !reason.is_empty()),
+            approved_at: entry.timestamp,
+            content: format!("\u{2705} \"{}\" was approved on Faithful Archive: {}", title, url),
+        }
+    }
+
+    fn test(owner: &str) -> Self {
+        let now = chrono::Utc::now().timestamp();
+        Self {
+            event: TEST_EVENT.to_string(),
+            delivery_id: format!("test-{}", now),
+            item: None,
+            owner: owner.to_string(),
+            moderator: None,
+            entry_tx_id: None,
+            reason: None,
+            approved_at: None,
+            content: "Faithful Archive webhook test: approvals of your items will be posted here.".to_string(),
+        }
+    }
+}
+
+/// A payload waiting to be delivered, or the record of one that was
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    pub payload: WebhookPayload,
+    pub attempts: u32,
+    /// Unix timestamp of the next attempt
+    pub next_attempt_at: i64,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<i64>,
+}
+
+impl OutboxEntry {
+    fn new(payload: WebhookPayload, now: i64) -> Self {
+        Self { payload, attempts: 0, next_attempt_at: now, last_error: None, delivered_at: None }
+    }
+
+    pub fn delivery_id(&self) -> &str {
+        &self.payload.delivery_id
+    }
+
+    /// Out of attempts; only a manual retry sends it again
+    pub fn gave_up(&self) -> bool {
+        self.delivered_at.is_none() && self.attempts >= WEBHOOK_MAX_ATTEMPTS
+    }
+
+    fn is_due(&self, now: i64) -> bool {
+        self.delivered_at.is_none() && !self.gave_up() && self.next_attempt_at <= now
+    }
+
+    fn record_failure(&mut self, error: String, now: i64) {
+        self.attempts += 1;
+        self.next_attempt_at = now + retry_delay_secs(self.attempts);
+        self.last_error = Some(error);
+    }
+}
+
+/// Wait after the `attempts`th failed delivery before trying again
+pub fn retry_delay_secs(attempts: u32) -> i64 {
+    let doublings = attempts.saturating_sub(1).min(20);
+    (WEBHOOK_RETRY_BASE_SECS << doublings).min(WEBHOOK_RETRY_MAX_SECS)
+}
+
+/// HMAC-SHA256 of `message` under `key` (RFC 2104)
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let pad = |byte: u8| block.map(|k| k ^ byte);
+    let inner = Sha256::new().chain_update(pad(0x36)).chain_update(message).finalize();
+    Sha256::new().chain_update(pad(0x5c)).chain_update(inner).finalize().into()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// `SIGNATURE_HEADER` value for `body` sent at `timestamp`
+pub fn signature(secret: &str, timestamp: i64, body: &str) -> String {
+    let signed = format!("{}.{}", timestamp, body);
+    format!("sha256={}", hex(&hmac_sha256(secret.as_bytes(), signed.as_bytes())))
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+}
+
+fn new_secret() -> Result<String, OutboxError> {
+    let mut secret = [0u8; 32];
+    getrandom::getrandom(&mut secret).map_err(|e| OutboxError::DeliveryFailed(e.to_string()))?;
+    Ok(hex(&secret))
+}
+
+fn store_webhook(config: Option<&WebhookConfig>) {
+    let Some(storage) = local_storage() else {
+        return;
+    };
+    let _ = match config.and_then(|config| serde_json::to_string(config).ok()) {
+        Some(json) => storage.set_item(WEBHOOK_KEY, &json),
+        None => storage.remove_item(WEBHOOK_KEY),
+    };
+    *use_webhook_state().write() = config.cloned();
+}
+
+fn read_webhook() -> Option<WebhookConfig> {
+    local_storage()
+        .and_then(|storage| storage.get_item(WEBHOOK_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+// Global webhook settings using Dioxus signals
+fn use_webhook_state() -> &'static GlobalSignal<Option<WebhookConfig>> {
+    static WEBHOOK: GlobalSignal<Option<WebhookConfig>> = GlobalSignal::new(read_webhook);
+    &WEBHOOK
+}
+
+// Outbox entries, newest first
+fn use_outbox_state() -> &'static GlobalSignal<Vec<OutboxEntry>> {
+    static OUTBOX: GlobalSignal<Vec<OutboxEntry>> = GlobalSignal::new(Vec::new);
+    &OUTBOX
+}
+
+/// The configured webhook, if any
+pub fn webhook_config() -> Option<WebhookConfig> {
+    use_webhook_state().peek().clone()
+}
+
+/// Set the webhook URL, keeping the secret if one was already made
+pub fn save_webhook(url: &str) -> Result<WebhookConfig, OutboxError> {
+    let url = url.trim();
+    let parsed = reqwest::Url::parse(url).map_err(|_| OutboxError::InvalidUrl(url.to_string()))?;
+    let local = matches!(parsed.host_str(), Some("localhost" | "127.0.0.1"));
+    if parsed.scheme() != "https" && !(local && parsed.scheme() == "http") {
+        return Err(OutboxError::InvalidUrl(url.to_string()));
+    }
+
+    let config = match webhook_config() {
+        Some(existing) => WebhookConfig { url: url.to_string(), ..existing },
+        None => WebhookConfig { url: url.to_string(), secret: new_secret()?, since: chrono::Utc::now().timestamp() },
+    };
+    store_webhook(Some(&config));
+    Ok(config)
+}
+
+/// Replace the signing secret, e.g. after it leaked
+pub fn regenerate_webhook_secret() -> Result<(), OutboxError> {
+    let config = webhook_config().ok_or(OutboxError::NotConfigured)?;
+    store_webhook(Some(&WebhookConfig { secret: new_secret()?, ..config }));
+    Ok(())
+}
+
+/// Stop sending approvals; undelivered entries stay until cleared
+pub fn remove_webhook() {
+    store_webhook(None);
+}
+
+async fn deliver(config: &WebhookConfig, payload: &WebhookPayload) -> Result<(), OutboxError> {
+    let body = serde_json::to_string(payload).map_err(|e| OutboxError::DeliveryFailed(e.to_string()))?;
+    let timestamp = chrono::Utc::now().timestamp();
+    let response = reqwest::Client::new()
+        .post(&config.url)
+        .header("Content-Type", "application/json")
+        .header(SIGNATURE_HEADER, signature(&config.secret, timestamp, &body))
+        .header(TIMESTAMP_HEADER, timestamp.to_string())
+        .header("X-Faithful-Archive-Event", payload.event.as_str())
+        .header("X-Faithful-Archive-Delivery", payload.delivery_id.as_str())
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| OutboxError::DeliveryFailed(e.to_string()))?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(OutboxError::DeliveryFailed(format!("HTTP {}", response.status())))
+    }
+}
+
+async fn save_entry(entry: &OutboxEntry) -> Result<(), OutboxError> {
+    StorageService::open().await?.put(OUTBOX_STORE, entry.delivery_id(), entry).await?;
+    let mut outbox = use_outbox_state().write();
+    match outbox.iter_mut().find(|existing| existing.delivery_id() == entry.delivery_id()) {
+        Some(existing) => *existing = entry.clone(),
+        None => outbox.insert(0, entry.clone()),
+    }
+    Ok(())
+}
+
+/// Webhook deliveries kept in this browser
+pub struct Outbox;
+
+impl Outbox {
+    /// Load saved entries into the outbox state
+    pub async fn load() -> Result<(), OutboxError> {
+        let mut entries: Vec<OutboxEntry> = StorageService::open().await?.get_all(OUTBOX_STORE).await?;
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.payload.approved_at.unwrap_or(i64::MAX)));
+        *use_outbox_state().write() = entries;
+        Ok(())
+    }
+
+    /// Add an entry for each approval of `owner`'s items since the webhook
+    /// was set up, returning how many were added
+    pub async fn enqueue_approvals(owner: &str, since: i64) -> Result<usize, OutboxError> {
+        let known: HashSet<String> = use_outbox_state().peek().iter().map(|entry| entry.delivery_id().to_string()).collect();
+        let approvals: Vec<HideEntry> = all_entries()
+            .into_iter()
+            .filter(|entry| entry.action == HideAction::Restore)
+            .filter(|entry| entry.timestamp.is_none_or(|timestamp| timestamp >= since))
+            .filter(|entry| !known.contains(&entry.entry_tx_id))
+            .collect();
+        let owned = entries_on_items_of(owner, approvals).await.map_err(OutboxError::LookupFailed)?;
+
+        let now = chrono::Utc::now().timestamp();
+        for (entry, title) in &owned {
+            save_entry(&OutboxEntry::new(WebhookPayload::approved(entry, owner, title), now)).await?;
+        }
+        let added = owned.len();
+        if added > 0 {
+            log::info!("📮 Queued {} approval webhooks", added);
+        }
+        Ok(added)
+    }
+
+    /// Attempt every entry that is due, recording failures for retry
+    pub async fn deliver_due(config: &WebhookConfig) -> Result<(), OutboxError> {
+        let now = chrono::Utc::now().timestamp();
+        let due: Vec<OutboxEntry> = use_outbox_state().peek().iter().filter(|entry| entry.is_due(now)).cloned().collect();
+        for mut entry in due {
+            match deliver(config, &entry.payload).await {
+                Ok(()) => {
+                    entry.delivered_at = Some(chrono::Utc::now().timestamp());
+                    entry.last_error = None;
+                }
+                Err(e) => {
+                    log::warn!("📮 Webhook delivery {} failed: {}", entry.delivery_id(), e);
+                    entry.record_failure(e.to_string(), chrono::Utc::now().timestamp());
+                }
+            }
+            save_entry(&entry).await?;
+        }
+        Ok(())
+    }
+
+    /// Queue new approvals and send what is due; a no-op without a webhook
+    /// or a connected wallet
+    pub async fn sync() -> Result<(), OutboxError> {
+        let (Some(config), Some(owner)) = (webhook_config(), connected_address()) else {
+            return Ok(());
+        };
+        Self::load().await?;
+        Self::enqueue_approvals(&owner, config.since).await?;
+        Self::deliver_due(&config).await
+    }
+
+    /// Send a test payload straight away
+    pub async fn send_test() -> Result<(), OutboxError> {
+        let config = webhook_config().ok_or(OutboxError::NotConfigured)?;
+        let owner = connected_address().unwrap_or_default();
+        deliver(&config, &WebhookPayload::test(&owner)).await
+    }
+
+    /// Send an entry again now, with a fresh set of attempts
+    pub async fn retry(delivery_id: &str) -> Result<(), OutboxError> {
+        let config = webhook_config().ok_or(OutboxError::NotConfigured)?;
+        let Some(mut entry) = use_outbox_state().peek().iter().find(|entry| entry.delivery_id() == delivery_id).cloned() else {
+            return Ok(());
+        };
+        entry.attempts = 0;
+        entry.next_attempt_at = chrono::Utc::now().timestamp();
+        save_entry(&entry).await?;
+        Self::deliver_due(&config).await
+    }
+
+    /// Forget delivered and abandoned entries
+    pub async fn clear_finished() -> Result<(), OutboxError> {
+        let storage = StorageService::open().await?;
+        let finished: Vec<String> = use_outbox_state()
+            .peek()
+            .iter()
+            .filter(|entry| entry.delivered_at.is_some() || entry.gave_up())
+            .map(|entry| entry.delivery_id().to_string())
+            .collect();
+        for delivery_id in &finished {
+            storage.delete(OUTBOX_STORE, delivery_id).await?;
+        }
+        use_outbox_state().write().retain(|entry| !finished.iter().any(|id| id == entry.delivery_id()));
+        Ok(())
+    }
+}
+
+/// Hook returning the configured webhook
+pub fn use_webhook() -> Memo<Option<WebhookConfig>> {
+    use_memo(move || use_webhook_state().read().clone())
+}
+
+/// Hook returning outbox entries, newest first
+pub fn use_outbox() -> Memo<Vec<OutboxEntry>> {
+    use_memo(move || use_outbox_state().read().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn hmac_matches_rfc_4231() {
+        let mac = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(hex(&mac), "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+        // Keys longer than a block are hashed first
+        let mac = hmac_sha256(&[0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First");
+        assert_eq!(hex(&mac), "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54");
+    }
+
+    #[wasm_bindgen_test]
+    fn retries_back_off_to_the_cap() {
+        assert_eq!(retry_delay_secs(1), WEBHOOK_RETRY_BASE_SECS);
+        assert_eq!(retry_delay_secs(3), WEBHOOK_RETRY_BASE_SECS * 4);
+        assert_eq!(retry_delay_secs(30), WEBHOOK_RETRY_MAX_SECS);
+    }
+}
@@ -0,0 +1,101 @@
+use serde::Deserialize;
+
+use crate::config::app_config;
+use crate::utils::constants::PASSAGE_TRANSLATION;
+use crate::utils::query_string::encode_component;
+
+#[derive(Debug, Clone)]
+pub enum PassageError {
+    RequestFailed(String),
+    NotFound,
+    InvalidResponse(String),
+}
+
+impl std::fmt::Display for PassageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PassageError::RequestFailed(msg) => write!(f, "Passage request failed: {}", msg),
+            PassageError::NotFound => write!(f, "Passage not found"),
+            PassageError::InvalidResponse(msg) => write!(f, "Invalid passage response: {}", msg),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Verse {
+    pub book_name: String,
+    pub chapter: u32,
+    pub verse: u32,
+    pub text: String,
+}
+
+/// Scripture text for a reference such as "John 3:16-18"
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Passage {
+    pub reference: String,
+    pub verses: Vec<Verse>,
+    pub translation_name: String,
+}
+
+/// Looks up passage text from the public Bible API
+pub struct PassageService {
+    client: reqwest::Client,
+}
+
+impl PassageService {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub async fn lookup(&self, reference: &str) -> Result<Passage, PassageError> {
+        let url = format!(
+            "{}/{}?translation={}",
+            app_config().passage_api_url,
+            encode_component(reference.trim()),
+            PASSAGE_TRANSLATION
+        );
+
+        let response = self.client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| PassageError::RequestFailed(e.to_string()))?;
+
+        if response.status().as_u16() == 404 {
+            return Err(PassageError::NotFound);
+        }
+        if !response.status().is_success() {
+            return Err(PassageError::RequestFailed(format!("HTTP {}", response.status().as_u16())));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| PassageError::InvalidResponse(e.to_string()))
+    }
+}
+
+impl Default for PassageService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether `text` mentions the book and chapter of `reference`
+///
+/// "John 3:16" matches text containing "John 3", so a transcript that says
+/// "turn to John 3" is linked to a pinned "John 3:16-18".
+pub fn mentions(text: &str, reference: &str) -> bool {
+    let chapter_ref = reference.split(':').next().unwrap_or(reference).trim().to_lowercase();
+    if chapter_ref.is_empty() {
+        return false;
+    }
+    let text = text.to_lowercase();
+    text.match_indices(&chapter_ref).any(|(start, _)| {
+        // Don't let "John 3" match "John 31"
+        let end = start + chapter_ref.len();
+        !text[end..].starts_with(|c: char| c.is_ascii_digit())
+    })
+}
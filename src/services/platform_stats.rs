@@ -0,0 +1,183 @@
+//! Archive-wide statistics
+//!
+//! Counts every listed item from GraphQL: how many there are, how many
+//! addresses uploaded them, how much they store and what kind of media
+//! they are. Walking the whole archive takes many queries, so the result
+//! is kept in memory and in IndexedDB and only counted again once it's
+//! older than `PLATFORM_STATS_MAX_AGE_SECS`, or when asked to.
+
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::DateTime;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{ContentKind, ContentMetadata, SPIRITUAL_CONTENT_TYPE};
+use crate::services::graphql::{GraphQLClient, GraphQLError};
+use crate::services::hide_registry::is_hidden;
+use crate::services::storage::{StorageService, PLATFORM_STATS_STORE};
+use crate::utils::constants::PLATFORM_STATS_MAX_AGE_SECS;
+
+/// Key of the single record in `PLATFORM_STATS_STORE`
+const STATS_KEY: &str = "latest";
+
+/// Category of items whose type fits no `ContentKind`
+pub const OTHER_CATEGORY: &str = "Other";
+
+/// Counts over every listed item
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlatformStats {
+    pub items: usize,
+    /// Distinct uploading addresses
+    pub uploaders: usize,
+    /// Sum of `File-Size` tags; older items without one aren't counted
+    pub bytes_stored: u64,
+    /// Items with a `File-Size` tag
+    pub sized_items: usize,
+    /// Items per `ContentKind` display name, then `OTHER_CATEGORY`; empty
+    /// categories are left out
+    pub by_category: Vec<(String, usize)>,
+    /// Items per month uploaded ("2024-05"), oldest first; pending items
+    /// count in the month they were counted
+    pub by_month: Vec<(String, usize)>,
+    /// When these were counted (seconds since the epoch)
+    pub computed_at: i64,
+}
+
+impl PlatformStats {
+    /// Count `items` as of `now`
+    pub fn compute(items: &[ContentMetadata], now: i64) -> Self {
+        let uploaders: HashSet<&str> = items.iter().map(|item| item.owner.as_str()).collect();
+        let sizes: Vec<u64> = items.iter().filter_map(|item| item.file_size).collect();
+
+        let mut by_category: Vec<(String, usize)> = ContentKind::ALL
+            .iter()
+            .map(|kind| kind.display_name())
+            .chain([OTHER_CATEGORY])
+            .map(|name| (name.to_string(), 0))
+            .collect();
+        let mut by_month: BTreeMap<String, usize> = BTreeMap::new();
+        for item in items {
+            // Encrypted items are filed by what they decrypt to
+            let content_type = item.encryption.as_ref().map_or(&item.content_type, |encryption| &encryption.content_type);
+            let category = ContentKind::ALL
+                .iter()
+                .find(|kind| kind.matches(content_type))
+                .map_or(OTHER_CATEGORY, |kind| kind.display_name());
+            if let Some((_, count)) = by_category.iter_mut().find(|(name, _)| name == category) {
+                *count += 1;
+            }
+
+            let timestamp = item.block_timestamp.unwrap_or(now);
+            if let Some(date) = DateTime::from_timestamp(timestamp, 0) {
+                *by_month.entry(date.format("%Y-%m").to_string()).or_insert(0) += 1;
+            }
+        }
+        by_category.retain(|(_, count)| *count > 0);
+
+        Self {
+            items: items.len(),
+            uploaders: uploaders.len(),
+            bytes_stored: sizes.iter().sum(),
+            sized_items: sizes.len(),
+            by_category,
+            by_month: by_month.into_iter().collect(),
+            computed_at: now,
+        }
+    }
+
+    pub fn is_fresh(&self, now: i64) -> bool {
+        now - self.computed_at < PLATFORM_STATS_MAX_AGE_SECS
+    }
+}
+
+// Stats counted or loaded this session
+fn use_stats_state() -> &'static GlobalSignal<Option<PlatformStats>> {
+    static PLATFORM_STATS: GlobalSignal<Option<PlatformStats>> = GlobalSignal::new(|| None);
+    &PLATFORM_STATS
+}
+
+async fn stored_stats() -> Option<PlatformStats> {
+    let storage = StorageService::open().await.ok()?;
+    storage.get(PLATFORM_STATS_STORE, STATS_KEY).await.ok().flatten()
+}
+
+/// Archive-wide counts, reused while fresh unless `recount` is set
+pub async fn load_platform_stats(recount: bool) -> Result<PlatformStats, GraphQLError> {
+    let now = chrono::Utc::now().timestamp();
+    if !recount {
+        let cached = use_stats_state().peek().clone();
+        let cached = match cached {
+            Some(stats) => Some(stats),
+            None => stored_stats().await,
+        };
+        if let Some(stats) = cached.filter(|stats| stats.is_fresh(now)) {
+            *use_stats_state().write() = Some(stats.clone());
+            return Ok(stats);
+        }
+    }
+
+    let transactions = GraphQLClient::new()
+        .find_transactions(&[], &[("Type", &[SPIRITUAL_CONTENT_TYPE])])
+        .await?;
+    let items: Vec<ContentMetadata> = transactions
+        .iter()
+        .filter(|tx| !is_hidden(&tx.id))
+        .filter_map(|tx| ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp))
+        .collect();
+    let stats = PlatformStats::compute(&items, now);
+
+    match StorageService::open().await {
+        Ok(storage) => {
+            if let Err(e) = storage.put(PLATFORM_STATS_STORE, STATS_KEY, &stats).await {
+                log::warn!("Could not cache platform stats: {}", e);
+            }
+        }
+        Err(e) => log::warn!("Could not cache platform stats: {}", e),
+    }
+    *use_stats_state().write() = Some(stats.clone());
+    log::info!("📊 Counted {} items from {} uploaders", stats.items, stats.uploaders);
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn item(tx_id: &str, owner: &str, content_type: &str, size: Option<u64>, timestamp: Option<i64>) -> ContentMetadata {
+        let mut tags = vec![
+            ("Title".to_string(), format!("Item {}", tx_id)),
+            ("Content-Type".to_string(), content_type.to_string()),
+        ];
+        if let Some(size) = size {
+            tags.push(("File-Size".to_string(), size.to_string()));
+        }
+        ContentMetadata::from_tags(tx_id, owner, &tags, None, timestamp).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn counts_items_uploaders_sizes_and_categories() {
+        // 2024-05-01 and 2024-06-15
+        let may = 1_714_521_600;
+        let june = 1_718_409_600;
+        let items = vec![
+            item("a", "church-1", "audio/mpeg", Some(1_000), Some(may)),
+            item("b", "church-1", "audio/mpeg", Some(500), Some(june)),
+            item("c", "church-2", "application/pdf", None, Some(june)),
+            item("d", "church-3", "image/png", Some(20), None),
+        ];
+        let stats = PlatformStats::compute(&items, june + 60);
+
+        assert_eq!(stats.items, 4);
+        assert_eq!(stats.uploaders, 3);
+        assert_eq!((stats.bytes_stored, stats.sized_items), (1_520, 3));
+        assert_eq!(
+            stats.by_category,
+            vec![("Audio".to_string(), 2), ("Document".to_string(), 1), (OTHER_CATEGORY.to_string(), 1)]
+        );
+        assert_eq!(stats.by_month, vec![("2024-05".to_string(), 1), ("2024-06".to_string(), 3)]);
+        assert!(stats.is_fresh(june + 60));
+        assert!(!stats.is_fresh(june + 60 + PLATFORM_STATS_MAX_AGE_SECS));
+    }
+}
@@ -0,0 +1,117 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::services::storage::{StorageError, StorageService, PLAYBACK_STORE};
+
+/// Minimum progress between persisted positions while playing (seconds)
+const SAVE_INTERVAL_SECS: f64 = 5.0;
+
+/// Positions closer than this to the start aren't worth resuming (seconds)
+const MIN_RESUME_SECS: f64 = 10.0;
+
+/// Positions this close to the end count as finished (seconds)
+const FINISHED_MARGIN_SECS: f64 = 15.0;
+
+/// Where playback of an item stopped
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlaybackPosition {
+    pub tx_id: String,
+    pub position_secs: f64,
+    pub duration_secs: f64,
+    pub updated_at: i64,
+}
+
+impl PlaybackPosition {
+    pub fn is_finished(&self) -> bool {
+        self.duration_secs > 0.0 && self.position_secs >= self.duration_secs - FINISHED_MARGIN_SECS
+    }
+
+    /// Whether the detail page should offer to resume
+    pub fn is_resumable(&self) -> bool {
+        self.position_secs >= MIN_RESUME_SECS && !self.is_finished()
+    }
+
+    /// Fraction played, for progress bars
+    pub fn progress(&self) -> f64 {
+        if self.duration_secs <= 0.0 {
+            return 0.0;
+        }
+        (self.position_secs / self.duration_secs).clamp(0.0, 1.0)
+    }
+}
+
+// Global playback positions using Dioxus signals
+fn use_playback_state() -> &'static GlobalSignal<HashMap<String, PlaybackPosition>> {
+    static PLAYBACK: GlobalSignal<HashMap<String, PlaybackPosition>> = GlobalSignal::new(HashMap::new);
+    &PLAYBACK
+}
+
+/// Remembers listening/watching positions across sessions
+pub struct PlaybackService;
+
+impl PlaybackService {
+    /// Load saved positions into memory
+    pub async fn load() -> Result<(), StorageError> {
+        let storage = StorageService::open().await?;
+        let positions = storage.get_all::<PlaybackPosition>(PLAYBACK_STORE).await?;
+        let mut state = use_playback_state().write();
+        for position in positions {
+            state.insert(position.tx_id.clone(), position);
+        }
+        Ok(())
+    }
+
+    /// Record the player's position
+    ///
+    /// Called on every `timeupdate`, so positions are only persisted after
+    /// `SAVE_INTERVAL_SECS` of progress unless `force` is set (pause, end).
+    pub async fn record(tx_id: &str, position_secs: f64, duration_secs: f64, force: bool) {
+        let last = use_playback_state().read().get(tx_id).map(|p| p.position_secs);
+        if !force && last.is_some_and(|last| (position_secs - last).abs() < SAVE_INTERVAL_SECS) {
+            return;
+        }
+
+        let position = PlaybackPosition {
+            tx_id: tx_id.to_string(),
+            position_secs,
+            duration_secs,
+            updated_at: chrono::Utc::now().timestamp(),
+        };
+        use_playback_state().write().insert(tx_id.to_string(), position.clone());
+
+        match StorageService::open().await {
+            Ok(storage) => {
+                if let Err(e) = storage.put(PLAYBACK_STORE, tx_id, &position).await {
+                    log::warn!("Failed to save playback position for {}: {}", tx_id, e);
+                }
+            }
+            Err(e) => log::warn!("Playback storage unavailable: {}", e),
+        }
+    }
+}
+
+/// Load saved playback positions in the background
+pub fn init_playback_positions() {
+    spawn(async {
+        if let Err(e) = PlaybackService::load().await {
+            log::warn!("Failed to load playback positions: {}", e);
+        }
+    });
+}
+
+/// Hook returning the saved position of an item
+pub fn use_playback_position(tx_id: String) -> Memo<Option<PlaybackPosition>> {
+    use_memo(use_reactive!(|tx_id| use_playback_state().read().get(&tx_id).cloned()))
+}
+
+/// "23:41" or "1:02:03"
+pub fn format_position(secs: f64) -> String {
+    let total = secs.max(0.0) as u64;
+    let (hours, minutes, seconds) = (total / 3600, (total % 3600) / 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
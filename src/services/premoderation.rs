@@ -0,0 +1,292 @@
+//! Risk scoring of new submissions for the review queue
+//!
+//! Uploads go live straight away and are reviewed afterwards, so the queue
+//! of recent submissions can be long. Before it's shown, each item is run
+//! through a few cheap checks that don't need anyone to open it: no
+//! scripture references, a content type that isn't media or a document, an
+//! uploader whose items were de-listed before, and a file that's already
+//! in the archive under another item, and audio or video without the
+//! rights declaration or license number music needs. Each flag adds to a risk score and
+//! the queue is sorted by it, so reviewers see the likeliest problems
+//! first. Nothing is hidden automatically.
+
+use std::collections::HashMap;
+
+use crate::models::{ContentKind, ContentMetadata, SPIRITUAL_CONTENT_TYPE};
+use crate::services::graphql::{GraphQLClient, GraphQLError};
+use crate::services::hide_registry::{all_entries, entries_for, HideAction};
+use crate::services::revisions::revocation_of;
+use crate::services::upload::extension_for;
+
+/// How many of the newest submissions the queue looks at
+pub const SUBMISSION_QUEUE_SIZE: u32 = 50;
+
+/// Something about a submission worth a reviewer's attention
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RiskFlag {
+    MissingScripture,
+    /// Not a media, document or image type the uploader offers
+    SuspiciousContentType(String),
+    /// How many of the uploader's earlier items were de-listed
+    PriorRejections(usize),
+    /// The same file is already archived as this item
+    DuplicateFile(String),
+    /// Audio or video published without a rights declaration, so any music
+    /// in it is unaccounted for
+    MissingRightsDeclaration,
+    /// Declared as licensed music but with no CCLI or license number
+    MissingMusicLicense,
+}
+
+impl RiskFlag {
+    /// How much the flag adds to an item's risk score
+    pub fn weight(&self) -> u32 {
+        match self {
+            RiskFlag::MissingScripture => 1,
+            RiskFlag::SuspiciousContentType(_) => 4,
+            RiskFlag::PriorRejections(count) => 2 * (*count).min(3) as u32,
+            RiskFlag::DuplicateFile(_) => 3,
+            RiskFlag::MissingRightsDeclaration => 2,
+            RiskFlag::MissingMusicLicense => 3,
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            RiskFlag::MissingScripture => "No scripture references".to_string(),
+            RiskFlag::SuspiciousContentType(content_type) => format!("Unusual content type: {}", content_type),
+            RiskFlag::PriorRejections(1) => "Uploader has had an item de-listed before".to_string(),
+            RiskFlag::PriorRejections(count) => format!("Uploader has had {} items de-listed before", count),
+            RiskFlag::DuplicateFile(_) => "Same file as an earlier item".to_string(),
+            RiskFlag::MissingRightsDeclaration => "No rights declaration".to_string(),
+            RiskFlag::MissingMusicLicense => "Licensed music without a CCLI or license number".to_string(),
+        }
+    }
+}
+
+/// How risky a score is, for the queue's badges
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl RiskLevel {
+    pub fn of_score(score: u32) -> Self {
+        match score {
+            0..=1 => RiskLevel::Low,
+            2..=4 => RiskLevel::Medium,
+            _ => RiskLevel::High,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            RiskLevel::Low => "Low risk",
+            RiskLevel::Medium => "Medium risk",
+            RiskLevel::High => "High risk",
+        }
+    }
+}
+
+/// A submission with what the checks found
+#[derive(Debug, Clone, PartialEq)]
+pub struct RiskAssessment {
+    pub item: ContentMetadata,
+    pub flags: Vec<RiskFlag>,
+    pub score: u32,
+}
+
+impl RiskAssessment {
+    pub fn level(&self) -> RiskLevel {
+        RiskLevel::of_score(self.score)
+    }
+}
+
+/// What the checks know beyond the item itself
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RiskContext {
+    /// De-listed items per uploader address
+    pub rejections: HashMap<String, usize>,
+    /// Earliest item holding each file hash
+    pub first_with_hash: HashMap<String, String>,
+}
+
+/// Run every check on `item`
+pub fn assess(item: ContentMetadata, context: &RiskContext) -> RiskAssessment {
+    let mut flags = Vec::new();
+    if item.scripture_refs.is_empty() {
+        flags.push(RiskFlag::MissingScripture);
+    }
+    // Encrypted items are stored as octet-stream by design
+    if item.encryption.is_none() && extension_for(&item.content_type) == "bin" {
+        flags.push(RiskFlag::SuspiciousContentType(item.content_type.clone()));
+    }
+    if let Some(&count) = context.rejections.get(&item.owner).filter(|count| **count > 0) {
+        flags.push(RiskFlag::PriorRejections(count));
+    }
+    match &item.rights {
+        Some(rights) if rights.missing_music_license() => flags.push(RiskFlag::MissingMusicLicense),
+        Some(_) => {}
+        None if is_recording(&item) => flags.push(RiskFlag::MissingRightsDeclaration),
+        None => {}
+    }
+    if let Some(first) = item.file_hash.as_ref().and_then(|hash| context.first_with_hash.get(hash)) {
+        if *first != item.tx_id {
+            flags.push(RiskFlag::DuplicateFile(first.clone()));
+        }
+    }
+
+    let score = flags.iter().map(RiskFlag::weight).sum();
+    RiskAssessment { item, flags, score }
+}
+
+/// Audio or video, where worship music is likeliest
+fn is_recording(item: &ContentMetadata) -> bool {
+    let content_type = item.encryption.as_ref().map_or(&item.content_type, |encryption| &encryption.content_type);
+    ContentKind::Audio.matches(content_type) || ContentKind::Video.matches(content_type)
+}
+
+/// Riskiest first; newest first among equals
+pub fn sort_by_risk(assessments: &mut [RiskAssessment]) {
+    assessments.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| b.item.block_timestamp.unwrap_or(i64::MAX).cmp(&a.item.block_timestamp.unwrap_or(i64::MAX)))
+    });
+}
+
+/// The newest submissions no moderator has decided on yet, scored and
+/// sorted riskiest first
+pub async fn load_submission_queue() -> Result<Vec<RiskAssessment>, GraphQLError> {
+    let graphql = GraphQLClient::new();
+    let page = graphql
+        .find_transactions_page(&[("Type", &[SPIRITUAL_CONTENT_TYPE])], SUBMISSION_QUEUE_SIZE, None)
+        .await?;
+    let items: Vec<ContentMetadata> = page
+        .transactions
+        .iter()
+        .filter(|tx| entries_for(&tx.id).is_empty())
+        .filter_map(|tx| ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp))
+        .collect();
+
+    let context = RiskContext {
+        rejections: rejections_by_uploader(&graphql).await?,
+        first_with_hash: first_items_by_hash(&graphql, &items).await?,
+    };
+    let mut assessments: Vec<RiskAssessment> = items.into_iter().map(|item| assess(item, &context)).collect();
+    sort_by_risk(&mut assessments);
+    Ok(assessments)
+}
+
+/// De-listed items per uploader, leaving out those the uploader withdrew
+/// themselves
+async fn rejections_by_uploader(graphql: &GraphQLClient) -> Result<HashMap<String, usize>, GraphQLError> {
+    let mut hidden: Vec<String> = all_entries()
+        .into_iter()
+        .filter(|entry| entry.action == HideAction::Hide && revocation_of(&entry.target_id).is_none())
+        .map(|entry| entry.target_id)
+        .collect();
+    hidden.sort();
+    hidden.dedup();
+    if hidden.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let ids: Vec<&str> = hidden.iter().map(String::as_str).collect();
+    let mut rejections = HashMap::new();
+    for tx in graphql.transactions_by_ids(&ids).await? {
+        *rejections.entry(tx.owner).or_insert(0) += 1;
+    }
+    Ok(rejections)
+}
+
+/// The earliest item holding each of `items`' files
+async fn first_items_by_hash(graphql: &GraphQLClient, items: &[ContentMetadata]) -> Result<HashMap<String, String>, GraphQLError> {
+    let hashes: Vec<&str> = items.iter().filter_map(|item| item.file_hash.as_deref()).collect();
+    if hashes.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let mut holders = graphql
+        .find_transactions(&[], &[("Type", &[SPIRITUAL_CONTENT_TYPE]), ("File-Hash", &hashes)])
+        .await?;
+    // Pending items have no timestamp yet and are the newest
+    holders.sort_by_key(|tx| tx.timestamp.unwrap_or(i64::MAX));
+
+    let mut first = HashMap::new();
+    for tx in holders {
+        if let Some(hash) = tx.tag("File-Hash") {
+            first.entry(hash.to_string()).or_insert(tx.id.clone());
+        }
+    }
+    Ok(first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RightsBasis, RightsDeclaration};
+    use wasm_bindgen_test::*;
+
+    fn item(tx_id: &str, owner: &str, content_type: &str, scripture: &[&str], hash: Option<&str>) -> ContentMetadata {
+        let mut tags = vec![
+            ("Title".to_string(), format!("Sermon {}", tx_id)),
+            ("Content-Type".to_string(), content_type.to_string()),
+            ("Rights-Basis".to_string(), "Own-Work".to_string()),
+            ("Rights-Holder".to_string(), owner.to_string()),
+        ];
+        for (index, scripture_ref) in scripture.iter().enumerate() {
+            tags.push((format!("Scripture-Ref-{}", index), scripture_ref.to_string()));
+        }
+        if let Some(hash) = hash {
+            tags.push(("File-Hash".to_string(), hash.to_string()));
+        }
+        ContentMetadata::from_tags(tx_id, owner, &tags, None, Some(1_700_000_000)).unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn riskiest_submissions_sort_first() {
+        let context = RiskContext {
+            rejections: HashMap::from([("repeat-offender".to_string(), 2)]),
+            first_with_hash: HashMap::from([("abc123".to_string(), "original".to_string())]),
+        };
+        let mut queue: Vec<RiskAssessment> = vec![
+            item("clean", "faithful", "audio/mpeg", &["John 3:16"], Some("def456")),
+            item("no-refs", "faithful", "audio/mpeg", &[], None),
+            item("copy", "faithful", "audio/mpeg", &["Rom 8:28"], Some("abc123")),
+            item("script", "repeat-offender", "text/html", &[], None),
+        ]
+        .into_iter()
+        .map(|item| assess(item, &context))
+        .collect();
+        sort_by_risk(&mut queue);
+
+        let order: Vec<&str> = queue.iter().map(|assessment| assessment.item.tx_id.as_str()).collect();
+        assert_eq!(order, vec!["script", "copy", "no-refs", "clean"]);
+        assert_eq!(queue[0].level(), RiskLevel::High);
+        assert_eq!(queue[1].flags, vec![RiskFlag::DuplicateFile("original".to_string())]);
+        assert!(queue[3].flags.is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn recordings_without_rights_for_their_music_are_flagged() {
+        let context = RiskContext::default();
+        let mut undeclared = item("undeclared", "faithful", "audio/mpeg", &["Psalm 96"], None);
+        undeclared.rights = None;
+        let mut unlicensed = item("unlicensed", "faithful", "video/mp4", &["Psalm 96"], None);
+        unlicensed.rights = Some(RightsDeclaration {
+            basis: RightsBasis::Licensed,
+            rights_holder: "Grace Fellowship".to_string(),
+            contains_music: true,
+            ..RightsDeclaration::default()
+        });
+        let mut notes = item("notes", "faithful", "application/pdf", &["Psalm 96"], None);
+        notes.rights = None;
+
+        assert_eq!(assess(undeclared, &context).flags, vec![RiskFlag::MissingRightsDeclaration]);
+        assert_eq!(assess(unlicensed, &context).flags, vec![RiskFlag::MissingMusicLicense]);
+        assert!(assess(notes, &context).flags.is_empty());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::services::gateway::{GatewayError, GatewayManager};
+
+/// Winston per AR (1 AR = 10^12 winston)
+const WINSTON_PER_AR: f64 = 1_000_000_000_000.0;
+
+#[derive(Debug, Clone)]
+pub enum PricingError {
+    Gateway(GatewayError),
+    BadStatus(u16),
+    InvalidResponse(String),
+}
+
+impl std::fmt::Display for PricingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PricingError::Gateway(e) => write!(f, "Price lookup failed: {}", e),
+            PricingError::BadStatus(status) => write!(f, "Price endpoint returned HTTP {}", status),
+            PricingError::InvalidResponse(msg) => write!(f, "Invalid price response: {}", msg),
+        }
+    }
+}
+
+/// Storage cost estimates from the gateway `/price` endpoint
+pub struct PricingService {
+    gateways: GatewayManager,
+}
+
+impl PricingService {
+    pub fn new() -> Self {
+        Self {
+            gateways: GatewayManager::new(),
+        }
+    }
+
+    // Gateway endpoints answer with a bare winston amount
+    async fn get_winston(&self, path: &str) -> Result<u128, PricingError> {
+        let response = self.gateways
+            .get(path)
+            .await
+            .map_err(PricingError::Gateway)?;
+
+        if !response.status().is_success() {
+            return Err(PricingError::BadStatus(response.status().as_u16()));
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| PricingError::InvalidResponse(e.to_string()))?;
+
+        body.trim()
+            .parse::<u128>()
+            .map_err(|e| PricingError::InvalidResponse(e.to_string()))
+    }
+
+    /// Cost in winston to store `bytes` bytes permanently
+    pub async fn storage_price_winston(&self, bytes: u64) -> Result<u128, PricingError> {
+        self.get_winston(&format!("/price/{}", bytes)).await
+    }
+
+    /// Fee in winston for a transfer to `target` with no data, which is
+    /// higher the first time a wallet receives AR
+    pub async fn transfer_fee_winston(&self, target: &str) -> Result<u128, PricingError> {
+        self.get_winston(&format!("/price/0/{}", target)).await
+    }
+
+    /// AR balance of `address`, in winston
+    pub async fn wallet_balance_winston(&self, address: &str) -> Result<u128, PricingError> {
+        self.get_winston(&format!("/wallet/{}/balance", address)).await
+    }
+
+    /// Convert winston to AR for display
+    pub fn winston_to_ar(winston: u128) -> f64 {
+        winston as f64 / WINSTON_PER_AR
+    }
+}
+
+impl Default for PricingService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::{HashMap, HashSet};
+
+use dioxus::prelude::*;
+
+use crate::models::{UploaderProfile, UPLOADER_PROFILE_TYPE};
+use crate::services::graphql::{GraphQLClient, GraphQLError, TransactionSummary};
+use crate::services::http::HttpCache;
+use crate::services::upload::{publish_with_wallet, PublishError};
+
+/// `Type` tag of a profile logo DataItem
+pub const PROFILE_LOGO_TYPE: &str = "Profile-Logo";
+
+/// Longest side of a profile logo in pixels
+pub const PROFILE_LOGO_MAX_DIMENSION: u32 = 256;
+
+#[derive(Debug, Clone)]
+pub enum ProfileError {
+    LookupFailed(GraphQLError),
+    InvalidProfile(String),
+    PublishFailed(PublishError),
+}
+
+impl std::fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileError::LookupFailed(e) => write!(f, "Could not look up profiles: {}", e),
+            ProfileError::InvalidProfile(msg) => write!(f, "Profile is invalid: {}", msg),
+            ProfileError::PublishFailed(e) => write!(f, "Could not publish profile: {}", e),
+        }
+    }
+}
+
+// Resolved profiles by address; `None` when the address has none
+fn use_profile_cache() -> &'static GlobalSignal<HashMap<String, Option<UploaderProfile>>> {
+    static PROFILE_CACHE: GlobalSignal<HashMap<String, Option<UploaderProfile>>> = GlobalSignal::new(HashMap::new);
+    &PROFILE_CACHE
+}
+
+// Addresses with a lookup in flight, so cards sharing an uploader query once
+fn use_pending_profiles() -> &'static GlobalSignal<HashSet<String>> {
+    static PENDING_PROFILES: GlobalSignal<HashSet<String>> = GlobalSignal::new(HashSet::new);
+    &PENDING_PROFILES
+}
+
+/// Publishes and resolves uploader profiles
+pub struct ProfileService {
+    graphql: GraphQLClient,
+    http: HttpCache,
+}
+
+impl ProfileService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::cached(),
+            http: HttpCache::new(),
+        }
+    }
+
+    /// Look up the profiles of `addresses` that aren't cached yet, in one
+    /// query
+    ///
+    /// Only profiles signed by the address they describe are read, so
+    /// nobody can publish a profile for someone else.
+    pub async fn resolve(&self, addresses: &[&str]) -> Result<(), ProfileError> {
+        let wanted: Vec<&str> = {
+            let cache = use_profile_cache().read();
+            let pending = use_pending_profiles().read();
+            let mut wanted: Vec<&str> = addresses
+                .iter()
+                .copied()
+                .filter(|address| !cache.contains_key(*address) && !pending.contains(*address))
+                .collect();
+            wanted.sort_unstable();
+            wanted.dedup();
+            wanted
+        };
+        if wanted.is_empty() {
+            return Ok(());
+        }
+        use_pending_profiles().write().extend(wanted.iter().map(|address| address.to_string()));
+
+        let result = self.fetch_latest(&wanted).await;
+        use_pending_profiles().write().retain(|address| !wanted.contains(&address.as_str()));
+        let mut found = result?;
+
+        let mut cache = use_profile_cache().write();
+        for address in wanted {
+            cache.insert(address.to_string(), found.remove(address));
+        }
+        Ok(())
+    }
+
+    /// Profile of one address, looked up if it isn't cached
+    pub async fn load(&self, address: &str) -> Result<Option<UploaderProfile>, ProfileError> {
+        self.resolve(&[address]).await?;
+        Ok(cached_profile(address))
+    }
+
+    async fn fetch_latest(&self, addresses: &[&str]) -> Result<HashMap<String, UploaderProfile>, ProfileError> {
+        let transactions = self.graphql
+            .find_transactions(addresses, &[("Type", &[UPLOADER_PROFILE_TYPE])])
+            .await
+            .map_err(ProfileError::LookupFailed)?;
+
+        // Pending profiles have no timestamp yet and are the newest
+        let mut latest: HashMap<&str, &TransactionSummary> = HashMap::new();
+        for tx in &transactions {
+            let newer = latest
+                .get(tx.owner.as_str())
+                .is_none_or(|current| tx.timestamp.unwrap_or(i64::MAX) > current.timestamp.unwrap_or(i64::MAX));
+            if newer {
+                latest.insert(&tx.owner, tx);
+            }
+        }
+
+        let mut profiles = HashMap::new();
+        for (owner, tx) in latest {
+            let response = match self.http.get(&format!("/{}", tx.id)).await {
+                Ok(response) if response.is_success() => response,
+                Ok(response) => {
+                    log::warn!("Could not fetch profile {}: HTTP {}", tx.id, response.status);
+                    continue;
+                }
+                Err(e) => {
+                    log::warn!("Could not fetch profile {}: {}", tx.id, e);
+                    continue;
+                }
+            };
+            match response.json::<UploaderProfile>() {
+                Ok(profile) => {
+                    profiles.insert(owner.to_string(), profile);
+                }
+                Err(e) => log::warn!("Skipping invalid profile {}: {}", tx.id, e),
+            }
+        }
+        Ok(profiles)
+    }
+
+    /// Sign and publish a profile for the connected wallet
+    pub async fn publish(&self, profile: &UploaderProfile) -> Result<String, ProfileError> {
+        profile.validate().map_err(ProfileError::InvalidProfile)?;
+
+        let data = serde_json::to_vec(profile).map_err(|e| ProfileError::InvalidProfile(e.to_string()))?;
+        let tags = vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Type".to_string(), UPLOADER_PROFILE_TYPE.to_string()),
+            ("Title".to_string(), profile.ministry_name.clone()),
+        ];
+
+        let (owner, tx_id) = publish_with_wallet(data, tags)
+            .await
+            .map_err(ProfileError::PublishFailed)?;
+        use_profile_cache().write().insert(owner, Some(profile.clone()));
+        log::info!("🪪 Published profile for {} ({})", profile.ministry_name, tx_id);
+        Ok(tx_id)
+    }
+
+    /// Publish a logo image, returning its transaction ID
+    pub async fn publish_logo(&self, content_type: &str, bytes: Vec<u8>) -> Result<String, ProfileError> {
+        let tags = vec![
+            ("Content-Type".to_string(), content_type.to_string()),
+            ("Type".to_string(), PROFILE_LOGO_TYPE.to_string()),
+        ];
+        let (_, tx_id) = publish_with_wallet(bytes, tags)
+            .await
+            .map_err(ProfileError::PublishFailed)?;
+        Ok(tx_id)
+    }
+}
+
+impl Default for ProfileService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Cached profile of an address, without looking it up
+pub fn cached_profile(address: &str) -> Option<UploaderProfile> {
+    use_profile_cache().read().get(address).cloned().flatten()
+}
+
+/// Profile of `address`, once something has resolved it
+pub fn use_uploader_profile(address: String) -> Memo<Option<UploaderProfile>> {
+    use_memo(use_reactive!(|address| use_profile_cache().read().get(&address).cloned().flatten()))
+}
@@ -0,0 +1,273 @@
+//! Reactions to archive items
+//!
+//! A reaction is a tiny DataItem naming the item (`Target-Tx`) and the
+//! reaction (`Reaction`). Counts are tallied in the browser from the
+//! gateway's index, with each address counted once per reaction however
+//! often it publishes one. Cards ask for counts through
+//! `use_reaction_counts`, which batches every request made in the same
+//! render into one query and keeps results for `CACHE_TTL_MS`.
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+use crate::services::graphql::{GraphQLClient, GraphQLError, TransactionSummary};
+use crate::services::identity::{publish_low_stakes, IdentityError, Signer};
+
+/// `Type` tag of a reaction
+pub const REACTION_TYPE: &str = "Reaction";
+/// How long fetched counts are shown before being looked up again
+const CACHE_TTL_MS: f64 = 5.0 * 60.0 * 1000.0;
+/// How long to collect requests from cards before querying
+const BATCH_DELAY_MS: u32 = 50;
+/// Items per query, to keep the tag filter a reasonable size
+const BATCH_SIZE: usize = 50;
+
+#[derive(Debug, Clone)]
+pub enum ReactionError {
+    LookupFailed(GraphQLError),
+    PublishFailed(IdentityError),
+}
+
+impl std::fmt::Display for ReactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReactionError::LookupFailed(e) => write!(f, "Could not load reactions: {}", e),
+            ReactionError::PublishFailed(e) => write!(f, "Could not send the reaction: {}", e),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Reaction {
+    Amen,
+    Encouraged,
+}
+
+impl Reaction {
+    pub const ALL: [Reaction; 2] = [Reaction::Amen, Reaction::Encouraged];
+
+    pub fn tag_value(&self) -> &'static str {
+        match self {
+            Reaction::Amen => "amen",
+            Reaction::Encouraged => "encouraged",
+        }
+    }
+
+    pub fn from_tag(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|reaction| reaction.tag_value() == value)
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            Reaction::Amen => "🙏",
+            Reaction::Encouraged => "💛",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Reaction::Amen => "Amen",
+            Reaction::Encouraged => "Encouraged",
+        }
+    }
+}
+
+/// Who reacted to one item, by reaction
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReactionCounts {
+    reactors: HashMap<Reaction, HashSet<String>>,
+}
+
+impl ReactionCounts {
+    pub fn count(&self, reaction: Reaction) -> usize {
+        self.reactors.get(&reaction).map_or(0, HashSet::len)
+    }
+
+    pub fn total(&self) -> usize {
+        self.reactors.values().map(HashSet::len).sum()
+    }
+
+    pub fn has_reacted(&self, reaction: Reaction, address: &str) -> bool {
+        self.reactors.get(&reaction).is_some_and(|reactors| reactors.contains(address))
+    }
+
+    fn add(&mut self, reaction: Reaction, address: &str) {
+        self.reactors.entry(reaction).or_default().insert(address.to_string());
+    }
+}
+
+/// Counts per target item, each address counted once per reaction
+pub fn tally(transactions: &[TransactionSummary]) -> HashMap<String, ReactionCounts> {
+    let mut counts: HashMap<String, ReactionCounts> = HashMap::new();
+    for tx in transactions {
+        let (Some(target), Some(reaction)) = (tx.tag("Target-Tx"), tx.tag("Reaction").and_then(Reaction::from_tag)) else {
+            continue;
+        };
+        counts.entry(target.to_string()).or_default().add(reaction, &tx.owner);
+    }
+    counts
+}
+
+#[derive(Clone)]
+struct CachedCounts {
+    counts: ReactionCounts,
+    fetched_at: f64,
+}
+
+// Tallied reactions by item ID
+fn use_reaction_cache() -> &'static GlobalSignal<HashMap<String, CachedCounts>> {
+    static REACTION_CACHE: GlobalSignal<HashMap<String, CachedCounts>> = GlobalSignal::new(HashMap::new);
+    &REACTION_CACHE
+}
+
+thread_local! {
+    // Items asked for since the last batch went out
+    static QUEUED: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+    static BATCH_SCHEDULED: Cell<bool> = const { Cell::new(false) };
+}
+
+fn is_fresh(tx_id: &str, now: f64) -> bool {
+    use_reaction_cache()
+        .peek()
+        .get(tx_id)
+        .is_some_and(|cached| now - cached.fetched_at < CACHE_TTL_MS)
+}
+
+/// Publishes and counts reactions
+pub struct ReactionService {
+    graphql: GraphQLClient,
+}
+
+impl ReactionService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+        }
+    }
+
+    /// Look up counts for the items in `tx_ids` whose cached counts are
+    /// missing or stale
+    pub async fn resolve(&self, tx_ids: &[&str]) -> Result<(), ReactionError> {
+        let now = js_sys::Date::now();
+        let mut wanted: Vec<&str> = tx_ids.iter().copied().filter(|tx_id| !is_fresh(tx_id, now)).collect();
+        wanted.sort_unstable();
+        wanted.dedup();
+
+        for batch in wanted.chunks(BATCH_SIZE) {
+            let transactions = self.graphql
+                .find_transactions(&[], &[("Type", &[REACTION_TYPE]), ("Target-Tx", batch)])
+                .await
+                .map_err(ReactionError::LookupFailed)?;
+            let mut counts = tally(&transactions);
+
+            let mut cache = use_reaction_cache().write();
+            for tx_id in batch {
+                let counts = counts.remove(*tx_id).unwrap_or_default();
+                cache.insert(tx_id.to_string(), CachedCounts { counts, fetched_at: now });
+            }
+        }
+        Ok(())
+    }
+
+    /// Publish `reaction` to `tx_id`, signed by the wallet or the
+    /// anonymous identity, and count it straight away
+    pub async fn react(&self, tx_id: &str, reaction: Reaction) -> Result<Signer, ReactionError> {
+        let tags = vec![
+            ("Content-Type".to_string(), "text/plain".to_string()),
+            ("Type".to_string(), REACTION_TYPE.to_string()),
+            ("Target-Tx".to_string(), tx_id.to_string()),
+            ("Reaction".to_string(), reaction.tag_value().to_string()),
+        ];
+        let (signer, reaction_id) = publish_low_stakes(reaction.tag_value().as_bytes().to_vec(), tags)
+            .await
+            .map_err(ReactionError::PublishFailed)?;
+        log::info!("{} Reacted {} to {} ({})", reaction.emoji(), reaction.tag_value(), tx_id, reaction_id);
+
+        let mut cache = use_reaction_cache().write();
+        let cached = cache.entry(tx_id.to_string()).or_insert_with(|| CachedCounts {
+            counts: ReactionCounts::default(),
+            fetched_at: 0.0,
+        });
+        cached.counts.add(reaction, &signer.address);
+        Ok(signer)
+    }
+}
+
+impl Default for ReactionService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Queue `tx_id` for the next batched lookup unless its counts are fresh
+pub fn request_reactions(tx_id: &str) {
+    if is_fresh(tx_id, js_sys::Date::now()) {
+        return;
+    }
+    QUEUED.with(|queued| queued.borrow_mut().insert(tx_id.to_string()));
+    if BATCH_SCHEDULED.with(|scheduled| scheduled.replace(true)) {
+        return;
+    }
+
+    // Not tied to the requesting card, which may unmount before the batch
+    spawn_forever(async {
+        TimeoutFuture::new(BATCH_DELAY_MS).await;
+        BATCH_SCHEDULED.with(|scheduled| scheduled.set(false));
+        let tx_ids: Vec<String> = QUEUED.with(|queued| queued.borrow_mut().drain().collect());
+        let tx_ids: Vec<&str> = tx_ids.iter().map(String::as_str).collect();
+        if let Err(e) = ReactionService::new().resolve(&tx_ids).await {
+            log::warn!("{}", e);
+        }
+    });
+}
+
+/// Reaction counts for `tx_id`, looked up in the background when missing
+/// or stale
+pub fn use_reaction_counts(tx_id: String) -> Memo<Option<ReactionCounts>> {
+    let lookup_id = tx_id.clone();
+    use_effect(use_reactive!(|lookup_id| request_reactions(&lookup_id)));
+    use_memo(use_reactive!(|tx_id| use_reaction_cache().read().get(&tx_id).map(|cached| cached.counts.clone())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn reaction(owner: &str, target: &str, reaction: &str) -> TransactionSummary {
+        TransactionSummary {
+            id: format!("{}-{}-{}", owner, target, reaction),
+            owner: owner.to_string(),
+            tags: vec![
+                ("Type".to_string(), REACTION_TYPE.to_string()),
+                ("Target-Tx".to_string(), target.to_string()),
+                ("Reaction".to_string(), reaction.to_string()),
+            ],
+            block_height: None,
+            timestamp: None,
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn counts_each_address_once_per_reaction() {
+        let counts = tally(&[
+            reaction("ann", "sermon", "amen"),
+            reaction("ann", "sermon", "amen"),
+            reaction("ann", "sermon", "encouraged"),
+            reaction("bob", "sermon", "amen"),
+            reaction("bob", "hymn", "amen"),
+            reaction("eve", "sermon", "applause"),
+        ]);
+
+        let sermon = &counts["sermon"];
+        assert_eq!(sermon.count(Reaction::Amen), 2);
+        assert_eq!(sermon.count(Reaction::Encouraged), 1);
+        assert_eq!(sermon.total(), 3);
+        assert!(sermon.has_reacted(Reaction::Encouraged, "ann"));
+        assert!(!sermon.has_reacted(Reaction::Encouraged, "bob"));
+        assert_eq!(counts["hymn"].total(), 1);
+    }
+}
@@ -0,0 +1,198 @@
+//! Upload receipts
+//!
+//! Every item published from the upload form gets a receipt: its ID, tags,
+//! size, when and where it was submitted, and the uploader's ANS-104
+//! signature over it. Churches keep these with their own records; anyone
+//! can check a receipt against the archive, since the signature verifies
+//! with the owner key the gateway reports for the item.
+//!
+//! The bundle an item lands in isn't known at upload time, so
+//! `fill_bundle_ids` looks it up once the gateway has indexed it.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{Deserialize, Serialize};
+
+use crate::config::app_config;
+use crate::services::graphql::{GraphQLClient, TransactionTag};
+use crate::services::native_tx::NativeTransaction;
+use crate::services::storage::{StorageError, StorageService, RECEIPTS_STORE};
+use crate::utils::citation::permanent_url;
+use crate::utils::download::download_bytes;
+
+/// Receipt format; bump if fields change meaning
+const RECEIPT_VERSION: u32 = 1;
+
+/// Signature length for each ANS-104 signature type
+fn signature_length(signature_type: u16) -> Option<usize> {
+    match signature_type {
+        // Arweave RSA-PSS
+        1 => Some(512),
+        // ED25519 and Solana
+        2 | 4 => Some(64),
+        // Ethereum and typed Ethereum
+        3 | 7 => Some(65),
+        _ => None,
+    }
+}
+
+/// Signature type and base64url signature of a serialized DataItem
+pub fn data_item_signature(signed: &[u8]) -> Option<(u16, String)> {
+    let signature_type = u16::from_le_bytes([*signed.first()?, *signed.get(1)?]);
+    let length = signature_length(signature_type)?;
+    let signature = signed.get(2..2 + length)?;
+    Some((signature_type, URL_SAFE_NO_PAD.encode(signature)))
+}
+
+/// Record of one published item
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadReceipt {
+    pub version: u32,
+    pub tx_id: String,
+    pub title: String,
+    /// Address of the wallet that signed the item
+    pub owner: String,
+    /// Tags as signed, including `App-Name`
+    pub tags: Vec<TransactionTag>,
+    /// Size of the data as published (after any encryption)
+    pub size_bytes: u64,
+    /// Unix timestamp of the submission
+    pub submitted_at: i64,
+    /// Bundler the item was posted to, or the gateway for a layer-1
+    /// transaction
+    pub bundler: String,
+    /// ANS-104 signature type, e.g. 1 for Arweave wallets
+    pub signature_type: u16,
+    /// Base64url signature over the item's deep hash
+    pub signature: String,
+    /// Bundle transaction carrying the item, once known
+    pub bundle_id: Option<String>,
+    pub permanent_url: String,
+}
+
+impl UploadReceipt {
+    /// Receipt for an item the bundler accepted; None if `signed` isn't a
+    /// DataItem with a known signature type
+    pub fn new(tx_id: &str, title: &str, owner: &str, tags: &[(String, String)], size_bytes: u64, signed: &[u8]) -> Option<Self> {
+        let (signature_type, signature) = data_item_signature(signed)?;
+        Some(Self {
+            version: RECEIPT_VERSION,
+            tx_id: tx_id.to_string(),
+            title: title.to_string(),
+            owner: owner.to_string(),
+            tags: tags
+                .iter()
+                .map(|(name, value)| TransactionTag { name: name.clone(), value: value.clone() })
+                .collect(),
+            size_bytes,
+            submitted_at: chrono::Utc::now().timestamp(),
+            bundler: app_config().bundler.clone(),
+            signature_type,
+            signature,
+            bundle_id: None,
+            permanent_url: permanent_url(tx_id),
+        })
+    }
+
+    /// Receipt for a layer-1 transaction the gateway accepted
+    pub fn for_transaction(tx: &NativeTransaction, title: &str, owner: &str, tags: &[(String, String)], size_bytes: u64) -> Self {
+        Self {
+            version: RECEIPT_VERSION,
+            tx_id: tx.id.clone(),
+            title: title.to_string(),
+            owner: owner.to_string(),
+            tags: tags
+                .iter()
+                .map(|(name, value)| TransactionTag { name: name.clone(), value: value.clone() })
+                .collect(),
+            size_bytes,
+            submitted_at: chrono::Utc::now().timestamp(),
+            bundler: app_config().primary_gateway().to_string(),
+            // Arweave RSA-PSS, the only kind a layer-1 transaction has
+            signature_type: 1,
+            signature: tx.signature.clone(),
+            bundle_id: None,
+            permanent_url: permanent_url(&tx.id),
+        }
+    }
+
+    pub fn filename(&self) -> String {
+        format!("receipt-{}.json", self.tx_id)
+    }
+
+    pub fn to_json(&self) -> Vec<u8> {
+        serde_json::to_vec_pretty(self).unwrap_or_default()
+    }
+
+    /// Offer the receipt as a JSON download
+    pub fn download(&self) -> Result<(), String> {
+        download_bytes(&self.filename(), &self.to_json(), "application/json")
+    }
+}
+
+/// Stores and exports upload receipts
+pub struct ReceiptService;
+
+impl ReceiptService {
+    pub async fn save(receipt: &UploadReceipt) -> Result<(), StorageError> {
+        StorageService::open().await?.put(RECEIPTS_STORE, &receipt.tx_id, receipt).await
+    }
+
+    pub async fn get(tx_id: &str) -> Result<Option<UploadReceipt>, StorageError> {
+        StorageService::open().await?.get(RECEIPTS_STORE, tx_id).await
+    }
+
+    /// Every receipt, newest first
+    pub async fn all() -> Result<Vec<UploadReceipt>, StorageError> {
+        let mut receipts: Vec<UploadReceipt> = StorageService::open().await?.get_all(RECEIPTS_STORE).await?;
+        receipts.sort_by_key(|receipt| std::cmp::Reverse(receipt.submitted_at));
+        Ok(receipts)
+    }
+
+    /// Look up the bundles of receipts that don't have one yet, returning
+    /// how many were found
+    pub async fn fill_bundle_ids(receipts: &mut [UploadReceipt]) -> Result<usize, StorageError> {
+        let client = GraphQLClient::new();
+        let mut filled = 0;
+        for receipt in receipts.iter_mut().filter(|receipt| receipt.bundle_id.is_none()) {
+            let bundle_id = match client.transaction_details(&receipt.tx_id).await {
+                Ok(details) => details.and_then(|details| details.bundled_in).map(|bundle| bundle.id),
+                Err(e) => {
+                    log::warn!("Could not look up the bundle of {}: {}", receipt.tx_id, e);
+                    None
+                }
+            };
+            if bundle_id.is_some() {
+                receipt.bundle_id = bundle_id;
+                Self::save(receipt).await?;
+                filled += 1;
+            }
+        }
+        Ok(filled)
+    }
+
+    /// Offer every receipt as one JSON array download
+    pub fn export(receipts: &[UploadReceipt]) -> Result<(), String> {
+        let json = serde_json::to_vec_pretty(receipts).map_err(|e| e.to_string())?;
+        let date = chrono::Local::now().format("%Y-%m-%d");
+        download_bytes(&format!("upload-receipts-{}.json", date), &json, "application/json")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn signatures_are_read_from_the_data_item_header() {
+        let mut item = vec![3, 0];
+        item.extend([7u8; 65]);
+        item.extend([1u8; 65]);
+        let (signature_type, signature) = data_item_signature(&item).unwrap();
+        assert_eq!(signature_type, 3);
+        assert_eq!(URL_SAFE_NO_PAD.decode(signature).unwrap(), vec![7u8; 65]);
+
+        assert!(data_item_signature(&[1, 0, 9, 9]).is_none());
+        assert!(data_item_signature(&[99, 0]).is_none());
+    }
+}
@@ -0,0 +1,174 @@
+//! Crash recovery
+//!
+//! When rendering fails the app shows a recovery screen instead of a blank
+//! page. Reloading from there would lose whatever the visitor was typing,
+//! so forms with unsaved work keep a copy of it here; `preserve_drafts`
+//! writes that copy to localStorage before the reload, and the form picks
+//! it up again with `take_upload_draft`.
+//!
+//! A panic takes the Dioxus runtime down with it, so `install_panic_hook`
+//! preserves drafts and puts up a plain DOM version of the recovery screen.
+
+use std::cell::RefCell;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{closure::Closure, JsCast};
+
+use crate::models::{RightsDeclaration, UdlTerms};
+use crate::services::logging::diagnostic_bundle;
+
+/// localStorage key for an upload draft saved before a reload
+const UPLOAD_DRAFT_KEY: &str = "faithful_archive_upload_draft";
+
+thread_local! {
+    // Unsaved upload form contents, kept current while the form is open
+    static UPLOAD_DRAFT: RefCell<Option<UploadDraft>> = const { RefCell::new(None) };
+}
+
+/// Text fields and choices of the upload form
+///
+/// Files are too large to stash and the passphrase shouldn't be stored, so
+/// both have to be entered again.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UploadDraft {
+    pub title: String,
+    pub description: String,
+    pub speaker: String,
+    pub ministry: String,
+    pub scripture_refs: String,
+    pub language: String,
+    pub series_choice: String,
+    pub new_series_title: String,
+    pub privacy: String,
+    pub license: Option<UdlTerms>,
+    pub rights: Option<RightsDeclaration>,
+    /// Name of the chosen file, to remind the visitor which one it was
+    pub file_name: Option<String>,
+}
+
+impl UploadDraft {
+    /// Whether there is anything typed worth keeping
+    pub fn has_content(&self) -> bool {
+        [&self.title, &self.description, &self.speaker, &self.ministry, &self.scripture_refs, &self.new_series_title]
+            .iter()
+            .any(|text| !text.trim().is_empty())
+    }
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+}
+
+/// Keep `draft` as the upload form's unsaved work; None once it's
+/// published or the form closes
+pub fn track_upload_draft(draft: Option<UploadDraft>) {
+    let draft = draft.filter(UploadDraft::has_content);
+    UPLOAD_DRAFT.with(|tracked| {
+        if let Ok(mut tracked) = tracked.try_borrow_mut() {
+            *tracked = draft;
+        }
+    });
+}
+
+/// The draft saved before the last reload, removing it from storage
+pub fn take_upload_draft() -> Option<UploadDraft> {
+    let storage = local_storage()?;
+    let saved = storage.get_item(UPLOAD_DRAFT_KEY).ok().flatten()?;
+    let _ = storage.remove_item(UPLOAD_DRAFT_KEY);
+    serde_json::from_str(&saved).ok()
+}
+
+/// Save unsaved form contents so they survive a reload
+pub fn preserve_drafts() {
+    // May run from the panic hook mid-update, so never wait on a borrow
+    let draft = UPLOAD_DRAFT.with(|tracked| tracked.try_borrow().ok().and_then(|draft| draft.clone()));
+    let (Some(draft), Some(storage)) = (draft, local_storage()) else {
+        return;
+    };
+    if let Ok(json) = serde_json::to_string(&draft) {
+        if storage.set_item(UPLOAD_DRAFT_KEY, &json).is_ok() {
+            log::info!("💾 Kept the unsaved upload \"{}\" for after the reload", draft.title);
+        }
+    }
+}
+
+/// Save drafts and reload the page
+pub fn reload_app() {
+    preserve_drafts();
+    if let Some(window) = web_sys::window() {
+        let _ = window.location().reload();
+    }
+}
+
+/// Error details plus diagnostics, for "copy error details"
+pub fn crash_report(error: &str) -> String {
+    format!("Error: {}\n\n{}", error, diagnostic_bundle())
+}
+
+// Last resort when the runtime is gone: a static copy of the recovery
+// screen, wired up with plain DOM listeners
+fn show_static_recovery(report: String) -> Option<()> {
+    let document = web_sys::window()?.document()?;
+    let body = document.body()?;
+    let overlay = document.create_element("div").ok()?;
+    overlay.set_class_name("fixed inset-0 z-50 flex items-center justify-center bg-green-50 p-4");
+    overlay.set_inner_html(
+        "<div class=\"bg-white rounded-xl shadow-sm border border-green-200 p-8 max-w-lg space-y-4\">\
+         <h2 class=\"text-2xl font-bold text-gray-900\">Something went wrong</h2>\
+         <p class=\"text-gray-600\">Faithful Archive hit an unexpected error and had to stop. \
+         Reloading usually fixes it, and any upload details you were typing will be restored.</p>\
+         <div class=\"flex gap-2\">\
+         <button id=\"crash-reload\" class=\"bg-green-600 text-white px-4 py-2 rounded-lg text-sm font-medium\">Reload</button>\
+         <button id=\"crash-copy\" class=\"bg-white text-green-700 border border-green-300 px-4 py-2 rounded-lg text-sm font-medium\">Copy error details</button>\
+         </div></div>",
+    );
+    body.append_child(&overlay).ok()?;
+
+    let on_reload = Closure::<dyn FnMut(web_sys::Event)>::new(|_| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.location().reload();
+        }
+    });
+    let on_copy = Closure::<dyn FnMut(web_sys::Event)>::new(move |_| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.navigator().clipboard().write_text(&report);
+        }
+    });
+    if let Some(button) = document.get_element_by_id("crash-reload") {
+        let _ = button.add_event_listener_with_callback("click", on_reload.as_ref().unchecked_ref());
+    }
+    if let Some(button) = document.get_element_by_id("crash-copy") {
+        let _ = button.add_event_listener_with_callback("click", on_copy.as_ref().unchecked_ref());
+    }
+    // The page is finished; these live until it's reloaded
+    on_reload.forget();
+    on_copy.forget();
+    Some(())
+}
+
+/// Log panics, keep unsaved drafts and show the recovery screen
+pub fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        let message = info.to_string();
+        log::error!("💥 {}", message);
+        // Drafts are saved now: the runtime is gone, so the reload button
+        // on the static screen can't ask for them
+        preserve_drafts();
+        let _ = show_static_recovery(crash_report(&message));
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn only_drafts_with_text_are_kept() {
+        let untouched = UploadDraft { privacy: "wallet".to_string(), ..UploadDraft::default() };
+        assert!(!untouched.has_content());
+        let typed = UploadDraft { title: "Sunday sermon".to_string(), ..UploadDraft::default() };
+        assert!(typed.has_content());
+    }
+}
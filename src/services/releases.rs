@@ -0,0 +1,190 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, GraphQLError};
+use crate::services::upload::{publish_with_wallet, PublishError};
+use crate::services::wallet::WalletService;
+use crate::utils::constants::{APP_VERSION, RELEASE_PUBLISHER_ADDRESSES};
+
+/// `Type` tag value of release-notes DataItems
+pub const RELEASE_NOTES_TYPE: &str = "Release-Notes";
+
+/// localStorage key for the last version whose notes were shown
+const LAST_SEEN_VERSION_KEY: &str = "faithful_archive_last_seen_version";
+
+#[derive(Debug, Clone)]
+pub enum ReleaseError {
+    LookupFailed(GraphQLError),
+    InvalidNotes(String),
+    NotPublisher,
+    PublishFailed(PublishError),
+}
+
+impl std::fmt::Display for ReleaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReleaseError::LookupFailed(e) => write!(f, "Could not look up release notes: {}", e),
+            ReleaseError::InvalidNotes(msg) => write!(f, "Release notes are invalid: {}", msg),
+            ReleaseError::NotPublisher => write!(f, "The connected wallet is not a release publisher"),
+            ReleaseError::PublishFailed(e) => write!(f, "Could not publish release notes: {}", e),
+        }
+    }
+}
+
+/// A feature called out in release notes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReleaseHighlight {
+    pub title: String,
+    pub description: String,
+    /// In-app path to the feature, such as "/library"
+    #[serde(default)]
+    pub link: Option<String>,
+}
+
+/// Notes for one release, published as a JSON DataItem
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReleaseNotes {
+    pub version: String,
+    pub summary: String,
+    pub highlights: Vec<ReleaseHighlight>,
+    pub published_at: i64,
+}
+
+/// "1.2.3" as a comparable tuple; missing or non-numeric parts count as 0
+pub fn parse_version(version: &str) -> (u64, u64, u64) {
+    let mut parts = version
+        .trim_start_matches('v')
+        .split('.')
+        .map(|part| part.split(|c: char| !c.is_ascii_digit()).next().unwrap_or_default().parse().unwrap_or(0));
+    (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    )
+}
+
+/// Whether `address` may publish release notes
+pub fn is_release_publisher(address: &str) -> bool {
+    RELEASE_PUBLISHER_ADDRESSES.contains(&address)
+}
+
+fn last_seen_version() -> Option<String> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(LAST_SEEN_VERSION_KEY).ok().flatten())
+}
+
+fn set_last_seen_version(version: &str) {
+    if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+        let _ = storage.set_item(LAST_SEEN_VERSION_KEY, version);
+    }
+}
+
+// Version the user upgraded from, while its "What's new" is unseen
+fn use_upgraded_from_state() -> &'static GlobalSignal<Option<String>> {
+    static UPGRADED_FROM: GlobalSignal<Option<String>> = GlobalSignal::new(|| None);
+    &UPGRADED_FROM
+}
+
+/// Loads and publishes signed release notes
+pub struct ReleaseService {
+    graphql: GraphQLClient,
+}
+
+impl ReleaseService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+        }
+    }
+
+    /// Notes for releases after `since` up to the running version, newest
+    /// first. Only notes from `RELEASE_PUBLISHER_ADDRESSES` are read.
+    pub async fn notes_between(&self, since: Option<&str>) -> Result<Vec<ReleaseNotes>, ReleaseError> {
+        if RELEASE_PUBLISHER_ADDRESSES.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let current = parse_version(APP_VERSION);
+        let since = since.map(parse_version);
+        let transactions = self.graphql
+            .find_transactions(RELEASE_PUBLISHER_ADDRESSES, &[("Type", &[RELEASE_NOTES_TYPE])])
+            .await
+            .map_err(ReleaseError::LookupFailed)?;
+
+        let mut notes = Vec::new();
+        for tx in transactions {
+            let Some(version) = tx.tag("Version").map(parse_version) else {
+                continue;
+            };
+            if version > current || since.is_some_and(|since| version <= since) {
+                continue;
+            }
+            let response = GatewayManager::new()
+                .get(&format!("/{}", tx.id))
+                .await
+                .map_err(|e| ReleaseError::InvalidNotes(e.to_string()))?;
+            match response.json::<ReleaseNotes>().await {
+                Ok(release) => notes.push(release),
+                Err(e) => log::warn!("Skipping invalid release notes {}: {}", tx.id, e),
+            }
+        }
+
+        notes.sort_by_key(|release| std::cmp::Reverse(parse_version(&release.version)));
+        notes.dedup_by(|a, b| a.version == b.version);
+        Ok(notes)
+    }
+
+    /// Sign and publish notes from the connected publisher wallet
+    pub async fn publish(&self, notes: &ReleaseNotes) -> Result<String, ReleaseError> {
+        let signer = WalletService::init().await.get_active_address().await.unwrap_or_default();
+        if !is_release_publisher(&signer) {
+            return Err(ReleaseError::NotPublisher);
+        }
+
+        let data = serde_json::to_vec(notes).map_err(|e| ReleaseError::InvalidNotes(e.to_string()))?;
+        let tags = vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Type".to_string(), RELEASE_NOTES_TYPE.to_string()),
+            ("Version".to_string(), notes.version.clone()),
+        ];
+
+        let (_, tx_id) = publish_with_wallet(data, tags)
+            .await
+            .map_err(ReleaseError::PublishFailed)?;
+        log::info!("📰 Published release notes for {} ({})", notes.version, tx_id);
+        Ok(tx_id)
+    }
+}
+
+impl Default for ReleaseService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Detect an upgrade since the last visit
+///
+/// First visits record the running version without announcing anything.
+pub fn init_release_notes() {
+    match last_seen_version() {
+        Some(seen) if parse_version(&seen) < parse_version(APP_VERSION) => {
+            log::info!("📰 Upgraded from {} to {}", seen, APP_VERSION);
+            *use_upgraded_from_state().write() = Some(seen);
+        }
+        Some(_) => {}
+        None => set_last_seen_version(APP_VERSION),
+    }
+}
+
+/// Version the user upgraded from, if its notes haven't been seen
+pub fn use_upgraded_from() -> Memo<Option<String>> {
+    use_memo(move || use_upgraded_from_state().read().clone())
+}
+
+/// Record that the running version's notes were seen
+pub fn mark_release_seen() {
+    set_last_seen_version(APP_VERSION);
+    *use_upgraded_from_state().write() = None;
+}
@@ -0,0 +1,58 @@
+use crate::services::identity::{publish_low_stakes, IdentityError, Signer};
+
+/// `Type` tag of a viewer report about an item
+pub const CONTENT_REPORT_TYPE: &str = "Content-Report";
+
+/// Why a viewer flagged an item for the moderation team
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportReason {
+    Doctrine,
+    Inappropriate,
+    Spam,
+    BrokenMedia,
+    Other,
+}
+
+impl ReportReason {
+    pub const ALL: [ReportReason; 5] = [
+        ReportReason::Doctrine,
+        ReportReason::Inappropriate,
+        ReportReason::Spam,
+        ReportReason::BrokenMedia,
+        ReportReason::Other,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ReportReason::Doctrine => "Not Christ-honoring",
+            ReportReason::Inappropriate => "Inappropriate",
+            ReportReason::Spam => "Spam",
+            ReportReason::BrokenMedia => "Media doesn't play",
+            ReportReason::Other => "Other",
+        }
+    }
+
+    pub fn tag_value(&self) -> &'static str {
+        match self {
+            ReportReason::Doctrine => "doctrine",
+            ReportReason::Inappropriate => "inappropriate",
+            ReportReason::Spam => "spam",
+            ReportReason::BrokenMedia => "broken-media",
+            ReportReason::Other => "other",
+        }
+    }
+}
+
+/// Publish a report about `tx_id`, signed by the wallet or the local
+/// pseudonym. Returns the signer and the report's ID.
+pub async fn submit_report(tx_id: &str, reason: ReportReason, note: &str) -> Result<(Signer, String), IdentityError> {
+    let tags = vec![
+        ("Content-Type".to_string(), "text/plain".to_string()),
+        ("Type".to_string(), CONTENT_REPORT_TYPE.to_string()),
+        ("Target-Tx".to_string(), tx_id.to_string()),
+        ("Reason".to_string(), reason.tag_value().to_string()),
+    ];
+    let (signer, report_id) = publish_low_stakes(note.trim().as_bytes().to_vec(), tags).await?;
+    log::info!("🚩 Reported {} as {} ({})", tx_id, reason.tag_value(), report_id);
+    Ok((signer, report_id))
+}
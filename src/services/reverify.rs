@@ -0,0 +1,96 @@
+use dioxus::prelude::*;
+use wasm_bindgen::prelude::*;
+
+use crate::services::health::{record_run, HealthMonitor, HealthRun};
+use crate::utils::constants::{REVERIFY_INTERVAL_SECS, REVERIFY_SAMPLE_SIZE};
+
+/// localStorage key for the archive owner to re-verify in the background
+const REVERIFY_OWNER_KEY: &str = "faithful_archive_reverify_owner";
+
+/// localStorage key for the unix timestamp of the last scheduled run
+const LAST_REVERIFY_KEY: &str = "faithful_archive_last_reverify";
+
+// Bindings for public/reverify-bridge.js
+#[wasm_bindgen]
+extern "C" {
+    /// Register the service worker and its periodic sync
+    #[wasm_bindgen(js_namespace = ["window", "reverifyBridge"], js_name = register, catch)]
+    async fn register_periodic_sync(min_interval_ms: f64) -> Result<JsValue, JsValue>;
+
+    /// Invoke `callback` whenever the service worker fires a periodic sync
+    #[wasm_bindgen(js_namespace = ["window", "reverifyBridge"], js_name = onReverify, catch)]
+    fn on_reverify(callback: &Closure<dyn FnMut()>) -> Result<(), JsValue>;
+}
+
+/// Remember which archive scheduled re-verification should check
+pub fn set_reverify_owner(owner: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(REVERIFY_OWNER_KEY, owner);
+    }
+}
+
+/// Archive owner scheduled re-verification checks, if one has been set
+pub fn reverify_owner() -> Option<String> {
+    local_storage()
+        .and_then(|storage| storage.get_item(REVERIFY_OWNER_KEY).ok().flatten())
+        .filter(|owner| !owner.is_empty())
+}
+
+fn last_run() -> Option<i64> {
+    local_storage()
+        .and_then(|storage| storage.get_item(LAST_REVERIFY_KEY).ok().flatten())
+        .and_then(|value| value.parse().ok())
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+}
+
+/// Check a sample of the archive and record the result, unless a run
+/// happened within the last `REVERIFY_INTERVAL_SECS`
+pub async fn run_if_due() {
+    let Some(owner) = reverify_owner() else {
+        return;
+    };
+
+    let now = chrono::Utc::now().timestamp();
+    if last_run().is_some_and(|last| now - last < REVERIFY_INTERVAL_SECS) {
+        return;
+    }
+
+    log::info!("🗓️ Running scheduled re-verification for {}", owner);
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(LAST_REVERIFY_KEY, &now.to_string());
+    }
+
+    match HealthMonitor::new().check_sample(&owner, REVERIFY_SAMPLE_SIZE).await {
+        Ok(report) => {
+            if let Err(e) = record_run(&HealthRun::from_report(&report, true)).await {
+                log::warn!("Failed to record re-verification: {}", e);
+            }
+        }
+        Err(e) => log::warn!("Scheduled re-verification failed: {}", e),
+    }
+}
+
+/// Start scheduled re-verification of the archive
+///
+/// The service worker's periodic sync wakes open tabs; browsers without
+/// periodic sync fall back to a catch-up check on startup.
+pub fn init_reverification_scheduler() {
+    let callback = Closure::<dyn FnMut()>::new(|| {
+        spawn(run_if_due());
+    });
+    if let Err(e) = on_reverify(&callback) {
+        log::warn!("Re-verification bridge unavailable: {:?}", e);
+    }
+    callback.forget();
+
+    spawn(async {
+        let min_interval_ms = (REVERIFY_INTERVAL_SECS * 1000) as f64;
+        if let Err(e) = register_periodic_sync(min_interval_ms).await {
+            log::warn!("Periodic sync not available, using startup checks only: {:?}", e);
+        }
+        run_if_due().await;
+    });
+}
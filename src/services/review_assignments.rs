@@ -0,0 +1,209 @@
+//! Who on the moderation team is reviewing what
+//!
+//! Before working on a rights claim or a withdrawal, a moderator claims it
+//! in the moderation AO process, so the rest of the team can see it's taken
+//! and nobody reviews it twice. Claims are released when the moderator
+//! decides or gives up, and one left untouched for `STALE_CLAIM_SECS` is
+//! treated as abandoned so work can't stay stuck behind someone who's away.
+//!
+//! Assignments only coordinate the team: decisions themselves are still
+//! the signed DataItems in the hide registry and claim acknowledgments.
+
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+use crate::config::app_config;
+use crate::services::ao::{dry_run, send_message, AoError};
+use crate::services::hide_registry::is_moderator;
+use crate::services::wallet::{connected_wallet, WalletError};
+
+/// A claim older than this is stale and can be taken over (seconds)
+pub const STALE_CLAIM_SECS: i64 = 48 * 60 * 60;
+
+#[derive(Debug, Clone)]
+pub enum AssignmentError {
+    /// No moderation process is configured
+    Unavailable,
+    NotModerator,
+    /// Someone else is already reviewing it
+    AlreadyClaimed(String),
+    SigningFailed(WalletError),
+    Ao(AoError),
+    InvalidResponse(String),
+}
+
+impl std::fmt::Display for AssignmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssignmentError::Unavailable => write!(f, "Review assignments aren't set up for this archive"),
+            AssignmentError::NotModerator => write!(f, "Only moderation team wallets can claim reviews"),
+            AssignmentError::AlreadyClaimed(reviewer) => write!(f, "Already being reviewed by {}", reviewer),
+            AssignmentError::SigningFailed(e) => write!(f, "Could not sign the claim: {}", e),
+            AssignmentError::Ao(e) => write!(f, "{}", e),
+            AssignmentError::InvalidResponse(msg) => write!(f, "Unexpected reply from the moderation process: {}", msg),
+        }
+    }
+}
+
+/// A moderator's claim on a queue item
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ReviewAssignment {
+    /// Tracking number of a rights claim, or the ID of a withdrawn item
+    pub subject: String,
+    pub reviewer: String,
+    /// When it was claimed (seconds since the epoch)
+    pub claimed_at: i64,
+}
+
+impl ReviewAssignment {
+    pub fn is_stale(&self, now: i64) -> bool {
+        now - self.claimed_at >= STALE_CLAIM_SECS
+    }
+}
+
+// Assignments as last read from the process, plus claims made here since
+fn use_assignments_state() -> &'static GlobalSignal<Vec<ReviewAssignment>> {
+    static ASSIGNMENTS: GlobalSignal<Vec<ReviewAssignment>> = GlobalSignal::new(Vec::new);
+    &ASSIGNMENTS
+}
+
+fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+fn moderation_process() -> Result<String, AssignmentError> {
+    let process = app_config().ao_moderation_process.clone();
+    if process.is_empty() {
+        return Err(AssignmentError::Unavailable);
+    }
+    Ok(process)
+}
+
+/// Whether review assignments are switched on
+pub fn assignments_enabled() -> bool {
+    !app_config().ao_moderation_process.is_empty()
+}
+
+/// Read the current assignments from the moderation process
+pub async fn refresh_assignments() -> Result<(), AssignmentError> {
+    let process = moderation_process()?;
+    let data = dry_run(&process, &[("Action", "Assignments")])
+        .await
+        .map_err(AssignmentError::Ao)?;
+    let assignments: Vec<ReviewAssignment> =
+        serde_json::from_str(&data).map_err(|e| AssignmentError::InvalidResponse(e.to_string()))?;
+    *use_assignments_state().write() = assignments;
+    Ok(())
+}
+
+/// Claim `subject` for the connected moderator wallet
+///
+/// Fails if another moderator holds a claim on it that isn't stale yet;
+/// the process applies the same rule, so a race between two moderators
+/// still leaves one owner.
+pub async fn claim_review(subject: &str) -> Result<ReviewAssignment, AssignmentError> {
+    let process = moderation_process()?;
+    let reviewer = moderator_address().await?;
+    if let Some(current) = active_assignment(subject) {
+        if current.reviewer != reviewer {
+            return Err(AssignmentError::AlreadyClaimed(current.reviewer));
+        }
+    }
+
+    send_review_message(&process, "Claim-Review", subject).await?;
+    let assignment = ReviewAssignment { subject: subject.to_string(), reviewer, claimed_at: now() };
+    let mut assignments = use_assignments_state().write();
+    assignments.retain(|existing| existing.subject != subject);
+    assignments.push(assignment.clone());
+    Ok(assignment)
+}
+
+/// Give up the connected moderator's claim on `subject`
+pub async fn release_review(subject: &str) -> Result<(), AssignmentError> {
+    let process = moderation_process()?;
+    let reviewer = moderator_address().await?;
+    send_review_message(&process, "Release-Review", subject).await?;
+    use_assignments_state()
+        .write()
+        .retain(|existing| !(existing.subject == subject && existing.reviewer == reviewer));
+    Ok(())
+}
+
+async fn moderator_address() -> Result<String, AssignmentError> {
+    let address = connected_wallet()
+        .await
+        .map_err(AssignmentError::SigningFailed)?
+        .get_active_address()
+        .await
+        .map_err(AssignmentError::SigningFailed)?;
+    if !is_moderator(&address) {
+        return Err(AssignmentError::NotModerator);
+    }
+    Ok(address)
+}
+
+async fn send_review_message(process: &str, action: &str, subject: &str) -> Result<(), AssignmentError> {
+    let tags = vec![
+        ("Action".to_string(), action.to_string()),
+        ("Subject".to_string(), subject.to_string()),
+    ];
+    let message_id = send_message(process, tags).await.map_err(|e| match e {
+        AoError::SigningFailed(e) => AssignmentError::SigningFailed(e),
+        e => AssignmentError::Ao(e),
+    })?;
+    log::info!("🗂️ {} {} ({})", action, subject, message_id);
+    Ok(())
+}
+
+/// The claim on `subject`, unless there is none or it has gone stale
+pub fn active_assignment(subject: &str) -> Option<ReviewAssignment> {
+    let now = now();
+    use_assignments_state()
+        .read()
+        .iter()
+        .find(|assignment| assignment.subject == subject && !assignment.is_stale(now))
+        .cloned()
+}
+
+/// Reviewers with how many items each is holding, busiest first
+pub fn queue_counts(assignments: &[ReviewAssignment], now: i64) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for assignment in assignments.iter().filter(|assignment| !assignment.is_stale(now)) {
+        match counts.iter_mut().find(|(reviewer, _)| *reviewer == assignment.reviewer) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((assignment.reviewer.clone(), 1)),
+        }
+    }
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+/// Hook returning per-reviewer counts of live claims
+pub fn use_queue_counts() -> Memo<Vec<(String, usize)>> {
+    use_memo(move || queue_counts(&use_assignments_state().read(), now()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn stale_claims_drop_out_of_queue_counts() {
+        let now = 1_700_000_000;
+        let claim = |subject: &str, reviewer: &str, age: i64| ReviewAssignment {
+            subject: subject.to_string(),
+            reviewer: reviewer.to_string(),
+            claimed_at: now - age,
+        };
+        let assignments = vec![
+            claim("FA-AAAA0001", "mod-b", 60),
+            claim("FA-AAAA0002", "mod-a", 3600),
+            claim("FA-AAAA0003", "mod-a", 120),
+            claim("FA-AAAA0004", "mod-c", STALE_CLAIM_SECS),
+        ];
+
+        assert!(assignments[3].is_stale(now));
+        assert_eq!(queue_counts(&assignments, now), vec![("mod-a".to_string(), 2), ("mod-b".to_string(), 1)]);
+    }
+}
@@ -0,0 +1,538 @@
+//! Versions, replacement and withdrawal records
+//!
+//! Arweave items can't be edited or deleted, so a corrected upload is a
+//! new item. It can name the item it corrects in a `Previous-Version` tag
+//! when it's uploaded, or the uploader can publish a revision record
+//! afterwards pointing from the old item to the new one (`Replace`). An
+//! item can also be withdrawn outright (`Revoke`). Links and records are
+//! only honoured between items of the same wallet, and for each target the
+//! most recent record wins.
+//!
+//! A withdrawal alone doesn't hide anything: the item stays listed, marked
+//! as withdrawn, until the moderation team agrees and de-lists it through
+//! the hide registry.
+
+use dioxus::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::models::ContentMetadata;
+use crate::services::bundler::{BundlerClient, BundlerError};
+use crate::services::graphql::{GraphQLClient, GraphQLError, TransactionSummary};
+use crate::services::upload::{signing_wallet, PublishError};
+use crate::services::wallet::WalletError;
+use crate::utils::constants::APP_NAME;
+
+/// `Type` tag of revision record DataItems
+const REVISION_RECORD_TYPE: &str = "Revision-Record";
+
+/// Versions walked in each direction before a chain is cut short
+const MAX_VERSION_CHAIN: usize = 50;
+
+/// What a record does to its target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevisionAction {
+    /// A newer version replaces the target
+    Replace,
+    /// The uploader withdraws the target
+    Revoke,
+}
+
+impl RevisionAction {
+    pub fn tag_value(&self) -> &'static str {
+        match self {
+            RevisionAction::Replace => "Replace",
+            RevisionAction::Revoke => "Revoke",
+        }
+    }
+}
+
+/// An uploader's signed statement that one of their items was replaced
+/// or withdrawn
+#[derive(Debug, Clone, PartialEq)]
+pub struct RevisionRecord {
+    pub target_id: String,
+    pub action: RevisionAction,
+    /// The newer version, for `Replace`
+    pub replacement_id: Option<String>,
+    pub reason: String,
+    pub owner: String,
+    pub record_tx_id: String,
+    /// Block timestamp; `None` while still pending
+    pub timestamp: Option<i64>,
+}
+
+/// One version of an item in its history
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemVersion {
+    pub tx_id: String,
+    pub title: Option<String>,
+    /// Block timestamp; `None` while still pending
+    pub timestamp: Option<i64>,
+    /// The record that replaced or withdrew this version, if any
+    pub record: Option<RevisionRecord>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RevisionError {
+    LookupFailed(GraphQLError),
+    NotFound,
+    NotOwner,
+    InvalidReplacement(String),
+    /// No usable wallet, or one on the wrong network
+    Wallet(PublishError),
+    SigningFailed(WalletError),
+    SubmitFailed(BundlerError),
+}
+
+impl std::fmt::Display for RevisionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RevisionError::LookupFailed(e) => write!(f, "Could not load revision records: {}", e),
+            RevisionError::NotFound => write!(f, "Item not found"),
+            RevisionError::NotOwner => write!(f, "Only the wallet that uploaded an item can replace or withdraw it"),
+            RevisionError::InvalidReplacement(msg) => write!(f, "{}", msg),
+            RevisionError::Wallet(e) => write!(f, "{}", e),
+            RevisionError::SigningFailed(e) => write!(f, "Could not sign the revision record: {}", e),
+            RevisionError::SubmitFailed(e) => write!(f, "Could not publish the revision record: {}", e),
+        }
+    }
+}
+
+// Global revision records using Dioxus signals
+fn use_revision_records_state() -> &'static GlobalSignal<Vec<RevisionRecord>> {
+    static REVISION_RECORDS: GlobalSignal<Vec<RevisionRecord>> = GlobalSignal::new(Vec::new);
+    &REVISION_RECORDS
+}
+
+/// Client for uploaders' replacement and withdrawal records
+pub struct RevisionRegistry {
+    graphql: GraphQLClient,
+}
+
+impl RevisionRegistry {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+        }
+    }
+
+    /// Load every record signed by its target's uploader, oldest first
+    pub async fn load(&self) -> Result<Vec<RevisionRecord>, RevisionError> {
+        let transactions = self.graphql
+            .find_transactions(&[], &[("Type", &[REVISION_RECORD_TYPE])])
+            .await
+            .map_err(RevisionError::LookupFailed)?;
+
+        let candidates: Vec<RevisionRecord> = transactions
+            .into_iter()
+            .filter_map(|tx| {
+                let action = match tx.tag("Action")? {
+                    "Replace" => RevisionAction::Replace,
+                    "Revoke" => RevisionAction::Revoke,
+                    _ => return None,
+                };
+                let replacement_id = tx.tag("Replacement-Id").map(str::to_string);
+                if action == RevisionAction::Replace && replacement_id.is_none() {
+                    return None;
+                }
+
+                Some(RevisionRecord {
+                    target_id: tx.tag("Target-Id")?.to_string(),
+                    action,
+                    replacement_id,
+                    reason: tx.tag("Reason").unwrap_or_default().to_string(),
+                    owner: tx.owner.clone(),
+                    record_tx_id: tx.id.clone(),
+                    timestamp: tx.timestamp,
+                })
+            })
+            .collect();
+        if candidates.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Anyone can publish a record; only the uploader's count
+        let mut ids: Vec<&str> = candidates
+            .iter()
+            .flat_map(|record| std::iter::once(record.target_id.as_str()).chain(record.replacement_id.as_deref()))
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        let owners: HashMap<String, String> = self.graphql
+            .transactions_by_ids(&ids)
+            .await
+            .map_err(RevisionError::LookupFailed)?
+            .into_iter()
+            .map(|tx| (tx.id, tx.owner))
+            .collect();
+
+        let mut records = trusted_records(candidates, &owners);
+        // Pending records are the newest
+        records.sort_by_key(|record| record.timestamp.unwrap_or(i64::MAX));
+        Ok(records)
+    }
+
+    /// Reload records into the global state
+    pub async fn refresh(&self) -> Result<(), RevisionError> {
+        let records = self.load().await?;
+        log::info!("🔁 Loaded {} revision records", records.len());
+        *use_revision_records_state().write() = records;
+        Ok(())
+    }
+
+    /// Point `target_id` at its newer version `replacement_id`; both must
+    /// be the connected wallet's items
+    pub async fn replace(&self, target_id: &str, replacement_id: &str, reason: &str) -> Result<RevisionRecord, RevisionError> {
+        let replacement_id = replacement_id.trim();
+        if replacement_id == target_id {
+            return Err(RevisionError::InvalidReplacement("An item can't replace itself".to_string()));
+        }
+        if record_chain(&use_revision_records_state().peek(), target_id).iter().any(|tx_id| tx_id == replacement_id) {
+            return Err(RevisionError::InvalidReplacement("That item is already a version of this one".to_string()));
+        }
+        self.publish(target_id, RevisionAction::Replace, Some(replacement_id), reason).await
+    }
+
+    /// Withdraw `target_id`, one of the connected wallet's items
+    pub async fn revoke(&self, target_id: &str, reason: &str) -> Result<RevisionRecord, RevisionError> {
+        self.publish(target_id, RevisionAction::Revoke, None, reason).await
+    }
+
+    async fn publish(
+        &self,
+        target_id: &str,
+        action: RevisionAction,
+        replacement_id: Option<&str>,
+        reason: &str,
+    ) -> Result<RevisionRecord, RevisionError> {
+        let (wallet, owner) = signing_wallet().await.map_err(RevisionError::Wallet)?;
+
+        let ids: Vec<&str> = std::iter::once(target_id).chain(replacement_id).collect();
+        let items = self.graphql
+            .transactions_by_ids(&ids)
+            .await
+            .map_err(RevisionError::LookupFailed)?;
+        let target = items.iter().find(|tx| tx.id == target_id).ok_or(RevisionError::NotFound)?;
+        if target.owner != owner {
+            return Err(RevisionError::NotOwner);
+        }
+        if let Some(replacement_id) = replacement_id {
+            let replacement = items
+                .iter()
+                .find(|tx| tx.id == replacement_id)
+                .ok_or_else(|| RevisionError::InvalidReplacement("The new version wasn't found; it may not be indexed yet".to_string()))?;
+            if replacement.owner != owner {
+                return Err(RevisionError::InvalidReplacement("The new version must be uploaded from the same wallet".to_string()));
+            }
+        }
+
+        let mut tags = vec![
+            ("App-Name".to_string(), APP_NAME.to_string()),
+            ("Type".to_string(), REVISION_RECORD_TYPE.to_string()),
+            ("Target-Id".to_string(), target_id.to_string()),
+            ("Action".to_string(), action.tag_value().to_string()),
+            ("Reason".to_string(), reason.to_string()),
+        ];
+        if let Some(replacement_id) = replacement_id {
+            tags.push(("Replacement-Id".to_string(), replacement_id.to_string()));
+        }
+
+        let signed = wallet
+            .sign_data_item(reason.as_bytes().to_vec(), tags)
+            .await
+            .map_err(RevisionError::SigningFailed)?;
+        let record_tx_id = BundlerClient::new()
+            .submit(signed)
+            .await
+            .map_err(RevisionError::SubmitFailed)?;
+
+        let record = RevisionRecord {
+            target_id: target_id.to_string(),
+            action,
+            replacement_id: replacement_id.map(str::to_string),
+            reason: reason.to_string(),
+            owner,
+            record_tx_id,
+            timestamp: None,
+        };
+        use_revision_records_state().write().push(record.clone());
+        Ok(record)
+    }
+}
+
+impl Default for RevisionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Those of `records` signed by the uploader of their target, with a
+/// replacement from the same uploader. `owners` maps item IDs to uploaders.
+fn trusted_records(records: Vec<RevisionRecord>, owners: &HashMap<String, String>) -> Vec<RevisionRecord> {
+    records
+        .into_iter()
+        .filter(|record| owners.get(&record.target_id) == Some(&record.owner))
+        .filter(|record| match &record.replacement_id {
+            Some(replacement_id) => owners.get(replacement_id) == Some(&record.owner),
+            None => true,
+        })
+        .collect()
+}
+
+/// The record in effect for each target: its latest
+fn latest_records(records: &[RevisionRecord]) -> HashMap<&str, &RevisionRecord> {
+    let mut latest = HashMap::new();
+    for record in records {
+        latest.insert(record.target_id.as_str(), record);
+    }
+    latest
+}
+
+/// Every version of an item the records link it to, oldest first
+fn record_chain(records: &[RevisionRecord], tx_id: &str) -> Vec<String> {
+    let latest = latest_records(records);
+    let replaced_by: HashMap<&str, &str> = latest
+        .values()
+        .filter_map(|record| Some((record.replacement_id.as_deref()?, record.target_id.as_str())))
+        .collect();
+
+    // Back to the first version; the seen sets stop malformed cycles
+    let mut seen = HashSet::from([tx_id]);
+    let mut first = tx_id;
+    while let Some(previous) = replaced_by.get(first).copied().filter(|previous| seen.insert(previous)) {
+        first = previous;
+    }
+
+    let mut chain = Vec::new();
+    let mut seen = HashSet::new();
+    let mut current = Some(first);
+    while let Some(tx_id) = current.filter(|tx_id| seen.insert(*tx_id)) {
+        current = latest.get(tx_id).and_then(|record| record.replacement_id.as_deref());
+        chain.push(tx_id.to_string());
+    }
+    chain
+}
+
+async fn lookup(graphql: &GraphQLClient, tx_id: &str) -> Result<Option<TransactionSummary>, RevisionError> {
+    let found = graphql
+        .transactions_by_ids(&[tx_id])
+        .await
+        .map_err(RevisionError::LookupFailed)?;
+    Ok(found.into_iter().find(|tx| tx.id == tx_id))
+}
+
+/// Every version of an item, oldest first, following `Previous-Version`
+/// tags and `records` between items of the same uploader
+pub async fn version_chain(records: &[RevisionRecord], tx_id: &str) -> Result<Vec<ItemVersion>, RevisionError> {
+    let graphql = GraphQLClient::cached();
+    let latest = latest_records(records);
+    let replaced_by: HashMap<&str, &str> = latest
+        .values()
+        .filter_map(|record| Some((record.replacement_id.as_deref()?, record.target_id.as_str())))
+        .collect();
+
+    let start = lookup(&graphql, tx_id).await?.ok_or(RevisionError::NotFound)?;
+    let owner = start.owner.clone();
+    let mut seen = HashSet::from([start.id.clone()]);
+
+    // Earlier versions name theirs in a tag, or were replaced by a record
+    let mut earlier = Vec::new();
+    let mut current = start.clone();
+    while earlier.len() < MAX_VERSION_CHAIN {
+        let Some(previous_id) = current.tag("Previous-Version").or_else(|| replaced_by.get(current.id.as_str()).copied()) else {
+            break;
+        };
+        if seen.contains(previous_id) {
+            break;
+        }
+        let Some(previous) = lookup(&graphql, previous_id).await?.filter(|tx| tx.owner == owner) else {
+            break;
+        };
+        seen.insert(previous.id.clone());
+        earlier.push(previous.clone());
+        current = previous;
+    }
+
+    // A record decides what comes next; without one, the newest upload
+    // naming this version as its previous one
+    let mut later = Vec::new();
+    let mut current = start.clone();
+    while later.len() < MAX_VERSION_CHAIN {
+        let next = match latest.get(current.id.as_str()) {
+            Some(record) => match record.replacement_id.as_deref() {
+                Some(replacement_id) => lookup(&graphql, replacement_id).await?,
+                None => break,
+            },
+            None => graphql
+                .find_transactions(&[&owner], &[("Previous-Version", &[current.id.as_str()])])
+                .await
+                .map_err(RevisionError::LookupFailed)?
+                .into_iter()
+                .max_by_key(|tx| tx.timestamp.unwrap_or(i64::MAX)),
+        };
+        let Some(next) = next.filter(|tx| tx.owner == owner && !seen.contains(&tx.id)) else {
+            break;
+        };
+        seen.insert(next.id.clone());
+        later.push(next.clone());
+        current = next;
+    }
+
+    earlier.reverse();
+    Ok(earlier
+        .into_iter()
+        .chain(std::iter::once(start))
+        .chain(later)
+        .map(|tx| ItemVersion {
+            title: tx.tag("Title").map(str::to_string),
+            timestamp: tx.timestamp,
+            record: latest.get(tx.id.as_str()).map(|record| (*record).clone()),
+            tx_id: tx.id,
+        })
+        .collect())
+}
+
+/// Newest version of an item when a newer one has replaced it
+fn newest_version(records: &[RevisionRecord], tx_id: &str) -> Option<String> {
+    let chain = record_chain(records, tx_id);
+    let newest = chain.last()?;
+    (newest != tx_id).then(|| newest.clone())
+}
+
+/// Newest version of an item when a newer one has replaced it
+pub fn superseded_by(tx_id: &str) -> Option<String> {
+    newest_version(&use_revision_records_state().read(), tx_id)
+}
+
+/// The uploader's withdrawal of an item, if that's its latest record
+pub fn revocation_of(tx_id: &str) -> Option<RevisionRecord> {
+    latest_records(&use_revision_records_state().read())
+        .get(tx_id)
+        .filter(|record| record.action == RevisionAction::Revoke)
+        .map(|record| (*record).clone())
+}
+
+/// Every loaded record, oldest first; reading it in a component or
+/// resource re-runs that when records are refreshed
+pub fn revision_records() -> Vec<RevisionRecord> {
+    use_revision_records_state().read().clone()
+}
+
+/// `items` without those that a newer item among them, or a record, has
+/// replaced, so each item is listed once at its newest version
+pub fn latest_versions_only(items: Vec<ContentMetadata>) -> Vec<ContentMetadata> {
+    collapse_versions(&use_revision_records_state().read(), items)
+}
+
+fn collapse_versions(records: &[RevisionRecord], items: Vec<ContentMetadata>) -> Vec<ContentMetadata> {
+    let replaced: HashSet<(String, String)> = items
+        .iter()
+        .filter_map(|item| Some((item.previous_version.clone()?, item.owner.clone())))
+        .collect();
+    items
+        .into_iter()
+        .filter(|item| !replaced.contains(&(item.tx_id.clone(), item.owner.clone())))
+        .filter(|item| newest_version(records, &item.tx_id).is_none())
+        .collect()
+}
+
+/// Hook returning the items their uploaders have withdrawn, newest first
+pub fn use_revocations() -> Memo<Vec<RevisionRecord>> {
+    use_memo(move || {
+        let records = use_revision_records_state().read();
+        let mut revoked: Vec<RevisionRecord> = latest_records(&records)
+            .into_values()
+            .filter(|record| record.action == RevisionAction::Revoke)
+            .cloned()
+            .collect();
+        revoked.sort_by_key(|record| std::cmp::Reverse(record.timestamp.unwrap_or(i64::MAX)));
+        revoked
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn record(target: &str, action: RevisionAction, replacement: Option<&str>, owner: &str, timestamp: i64) -> RevisionRecord {
+        RevisionRecord {
+            target_id: target.to_string(),
+            action,
+            replacement_id: replacement.map(str::to_string),
+            reason: String::new(),
+            owner: owner.to_string(),
+            record_tx_id: format!("record-{}-{}", target, timestamp),
+            timestamp: Some(timestamp),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn only_the_uploaders_records_count() {
+        let owners = HashMap::from([
+            ("a".to_string(), "alice".to_string()),
+            ("b".to_string(), "alice".to_string()),
+            ("c".to_string(), "mallory".to_string()),
+        ]);
+        let records = vec![
+            record("a", RevisionAction::Replace, Some("b"), "alice", 1),
+            record("a", RevisionAction::Revoke, None, "mallory", 2),
+            record("a", RevisionAction::Replace, Some("c"), "alice", 3),
+        ];
+
+        let trusted = trusted_records(records, &owners);
+        assert_eq!(trusted.len(), 1);
+        assert_eq!(trusted[0].replacement_id.as_deref(), Some("b"));
+    }
+
+    #[wasm_bindgen_test]
+    fn history_follows_replacements_both_ways() {
+        let records = vec![
+            record("a", RevisionAction::Replace, Some("b"), "alice", 1),
+            record("b", RevisionAction::Replace, Some("c"), "alice", 2),
+        ];
+
+        assert_eq!(record_chain(&records, "b"), ["a", "b", "c"]);
+        assert_eq!(newest_version(&records, "a").as_deref(), Some("c"));
+        assert_eq!(newest_version(&records, "c"), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn cycles_and_later_records_are_handled() {
+        // A replacement withdrawn later isn't a replacement any more
+        let records = vec![
+            record("a", RevisionAction::Replace, Some("b"), "alice", 1),
+            record("a", RevisionAction::Revoke, None, "alice", 2),
+        ];
+        assert_eq!(newest_version(&records, "a"), None);
+
+        let cycle = vec![
+            record("a", RevisionAction::Replace, Some("b"), "alice", 1),
+            record("b", RevisionAction::Replace, Some("a"), "alice", 2),
+        ];
+        assert_eq!(record_chain(&cycle, "a").len(), 2);
+    }
+
+    #[wasm_bindgen_test]
+    fn listings_keep_only_the_newest_version() {
+        let item = |tx_id: &str, owner: &str, previous: Option<&str>| {
+            let mut tags = vec![("Title".to_string(), tx_id.to_string())];
+            if let Some(previous) = previous {
+                tags.push(("Previous-Version".to_string(), previous.to_string()));
+            }
+            ContentMetadata::from_tags(tx_id, owner, &tags, None, None).unwrap()
+        };
+        let items = vec![
+            item("a", "alice", None),
+            item("b", "alice", Some("a")),
+            // Someone else's upload can't claim to replace alice's
+            item("c", "alice", None),
+            item("d", "mallory", Some("c")),
+            item("e", "alice", None),
+        ];
+        let records = vec![record("e", RevisionAction::Replace, Some("b"), "alice", 1)];
+
+        let listed: Vec<String> = collapse_versions(&records, items).into_iter().map(|item| item.tx_id).collect();
+        assert_eq!(listed, ["b", "c", "d"]);
+    }
+}
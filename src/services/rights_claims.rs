@@ -0,0 +1,428 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use crate::models::{ClaimAcknowledgment, ClaimReceipt, ClaimStatus, ReviewChecklist, RightsClaim};
+use crate::services::arweave::ArweaveService;
+use crate::services::bundler::{BundlerClient, BundlerError};
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, GraphQLError, TransactionSummary};
+use crate::services::hide_registry::{publish_as_moderator, HideAction, HideRegistry, HideRegistryError};
+use crate::utils::constants::MODERATOR_ADDRESSES;
+use crate::utils::crypto::{encrypt_for_recipient, sha256_hex, CryptoError};
+
+/// `Type` tag of encrypted claim DataItems
+const CLAIM_TYPE: &str = "Rights-Claim";
+
+/// `Type` tag of moderator acknowledgments and decisions
+const ACKNOWLEDGMENT_TYPE: &str = "Claim-Acknowledgment";
+
+/// `Type` tag of the moderation team's published RSA public key (JWK)
+const MODERATION_KEY_TYPE: &str = "Moderation-Key";
+
+/// localStorage key for receipts of claims submitted from this browser
+const CLAIM_RECEIPTS_KEY: &str = "faithful_archive_claim_receipts";
+
+#[derive(Debug, Clone)]
+pub enum RightsClaimError {
+    Invalid(String),
+    NoModerationKey,
+    LookupFailed(GraphQLError),
+    EncryptionFailed(CryptoError),
+    SigningFailed(String),
+    SubmitFailed(BundlerError),
+    NotFound,
+    Moderation(HideRegistryError),
+}
+
+impl std::fmt::Display for RightsClaimError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RightsClaimError::Invalid(msg) => write!(f, "{}", msg),
+            RightsClaimError::NoModerationKey => write!(f, "The moderation team's encryption key could not be found"),
+            RightsClaimError::LookupFailed(e) => write!(f, "Claim lookup failed: {}", e),
+            RightsClaimError::EncryptionFailed(e) => write!(f, "Could not encrypt claim: {}", e),
+            RightsClaimError::SigningFailed(msg) => write!(f, "Could not sign claim: {}", msg),
+            RightsClaimError::SubmitFailed(e) => write!(f, "Could not submit claim: {}", e),
+            RightsClaimError::NotFound => write!(f, "No claim found with that tracking number"),
+            RightsClaimError::Moderation(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<HideRegistryError> for RightsClaimError {
+    fn from(error: HideRegistryError) -> Self {
+        RightsClaimError::Moderation(error)
+    }
+}
+
+/// A published claim as seen by moderators (details stay encrypted)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClaimSummary {
+    pub tracking_number: String,
+    pub target_tx_id: String,
+    pub claim_tx_id: String,
+    pub submitted_at: Option<i64>,
+    pub status: ClaimStatus,
+}
+
+/// Current status of a claim plus every signed acknowledgment, oldest first
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClaimTracking {
+    pub summary: ClaimSummary,
+    pub acknowledgments: Vec<ClaimAcknowledgment>,
+}
+
+/// Rights-claim (DMCA) intake and review
+///
+/// Claimants publish a claim encrypted to the moderation team, signed by an
+/// ephemeral key so no wallet is needed. Moderators answer with signed
+/// acknowledgments; validating a claim also de-lists the item through the
+/// hide registry, referencing the claim's tracking number.
+pub struct RightsClaimService {
+    graphql: GraphQLClient,
+}
+
+impl RightsClaimService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+        }
+    }
+
+    /// Latest RSA public key (JWK) published by the moderation team
+    async fn moderation_key(&self) -> Result<String, RightsClaimError> {
+        if MODERATOR_ADDRESSES.is_empty() {
+            return Err(RightsClaimError::NoModerationKey);
+        }
+
+        let keys = self.graphql
+            .find_transactions(MODERATOR_ADDRESSES, &[("Type", &[MODERATION_KEY_TYPE])])
+            .await
+            .map_err(RightsClaimError::LookupFailed)?;
+        let latest = keys
+            .into_iter()
+            .max_by_key(|tx| tx.timestamp.unwrap_or(i64::MAX))
+            .ok_or(RightsClaimError::NoModerationKey)?;
+
+        let response = GatewayManager::new()
+            .get(&format!("/{}", latest.id))
+            .await
+            .map_err(|_| RightsClaimError::NoModerationKey)?;
+        response.text().await.map_err(|_| RightsClaimError::NoModerationKey)
+    }
+
+    /// Encrypt and publish a claim, returning the claimant's receipt
+    pub async fn submit(&self, claim: &RightsClaim) -> Result<ClaimReceipt, RightsClaimError> {
+        claim.validate().map_err(RightsClaimError::Invalid)?;
+
+        let plaintext = serde_json::to_vec(claim)
+            .map_err(|e| RightsClaimError::Invalid(e.to_string()))?;
+        let key = self.moderation_key().await?;
+        let envelope = encrypt_for_recipient(&plaintext, &key)
+            .await
+            .map_err(RightsClaimError::EncryptionFailed)?;
+        let claim_hash = sha256_hex(&plaintext);
+
+        let service = ArweaveService::new_random()
+            .map_err(|e| RightsClaimError::SigningFailed(e.to_string()))?;
+        let item = service
+            .create_tagged_item(
+                &[
+                    ("Content-Type", "application/json"),
+                    ("Type", CLAIM_TYPE),
+                    ("Tracking-Number", &claim.tracking_number),
+                    ("Target-Id", claim.target_tx_id.trim()),
+                    ("Claim-Hash", &claim_hash),
+                ],
+                envelope.into_bytes(),
+            )
+            .map_err(|e| RightsClaimError::SigningFailed(e.to_string()))?;
+        let bytes = service
+            .serialize_item(&item)
+            .map_err(|e| RightsClaimError::SigningFailed(e.to_string()))?;
+
+        let claim_tx_id = BundlerClient::new()
+            .submit(bytes)
+            .await
+            .map_err(RightsClaimError::SubmitFailed)?;
+
+        log::info!("⚖️ Submitted rights claim {}", claim.tracking_number);
+
+        let receipt = ClaimReceipt {
+            tracking_number: claim.tracking_number.clone(),
+            claim_tx_id,
+            submitted_at: claim.submitted_at,
+        };
+        save_receipt(&receipt);
+        Ok(receipt)
+    }
+
+    /// Look up a claim and its acknowledgments by tracking number
+    pub async fn track(&self, tracking_number: &str) -> Result<ClaimTracking, RightsClaimError> {
+        let tracking_number = tracking_number.trim().to_uppercase();
+        let claims = self.graphql
+            .find_transactions(&[], &[("Type", &[CLAIM_TYPE]), ("Tracking-Number", &[tracking_number.as_str()])])
+            .await
+            .map_err(RightsClaimError::LookupFailed)?;
+        let claim = first_filed(claims).into_iter().next().ok_or(RightsClaimError::NotFound)?;
+
+        let acknowledgments: Vec<ClaimAcknowledgment> = self
+            .acknowledgments(&[tracking_number.as_str()])
+            .await?
+            .into_iter()
+            .filter(|ack| answers(ack, &claim))
+            .collect();
+        let summary = summarize(&claim, &acknowledgments).ok_or(RightsClaimError::NotFound)?;
+
+        Ok(ClaimTracking { summary, acknowledgments })
+    }
+
+    /// Every published claim with its current status, newest first
+    pub async fn list_claims(&self) -> Result<Vec<ClaimSummary>, RightsClaimError> {
+        let claims = self.graphql
+            .find_transactions(&[], &[("Type", &[CLAIM_TYPE])])
+            .await
+            .map_err(RightsClaimError::LookupFailed)?;
+        let acknowledgments = self.acknowledgments(&[]).await?;
+
+        let mut summaries: Vec<ClaimSummary> = first_filed(claims)
+            .iter()
+            .filter_map(|claim| summarize(claim, &acknowledgments))
+            .collect();
+        summaries.sort_by_key(|summary| std::cmp::Reverse(summary.submitted_at.unwrap_or(i64::MAX)));
+        Ok(summaries)
+    }
+
+    /// Every claim filed against an item, with its acknowledgments
+    pub async fn claims_for_item(&self, target_tx_id: &str) -> Result<Vec<ClaimTracking>, RightsClaimError> {
+        let claims = self.graphql
+            .find_transactions(&[], &[("Type", &[CLAIM_TYPE]), ("Target-Id", &[target_tx_id])])
+            .await
+            .map_err(RightsClaimError::LookupFailed)?;
+        let claims = first_filed(claims);
+
+        let tracking_numbers: Vec<&str> = claims.iter().filter_map(|claim| claim.tag("Tracking-Number")).collect();
+        if tracking_numbers.is_empty() {
+            return Ok(Vec::new());
+        }
+        let acknowledgments = self.acknowledgments(&tracking_numbers).await?;
+
+        Ok(claims
+            .iter()
+            .filter_map(|claim| {
+                let summary = summarize(claim, &acknowledgments)?;
+                let acknowledgments = acknowledgments
+                    .iter()
+                    .filter(|ack| answers(ack, claim))
+                    .cloned()
+                    .collect();
+                Some(ClaimTracking { summary, acknowledgments })
+            })
+            .collect())
+    }
+
+    /// Moderator acknowledgments for the given tracking numbers (all if empty)
+    pub async fn acknowledgments(&self, tracking_numbers: &[&str]) -> Result<Vec<ClaimAcknowledgment>, RightsClaimError> {
+        if MODERATOR_ADDRESSES.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut filters: Vec<(&str, &[&str])> = vec![("Type", &[ACKNOWLEDGMENT_TYPE])];
+        if !tracking_numbers.is_empty() {
+            filters.push(("Tracking-Number", tracking_numbers));
+        }
+
+        let transactions = self.graphql
+            .find_transactions(MODERATOR_ADDRESSES, &filters)
+            .await
+            .map_err(RightsClaimError::LookupFailed)?;
+
+        let mut acknowledgments: Vec<ClaimAcknowledgment> = transactions
+            .into_iter()
+            .filter_map(|tx| {
+                Some(ClaimAcknowledgment {
+                    tracking_number: tx.tag("Tracking-Number")?.to_string(),
+                    claim_tx_id: tx.tag("Claim-Tx").map(str::to_string),
+                    status: ClaimStatus::from_tag_value(tx.tag("Decision")?)?,
+                    moderator: tx.owner.clone(),
+                    note: tx.tag("Reason").unwrap_or_default().to_string(),
+                    review: ReviewChecklist::from_tags(&tx.tags),
+                    tx_id: tx.id.clone(),
+                    timestamp: tx.timestamp,
+                })
+            })
+            .collect();
+        acknowledgments.sort_by_key(|ack| ack.timestamp.unwrap_or(i64::MAX));
+        Ok(acknowledgments)
+    }
+
+    /// Publish a signed acknowledgment or decision (moderators only), with
+    /// the guideline scores behind it if there are any
+    pub async fn acknowledge(
+        &self,
+        claim: &ClaimSummary,
+        status: ClaimStatus,
+        note: &str,
+        review: Option<&ReviewChecklist>,
+    ) -> Result<ClaimAcknowledgment, RightsClaimError> {
+        let mut tags = vec![
+            ("Type".to_string(), ACKNOWLEDGMENT_TYPE.to_string()),
+            ("Tracking-Number".to_string(), claim.tracking_number.clone()),
+            ("Claim-Tx".to_string(), claim.claim_tx_id.clone()),
+            ("Decision".to_string(), status.tag_value().to_string()),
+            ("Reason".to_string(), note.to_string()),
+        ];
+        if let Some(review) = review {
+            tags.extend(review.to_tags());
+        }
+        let (moderator, tx_id) = publish_as_moderator(note.as_bytes().to_vec(), tags).await?;
+
+        Ok(ClaimAcknowledgment {
+            tracking_number: claim.tracking_number.clone(),
+            claim_tx_id: Some(claim.claim_tx_id.clone()),
+            status,
+            moderator,
+            note: note.to_string(),
+            review: review.cloned(),
+            tx_id,
+            timestamp: None,
+        })
+    }
+
+    /// Uphold a claim: record the decision and de-list the item
+    pub async fn validate(&self, claim: &ClaimSummary, note: &str, review: &ReviewChecklist) -> Result<(), RightsClaimError> {
+        self.acknowledge(claim, ClaimStatus::Validated, note, Some(review)).await?;
+        HideRegistry::new()
+            .publish(
+                &claim.target_tx_id,
+                HideAction::Hide,
+                &format!("Rights claim upheld: {}", note),
+                Some(&claim.tracking_number),
+                Some(review),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+impl Default for RightsClaimService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// When a claim was filed: mined claims by block height, pending ones
+/// last, ties broken by ID
+fn filing_order(claim: &TransactionSummary) -> (u64, &str) {
+    (claim.block_height.unwrap_or(u64::MAX), claim.id.as_str())
+}
+
+/// The first claim filed under each tracking number, so a later item
+/// reusing the number can't take over its status
+fn first_filed(mut claims: Vec<TransactionSummary>) -> Vec<TransactionSummary> {
+    claims.sort_by(|a, b| filing_order(a).cmp(&filing_order(b)));
+    let mut seen = HashSet::new();
+    claims.retain(|claim| claim.tag("Tracking-Number").is_some_and(|number| seen.insert(number.to_string())));
+    claims
+}
+
+/// Whether `ack` answers `claim`; entries without a `Claim-Tx` tag only
+/// name the tracking number
+fn answers(ack: &ClaimAcknowledgment, claim: &TransactionSummary) -> bool {
+    claim.tag("Tracking-Number") == Some(ack.tracking_number.as_str())
+        && ack.claim_tx_id.as_ref().is_none_or(|claim_tx_id| *claim_tx_id == claim.id)
+}
+
+/// Combine a claim transaction with its latest acknowledgment
+fn summarize(claim: &TransactionSummary, acknowledgments: &[ClaimAcknowledgment]) -> Option<ClaimSummary> {
+    let tracking_number = claim.tag("Tracking-Number")?.to_string();
+    let status = acknowledgments
+        .iter()
+        .rev()
+        .find(|ack| answers(ack, claim))
+        .map(|ack| ack.status)
+        .unwrap_or(ClaimStatus::Submitted);
+
+    Some(ClaimSummary {
+        target_tx_id: claim.tag("Target-Id")?.to_string(),
+        claim_tx_id: claim.id.clone(),
+        submitted_at: claim.timestamp,
+        tracking_number,
+        status,
+    })
+}
+
+/// Receipts of claims submitted from this browser
+pub fn saved_receipts() -> Vec<ClaimReceipt> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(CLAIM_RECEIPTS_KEY).ok().flatten())
+        .and_then(|stored| serde_json::from_str(&stored).ok())
+        .unwrap_or_default()
+}
+
+fn save_receipt(receipt: &ClaimReceipt) {
+    let mut receipts = saved_receipts();
+    receipts.push(receipt.clone());
+
+    if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+        if let Ok(serialized) = serde_json::to_string(&receipts) {
+            let _ = storage.set_item(CLAIM_RECEIPTS_KEY, &serialized);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn claim(id: &str, block_height: Option<u64>) -> TransactionSummary {
+        TransactionSummary {
+            id: id.to_string(),
+            owner: format!("{}-signer", id),
+            tags: vec![
+                ("Type".to_string(), CLAIM_TYPE.to_string()),
+                ("Tracking-Number".to_string(), "FA-1234ABCD".to_string()),
+                ("Target-Id".to_string(), "sermon".to_string()),
+            ],
+            block_height,
+            timestamp: None,
+        }
+    }
+
+    fn acknowledgment(claim_tx_id: Option<&str>, status: ClaimStatus) -> ClaimAcknowledgment {
+        ClaimAcknowledgment {
+            tracking_number: "FA-1234ABCD".to_string(),
+            claim_tx_id: claim_tx_id.map(str::to_string),
+            status,
+            moderator: "moderator".to_string(),
+            note: String::new(),
+            review: None,
+            tx_id: format!("ack-{:?}", status),
+            timestamp: None,
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn a_reused_tracking_number_keeps_the_first_claim() {
+        let first = claim("b-first", Some(100));
+        let copy = claim("a-copy", Some(120));
+        let pending = claim("0-pending", None);
+        let tied = claim("c-tied", Some(100));
+
+        let kept = first_filed(vec![pending, copy.clone(), tied, first.clone()]);
+        assert_eq!(kept, vec![first.clone()]);
+
+        let acknowledgments = vec![
+            acknowledgment(Some("b-first"), ClaimStatus::UnderReview),
+            acknowledgment(Some("a-copy"), ClaimStatus::Validated),
+        ];
+        assert_eq!(summarize(&first, &acknowledgments).unwrap().status, ClaimStatus::UnderReview);
+        assert_eq!(summarize(&copy, &acknowledgments).unwrap().status, ClaimStatus::Validated);
+
+        let untagged = vec![acknowledgment(None, ClaimStatus::Rejected)];
+        assert_eq!(summarize(&first, &untagged).unwrap().status, ClaimStatus::Rejected);
+    }
+}
@@ -0,0 +1,376 @@
+//! Content-safety scanning before upload
+//!
+//! Every file in an upload goes through each `ScanProvider` before the
+//! wallet is asked to sign, since nothing can be taken back once it is on
+//! Arweave. The built-in `HeuristicScanner` catches files whose bytes don't
+//! match their declared type and documents that can run script; setting
+//! `scan_api_url` adds an `HttpScanner` for a malware-scanning service.
+//! Blocking findings stop the upload; warnings need the uploader's
+//! confirmation.
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::config::app_config;
+
+/// How serious a finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScanSeverity {
+    /// Worth a second look; the uploader may continue
+    Warning,
+    /// The file must not be published
+    Block,
+}
+
+/// Something a scanner flagged in one file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanFinding {
+    pub severity: ScanSeverity,
+    /// Which file, e.g. "Cover image"
+    pub file: String,
+    pub message: String,
+    /// Name of the provider that raised it
+    pub provider: &'static str,
+}
+
+impl std::fmt::Display for ScanFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.file, self.message)
+    }
+}
+
+/// A file to scan
+#[derive(Debug, Clone, Copy)]
+pub struct ScanFile<'a> {
+    /// Shown in findings, e.g. "Main file"
+    pub label: &'a str,
+    /// MIME type the file will be published as
+    pub content_type: &'a str,
+    pub data: &'a [u8],
+}
+
+#[derive(Debug, Clone)]
+pub enum ScanError {
+    Unavailable(String),
+    InvalidResponse(String),
+}
+
+impl std::fmt::Display for ScanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScanError::Unavailable(msg) => write!(f, "Scanner unavailable: {}", msg),
+            ScanError::InvalidResponse(msg) => write!(f, "Scanner gave an invalid response: {}", msg),
+        }
+    }
+}
+
+/// A stage that inspects upload files
+///
+/// Implement this to plug in another scanner and add it with
+/// `ScanService::with_provider`.
+#[async_trait(?Send)]
+pub trait ScanProvider {
+    fn name(&self) -> &'static str;
+
+    /// Findings for `file`; empty when it looks clean
+    async fn scan(&self, file: &ScanFile<'_>) -> Result<Vec<ScanFinding>, ScanError>;
+}
+
+/// Everything the providers found across an upload's files
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScanReport {
+    pub findings: Vec<ScanFinding>,
+}
+
+impl ScanReport {
+    pub fn is_blocked(&self) -> bool {
+        self.findings.iter().any(|finding| finding.severity == ScanSeverity::Block)
+    }
+
+    pub fn has_warnings(&self) -> bool {
+        self.findings.iter().any(|finding| finding.severity == ScanSeverity::Warning)
+    }
+
+    /// Findings of `severity`, one per line
+    pub fn summary(&self, severity: ScanSeverity) -> String {
+        self.findings
+            .iter()
+            .filter(|finding| finding.severity == severity)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Runs every provider over every file
+pub struct ScanService {
+    providers: Vec<Box<dyn ScanProvider>>,
+}
+
+impl ScanService {
+    /// The heuristic scanner, plus the HTTP scanner when `scan_api_url`
+    /// is configured
+    pub fn new() -> Self {
+        let mut service = Self {
+            providers: vec![Box::new(HeuristicScanner)],
+        };
+        let url = app_config().scan_api_url.trim();
+        if !url.is_empty() {
+            service = service.with_provider(Box::new(HttpScanner::new(url)));
+        }
+        service
+    }
+
+    /// Add another provider; it runs after the existing ones
+    pub fn with_provider(mut self, provider: Box<dyn ScanProvider>) -> Self {
+        self.providers.push(provider);
+        self
+    }
+
+    pub async fn scan(&self, files: &[ScanFile<'_>]) -> ScanReport {
+        let mut report = ScanReport::default();
+        for file in files {
+            for provider in &self.providers {
+                match provider.scan(file).await {
+                    Ok(findings) => report.findings.extend(findings),
+                    // A scanner being down shouldn't stop uploads outright
+                    Err(e) => report.findings.push(ScanFinding {
+                        severity: ScanSeverity::Warning,
+                        file: file.label.to_string(),
+                        message: format!("not checked by {}: {}", provider.name(), e),
+                        provider: provider.name(),
+                    }),
+                }
+            }
+        }
+        if !report.findings.is_empty() {
+            log::info!("🛡️ Upload scan raised {} finding(s)", report.findings.len());
+        }
+        report
+    }
+}
+
+impl Default for ScanService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Bytes-level checks that need no network
+///
+/// Flags programs disguised as media, files whose contents don't match
+/// their declared type, PDFs with JavaScript or launch actions, and SVGs
+/// with scripts or event handlers.
+pub struct HeuristicScanner;
+
+impl HeuristicScanner {
+    const NAME: &'static str = "Built-in checks";
+
+    fn findings(file: &ScanFile<'_>) -> Vec<ScanFinding> {
+        let finding = |severity, message: &str| ScanFinding {
+            severity,
+            file: file.label.to_string(),
+            message: message.to_string(),
+            provider: Self::NAME,
+        };
+        let mut findings = Vec::new();
+        let essence = file.content_type.split(';').next().unwrap_or_default().trim().to_lowercase();
+
+        if is_executable(file.data) {
+            findings.push(finding(ScanSeverity::Block, "this is a program, not a media file or document"));
+            return findings;
+        }
+        if matches_type(&essence, file.data) == Some(false) {
+            findings.push(finding(ScanSeverity::Warning, &format!("the contents don't look like {}", essence)));
+        }
+
+        if essence == "application/pdf" || file.data.starts_with(b"%PDF-") {
+            if contains(file.data, b"/JavaScript") || contains(file.data, b"/JS") {
+                findings.push(finding(ScanSeverity::Block, "the PDF contains JavaScript"));
+            }
+            if contains(file.data, b"/Launch") {
+                findings.push(finding(ScanSeverity::Block, "the PDF can launch other programs"));
+            }
+            if contains(file.data, b"/EmbeddedFile") {
+                findings.push(finding(ScanSeverity::Warning, "the PDF has attached files"));
+            }
+        }
+
+        let head = String::from_utf8_lossy(&file.data[..file.data.len().min(1024)]).to_lowercase();
+        if essence == "image/svg+xml" || head.contains("<svg") {
+            let text = String::from_utf8_lossy(file.data).to_lowercase();
+            if text.contains("<script") || text.contains("javascript:") || has_event_handler(&text) {
+                findings.push(finding(ScanSeverity::Block, "the SVG contains script"));
+            } else if text.contains("<foreignobject") {
+                findings.push(finding(ScanSeverity::Warning, "the SVG embeds HTML"));
+            }
+        } else if essence.starts_with("text/") && String::from_utf8_lossy(file.data).to_lowercase().contains("<script") {
+            findings.push(finding(ScanSeverity::Warning, "the text contains a <script> tag"));
+        }
+
+        findings
+    }
+}
+
+#[async_trait(?Send)]
+impl ScanProvider for HeuristicScanner {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    async fn scan(&self, file: &ScanFile<'_>) -> Result<Vec<ScanFinding>, ScanError> {
+        Ok(Self::findings(file))
+    }
+}
+
+// Windows, Linux and macOS executables, and scripts with a shebang
+fn is_executable(data: &[u8]) -> bool {
+    const SIGNATURES: &[&[u8]] = &[
+        b"MZ",
+        b"\x7fELF",
+        b"\xcf\xfa\xed\xfe",
+        b"\xce\xfa\xed\xfe",
+        b"\xca\xfe\xba\xbe",
+        b"#!",
+    ];
+    SIGNATURES.iter().any(|signature| data.starts_with(signature))
+}
+
+/// Whether `data` looks like `content_type`, or `None` for types without
+/// a known signature
+fn matches_type(content_type: &str, data: &[u8]) -> Option<bool> {
+    let riff = |form: &[u8]| data.starts_with(b"RIFF") && data.get(8..12) == Some(form);
+    Some(match content_type {
+        "audio/mpeg" => data.starts_with(b"ID3") || (data.len() > 1 && data[0] == 0xff && data[1] & 0xe0 == 0xe0),
+        "audio/mp4" | "audio/x-m4a" | "video/mp4" => data.get(4..8) == Some(b"ftyp"),
+        "audio/wav" | "audio/x-wav" => riff(b"WAVE"),
+        "audio/ogg" => data.starts_with(b"OggS"),
+        "video/webm" => data.starts_with(b"\x1a\x45\xdf\xa3"),
+        // Readers accept a PDF header anywhere in the first kilobyte
+        "application/pdf" => contains(&data[..data.len().min(1024)], b"%PDF-"),
+        "image/jpeg" => data.starts_with(b"\xff\xd8\xff"),
+        "image/png" => data.starts_with(b"\x89PNG\r\n\x1a\n"),
+        "image/webp" => riff(b"WEBP"),
+        "text/plain" | "text/vtt" | "text/markdown" | "application/json" => std::str::from_utf8(data).is_ok(),
+        _ => return None,
+    })
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+// An `onload=`-style attribute: whitespace, "on", letters, optional
+// whitespace, "="
+fn has_event_handler(text: &str) -> bool {
+    text.match_indices("on").any(|(index, _)| {
+        let before = text[..index].chars().next_back();
+        if !before.is_some_and(char::is_whitespace) {
+            return false;
+        }
+        let rest = &text[index + 2..];
+        let name_len = rest.chars().take_while(char::is_ascii_alphabetic).count();
+        name_len > 0 && rest[name_len..].trim_start().starts_with('=')
+    })
+}
+
+#[derive(Deserialize)]
+struct HttpVerdict {
+    /// "clean", "suspicious" or "malicious"
+    verdict: String,
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+/// External scanning API
+///
+/// Each file is POSTed as-is with its `Content-Type`; the service answers
+/// `{"verdict": "clean" | "suspicious" | "malicious", "reason": "..."}`.
+/// Suspicious files warn and malicious ones block.
+pub struct HttpScanner {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl HttpScanner {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl ScanProvider for HttpScanner {
+    fn name(&self) -> &'static str {
+        "Malware scanner"
+    }
+
+    async fn scan(&self, file: &ScanFile<'_>) -> Result<Vec<ScanFinding>, ScanError> {
+        let response = self
+            .client
+            .post(&self.url)
+            .header("Content-Type", file.content_type)
+            .body(file.data.to_vec())
+            .send()
+            .await
+            .map_err(|e| ScanError::Unavailable(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(ScanError::Unavailable(format!("HTTP {}", response.status().as_u16())));
+        }
+        let verdict: HttpVerdict = response.json().await.map_err(|e| ScanError::InvalidResponse(e.to_string()))?;
+
+        let severity = match verdict.verdict.as_str() {
+            "clean" => return Ok(Vec::new()),
+            "suspicious" => ScanSeverity::Warning,
+            "malicious" => ScanSeverity::Block,
+            other => return Err(ScanError::InvalidResponse(format!("unknown verdict \"{}\"", other))),
+        };
+        Ok(vec![ScanFinding {
+            severity,
+            file: file.label.to_string(),
+            message: verdict.reason.unwrap_or_else(|| format!("flagged as {}", verdict.verdict)),
+            provider: self.name(),
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn scan(content_type: &str, data: &[u8]) -> Vec<ScanFinding> {
+        HeuristicScanner::findings(&ScanFile { label: "Main file", content_type, data })
+    }
+
+    fn severities(findings: &[ScanFinding]) -> Vec<ScanSeverity> {
+        findings.iter().map(|finding| finding.severity).collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn clean_files_pass() {
+        assert!(scan("audio/mpeg", b"ID3\x04\x00rest of the file").is_empty());
+        assert!(scan("application/pdf", b"%PDF-1.7\n1 0 obj << /Type /Catalog >>").is_empty());
+        assert!(scan("text/vtt", b"WEBVTT\n\n00:00.000 --> 00:01.000\nIn the beginning").is_empty());
+        assert!(scan("image/svg+xml", br#"<svg xmlns="http://www.w3.org/2000/svg"><circle r="4"/></svg>"#).is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    fn disguised_programs_are_blocked() {
+        assert_eq!(severities(&scan("audio/mpeg", b"MZ\x90\x00 This program cannot be run in DOS mode")), vec![ScanSeverity::Block]);
+        assert_eq!(severities(&scan("video/mp4", b"not a video at all")), vec![ScanSeverity::Warning]);
+    }
+
+    #[wasm_bindgen_test]
+    fn active_content_is_blocked() {
+        let pdf = b"%PDF-1.4\n1 0 obj << /OpenAction << /S /JavaScript /JS (app.alert(1)) >> >>";
+        assert!(severities(&scan("application/pdf", pdf)).contains(&ScanSeverity::Block));
+
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" onload ="alert(1)"></svg>"#;
+        assert_eq!(severities(&scan("image/svg+xml", svg)), vec![ScanSeverity::Block]);
+        // "on" inside an attribute name or value isn't a handler
+        assert!(!has_event_handler(r#"<svg version="1.1"><text>gone = true</text></svg>"#));
+    }
+}
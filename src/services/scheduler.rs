@@ -0,0 +1,207 @@
+//! Request scheduling for gateway traffic: a global cap on requests in
+//! flight, per-host rate limits, and retries of 429 and 503 responses
+
+use futures::channel::oneshot;
+use gloo_timers::future::TimeoutFuture;
+use reqwest::StatusCode;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+
+use crate::utils::constants::{
+    HOST_REQUESTS_PER_SECOND, HOST_REQUEST_BURST, MAX_CONCURRENT_REQUESTS, MAX_REQUEST_RETRIES, MAX_RETRY_DELAY_MS,
+    RETRY_BASE_DELAY_MS,
+};
+
+thread_local! {
+    static SCHEDULER: RefCell<Scheduler> = RefCell::new(Scheduler::default());
+}
+
+#[derive(Default)]
+struct Scheduler {
+    in_flight: usize,
+    /// Requests waiting for a slot, oldest first
+    waiting: VecDeque<oneshot::Sender<()>>,
+    hosts: HashMap<String, HostLimit>,
+}
+
+/// Rate limit for one host
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct HostLimit {
+    /// When the next request would start if they were evenly spaced (ms)
+    next_at: f64,
+    /// No requests before this, after the host asked us to back off (ms)
+    paused_until: f64,
+}
+
+impl HostLimit {
+    /// Book a request at `now`, returning how long it must wait (ms)
+    fn reserve(&mut self, now: f64) -> f64 {
+        let interval = 1000.0 / HOST_REQUESTS_PER_SECOND;
+        let scheduled = self.next_at.max(now);
+        // Up to a burst's worth of requests may run ahead of the schedule
+        let start = (scheduled - interval * (HOST_REQUEST_BURST - 1) as f64)
+            .max(self.paused_until)
+            .max(now);
+        self.next_at = scheduled.max(start) + interval;
+        start - now
+    }
+}
+
+/// A slot among the requests in flight, freed on drop
+struct Permit;
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        release();
+    }
+}
+
+// A queued request; if it's dropped after being handed a slot but before
+// taking it, the slot goes to the next in line
+struct Waiting(Option<oneshot::Receiver<()>>);
+
+impl Drop for Waiting {
+    fn drop(&mut self) {
+        if let Some(mut receiver) = self.0.take() {
+            if let Ok(Some(())) = receiver.try_recv() {
+                release();
+            }
+        }
+    }
+}
+
+async fn acquire() -> Permit {
+    let receiver = SCHEDULER.with(|scheduler| {
+        let mut scheduler = scheduler.borrow_mut();
+        if scheduler.in_flight < MAX_CONCURRENT_REQUESTS {
+            scheduler.in_flight += 1;
+            None
+        } else {
+            let (sender, receiver) = oneshot::channel();
+            scheduler.waiting.push_back(sender);
+            Some(receiver)
+        }
+    });
+    if let Some(receiver) = receiver {
+        let mut waiting = Waiting(Some(receiver));
+        if let Some(receiver) = waiting.0.as_mut() {
+            let _ = receiver.await;
+        }
+        waiting.0 = None;
+    }
+    Permit
+}
+
+// Hand the slot straight to the next request still waiting, or free it
+fn release() {
+    SCHEDULER.with(|scheduler| {
+        let mut scheduler = scheduler.borrow_mut();
+        while let Some(next) = scheduler.waiting.pop_front() {
+            if next.send(()).is_ok() {
+                return;
+            }
+        }
+        scheduler.in_flight = scheduler.in_flight.saturating_sub(1);
+    });
+}
+
+async fn wait_for_host(host: &str) {
+    let now = js_sys::Date::now();
+    let delay = SCHEDULER.with(|scheduler| scheduler.borrow_mut().hosts.entry(host.to_string()).or_default().reserve(now));
+    if delay > 0.0 {
+        TimeoutFuture::new(delay.ceil() as u32).await;
+    }
+}
+
+fn pause_host(host: &str, delay_ms: u32) {
+    let until = js_sys::Date::now() + delay_ms as f64;
+    SCHEDULER.with(|scheduler| {
+        let mut scheduler = scheduler.borrow_mut();
+        let limit = scheduler.hosts.entry(host.to_string()).or_default();
+        limit.paused_until = limit.paused_until.max(until);
+    });
+}
+
+/// Whether a gateway is asking us to slow down rather than failing
+pub fn is_throttled(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::SERVICE_UNAVAILABLE
+}
+
+/// Wait before retry `attempt + 1` of a throttled request: an exponential
+/// backoff with `random` (0.0 to 1.0) spreading it over its upper half
+pub fn retry_delay_ms(attempt: u32, random: f64) -> u32 {
+    let ceiling = RETRY_BASE_DELAY_MS.saturating_mul(1 << attempt.min(16)).min(MAX_RETRY_DELAY_MS);
+    ceiling / 2 + (ceiling as f64 / 2.0 * random.clamp(0.0, 1.0)) as u32
+}
+
+// `Retry-After` in seconds; HTTP dates aren't worth parsing here
+fn retry_after_ms(response: &reqwest::Response) -> Option<u32> {
+    let seconds: u32 = response.headers().get("Retry-After")?.to_str().ok()?.trim().parse().ok()?;
+    Some(seconds.saturating_mul(1000))
+}
+
+/// Send the request `build` makes once a slot and the host's rate limit
+/// allow, retrying while the gateway is throttling
+///
+/// The last throttled response is returned if retries run out, or if the
+/// gateway asks for a longer wait than `MAX_RETRY_DELAY_MS`, so the caller
+/// can fail over instead.
+pub async fn schedule<F>(client: &reqwest::Client, build: F) -> Result<reqwest::Response, reqwest::Error>
+where
+    F: Fn() -> reqwest::RequestBuilder,
+{
+    let mut attempt = 0;
+    loop {
+        let request = build().build()?;
+        let host = request.url().host_str().unwrap_or_default().to_string();
+
+        wait_for_host(&host).await;
+        let permit = acquire().await;
+        let response = client.execute(request).await?;
+        drop(permit);
+
+        let status = response.status();
+        if !is_throttled(status) || attempt >= MAX_REQUEST_RETRIES {
+            return Ok(response);
+        }
+        let delay = match retry_after_ms(&response) {
+            Some(delay) if delay > MAX_RETRY_DELAY_MS => return Ok(response),
+            Some(delay) => delay,
+            None => retry_delay_ms(attempt, js_sys::Math::random()),
+        };
+
+        log::info!("⏳ {} returned HTTP {}, retrying in {} ms", host, status.as_u16(), delay);
+        pause_host(&host, delay);
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn hosts_allow_a_burst_then_space_requests() {
+        let interval = 1000.0 / HOST_REQUESTS_PER_SECOND;
+        let mut limit = HostLimit::default();
+        for _ in 0..HOST_REQUEST_BURST {
+            assert_eq!(limit.reserve(1000.0), 0.0);
+        }
+        assert_eq!(limit.reserve(1000.0), interval);
+        assert_eq!(limit.reserve(1000.0), 2.0 * interval);
+
+        // A pause holds back even the burst
+        let mut limit = HostLimit { next_at: 0.0, paused_until: 5000.0 };
+        assert_eq!(limit.reserve(1000.0), 4000.0);
+    }
+
+    #[wasm_bindgen_test]
+    fn retry_delays_grow_with_jitter_up_to_the_cap() {
+        assert_eq!(retry_delay_ms(0, 0.0), RETRY_BASE_DELAY_MS / 2);
+        assert_eq!(retry_delay_ms(0, 1.0), RETRY_BASE_DELAY_MS);
+        assert_eq!(retry_delay_ms(2, 1.0), RETRY_BASE_DELAY_MS * 4);
+        assert!(retry_delay_ms(3, 0.5) < retry_delay_ms(3, 0.9));
+        assert_eq!(retry_delay_ms(30, 1.0), MAX_RETRY_DELAY_MS);
+    }
+}
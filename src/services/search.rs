@@ -0,0 +1,315 @@
+use dioxus::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{BrowseFilters, ContentMetadata, SearchQuery, SPIRITUAL_CONTENT_TYPE};
+use crate::services::graphql::GraphQLClient;
+use crate::services::hide_registry::is_hidden;
+use crate::services::revisions::latest_versions_only;
+use crate::services::storage::{StorageService, SEARCH_DOCUMENTS_STORE};
+
+/// localStorage key for the highest block height already indexed
+const SEARCH_SYNCED_HEIGHT_KEY: &str = "faithful_archive_search_synced_height";
+
+/// Maximum results returned for a query
+const MAX_RESULTS: usize = 20;
+
+const TITLE_WEIGHT: f32 = 3.0;
+const SPEAKER_WEIGHT: f32 = 2.0;
+const SCRIPTURE_WEIGHT: f32 = 2.0;
+const DESCRIPTION_WEIGHT: f32 = 1.0;
+
+/// A ranked search hit
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    pub metadata: ContentMetadata,
+    pub score: f32,
+}
+
+/// In-memory inverted index over published item metadata
+///
+/// Only the metadata documents are persisted; postings are rebuilt when the
+/// index loads, which keeps the IndexedDB schema trivial.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchIndex {
+    documents: HashMap<String, ContentMetadata>,
+    /// term -> (tx_id -> weight)
+    postings: HashMap<String, HashMap<String, f32>>,
+}
+
+impl SearchIndex {
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Add or replace a document
+    pub fn insert(&mut self, metadata: ContentMetadata) {
+        self.remove(&metadata.tx_id);
+
+        let mut terms: HashMap<String, f32> = HashMap::new();
+        let mut add = |text: &str, weight: f32| {
+            for term in tokenize(text) {
+                *terms.entry(term).or_insert(0.0) += weight;
+            }
+        };
+
+        add(&metadata.title, TITLE_WEIGHT);
+        if let Some(speaker) = &metadata.speaker {
+            add(speaker, SPEAKER_WEIGHT);
+        }
+        for scripture_ref in &metadata.scripture_refs {
+            add(scripture_ref, SCRIPTURE_WEIGHT);
+        }
+        if let Some(description) = &metadata.description {
+            add(description, DESCRIPTION_WEIGHT);
+        }
+
+        for (term, weight) in terms {
+            self.postings
+                .entry(term)
+                .or_default()
+                .insert(metadata.tx_id.clone(), weight);
+        }
+        self.documents.insert(metadata.tx_id.clone(), metadata);
+    }
+
+    pub fn get(&self, tx_id: &str) -> Option<&ContentMetadata> {
+        self.documents.get(tx_id)
+    }
+
+    pub fn remove(&mut self, tx_id: &str) {
+        if self.documents.remove(tx_id).is_some() {
+            self.postings.retain(|_, docs| {
+                docs.remove(tx_id);
+                !docs.is_empty()
+            });
+        }
+    }
+
+    /// Indexed items passing `filters`, newest first
+    pub fn filter(&self, filters: &BrowseFilters) -> Vec<ContentMetadata> {
+        let mut items: Vec<ContentMetadata> = self.documents
+            .values()
+            .filter(|metadata| !is_hidden(&metadata.tx_id) && filters.matches(metadata))
+            .cloned()
+            .collect();
+        if !filters.all_versions {
+            items = latest_versions_only(items);
+        }
+        items.sort_by_key(|metadata| std::cmp::Reverse(metadata.block_timestamp.or(metadata.created_at)));
+        items
+    }
+
+    /// Top ranked results for a free-text query
+    pub fn search(&self, query: &str) -> Vec<SearchResult> {
+        let mut results = self.search_filtered(query, &BrowseFilters::default());
+        results.truncate(MAX_RESULTS);
+        results
+    }
+
+    /// All ranked results for a free-text query that pass `filters`
+    ///
+    /// The last query term matches as a prefix so results update while
+    /// typing. Documents matching more query terms always rank first.
+    pub fn search_filtered(&self, query: &str, filters: &BrowseFilters) -> Vec<SearchResult> {
+        let terms = tokenize(query);
+        let Some((last, rest)) = terms.split_last() else {
+            return Vec::new();
+        };
+
+        // tx_id -> (matched terms, score)
+        let mut scores: HashMap<&str, (usize, f32)> = HashMap::new();
+
+        for term in rest {
+            if let Some(docs) = self.postings.get(term) {
+                for (tx_id, weight) in docs {
+                    let entry = scores.entry(tx_id).or_insert((0, 0.0));
+                    entry.0 += 1;
+                    entry.1 += weight;
+                }
+            }
+        }
+
+        let mut prefix_scores: HashMap<&str, f32> = HashMap::new();
+        for (term, docs) in self.postings.iter().filter(|(term, _)| term.starts_with(last.as_str())) {
+            // Exact matches outrank prefix matches
+            let boost = if term == last { 1.0 } else { 0.5 };
+            for (tx_id, weight) in docs {
+                let best = prefix_scores.entry(tx_id).or_insert(0.0);
+                *best = best.max(weight * boost);
+            }
+        }
+        for (tx_id, score) in prefix_scores {
+            let entry = scores.entry(tx_id).or_insert((0, 0.0));
+            entry.0 += 1;
+            entry.1 += score;
+        }
+
+        let mut results: Vec<(usize, SearchResult)> = scores
+            .into_iter()
+            .filter(|(tx_id, _)| !is_hidden(tx_id))
+            .filter_map(|(tx_id, (matched, score))| {
+                let metadata = self.documents.get(tx_id).filter(|metadata| filters.matches(metadata))?.clone();
+                Some((matched, SearchResult { metadata, score }))
+            })
+            .collect();
+        if !filters.all_versions {
+            let matched = results.iter().map(|(_, result)| result.metadata.clone()).collect();
+            let newest: HashSet<String> = latest_versions_only(matched).into_iter().map(|metadata| metadata.tx_id).collect();
+            results.retain(|(_, result)| newest.contains(&result.metadata.tx_id));
+        }
+
+        results.sort_by(|(a_matched, a), (b_matched, b)| {
+            b_matched
+                .cmp(a_matched)
+                .then(b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal))
+        });
+
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+/// Lowercased alphanumeric terms; scripture refs like "John 3:16" become
+/// "john", "3", "16"
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+        .collect()
+}
+
+// Global search index using Dioxus signals
+fn use_search_index_state() -> &'static GlobalSignal<SearchIndex> {
+    static SEARCH_INDEX: GlobalSignal<SearchIndex> = GlobalSignal::new(SearchIndex::default);
+    &SEARCH_INDEX
+}
+
+fn synced_height() -> Option<u64> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(SEARCH_SYNCED_HEIGHT_KEY).ok().flatten())
+        .and_then(|value| value.parse().ok())
+}
+
+fn set_synced_height(height: u64) {
+    if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+        let _ = storage.set_item(SEARCH_SYNCED_HEIGHT_KEY, &height.to_string());
+    }
+}
+
+/// Builds and incrementally updates the search index
+pub struct SearchService {
+    graphql: GraphQLClient,
+}
+
+impl SearchService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+        }
+    }
+
+    /// Rebuild the in-memory index from persisted documents
+    pub async fn load_persisted(&self) {
+        let storage = match StorageService::open().await {
+            Ok(storage) => storage,
+            Err(e) => {
+                log::warn!("Search index storage unavailable: {}", e);
+                return;
+            }
+        };
+
+        if let Ok(documents) = storage.get_all::<ContentMetadata>(SEARCH_DOCUMENTS_STORE).await {
+            let mut index = use_search_index_state().write();
+            for metadata in documents {
+                index.insert(metadata);
+            }
+            log::info!("🔎 Loaded {} documents into search index", index.len());
+        }
+    }
+
+    /// Fetch metadata published since the last sync and add it to the index
+    ///
+    /// Re-syncs from the last indexed height (inclusive) so items that were
+    /// still pending last time are picked up once mined.
+    pub async fn sync(&self) {
+        let since = synced_height();
+        let transactions = match self.graphql
+            .find_transactions_since(&[], &[("Type", &[SPIRITUAL_CONTENT_TYPE])], since)
+            .await
+        {
+            Ok(transactions) => transactions,
+            Err(e) => {
+                log::warn!("Search index sync failed: {}", e);
+                return;
+            }
+        };
+
+        let storage = StorageService::open().await.ok();
+        let mut max_height = since.unwrap_or(0);
+        let mut added = 0;
+
+        for tx in transactions {
+            let Some(metadata) = ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp) else {
+                continue;
+            };
+
+            if let Some(height) = tx.block_height {
+                max_height = max_height.max(height);
+            }
+            if let Some(storage) = &storage {
+                if let Err(e) = storage.put(SEARCH_DOCUMENTS_STORE, &metadata.tx_id, &metadata).await {
+                    log::warn!("Failed to persist search document {}: {}", metadata.tx_id, e);
+                }
+            }
+
+            use_search_index_state().write().insert(metadata);
+            added += 1;
+        }
+
+        if max_height > 0 {
+            set_synced_height(max_height);
+        }
+        log::info!("🔎 Search index synced ({} documents updated)", added);
+    }
+}
+
+impl Default for SearchService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Load the persisted search index and sync new items in the background
+pub fn init_search_index() {
+    spawn(async {
+        let service = SearchService::new();
+        service.load_persisted().await;
+        service.sync().await;
+    });
+}
+
+/// Hook returning ranked results for a query signal
+pub fn use_search(query: Signal<String>) -> Memo<Vec<SearchResult>> {
+    use_memo(move || use_search_index_state().read().search(&query.read()))
+}
+
+/// Hook returning every result for a Search page query
+pub fn use_search_query(query: SearchQuery) -> Memo<Vec<SearchResult>> {
+    use_memo(use_reactive!(|query| {
+        use_search_index_state().read().search_filtered(&query.text, &query.filters)
+    }))
+}
+
+/// Indexed items passing `filters`, read from the current local index
+pub fn filter_local(filters: &BrowseFilters) -> Vec<ContentMetadata> {
+    use_search_index_state().read().filter(filters)
+}
+
+/// Metadata of an indexed item, if the local index has it
+pub fn find_local(tx_id: &str) -> Option<ContentMetadata> {
+    use_search_index_state().read().get(tx_id).cloned()
+}
@@ -0,0 +1,192 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::models::{ContentMetadata, SeriesManifest, SERIES_MANIFEST_TYPE};
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, GraphQLError, TransactionSummary};
+use crate::services::upload::{publish_with_wallet, PublishError};
+
+/// localStorage key for items played per series
+const SERIES_PROGRESS_KEY: &str = "faithful_archive_series_progress";
+
+#[derive(Debug, Clone)]
+pub enum SeriesError {
+    LookupFailed(GraphQLError),
+    NotFound,
+    InvalidManifest(String),
+    PublishFailed(PublishError),
+}
+
+impl std::fmt::Display for SeriesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SeriesError::LookupFailed(e) => write!(f, "Could not look up series: {}", e),
+            SeriesError::NotFound => write!(f, "Series not found"),
+            SeriesError::InvalidManifest(msg) => write!(f, "Series manifest is invalid: {}", msg),
+            SeriesError::PublishFailed(e) => write!(f, "Could not publish series: {}", e),
+        }
+    }
+}
+
+/// A series as listed in the upload form
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesSummary {
+    pub series_id: String,
+    pub title: String,
+    /// Latest manifest DataItem
+    pub manifest_tx_id: String,
+}
+
+/// Publishes and resolves series manifests
+pub struct SeriesService {
+    graphql: GraphQLClient,
+}
+
+impl SeriesService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+        }
+    }
+
+    /// Current manifest of a series
+    ///
+    /// Only manifests signed by the series creator (the owner of its first
+    /// manifest) are considered, so nobody else can rewrite the series.
+    pub async fn load(&self, series_id: &str) -> Result<SeriesManifest, SeriesError> {
+        let manifests = self.graphql
+            .find_transactions(&[], &[("Type", &[SERIES_MANIFEST_TYPE]), ("Series-Id", &[series_id])])
+            .await
+            .map_err(SeriesError::LookupFailed)?;
+        let latest = latest_from_creator(&manifests).ok_or(SeriesError::NotFound)?;
+
+        let response = GatewayManager::new()
+            .get(&format!("/{}", latest.id))
+            .await
+            .map_err(|e| SeriesError::InvalidManifest(e.to_string()))?;
+        response
+            .json()
+            .await
+            .map_err(|e| SeriesError::InvalidManifest(e.to_string()))
+    }
+
+    /// Series created by `owner`, for attaching new uploads
+    pub async fn list_for_owner(&self, owner: &str) -> Result<Vec<SeriesSummary>, SeriesError> {
+        let manifests = self.graphql
+            .find_transactions(&[owner], &[("Type", &[SERIES_MANIFEST_TYPE])])
+            .await
+            .map_err(SeriesError::LookupFailed)?;
+
+        let mut by_series: HashMap<&str, Vec<TransactionSummary>> = HashMap::new();
+        for manifest in &manifests {
+            if let Some(series_id) = manifest.tag("Series-Id") {
+                by_series.entry(series_id).or_default().push(manifest.clone());
+            }
+        }
+
+        let mut series: Vec<SeriesSummary> = by_series
+            .into_iter()
+            .filter_map(|(series_id, manifests)| {
+                let latest = latest_from_creator(&manifests)?;
+                Some(SeriesSummary {
+                    series_id: series_id.to_string(),
+                    title: latest.tag("Title").unwrap_or(series_id).to_string(),
+                    manifest_tx_id: latest.id.clone(),
+                })
+            })
+            .collect();
+        series.sort_by(|a, b| a.title.cmp(&b.title));
+        Ok(series)
+    }
+
+    /// Publish a new version of a manifest from the connected wallet
+    pub async fn publish(&self, manifest: &SeriesManifest) -> Result<String, SeriesError> {
+        let data = serde_json::to_vec(manifest)
+            .map_err(|e| SeriesError::InvalidManifest(e.to_string()))?;
+        let tags = vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Type".to_string(), SERIES_MANIFEST_TYPE.to_string()),
+            ("Series-Id".to_string(), manifest.series_id.clone()),
+            ("Title".to_string(), manifest.title.clone()),
+        ];
+
+        let (_, tx_id) = publish_with_wallet(data, tags)
+            .await
+            .map_err(SeriesError::PublishFailed)?;
+        log::info!("📚 Published series manifest {} ({} items)", manifest.series_id, manifest.members.len());
+        Ok(tx_id)
+    }
+
+    /// Metadata of each member in series order; members the gateway can't
+    /// find are skipped
+    pub async fn members(&self, manifest: &SeriesManifest) -> Result<Vec<ContentMetadata>, SeriesError> {
+        let ids: Vec<&str> = manifest.members.iter().map(String::as_str).collect();
+        let found = self.graphql
+            .transactions_by_ids(&ids)
+            .await
+            .map_err(SeriesError::LookupFailed)?;
+
+        let mut by_id: HashMap<String, ContentMetadata> = found
+            .iter()
+            .filter_map(|tx| ContentMetadata::from_tags(&tx.id, &tx.owner, &tx.tags, tx.block_height, tx.timestamp))
+            .map(|metadata| (metadata.tx_id.clone(), metadata))
+            .collect();
+
+        Ok(manifest.members.iter().filter_map(|id| by_id.remove(id)).collect())
+    }
+}
+
+impl Default for SeriesService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Newest manifest signed by the creator of the series
+fn latest_from_creator(manifests: &[TransactionSummary]) -> Option<&TransactionSummary> {
+    // Pending manifests have no timestamp yet and are the newest
+    let order = |tx: &&TransactionSummary| tx.timestamp.unwrap_or(i64::MAX);
+    let creator = &manifests.iter().min_by_key(order)?.owner;
+    manifests
+        .iter()
+        .filter(|tx| &tx.owner == creator)
+        .max_by_key(order)
+}
+
+fn load_progress() -> HashMap<String, HashSet<String>> {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(SERIES_PROGRESS_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_progress(progress: &HashMap<String, HashSet<String>>) {
+    if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+        if let Ok(json) = serde_json::to_string(progress) {
+            let _ = storage.set_item(SERIES_PROGRESS_KEY, &json);
+        }
+    }
+}
+
+/// Items of a series already played in this browser
+pub fn played_in_series(series_id: &str) -> HashSet<String> {
+    load_progress().remove(series_id).unwrap_or_default()
+}
+
+/// Items played in any series in this browser
+pub fn all_played() -> HashSet<String> {
+    load_progress().into_values().flatten().collect()
+}
+
+pub fn mark_played(series_id: &str, tx_id: &str) {
+    let mut progress = load_progress();
+    progress.entry(series_id.to_string()).or_default().insert(tx_id.to_string());
+    save_progress(&progress);
+}
+
+pub fn reset_progress(series_id: &str) {
+    let mut progress = load_progress();
+    if progress.remove(series_id).is_some() {
+        save_progress(&progress);
+    }
+}
@@ -0,0 +1,149 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{current_locale, set_locale, Locale};
+use crate::models::{BrowseFilters, BrowseQuery, UdlTerms};
+use crate::services::gateway::GatewayManager;
+use crate::services::storage::{StorageError, StorageService, SETTINGS_STORE};
+use crate::services::theme::{set_theme_mode, theme_mode, ThemeMode};
+use crate::services::wallet::WalletStrategyType;
+
+/// Key of the single preferences record in `SETTINGS_STORE`
+const SETTINGS_KEY: &str = "app";
+
+/// Preferences that apply across the app
+///
+/// Theme, language and gateway also keep a localStorage copy in their own
+/// modules, which they need synchronously at startup; this record is the
+/// one the Settings page edits and the rest of the app reads.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    /// Pinned gateway URL; None picks the fastest
+    pub gateway: Option<String>,
+    /// License terms the upload form starts with
+    pub default_license: Option<UdlTerms>,
+    pub language: Locale,
+    /// Open Browse filtered to items in the interface language
+    pub browse_in_interface_language: bool,
+    pub theme: ThemeMode,
+    /// Start playing when an item page opens
+    pub autoplay: bool,
+    /// Skip cover images and don't preload media
+    pub data_saver: bool,
+    /// Average upload speed cap in KB/s, for shared connections
+    pub upload_limit_kbps: Option<u32>,
+    /// Disconnect the wallet after this many minutes without input
+    pub idle_lock_minutes: Option<u32>,
+    /// Count plays, searches and upload steps in this browser
+    pub usage_stats: bool,
+    /// Wallets to select first, most preferred first; the rest follow in
+    /// the built-in order
+    pub wallet_order: Vec<WalletStrategyType>,
+}
+
+impl AppSettings {
+    // What the theme, language and gateway modules are using right now
+    fn current() -> Self {
+        Self {
+            gateway: GatewayManager::new().pinned(),
+            language: current_locale(),
+            theme: theme_mode(),
+            ..Self::default()
+        }
+    }
+}
+
+fn use_settings_state() -> &'static GlobalSignal<AppSettings> {
+    static SETTINGS: GlobalSignal<AppSettings> = GlobalSignal::new(AppSettings::current);
+    &SETTINGS
+}
+
+// Hand changed preferences to the modules that act on them
+fn apply(previous: &AppSettings, settings: &AppSettings) {
+    if previous.theme != settings.theme {
+        set_theme_mode(settings.theme);
+    }
+    if previous.language != settings.language {
+        set_locale(settings.language);
+    }
+    if previous.gateway != settings.gateway {
+        GatewayManager::new().pin(settings.gateway.as_deref());
+    }
+}
+
+/// Loads and saves `AppSettings` in IndexedDB
+pub struct SettingsService;
+
+impl SettingsService {
+    pub async fn load() -> Result<Option<AppSettings>, StorageError> {
+        let storage = StorageService::open().await?;
+        storage.get(SETTINGS_STORE, SETTINGS_KEY).await
+    }
+
+    pub async fn save(settings: &AppSettings) -> Result<(), StorageError> {
+        let storage = StorageService::open().await?;
+        storage.put(SETTINGS_STORE, SETTINGS_KEY, settings).await
+    }
+}
+
+/// Change preferences, apply them and save them in the background
+pub fn update_settings(change: impl FnOnce(&mut AppSettings)) {
+    let previous = use_settings_state().peek().clone();
+    let mut settings = previous.clone();
+    change(&mut settings);
+    if settings == previous {
+        return;
+    }
+    apply(&previous, &settings);
+    *use_settings_state().write() = settings.clone();
+    spawn(async move {
+        if let Err(e) = SettingsService::save(&settings).await {
+            log::warn!("Failed to save settings: {}", e);
+        }
+    });
+}
+
+/// Load saved preferences in the background
+///
+/// On first run, saves what the theme, language and gateway modules picked
+/// up from localStorage so later loads have a complete record.
+pub fn init_settings() {
+    spawn(async {
+        match SettingsService::load().await {
+            Ok(Some(saved)) => {
+                let previous = use_settings_state().peek().clone();
+                apply(&previous, &saved);
+                *use_settings_state().write() = saved;
+                log::info!("⚙️ Loaded saved settings");
+            }
+            Ok(None) => {
+                let current = use_settings_state().peek().clone();
+                if let Err(e) = SettingsService::save(&current).await {
+                    log::warn!("Failed to save settings: {}", e);
+                }
+            }
+            Err(e) => log::warn!("Failed to load settings: {}", e),
+        }
+    });
+}
+
+/// The current preferences, for services outside components
+pub fn current_settings() -> AppSettings {
+    use_settings_state().peek().clone()
+}
+
+/// Hook returning the current preferences
+pub fn use_settings() -> Memo<AppSettings> {
+    use_memo(move || use_settings_state().read().clone())
+}
+
+/// Hook returning where Browse links should open, filtered to the
+/// interface language when `browse_in_interface_language` is on
+pub fn use_browse_start() -> Memo<BrowseQuery> {
+    use_memo(move || {
+        let settings = use_settings_state().read();
+        let language = settings.browse_in_interface_language.then(|| settings.language.code().to_string());
+        BrowseQuery::new(BrowseFilters { language, ..BrowseFilters::default() })
+    })
+}
@@ -0,0 +1,188 @@
+//! Share links and link-preview metadata
+//!
+//! Share links point at the hosted app (`AppConfig::site_url`) rather
+//! than whichever gateway or ArNS name the sharer happens to be browsing
+//! through, so every copy of a link is the same. Sharing uses the Web
+//! Share API where the browser has it and falls back to the clipboard.
+
+use js_sys::{Function, Object, Promise, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+use crate::app::Route;
+use crate::config::app_config;
+use crate::models::{CollectionManifest, ContentMetadata};
+use crate::utils::citation::permanent_url;
+use crate::utils::constants::APP_NAME;
+use crate::utils::display::copy_to_clipboard;
+
+/// Link-preview descriptions are cut to about this many characters
+const PREVIEW_DESCRIPTION_CHARS: usize = 200;
+/// `type` of the message an embedded player posts to its parent page
+/// whenever its height changes
+pub const EMBED_RESIZE_MESSAGE: &str = "faithful-archive:embed-resize";
+/// Starting iframe height, before the player reports its own
+const EMBED_INITIAL_HEIGHT: u32 = 160;
+
+/// How a share went
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareOutcome {
+    /// Handed to the system share sheet
+    Shared,
+    /// Copied to the clipboard instead
+    Copied,
+    /// The viewer closed the share sheet
+    Cancelled,
+}
+
+/// Canonical link to an item's page
+pub fn share_url(tx_id: &str) -> String {
+    format!("{}{}", app_config().site_url, Route::ContentDetail { tx_id: tx_id.to_string() })
+}
+
+/// Canonical link to a collection's page
+pub fn collection_share_url(collection_id: &str) -> String {
+    format!("{}{}", app_config().site_url, Route::CollectionDetail { collection_id: collection_id.to_string() })
+}
+
+/// Link to the bare player for an item, for iframes
+pub fn embed_url(tx_id: &str) -> String {
+    format!("{}{}", app_config().site_url, Route::EmbedPlayer { tx_id: tx_id.to_string() })
+}
+
+/// HTML for a ministry website: the player iframe plus a small script that
+/// resizes it to fit whenever the player reports a new height
+pub fn embed_code(tx_id: &str, title: &str) -> String {
+    let title = title.replace('&', "&amp;").replace('"', "&quot;").replace('<', "&lt;");
+    format!(
+        "<iframe src=\"{url}\" title=\"{title}\" width=\"100%\" height=\"{height}\" style=\"border:0\" allow=\"autoplay; fullscreen\" loading=\"lazy\"></iframe>\n\
+         <script>addEventListener(\"message\",function(e){{if(e.origin!==\"{origin}\"||!e.data||e.data.type!==\"{message}\")return;\
+         document.querySelectorAll(\"iframe\").forEach(function(f){{if(f.contentWindow===e.source)f.style.height=e.data.height+\"px\"}})}})</script>",
+        url = embed_url(tx_id),
+        title = title,
+        height = EMBED_INITIAL_HEIGHT,
+        origin = app_config().site_url,
+        message = EMBED_RESIZE_MESSAGE,
+    )
+}
+
+/// What a shared link unfurls to in chat apps and social networks
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShareMeta {
+    pub title: String,
+    pub description: String,
+    pub url: String,
+    pub image_url: String,
+}
+
+impl ShareMeta {
+    pub fn for_item(item: &ContentMetadata) -> Self {
+        let description = match (item.speaker.as_ref(), item.description.as_ref()) {
+            (_, Some(description)) => description.clone(),
+            (Some(speaker), None) => format!("{} — archived on {}", speaker, APP_NAME),
+            (None, None) => format!("Archived permanently on {}", APP_NAME),
+        };
+        Self {
+            title: item.title.clone(),
+            description: preview_text(&description),
+            url: share_url(&item.tx_id),
+            image_url: item
+                .cover_tx_id
+                .as_deref()
+                .map(permanent_url)
+                .unwrap_or_else(|| app_config().logo_url.clone()),
+        }
+    }
+
+    pub fn for_collection(collection: &CollectionManifest) -> Self {
+        let description = collection
+            .description
+            .clone()
+            .unwrap_or_else(|| format!("{} items collected on {}", collection.items.len(), APP_NAME));
+        Self {
+            title: collection.title.clone(),
+            description: preview_text(&description),
+            url: collection_share_url(&collection.collection_id),
+            image_url: app_config().logo_url.clone(),
+        }
+    }
+}
+
+// One line, cut at a word boundary
+fn preview_text(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= PREVIEW_DESCRIPTION_CHARS {
+        return text;
+    }
+    let cut: String = text.chars().take(PREVIEW_DESCRIPTION_CHARS).collect();
+    let cut = cut.rsplit_once(' ').map_or(cut.as_str(), |(head, _)| head);
+    format!("{}…", cut.trim_end_matches(|c: char| c.is_ascii_punctuation()))
+}
+
+/// Share `url` through the system share sheet, or copy it when the
+/// browser can't
+pub async fn share_link(title: &str, text: &str, url: &str) -> Result<ShareOutcome, String> {
+    let navigator = web_sys::window().ok_or("No window")?.navigator();
+    let share = Reflect::get(&navigator, &JsValue::from_str("share"))
+        .ok()
+        .and_then(|share| share.dyn_into::<Function>().ok());
+
+    let Some(share) = share else {
+        copy_to_clipboard(url).await?;
+        return Ok(ShareOutcome::Copied);
+    };
+
+    let data = Object::new();
+    for (key, value) in [("title", title), ("text", text), ("url", url)] {
+        Reflect::set(&data, &JsValue::from_str(key), &JsValue::from_str(value)).map_err(|_| "Could not share".to_string())?;
+    }
+    let promise: Promise = share
+        .call1(&navigator, &data)
+        .and_then(|promise| promise.dyn_into())
+        .map_err(|_| "Could not share".to_string())?;
+
+    match JsFuture::from(promise).await {
+        Ok(_) => Ok(ShareOutcome::Shared),
+        Err(error) => {
+            let name = Reflect::get(&error, &JsValue::from_str("name"))
+                .ok()
+                .and_then(|name| name.as_string())
+                .unwrap_or_default();
+            if name == "AbortError" {
+                return Ok(ShareOutcome::Cancelled);
+            }
+            // e.g. NotAllowedError when the share sheet is unavailable
+            copy_to_clipboard(url).await?;
+            Ok(ShareOutcome::Copied)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn share_urls_use_the_site() {
+        assert_eq!(share_url("abc123"), format!("{}/item/abc123", app_config().site_url));
+    }
+
+    #[wasm_bindgen_test]
+    fn embed_code_frames_the_player() {
+        let code = embed_code("abc123", "Faith & \"Works\"");
+        assert!(code.starts_with(&format!("<iframe src=\"{}/embed/abc123\"", app_config().site_url)));
+        assert!(code.contains("title=\"Faith &amp; &quot;Works&quot;\""));
+        assert!(code.contains(EMBED_RESIZE_MESSAGE));
+    }
+
+    #[wasm_bindgen_test]
+    fn previews_are_cut_at_a_word() {
+        assert_eq!(preview_text("  Grace\n and   truth "), "Grace and truth");
+
+        let long = "word ".repeat(60);
+        let preview = preview_text(&long);
+        assert!(preview.ends_with("word…"));
+        assert!(preview.chars().count() <= PREVIEW_DESCRIPTION_CHARS + 1);
+    }
+}
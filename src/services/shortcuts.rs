@@ -0,0 +1,183 @@
+use std::fmt;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use wasm_bindgen::prelude::*;
+
+/// A single-key binding
+///
+/// `key` is a `KeyboardEvent.key` value such as "/", " " or "Escape";
+/// letters match regardless of case.
+#[derive(Clone)]
+pub struct Shortcut {
+    pub key: &'static str,
+    pub description: &'static str,
+    handler: Rc<dyn Fn()>,
+}
+
+impl Shortcut {
+    pub fn new(key: &'static str, description: &'static str, handler: impl Fn() + 'static) -> Self {
+        Self { key, description, handler: Rc::new(handler) }
+    }
+
+    fn matches(&self, key: &str) -> bool {
+        self.key.eq_ignore_ascii_case(key)
+    }
+}
+
+/// Key name as shown in the help overlay
+pub fn key_label(key: &str) -> String {
+    match key {
+        " " => "Space".to_string(),
+        "Escape" => "Esc".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// A registered binding, for listing in the help overlay
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutInfo {
+    pub key: &'static str,
+    pub description: &'static str,
+}
+
+/// A binding refused because its key is already taken
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutConflict {
+    pub key: &'static str,
+    /// What the key already does
+    pub existing: &'static str,
+    /// What the refused binding would have done
+    pub attempted: &'static str,
+}
+
+impl fmt::Display for ShortcutConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\"{}\" is already bound to \"{}\", ignoring \"{}\"",
+            key_label(self.key),
+            self.existing,
+            self.attempted
+        )
+    }
+}
+
+impl std::error::Error for ShortcutConflict {}
+
+/// Handle for removing a registered binding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShortcutId(u64);
+
+// Active bindings in registration order, and the next ID
+fn use_registry_state() -> &'static GlobalSignal<(Vec<(ShortcutId, Shortcut)>, u64)> {
+    static REGISTRY: GlobalSignal<(Vec<(ShortcutId, Shortcut)>, u64)> = GlobalSignal::new(|| (Vec::new(), 0));
+    &REGISTRY
+}
+
+/// Bind a key; the first binding for a key wins until it's removed
+pub fn register_shortcut(shortcut: Shortcut) -> Result<ShortcutId, ShortcutConflict> {
+    let mut registry = use_registry_state().write();
+    if let Some((_, existing)) = registry.0.iter().find(|(_, existing)| existing.matches(shortcut.key)) {
+        return Err(ShortcutConflict {
+            key: shortcut.key,
+            existing: existing.description,
+            attempted: shortcut.description,
+        });
+    }
+    let id = ShortcutId(registry.1);
+    registry.1 += 1;
+    registry.0.push((id, shortcut));
+    Ok(id)
+}
+
+pub fn unregister_shortcut(id: ShortcutId) {
+    use_registry_state().write().0.retain(|(registered, _)| *registered != id);
+}
+
+/// Bind keys for as long as the calling component is mounted
+///
+/// Bindings whose key is already taken are skipped with a console warning.
+pub fn use_keyboard_shortcuts(shortcuts: impl FnOnce() -> Vec<Shortcut>) {
+    let ids = use_hook(move || {
+        shortcuts()
+            .into_iter()
+            .filter_map(|shortcut| match register_shortcut(shortcut) {
+                Ok(id) => Some(id),
+                Err(conflict) => {
+                    log::warn!("⌨️ Shortcut conflict: {}", conflict);
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+    use_drop(move || {
+        for id in ids.iter() {
+            unregister_shortcut(*id);
+        }
+    });
+}
+
+/// Hook returning the active bindings
+pub fn use_registered_shortcuts() -> Memo<Vec<ShortcutInfo>> {
+    use_memo(move || {
+        use_registry_state()
+            .read()
+            .0
+            .iter()
+            .map(|(_, shortcut)| ShortcutInfo { key: shortcut.key, description: shortcut.description })
+            .collect()
+    })
+}
+
+/// Whether the focused element uses `key` itself, so a shortcut shouldn't
+fn target_claims_key(event: &web_sys::KeyboardEvent, key: &str) -> bool {
+    let Some(element) = event.target().and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok()) else {
+        return false;
+    };
+    if element.is_content_editable() {
+        return true;
+    }
+    match element.tag_name().to_ascii_lowercase().as_str() {
+        "input" | "textarea" | "select" => true,
+        // Space and Enter activate buttons, links and native media controls
+        "button" | "a" | "audio" | "video" => key == " " || key == "Enter",
+        _ => false,
+    }
+}
+
+fn dispatch(event: web_sys::KeyboardEvent) {
+    if event.ctrl_key() || event.meta_key() || event.alt_key() || event.is_composing() {
+        return;
+    }
+    let key = event.key();
+    // Escape is never typed, so it reaches its binding from any field
+    if key != "Escape" && target_claims_key(&event, &key) {
+        return;
+    }
+    let handler = use_registry_state()
+        .peek()
+        .0
+        .iter()
+        .find(|(_, shortcut)| shortcut.matches(&key))
+        .map(|(_, shortcut)| shortcut.handler.clone());
+    if let Some(handler) = handler {
+        event.prevent_default();
+        handler();
+    }
+}
+
+/// Route page-wide key presses to the registered bindings
+///
+/// Call once from a component that lives as long as the app; handlers run
+/// in that component's scope.
+pub fn listen_for_shortcuts() {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let dispatch = Runtime::wrap_closure(dispatch);
+    let on_keydown = Closure::<dyn FnMut(web_sys::KeyboardEvent)>::new(dispatch);
+    let _ = document.add_event_listener_with_callback("keydown", on_keydown.as_ref().unchecked_ref());
+    // Lives as long as the page
+    on_keydown.forget();
+}
@@ -0,0 +1,197 @@
+use rexie::{ObjectStore, Rexie, TransactionMode};
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::config::app_config;
+use crate::utils::constants::DB_NAME;
+
+/// Object store holding items submitted from this browser
+pub const SUBMITTED_ITEMS_STORE: &str = "submitted_items";
+
+/// Object store holding the results of past archive health checks
+pub const HEALTH_HISTORY_STORE: &str = "health_history";
+
+/// Object store holding cached content bytes
+pub const CONTENT_CACHE_STORE: &str = "content_cache";
+
+/// Object store holding size and last access of cached content, used for
+/// LRU eviction without loading the cached bytes
+pub const CONTENT_CACHE_INDEX_STORE: &str = "content_cache_index";
+
+/// Object store holding metadata documents of the local search index
+pub const SEARCH_DOCUMENTS_STORE: &str = "search_documents";
+
+/// Object store holding the user's study notes
+pub const STUDY_NOTES_STORE: &str = "study_notes";
+
+/// Object store holding favorites and watch-later bookmarks
+pub const BOOKMARKS_STORE: &str = "bookmarks";
+
+/// Object store holding the opt-in local pseudonymous signing key
+pub const IDENTITY_STORE: &str = "identity";
+
+/// Object store holding listening/watching positions per item
+pub const PLAYBACK_STORE: &str = "playback_positions";
+
+/// Object store holding the app-wide preferences record
+pub const SETTINGS_STORE: &str = "settings";
+
+/// Object store holding gateway responses cached by `HttpCache`
+pub const HTTP_CACHE_STORE: &str = "http_cache";
+
+/// Object store holding opt-in usage stats, one record per day
+pub const USAGE_STATS_STORE: &str = "usage_stats";
+
+/// Object store holding receipts of items uploaded from this browser
+pub const RECEIPTS_STORE: &str = "upload_receipts";
+
+/// Object store holding webhook deliveries, keyed by delivery ID
+pub const OUTBOX_STORE: &str = "webhook_outbox";
+
+/// Object store holding in-app notifications, keyed by notification ID
+pub const NOTIFICATIONS_STORE: &str = "notifications";
+
+/// Object store holding the last computed platform statistics
+pub const PLATFORM_STATS_STORE: &str = "platform_stats";
+
+/// Bump whenever a store is added to `STORES`
+const DB_VERSION: u32 = 15;
+
+/// All object stores created in the database. Keys are supplied out-of-line
+/// so every store can be addressed by an arbitrary string key.
+const STORES: &[&str] = &[
+    SUBMITTED_ITEMS_STORE,
+    HEALTH_HISTORY_STORE,
+    CONTENT_CACHE_STORE,
+    CONTENT_CACHE_INDEX_STORE,
+    SEARCH_DOCUMENTS_STORE,
+    STUDY_NOTES_STORE,
+    BOOKMARKS_STORE,
+    IDENTITY_STORE,
+    PLAYBACK_STORE,
+    SETTINGS_STORE,
+    HTTP_CACHE_STORE,
+    USAGE_STATS_STORE,
+    RECEIPTS_STORE,
+    OUTBOX_STORE,
+    NOTIFICATIONS_STORE,
+    PLATFORM_STATS_STORE,
+];
+
+#[derive(Debug, Clone)]
+pub enum StorageError {
+    OpenFailed(String),
+    TransactionFailed(String),
+    SerializationFailed(String),
+}
+
+impl std::fmt::Display for StorageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageError::OpenFailed(msg) => write!(f, "Failed to open local storage: {}", msg),
+            StorageError::TransactionFailed(msg) => write!(f, "Local storage transaction failed: {}", msg),
+            StorageError::SerializationFailed(msg) => write!(f, "Failed to serialize stored data: {}", msg),
+        }
+    }
+}
+
+impl From<rexie::Error> for StorageError {
+    fn from(error: rexie::Error) -> Self {
+        StorageError::TransactionFailed(error.to_string())
+    }
+}
+
+/// IndexedDB-backed key/value storage
+///
+/// Wraps rexie so the rest of the app can store any serde type by key
+/// without dealing with JsValue conversion or transactions.
+pub struct StorageService {
+    db: Rexie,
+}
+
+impl StorageService {
+    /// Open (and upgrade if needed) the application database
+    pub async fn open() -> Result<Self, StorageError> {
+        // Test networks get their own database so dry-run uploads never show
+        // up in the mainnet history
+        let network = app_config().network;
+        let name = if network.is_mainnet() {
+            DB_NAME.to_string()
+        } else {
+            format!("{}_{}", DB_NAME, network.storage_value())
+        };
+        let mut builder = Rexie::builder(&name).version(DB_VERSION);
+        for store in STORES {
+            builder = builder.add_object_store(ObjectStore::new(store));
+        }
+
+        let db = builder
+            .build()
+            .await
+            .map_err(|e| StorageError::OpenFailed(e.to_string()))?;
+
+        Ok(Self { db })
+    }
+
+    /// Insert or replace a value under `key`
+    pub async fn put<T: Serialize>(&self, store: &str, key: &str, value: &T) -> Result<(), StorageError> {
+        let js_value = to_js(value)?;
+        let transaction = self.db.transaction(&[store], TransactionMode::ReadWrite)?;
+        let object_store = transaction.store(store)?;
+        object_store.put(&js_value, Some(&JsValue::from_str(key))).await?;
+        transaction.done().await?;
+        Ok(())
+    }
+
+    /// Get a value by key
+    pub async fn get<T: DeserializeOwned>(&self, store: &str, key: &str) -> Result<Option<T>, StorageError> {
+        let transaction = self.db.transaction(&[store], TransactionMode::ReadOnly)?;
+        let object_store = transaction.store(store)?;
+        let value = object_store.get(JsValue::from_str(key)).await?;
+        transaction.done().await?;
+
+        match value {
+            Some(js_value) if !js_value.is_undefined() => Ok(Some(from_js(js_value)?)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Get every value in a store
+    pub async fn get_all<T: DeserializeOwned>(&self, store: &str) -> Result<Vec<T>, StorageError> {
+        let transaction = self.db.transaction(&[store], TransactionMode::ReadOnly)?;
+        let object_store = transaction.store(store)?;
+        let values = object_store.get_all(None, None).await?;
+        transaction.done().await?;
+
+        values.into_iter().map(from_js).collect()
+    }
+
+    /// Delete a value by key
+    pub async fn delete(&self, store: &str, key: &str) -> Result<(), StorageError> {
+        let transaction = self.db.transaction(&[store], TransactionMode::ReadWrite)?;
+        let object_store = transaction.store(store)?;
+        object_store.delete(JsValue::from_str(key)).await?;
+        transaction.done().await?;
+        Ok(())
+    }
+
+    /// Remove every value in a store
+    pub async fn clear(&self, store: &str) -> Result<(), StorageError> {
+        let transaction = self.db.transaction(&[store], TransactionMode::ReadWrite)?;
+        let object_store = transaction.store(store)?;
+        object_store.clear().await?;
+        transaction.done().await?;
+        Ok(())
+    }
+}
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, StorageError> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| StorageError::SerializationFailed(e.to_string()))
+}
+
+fn from_js<T: DeserializeOwned>(value: JsValue) -> Result<T, StorageError> {
+    serde_wasm_bindgen::from_value(value)
+        .map_err(|e| StorageError::SerializationFailed(e.to_string()))
+}
@@ -0,0 +1,250 @@
+//! Background metadata sync
+//!
+//! Keeps what the app shows about the network current without each page
+//! polling on its own. Each `SyncTask` has its own interval; a scheduler
+//! wakes every `SYNC_TICK_MS` while the tab is visible and runs every task
+//! that is due in one pass. Regaining focus runs whatever hasn't synced in
+//! the last `SYNC_FOCUS_MIN_GAP_MS`, and "Sync now" runs everything.
+//!
+//! Triggers that arrive while a pass is running are dropped, so a timer
+//! tick and a focus event never double up. With data saver on, intervals
+//! are `DATA_SAVER_SYNC_MULTIPLIER` times longer and focus only runs tasks
+//! that are already due.
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use std::collections::HashMap;
+use wasm_bindgen::{closure::Closure, JsCast};
+
+use crate::services::confirmation::ConfirmationService;
+use crate::services::follows::{followed, following_feed};
+use crate::services::hide_registry::HideRegistry;
+use crate::services::notifications::NotificationService;
+use crate::services::outbox::Outbox;
+use crate::services::revisions::RevisionRegistry;
+use crate::services::settings::current_settings;
+use crate::services::wallet::connected_address;
+use crate::utils::constants::{
+    CONFIRMATION_POLL_INTERVAL_MS, DATA_SAVER_SYNC_MULTIPLIER, METADATA_SYNC_INTERVAL_MS, OUTBOX_SYNC_INTERVAL_MS, SYNC_FOCUS_MIN_GAP_MS,
+    SYNC_TICK_MS,
+};
+
+/// Something the scheduler keeps up to date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SyncTask {
+    /// Confirmation statuses of items uploaded from this browser
+    Confirmations,
+    /// Moderation hide registry
+    ModerationRegistry,
+    /// Uploaders' replacement and withdrawal records
+    Revisions,
+    /// New-item count of the Following feed
+    FollowedFeeds,
+    /// Approval webhooks waiting to be sent or retried
+    Outbox,
+}
+
+impl SyncTask {
+    /// In the order a pass runs them; the outbox reads the registry, so it
+    /// comes after it
+    pub const ALL: [SyncTask; 5] = [
+        SyncTask::Confirmations,
+        SyncTask::ModerationRegistry,
+        SyncTask::Revisions,
+        SyncTask::FollowedFeeds,
+        SyncTask::Outbox,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            SyncTask::Confirmations => "Upload confirmations",
+            SyncTask::ModerationRegistry => "Moderation registry",
+            SyncTask::Revisions => "Replaced and withdrawn items",
+            SyncTask::FollowedFeeds => "Following feed",
+            SyncTask::Outbox => "Approval webhooks",
+        }
+    }
+
+    fn interval_ms(&self, data_saver: bool) -> f64 {
+        let interval = match self {
+            SyncTask::Confirmations => CONFIRMATION_POLL_INTERVAL_MS,
+            SyncTask::ModerationRegistry | SyncTask::Revisions | SyncTask::FollowedFeeds => METADATA_SYNC_INTERVAL_MS,
+            SyncTask::Outbox => OUTBOX_SYNC_INTERVAL_MS,
+        };
+        let multiplier = if data_saver { DATA_SAVER_SYNC_MULTIPLIER } else { 1 };
+        (interval * multiplier) as f64
+    }
+
+    async fn run(&self) -> Result<(), String> {
+        match self {
+            SyncTask::Confirmations => {
+                ConfirmationService::new().poll_once().await;
+                Ok(())
+            }
+            SyncTask::ModerationRegistry => {
+                HideRegistry::new().refresh().await.map_err(|e| e.to_string())?;
+                match connected_address() {
+                    Some(owner) => NotificationService::moderation_decisions(&owner).await.map_err(|e| e.to_string()),
+                    None => Ok(()),
+                }
+            }
+            SyncTask::Revisions => RevisionRegistry::new().refresh().await.map_err(|e| e.to_string()),
+            SyncTask::FollowedFeeds if followed().is_empty() => Ok(()),
+            SyncTask::FollowedFeeds => {
+                let items = following_feed().await.map_err(|e| e.to_string())?;
+                NotificationService::followed_items(&items).await.map_err(|e| e.to_string())
+            }
+            SyncTask::Outbox => Outbox::sync().await.map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// What started a sync pass
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncTrigger {
+    Timer,
+    Focus,
+    Manual,
+}
+
+/// Where background sync is up to
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncStatus {
+    pub running: bool,
+    /// When the last pass finished (ms since the epoch)
+    pub last_synced: Option<f64>,
+    /// When each task last ran (ms since the epoch)
+    pub last_run: HashMap<SyncTask, f64>,
+    /// Tasks whose last run failed, with the error
+    pub errors: HashMap<SyncTask, String>,
+}
+
+fn use_sync_state() -> &'static GlobalSignal<SyncStatus> {
+    static SYNC_STATUS: GlobalSignal<SyncStatus> = GlobalSignal::new(SyncStatus::default);
+    &SYNC_STATUS
+}
+
+/// Tasks a `trigger` at `now` should run, given when each last ran
+pub fn due_tasks(trigger: SyncTrigger, last_run: &HashMap<SyncTask, f64>, now: f64, data_saver: bool) -> Vec<SyncTask> {
+    SyncTask::ALL
+        .into_iter()
+        .filter(|task| {
+            let Some(last) = last_run.get(task) else {
+                return true;
+            };
+            let age = now - last;
+            match trigger {
+                SyncTrigger::Manual => true,
+                SyncTrigger::Focus if !data_saver => age >= SYNC_FOCUS_MIN_GAP_MS as f64,
+                SyncTrigger::Focus | SyncTrigger::Timer => age >= task.interval_ms(data_saver),
+            }
+        })
+        .collect()
+}
+
+/// Run the tasks `trigger` makes due, unless a pass is already running
+pub fn request_sync(trigger: SyncTrigger) {
+    let tasks = {
+        let status = use_sync_state().peek();
+        if status.running {
+            return;
+        }
+        due_tasks(trigger, &status.last_run, js_sys::Date::now(), current_settings().data_saver)
+    };
+    if tasks.is_empty() {
+        return;
+    }
+    use_sync_state().write().running = true;
+
+    spawn_forever(async move {
+        let mut results = Vec::new();
+        for task in tasks {
+            let result = task.run().await;
+            if let Err(e) = &result {
+                log::warn!("🔄 {} sync failed: {}", task.display_name(), e);
+            }
+            results.push((task, result));
+        }
+
+        let finished = js_sys::Date::now();
+        let mut status = use_sync_state().write();
+        status.running = false;
+        status.last_synced = Some(finished);
+        for (task, result) in results {
+            status.last_run.insert(task, finished);
+            match result {
+                Ok(()) => status.errors.remove(&task),
+                Err(e) => status.errors.insert(task, e),
+            };
+        }
+    });
+}
+
+/// Sync everything now, e.g. from the header indicator
+pub fn sync_now() {
+    request_sync(SyncTrigger::Manual);
+}
+
+fn page_hidden() -> bool {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .is_some_and(|document| document.hidden())
+}
+
+fn listen_for_focus() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let on_focus = Closure::<dyn FnMut(web_sys::Event)>::new(|_| {
+        if !page_hidden() {
+            request_sync(SyncTrigger::Focus);
+        }
+    });
+    let _ = window.add_event_listener_with_callback("focus", on_focus.as_ref().unchecked_ref());
+    if let Some(document) = window.document() {
+        let _ = document.add_event_listener_with_callback("visibilitychange", on_focus.as_ref().unchecked_ref());
+    }
+    // Lives as long as the page
+    on_focus.forget();
+}
+
+/// Restore tracked uploads and start syncing; runs for the lifetime of the
+/// app
+pub fn init_background_sync() {
+    listen_for_focus();
+    spawn(async {
+        ConfirmationService::load_persisted().await;
+        loop {
+            if !page_hidden() {
+                request_sync(SyncTrigger::Timer);
+            }
+            TimeoutFuture::new(SYNC_TICK_MS).await;
+        }
+    });
+}
+
+/// Hook returning the background sync status
+pub fn use_sync_status() -> Memo<SyncStatus> {
+    use_memo(move || use_sync_state().read().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn tasks_come_due_on_their_own_intervals() {
+        let now = 10_000_000.0;
+        let two_minutes_ago = now - 120_000.0;
+        let last_run: HashMap<SyncTask, f64> = SyncTask::ALL.into_iter().map(|task| (task, two_minutes_ago)).collect();
+
+        assert_eq!(due_tasks(SyncTrigger::Timer, &HashMap::new(), now, false), SyncTask::ALL.to_vec());
+        assert_eq!(due_tasks(SyncTrigger::Timer, &last_run, now, false), vec![SyncTask::Confirmations]);
+        assert!(due_tasks(SyncTrigger::Timer, &last_run, now, true).is_empty());
+        assert_eq!(due_tasks(SyncTrigger::Focus, &last_run, now, false), SyncTask::ALL.to_vec());
+        // Data saver keeps focus from syncing anything early
+        assert!(due_tasks(SyncTrigger::Focus, &last_run, now, true).is_empty());
+        assert_eq!(due_tasks(SyncTrigger::Manual, &last_run, now, true), SyncTask::ALL.to_vec());
+    }
+}
@@ -0,0 +1,138 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+/// localStorage key for the chosen theme; read by the pre-paint script in
+/// public/index.html too, so keep the two in sync
+const THEME_KEY: &str = "faithful_archive_theme";
+
+/// Media query matching an OS-level dark preference
+const PREFERS_DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// Color scheme chosen by the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+    Light,
+    Dark,
+    /// Follow the operating system
+    #[default]
+    System,
+}
+
+impl ThemeMode {
+    pub const ALL: [ThemeMode; 3] = [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System];
+
+    pub fn storage_value(&self) -> &'static str {
+        match self {
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+            ThemeMode::System => "system",
+        }
+    }
+
+    pub fn from_storage_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.storage_value() == value)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ThemeMode::Light => "Light",
+            ThemeMode::Dark => "Dark",
+            ThemeMode::System => "System",
+        }
+    }
+
+    /// The mode after this one in the header toggle
+    pub fn next(&self) -> Self {
+        match self {
+            ThemeMode::Light => ThemeMode::Dark,
+            ThemeMode::Dark => ThemeMode::System,
+            ThemeMode::System => ThemeMode::Light,
+        }
+    }
+}
+
+/// The chosen mode and what it currently resolves to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub mode: ThemeMode,
+    pub is_dark: bool,
+}
+
+fn load_mode() -> ThemeMode {
+    web_sys::window()
+        .and_then(|window| window.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(THEME_KEY).ok().flatten())
+        .and_then(|value| ThemeMode::from_storage_value(&value))
+        .unwrap_or_default()
+}
+
+fn system_prefers_dark() -> bool {
+    web_sys::window()
+        .and_then(|window| window.match_media(PREFERS_DARK_QUERY).ok().flatten())
+        .is_some_and(|query| query.matches())
+}
+
+fn use_theme_mode_state() -> &'static GlobalSignal<ThemeMode> {
+    static THEME_MODE: GlobalSignal<ThemeMode> = GlobalSignal::new(load_mode);
+    &THEME_MODE
+}
+
+// Tracks the OS preference so System mode follows it live
+fn use_system_dark_state() -> &'static GlobalSignal<bool> {
+    static SYSTEM_DARK: GlobalSignal<bool> = GlobalSignal::new(system_prefers_dark);
+    &SYSTEM_DARK
+}
+
+/// The chosen mode, without subscribing to changes
+pub fn theme_mode() -> ThemeMode {
+    *use_theme_mode_state().peek()
+}
+
+/// Choose and persist a theme
+///
+/// UI should go through `update_settings` so the choice is saved with the
+/// other preferences.
+pub fn set_theme_mode(mode: ThemeMode) {
+    if let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) {
+        let _ = storage.set_item(THEME_KEY, mode.storage_value());
+    }
+    *use_theme_mode_state().write() = mode;
+}
+
+/// Put the `dark` class Tailwind's `dark:` variant keys on onto `<html>`
+pub fn apply_theme(theme: Theme) {
+    let root = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element());
+    if let Some(root) = root {
+        let _ = root.class_list().toggle_with_force("dark", theme.is_dark);
+    }
+}
+
+/// Follow changes to the OS color scheme for System mode
+pub fn watch_system_theme() {
+    let Some(query) = web_sys::window().and_then(|window| window.match_media(PREFERS_DARK_QUERY).ok().flatten()) else {
+        return;
+    };
+    let on_change = Closure::<dyn FnMut()>::new(move || {
+        *use_system_dark_state().write() = system_prefers_dark();
+    });
+    query.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+    // Lives as long as the page
+    on_change.forget();
+}
+
+/// Hook returning the current theme
+pub fn use_theme() -> Memo<Theme> {
+    use_memo(move || {
+        let mode = *use_theme_mode_state().read();
+        let is_dark = match mode {
+            ThemeMode::Light => false,
+            ThemeMode::Dark => true,
+            ThemeMode::System => *use_system_dark_state().read(),
+        };
+        Theme { mode, is_dark }
+    })
+}
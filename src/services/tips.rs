@@ -0,0 +1,200 @@
+//! Tips to uploaders
+//!
+//! "Support this ministry" sends AR or AO from the connected wallet to the
+//! `payment_address` in the uploader's profile. AR goes as a plain Arweave
+//! transfer, which costs a network fee; AO is a `Transfer` message to the
+//! AO token process, which costs nothing to send.
+
+use crate::config::app_config;
+use crate::services::ao::{send_message, AoError};
+use crate::services::native_tx::{NativeTxError, NativeTxService};
+use crate::services::pricing::PricingService;
+use crate::services::wallet::{connected_wallet, WalletError};
+use crate::utils::constants::APP_NAME;
+
+/// AR (in winston) and AO both have 12 decimal places
+const DECIMALS: usize = 12;
+const BASE_UNITS_PER_TOKEN: u128 = 1_000_000_000_000;
+
+/// What a tip is paid in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TipAsset {
+    Ar,
+    Ao,
+}
+
+impl TipAsset {
+    pub const ALL: [TipAsset; 2] = [TipAsset::Ar, TipAsset::Ao];
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            TipAsset::Ar => "AR",
+            TipAsset::Ao => "AO",
+        }
+    }
+
+    /// Amounts offered as one-click choices
+    pub fn presets(&self) -> &'static [&'static str] {
+        match self {
+            TipAsset::Ar => &["0.01", "0.05", "0.1"],
+            TipAsset::Ao => &["0.1", "0.5", "1"],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum TipError {
+    InvalidAmount(String),
+    FeeLookupFailed(String),
+    SigningFailed(WalletError),
+    SendFailed(String),
+}
+
+impl std::fmt::Display for TipError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TipError::InvalidAmount(msg) => write!(f, "{}", msg),
+            TipError::FeeLookupFailed(msg) => write!(f, "Could not look up the network fee: {}", msg),
+            TipError::SigningFailed(e) => write!(f, "Could not sign the tip: {}", e),
+            TipError::SendFailed(msg) => write!(f, "Could not send the tip: {}", msg),
+        }
+    }
+}
+
+impl From<NativeTxError> for TipError {
+    fn from(error: NativeTxError) -> Self {
+        match error {
+            NativeTxError::Signing(e) => TipError::SigningFailed(e),
+            NativeTxError::Pricing(e) => TipError::FeeLookupFailed(e.to_string()),
+            e => TipError::SendFailed(e.to_string()),
+        }
+    }
+}
+
+/// "0.05" -> 50_000_000_000 base units (winston or AO's smallest unit)
+pub fn parse_amount(text: &str) -> Result<u128, TipError> {
+    let invalid = || TipError::InvalidAmount(format!("{} is not an amount", text.trim()));
+    let text = text.trim();
+    let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid());
+    }
+    if !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    if fraction.len() > DECIMALS {
+        return Err(TipError::InvalidAmount(format!("Use at most {} decimal places", DECIMALS)));
+    }
+
+    let whole: u128 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| invalid())? };
+    let fraction: u128 = format!("{:0<width$}", fraction, width = DECIMALS).parse().map_err(|_| invalid())?;
+    let amount = whole
+        .checked_mul(BASE_UNITS_PER_TOKEN)
+        .and_then(|units| units.checked_add(fraction))
+        .ok_or_else(invalid)?;
+    if amount == 0 {
+        return Err(TipError::InvalidAmount("Enter an amount above zero".to_string()));
+    }
+    Ok(amount)
+}
+
+/// 50_000_000_000 -> "0.05"
+pub fn format_amount(units: u128) -> String {
+    let whole = units / BASE_UNITS_PER_TOKEN;
+    let fraction = units % BASE_UNITS_PER_TOKEN;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let fraction = format!("{:0>width$}", fraction, width = DECIMALS);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
+
+/// Sends tips from the connected wallet
+pub struct TipService;
+
+impl TipService {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Network fee on top of the tip, in the asset's base units
+    pub async fn network_fee(&self, asset: TipAsset, recipient: &str) -> Result<u128, TipError> {
+        match asset {
+            TipAsset::Ao => Ok(0),
+            TipAsset::Ar => PricingService::new()
+                .transfer_fee_winston(recipient)
+                .await
+                .map_err(|e| TipError::FeeLookupFailed(e.to_string())),
+        }
+    }
+
+    /// Send `amount` base units of `asset` to `recipient` for `content_id`,
+    /// returning the transaction or message ID as a receipt
+    pub async fn send(&self, asset: TipAsset, recipient: &str, amount: u128, content_id: &str) -> Result<String, TipError> {
+        let receipt = match asset {
+            TipAsset::Ar => self.send_ar(recipient, amount, content_id).await?,
+            TipAsset::Ao => {
+                let tags = vec![
+                    ("Action".to_string(), "Transfer".to_string()),
+                    ("Recipient".to_string(), recipient.to_string()),
+                    ("Quantity".to_string(), amount.to_string()),
+                    // Forwarded to the recipient's Credit-Notice
+                    ("X-App-Name".to_string(), APP_NAME.to_string()),
+                    ("X-Content-Id".to_string(), content_id.to_string()),
+                ];
+                send_message(&app_config().ao_token_process, tags).await.map_err(|e| match e {
+                    AoError::SigningFailed(e) => TipError::SigningFailed(e),
+                    AoError::SendFailed(msg) | AoError::ReadFailed(msg) => TipError::SendFailed(msg),
+                })?
+            }
+        };
+        log::info!("🙏 Sent {} {} to {} ({})", format_amount(amount), asset.symbol(), recipient, receipt);
+        Ok(receipt)
+    }
+
+    // A plain transfer with no data
+    async fn send_ar(&self, recipient: &str, amount: u128, content_id: &str) -> Result<String, TipError> {
+        let wallet = connected_wallet().await.map_err(TipError::SigningFailed)?;
+        let tags = vec![
+            ("App-Name".to_string(), APP_NAME.to_string()),
+            ("Type".to_string(), "Tip".to_string()),
+            ("Content-Id".to_string(), content_id.to_string()),
+        ];
+        let transfer = NativeTxService::new().transfer(&wallet, recipient, amount, &tags).await?;
+        Ok(transfer.id)
+    }
+}
+
+impl Default for TipService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn parses_decimal_amounts() {
+        assert_eq!(parse_amount("1").unwrap(), BASE_UNITS_PER_TOKEN);
+        assert_eq!(parse_amount(" 0.05 ").unwrap(), 50_000_000_000);
+        assert_eq!(parse_amount(".5").unwrap(), 500_000_000_000);
+        assert_eq!(parse_amount("0.000000000001").unwrap(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn rejects_bad_amounts() {
+        for text in ["", ".", "0", "-1", "1e3", "0.0000000000001", "1.2.3"] {
+            assert!(parse_amount(text).is_err(), "{} should be rejected", text);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn formats_without_trailing_zeros() {
+        assert_eq!(format_amount(50_000_000_000), "0.05");
+        assert_eq!(format_amount(2 * BASE_UNITS_PER_TOKEN), "2");
+        assert_eq!(format_amount(1), "0.000000000001");
+    }
+}
@@ -0,0 +1,84 @@
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+use crate::error::AppError;
+
+/// How long a toast stays up before dismissing itself
+const TOAST_DURATION_MS: u32 = 6_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Success,
+    Info,
+    Error,
+}
+
+/// A short notice shown in the corner of every page
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub id: u64,
+    pub kind: ToastKind,
+    pub message: String,
+    /// `AppError` code, for error toasts
+    pub code: Option<&'static str>,
+    pub action: Option<ToastAction>,
+}
+
+/// Button on a toast; clicking it runs `on_click` and dismisses the toast
+#[derive(Clone, PartialEq)]
+pub struct ToastAction {
+    pub label: String,
+    pub on_click: Callback<()>,
+}
+
+impl std::fmt::Debug for ToastAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToastAction").field("label", &self.label).finish_non_exhaustive()
+    }
+}
+
+// Visible toasts, oldest first, and the next toast ID
+fn use_toast_state() -> &'static GlobalSignal<(Vec<Toast>, u64)> {
+    static TOASTS: GlobalSignal<(Vec<Toast>, u64)> = GlobalSignal::new(|| (Vec::new(), 0));
+    &TOASTS
+}
+
+fn push(kind: ToastKind, message: String, code: Option<&'static str>, action: Option<ToastAction>, duration_ms: u32) -> u64 {
+    let id = {
+        let mut state = use_toast_state().write();
+        let id = state.1;
+        state.1 += 1;
+        state.0.push(Toast { id, kind, message, code, action });
+        id
+    };
+    spawn(async move {
+        TimeoutFuture::new(duration_ms).await;
+        dismiss_toast(id);
+    });
+    id
+}
+
+pub fn show_toast(kind: ToastKind, message: impl Into<String>) {
+    push(kind, message.into(), None, None, TOAST_DURATION_MS);
+}
+
+/// Show a toast with a button for `duration_ms`, returning its ID so the
+/// caller can dismiss it early
+pub fn show_action_toast(kind: ToastKind, message: impl Into<String>, action: ToastAction, duration_ms: u32) -> u64 {
+    push(kind, message.into(), None, Some(action), duration_ms)
+}
+
+/// Show an error's user message, logging its detail to the console
+pub fn show_error(error: &AppError) {
+    log::error!("❌ [{}] {}", error.code(), error.debug_detail());
+    push(ToastKind::Error, error.user_message(), Some(error.code()), None, TOAST_DURATION_MS);
+}
+
+pub fn dismiss_toast(id: u64) {
+    use_toast_state().write().0.retain(|toast| toast.id != id);
+}
+
+/// Hook returning the visible toasts
+pub fn use_toasts() -> Memo<Vec<Toast>> {
+    use_memo(move || use_toast_state().read().0.clone())
+}
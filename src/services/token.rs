@@ -0,0 +1,78 @@
+//! Short-lived auth tokens for signed actions
+//!
+//! Signing a fresh login challenge for every moderation entry or AO message
+//! would mean a wallet prompt per action, so a signed challenge with an
+//! expiry is cached per audience and reused until shortly before it runs
+//! out. Each request then carries its own `Request-Nonce`, covered by the
+//! DataItem signature, so a receiver that remembers nonces it has seen can
+//! refuse a replayed action even while the token is still valid.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::services::auth::{sign_challenge, AuthError, SignedChallenge};
+use crate::services::wallet::connected_wallet;
+
+/// How long a token is valid for (seconds)
+pub const TOKEN_LIFETIME_SECS: i64 = 10 * 60;
+/// Sign a new token when the cached one has less than this left (seconds)
+const RENEW_MARGIN_SECS: i64 = 60;
+
+thread_local! {
+    // Keyed by (address, audience) so switching accounts never reuses a token
+    static TOKENS: RefCell<HashMap<(String, String), SignedChallenge>> = RefCell::new(HashMap::new());
+}
+
+/// Whether a token expiring at `expires_at` can still be sent at `now`
+pub fn is_usable(expires_at: Option<i64>, now: i64) -> bool {
+    matches!(expires_at, Some(expires_at) if now + RENEW_MARGIN_SECS < expires_at)
+}
+
+/// A valid token for `audience`, signing a new one if needed
+pub async fn auth_token(audience: &str) -> Result<SignedChallenge, AuthError> {
+    let address = connected_wallet()
+        .await
+        .map_err(AuthError::Wallet)?
+        .get_active_address()
+        .await
+        .map_err(|_| AuthError::NotConnected)?;
+    let key = (address, audience.to_string());
+    let now = chrono::Utc::now().timestamp();
+
+    let cached = TOKENS.with(|tokens| tokens.borrow().get(&key).cloned());
+    if let Some(token) = cached.filter(|token| is_usable(token.challenge.expires_at, now)) {
+        return Ok(token);
+    }
+
+    let token = sign_challenge(audience, Some(now + TOKEN_LIFETIME_SECS)).await?;
+    TOKENS.with(|tokens| tokens.borrow_mut().insert(key, token.clone()));
+    Ok(token)
+}
+
+/// Token tags for `audience` plus a fresh `Request-Nonce`, to add to the
+/// tags of the DataItem being signed
+pub async fn authenticated_tags(audience: &str) -> Result<Vec<(String, String)>, AuthError> {
+    let mut tags = auth_token(audience).await?.to_tags();
+    tags.push(("Request-Nonce".to_string(), uuid::Uuid::new_v4().simple().to_string()));
+    Ok(tags)
+}
+
+/// Forget every cached token, e.g. when the wallet disconnects
+pub fn clear_tokens() {
+    TOKENS.with(|tokens| tokens.borrow_mut().clear());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn tokens_are_renewed_before_expiry() {
+        let now = 1_700_000_000;
+        assert!(is_usable(Some(now + TOKEN_LIFETIME_SECS), now));
+        assert!(!is_usable(Some(now + RENEW_MARGIN_SECS), now));
+        assert!(!is_usable(Some(now - 1), now));
+        assert!(!is_usable(None, now));
+    }
+}
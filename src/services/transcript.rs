@@ -0,0 +1,170 @@
+use serde::Deserialize;
+
+use crate::services::content_fetch::{ContentFetchError, ContentFetchService};
+
+/// `Type` tag of a transcript DataItem
+pub const TRANSCRIPT_TYPE: &str = "Transcript";
+
+#[derive(Debug, Clone)]
+pub enum TranscriptError {
+    FetchFailed(ContentFetchError),
+    NotText,
+    InvalidFormat(String),
+}
+
+impl std::fmt::Display for TranscriptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TranscriptError::FetchFailed(e) => write!(f, "Could not load transcript: {}", e),
+            TranscriptError::NotText => write!(f, "Transcript is not valid UTF-8 text"),
+            TranscriptError::InvalidFormat(msg) => write!(f, "Transcript could not be read: {}", msg),
+        }
+    }
+}
+
+/// One cue or paragraph of a transcript
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TranscriptSegment {
+    /// Start time in seconds; `None` for plain-text transcripts
+    #[serde(default, alias = "start_secs")]
+    pub start: Option<f64>,
+    #[serde(default, alias = "end_secs")]
+    pub end: Option<f64>,
+    pub text: String,
+}
+
+/// JSON transcripts are either a bare segment list or `{ "segments": [...] }`
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonTranscript {
+    Segments(Vec<TranscriptSegment>),
+    Wrapped { segments: Vec<TranscriptSegment> },
+}
+
+/// Transcript of an item, as timed cues (WebVTT, JSON) or plain paragraphs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transcript {
+    pub tx_id: String,
+    pub segments: Vec<TranscriptSegment>,
+}
+
+impl Transcript {
+    /// Parse WebVTT, JSON or plain text, detected from the content
+    pub fn parse(tx_id: &str, text: &str) -> Result<Self, TranscriptError> {
+        let trimmed = text.trim_start_matches('\u{feff}').trim_start();
+        let segments = if trimmed.starts_with("WEBVTT") {
+            parse_webvtt(trimmed)
+        } else if trimmed.starts_with('[') || trimmed.starts_with('{') {
+            match serde_json::from_str(trimmed).map_err(|e| TranscriptError::InvalidFormat(e.to_string()))? {
+                JsonTranscript::Segments(segments) | JsonTranscript::Wrapped { segments } => segments,
+            }
+        } else {
+            parse_paragraphs(trimmed)
+        };
+
+        Ok(Self {
+            tx_id: tx_id.to_string(),
+            segments,
+        })
+    }
+
+    /// Whether segments carry timestamps for playback sync
+    pub fn is_timed(&self) -> bool {
+        self.segments.iter().any(|segment| segment.start.is_some())
+    }
+
+    /// Index of the segment being spoken at `secs`
+    pub fn segment_at(&self, secs: f64) -> Option<usize> {
+        self.segments
+            .iter()
+            .rposition(|segment| segment.start.is_some_and(|start| start <= secs))
+            .filter(|&index| self.segments[index].end.is_none_or(|end| secs < end || index + 1 == self.segments.len()))
+    }
+}
+
+fn parse_paragraphs(text: &str) -> Vec<TranscriptSegment> {
+    text.split("\n\n")
+        .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|text| TranscriptSegment { start: None, end: None, text })
+        .collect()
+}
+
+/// Cues of a WebVTT file; cue identifiers, settings, NOTE and STYLE
+/// blocks are skipped and inline tags such as `<v Speaker>` are stripped
+fn parse_webvtt(text: &str) -> Vec<TranscriptSegment> {
+    let normalized = text.replace("\r\n", "\n");
+    normalized
+        .split("\n\n")
+        .filter_map(|block| {
+            let mut lines = block.lines().skip_while(|line| !line.contains("-->"));
+            let timing = lines.next()?;
+            let (start, rest) = timing.split_once("-->")?;
+            let end = rest.split_whitespace().next()?;
+            let text = lines
+                .map(strip_tags)
+                .collect::<Vec<_>>()
+                .join(" ")
+                .trim()
+                .to_string();
+            if text.is_empty() {
+                return None;
+            }
+            Some(TranscriptSegment {
+                start: Some(parse_timestamp(start.trim())?),
+                end: parse_timestamp(end.trim()),
+                text,
+            })
+        })
+        .collect()
+}
+
+/// "01:02:03.500" or "02:03.500" in seconds
+fn parse_timestamp(value: &str) -> Option<f64> {
+    value
+        .split(':')
+        .try_fold(0.0, |total, part| Some(total * 60.0 + part.replace(',', ".").parse::<f64>().ok()?))
+}
+
+fn strip_tags(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut in_tag = false;
+    for c in line.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Loads transcripts published as separate DataItems
+pub struct TranscriptService {
+    fetcher: ContentFetchService,
+}
+
+impl TranscriptService {
+    pub fn new() -> Self {
+        Self {
+            fetcher: ContentFetchService::new(),
+        }
+    }
+
+    /// Load the transcript DataItem `transcript_tx_id`
+    pub async fn load(&self, transcript_tx_id: &str) -> Result<Transcript, TranscriptError> {
+        let content = self.fetcher
+            .fetch(transcript_tx_id)
+            .await
+            .map_err(TranscriptError::FetchFailed)?;
+        let text = String::from_utf8(content.bytes).map_err(|_| TranscriptError::NotText)?;
+        Transcript::parse(transcript_tx_id, &text)
+    }
+}
+
+impl Default for TranscriptService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,152 @@
+//! Pausing, cancelling and throttling uploads
+//!
+//! Uploads post their data in pieces: layer-1 chunks, or bundler chunks
+//! for large DataItems. Before each piece they wait at their
+//! `TransferControl`'s checkpoint, which holds while the upload is paused
+//! and stops it once it's cancelled, and after each one they `pace`,
+//! sleeping long enough to keep the average speed under the upload limit
+//! in Settings. A church office sharing one connection can then let a
+//! batch of sermons upload without swamping everyone else.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gloo_timers::future::TimeoutFuture;
+
+use crate::services::settings::current_settings;
+
+/// Limits offered in Settings and the upload queue, in KB/s
+pub const UPLOAD_LIMIT_CHOICES: [u32; 4] = [128, 256, 512, 1024];
+/// How often a paused upload checks whether it's been resumed (ms)
+const PAUSE_POLL_MS: u32 = 250;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferState {
+    Running,
+    Paused,
+    Cancelled,
+}
+
+/// The upload was cancelled part way through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The upload was cancelled")
+    }
+}
+
+/// Pause, resume and cancel for one upload, shared by whoever shows it and
+/// the code posting it
+#[derive(Debug, Clone)]
+pub struct TransferControl {
+    state: Rc<Cell<TransferState>>,
+    /// When the current run of pacing started (ms since the epoch) and the
+    /// bytes posted since; restarted on resume so a pause isn't made up
+    /// for with a burst
+    window: Rc<Cell<Option<(f64, u64)>>>,
+}
+
+impl TransferControl {
+    pub fn new() -> Self {
+        Self { state: Rc::new(Cell::new(TransferState::Running)), window: Rc::new(Cell::new(None)) }
+    }
+
+    pub fn state(&self) -> TransferState {
+        self.state.get()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.state() == TransferState::Cancelled
+    }
+
+    /// Hold the upload at its next checkpoint
+    pub fn pause(&self) {
+        if self.state() == TransferState::Running {
+            self.state.set(TransferState::Paused);
+        }
+    }
+
+    pub fn resume(&self) {
+        if self.state() == TransferState::Paused {
+            self.window.set(None);
+            self.state.set(TransferState::Running);
+        }
+    }
+
+    /// Stop the upload at its next checkpoint; it can't be resumed
+    pub fn cancel(&self) {
+        self.state.set(TransferState::Cancelled);
+    }
+
+    /// Wait out a pause, or stop if the upload's been cancelled
+    pub async fn checkpoint(&self) -> Result<(), Cancelled> {
+        loop {
+            match self.state() {
+                TransferState::Running => return Ok(()),
+                TransferState::Cancelled => return Err(Cancelled),
+                TransferState::Paused => TimeoutFuture::new(PAUSE_POLL_MS).await,
+            }
+        }
+    }
+
+    /// Count `bytes` as posted and sleep off any time the upload is ahead
+    /// of the limit in Settings
+    pub async fn pace(&self, bytes: u64) {
+        let now = js_sys::Date::now();
+        let (started, sent) = self.window.get().unwrap_or((now, 0));
+        let sent = sent + bytes;
+        self.window.set(Some((started, sent)));
+
+        let Some(limit) = current_settings().upload_limit_kbps else {
+            return;
+        };
+        let wait = pacing_delay_ms(sent, now - started, limit);
+        if wait > 0 {
+            TimeoutFuture::new(wait).await;
+        }
+    }
+}
+
+impl Default for TransferControl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Copies share one state, so any two are equal only if they're the same
+/// upload's control
+impl PartialEq for TransferControl {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+/// How long to wait (ms) after posting `sent` bytes in `elapsed_ms` to
+/// average no more than `limit_kbps`
+fn pacing_delay_ms(sent: u64, elapsed_ms: f64, limit_kbps: u32) -> u32 {
+    let due_ms = sent as f64 / (limit_kbps.max(1) as f64 * 1024.0) * 1000.0;
+    (due_ms - elapsed_ms).max(0.0).ceil() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn pacing_keeps_the_average_under_the_limit() {
+        // 512 KB at 256 KB/s is due after two seconds
+        assert_eq!(pacing_delay_ms(512 * 1024, 500.0, 256), 1500);
+        assert_eq!(pacing_delay_ms(512 * 1024, 2500.0, 256), 0);
+
+        let control = TransferControl::new();
+        control.pause();
+        assert_eq!(control.state(), TransferState::Paused);
+        control.resume();
+        control.cancel();
+        control.resume();
+        assert!(control.clone().is_cancelled());
+    }
+}
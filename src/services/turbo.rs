@@ -0,0 +1,307 @@
+//! Turbo upload credits
+//!
+//! The default bundler is Turbo, which charges uploads above its free
+//! limit to the signer's credit balance. Credits are bought from the Turbo
+//! payment service either by card, through a hosted checkout page, or by
+//! sending AR to Turbo's wallet and telling the service about the transfer.
+//!
+//! The connected wallet's balance is kept in a global signal so the upload
+//! form and the top-up dialog agree on it; anything that changes it calls
+//! `refresh_credit_balance`.
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::config::app_config;
+use crate::services::native_tx::{NativeTxError, NativeTxService};
+use crate::services::wallet::{connected_wallet, WalletError};
+use crate::utils::constants::APP_NAME;
+
+/// How often the balance is checked while a card checkout is open
+const CHECKOUT_POLL_MS: u32 = 10_000;
+
+/// How long to keep checking before leaving it to the refresh button
+const CHECKOUT_POLL_LIMIT: u32 = 60;
+
+/// Card payments are quoted in US cents
+pub const CHECKOUT_CURRENCY: &str = "usd";
+
+/// One-click card amounts, in dollars
+pub const CHECKOUT_PRESETS: &[u32] = &[5, 10, 25];
+
+/// Smallest card payment the service takes, in dollars
+pub const MIN_CHECKOUT_USD: u32 = 5;
+
+#[derive(Debug, Clone)]
+pub enum TurboError {
+    /// No payment service for this network
+    Unavailable,
+    RequestFailed(String),
+    BadStatus(u16, String),
+    InvalidResponse(String),
+    SigningFailed(WalletError),
+    TransferFailed(NativeTxError),
+    /// The AR was sent but the service hasn't recorded the transfer;
+    /// `TurboService::claim_ar_transfer` with `tx_id` submits it again
+    ClaimFailed { tx_id: String, reason: String },
+}
+
+impl std::fmt::Display for TurboError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TurboError::Unavailable => write!(f, "Upload credits aren't available on this network"),
+            TurboError::RequestFailed(msg) => write!(f, "Could not reach the payment service: {}", msg),
+            TurboError::BadStatus(status, msg) => write!(f, "Payment service returned HTTP {}: {}", status, msg),
+            TurboError::InvalidResponse(msg) => write!(f, "Invalid payment service response: {}", msg),
+            TurboError::SigningFailed(e) => write!(f, "Could not sign the top-up: {}", e),
+            TurboError::TransferFailed(e) => write!(f, "Could not send AR for the top-up: {}", e),
+            TurboError::ClaimFailed { tx_id, reason } => {
+                write!(f, "Sent the AR ({}) but the payment service didn't record it: {}", tx_id, reason)
+            }
+        }
+    }
+}
+
+/// Last balance fetched for an address, in winc (10^12 winc = 1 credit)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreditBalance {
+    pub address: String,
+    pub winc: u128,
+}
+
+/// What an upload costs against what the wallet holds, in winc
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadEstimate {
+    pub cost_winc: u128,
+    pub balance_winc: u128,
+}
+
+impl UploadEstimate {
+    pub fn is_insufficient(&self) -> bool {
+        self.cost_winc > self.balance_winc
+    }
+
+    /// Credits still needed, in winc
+    pub fn shortfall(&self) -> u128 {
+        self.cost_winc.saturating_sub(self.balance_winc)
+    }
+}
+
+#[derive(Deserialize)]
+struct WincResponse {
+    winc: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CheckoutResponse {
+    payment_session: PaymentSession,
+}
+
+#[derive(Deserialize)]
+struct PaymentSession {
+    url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct InfoResponse {
+    addresses: InfoAddresses,
+}
+
+#[derive(Deserialize)]
+struct InfoAddresses {
+    arweave: String,
+}
+
+// Global credit balance using Dioxus signals
+fn use_credit_balance_state() -> &'static GlobalSignal<Option<CreditBalance>> {
+    static CREDIT_BALANCE: GlobalSignal<Option<CreditBalance>> = GlobalSignal::new(|| None);
+    &CREDIT_BALANCE
+}
+
+/// Hook for the connected wallet's last known credit balance
+pub fn use_credit_balance() -> Option<CreditBalance> {
+    use_credit_balance_state().read().clone()
+}
+
+fn parse_winc(winc: &str) -> Result<u128, TurboError> {
+    winc.trim().parse().map_err(|_| TurboError::InvalidResponse(format!("unexpected amount {}", winc.trim())))
+}
+
+/// Client for the Turbo payment service
+pub struct TurboService {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl TurboService {
+    pub fn new() -> Self {
+        Self::with_url(&app_config().turbo_payment_url)
+    }
+
+    pub fn with_url(url: &str) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Whether this network has a payment service at all
+    pub fn is_available(&self) -> bool {
+        !self.url.is_empty()
+    }
+
+    async fn get<T: for<'de> Deserialize<'de>>(&self, path: &str) -> Result<T, TurboError> {
+        if !self.is_available() {
+            return Err(TurboError::Unavailable);
+        }
+        let response = self.client
+            .get(format!("{}{}", self.url, path))
+            .send()
+            .await
+            .map_err(|e| TurboError::RequestFailed(e.to_string()))?;
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TurboError::BadStatus(status, body));
+        }
+        response.json().await.map_err(|e| TurboError::InvalidResponse(e.to_string()))
+    }
+
+    /// Credit balance of `address`, in winc
+    pub async fn balance(&self, address: &str) -> Result<u128, TurboError> {
+        let balance: WincResponse = self.get(&format!("/v1/account/balance/arweave?address={}", address)).await?;
+        parse_winc(&balance.winc)
+    }
+
+    /// What uploading `bytes` bytes costs, in winc, after any free allowance
+    pub async fn upload_cost(&self, bytes: u64) -> Result<u128, TurboError> {
+        let price: WincResponse = self.get(&format!("/v1/price/bytes/{}", bytes)).await?;
+        parse_winc(&price.winc)
+    }
+
+    /// Credits `winston` of AR buys, in winc
+    pub async fn credits_for_ar(&self, winston: u128) -> Result<u128, TurboError> {
+        let price: WincResponse = self.get(&format!("/v1/price/arweave/{}", winston)).await?;
+        parse_winc(&price.winc)
+    }
+
+    /// Hosted card checkout page crediting `address` with `usd` dollars
+    pub async fn checkout_url(&self, address: &str, usd: u32) -> Result<String, TurboError> {
+        let checkout: CheckoutResponse = self
+            .get(&format!("/v1/top-up/checkout-session/{}/{}/{}", address, CHECKOUT_CURRENCY, usd * 100))
+            .await?;
+        checkout
+            .payment_session
+            .url
+            .ok_or_else(|| TurboError::InvalidResponse("no checkout URL".to_string()))
+    }
+
+    /// Send `winston` of AR from the connected wallet to Turbo and submit
+    /// the transfer for credits, returning its ID. Credits arrive once
+    /// the transfer is mined.
+    ///
+    /// If the transfer goes out but can't be submitted, the error is
+    /// `TurboError::ClaimFailed` carrying its ID so it can be resubmitted.
+    pub async fn top_up_with_ar(&self, winston: u128) -> Result<String, TurboError> {
+        let info: InfoResponse = self.get("/v1/info").await?;
+        let wallet = connected_wallet().await.map_err(TurboError::SigningFailed)?;
+        let tags = vec![
+            ("App-Name".to_string(), APP_NAME.to_string()),
+            ("Type".to_string(), "Credit-Top-Up".to_string()),
+        ];
+        let transfer = NativeTxService::new()
+            .transfer(&wallet, &info.addresses.arweave, winston, &tags)
+            .await
+            .map_err(TurboError::TransferFailed)?;
+        log::info!("💳 Sent {} winston to Turbo ({})", winston, transfer.id);
+
+        self.claim_ar_transfer(&transfer.id).await.map_err(|e| TurboError::ClaimFailed {
+            tx_id: transfer.id.clone(),
+            reason: e.to_string(),
+        })?;
+        Ok(transfer.id)
+    }
+
+    /// Submit an AR transfer to Turbo's wallet for credits. The service
+    /// holds a pending transfer until it's mined, so this is safe to retry.
+    pub async fn claim_ar_transfer(&self, tx_id: &str) -> Result<(), TurboError> {
+        let response = self.client
+            .post(format!("{}/v1/account/balance/arweave", self.url))
+            .json(&json!({ "tx_id": tx_id }))
+            .send()
+            .await
+            .map_err(|e| TurboError::RequestFailed(e.to_string()))?;
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TurboError::BadStatus(status, body));
+        }
+        Ok(())
+    }
+}
+
+impl Default for TurboService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Fetch `address`'s credit balance and keep it for `use_credit_balance`
+pub async fn refresh_credit_balance(address: &str) -> Result<u128, TurboError> {
+    let winc = TurboService::new().balance(address).await?;
+    *use_credit_balance_state().write() = Some(CreditBalance { address: address.to_string(), winc });
+    Ok(winc)
+}
+
+/// Cost of uploading `bytes` bytes from `address` against its balance,
+/// refreshing the balance on the way
+pub async fn estimate_upload(address: &str, bytes: u64) -> Result<UploadEstimate, TurboError> {
+    let cost_winc = TurboService::new().upload_cost(bytes).await?;
+    // Free uploads don't need a balance lookup
+    let balance_winc = if cost_winc == 0 { 0 } else { refresh_credit_balance(address).await? };
+    Ok(UploadEstimate { cost_winc, balance_winc })
+}
+
+/// Check the balance while a card checkout is open in another tab, until
+/// it goes up or `CHECKOUT_POLL_LIMIT` checks have passed
+pub fn watch_for_top_up(address: String, before: u128) {
+    spawn(async move {
+        for _ in 0..CHECKOUT_POLL_LIMIT {
+            TimeoutFuture::new(CHECKOUT_POLL_MS).await;
+            match refresh_credit_balance(&address).await {
+                Ok(winc) if winc > before => {
+                    log::info!("💳 Credit balance of {} is now {} winc", address, winc);
+                    return;
+                }
+                Ok(_) => {}
+                Err(e) => log::debug!("Credit balance check failed: {}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn estimates_report_the_shortfall() {
+        let short = UploadEstimate { cost_winc: 300, balance_winc: 100 };
+        assert!(short.is_insufficient());
+        assert_eq!(short.shortfall(), 200);
+
+        let covered = UploadEstimate { cost_winc: 100, balance_winc: 300 };
+        assert!(!covered.is_insufficient());
+        assert_eq!(covered.shortfall(), 0);
+    }
+
+    #[wasm_bindgen_test]
+    fn parses_winc_amounts() {
+        assert_eq!(parse_winc(" 1500000000000 ").unwrap(), 1_500_000_000_000);
+        assert!(parse_winc("1.5").is_err());
+    }
+}
@@ -0,0 +1,834 @@
+use crate::config::{app_config, NetworkMode};
+use crate::models::{SeriesManifest, SubmittedItem, RightsDeclaration, UdlTerms, SPIRITUAL_CONTENT_TYPE};
+use crate::services::arweave::{data_item_id, ArweaveError, StreamedDataItem};
+use crate::services::bundler::{BundlerClient, BundlerError};
+use crate::services::confirmation::ConfirmationService;
+use crate::services::encryption::{encrypt_content, ContentKey, EncryptionError};
+use crate::services::file_reader::{FileReadError, LocalFile};
+use crate::services::graphql::GraphQLClient;
+use crate::services::native_tx::{NativeTransaction, NativeTxError, NativeTxService};
+use crate::services::receipts::{ReceiptService, UploadReceipt};
+use crate::services::scanning::{ScanFile, ScanReport, ScanService, ScanSeverity};
+use crate::services::series::{SeriesError, SeriesService};
+use crate::services::transcript::TRANSCRIPT_TYPE;
+use crate::services::transfer::{Cancelled, TransferControl};
+use crate::services::wallet::{connected_address, connected_wallet, network_mismatch, WalletError, WalletService};
+use crate::utils::constants::{APP_NAME, DISPATCH_FREE_LIMIT, SCAN_READ_LIMIT};
+use crate::utils::ipfs::Cid;
+
+#[derive(Debug, Clone)]
+pub enum PublishError {
+    SigningFailed(WalletError),
+    SubmitFailed(BundlerError),
+    /// A layer-1 transaction couldn't be priced, signed or posted
+    TransactionFailed(NativeTxError),
+    /// The wallet is on a different network from the app; what it signs
+    /// would go to the wrong bundler
+    WrongNetwork { wallet: String, app: NetworkMode },
+    ReadFailed(FileReadError),
+    /// A streamed DataItem couldn't be put together around the file
+    DataItemFailed(ArweaveError),
+    /// Cancelled from the upload queue before it was posted
+    Cancelled(Cancelled),
+}
+
+impl std::fmt::Display for PublishError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PublishError::SigningFailed(e) => write!(f, "Could not sign with the connected wallet: {}", e),
+            PublishError::SubmitFailed(e) => write!(f, "Could not publish to the bundler: {}", e),
+            PublishError::TransactionFailed(e) => write!(f, "Could not publish the transaction: {}", e),
+            PublishError::WrongNetwork { wallet, app } => write!(
+                f,
+                "Your wallet is on {} but the archive is on {}. Switch your wallet's gateway, or change the network in Settings",
+                wallet,
+                app.display_name()
+            ),
+            PublishError::ReadFailed(e) => write!(f, "{}", e),
+            PublishError::DataItemFailed(e) => write!(f, "{}", e),
+            PublishError::Cancelled(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum UploadError {
+    Invalid(String),
+    EncryptionFailed(EncryptionError),
+    PublishFailed(PublishError),
+    SeriesFailed(SeriesError),
+    ReadFailed(FileReadError),
+    /// A scanner found something that must not be published
+    ScanBlocked(ScanReport),
+    /// A scanner raised warnings the uploader hasn't accepted
+    ScanWarnings(ScanReport),
+    /// The IPFS CID given isn't the CID of the uploaded file
+    IpfsMismatch(String),
+}
+
+impl std::fmt::Display for UploadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UploadError::Invalid(msg) => write!(f, "{}", msg),
+            UploadError::EncryptionFailed(e) => write!(f, "Could not encrypt the upload: {}", e),
+            UploadError::PublishFailed(e) => write!(f, "Upload failed: {}", e),
+            UploadError::SeriesFailed(e) => write!(f, "Uploaded, but the series was not updated: {}", e),
+            UploadError::ReadFailed(e) => write!(f, "{}. Choose it again and retry", e),
+            UploadError::ScanBlocked(report) => write!(f, "This upload can't be published:\n{}", report.summary(ScanSeverity::Block)),
+            UploadError::ScanWarnings(report) => write!(f, "Review these before uploading:\n{}", report.summary(ScanSeverity::Warning)),
+            UploadError::IpfsMismatch(cid) => write!(
+                f,
+                "{} isn't the IPFS CID of this file. Check it was added with the default `ipfs add` settings, or leave the CID out",
+                cid
+            ),
+        }
+    }
+}
+
+/// `Type` tag of a cover image DataItem
+pub const COVER_IMAGE_TYPE: &str = "Cover-Image";
+
+/// MIME types accepted for transcript uploads
+const TRANSCRIPT_CONTENT_TYPES: &[&str] = &["text/vtt", "application/json", "text/plain"];
+
+/// Where an upload should be attached
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeriesChoice {
+    None,
+    Existing(String),
+    New(String),
+}
+
+/// How an upload reaches Arweave, and so who pays for it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UploadMethod {
+    /// ANS-104 DataItems posted to the bundler, paid from the wallet's
+    /// Turbo credits
+    #[default]
+    Bundler,
+    /// Layer-1 transactions; the uploader's wallet pays the fee in AR
+    Native,
+    /// Handed to the wallet's `dispatch()`, free up to
+    /// `DISPATCH_FREE_LIMIT` bytes per item
+    Dispatch,
+}
+
+impl UploadMethod {
+    pub const ALL: [UploadMethod; 3] = [UploadMethod::Bundler, UploadMethod::Native, UploadMethod::Dispatch];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            UploadMethod::Bundler => "Turbo credits",
+            UploadMethod::Native => "AR from my wallet",
+            UploadMethod::Dispatch => "Free wallet dispatch",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            UploadMethod::Bundler => "Posted through the bundler and charged to your storage credits. Available within minutes.",
+            UploadMethod::Native => "A layer-1 Arweave transaction with the network fee paid in AR. Appears once mined, usually within 20 minutes.",
+            UploadMethod::Dispatch => "Your wallet posts the item itself at no cost. Only for files up to 100 KiB.",
+        }
+    }
+}
+
+/// Everything needed to publish one item
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadRequest {
+    pub title: String,
+    pub description: Option<String>,
+    pub speaker: Option<String>,
+    pub ministry: Option<String>,
+    pub scripture_refs: Vec<String>,
+    pub language: Option<String>,
+    pub series: SeriesChoice,
+    pub content_type: String,
+    /// The file to publish; the upload queue fills this in when a bulk
+    /// import row's turn comes
+    pub file: Option<LocalFile>,
+    /// Running time read from the file, published as `Duration`
+    pub duration_secs: Option<u32>,
+    /// Optional WebVTT, JSON or plain-text transcript as (MIME type, bytes)
+    pub transcript: Option<(String, Vec<u8>)>,
+    /// Optional cover image as (MIME type, bytes), already optimized
+    pub cover: Option<(String, Vec<u8>)>,
+    /// Hex SHA-256 of `file`, published as `File-Hash` for deduplication
+    pub file_hash: Option<String>,
+    /// Encrypt the data before publishing
+    pub encryption: Option<ContentKey>,
+    /// Universal Data License terms, published as `License` tags
+    pub license: Option<UdlTerms>,
+    /// The uploader's rights declaration, published as `Rights-*` tags
+    pub rights: Option<RightsDeclaration>,
+    /// CID of the same file on IPFS, published as `IPFS-CID` once it's
+    /// checked against `file`
+    pub ipfs_cid: Option<String>,
+    /// Earlier upload of the uploader's that this one replaces, published
+    /// as `Previous-Version`
+    pub previous_version: Option<String>,
+    /// The uploader has seen the content scan's warnings and wants to
+    /// publish anyway
+    pub accept_scan_warnings: bool,
+    /// Applies to attachments as well as the main file
+    pub method: UploadMethod,
+    /// Pause, resume and cancel for the upload queue
+    pub transfer: TransferControl,
+    /// Parts an earlier attempt already published, which a retry skips
+    pub published: PublishedParts,
+}
+
+/// The separately published pieces of an upload, in the order they go out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadPart {
+    /// Scanning, checking and encrypting before anything is signed
+    Preparation,
+    Transcript,
+    Cover,
+    Item,
+    Series,
+}
+
+impl UploadPart {
+    pub fn label(&self) -> &'static str {
+        match self {
+            UploadPart::Preparation => "Checks before upload",
+            UploadPart::Transcript => "Transcript",
+            UploadPart::Cover => "Cover image",
+            UploadPart::Item => "Main file",
+            UploadPart::Series => "Series update",
+        }
+    }
+}
+
+/// IDs of the parts of an upload that have gone out so far
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PublishedParts {
+    pub transcript_tx_id: Option<String>,
+    pub cover_tx_id: Option<String>,
+    pub item_tx_id: Option<String>,
+}
+
+impl UploadRequest {
+    /// The part `error` came from, given what's been published so far
+    pub fn failed_part(&self, error: &UploadError) -> UploadPart {
+        match error {
+            UploadError::PublishFailed(_) if self.transcript.is_some() && self.published.transcript_tx_id.is_none() => UploadPart::Transcript,
+            UploadError::PublishFailed(_) if self.cover.is_some() && self.published.cover_tx_id.is_none() => UploadPart::Cover,
+            UploadError::PublishFailed(_) => UploadPart::Item,
+            UploadError::SeriesFailed(_) => UploadPart::Series,
+            _ => UploadPart::Preparation,
+        }
+    }
+
+    /// Parts this upload publishes, with the ID of each that has gone out
+    pub fn parts(&self) -> Vec<(UploadPart, Option<String>)> {
+        let mut parts = Vec::new();
+        if self.transcript.is_some() {
+            parts.push((UploadPart::Transcript, self.published.transcript_tx_id.clone()));
+        }
+        if self.cover.is_some() {
+            parts.push((UploadPart::Cover, self.published.cover_tx_id.clone()));
+        }
+        parts.push((UploadPart::Item, self.published.item_tx_id.clone()));
+        parts
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.title.trim().is_empty() {
+            return Err("A title is required".to_string());
+        }
+        if self.file.as_ref().is_none_or(|file| file.size() == 0) {
+            return Err("Choose a file to upload".to_string());
+        }
+        if let Some((content_type, _)) = &self.transcript {
+            if !TRANSCRIPT_CONTENT_TYPES.contains(&content_type.as_str()) {
+                return Err("Transcripts must be WebVTT, JSON or plain text".to_string());
+            }
+        }
+        if self.encryption.is_some() && (self.transcript.is_some() || self.cover.is_some()) {
+            return Err("Transcripts and cover images can't be attached to encrypted uploads".to_string());
+        }
+        if let Some(ContentKey::Passphrase(passphrase)) = &self.encryption {
+            if passphrase.chars().count() < 8 {
+                return Err("Use a passphrase of at least 8 characters".to_string());
+            }
+        }
+        if let SeriesChoice::New(title) = &self.series {
+            if title.trim().is_empty() {
+                return Err("Name the new series".to_string());
+            }
+        }
+        if self.method == UploadMethod::Dispatch && self.item_sizes().any(|size| size > DISPATCH_FREE_LIMIT) {
+            return Err(format!("Free dispatch takes files up to {} KiB; choose another way to pay", DISPATCH_FREE_LIMIT / 1024));
+        }
+        if let Some(rights) = &self.rights {
+            rights.validate()?;
+        }
+        if let Some(cid) = &self.ipfs_cid {
+            if self.encryption.is_some() {
+                return Err("Encrypted uploads can't link to an IPFS mirror".to_string());
+            }
+            cid.parse::<Cid>()?;
+        }
+        Ok(())
+    }
+
+    /// Size of each item the upload publishes: the file, then any
+    /// attachments
+    pub fn item_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.file
+            .iter()
+            .map(|file| file.size() as usize)
+            .chain(self.transcript.iter().map(|(_, data)| data.len()))
+            .chain(self.cover.iter().map(|(_, data)| data.len()))
+    }
+
+    /// The main file and any attachments, as the content scan sees them;
+    /// `main` is what was read of the main file
+    pub fn scan_files<'a>(&'a self, main: &'a [u8]) -> Vec<ScanFile<'a>> {
+        let mut files = vec![ScanFile { label: "Main file", content_type: &self.content_type, data: main }];
+        if let Some((content_type, data)) = &self.transcript {
+            files.push(ScanFile { label: "Transcript", content_type, data });
+        }
+        if let Some((content_type, data)) = &self.cover {
+            files.push(ScanFile { label: "Cover image", content_type, data });
+        }
+        files
+    }
+
+    fn tags(&self, series: Option<&SeriesManifest>, linked: &LinkedItems) -> Vec<(String, String)> {
+        let mut tags = vec![
+            ("Content-Type".to_string(), self.content_type.clone()),
+            ("Type".to_string(), SPIRITUAL_CONTENT_TYPE.to_string()),
+            ("Title".to_string(), self.title.trim().to_string()),
+        ];
+
+        let optional = [
+            ("Description", &self.description),
+            ("Speaker", &self.speaker),
+            ("Ministry", &self.ministry),
+            ("Language", &self.language),
+        ];
+        for (name, value) in optional {
+            if let Some(value) = value.as_ref().filter(|value| !value.trim().is_empty()) {
+                tags.push((name.to_string(), value.trim().to_string()));
+            }
+        }
+
+        for (i, scripture_ref) in self.scripture_refs.iter().enumerate() {
+            tags.push((format!("Scripture-Ref-{}", i + 1), scripture_ref.clone()));
+        }
+        if let Some(series) = series {
+            tags.push(("Series".to_string(), series.title.clone()));
+            tags.push(("Series-Id".to_string(), series.series_id.clone()));
+        }
+        if let Some(transcript_tx_id) = &linked.transcript_tx_id {
+            tags.push(("Transcript-Tx".to_string(), transcript_tx_id.clone()));
+        }
+        if let Some(cover_tx_id) = &linked.cover_tx_id {
+            tags.push(("Cover-Tx".to_string(), cover_tx_id.clone()));
+        }
+        if let Some(duration_secs) = self.duration_secs {
+            tags.push(("Duration".to_string(), duration_secs.to_string()));
+        }
+        // Size of the file as chosen, before any encryption
+        if let Some(file) = &self.file {
+            tags.push(("File-Size".to_string(), file.size().to_string()));
+        }
+        if let Some(file_hash) = &self.file_hash {
+            tags.push(("File-Hash".to_string(), file_hash.clone()));
+        }
+        if let Some(cid) = &self.ipfs_cid {
+            tags.push(("IPFS-CID".to_string(), cid.clone()));
+        }
+        if let Some(license) = &self.license {
+            tags.extend(license.to_tags());
+        }
+        if let Some(rights) = &self.rights {
+            tags.extend(rights.to_tags());
+        }
+        if let Some(previous_version) = &self.previous_version {
+            tags.push(("Previous-Version".to_string(), previous_version.clone()));
+        }
+        tags.push(("Created-At".to_string(), chrono::Utc::now().timestamp().to_string()));
+        tags
+    }
+}
+
+/// What the wallet signed for a submission
+enum Signed {
+    /// The serialized DataItem, or just its header when the data was
+    /// streamed from the file
+    DataItem(Vec<u8>),
+    Transaction(Box<NativeTransaction>),
+    /// The wallet signed and posted it without handing back a signature
+    Dispatched,
+}
+
+/// An item the connected wallet signed and the bundler or gateway accepted
+struct Submission {
+    owner: String,
+    tx_id: String,
+    /// Tags as signed, including `App-Name`
+    tags: Vec<(String, String)>,
+    signed: Signed,
+}
+
+/// Sign a DataItem with the connected wallet and post it to the bundler.
+/// `App-Name` is added to `tags`.
+///
+/// Returns the signer's address and the new DataItem ID.
+pub async fn publish_with_wallet(data: Vec<u8>, tags: Vec<(String, String)>) -> Result<(String, String), PublishError> {
+    let submission = submit_with_wallet(data, tags).await?;
+    Ok((submission.owner, submission.tx_id))
+}
+
+/// The connected wallet and its address, once it's on the app's network
+pub async fn signing_wallet() -> Result<(WalletService, String), PublishError> {
+    let wallet = connected_wallet().await.map_err(PublishError::SigningFailed)?;
+    if let Some(network) = network_mismatch() {
+        return Err(PublishError::WrongNetwork { wallet: network.display_name(), app: app_config().network });
+    }
+    let owner = wallet
+        .get_active_address()
+        .await
+        .map_err(PublishError::SigningFailed)?;
+    Ok((wallet, owner))
+}
+
+fn with_app_name(tags: Vec<(String, String)>) -> Vec<(String, String)> {
+    let mut all_tags = vec![("App-Name".to_string(), APP_NAME.to_string())];
+    all_tags.extend(tags);
+    all_tags
+}
+
+async fn submit_with_wallet(data: Vec<u8>, tags: Vec<(String, String)>) -> Result<Submission, PublishError> {
+    let (wallet, owner) = signing_wallet().await?;
+    let all_tags = with_app_name(tags);
+
+    let signed = wallet
+        .sign_data_item(data, all_tags.clone())
+        .await
+        .map_err(PublishError::SigningFailed)?;
+    let tx_id = BundlerClient::new()
+        .submit(signed.clone())
+        .await
+        .map_err(PublishError::SubmitFailed)?;
+
+    Ok(Submission { owner, tx_id, tags: all_tags, signed: Signed::DataItem(signed) })
+}
+
+/// Sign a file's DataItem with the connected wallet and post it to the
+/// bundler under `transfer`'s control. Wallets that sign a deep hash are
+/// given only that and the file is posted from disk; others need the whole
+/// file in memory.
+async fn submit_bundled(file: &LocalFile, tags: Vec<(String, String)>, transfer: &TransferControl) -> Result<Submission, PublishError> {
+    let (wallet, owner) = signing_wallet().await?;
+    let all_tags = with_app_name(tags);
+    let bundler = BundlerClient::new();
+
+    if !wallet.capabilities().can_sign_deep_hash {
+        let data = file.read_all().await.map_err(PublishError::ReadFailed)?;
+        let signed = wallet
+            .sign_data_item(data, all_tags.clone())
+            .await
+            .map_err(PublishError::SigningFailed)?;
+        let item = LocalFile::from_bytes(file.name(), &signed, "application/octet-stream").map_err(PublishError::ReadFailed)?;
+        let tx_id = bundler.upload(&item, transfer).await.map_err(PublishError::SubmitFailed)?;
+        return Ok(Submission { owner, tx_id, tags: all_tags, signed: Signed::DataItem(signed) });
+    }
+
+    let public_key = wallet.get_public_key().await.map_err(PublishError::SigningFailed)?;
+    let item = StreamedDataItem::new(&public_key, all_tags.clone(), file.clone()).map_err(PublishError::DataItemFailed)?;
+    let deep_hash = item.deep_hash(|_| {}).await.map_err(PublishError::ReadFailed)?;
+    let signature = wallet
+        .sign_deep_hash(&deep_hash)
+        .await
+        .map_err(PublishError::SigningFailed)?;
+    let header = item.header(&signature).map_err(PublishError::DataItemFailed)?;
+    let signed_item = LocalFile::from_blob(file.name(), item.to_blob(&signature).map_err(PublishError::DataItemFailed)?);
+    let tx_id = bundler
+        .upload(&signed_item, transfer)
+        .await
+        .map_err(PublishError::SubmitFailed)?;
+    if tx_id != data_item_id(&signature) {
+        log::warn!("Bundler returned {} for DataItem {}", tx_id, data_item_id(&signature));
+    }
+
+    Ok(Submission { owner, tx_id, tags: all_tags, signed: Signed::DataItem(header) })
+}
+
+/// Publish as a layer-1 transaction paid for by the connected wallet
+async fn submit_native(file: &LocalFile, tags: Vec<(String, String)>, transfer: &TransferControl) -> Result<Submission, PublishError> {
+    let (wallet, owner) = signing_wallet().await?;
+    let all_tags = with_app_name(tags);
+
+    let tx = NativeTxService::new()
+        .publish(&wallet, file, &all_tags, transfer)
+        .await
+        .map_err(PublishError::TransactionFailed)?;
+
+    Ok(Submission { owner, tx_id: tx.id.clone(), tags: all_tags, signed: Signed::Transaction(Box::new(tx)) })
+}
+
+/// Have the wallet post the item itself through `dispatch()`
+async fn submit_dispatch(data: Vec<u8>, tags: Vec<(String, String)>) -> Result<Submission, PublishError> {
+    let (wallet, owner) = signing_wallet().await?;
+    let all_tags = with_app_name(tags);
+
+    let tx_id = wallet
+        .dispatch(data, all_tags.clone())
+        .await
+        .map_err(PublishError::SigningFailed)?;
+
+    Ok(Submission { owner, tx_id, tags: all_tags, signed: Signed::Dispatched })
+}
+
+async fn submit(method: UploadMethod, file: &LocalFile, tags: Vec<(String, String)>, transfer: &TransferControl) -> Result<Submission, PublishError> {
+    transfer.checkpoint().await.map_err(PublishError::Cancelled)?;
+    // Layer-1 transactions are chunked straight from the file, as are
+    // DataItems for wallets that sign a deep hash; dispatching wallets
+    // take the item from memory, and post it beyond our control
+    match method {
+        UploadMethod::Bundler => submit_bundled(file, tags, transfer).await,
+        UploadMethod::Native => submit_native(file, tags, transfer).await,
+        UploadMethod::Dispatch => submit_dispatch(file.read_all().await.map_err(PublishError::ReadFailed)?, tags).await,
+    }
+}
+
+/// IDs of child DataItems published alongside an upload
+#[derive(Debug)]
+struct LinkedItems {
+    transcript_tx_id: Option<String>,
+    cover_tx_id: Option<String>,
+}
+
+/// Publish an optional (MIME type, bytes) child item with `Type` tag
+/// `item_type`, returning its ID
+async fn publish_child(child: Option<(String, Vec<u8>)>, item_type: &str, title: &str, method: UploadMethod, transfer: &TransferControl) -> Result<Option<String>, UploadError> {
+    let Some((content_type, bytes)) = child else {
+        return Ok(None);
+    };
+    let file = LocalFile::from_bytes(title, &bytes, &content_type).map_err(UploadError::ReadFailed)?;
+    let tags = vec![
+        ("Content-Type".to_string(), content_type),
+        ("Type".to_string(), item_type.to_string()),
+        ("Title".to_string(), title.to_string()),
+    ];
+    let submission = submit(method, &file, tags, transfer)
+        .await
+        .map_err(UploadError::PublishFailed)?;
+    Ok(Some(submission.tx_id))
+}
+
+/// A new version may only name one of the uploader's own items as the one
+/// it replaces
+async fn check_previous_version(tx_id: &str) -> Result<(), String> {
+    let owner = connected_address().ok_or_else(|| "Connect your wallet first".to_string())?;
+    let found = GraphQLClient::new()
+        .transactions_by_ids(&[tx_id])
+        .await
+        .map_err(|e| format!("Could not look up the previous version: {}", e))?;
+    match found.into_iter().find(|tx| tx.id == tx_id) {
+        None => Err("The previous version wasn't found; it may not be indexed yet".to_string()),
+        Some(tx) if tx.owner != owner => Err("The previous version must be one of your own uploads".to_string()),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Publishes new archive items from the connected wallet
+pub struct UploadService;
+
+impl UploadService {
+    /// Publish an item, attach it to its series and start tracking
+    /// confirmations. Returns the new item's ID.
+    pub async fn publish(mut request: UploadRequest) -> Result<String, UploadError> {
+        Self::publish_resuming(&mut request).await
+    }
+
+    /// Publish as `publish` does, recording each part in
+    /// `request.published` as it goes out. Trying the same request again
+    /// after a failure skips those parts, so nothing is paid for twice.
+    pub async fn publish_resuming(request: &mut UploadRequest) -> Result<String, UploadError> {
+        if let Some(tx_id) = request.published.item_tx_id.clone() {
+            // Only the series update is left
+            let series = SeriesService::new();
+            if let Some(mut manifest) = resolve_series(&series, &request.series).await? {
+                manifest.add_member(&tx_id);
+                series.publish(&manifest).await.map_err(UploadError::SeriesFailed)?;
+            }
+            return Ok(tx_id);
+        }
+
+        // Encryption and CID checks rewrite the request; the caller's copy
+        // stays as it was given for any retry
+        let mut request_copy = request.clone();
+        let result = Self::publish_parts(&mut request_copy).await;
+        request.published = request_copy.published;
+        result
+    }
+
+    async fn publish_parts(request: &mut UploadRequest) -> Result<String, UploadError> {
+        request.validate().map_err(UploadError::Invalid)?;
+        let mut file = request.file.clone().ok_or_else(|| UploadError::Invalid("Choose a file to upload".to_string()))?;
+
+        // Before anything is signed; once published it can't be taken down
+        let sample = file.read_head(SCAN_READ_LIMIT).await.map_err(UploadError::ReadFailed)?;
+        let report = ScanService::new().scan(&request.scan_files(&sample)).await;
+        drop(sample);
+        if report.is_blocked() {
+            return Err(UploadError::ScanBlocked(report));
+        }
+        if report.has_warnings() && !request.accept_scan_warnings {
+            return Err(UploadError::ScanWarnings(report));
+        }
+
+        if let Some(previous_version) = &request.previous_version {
+            check_previous_version(previous_version).await.map_err(UploadError::Invalid)?;
+        }
+
+        // Only link a mirror that serves these exact bytes
+        if let Some(cid) = request.ipfs_cid.take() {
+            let parsed: Cid = cid.parse().map_err(UploadError::Invalid)?;
+            let data = file.read_all().await.map_err(UploadError::ReadFailed)?;
+            if !parsed.matches(&data) {
+                return Err(UploadError::IpfsMismatch(cid));
+            }
+            request.ipfs_cid = Some(parsed.to_string());
+        }
+
+        // Encrypted items publish ciphertext; a plaintext hash would let
+        // anyone confirm a guess of the content
+        let mut encryption_tags = Vec::new();
+        if let Some(key) = request.encryption.take() {
+            let data = file.read_all().await.map_err(UploadError::ReadFailed)?;
+            let encrypted = encrypt_content(&data, &request.content_type, &key)
+                .await
+                .map_err(UploadError::EncryptionFailed)?;
+            file = LocalFile::from_bytes(file.name(), &encrypted.data, "application/octet-stream").map_err(UploadError::ReadFailed)?;
+            request.content_type = "application/octet-stream".to_string();
+            request.file_hash = None;
+            encryption_tags = encrypted.tags;
+        }
+
+        let series = SeriesService::new();
+        let mut manifest = resolve_series(&series, &request.series).await?;
+
+        // Publish child items first so the item can point at them
+        let title = request.title.trim().to_string();
+        if request.published.transcript_tx_id.is_none() {
+            request.published.transcript_tx_id =
+                publish_child(request.transcript.clone(), TRANSCRIPT_TYPE, &format!("Transcript: {}", title), request.method, &request.transfer).await?;
+        }
+        if request.published.cover_tx_id.is_none() {
+            request.published.cover_tx_id =
+                publish_child(request.cover.clone(), COVER_IMAGE_TYPE, &format!("Cover: {}", title), request.method, &request.transfer).await?;
+        }
+        let linked = LinkedItems {
+            transcript_tx_id: request.published.transcript_tx_id.clone(),
+            cover_tx_id: request.published.cover_tx_id.clone(),
+        };
+
+        let mut tags = request.tags(manifest.as_ref(), &linked);
+        tags.extend(encryption_tags);
+        let size_bytes = file.size();
+        let submission = submit(request.method, &file, tags, &request.transfer)
+            .await
+            .map_err(UploadError::PublishFailed)?;
+        let Submission { owner, tx_id, tags, signed } = submission;
+        log::info!("⬆️ Uploaded {} ({})", request.title, tx_id);
+        request.published.item_tx_id = Some(tx_id.clone());
+
+        // The item is already published, so a lost receipt is only logged
+        let receipt = match &signed {
+            Signed::DataItem(item) => UploadReceipt::new(&tx_id, &request.title, &owner, &tags, size_bytes, item),
+            Signed::Transaction(tx) => Some(UploadReceipt::for_transaction(tx, &request.title, &owner, &tags, size_bytes)),
+            Signed::Dispatched => None,
+        };
+        match receipt {
+            Some(receipt) => {
+                if let Err(e) = ReceiptService::save(&receipt).await {
+                    log::warn!("Could not save the receipt for {}: {}", tx_id, e);
+                }
+            }
+            None if matches!(signed, Signed::Dispatched) => log::info!("No receipt for {}; the wallet kept its signature", tx_id),
+            None => log::warn!("Could not read the signature of {} for its receipt", tx_id),
+        }
+
+        ConfirmationService::track(SubmittedItem::new(&tx_id, &request.title, Some(owner))).await;
+
+        if let Some(manifest) = manifest.as_mut() {
+            manifest.add_member(&tx_id);
+            series.publish(manifest).await.map_err(UploadError::SeriesFailed)?;
+        }
+
+        Ok(tx_id)
+    }
+
+    /// Attach an item that is already in the archive to a series, instead
+    /// of uploading its file again
+    pub async fn link_existing(tx_id: &str, choice: &SeriesChoice) -> Result<(), UploadError> {
+        let series = SeriesService::new();
+        if let Some(mut manifest) = resolve_series(&series, choice).await? {
+            manifest.add_member(tx_id);
+            series.publish(&manifest).await.map_err(UploadError::SeriesFailed)?;
+            log::info!("🔗 Linked existing item {} into series {}", tx_id, manifest.series_id);
+        }
+        Ok(())
+    }
+}
+
+async fn resolve_series(series: &SeriesService, choice: &SeriesChoice) -> Result<Option<SeriesManifest>, UploadError> {
+    Ok(match choice {
+        SeriesChoice::None => None,
+        SeriesChoice::Existing(series_id) => Some(
+            series.load(series_id).await.map_err(UploadError::SeriesFailed)?,
+        ),
+        SeriesChoice::New(title) => Some(SeriesManifest::new(title)),
+    })
+}
+
+/// MIME type for an upload, guessed from its file extension
+pub fn content_type_for(filename: &str) -> &'static str {
+    let extension = filename.rsplit_once('.').map(|(_, ext)| ext.to_lowercase()).unwrap_or_default();
+    match extension.as_str() {
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "wav" => "audio/wav",
+        "ogg" => "audio/ogg",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "pdf" => "application/pdf",
+        "txt" => "text/plain",
+        "vtt" => "text/vtt",
+        "json" => "application/json",
+        "md" => "text/markdown",
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "webp" => "image/webp",
+        _ => "application/octet-stream",
+    }
+}
+
+/// File extension for a MIME type, the reverse of `content_type_for`
+pub fn extension_for(content_type: &str) -> &'static str {
+    let essence = content_type.split(';').next().unwrap_or_default().trim().to_lowercase();
+    match essence.as_str() {
+        "audio/mpeg" => "mp3",
+        "audio/mp4" | "audio/x-m4a" => "m4a",
+        "audio/wav" | "audio/x-wav" => "wav",
+        "audio/ogg" => "ogg",
+        "video/mp4" => "mp4",
+        "video/webm" => "webm",
+        "application/pdf" => "pdf",
+        "text/plain" => "txt",
+        "text/vtt" => "vtt",
+        "application/json" => "json",
+        "text/markdown" => "md",
+        "image/jpeg" => "jpg",
+        "image/png" => "png",
+        "image/webp" => "webp",
+        _ => "bin",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CommercialUse, ContentMetadata, KeySource};
+    use wasm_bindgen_test::*;
+
+    fn request() -> UploadRequest {
+        UploadRequest {
+            title: "  The Sower  ".to_string(),
+            description: Some("Mark 4 sermon".to_string()),
+            speaker: Some("Pastor Ruth".to_string()),
+            ministry: Some(" ".to_string()),
+            scripture_refs: vec!["Mark 4:1-20".to_string(), "Matthew 13:3".to_string()],
+            language: Some("en".to_string()),
+            series: SeriesChoice::None,
+            content_type: "audio/mpeg".to_string(),
+            file: Some(LocalFile::from_bytes("sermon.mp3", &[1, 2, 3], "audio/mpeg").unwrap()),
+            duration_secs: Some(1800),
+            transcript: None,
+            cover: None,
+            file_hash: Some("abc123".to_string()),
+            encryption: None,
+            license: Some(UdlTerms { commercial_use: Some(CommercialUse::Allowed), ..UdlTerms::default() }),
+            rights: Some(RightsDeclaration { rights_holder: "Pastor Ruth".to_string(), ..RightsDeclaration::default() }),
+            ipfs_cid: Some("QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o".to_string()),
+            previous_version: Some("previous-tx".to_string()),
+            accept_scan_warnings: false,
+            method: UploadMethod::Bundler,
+            transfer: TransferControl::default(),
+            published: PublishedParts::default(),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn upload_tags_read_back_as_metadata() {
+        let request = request();
+        let mut series = SeriesManifest::new("Parables");
+        series.series_id = "series-1".to_string();
+        let linked = LinkedItems { transcript_tx_id: Some("transcript-tx".to_string()), cover_tx_id: Some("cover-tx".to_string()) };
+
+        let tags = request.tags(Some(&series), &linked);
+        let metadata = ContentMetadata::from_tags("tx", "owner", &tags, None, None).unwrap();
+
+        assert_eq!(metadata.title, "The Sower");
+        assert_eq!(metadata.description, request.description);
+        assert_eq!(metadata.speaker, request.speaker);
+        // Blank optional fields are left out
+        assert_eq!(metadata.ministry, None);
+        assert_eq!(metadata.scripture_refs, request.scripture_refs);
+        assert_eq!(metadata.language.as_deref(), Some("en"));
+        assert_eq!(metadata.content_type, "audio/mpeg");
+        assert_eq!(metadata.duration_secs, Some(1800));
+        assert_eq!(metadata.file_size, Some(3));
+        assert_eq!(metadata.series.as_deref(), Some("Parables"));
+        assert_eq!(metadata.series_id.as_deref(), Some("series-1"));
+        assert_eq!(metadata.transcript_tx_id.as_deref(), Some("transcript-tx"));
+        assert_eq!(metadata.cover_tx_id.as_deref(), Some("cover-tx"));
+        assert_eq!(metadata.file_hash.as_deref(), Some("abc123"));
+        assert_eq!(metadata.ipfs_cid, request.ipfs_cid);
+        assert_eq!(metadata.license, request.license);
+        assert_eq!(metadata.rights, request.rights);
+        assert_eq!(metadata.previous_version, request.previous_version);
+        assert!(metadata.created_at.is_some());
+        assert_eq!(metadata.encryption, None);
+    }
+
+    #[wasm_bindgen_test]
+    fn scripture_refs_keep_their_order_past_nine() {
+        let mut request = request();
+        request.scripture_refs = (1..=12).map(|verse| format!("Psalm 119:{}", verse)).collect();
+
+        let tags = request.tags(None, &LinkedItems { transcript_tx_id: None, cover_tx_id: None });
+        let metadata = ContentMetadata::from_tags("tx", "owner", &tags, None, None).unwrap();
+
+        assert_eq!(metadata.scripture_refs, request.scripture_refs);
+    }
+
+    #[wasm_bindgen_test]
+    fn key_source_round_trips_through_its_tag() {
+        for key_source in [KeySource::Passphrase, KeySource::Wallet] {
+            assert_eq!(KeySource::from_tag_value(key_source.tag_value()), Some(key_source));
+        }
+        assert_eq!(KeySource::from_tag_value("Other"), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn dispatch_only_takes_small_items() {
+        let mut small = request();
+        small.method = UploadMethod::Dispatch;
+        assert!(small.validate().is_ok());
+
+        let mut large_cover = small.clone();
+        large_cover.cover = Some(("image/jpeg".to_string(), vec![0; DISPATCH_FREE_LIMIT + 1]));
+        assert!(large_cover.validate().is_err());
+
+        large_cover.method = UploadMethod::Native;
+        assert!(large_cover.validate().is_ok());
+    }
+}
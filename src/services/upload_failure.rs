@@ -0,0 +1,230 @@
+//! Why an upload failed and what to do about it
+//!
+//! An upload goes out in parts (transcript, cover, the file itself, the
+//! series update), each of which can fail at a different layer: the
+//! wallet, the bundler, a gateway or the file on disk. `UploadFailure`
+//! reduces that chain of errors to the part that failed, a class the
+//! uploader can act on, and the chunk it stopped at, so the upload pages
+//! can show more than a one-line toast and offer the right way forward.
+
+use crate::services::bundler::BundlerError;
+use crate::services::gateway::GatewayError;
+use crate::services::native_tx::NativeTxError;
+use crate::services::series::SeriesError;
+use crate::services::upload::{PublishError, UploadError, UploadPart, UploadRequest};
+use crate::services::wallet::WalletError;
+
+/// What kind of problem stopped an upload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// Not enough Turbo credits or AR to pay for it
+    InsufficientFunds,
+    /// The uploader declined or cancelled the signature in their wallet
+    SignatureRejected,
+    /// The wallet couldn't be reached or couldn't sign
+    WalletUnavailable,
+    WrongNetwork,
+    /// A gateway or the bundler failed with a server error
+    ServerError(u16),
+    /// The bundler or a gateway refused the item
+    Rejected(u16),
+    /// No gateway or bundler could be reached
+    Unreachable,
+    /// The chosen file can no longer be read
+    FileUnavailable,
+    /// The upload itself needs changing before it can go out
+    NeedsChanges,
+    Cancelled,
+    Other,
+}
+
+impl FailureKind {
+    pub fn of(error: &UploadError) -> Self {
+        match error {
+            UploadError::Invalid(_) | UploadError::ScanBlocked(_) | UploadError::ScanWarnings(_) | UploadError::IpfsMismatch(_) => {
+                FailureKind::NeedsChanges
+            }
+            UploadError::ReadFailed(_) => FailureKind::FileUnavailable,
+            UploadError::EncryptionFailed(_) => FailureKind::Other,
+            UploadError::PublishFailed(e) | UploadError::SeriesFailed(SeriesError::PublishFailed(e)) => Self::of_publish(e),
+            UploadError::SeriesFailed(SeriesError::LookupFailed(_)) => FailureKind::Unreachable,
+            UploadError::SeriesFailed(_) => FailureKind::Other,
+        }
+    }
+
+    fn of_publish(error: &PublishError) -> Self {
+        match error {
+            PublishError::SigningFailed(e) => Self::of_wallet(e),
+            PublishError::SubmitFailed(e) => Self::of_bundler(e),
+            PublishError::TransactionFailed(e) => Self::of_transaction(e),
+            PublishError::WrongNetwork { .. } => FailureKind::WrongNetwork,
+            PublishError::ReadFailed(_) => FailureKind::FileUnavailable,
+            PublishError::DataItemFailed(_) => FailureKind::Other,
+            PublishError::Cancelled(_) => FailureKind::Cancelled,
+        }
+    }
+
+    fn of_wallet(error: &WalletError) -> Self {
+        match error {
+            WalletError::UserDenied | WalletError::Cancelled => FailureKind::SignatureRejected,
+            _ => FailureKind::WalletUnavailable,
+        }
+    }
+
+    fn of_bundler(error: &BundlerError) -> Self {
+        match error {
+            BundlerError::Rejected(status, body) => Self::of_status(*status, body),
+            BundlerError::RequestFailed(_) => FailureKind::Unreachable,
+            BundlerError::InvalidResponse(_) => FailureKind::Other,
+            BundlerError::Cancelled(_) => FailureKind::Cancelled,
+            BundlerError::ChunkFailed { error, .. } => Self::of_bundler(error),
+        }
+    }
+
+    fn of_transaction(error: &NativeTxError) -> Self {
+        match error {
+            NativeTxError::Gateway(GatewayError::AllGatewaysFailed(detail)) => match http_status_in(detail) {
+                Some(status) => Self::of_status(status, detail),
+                None => FailureKind::Unreachable,
+            },
+            NativeTxError::Gateway(GatewayError::NoGateways) | NativeTxError::Pricing(_) => FailureKind::Unreachable,
+            NativeTxError::Signing(e) => Self::of_wallet(e),
+            NativeTxError::Rejected(status, body) => Self::of_status(*status, body),
+            NativeTxError::ReadFailed(_) => FailureKind::FileUnavailable,
+            NativeTxError::InvalidResponse(_) | NativeTxError::Merkle(_) => FailureKind::Other,
+            NativeTxError::Cancelled(_) => FailureKind::Cancelled,
+            NativeTxError::ChunkFailed { error, .. } => Self::of_transaction(error),
+        }
+    }
+
+    fn of_status(status: u16, body: &str) -> Self {
+        let body = body.to_lowercase();
+        if status == 402 || body.contains("insufficient") || body.contains("balance") {
+            FailureKind::InsufficientFunds
+        } else if status >= 500 {
+            FailureKind::ServerError(status)
+        } else {
+            FailureKind::Rejected(status)
+        }
+    }
+
+    pub fn title(&self) -> String {
+        match self {
+            FailureKind::InsufficientFunds => "Not enough funds".to_string(),
+            FailureKind::SignatureRejected => "Signature rejected".to_string(),
+            FailureKind::WalletUnavailable => "Wallet unavailable".to_string(),
+            FailureKind::WrongNetwork => "Wallet on another network".to_string(),
+            FailureKind::ServerError(status) => format!("Gateway error (HTTP {})", status),
+            FailureKind::Rejected(status) => format!("Rejected (HTTP {})", status),
+            FailureKind::Unreachable => "Network unreachable".to_string(),
+            FailureKind::FileUnavailable => "File can't be read".to_string(),
+            FailureKind::NeedsChanges => "Needs changes".to_string(),
+            FailureKind::Cancelled => "Cancelled".to_string(),
+            FailureKind::Other => "Upload error".to_string(),
+        }
+    }
+
+    /// What the uploader can do next
+    pub fn advice(&self) -> &'static str {
+        match self {
+            FailureKind::InsufficientFunds => "Top up your storage credits, or add AR to your wallet for layer-1 uploads, then retry.",
+            FailureKind::SignatureRejected => "Retry and approve the request in your wallet.",
+            FailureKind::WalletUnavailable => "Check that your wallet is open, unlocked and connected, then retry.",
+            FailureKind::WrongNetwork => "Switch your wallet's gateway to the archive's network, or change the network in Settings.",
+            FailureKind::ServerError(_) => "The server had a problem on its end. Retry in a few minutes, or pick another gateway in Settings.",
+            FailureKind::Rejected(_) => "The item was refused as sent. The detail below says why; retrying unchanged may fail again.",
+            FailureKind::Unreachable => "Check your internet connection, then retry.",
+            FailureKind::FileUnavailable => "The file was moved or changed after it was chosen. Choose it again and upload it anew.",
+            FailureKind::NeedsChanges => "Fix what's described below, then upload again.",
+            FailureKind::Cancelled => "The upload was stopped before it finished.",
+            FailureKind::Other => "Retry; if it keeps failing, include the detail below in a bug report.",
+        }
+    }
+
+    /// Whether trying the same upload again might work
+    pub fn is_retryable(&self) -> bool {
+        !matches!(self, FailureKind::FileUnavailable | FailureKind::NeedsChanges | FailureKind::Cancelled)
+    }
+}
+
+/// A failed upload: where it stopped, why, and what already went out
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadFailure {
+    pub part: UploadPart,
+    pub kind: FailureKind,
+    /// Chunk (from 0) and chunk count, when it stopped part way through
+    pub chunk: Option<(u64, u64)>,
+    /// Parts of the upload with the IDs of those already published
+    pub parts: Vec<(UploadPart, Option<String>)>,
+    /// The full error, for bug reports
+    pub detail: String,
+}
+
+impl UploadFailure {
+    pub fn new(request: &UploadRequest, error: &UploadError) -> Self {
+        Self {
+            part: request.failed_part(error),
+            kind: FailureKind::of(error),
+            chunk: failed_chunk(error),
+            parts: request.parts(),
+            detail: error.to_string(),
+        }
+    }
+
+    /// A failure before the upload could start, such as its file going
+    /// missing
+    pub fn before_start(kind: FailureKind, detail: String) -> Self {
+        Self { part: UploadPart::Preparation, kind, chunk: None, parts: Vec::new(), detail }
+    }
+}
+
+impl std::fmt::Display for UploadFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.part.label(), self.kind.title())
+    }
+}
+
+fn failed_chunk(error: &UploadError) -> Option<(u64, u64)> {
+    let publish = match error {
+        UploadError::PublishFailed(e) | UploadError::SeriesFailed(SeriesError::PublishFailed(e)) => e,
+        _ => return None,
+    };
+    match publish {
+        PublishError::SubmitFailed(BundlerError::ChunkFailed { index, total, .. }) => Some((*index, *total)),
+        PublishError::TransactionFailed(NativeTxError::ChunkFailed { index, total, .. }) => Some((*index as u64, *total as u64)),
+        _ => None,
+    }
+}
+
+/// The first "HTTP nnn" in a gateway's error message
+fn http_status_in(detail: &str) -> Option<u16> {
+    detail
+        .split("HTTP ")
+        .skip(1)
+        .find_map(|rest| rest.get(..3).and_then(|code| code.parse().ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn failures_are_classified_through_chunk_errors() {
+        let chunk_failed = |error| {
+            UploadError::PublishFailed(PublishError::SubmitFailed(BundlerError::ChunkFailed { index: 3, total: 9, error: Box::new(error) }))
+        };
+        let underfunded = chunk_failed(BundlerError::Rejected(402, "Insufficient balance".to_string()));
+        assert_eq!(FailureKind::of(&underfunded), FailureKind::InsufficientFunds);
+        assert_eq!(failed_chunk(&underfunded), Some((3, 9)));
+
+        let bad_gateway = UploadError::PublishFailed(PublishError::TransactionFailed(NativeTxError::Gateway(
+            GatewayError::AllGatewaysFailed("https://arweave.net returned HTTP 502".to_string()),
+        )));
+        assert_eq!(FailureKind::of(&bad_gateway), FailureKind::ServerError(502));
+
+        let declined = UploadError::PublishFailed(PublishError::SigningFailed(WalletError::UserDenied));
+        assert_eq!(FailureKind::of(&declined), FailureKind::SignatureRejected);
+        assert!(!FailureKind::of(&UploadError::Invalid("A title is required".to_string())).is_retryable());
+    }
+}
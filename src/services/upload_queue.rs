@@ -0,0 +1,309 @@
+//! Upload queue
+//!
+//! Batches of uploads, such as a bulk import, publish one at a time in the
+//! background so the page that queued them can be left. Each file is only
+//! opened when its turn comes, and then read a slice at a time, which keeps
+//! a batch of hundreds of sermons from sitting in memory at once.
+//!
+//! Each upload can be paused, resumed or cancelled through the
+//! `TransferControl` on its request. A paused upload that hasn't started
+//! is skipped until it's resumed; one part way through holds its place at
+//! the next chunk, and the uploads behind it wait.
+
+use dioxus::html::FileEngine;
+use dioxus::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::services::audio_prep::media_duration;
+use crate::services::file_reader::LocalFile;
+use crate::services::transfer::TransferControl;
+use crate::services::upload::{UploadRequest, UploadService};
+use crate::services::upload_failure::{FailureKind, UploadFailure};
+
+/// Where a queued upload is up to
+#[derive(Debug, Clone, PartialEq)]
+pub enum UploadStatus {
+    Queued,
+    Uploading,
+    Paused,
+    Published(String),
+    Failed(UploadFailure),
+    Cancelled,
+}
+
+impl UploadStatus {
+    /// Published, failed or cancelled, so no longer waiting on the queue
+    pub fn is_finished(&self) -> bool {
+        matches!(self, UploadStatus::Published(_) | UploadStatus::Failed(_) | UploadStatus::Cancelled)
+    }
+}
+
+/// An upload in the queue
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueuedUpload {
+    pub id: u32,
+    pub title: String,
+    pub file_name: String,
+    pub status: UploadStatus,
+}
+
+/// An upload waiting for its file to be read
+pub struct PendingUpload {
+    /// Everything but `file`, `file_hash` and `duration_secs`, which are
+    /// filled in from the file when the upload starts
+    pub request: UploadRequest,
+    pub file_name: String,
+    pub files: Arc<dyn FileEngine>,
+}
+
+/// How far the queue has got, across every upload in it
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct UploadQueueProgress {
+    pub total: usize,
+    pub published: usize,
+    pub failed: usize,
+    pub cancelled: usize,
+}
+
+impl UploadQueueProgress {
+    pub fn of(uploads: &[QueuedUpload]) -> Self {
+        let count = |matches: fn(&UploadStatus) -> bool| uploads.iter().filter(|upload| matches(&upload.status)).count();
+        Self {
+            total: uploads.len(),
+            published: count(|status| matches!(status, UploadStatus::Published(_))),
+            failed: count(|status| matches!(status, UploadStatus::Failed(_))),
+            cancelled: count(|status| *status == UploadStatus::Cancelled),
+        }
+    }
+
+    pub fn finished(&self) -> usize {
+        self.published + self.failed + self.cancelled
+    }
+
+    /// Share of the queue that's finished, from 0 to 1
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.finished() as f64 / self.total as f64
+    }
+}
+
+// Uploads in the order they were queued
+fn use_upload_queue_state() -> &'static GlobalSignal<Vec<QueuedUpload>> {
+    static UPLOADS: GlobalSignal<Vec<QueuedUpload>> = GlobalSignal::new(Vec::new);
+    &UPLOADS
+}
+
+thread_local! {
+    static QUEUE_RUNNING: Cell<bool> = const { Cell::new(false) };
+    // The upload being published right now, if any
+    static ACTIVE: Cell<Option<u32>> = const { Cell::new(None) };
+    static NEXT_ID: Cell<u32> = const { Cell::new(1) };
+    // Requests and file handles of uploads that haven't published yet
+    static PENDING: RefCell<HashMap<u32, PendingUpload>> = RefCell::new(HashMap::new());
+}
+
+fn set_status(id: u32, status: UploadStatus) {
+    if let Some(upload) = use_upload_queue_state().write().iter_mut().find(|upload| upload.id == id) {
+        upload.status = status;
+    }
+}
+
+/// Add a batch of uploads to the end of the queue
+pub fn queue_uploads(batch: Vec<PendingUpload>) {
+    {
+        let mut uploads = use_upload_queue_state().write();
+        for pending in batch {
+            let id = NEXT_ID.with(|next| next.replace(next.get() + 1));
+            uploads.push(QueuedUpload {
+                id,
+                title: pending.request.title.clone(),
+                file_name: pending.file_name.clone(),
+                status: UploadStatus::Queued,
+            });
+            PENDING.with(|queued| queued.borrow_mut().insert(id, pending));
+        }
+    }
+    run_queue();
+}
+
+/// Try a failed upload again from the part that failed
+pub fn retry_upload(id: u32) {
+    set_status(id, UploadStatus::Queued);
+    run_queue();
+}
+
+/// Try every failed upload that might work a second time
+pub fn retry_failed_uploads() {
+    let retryable: Vec<u32> = use_upload_queue_state()
+        .peek()
+        .iter()
+        .filter(|upload| matches!(&upload.status, UploadStatus::Failed(failure) if failure.kind.is_retryable()))
+        .map(|upload| upload.id)
+        .collect();
+    for id in retryable {
+        set_status(id, UploadStatus::Queued);
+    }
+    run_queue();
+}
+
+fn transfer_control(id: u32) -> Option<TransferControl> {
+    PENDING.with(|queued| queued.borrow().get(&id).map(|pending| pending.request.transfer.clone()))
+}
+
+fn is_active(id: u32) -> bool {
+    ACTIVE.with(|active| active.get() == Some(id))
+}
+
+/// Pause an upload: one that hasn't started is skipped, and one part way
+/// through stops at its next chunk
+pub fn pause_upload(id: u32) {
+    if let Some(transfer) = transfer_control(id) {
+        transfer.pause();
+        set_status(id, UploadStatus::Paused);
+    }
+}
+
+/// Carry on with a paused upload, or put it back in line if it hadn't
+/// started
+pub fn resume_upload(id: u32) {
+    let Some(transfer) = transfer_control(id) else {
+        return;
+    };
+    transfer.resume();
+    if is_active(id) {
+        set_status(id, UploadStatus::Uploading);
+    } else {
+        set_status(id, UploadStatus::Queued);
+        run_queue();
+    }
+}
+
+/// Stop an upload for good; one part way through stops at its next chunk
+/// and is marked cancelled once it has
+pub fn cancel_upload(id: u32) {
+    let Some(transfer) = transfer_control(id) else {
+        return;
+    };
+    transfer.cancel();
+    if !is_active(id) {
+        PENDING.with(|queued| queued.borrow_mut().remove(&id));
+        set_status(id, UploadStatus::Cancelled);
+    }
+}
+
+/// Drop every published, failed or cancelled upload from the queue
+pub fn clear_finished_uploads() {
+    use_upload_queue_state().write().retain(|upload| {
+        let keep = !upload.status.is_finished();
+        if !keep {
+            PENDING.with(|queued| queued.borrow_mut().remove(&upload.id));
+        }
+        keep
+    });
+}
+
+// Read the file and publish it, filling in the hash and running time the
+// form would have. Parts that go out are noted on the pending request, so
+// a retry carries on from the part that failed.
+async fn publish(id: u32) -> Result<String, UploadFailure> {
+    let (mut request, file_name, files) = PENDING
+        .with(|queued| {
+            queued
+                .borrow()
+                .get(&id)
+                .map(|pending| (pending.request.clone(), pending.file_name.clone(), pending.files.clone()))
+        })
+        .ok_or_else(|| UploadFailure::before_start(FailureKind::Other, "This upload was removed from the queue".to_string()))?;
+
+    let file = LocalFile::from_engine(&files, &file_name)
+        .await
+        .ok_or_else(|| UploadFailure::before_start(FailureKind::FileUnavailable, format!("Could not read {}", file_name)))?;
+    request.file_hash = match file.digest(|_| {}).await {
+        Ok(digest) => Some(digest.sha256),
+        Err(e) => {
+            log::warn!("Could not hash {}: {}", file_name, e);
+            None
+        }
+    };
+    if request.content_type.starts_with("audio/") || request.content_type.starts_with("video/") {
+        request.duration_secs = media_duration(&file, &request.content_type).await;
+    }
+    request.file = Some(file);
+    let result = UploadService::publish_resuming(&mut request).await;
+    PENDING.with(|queued| {
+        if let Some(pending) = queued.borrow_mut().get_mut(&id) {
+            pending.request.published = request.published.clone();
+        }
+    });
+    result.map_err(|e| UploadFailure::new(&request, &e))
+}
+
+// Work through queued uploads one at a time, outliving whichever
+// component queued them
+fn run_queue() {
+    if QUEUE_RUNNING.with(|running| running.replace(true)) {
+        return;
+    }
+    spawn_forever(async {
+        loop {
+            let next = use_upload_queue_state()
+                .peek()
+                .iter()
+                .find(|upload| upload.status == UploadStatus::Queued)
+                .map(|upload| upload.id);
+            let Some(id) = next else {
+                break;
+            };
+            set_status(id, UploadStatus::Uploading);
+            ACTIVE.with(|active| active.set(Some(id)));
+            let status = match publish(id).await {
+                Ok(tx_id) => {
+                    PENDING.with(|queued| queued.borrow_mut().remove(&id));
+                    UploadStatus::Published(tx_id)
+                }
+                Err(_) if transfer_control(id).is_some_and(|transfer| transfer.is_cancelled()) => {
+                    PENDING.with(|queued| queued.borrow_mut().remove(&id));
+                    UploadStatus::Cancelled
+                }
+                Err(failure) => {
+                    log::warn!("Queued upload {} failed: {} ({})", id, failure, failure.detail);
+                    UploadStatus::Failed(failure)
+                }
+            };
+            ACTIVE.with(|active| active.set(None));
+            set_status(id, status);
+        }
+        QUEUE_RUNNING.with(|running| running.set(false));
+    });
+}
+
+/// Every upload in the queue, oldest first
+pub fn use_upload_queue() -> Vec<QueuedUpload> {
+    use_upload_queue_state().read().clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn progress_counts_finished_uploads() {
+        let upload = |id, status| QueuedUpload { id, title: String::new(), file_name: String::new(), status };
+        let progress = UploadQueueProgress::of(&[
+            upload(1, UploadStatus::Published("tx".to_string())),
+            upload(2, UploadStatus::Failed(UploadFailure::before_start(FailureKind::Unreachable, "offline".to_string()))),
+            upload(3, UploadStatus::Uploading),
+            upload(4, UploadStatus::Queued),
+            upload(5, UploadStatus::Cancelled),
+            upload(6, UploadStatus::Paused),
+        ]);
+        assert_eq!(progress, UploadQueueProgress { total: 6, published: 1, failed: 1, cancelled: 1 });
+        assert_eq!(progress.fraction(), 0.5);
+        assert_eq!(UploadQueueProgress::of(&[]).fraction(), 0.0);
+    }
+}
@@ -0,0 +1,350 @@
+//! Checking content against its owner's signature, recomputed from the data
+//! actually received; only Arweave (RSA-PSS) signatures are checked
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use crate::services::auth::owner_address;
+use crate::services::content_fetch::{ContentFetchError, ContentFetchService};
+use crate::services::gateway::GatewayManager;
+use crate::services::graphql::{GraphQLClient, TransactionDetails};
+use crate::utils::crypto::{verify_rsa_pss, CryptoError};
+use crate::utils::deep_hash::{data_digest, DataItemParts, TransactionParts};
+use crate::utils::merkle::data_root;
+
+/// Length of an Arweave RSA-4096 public key
+const ARWEAVE_OWNER_LENGTH: usize = 512;
+
+/// What didn't match when content fails its check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tampering {
+    /// The ID isn't the hash of the signature
+    IdMismatch,
+    /// The public key doesn't belong to the credited address
+    OwnerMismatch,
+    /// The signature doesn't cover the data and tags served
+    BadSignature,
+    /// The data doesn't match the signed `data_root`
+    DataMismatch,
+}
+
+impl std::fmt::Display for Tampering {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tampering::IdMismatch => write!(f, "the transaction ID doesn't match its signature"),
+            Tampering::OwnerMismatch => write!(f, "the signing key doesn't belong to the credited address"),
+            Tampering::BadSignature => write!(f, "the signature doesn't match the content served"),
+            Tampering::DataMismatch => write!(f, "the data doesn't match the signed data root"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum VerificationError {
+    LookupFailed(String),
+    NotFound,
+    /// The data is larger than the caller was willing to download
+    TooLarge(u64),
+    /// Signed in a way the app can't check
+    Unsupported(String),
+    Data(ContentFetchError),
+    Crypto(CryptoError),
+    Tampered(Tampering),
+}
+
+impl VerificationError {
+    /// The content was checked and doesn't match, as opposed to the check
+    /// not being possible
+    pub fn is_tampered(&self) -> bool {
+        matches!(self, VerificationError::Tampered(_))
+    }
+}
+
+impl std::fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerificationError::LookupFailed(msg) => write!(f, "Couldn't look up the transaction: {}", msg),
+            VerificationError::NotFound => write!(f, "The transaction isn't indexed yet"),
+            VerificationError::TooLarge(size) => write!(f, "Checking needs a {} MB download", size.div_ceil(1024 * 1024)),
+            VerificationError::Unsupported(msg) => write!(f, "{}", msg),
+            VerificationError::Data(e) => write!(f, "{}", e),
+            VerificationError::Crypto(e) => write!(f, "{}", e),
+            VerificationError::Tampered(tampering) => write!(f, "Not authentic: {}", tampering),
+        }
+    }
+}
+
+impl From<ContentFetchError> for VerificationError {
+    fn from(error: ContentFetchError) -> Self {
+        VerificationError::Data(error)
+    }
+}
+
+impl From<CryptoError> for VerificationError {
+    fn from(error: CryptoError) -> Self {
+        VerificationError::Crypto(error)
+    }
+}
+
+/// An item whose signature checked out
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedItem {
+    /// Address that signed the item
+    pub owner: String,
+    /// Whether it is a DataItem inside a bundle rather than a base-layer
+    /// transaction
+    pub bundled: bool,
+}
+
+/// A base-layer transaction as served by a gateway's `/tx/{id}`; binary
+/// fields and tags are base64url
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransactionRecord {
+    #[serde(default)]
+    pub format: u8,
+    pub owner: String,
+    #[serde(default)]
+    pub target: String,
+    #[serde(default)]
+    pub quantity: String,
+    #[serde(default)]
+    pub reward: String,
+    #[serde(default)]
+    pub last_tx: String,
+    #[serde(default)]
+    pub tags: Vec<RecordTag>,
+    #[serde(default)]
+    pub data_size: String,
+    #[serde(default)]
+    pub data_root: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordTag {
+    pub name: String,
+    pub value: String,
+}
+
+/// `/tx/{id}` record of a base-layer transaction, or `None` for a bundled
+/// DataItem or a transaction the gateway doesn't have
+pub async fn transaction_record(gateways: &GatewayManager, tx_id: &str) -> Result<Option<TransactionRecord>, String> {
+    let response = gateways.get(&format!("/tx/{}", tx_id)).await.map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Ok(None);
+    }
+    response.json().await.map(Some).map_err(|e| e.to_string())
+}
+
+fn decode(value: &str) -> Result<Vec<u8>, VerificationError> {
+    URL_SAFE_NO_PAD
+        .decode(value)
+        .map_err(|_| VerificationError::Unsupported("The transaction record isn't valid base64url".to_string()))
+}
+
+// A 32-byte DataItem target or anchor, absent when empty
+fn optional_field(value: &str) -> Result<Option<[u8; 32]>, VerificationError> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    decode(value)?
+        .try_into()
+        .map(Some)
+        .map_err(|_| VerificationError::Unsupported("The DataItem's target or anchor isn't 32 bytes".to_string()))
+}
+
+/// Check that `tx_id` is the hash of `signature` and that `owner_key`
+/// belongs to `address`
+pub fn check_identity(tx_id: &str, signature: &str, owner_key: &str, address: &str) -> Result<(), VerificationError> {
+    if URL_SAFE_NO_PAD.encode(Sha256::digest(decode(signature)?)) != tx_id {
+        return Err(VerificationError::Tampered(Tampering::IdMismatch));
+    }
+    if owner_address(owner_key).as_deref() != Some(address) {
+        return Err(VerificationError::Tampered(Tampering::OwnerMismatch));
+    }
+    Ok(())
+}
+
+/// Checks items against their signatures
+pub struct VerificationService {
+    graphql: GraphQLClient,
+    gateways: GatewayManager,
+    fetcher: ContentFetchService,
+}
+
+impl VerificationService {
+    pub fn new() -> Self {
+        Self {
+            graphql: GraphQLClient::new(),
+            gateways: GatewayManager::new(),
+            fetcher: ContentFetchService::new(),
+        }
+    }
+
+    /// Check `tx_id`, refusing with `TooLarge` rather than downloading
+    /// more than `max_bytes` of data
+    pub async fn verify(&self, tx_id: &str, max_bytes: Option<u64>) -> Result<VerifiedItem, VerificationError> {
+        let details = self
+            .graphql
+            .transaction_details(tx_id)
+            .await
+            .map_err(|e| VerificationError::LookupFailed(e.to_string()))?
+            .ok_or(VerificationError::NotFound)?;
+
+        let size = details.data.size.parse::<u64>().unwrap_or(0);
+        if let Some(max_bytes) = max_bytes.filter(|max_bytes| size > *max_bytes) {
+            log::info!("⏸️ Not verifying {} automatically: {} bytes > {}", tx_id, size, max_bytes);
+            return Err(VerificationError::TooLarge(size));
+        }
+
+        check_identity(tx_id, &details.signature, &details.owner.key, &details.owner.address)?;
+        if decode(&details.owner.key)?.len() != ARWEAVE_OWNER_LENGTH {
+            return Err(VerificationError::Unsupported("Only Arweave wallet signatures can be checked".to_string()));
+        }
+
+        let bundled = details.bundled_in.is_some();
+        if bundled {
+            self.verify_data_item(&details).await?;
+        } else {
+            self.verify_transaction(tx_id).await?;
+        }
+
+        log::info!("✅ Verified {} as signed by {}", tx_id, details.owner.address);
+        Ok(VerifiedItem {
+            owner: details.owner.address,
+            bundled,
+        })
+    }
+
+    async fn verify_data_item(&self, details: &TransactionDetails) -> Result<(), VerificationError> {
+        let content = self.fetcher.fetch(&details.id).await?;
+        let parts = DataItemParts {
+            signature_type: 1,
+            owner: decode(&details.owner.key)?,
+            target: optional_field(&details.recipient)?,
+            anchor: optional_field(&details.anchor)?,
+            tags: details.tag_pairs(),
+        };
+        let message = parts.deep_hash(content.bytes.len() as u64, &data_digest(&content.bytes).await);
+        if !verify_rsa_pss(&details.owner.key, &decode(&details.signature)?, &message).await? {
+            return Err(VerificationError::Tampered(Tampering::BadSignature));
+        }
+        Ok(())
+    }
+
+    async fn verify_transaction(&self, tx_id: &str) -> Result<(), VerificationError> {
+        let record = transaction_record(&self.gateways, tx_id)
+            .await
+            .map_err(VerificationError::LookupFailed)?
+            .ok_or(VerificationError::NotFound)?;
+        if record.format != 2 {
+            return Err(VerificationError::Unsupported("Format 1 transactions can't be checked".to_string()));
+        }
+
+        if record.data_size != "0" {
+            let content = self.fetcher.fetch(tx_id).await?;
+            if !data_root(&content.bytes).is_ok_and(|root| URL_SAFE_NO_PAD.encode(root) == record.data_root) {
+                return Err(VerificationError::Tampered(Tampering::DataMismatch));
+            }
+        }
+
+        let tags = record
+            .tags
+            .iter()
+            .map(|tag| Ok((decode(&tag.name)?, decode(&tag.value)?)))
+            .collect::<Result<Vec<_>, VerificationError>>()?;
+        let parts = TransactionParts {
+            owner: decode(&record.owner)?,
+            target: decode(&record.target)?,
+            quantity: record.quantity.clone(),
+            reward: record.reward.clone(),
+            last_tx: decode(&record.last_tx)?,
+            tags,
+            data_size: record.data_size.clone(),
+            data_root: decode(&record.data_root)?,
+        };
+        let message = parts.deep_hash();
+        if !verify_rsa_pss(&record.owner, &decode(&record.signature)?, &message).await? {
+            return Err(VerificationError::Tampered(Tampering::BadSignature));
+        }
+        Ok(())
+    }
+}
+
+impl Default for VerificationService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_test::*;
+
+    // A fresh RSA-PSS key the size of an Arweave wallet's
+    const GENERATE_KEY: &str = "(async () => {
+        const algorithm = { name: 'RSA-PSS', modulusLength: 4096, publicExponent: new Uint8Array([1, 0, 1]), hash: 'SHA-256' };
+        const keys = await crypto.subtle.generateKey(algorithm, true, ['sign', 'verify']);
+        const { n } = await crypto.subtle.exportKey('jwk', keys.publicKey);
+        return { owner: n, privateKey: keys.privateKey };
+    })";
+
+    const SIGN: &str = "(async (privateKey, message, saltLength) => {
+        return new Uint8Array(await crypto.subtle.sign({ name: 'RSA-PSS', saltLength }, privateKey, message));
+    })";
+
+    async fn run_js(source: &str, args: &[JsValue]) -> JsValue {
+        let function: js_sys::Function = js_sys::eval(source).unwrap().unchecked_into();
+        let promise: js_sys::Promise = function.apply(&JsValue::NULL, &args.iter().collect()).unwrap().unchecked_into();
+        wasm_bindgen_futures::JsFuture::from(promise).await.unwrap()
+    }
+
+    #[wasm_bindgen_test]
+    fn identity_ties_id_signature_and_owner() {
+        let signature = URL_SAFE_NO_PAD.encode([7u8; 512]);
+        let tx_id = URL_SAFE_NO_PAD.encode(Sha256::digest([7u8; 512]));
+        let key = URL_SAFE_NO_PAD.encode([9u8; 512]);
+        let address = owner_address(&key).unwrap();
+
+        assert!(check_identity(&tx_id, &signature, &key, &address).is_ok());
+        assert!(matches!(
+            check_identity("someone-elses-id", &signature, &key, &address),
+            Err(VerificationError::Tampered(Tampering::IdMismatch))
+        ));
+        assert!(matches!(
+            check_identity(&tx_id, &signature, &key, "vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JI"),
+            Err(VerificationError::Tampered(Tampering::OwnerMismatch))
+        ));
+    }
+
+    #[wasm_bindgen_test]
+    async fn items_signed_with_any_wallet_salt_length_verify() {
+        // index.html loads the bridge for the app
+        js_sys::eval(include_str!("../../public/crypto-bridge.js")).unwrap();
+        let keys = run_js(GENERATE_KEY, &[]).await;
+        let owner = js_sys::Reflect::get(&keys, &"owner".into()).unwrap().as_string().unwrap();
+        let private_key = js_sys::Reflect::get(&keys, &"privateKey".into()).unwrap();
+
+        let data = b"Grace and peace to you";
+        let parts = DataItemParts {
+            signature_type: 1,
+            owner: decode(&owner).unwrap(),
+            target: None,
+            anchor: None,
+            tags: vec![("Type".to_string(), "Sermon".to_string())],
+        };
+        let message = parts.deep_hash(data.len() as u64, &data_digest(data).await);
+        let tampered = parts.deep_hash(data.len() as u64, &data_digest(b"Grace and peace to yo!").await);
+
+        // None, a hash's worth, and the most a 4096-bit key allows
+        for salt_length in [0u32, 32, 512 - 32 - 2] {
+            let args = [private_key.clone(), js_sys::Uint8Array::from(&message[..]).into(), salt_length.into()];
+            let signature = js_sys::Uint8Array::new(&run_js(SIGN, &args).await).to_vec();
+            assert!(verify_rsa_pss(&owner, &signature, &message).await.unwrap(), "salt of {} bytes", salt_length);
+            assert!(!verify_rsa_pss(&owner, &signature, &tampered).await.unwrap());
+        }
+    }
+}
@@ -1,12 +1,16 @@
 use async_trait::async_trait;
+use dioxus::prelude::*;
+use std::cell::RefCell;
 use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use js_sys::{Object, Reflect, Array};
-use web_sys::console;
+use js_sys::{Object, Reflect, Function};
+use sha2::{Digest, Sha256};
 
+use crate::config::app_config;
+use crate::services::gateway::GatewayManager;
 use crate::services::wallet::{WalletError, WalletStrategy, WalletStrategyType, WalletCapabilities};
 
 // WASM bindings for the JavaScript ao-sync-sdk WalletClient
@@ -31,6 +35,12 @@ extern "C" {
     #[wasm_bindgen(method, js_name = "signDataItem")]
     fn sign_data_item_js(this: &WalletClient, data_item: &JsValue) -> js_sys::Promise;
 
+    #[wasm_bindgen(method, js_name = "signature")]
+    fn signature_js(this: &WalletClient, data: &JsValue, algorithm: &JsValue) -> js_sys::Promise;
+
+    #[wasm_bindgen(method, js_name = "getActivePublicKey")]
+    fn get_active_public_key_js(this: &WalletClient) -> js_sys::Promise;
+
     #[wasm_bindgen(method, js_name = "reconnect")]
     fn reconnect_js(this: &WalletClient) -> js_sys::Promise;
 
@@ -38,16 +48,6 @@ extern "C" {
     fn on(this: &WalletClient, event: &str, callback: &js_sys::Function);
 }
 
-// Helper function to check if ao-sync-sdk is available
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = console, js_name = log)]
-    fn console_log(s: &str);
-    
-    #[wasm_bindgen(js_name = "eval")]
-    fn js_eval(code: &str) -> JsValue;
-}
-
 #[derive(Serialize, Deserialize)]
 struct BeaconConnectOptions {
     permissions: Vec<String>,
@@ -78,6 +78,71 @@ struct BeaconOptions {
     protocol_version: u8,
 }
 
+// Strategies are built per operation, but a Beacon session lives in the
+// WalletClient that connected, so keep that client for later instances
+thread_local! {
+    static SESSION: RefCell<Option<(JsValue, String)>> = const { RefCell::new(None) };
+    // Client waiting for the phone to scan its pairing code
+    static PAIRING_CLIENT: RefCell<Option<JsValue>> = const { RefCell::new(None) };
+}
+
+// `aosync=` URI the Beacon app scans to pair, while a desktop connect waits
+fn use_beacon_pairing_state() -> &'static GlobalSignal<Option<String>> {
+    static BEACON_PAIRING: GlobalSignal<Option<String>> = GlobalSignal::new(|| None);
+    &BEACON_PAIRING
+}
+
+/// Pairing URI to show as a QR code while a Beacon connect waits for the
+/// phone, or `None`
+///
+/// beacon-wallet-loader.js turns off the SDK's own QR modal so the app can
+/// draw this one; on iOS the SDK opens the Beacon app instead and there is
+/// nothing to scan.
+pub fn use_beacon_pairing() -> Option<String> {
+    use_beacon_pairing_state().read().clone()
+}
+
+/// Give up on the pairing in progress; the pending connect fails
+pub fn cancel_beacon_pairing() {
+    let Some(client) = PAIRING_CLIENT.with(|pairing| pairing.borrow_mut().take()) else {
+        return;
+    };
+    let listener = Reflect::get(&client, &JsValue::from_str("connectionListener"))
+        .ok()
+        .and_then(|listener| listener.dyn_into::<Function>().ok());
+    match listener {
+        Some(listener) => {
+            let _ = listener.call1(&client, &JsValue::from_str("connection_canceled"));
+        }
+        None => *use_beacon_pairing_state().write() = None,
+    }
+}
+
+// The SDK shows a QR code only off iOS and outside the Beacon app's browser
+fn shows_pairing_code(client: &JsValue) -> bool {
+    let flag = |name: &str| Reflect::get(client, &JsValue::from_str(name)).map(|value| value.is_truthy()).unwrap_or(false);
+    !flag("isAppleMobileDevice") && !flag("isInappBrowser")
+}
+
+fn start_pairing(client: &JsValue) {
+    if !shows_pairing_code(client) {
+        return;
+    }
+    // connect() picks the session id before its first await
+    let Some(uid) = Reflect::get(client, &JsValue::from_str("uid")).ok().and_then(|uid| uid.as_string()) else {
+        return;
+    };
+    PAIRING_CLIENT.with(|pairing| *pairing.borrow_mut() = Some(client.clone()));
+    *use_beacon_pairing_state().write() = Some(format!("aosync={}", uid));
+}
+
+fn end_pairing() {
+    PAIRING_CLIENT.with(|pairing| *pairing.borrow_mut() = None);
+    if use_beacon_pairing_state().peek().is_some() {
+        *use_beacon_pairing_state().write() = None;
+    }
+}
+
 /// Beacon wallet strategy implementation
 /// 
 /// Beacon is an iOS-based agent-first wallet designed for AO (Autonomous Objects).
@@ -91,86 +156,46 @@ pub struct BeaconStrategy {
 }
 
 impl BeaconStrategy {
+    /// Picks up the session a previous instance connected, if any
     pub fn new() -> Self {
-        Self {
-            wallet_client: None,
-            connected: false,
-            address: None,
+        match SESSION.with(|session| session.borrow().clone()) {
+            Some((client, address)) => Self {
+                wallet_client: Some(client.unchecked_into()),
+                connected: true,
+                address: Some(address),
+            },
+            None => Self {
+                wallet_client: None,
+                connected: false,
+                address: None,
+            },
         }
     }
-    
-    /// Check if Beacon wallet ao-sync-sdk is available
-    async fn is_beacon_available() -> bool {
-        console_log("🔍 Checking Beacon availability...");
-        
-        // First, try to create WalletClient if it doesn't exist
-        let create_wallet_client = r#"
-(function() {
-    if (typeof window.WalletClient === 'undefined') {
-        console.log('🛠️ Creating mock WalletClient directly from WASM');
-        
-        window.WalletClient = class MockWalletClient {
-            constructor() {
-                console.log('🆕 Mock WalletClient created from WASM');
-            }
-            
-            async connect(options) {
-                console.log('🔗 Mock connect called with options:', options);
-                await new Promise(resolve => setTimeout(resolve, 1000));
-                return {
-                    address: 'mock_beacon_address_' + Math.random().toString(36).substr(2, 9)
-                };
-            }
-            
-            async disconnect() {
-                console.log('🔌 Mock disconnect called');
-                return true;
-            }
-            
-            async signTransaction(transaction) {
-                console.log('✍️ Mock signTransaction called:', transaction);
-                return {
-                    ...transaction,
-                    signature: 'mock_signature_' + Math.random().toString(36).substr(2, 9)
-                };
-            }
-            
-            async reconnect() {
-                console.log('🔄 Mock reconnect called');
-                return this.connect({});
-            }
-            
-            on(event, callback) {
-                console.log('👂 Mock event listener registered:', event);
+
+    fn remember_session(&self) {
+        let session = match (&self.wallet_client, &self.address) {
+            (Some(client), Some(address)) if self.connected => {
+                let client: &JsValue = client.as_ref();
+                Some((client.clone(), address.clone()))
             }
+            _ => None,
         };
-        
-        console.log('✅ WalletClient created from WASM');
-        return true;
-    } else {
-        console.log('✅ WalletClient already exists');
-        return true;
+        SESSION.with(|stored| *stored.borrow_mut() = session);
     }
-})()
-        "#;
-        
-        // Execute the WalletClient creation
-        match js_eval(create_wallet_client).as_bool() {
-            Some(true) => {
-                console_log("✅ WalletClient is now available");
-                
-                // Verify it works
-                let test_code = "try { new WalletClient(); true; } catch(e) { console.error('WalletClient constructor failed:', e); false; }";
-                let can_instantiate = js_eval(test_code).as_bool().unwrap_or(false);
-                console_log(&format!("🏗️ Can instantiate WalletClient: {}", can_instantiate));
-                
-                can_instantiate
-            },
-            _ => {
-                console_log("❌ Failed to create or verify WalletClient");
-                false
-            }
-        }
+    
+    /// Check if beacon-wallet-loader.js exposed the ao-sync-sdk WalletClient
+    fn is_beacon_available() -> bool {
+        let client = Reflect::get(&js_sys::global(), &JsValue::from_str("WalletClient"))
+            .unwrap_or(JsValue::UNDEFINED);
+        let available = client.is_function();
+        log::debug!("🔍 Beacon WalletClient available: {}", available);
+        available
+    }
+}
+
+impl Default for BeaconStrategy {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -183,7 +208,7 @@ impl WalletStrategy for BeaconStrategy {
     async fn is_available(&self) -> Result<bool, WalletError> {
         // Add a small delay to allow beacon-wallet-loader.js to finish
         gloo_timers::future::TimeoutFuture::new(100).await;
-        Ok(Self::is_beacon_available().await)
+        Ok(Self::is_beacon_available())
     }
     
     fn get_capabilities(&self) -> WalletCapabilities {
@@ -194,6 +219,8 @@ impl WalletStrategy for BeaconStrategy {
             supports_batch_signing: true, // AO-focused wallets typically support batch operations
             supports_permissions: true,
             supports_multiple_addresses: false,
+            can_dispatch: false,
+            can_sign_deep_hash: true,
         }
     }
     
@@ -205,18 +232,19 @@ impl WalletStrategy for BeaconStrategy {
         
         if let Some(client) = &self.wallet_client {
             // Create connection options
+            let gateway = GatewayManager::new().primary();
             let options = BeaconConnectOptions {
                 permissions: permissions.iter().map(|s| s.to_string()).collect(),
                 app_info: BeaconAppInfo {
                     name: "Faithful Archive".to_string(),
-                    logo: "https://faithfularchive.org/logo.png".to_string(),
+                    logo: app_config().logo_url.clone(),
                 },
                 gateway: BeaconGateway {
-                    host: "arweave.net".to_string(),
-                    port: 443,
-                    protocol: "https".to_string(),
+                    host: gateway.host(),
+                    port: gateway.port(),
+                    protocol: gateway.protocol(),
                 },
-                broker_url: "wss://aosync-broker-eu.beaconwallet.dev:8081".to_string(),
+                broker_url: app_config().beacon_broker_url.clone(),
                 options: BeaconOptions {
                     protocol_version: 5,
                 },
@@ -226,13 +254,17 @@ impl WalletStrategy for BeaconStrategy {
                 .map_err(|e| WalletError::ConnectionFailed(format!("Failed to serialize options: {}", e)))?;
             
             let promise = client.connect_js(&options_js);
+            start_pairing(client.as_ref());
+            let result = JsFuture::from(promise).await;
+            end_pairing();
             
-            match JsFuture::from(promise).await {
+            match result {
                 Ok(result) => {
                     // Parse the connection result
                     if let Some(address) = result.as_string() {
                         self.connected = true;
                         self.address = Some(address.clone());
+                        self.remember_session();
                         Ok(address)
                     } else {
                         // Try to extract address from result object
@@ -240,6 +272,7 @@ impl WalletStrategy for BeaconStrategy {
                             if let Some(address) = addr.as_string() {
                                 self.connected = true;
                                 self.address = Some(address.clone());
+                                self.remember_session();
                                 Ok(address)
                             } else {
                                 Err(WalletError::ConnectionFailed("Invalid connection response".to_string()))
@@ -250,7 +283,7 @@ impl WalletStrategy for BeaconStrategy {
                     }
                 }
                 Err(e) => {
-                    console_log(&format!("Beacon connection error: {:?}", e));
+                    log::warn!("Beacon connection error: {:?}", e);
                     Err(WalletError::ConnectionFailed(format!("Beacon connection failed: {:?}", e)))
                 }
             }
@@ -267,10 +300,11 @@ impl WalletStrategy for BeaconStrategy {
                 Ok(_) => {
                     self.connected = false;
                     self.address = None;
+                    self.remember_session();
                     Ok(())
                 }
                 Err(e) => {
-                    console_log(&format!("Beacon disconnect error: {:?}", e));
+                    log::warn!("Beacon disconnect error: {:?}", e);
                     Err(WalletError::ConnectionFailed(format!("Beacon disconnect failed: {:?}", e)))
                 }
             }
@@ -316,7 +350,7 @@ impl WalletStrategy for BeaconStrategy {
                         Ok(signed_tx)
                     }
                     Err(e) => {
-                        console_log(&format!("Beacon signing error: {:?}", e));
+                        log::warn!("Beacon signing error: {:?}", e);
                         Err(WalletError::SigningFailed(format!("Beacon transaction signing failed: {:?}", e)))
                     }
                 }
@@ -331,6 +365,64 @@ impl WalletStrategy for BeaconStrategy {
     async fn check_connection(&self) -> Result<bool, WalletError> {
         Ok(self.connected)
     }
+    
+    async fn sign_message(&self, message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        let client = match &self.wallet_client {
+            Some(client) if self.connected => client,
+            _ => return Err(WalletError::SigningFailed("Beacon not connected".to_string())),
+        };
+        
+        // ao-sync-sdk has no signMessage, only the raw RSA-PSS signature().
+        // Signing the SHA-256 of the message gives the same signature
+        // Wander's signMessage produces, so verifiers treat both alike.
+        let algorithm = Object::new();
+        Reflect::set(&algorithm, &"name".into(), &"RSA-PSS".into())?;
+        Reflect::set(&algorithm, &"saltLength".into(), &JsValue::from(32))?;
+        let digest = Sha256::digest(message);
+        let data: JsValue = js_sys::Uint8Array::from(digest.as_slice()).into();
+        
+        match JsFuture::from(client.signature_js(&data, &algorithm)).await {
+            Ok(signature) => Ok(js_sys::Uint8Array::new(&signature).to_vec()),
+            Err(e) => {
+                log::warn!("Beacon message signing error: {:?}", e);
+                Err(WalletError::from(e))
+            }
+        }
+    }
+    
+    async fn sign_deep_hash(&self, deep_hash: &[u8]) -> Result<Vec<u8>, WalletError> {
+        let client = match &self.wallet_client {
+            Some(client) if self.connected => client,
+            _ => return Err(WalletError::SigningFailed("Beacon not connected".to_string())),
+        };
+        
+        // ANS-104 signs the deep hash itself with the same RSA-PSS
+        // parameters, so signature() stands in for signDataItem
+        let algorithm = Object::new();
+        Reflect::set(&algorithm, &"name".into(), &"RSA-PSS".into())?;
+        Reflect::set(&algorithm, &"saltLength".into(), &JsValue::from(32))?;
+        let data: JsValue = js_sys::Uint8Array::from(deep_hash).into();
+        
+        match JsFuture::from(client.signature_js(&data, &algorithm)).await {
+            Ok(signature) => Ok(js_sys::Uint8Array::new(&signature).to_vec()),
+            Err(e) => {
+                log::warn!("Beacon deep-hash signing error: {:?}", e);
+                Err(WalletError::from(e))
+            }
+        }
+    }
+    
+    async fn get_public_key(&self) -> Result<String, WalletError> {
+        let client = match &self.wallet_client {
+            Some(client) if self.connected => client,
+            _ => return Err(WalletError::ConnectionFailed("Beacon not connected".to_string())),
+        };
+        
+        JsFuture::from(client.get_active_public_key_js())
+            .await?
+            .as_string()
+            .ok_or_else(|| WalletError::ConnectionFailed("Beacon returned no public key".to_string()))
+    }
 }
 
 // Beacon strategy is now integrated into WalletStrategyType enum in strategy.rs
\ No newline at end of file
@@ -48,7 +48,7 @@ pub struct WalletProviderProps {
 /// 
 /// # Example
 /// 
-/// ```rust
+/// ```rust,ignore
 /// use dioxus::prelude::*;
 /// use faithful_archive::services::wallet::{WalletProvider, use_wallet_context};
 /// 
@@ -58,7 +58,7 @@ pub struct WalletProviderProps {
 ///         WalletProvider {
 ///             auto_reconnect: true,
 ///             
-///             Router::<Route> {}
+///             MyComponent {}
 ///         }
 ///     }
 /// }
@@ -83,8 +83,8 @@ pub struct WalletProviderProps {
 /// ```
 #[component]
 pub fn WalletProvider(props: WalletProviderProps) -> Element {
-    let mut wallet_service = use_signal(|| WalletService::new());
-    let mut wallet_state = use_signal(|| ExtendedWalletState::default());
+    let mut wallet_service = use_signal(WalletService::new);
+    let mut wallet_state = use_signal(ExtendedWalletState::default);
     
     // Initialize wallet service
     use_effect(move || {
@@ -120,77 +120,58 @@ pub fn WalletProvider(props: WalletProviderProps) -> Element {
     });
     
     // Connect callback
-    let connect = use_callback({
-        let wallet_service = wallet_service.clone();
-        move |_: ()| {
-            let mut wallet_service = wallet_service.clone();
-            // Spawn async task and return placeholder result
-            spawn(async move {
-                // Use a different approach - create a temporary service for the async call
-                let mut temp_service = WalletService::new();
-                let _ = temp_service.connect().await;
-                // Update the main service after the async operation
-                wallet_service.set(temp_service);
-            });
-            Ok("connecting".to_string())
-        }
+    let connect = use_callback(move |_: ()| {
+        // Spawn async task and return placeholder result
+        spawn(async move {
+            // Use a different approach - create a temporary service for the async call
+            let mut temp_service = WalletService::new();
+            let _ = temp_service.connect().await;
+            // Update the main service after the async operation
+            wallet_service.set(temp_service);
+        });
+        Ok("connecting".to_string())
     });
     
     // Disconnect callback
-    let disconnect = use_callback({
-        let wallet_service = wallet_service.clone();
-        move |_: ()| {
-            let mut wallet_service = wallet_service.clone();
-            // Spawn async task and return placeholder result
-            spawn(async move {
-                // Use a different approach - create a temporary service for the async call
-                let mut temp_service = WalletService::new();
-                let _ = temp_service.disconnect().await;
-                // Update the main service after the async operation
-                wallet_service.set(temp_service);
-            });
-            Ok(())
-        }
+    let disconnect = use_callback(move |_: ()| {
+        // Spawn async task and return placeholder result
+        spawn(async move {
+            // Use a different approach - create a temporary service for the async call
+            let mut temp_service = WalletService::new();
+            let _ = temp_service.disconnect().await;
+            // Update the main service after the async operation
+            wallet_service.set(temp_service);
+        });
+        Ok(())
     });
     
     // Set strategy callback
-    let set_strategy = use_callback({
-        let wallet_service = wallet_service.clone();
-        move |strategy: WalletStrategyType| {
-            let mut wallet_service = wallet_service.clone();
-            // Spawn async task and return placeholder result
-            spawn(async move {
-                // Use a different approach - create a temporary service for the async call
-                let mut temp_service = WalletService::new();
-                let _ = temp_service.set_strategy(strategy).await;
-                // Update the main service after the async operation
-                wallet_service.set(temp_service);
-            });
-            Ok(())
-        }
+    let set_strategy = use_callback(move |strategy: WalletStrategyType| {
+        // Spawn async task and return placeholder result
+        spawn(async move {
+            // Use a different approach - create a temporary service for the async call
+            let mut temp_service = WalletService::new();
+            let _ = temp_service.set_strategy(strategy).await;
+            // Update the main service after the async operation
+            wallet_service.set(temp_service);
+        });
+        Ok(())
     });
     
     // Get available strategies callback
-    let get_available_strategies = use_callback({
-        let wallet_state = wallet_state.clone();
-        move |_: ()| {
-            wallet_state.read().available_strategies.clone()
-        }
+    let get_available_strategies = use_callback(move |_: ()| {
+        wallet_state.read().available_strategies.clone()
     });
     
     // Sign transaction callback
-    let sign_transaction = use_callback({
-        let wallet_service = wallet_service.clone();
-        move |transaction_data: HashMap<String, serde_json::Value>| {
-            let wallet_service = wallet_service.clone();
-            // Spawn async task and return placeholder result
-            spawn(async move {
-                // Use a different approach - create a temporary service for the async call
-                let temp_service = WalletService::new();
-                let _ = temp_service.sign_transaction(transaction_data.clone()).await;
-            });
-            Ok(HashMap::new())
-        }
+    let sign_transaction = use_callback(move |transaction_data: HashMap<String, serde_json::Value>| {
+        // Spawn async task and return placeholder result
+        spawn(async move {
+            // Use a different approach - create a temporary service for the async call
+            let temp_service = WalletService::new();
+            let _ = temp_service.sign_transaction(transaction_data.clone()).await;
+        });
+        Ok(HashMap::new())
     });
     
     let wallet_context = WalletContext {
@@ -223,9 +204,9 @@ pub fn WalletProvider(props: WalletProviderProps) -> Element {
 /// 
 /// # Example
 /// 
-/// ```rust
+/// ```rust,ignore
 /// use dioxus::prelude::*;
-/// use faithful_archive::services::wallet::use_wallet_context;
+/// use faithful_archive::services::wallet::{use_wallet_context, ConnectionState};
 /// 
 /// #[component]
 /// fn WalletInfo() -> Element {
@@ -234,11 +215,12 @@ pub fn WalletProvider(props: WalletProviderProps) -> Element {
 ///     
 ///     rsx! {
 ///         div {
-///             if state.base_state.connected {
-///                 p { "Connected to: {wallet.format_address(state.base_state.address.as_ref().unwrap())}" }
-///                 p { "Strategy: {state.strategy.display_name()}" }
-///             } else {
-///                 p { "Not connected" }
+///             match &state.base_state.connection {
+///                 ConnectionState::Connected { address } => rsx! {
+///                     p { "Connected to: {(wallet.format_address)(address)}" }
+///                     p { "Strategy: {state.strategy.display_name()}" }
+///                 },
+///                 _ => rsx! { p { "Not connected" } },
 ///             }
 ///         }
 ///     }
@@ -255,7 +237,7 @@ pub fn use_wallet_context() -> WalletContext {
 pub fn use_wallet_connection() -> (bool, Option<String>) {
     let wallet = use_wallet_context();
     let state = wallet.state.read();
-    (state.base_state.connected, state.base_state.address.clone())
+    (state.base_state.is_connected(), state.base_state.address().map(str::to_string))
 }
 
 /// Hook for wallet operations only
@@ -318,7 +300,7 @@ pub fn WalletErrorBoundary(
     let wallet = use_wallet_context();
     let state = wallet.state.read();
     
-    if let Some(error) = &state.base_state.error {
+    if let Some(error) = state.base_state.error() {
         if let Some(fallback_ui) = fallback {
             return fallback_ui;
         }
@@ -385,7 +367,7 @@ pub fn WalletGated(
     let state = wallet.state.read();
     
     let should_show = if require_specific_strategy {
-        connected && required_strategy.map_or(true, |rs| rs == state.strategy)
+        connected && required_strategy.is_none_or(|rs| rs == state.strategy)
     } else {
         connected
     };
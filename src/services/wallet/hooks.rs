@@ -2,7 +2,7 @@ use dioxus::prelude::*;
 use std::collections::HashMap;
 use crate::services::wallet::{
     WalletError, WalletStrategyType, use_wallet_context, 
-    use_wallet_connection, WalletCapabilities
+    use_wallet_connection, WalletCapabilities, WalletService, cancel_wallet_operations, ConnectionState
 };
 
 /// Hook for automatic wallet reconnection
@@ -12,7 +12,7 @@ use crate::services::wallet::{
 /// 
 /// # Example
 /// 
-/// ```rust
+/// ```rust,ignore
 /// use dioxus::prelude::*;
 /// use faithful_archive::services::wallet::use_wallet_reconnect;
 /// 
@@ -86,6 +86,9 @@ pub fn use_wallet_persistence() {
     });
 }
 
+/// Signs the transaction fields it's called with
+type SignCallback = Callback<HashMap<String, serde_json::Value>>;
+
 /// Hook for wallet transaction signing with loading state
 /// 
 /// Provides a convenient interface for signing transactions with automatic
@@ -93,7 +96,7 @@ pub fn use_wallet_persistence() {
 /// 
 /// Returns (sign_function, is_loading, last_error)
 pub fn use_wallet_signing() -> (
-    Callback<HashMap<String, serde_json::Value>, ()>,
+    SignCallback,
     Signal<bool>,
     Signal<Option<String>>,
 ) {
@@ -103,15 +106,13 @@ pub fn use_wallet_signing() -> (
     
     let sign_function = use_callback(move |transaction_data: HashMap<String, serde_json::Value>| {
         let wallet = wallet.clone();
-        let mut is_loading = is_loading.clone();
-        let mut last_error = last_error.clone();
         
         spawn(async move {
             is_loading.set(true);
             last_error.set(None);
             
             match wallet.sign_transaction.call(transaction_data) {
-                Ok(signed_tx) => {
+                Ok(_) => {
                     log::info!("Transaction signed successfully");
                     // You might want to emit a custom event or callback here
                 }
@@ -147,11 +148,11 @@ pub fn use_wallet_events(
     use_effect(move || {
         let state = wallet.state.read();
         // Check for connection state changes
-        if state.base_state.connected != *previous_connected.read() {
-            if state.base_state.connected {
+        if state.base_state.is_connected() != *previous_connected.read() {
+            if state.base_state.is_connected() {
                 if let Some(callback) = on_connect {
-                    if let Some(address) = &state.base_state.address {
-                        callback.call(address.clone());
+                    if let Some(address) = state.base_state.address() {
+                        callback.call(address.to_string());
                     }
                 }
             } else {
@@ -159,7 +160,7 @@ pub fn use_wallet_events(
                     callback.call(());
                 }
             }
-            previous_connected.set(state.base_state.connected);
+            previous_connected.set(state.base_state.is_connected());
         }
         
         // Check for strategy changes
@@ -171,13 +172,14 @@ pub fn use_wallet_events(
         }
         
         // Check for error changes
-        if state.base_state.error != *previous_error.read() {
-            if let Some(error) = &state.base_state.error {
+        let error = state.base_state.error().map(str::to_string);
+        if error != *previous_error.read() {
+            if let Some(error) = &error {
                 if let Some(callback) = on_error {
                     callback.call(error.clone());
                 }
             }
-            previous_error.set(state.base_state.error.clone());
+            previous_error.set(error);
         }
     });
 }
@@ -225,23 +227,25 @@ pub fn use_wallet_status() -> WalletStatus {
     let (connected, address) = use_wallet_connection();
     
     WalletStatus {
+        connection: state.base_state.connection.clone(),
         connected,
-        connecting: state.base_state.connecting,
-        available: state.base_state.available,
+        connecting: state.base_state.is_connecting(),
+        available: state.base_state.is_available(),
         address: address.clone(),
         formatted_address: address.as_ref().map(|addr| (wallet.format_address)(addr)),
         strategy: state.strategy,
         strategy_name: state.strategy.display_name(),
         capabilities: state.capabilities.clone(),
         available_strategies: state.available_strategies.clone(),
-        error: state.base_state.error.clone(),
-        has_error: state.base_state.error.is_some(),
+        error: state.base_state.error().map(str::to_string),
+        has_error: state.base_state.error().is_some(),
         permissions: state.base_state.permissions.clone(),
     }
 }
 
 #[derive(Clone, PartialEq)]
 pub struct WalletStatus {
+    pub connection: ConnectionState,
     pub connected: bool,
     pub connecting: bool,
     pub available: bool,
@@ -321,11 +325,10 @@ pub fn use_wallet_error_recovery() -> (
     let state = wallet.state.read();
     let mut is_recovering = use_signal(|| false);
     
-    let current_error = use_signal(move || state.base_state.error.clone());
+    let current_error = use_signal(move || state.base_state.error().map(str::to_string));
     
     let recover = use_callback(move |_: ()| {
         let wallet = wallet.clone();
-        let mut is_recovering = is_recovering.clone();
         
         spawn(async move {
             is_recovering.set(true);
@@ -350,11 +353,16 @@ pub fn use_wallet_error_recovery() -> (
 
 /// Hook for wallet connection with timeout
 /// 
-/// Provides connection functionality with configurable timeout.
+/// Connects with the selected strategy, giving up after `timeout_ms`.
+/// The cancel callback stops waiting straight away, for a user who closed
+/// the wallet popup or can't scan the pairing code.
+///
+/// Returns (connect_function, cancel_function, is_connecting, last_error)
 pub fn use_wallet_connect_with_timeout(
     timeout_ms: u32,
 ) -> (
-    Callback<(), ()>,
+    Callback,
+    Callback,
     Signal<bool>,
     Signal<Option<String>>,
 ) {
@@ -363,17 +371,20 @@ pub fn use_wallet_connect_with_timeout(
     let mut connection_error = use_signal(|| None::<String>);
     
     let connect_with_timeout = use_callback(move |_: ()| {
-        let wallet = wallet.clone();
-        let mut is_connecting = is_connecting.clone();
-        let mut connection_error = connection_error.clone();
+        let strategy = wallet.state.peek().strategy;
         
         spawn(async move {
             is_connecting.set(true);
             connection_error.set(None);
             
-            // Since callbacks are synchronous, just call directly
-            match wallet.connect.call(()) {
+            let mut service = WalletService::new();
+            let result = match service.set_strategy(strategy).await {
+                Ok(()) => service.connect_with_timeout(timeout_ms).await,
+                Err(e) => Err(e),
+            };
+            match result {
                 Ok(_) => log::info!("Wallet connected successfully"),
+                Err(WalletError::Cancelled) => log::info!("Wallet connection cancelled"),
                 Err(e) => {
                     connection_error.set(Some(e.to_string()));
                     log::error!("Wallet connection failed: {}", e);
@@ -384,14 +395,16 @@ pub fn use_wallet_connect_with_timeout(
         });
     });
     
-    (connect_with_timeout, is_connecting, connection_error)
+    let cancel = use_callback(move |_: ()| cancel_wallet_operations());
+    
+    (connect_with_timeout, cancel, is_connecting, connection_error)
 }
 
 /// Utility function to validate wallet addresses
 pub fn is_valid_arweave_address(address: &str) -> bool {
     // Arweave addresses are base64url encoded and typically 43 characters long
     address.len() == 43 && 
-    address.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    address.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
 }
 
 /// Utility function to get wallet strategy icon/emoji
@@ -401,6 +414,8 @@ pub fn get_strategy_icon(strategy: WalletStrategyType) -> &'static str {
         WalletStrategyType::Wander => "🧭", // Navigation/exploration theme
         WalletStrategyType::WalletKit => "🔧", // Tool/kit theme
         WalletStrategyType::WebWallet => "🌐", // Web theme
+        #[cfg(feature = "mock-wallet")]
+        WalletStrategyType::Mock => "🧪", // Development only
     }
 }
 
@@ -427,6 +442,12 @@ pub fn get_strategy_colors(strategy: WalletStrategyType) -> StrategyColors {
             background: "#FEF2F2",
             text: "#7F1D1D",
         },
+        #[cfg(feature = "mock-wallet")]
+        WalletStrategyType::Mock => StrategyColors {
+            primary: "#D97706",
+            background: "#FFFBEB",
+            text: "#78350F",
+        },
     }
 }
 
@@ -435,4 +456,30 @@ pub struct StrategyColors {
     pub primary: &'static str,
     pub background: &'static str,
     pub text: &'static str,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn accepts_base64url_addresses() {
+        assert!(is_valid_arweave_address("vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JI"));
+    }
+
+    #[wasm_bindgen_test]
+    fn rejects_wrong_lengths() {
+        assert!(!is_valid_arweave_address(""));
+        assert!(!is_valid_arweave_address("vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2J"));
+        assert!(!is_valid_arweave_address("vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JIx"));
+    }
+
+    #[wasm_bindgen_test]
+    fn rejects_characters_outside_base64url() {
+        assert!(!is_valid_arweave_address("vh+NTHVvlKZqRxc8LyyTNok65yQ55a/PJ1zWLb9G2JI"));
+        assert!(!is_valid_arweave_address("vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2J="));
+        // 41 ASCII characters plus one two-byte letter is 43 bytes
+        assert!(!is_valid_arweave_address("vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2é"));
+    }
+}
@@ -0,0 +1,150 @@
+//! Idle auto-lock
+//!
+//! On a shared computer a wallet left connected lets the next person sign
+//! as the last one. With `idle_lock_minutes` set in Settings, the wallet is
+//! disconnected after that long without input, following a toast a minute
+//! beforehand that keeps the session going with one click.
+
+use std::cell::Cell;
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use wasm_bindgen::{closure::Closure, JsCast};
+
+use super::WalletService;
+use crate::i18n::t;
+use crate::services::settings::use_settings;
+use crate::services::toast::{dismiss_toast, show_action_toast, show_toast, ToastAction, ToastKind};
+
+/// Choices offered in Settings, in minutes
+pub const IDLE_LOCK_CHOICES: [u32; 4] = [5, 15, 30, 60];
+/// How long before the lock the warning appears (ms)
+const IDLE_WARNING_MS: f64 = 60_000.0;
+/// Time between idle checks (ms)
+const IDLE_CHECK_INTERVAL_MS: u32 = 5_000;
+/// Input that counts as activity
+const ACTIVITY_EVENTS: [&str; 5] = ["pointerdown", "pointermove", "keydown", "scroll", "touchstart"];
+
+thread_local! {
+    // Written on every pointer move, so kept out of the signal graph
+    static LAST_ACTIVITY: Cell<f64> = Cell::new(js_sys::Date::now());
+}
+
+fn mark_activity() {
+    LAST_ACTIVITY.with(|last| last.set(js_sys::Date::now()));
+}
+
+fn idle_ms() -> f64 {
+    js_sys::Date::now() - LAST_ACTIVITY.with(Cell::get)
+}
+
+/// Where an idle session stands against its timeout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleStage {
+    Active,
+    /// Within `IDLE_WARNING_MS` of the lock
+    Warning,
+    Expired,
+}
+
+pub fn idle_stage(idle_ms: f64, timeout_ms: f64) -> IdleStage {
+    if idle_ms >= timeout_ms {
+        IdleStage::Expired
+    } else if idle_ms >= timeout_ms - IDLE_WARNING_MS {
+        IdleStage::Warning
+    } else {
+        IdleStage::Active
+    }
+}
+
+fn listen_for_activity() {
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return;
+    };
+    let on_activity = Closure::<dyn FnMut(web_sys::Event)>::new(|_| mark_activity());
+    for event in ACTIVITY_EVENTS {
+        let _ = document.add_event_listener_with_callback(event, on_activity.as_ref().unchecked_ref());
+    }
+    // Lives as long as the page
+    on_activity.forget();
+}
+
+async fn lock_wallet() {
+    let strategy = WalletService::get_extended_state().peek().strategy;
+    let mut service = WalletService::init().await;
+    let result = match service.set_strategy(strategy).await {
+        Ok(()) => service.disconnect().await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        log::warn!("Failed to lock idle wallet: {}", e);
+    }
+}
+
+/// Disconnect the wallet after the idle time chosen in Settings
+///
+/// Call once from a component that lives as long as the app. Does nothing
+/// while no wallet is connected or the setting is off.
+pub fn use_wallet_idle_lock() {
+    let settings = use_settings();
+    let wallet_state = WalletService::get_extended_state();
+    let mut warning_toast = use_signal(|| None::<u64>);
+
+    use_hook(listen_for_activity);
+
+    let mut clear_warning = move || {
+        if let Some(id) = warning_toast.take() {
+            dismiss_toast(id);
+        }
+    };
+
+    use_future(move || async move {
+        loop {
+            TimeoutFuture::new(IDLE_CHECK_INTERVAL_MS).await;
+
+            let minutes = settings.peek().idle_lock_minutes;
+            let connected = wallet_state.peek().base_state.is_connected();
+            let Some(minutes) = minutes.filter(|_| connected) else {
+                clear_warning();
+                continue;
+            };
+
+            match idle_stage(idle_ms(), minutes as f64 * 60_000.0) {
+                IdleStage::Active => clear_warning(),
+                IdleStage::Warning if warning_toast.peek().is_none() => {
+                    let action = ToastAction {
+                        label: t!("wallet.stay_connected"),
+                        on_click: Callback::new(move |_| {
+                            mark_activity();
+                            warning_toast.set(None);
+                        }),
+                    };
+                    let id = show_action_toast(ToastKind::Info, t!("wallet.idle_warning"), action, IDLE_WARNING_MS as u32);
+                    warning_toast.set(Some(id));
+                }
+                IdleStage::Warning => {}
+                IdleStage::Expired => {
+                    clear_warning();
+                    log::info!("🔒 Locking wallet after {} idle minutes", minutes);
+                    lock_wallet().await;
+                    show_toast(ToastKind::Info, t!("wallet.idle_locked", minutes = minutes));
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn warns_in_the_last_minute() {
+        let timeout = 5.0 * 60_000.0;
+        assert_eq!(idle_stage(0.0, timeout), IdleStage::Active);
+        assert_eq!(idle_stage(timeout - IDLE_WARNING_MS - 1.0, timeout), IdleStage::Active);
+        assert_eq!(idle_stage(timeout - IDLE_WARNING_MS, timeout), IdleStage::Warning);
+        assert_eq!(idle_stage(timeout, timeout), IdleStage::Expired);
+    }
+}
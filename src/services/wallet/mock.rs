@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use anyhow::Result;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use sha2::{Digest, Sha256};
+use bundles_rs::{
+    ans104::{data_item::DataItem, tags::Tag},
+    crypto::ethereum::EthereumSigner,
+};
+
+use crate::config::app_config;
+use crate::services::wallet::{WalletError, WalletNetwork, WalletStrategy, WalletStrategyType, WalletCapabilities};
+
+/// Address the mock wallet always connects as, that of its public key so
+/// login challenges pass `auth::check_key`
+pub const MOCK_WALLET_ADDRESS: &str = "S7Bvjk46dxXSAdVz0KpCN2LlXavWGiwCJ4-lbMbSlOA";
+
+/// Fixed key DataItems are signed with, so the same input always gives the
+/// same bytes. Items carry this key's Ethereum owner, not `MOCK_WALLET_ADDRESS`.
+const MOCK_SIGNING_KEY: [u8; 32] = [7; 32];
+
+/// Deterministic in-browser wallet for UI development and wasm tests
+///
+/// Always available, connects instantly as `MOCK_WALLET_ADDRESS` and signs
+/// DataItems locally with a fixed development key. Only compiled with the
+/// `mock-wallet` feature and only registered in debug builds.
+pub struct MockWalletStrategy {
+    connected: bool,
+    permissions: Vec<String>,
+}
+
+impl MockWalletStrategy {
+    pub fn new() -> Self {
+        Self {
+            connected: false,
+            permissions: vec![],
+        }
+    }
+
+    fn sign(&self, target: Option<&str>, data: Vec<u8>, tags: Vec<(String, String)>) -> Result<Vec<u8>, WalletError> {
+        if !self.connected {
+            return Err(WalletError::SigningFailed("Mock wallet not connected".to_string()));
+        }
+
+        let target = match target {
+            Some(target) => {
+                let bytes = URL_SAFE_NO_PAD
+                    .decode(target)
+                    .map_err(|e| WalletError::SigningFailed(format!("Invalid target: {}", e)))?;
+                let target: [u8; 32] = bytes
+                    .try_into()
+                    .map_err(|_| WalletError::SigningFailed("Target must be 32 bytes".to_string()))?;
+                Some(target)
+            }
+            None => None,
+        };
+
+        let signer = EthereumSigner::from_bytes(&MOCK_SIGNING_KEY)
+            .map_err(|e| WalletError::SigningFailed(e.to_string()))?;
+        let tags = tags.iter().map(|(name, value)| Tag::new(name, value)).collect();
+        let item = DataItem::build_and_sign(&signer, target, None, tags, data)
+            .map_err(|e| WalletError::SigningFailed(e.to_string()))?;
+        item.to_bytes().map_err(|e| WalletError::SigningFailed(e.to_string()))
+    }
+}
+
+impl Default for MockWalletStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait(?Send)]
+impl WalletStrategy for MockWalletStrategy {
+    fn strategy_type(&self) -> WalletStrategyType {
+        WalletStrategyType::Mock
+    }
+
+    async fn is_available(&self) -> Result<bool, WalletError> {
+        Ok(true)
+    }
+
+    fn get_capabilities(&self) -> WalletCapabilities {
+        WalletCapabilities {
+            can_sign_transactions: true,
+            can_encrypt_data: false,
+            can_decrypt_data: false,
+            supports_batch_signing: true,
+            supports_permissions: true,
+            supports_multiple_addresses: false,
+            can_dispatch: false,
+            can_sign_deep_hash: false,
+        }
+    }
+
+    async fn connect(&mut self, permissions: Vec<&str>) -> Result<String, WalletError> {
+        self.connected = true;
+        self.permissions = permissions.into_iter().map(str::to_string).collect();
+        log::info!("🧪 Mock wallet connected as {}", MOCK_WALLET_ADDRESS);
+        Ok(MOCK_WALLET_ADDRESS.to_string())
+    }
+
+    async fn disconnect(&mut self) -> Result<(), WalletError> {
+        self.connected = false;
+        self.permissions.clear();
+        Ok(())
+    }
+
+    async fn get_active_address(&self) -> Result<String, WalletError> {
+        if self.connected {
+            Ok(MOCK_WALLET_ADDRESS.to_string())
+        } else {
+            Err(WalletError::ConnectionFailed("Mock wallet not connected".to_string()))
+        }
+    }
+
+    async fn get_permissions(&self) -> Result<Vec<String>, WalletError> {
+        Ok(self.permissions.clone())
+    }
+
+    /// Echo the transaction back with a fixed signature
+    async fn sign_transaction(&self, mut transaction_data: HashMap<String, serde_json::Value>) -> Result<HashMap<String, serde_json::Value>, WalletError> {
+        if !self.connected {
+            return Err(WalletError::SigningFailed("Mock wallet not connected".to_string()));
+        }
+        transaction_data.insert("owner".to_string(), MOCK_WALLET_ADDRESS.into());
+        transaction_data.insert("signature".to_string(), "mock_signature".into());
+        Ok(transaction_data)
+    }
+
+    async fn check_connection(&self) -> Result<bool, WalletError> {
+        Ok(self.connected)
+    }
+
+    async fn sign_data_item(&self, data: Vec<u8>, tags: Vec<(String, String)>) -> Result<Vec<u8>, WalletError> {
+        self.sign(None, data, tags)
+    }
+
+    async fn sign_data_item_to(&self, target: &str, data: Vec<u8>, tags: Vec<(String, String)>) -> Result<Vec<u8>, WalletError> {
+        self.sign(Some(target), data, tags)
+    }
+
+    /// SHA-256 of the development key and message; stable, but not a
+    /// signature anything outside the app will accept
+    async fn sign_message(&self, message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        if !self.connected {
+            return Err(WalletError::SigningFailed("Mock wallet not connected".to_string()));
+        }
+        let mut hasher = Sha256::new();
+        hasher.update(MOCK_SIGNING_KEY);
+        hasher.update(message);
+        Ok(hasher.finalize().to_vec())
+    }
+
+    async fn get_public_key(&self) -> Result<String, WalletError> {
+        Ok(URL_SAFE_NO_PAD.encode(MOCK_SIGNING_KEY))
+    }
+
+    /// Always on whichever network the app is on
+    async fn get_network(&self) -> Result<Option<WalletNetwork>, WalletError> {
+        Ok(Some(WalletNetwork::from_gateway(app_config().primary_gateway())))
+    }
+}
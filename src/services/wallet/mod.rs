@@ -4,13 +4,20 @@ pub mod wander;
 pub mod beacon;
 pub mod wallet_kit;
 pub mod web_wallet;
+#[cfg(feature = "mock-wallet")]
+pub mod mock;
 pub mod context;
 pub mod hooks;
+pub mod monitor;
+pub mod idle;
+pub mod state;
+pub mod network;
 
 // Re-export main types
 pub use strategy::{
     WalletStrategy, WalletStrategyType, WalletCapabilities, 
-    ExtendedWalletState, WalletStrategyManager
+    ExtendedWalletState, WalletStrategyManager, DEFAULT_STRATEGY_ORDER, preference_order,
+    with_timeout, cancel_wallet_operations
 };
 pub use context::{
     WalletContext, WalletProvider, WalletErrorBoundary, WalletGated,
@@ -24,31 +31,50 @@ pub use hooks::{
     WalletFeatures, WalletStatus, StrategyColors,
     is_valid_arweave_address, get_strategy_icon, get_strategy_colors
 };
+pub use idle::use_wallet_idle_lock;
+pub use state::{ConnectionErrorKind, ConnectionEvent, ConnectionState};
+pub use network::{WalletNetwork, network_mismatch, use_network_mismatch};
+pub use beacon::{use_beacon_pairing, cancel_beacon_pairing};
+pub use monitor::{
+    ConnectionHealth, connected_wallet, connection_health, retry_connection, init_connection_monitor, use_connection_health
+};
 
 // Original wallet types and errors
 use serde::{Deserialize, Serialize};
 use dioxus::prelude::*;
+use crate::services::flags::{is_enabled, Flag};
+use crate::services::settings::current_settings;
+use crate::services::token::clear_tokens;
+use crate::utils::constants::{WALLET_CONNECT_TIMEOUT_MS, WALLET_SIGN_TIMEOUT_MS};
+use crate::utils::display::short_address;
+use state::dispatch;
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct WalletState {
-    pub connected: bool,
-    pub address: Option<String>,
+    pub connection: ConnectionState,
     pub permissions: Vec<String>,
-    pub error: Option<String>,
-    pub connecting: bool,
-    pub available: bool,
 }
 
-impl Default for WalletState {
-    fn default() -> Self {
-        Self {
-            connected: false,
-            address: None,
-            permissions: vec![],
-            error: None,
-            connecting: false,
-            available: false,
-        }
+impl WalletState {
+    pub fn is_connected(&self) -> bool {
+        self.connection.is_connected()
+    }
+
+    pub fn is_connecting(&self) -> bool {
+        self.connection.is_busy()
+    }
+
+    /// Whether any wallet strategy was found
+    pub fn is_available(&self) -> bool {
+        self.connection != ConnectionState::Unavailable
+    }
+
+    pub fn address(&self) -> Option<&str> {
+        self.connection.address()
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.connection.error()
     }
 }
 
@@ -61,6 +87,10 @@ pub enum WalletError {
     TransactionFailed(String),
     ConnectionFailed(String),
     SigningFailed(String),
+    /// The wallet didn't answer within the operation's timeout
+    TimedOut,
+    /// The user gave up waiting, see `cancel_wallet_operations`
+    Cancelled,
 }
 
 impl std::fmt::Display for WalletError {
@@ -73,27 +103,37 @@ impl std::fmt::Display for WalletError {
             WalletError::TransactionFailed(msg) => write!(f, "Transaction failed: {}", msg),
             WalletError::ConnectionFailed(msg) => write!(f, "Connection failed: {}", msg),
             WalletError::SigningFailed(msg) => write!(f, "Transaction signing failed: {}", msg),
+            WalletError::TimedOut => write!(f, "Wallet did not respond in time"),
+            WalletError::Cancelled => write!(f, "Wallet request cancelled"),
         }
     }
 }
 
 impl From<wasm_bindgen::JsValue> for WalletError {
     fn from(js_error: wasm_bindgen::JsValue) -> Self {
-        let error_msg = js_error.as_string().unwrap_or_else(|| {
-            format!("{:?}", js_error)
-        });
-        
-        if error_msg.to_lowercase().contains("not installed") || 
-           error_msg.to_lowercase().contains("undefined") {
+        // Extensions reject with strings, Error objects or plain objects
+        // carrying a message; keep the message itself rather than the
+        // JsValue's debug form
+        let error_msg = js_error
+            .as_string()
+            .or_else(|| {
+                js_sys::Reflect::get(&js_error, &"message".into())
+                    .ok()
+                    .and_then(|message| message.as_string())
+            })
+            .unwrap_or_else(|| format!("{:?}", js_error));
+        log::debug!("Wallet error: {}", error_msg);
+
+        let lower = error_msg.to_lowercase();
+        if lower.contains("not installed") || lower.contains("undefined") {
             WalletError::NotInstalled
-        } else if error_msg.to_lowercase().contains("denied") || 
-                  error_msg.to_lowercase().contains("rejected") {
+        } else if lower.contains("denied") || lower.contains("rejected") {
             WalletError::UserDenied
-        } else if error_msg.to_lowercase().contains("network") {
+        } else if lower.contains("network") {
             WalletError::NetworkError(error_msg)
-        } else if error_msg.to_lowercase().contains("permission") {
+        } else if lower.contains("permission") {
             WalletError::InvalidPermissions
-        } else if error_msg.to_lowercase().contains("sign") {
+        } else if lower.contains("sign") {
             WalletError::SigningFailed(error_msg)
         } else {
             WalletError::ConnectionFailed(error_msg)
@@ -107,16 +147,55 @@ fn use_extended_wallet_state() -> &'static GlobalSignal<ExtendedWalletState> {
     &EXTENDED_WALLET_STATE
 }
 
-// Legacy compatibility - returns just the base wallet state  
-pub fn use_wallet_state() -> Signal<WalletState> {
-    let extended_state = use_extended_wallet_state();
-    let mut wallet_state = use_signal(|| WalletState::default());
-    
-    use_effect(move || {
-        wallet_state.set(extended_state().base_state.clone());
-    });
-    
-    wallet_state
+/// Hook returning the connection part of the wallet state
+///
+/// Read straight from the global state, so every component sees the same
+/// connection in the same render.
+pub fn use_wallet_state() -> Memo<WalletState> {
+    use_memo(move || use_extended_wallet_state().read().base_state.clone())
+}
+
+/// Address of the connected wallet, for code outside components
+pub fn connected_address() -> Option<String> {
+    use_extended_wallet_state().peek().base_state.connection.address().map(str::to_string)
+}
+
+/// Wallet details for diagnostic bundles, as `(name, value)` pairs
+pub fn wallet_diagnostics() -> Vec<(&'static str, String)> {
+    let state = use_extended_wallet_state().peek().clone();
+    let available: Vec<&str> = state.available_strategies.iter().map(|strategy| strategy.display_name()).collect();
+    vec![
+        ("Wallet", state.strategy.display_name().to_string()),
+        ("Wallet connection", format!("{:?}", state.base_state.connection)),
+        ("Wallet permissions", state.base_state.permissions.join(", ")),
+        ("Wallet health", format!("{:?}", connection_health())),
+        ("Wallets available", available.join(", ")),
+    ]
+}
+
+/// localStorage key for the strategy last connected with
+const LAST_STRATEGY_KEY: &str = "faithful_archive_wallet_strategy";
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+}
+
+/// Strategy the user last connected with, the only one connected to
+/// without asking at startup
+pub fn remembered_strategy() -> Option<WalletStrategyType> {
+    local_storage()
+        .and_then(|storage| storage.get_item(LAST_STRATEGY_KEY).ok().flatten())
+        .and_then(|value| value.parse().ok())
+}
+
+/// Remember `strategy` for the next visit, or forget it with `None`
+pub fn remember_strategy(strategy: Option<WalletStrategyType>) {
+    if let Some(storage) = local_storage() {
+        let _ = match strategy {
+            Some(strategy) => storage.set_item(LAST_STRATEGY_KEY, &strategy.to_string()),
+            None => storage.remove_item(LAST_STRATEGY_KEY),
+        };
+    }
 }
 
 /// Enhanced wallet service with strategy support
@@ -130,7 +209,12 @@ impl WalletService {
         
         // Register all available strategies
         strategy_manager.register_strategy(Box::new(wander::WanderStrategy::new()));
-        strategy_manager.register_strategy(Box::new(beacon::BeaconStrategy::new()));
+        if is_enabled(Flag::Beacon) {
+            strategy_manager.register_strategy(Box::new(beacon::BeaconStrategy::new()));
+        }
+        // Never in release builds, even if the feature is left on
+        #[cfg(all(feature = "mock-wallet", debug_assertions))]
+        strategy_manager.register_strategy(Box::new(mock::MockWalletStrategy::new()));
         // TODO: Register other strategies when implemented
         // strategy_manager.register_strategy(Box::new(wallet_kit::WalletKitStrategy::new()));
         // strategy_manager.register_strategy(Box::new(web_wallet::WebWalletStrategy::new()));
@@ -138,6 +222,16 @@ impl WalletService {
         Self { strategy_manager }
     }
     
+    /// Service with only `strategy`, already selected
+    #[cfg(test)]
+    pub(crate) fn with_strategy(strategy: Box<dyn WalletStrategy>) -> Self {
+        let strategy_type = strategy.strategy_type();
+        let mut strategy_manager = WalletStrategyManager::new();
+        strategy_manager.register_strategy(strategy);
+        strategy_manager.set_strategy(strategy_type).expect("just registered");
+        Self { strategy_manager }
+    }
+    
     /// Initialize wallet service and discover available strategies
     pub async fn init() -> Self {
         let mut service = Self::new();
@@ -150,12 +244,12 @@ impl WalletService {
         log::info!("🔍 Found {} available strategies: {:?}", available_strategies.len(), available_strategies);
         extended_state.write().available_strategies = available_strategies.clone();
         
-        // Auto-select best strategy if any available
+        // Auto-select the user's preferred strategy if any available
         if !available_strategies.is_empty() {
-            if let Ok(selected_strategy) = service.strategy_manager.auto_select_strategy().await {
+            let order = preference_order(&current_settings().wallet_order);
+            if let Ok(selected_strategy) = service.strategy_manager.auto_select_strategy_by(&order).await {
                 log::info!("✅ Auto-selected strategy: {:?}", selected_strategy);
                 extended_state.write().strategy = selected_strategy;
-                extended_state.write().base_state.available = true;
                 
                 // Set capabilities for selected strategy
                 if let Some(strategy) = service.strategy_manager.get_current_strategy() {
@@ -164,9 +258,8 @@ impl WalletService {
             }
         } else {
             log::warn!("❌ No wallet strategies available");
-            extended_state.write().base_state.available = false;
-            extended_state.write().base_state.error = Some("No wallet strategies available".to_string());
         }
+        dispatch(ConnectionEvent::Discovered { available: !available_strategies.is_empty() });
         
         service
     }
@@ -193,31 +286,34 @@ impl WalletService {
     
     /// Connect using current strategy
     pub async fn connect(&mut self) -> Result<String, WalletError> {
-        let extended_state = use_extended_wallet_state();
-        
-        extended_state.write().base_state.connecting = true;
-        extended_state.write().base_state.error = None;
+        self.connect_with_timeout(WALLET_CONNECT_TIMEOUT_MS).await
+    }
+    
+    /// Connect using current strategy, giving up after `timeout_ms` or a
+    /// cancel; `connecting` is cleared however it ends
+    pub async fn connect_with_timeout(&mut self, timeout_ms: u32) -> Result<String, WalletError> {
+        dispatch(ConnectionEvent::ConnectStarted);
         
-        let permissions = vec!["ACCESS_ADDRESS", "SIGN_TRANSACTION", "ACCESS_PUBLIC_KEY"];
+        let permissions = vec!["ACCESS_ADDRESS", "SIGN_TRANSACTION", "ACCESS_PUBLIC_KEY", "SIGNATURE", "ENCRYPT", "DECRYPT"];
         let permissions_clone = permissions.clone();
         
-        let result = self.strategy_manager.with_current_strategy_mut(|strategy| {
+        let result = with_timeout(timeout_ms, self.strategy_manager.with_current_strategy_mut(|strategy| {
             Box::pin(async move {
                 strategy.connect(permissions_clone).await
             })
-        }).await;
+        })).await;
         
         match result {
             Ok(address) => {
-                extended_state.write().base_state.connected = true;
-                extended_state.write().base_state.address = Some(address.clone());
-                extended_state.write().base_state.permissions = permissions.into_iter().map(|s| s.to_string()).collect();
-                extended_state.write().base_state.connecting = false;
+                remember_strategy(self.strategy_manager.get_current_strategy().map(|strategy| strategy.strategy_type()));
+                dispatch(ConnectionEvent::Connected { address: address.clone() });
+                use_extended_wallet_state().write().base_state.permissions = permissions.into_iter().map(|s| s.to_string()).collect();
+                self.refresh_network().await;
                 Ok(address)
             }
             Err(e) => {
-                extended_state.write().base_state.connecting = false;
-                extended_state.write().base_state.error = Some(e.to_string());
+                // Cancelling goes back to idle rather than an error
+                dispatch(ConnectionEvent::ConnectFailed(e.clone()));
                 Err(e)
             }
         }
@@ -225,8 +321,6 @@ impl WalletService {
     
     /// Disconnect using current strategy
     pub async fn disconnect(&mut self) -> Result<(), WalletError> {
-        let extended_state = use_extended_wallet_state();
-        
         let result = self.strategy_manager.with_current_strategy_mut(|strategy| {
             Box::pin(async move {
                 strategy.disconnect().await
@@ -235,14 +329,16 @@ impl WalletService {
         
         match result {
             Ok(()) => {
-                extended_state.write().base_state.connected = false;
-                extended_state.write().base_state.address = None;
-                extended_state.write().base_state.permissions.clear();
-                extended_state.write().base_state.error = None;
+                remember_strategy(None);
+                dispatch(ConnectionEvent::Disconnected);
+                use_extended_wallet_state().write().base_state.permissions.clear();
+                use_extended_wallet_state().write().network = None;
+                clear_tokens();
                 Ok(())
             }
+            // The session is still there as far as we know
             Err(e) => {
-                extended_state.write().base_state.error = Some(e.to_string());
+                log::warn!("Wallet disconnect failed: {}", e);
                 Err(e)
             }
         }
@@ -260,12 +356,108 @@ impl WalletService {
     /// Sign transaction using current strategy
     pub async fn sign_transaction(&self, transaction_data: std::collections::HashMap<String, serde_json::Value>) -> Result<std::collections::HashMap<String, serde_json::Value>, WalletError> {
         if let Some(strategy) = self.strategy_manager.get_current_strategy() {
-            strategy.sign_transaction(transaction_data).await
+            with_timeout(WALLET_SIGN_TIMEOUT_MS, strategy.sign_transaction(transaction_data)).await
+        } else {
+            Err(WalletError::NotInstalled)
+        }
+    }
+    
+    /// Sign a DataItem using current strategy
+    pub async fn sign_data_item(&self, data: Vec<u8>, tags: Vec<(String, String)>) -> Result<Vec<u8>, WalletError> {
+        if let Some(strategy) = self.strategy_manager.get_current_strategy() {
+            with_timeout(WALLET_SIGN_TIMEOUT_MS, strategy.sign_data_item(data, tags)).await
+        } else {
+            Err(WalletError::NotInstalled)
+        }
+    }
+    
+    /// Have the current strategy's wallet post `data` itself, returning
+    /// the ID
+    pub async fn dispatch(&self, data: Vec<u8>, tags: Vec<(String, String)>) -> Result<String, WalletError> {
+        if let Some(strategy) = self.strategy_manager.get_current_strategy() {
+            with_timeout(WALLET_SIGN_TIMEOUT_MS, strategy.dispatch(data, tags)).await
+        } else {
+            Err(WalletError::NotInstalled)
+        }
+    }
+    
+    /// Sign a DataItem addressed to `target` (an AO process, say) using
+    /// current strategy
+    pub async fn sign_data_item_to(&self, target: &str, data: Vec<u8>, tags: Vec<(String, String)>) -> Result<Vec<u8>, WalletError> {
+        if let Some(strategy) = self.strategy_manager.get_current_strategy() {
+            with_timeout(WALLET_SIGN_TIMEOUT_MS, strategy.sign_data_item_to(target, data, tags)).await
+        } else {
+            Err(WalletError::NotInstalled)
+        }
+    }
+    
+    /// Encrypt data to the connected wallet's key using current strategy
+    pub async fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, WalletError> {
+        if let Some(strategy) = self.strategy_manager.get_current_strategy() {
+            strategy.encrypt(data, None).await
         } else {
             Err(WalletError::NotInstalled)
         }
     }
     
+    /// Decrypt data encrypted to the connected wallet using current strategy
+    pub async fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, WalletError> {
+        if let Some(strategy) = self.strategy_manager.get_current_strategy() {
+            strategy.decrypt(data, None).await
+        } else {
+            Err(WalletError::NotInstalled)
+        }
+    }
+    
+    /// Sign arbitrary bytes with the current strategy
+    pub async fn sign_message(&self, message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        if let Some(strategy) = self.strategy_manager.get_current_strategy() {
+            with_timeout(WALLET_SIGN_TIMEOUT_MS, strategy.sign_message(message)).await
+        } else {
+            Err(WalletError::NotInstalled)
+        }
+    }
+    
+    /// What the current strategy's wallet can do
+    pub fn capabilities(&self) -> WalletCapabilities {
+        self.strategy_manager
+            .get_current_strategy()
+            .map(|strategy| strategy.get_capabilities())
+            .unwrap_or_default()
+    }
+    
+    /// Sign an ANS-104 deep hash with the current strategy
+    pub async fn sign_deep_hash(&self, deep_hash: &[u8]) -> Result<Vec<u8>, WalletError> {
+        if let Some(strategy) = self.strategy_manager.get_current_strategy() {
+            with_timeout(WALLET_SIGN_TIMEOUT_MS, strategy.sign_deep_hash(deep_hash)).await
+        } else {
+            Err(WalletError::NotInstalled)
+        }
+    }
+    
+    /// Public key of the active address, base64url-encoded
+    pub async fn get_public_key(&self) -> Result<String, WalletError> {
+        if let Some(strategy) = self.strategy_manager.get_current_strategy() {
+            strategy.get_public_key().await
+        } else {
+            Err(WalletError::NotInstalled)
+        }
+    }
+    
+    /// Ask the wallet which network it's on and keep the answer in the
+    /// wallet state; a wallet that can't say is assumed to match the app
+    pub async fn refresh_network(&self) -> Option<WalletNetwork> {
+        let network = match self.strategy_manager.get_current_strategy() {
+            Some(strategy) => strategy.get_network().await.unwrap_or_else(|e| {
+                log::debug!("Wallet didn't report its network: {}", e);
+                None
+            }),
+            None => None,
+        };
+        use_extended_wallet_state().write().network = network.clone();
+        network
+    }
+    
     /// Check connection status using current strategy
     pub async fn check_connection(&self) -> Result<bool, WalletError> {
         if let Some(strategy) = self.strategy_manager.get_current_strategy() {
@@ -275,13 +467,9 @@ impl WalletService {
         }
     }
     
-    /// Format address for display
+    /// Format address for display; see `utils::display::short_address`
     pub fn format_address(address: &str) -> String {
-        if address.len() <= 10 {
-            address.to_string()
-        } else {
-            format!("{}...{}", &address[..6], &address[address.len()-4..])
-        }
+        short_address(address)
     }
     
     /// Get current extended wallet state
@@ -296,77 +484,89 @@ impl Default for WalletService {
     }
 }
 
-// Legacy wallet button component - maintains compatibility
-#[component]
-pub fn WalletButton() -> Element {
-    let wallet_state = use_wallet_state();
-    
-    // TODO: Replace with WalletStrategySelector for multi-strategy support
-    let connect_handler = {
-        let wallet_state = wallet_state.clone();
-        move |_| {
-            let wallet_state = wallet_state.clone();
-            spawn(async move {
-                let mut service = WalletService::new();
-                if wallet_state.read().connected {
-                    let _ = service.disconnect().await;
-                } else {
-                    let _ = service.connect().await;
-                }
-            });
-        }
-    };
-    
-    let button_text = if wallet_state.read().connecting {
-        "Connecting..."
-    } else if wallet_state.read().connected {
-        "Disconnect Wallet"
-    } else if !wallet_state.read().available {
-        "No Wallet Available"
-    } else {
-        "Connect Wallet"
-    };
-    
-    let button_class = if wallet_state.read().connected {
-        "bg-red-600 hover:bg-red-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors"
-    } else if !wallet_state.read().available {
-        "bg-gray-400 cursor-not-allowed text-white px-4 py-2 rounded-lg text-sm font-medium"
-    } else {
-        "bg-green-600 hover:bg-green-700 text-white px-4 py-2 rounded-lg text-sm font-medium transition-colors"
-    };
-    
-    rsx! {
-        div {
-            class: "wallet-button-container",
-            
-            button {
-                class: button_class,
-                disabled: wallet_state.read().connecting || !wallet_state.read().available,
-                onclick: connect_handler,
-                "{button_text}"
-            }
-            
-            if wallet_state.read().connected {
-                div {
-                    class: "mt-2 text-xs text-gray-600",
-                    "Connected: {WalletService::format_address(wallet_state.read().address.as_ref().unwrap_or(&\"Unknown\".to_string()))}"
-                }
-            }
-            
-            if let Some(error) = &wallet_state.read().error {
-                div {
-                    class: "mt-2 text-xs text-red-600",
-                    "{error}"
-                }
-            }
-        }
-    }
-}
-
-// Initialize wallet service (legacy compatibility)
+/// Discover wallets and reconnect with the one last used
+///
+/// Only the remembered strategy is connected to without asking; when it is
+/// missing or unavailable the user picks one in the wallet modal.
 pub fn init_wallet_service() {
     // Initialize in async context
     spawn(async {
-        let _service = WalletService::init().await;
+        let mut service = WalletService::init().await;
+        let Some(strategy) = remembered_strategy() else {
+            return;
+        };
+        if !use_extended_wallet_state().peek().available_strategies.contains(&strategy) {
+            log::info!("🔌 Last used wallet {} is not available; waiting for a choice", strategy.display_name());
+            return;
+        }
+        let result = match service.set_strategy(strategy).await {
+            Ok(()) => service.connect().await.map(|_| ()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            // Not an error the user asked for; the Connect button still works
+            log::warn!("Could not reconnect {}: {}", strategy.display_name(), e);
+            dispatch(ConnectionEvent::ErrorDismissed);
+        }
     });
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen::JsValue;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn classifies_string_rejections() {
+        assert!(matches!(WalletError::from(JsValue::from_str("User rejected the request")), WalletError::UserDenied));
+        assert!(matches!(WalletError::from(JsValue::from_str("Permission ACCESS_ADDRESS missing")), WalletError::InvalidPermissions));
+        assert!(matches!(WalletError::from(JsValue::from_str("Wallet not installed")), WalletError::NotInstalled));
+        assert!(matches!(WalletError::from(JsValue::from_str("Something else")), WalletError::ConnectionFailed(msg) if msg == "Something else"));
+    }
+
+    #[wasm_bindgen_test]
+    fn classifies_error_objects_by_message() {
+        let error: JsValue = js_sys::Error::new("Network request failed").into();
+        assert!(matches!(WalletError::from(error), WalletError::NetworkError(msg) if msg == "Network request failed"));
+
+        let error: JsValue = js_sys::Error::new("Could not sign DataItem").into();
+        assert!(matches!(WalletError::from(error), WalletError::SigningFailed(_)));
+    }
+
+    #[wasm_bindgen_test]
+    fn classifies_plain_objects_with_a_message() {
+        let error = js_sys::Object::new();
+        js_sys::Reflect::set(&error, &"message".into(), &"Request denied by user".into()).unwrap();
+
+        assert!(matches!(WalletError::from(JsValue::from(error)), WalletError::UserDenied));
+    }
+
+    #[wasm_bindgen_test]
+    fn treats_undefined_as_missing_wallet() {
+        assert!(matches!(WalletError::from(JsValue::UNDEFINED), WalletError::NotInstalled));
+    }
+
+    #[wasm_bindgen_test]
+    fn format_address_shortens_long_addresses() {
+        assert_eq!(
+            WalletService::format_address("vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JI"),
+            "vh-NTH...G2JI"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn format_address_keeps_short_input() {
+        assert_eq!(WalletService::format_address(""), "");
+        assert_eq!(WalletService::format_address("0123456789"), "0123456789");
+    }
+
+    #[wasm_bindgen_test]
+    fn format_address_counts_characters_not_bytes() {
+        // Under 10 characters but over 10 bytes
+        assert_eq!(WalletService::format_address("ñññññññ"), "ñññññññ");
+        // Byte offsets 6 and len - 4 fall inside multi-byte characters
+        assert_eq!(WalletService::format_address("ab✝️cdefghijklm🕊️"), "ab✝️cd...lm🕊️");
+        assert_eq!(WalletService::format_address("éééééééééééé"), "éééééé...éééé");
+    }
+}
@@ -0,0 +1,190 @@
+//! Wallet connection health
+//!
+//! Beacon's broker socket and extension sessions can drop without telling
+//! the app, which used to surface only as a failed signature. The monitor
+//! checks the connected wallet in the background and, once a session has
+//! gone stale, reconnects with exponential backoff before giving up and
+//! marking the wallet disconnected.
+
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+use super::state::{dispatch, ConnectionEvent};
+use super::{use_extended_wallet_state, WalletError, WalletService, WalletStrategyType};
+
+/// Time between background connection checks (ms)
+const HEALTH_CHECK_INTERVAL_MS: u32 = 30_000;
+/// Reconnect attempts before the session counts as lost
+pub const MAX_RECONNECT_ATTEMPTS: u32 = 5;
+/// Wait after the first failed attempt; doubles each time (ms)
+const BASE_BACKOFF_MS: u32 = 1_000;
+const MAX_BACKOFF_MS: u32 = 16_000;
+/// How often a caller waiting on someone else's reconnect looks again (ms)
+const RECONNECT_WAIT_MS: u32 = 250;
+
+/// State of the wallet session as far as the monitor can tell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConnectionHealth {
+    #[default]
+    Healthy,
+    /// The session went stale and reconnect `attempt` is under way
+    Reconnecting { attempt: u32 },
+    /// Reconnecting gave up; the wallet has been marked disconnected
+    Lost,
+}
+
+fn use_connection_health_state() -> &'static GlobalSignal<ConnectionHealth> {
+    static CONNECTION_HEALTH: GlobalSignal<ConnectionHealth> = GlobalSignal::new(ConnectionHealth::default);
+    &CONNECTION_HEALTH
+}
+
+fn set_health(health: ConnectionHealth) {
+    if *use_connection_health_state().peek() != health {
+        *use_connection_health_state().write() = health;
+    }
+}
+
+/// Wait before reconnect attempt `attempt + 1`
+pub fn backoff_delay_ms(attempt: u32) -> u32 {
+    let doublings = attempt.saturating_sub(1).min(16);
+    BASE_BACKOFF_MS.saturating_mul(1 << doublings).min(MAX_BACKOFF_MS)
+}
+
+// `init()` auto-selects a strategy, which may not be the one the user
+// connected with, so switch back to that one
+async fn service_for(strategy: WalletStrategyType) -> Result<WalletService, WalletError> {
+    let mut service = WalletService::init().await;
+    service.set_strategy(strategy).await?;
+    Ok(service)
+}
+
+// Connect `service` again, backing off between attempts
+async fn reconnect(service: &mut WalletService) -> Result<String, WalletError> {
+    let mut last_error = WalletError::ConnectionFailed("Wallet session expired".to_string());
+    // After the monitor gave up, `retry_connection` starts from the error
+    if use_extended_wallet_state().peek().base_state.is_connected() {
+        dispatch(ConnectionEvent::SessionStale);
+    }
+
+    for attempt in 1..=MAX_RECONNECT_ATTEMPTS {
+        set_health(ConnectionHealth::Reconnecting { attempt });
+        log::info!("🔌 Reconnecting wallet (attempt {} of {})", attempt, MAX_RECONNECT_ATTEMPTS);
+
+        match service.connect().await {
+            Ok(address) => {
+                log::info!("✅ Wallet reconnected");
+                set_health(ConnectionHealth::Healthy);
+                return Ok(address);
+            }
+            // The user said no or gave up; asking again would just nag
+            Err(e @ (WalletError::UserDenied | WalletError::Cancelled)) => {
+                last_error = e;
+                break;
+            }
+            Err(e) => last_error = e,
+        }
+
+        if attempt < MAX_RECONNECT_ATTEMPTS {
+            TimeoutFuture::new(backoff_delay_ms(attempt)).await;
+        }
+    }
+
+    log::warn!("❌ Wallet connection lost: {}", last_error);
+    dispatch(ConnectionEvent::SessionLost(last_error.clone()));
+    set_health(ConnectionHealth::Lost);
+    Err(last_error)
+}
+
+// Let a reconnect started elsewhere finish instead of racing it
+async fn wait_for_reconnect() {
+    while matches!(*use_connection_health_state().peek(), ConnectionHealth::Reconnecting { .. }) {
+        TimeoutFuture::new(RECONNECT_WAIT_MS).await;
+    }
+}
+
+/// Wallet service on the strategy the user connected with, reconnected
+/// first if its session has gone stale
+///
+/// Use this instead of `WalletService::init()` before signing. When no
+/// wallet is connected the service is returned unchecked, as `init()` would.
+pub async fn connected_wallet() -> Result<WalletService, WalletError> {
+    wait_for_reconnect().await;
+
+    let state = use_extended_wallet_state().peek().clone();
+    if !state.base_state.is_connected() {
+        return Ok(WalletService::init().await);
+    }
+
+    let mut service = service_for(state.strategy).await?;
+    match service.check_connection().await {
+        Ok(true) => set_health(ConnectionHealth::Healthy),
+        Ok(false) | Err(_) => {
+            reconnect(&mut service).await?;
+        }
+    }
+    // The user may have switched gateways since connecting
+    service.refresh_network().await;
+    Ok(service)
+}
+
+/// Try again after the monitor gave up, with the strategy last used
+pub fn retry_connection() {
+    spawn(async {
+        let strategy = use_extended_wallet_state().peek().strategy;
+        let result = match service_for(strategy).await {
+            Ok(mut service) => reconnect(&mut service).await.map(|_| ()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            log::warn!("Wallet reconnect failed: {}", e);
+        }
+    });
+}
+
+/// Check the connected wallet every `HEALTH_CHECK_INTERVAL_MS` for the
+/// lifetime of the app
+pub fn init_connection_monitor() {
+    spawn(async {
+        loop {
+            TimeoutFuture::new(HEALTH_CHECK_INTERVAL_MS).await;
+
+            let connected = use_extended_wallet_state().peek().base_state.is_connected();
+            let health = *use_connection_health_state().peek();
+            match (connected, health) {
+                (false, _) | (_, ConnectionHealth::Reconnecting { .. }) => continue,
+                // Connected again through the wallet button
+                (true, ConnectionHealth::Lost) => set_health(ConnectionHealth::Healthy),
+                (true, ConnectionHealth::Healthy) => {}
+            }
+
+            if let Err(e) = connected_wallet().await {
+                log::warn!("Wallet health check failed: {}", e);
+            }
+        }
+    });
+}
+
+/// The current connection health, for services outside components
+pub fn connection_health() -> ConnectionHealth {
+    *use_connection_health_state().peek()
+}
+
+/// Hook returning the current connection health
+pub fn use_connection_health() -> Memo<ConnectionHealth> {
+    use_memo(move || *use_connection_health_state().read())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn backoff_doubles_up_to_the_cap() {
+        assert_eq!(backoff_delay_ms(1), 1_000);
+        assert_eq!(backoff_delay_ms(2), 2_000);
+        assert_eq!(backoff_delay_ms(4), 8_000);
+        assert_eq!(backoff_delay_ms(5), MAX_BACKOFF_MS);
+        assert_eq!(backoff_delay_ms(40), MAX_BACKOFF_MS);
+    }
+}
@@ -0,0 +1,94 @@
+//! Which Arweave network the connected wallet is on
+//!
+//! Wander lets users point it at any gateway, testnet and arlocal
+//! included. A wallet on testnet signs uploads that the archive then sends
+//! to the mainnet bundler (or the reverse), which fails late and
+//! confusingly, so the wallet's network is kept next to its session and
+//! compared with the app's before publishing.
+
+use dioxus::prelude::*;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+use super::use_extended_wallet_state;
+use crate::config::{app_config, NetworkMode};
+
+/// Gateway and network a wallet reports
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WalletNetwork {
+    pub gateway: String,
+    /// `NetworkMode::chain_id` of the network the gateway serves
+    pub chain_id: String,
+}
+
+impl WalletNetwork {
+    /// Network served by `gateway`, told apart by host: local addresses
+    /// are arlocal and the configured testnet gateways are testnet
+    pub fn from_gateway(gateway: &str) -> Self {
+        let gateway = gateway.trim().trim_end_matches('/').to_string();
+        let host = gateway_host(&gateway);
+        let config = app_config();
+        let mode = if matches!(host.as_deref(), Some("localhost" | "127.0.0.1" | "0.0.0.0" | "[::1]")) {
+            NetworkMode::ArLocal
+        } else if config.testnet.gateways.iter().any(|testnet| host.is_some() && gateway_host(testnet) == host) {
+            NetworkMode::Testnet
+        } else {
+            NetworkMode::Mainnet
+        };
+        Self { gateway, chain_id: mode.chain_id().to_string() }
+    }
+
+    pub fn network_mode(&self) -> Option<NetworkMode> {
+        NetworkMode::ALL.into_iter().find(|mode| mode.chain_id() == self.chain_id)
+    }
+
+    /// Name to show, falling back to the raw chain id
+    pub fn display_name(&self) -> String {
+        match self.network_mode() {
+            Some(mode) => mode.display_name().to_string(),
+            None => self.chain_id.clone(),
+        }
+    }
+}
+
+fn gateway_host(gateway: &str) -> Option<String> {
+    let url = Url::parse(gateway).ok()?;
+    let host = url.host_str()?.to_ascii_lowercase();
+    Some(host)
+}
+
+/// The wallet's network when it differs from the app's; `None` when they
+/// match or the wallet doesn't say
+pub fn network_mismatch() -> Option<WalletNetwork> {
+    let network = use_extended_wallet_state().peek().network.clone()?;
+    (network.network_mode() != Some(app_config().network)).then_some(network)
+}
+
+/// Reactive `network_mismatch` for components
+pub fn use_network_mismatch() -> Memo<Option<WalletNetwork>> {
+    use_memo(|| {
+        let network = use_extended_wallet_state().read().network.clone()?;
+        (network.network_mode() != Some(app_config().network)).then_some(network)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn gateways_are_told_apart_by_host() {
+        let local = WalletNetwork::from_gateway("http://localhost:1984/");
+        assert_eq!(local.network_mode(), Some(NetworkMode::ArLocal));
+        assert_eq!(local.gateway, "http://localhost:1984");
+
+        let testnet_gateway = app_config().testnet.gateways[0].clone();
+        let testnet = WalletNetwork::from_gateway(&format!("{}/", testnet_gateway.to_uppercase()));
+        assert_eq!(testnet.network_mode(), Some(NetworkMode::Testnet));
+
+        let mainnet = WalletNetwork::from_gateway("https://arweave.net");
+        assert_eq!(mainnet.network_mode(), Some(NetworkMode::Mainnet));
+        assert_eq!(mainnet.display_name(), "Mainnet");
+    }
+}
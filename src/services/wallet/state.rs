@@ -0,0 +1,213 @@
+//! Wallet connection state machine
+//!
+//! The connection used to be four booleans and an error string, set
+//! piecemeal by `connect`, `disconnect`, the health monitor and startup
+//! discovery. They drifted apart: a wallet could be `connecting` and
+//! `connected` at once, or keep an address after the monitor gave up.
+//! Now the connection is one `ConnectionState`, and every change goes
+//! through `transition`, which refuses the ones that make no sense.
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{use_extended_wallet_state, WalletError};
+
+/// Why a connection ended up in `ConnectionState::Error`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConnectionErrorKind {
+    NotInstalled,
+    Denied,
+    TimedOut,
+    /// The session went stale and reconnecting gave up
+    Lost,
+    Failed,
+}
+
+impl From<&WalletError> for ConnectionErrorKind {
+    fn from(error: &WalletError) -> Self {
+        match error {
+            WalletError::NotInstalled => ConnectionErrorKind::NotInstalled,
+            WalletError::UserDenied | WalletError::InvalidPermissions => ConnectionErrorKind::Denied,
+            WalletError::TimedOut => ConnectionErrorKind::TimedOut,
+            _ => ConnectionErrorKind::Failed,
+        }
+    }
+}
+
+/// Where the wallet connection stands
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum ConnectionState {
+    /// No wallet strategy is available, or discovery hasn't run yet
+    #[default]
+    Unavailable,
+    /// A wallet is available but not connected
+    Idle,
+    Connecting,
+    Connected { address: String },
+    /// The session went stale and the monitor is connecting again
+    Reconnecting { address: String },
+    Error { kind: ConnectionErrorKind, message: String },
+}
+
+/// Something that happened to the connection
+#[derive(Debug, Clone)]
+pub enum ConnectionEvent {
+    /// Discovery finished, finding a usable strategy or not
+    Discovered { available: bool },
+    ConnectStarted,
+    Connected { address: String },
+    ConnectFailed(WalletError),
+    Disconnected,
+    /// The health check found the session stale
+    SessionStale,
+    /// Reconnecting gave up
+    SessionLost(WalletError),
+    /// The error was dealt with, or wasn't worth showing
+    ErrorDismissed,
+}
+
+impl ConnectionState {
+    pub fn is_connected(&self) -> bool {
+        matches!(self, ConnectionState::Connected { .. })
+    }
+
+    /// Connecting for the first time or again after a stale session
+    pub fn is_busy(&self) -> bool {
+        matches!(self, ConnectionState::Connecting | ConnectionState::Reconnecting { .. })
+    }
+
+    /// Address of the session, including one being reconnected
+    pub fn address(&self) -> Option<&str> {
+        match self {
+            ConnectionState::Connected { address } | ConnectionState::Reconnecting { address } => Some(address),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            ConnectionState::Error { message, .. } => Some(message),
+            _ => None,
+        }
+    }
+}
+
+/// The state `event` leads to from `state`, or `None` if it can't happen
+/// there
+pub fn transition(state: &ConnectionState, event: &ConnectionEvent) -> Option<ConnectionState> {
+    use ConnectionEvent as Event;
+    use ConnectionState as State;
+
+    let next = match (state, event) {
+        // Discovery runs often; it only matters while nothing is connected
+        (State::Unavailable | State::Idle, Event::Discovered { available: true }) => State::Idle,
+        (State::Unavailable | State::Idle, Event::Discovered { available: false }) => State::Unavailable,
+        (_, Event::Discovered { .. }) => state.clone(),
+
+        // A wallet picked by hand may have been missed by discovery
+        (State::Unavailable | State::Idle | State::Error { .. }, Event::ConnectStarted) => State::Connecting,
+        // The monitor's reconnect attempts run through `connect`
+        (State::Reconnecting { .. }, Event::ConnectStarted) => state.clone(),
+
+        (State::Connecting | State::Reconnecting { .. } | State::Connected { .. }, Event::Connected { address }) => {
+            State::Connected { address: address.clone() }
+        }
+
+        (State::Connecting, Event::ConnectFailed(WalletError::Cancelled)) => State::Idle,
+        (State::Connecting, Event::ConnectFailed(error)) => State::Error {
+            kind: ConnectionErrorKind::from(error),
+            message: error.to_string(),
+        },
+        // Only `SessionLost` ends a reconnect
+        (State::Reconnecting { .. }, Event::ConnectFailed(_)) => state.clone(),
+
+        (State::Unavailable, Event::Disconnected) => State::Unavailable,
+        (_, Event::Disconnected) => State::Idle,
+
+        (State::Connected { address }, Event::SessionStale) => State::Reconnecting { address: address.clone() },
+
+        (State::Reconnecting { .. } | State::Error { .. }, Event::SessionLost(error)) => State::Error {
+            kind: ConnectionErrorKind::Lost,
+            message: error.to_string(),
+        },
+
+        (State::Error { .. }, Event::ErrorDismissed) => State::Idle,
+        (_, Event::ErrorDismissed) => state.clone(),
+
+        _ => return None,
+    };
+    Some(next)
+}
+
+/// Apply `event` to the wallet's connection state; events that can't
+/// happen in the current state are logged and ignored
+///
+/// Returns whether the state accepted the event.
+pub(super) fn dispatch(event: ConnectionEvent) -> bool {
+    let current = use_extended_wallet_state().peek().base_state.connection.clone();
+    match transition(&current, &event) {
+        Some(next) => {
+            if next != current {
+                log::debug!("Wallet connection: {:?} -> {:?}", current, next);
+                use_extended_wallet_state().write().base_state.connection = next;
+            }
+            true
+        }
+        None => {
+            log::warn!("Ignoring wallet event {:?} while {:?}", event, current);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    fn connected() -> ConnectionState {
+        ConnectionState::Connected { address: "address".to_string() }
+    }
+
+    #[wasm_bindgen_test]
+    fn connecting_ends_connected_failed_or_idle() {
+        let connecting = transition(&ConnectionState::Idle, &ConnectionEvent::ConnectStarted).unwrap();
+        assert_eq!(connecting, ConnectionState::Connecting);
+
+        let done = transition(&connecting, &ConnectionEvent::Connected { address: "address".to_string() });
+        assert_eq!(done, Some(connected()));
+
+        let failed = transition(&connecting, &ConnectionEvent::ConnectFailed(WalletError::TimedOut)).unwrap();
+        assert!(matches!(failed, ConnectionState::Error { kind: ConnectionErrorKind::TimedOut, .. }));
+
+        let cancelled = transition(&connecting, &ConnectionEvent::ConnectFailed(WalletError::Cancelled));
+        assert_eq!(cancelled, Some(ConnectionState::Idle));
+    }
+
+    #[wasm_bindgen_test]
+    fn stale_sessions_reconnect_or_are_lost() {
+        let reconnecting = transition(&connected(), &ConnectionEvent::SessionStale).unwrap();
+        assert_eq!(reconnecting.address(), Some("address"));
+        assert!(reconnecting.is_busy());
+
+        // A failed attempt doesn't end the reconnect; giving up does
+        let retry = ConnectionEvent::ConnectFailed(WalletError::ConnectionFailed("stale".to_string()));
+        assert_eq!(transition(&reconnecting, &retry), Some(reconnecting.clone()));
+        let lost = transition(&reconnecting, &ConnectionEvent::SessionLost(WalletError::UserDenied)).unwrap();
+        assert!(matches!(lost, ConnectionState::Error { kind: ConnectionErrorKind::Lost, .. }));
+        assert_eq!(lost.address(), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn impossible_events_are_refused() {
+        // A late answer after a cancel
+        let late = ConnectionEvent::Connected { address: "address".to_string() };
+        assert_eq!(transition(&ConnectionState::Idle, &late), None);
+        assert_eq!(transition(&ConnectionState::Connecting, &ConnectionEvent::ConnectStarted), None);
+        assert_eq!(transition(&ConnectionState::Idle, &ConnectionEvent::SessionStale), None);
+
+        // Discovery never drops a live session
+        let discovered = ConnectionEvent::Discovered { available: false };
+        assert_eq!(transition(&connected(), &discovered), Some(connected()));
+    }
+}
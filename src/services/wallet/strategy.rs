@@ -1,8 +1,18 @@
 use async_trait::async_trait;
+use futures::channel::oneshot;
+use futures::future::{select, Either};
+use gloo_timers::future::TimeoutFuture;
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::future::Future;
 use anyhow::Result;
-use crate::services::wallet::{WalletError, WalletState};
+use crate::services::wallet::{cancel_beacon_pairing, WalletError, WalletNetwork, WalletState};
+
+thread_local! {
+    // One sender per wallet operation in flight, fired by a cancel
+    static PENDING_CANCELS: RefCell<Vec<oneshot::Sender<()>>> = const { RefCell::new(Vec::new()) };
+}
 
 /// Supported wallet connection strategies
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -15,6 +25,9 @@ pub enum WalletStrategyType {
     WalletKit,
     /// ArweaveWebWallet - web-based wallet connection
     WebWallet,
+    /// Deterministic development wallet, see `MockWalletStrategy`
+    #[cfg(feature = "mock-wallet")]
+    Mock,
 }
 
 impl WalletStrategyType {
@@ -24,6 +37,8 @@ impl WalletStrategyType {
             WalletStrategyType::Beacon => "Beacon",
             WalletStrategyType::WalletKit => "Arweave Wallet Kit", 
             WalletStrategyType::WebWallet => "Web Wallet",
+            #[cfg(feature = "mock-wallet")]
+            WalletStrategyType::Mock => "Mock Wallet",
         }
     }
     
@@ -33,6 +48,8 @@ impl WalletStrategyType {
             WalletStrategyType::Beacon => "iOS based agent first wallet for AO",
             WalletStrategyType::WalletKit => "Universal wallet connection library",
             WalletStrategyType::WebWallet => "Web-based wallet connection",
+            #[cfg(feature = "mock-wallet")]
+            WalletStrategyType::Mock => "Development wallet with a fixed address",
         }
     }
     
@@ -42,8 +59,68 @@ impl WalletStrategyType {
             WalletStrategyType::Beacon => false,
             WalletStrategyType::WalletKit => false,
             WalletStrategyType::WebWallet => false,
+            #[cfg(feature = "mock-wallet")]
+            WalletStrategyType::Mock => false,
+        }
+    }
+}
+
+/// Order strategies are tried in when the user hasn't set one
+pub const DEFAULT_STRATEGY_ORDER: [WalletStrategyType; 4] = [
+    WalletStrategyType::Wander,
+    WalletStrategyType::Beacon,
+    WalletStrategyType::WalletKit,
+    WalletStrategyType::WebWallet,
+];
+
+/// The user's `preferred` strategies first, then the rest of the built-in
+/// order, each once
+pub fn preference_order(preferred: &[WalletStrategyType]) -> Vec<WalletStrategyType> {
+    let mut order: Vec<WalletStrategyType> = Vec::new();
+    for strategy in preferred.iter().chain(DEFAULT_STRATEGY_ORDER.iter()) {
+        if !order.contains(strategy) {
+            order.push(*strategy);
         }
     }
+    order
+}
+
+/// Run a wallet `operation`, failing with `TimedOut` after `timeout_ms` or
+/// `Cancelled` once `cancel_wallet_operations` is called
+///
+/// The wallet's own promise can't be withdrawn, so a late answer is simply
+/// dropped; callers see the operation end either way.
+pub async fn with_timeout<T>(timeout_ms: u32, operation: impl Future<Output = Result<T, WalletError>>) -> Result<T, WalletError> {
+    let (sender, cancelled) = oneshot::channel();
+    PENDING_CANCELS.with(|pending| {
+        let mut pending = pending.borrow_mut();
+        pending.retain(|sender| !sender.is_canceled());
+        pending.push(sender);
+    });
+
+    let abort = async move {
+        match select(TimeoutFuture::new(timeout_ms), cancelled).await {
+            Either::Left(_) => WalletError::TimedOut,
+            Either::Right(_) => WalletError::Cancelled,
+        }
+    };
+    match select(Box::pin(operation), Box::pin(abort)).await {
+        Either::Left((result, _)) => result,
+        Either::Right((error, _)) => {
+            log::warn!("⏹️ Wallet operation ended early: {}", error);
+            Err(error)
+        }
+    }
+}
+
+/// Stop waiting on every wallet connect or signature in flight, and close
+/// a Beacon pairing code if one is showing
+pub fn cancel_wallet_operations() {
+    let pending = PENDING_CANCELS.with(|pending| std::mem::take(&mut *pending.borrow_mut()));
+    for sender in pending {
+        let _ = sender.send(());
+    }
+    cancel_beacon_pairing();
 }
 
 /// Wallet capability flags
@@ -55,6 +132,13 @@ pub struct WalletCapabilities {
     pub supports_batch_signing: bool,
     pub supports_permissions: bool,
     pub supports_multiple_addresses: bool,
+    /// The wallet can post data itself through `dispatch`
+    #[serde(default)]
+    pub can_dispatch: bool,
+    /// The wallet can sign a DataItem's deep hash, so large uploads are
+    /// signed without handing it the whole file
+    #[serde(default)]
+    pub can_sign_deep_hash: bool,
 }
 
 impl Default for WalletCapabilities {
@@ -66,6 +150,8 @@ impl Default for WalletCapabilities {
             supports_batch_signing: false,
             supports_permissions: true,
             supports_multiple_addresses: false,
+            can_dispatch: false,
+            can_sign_deep_hash: false,
         }
     }
 }
@@ -77,6 +163,8 @@ pub struct ExtendedWalletState {
     pub strategy: WalletStrategyType,
     pub capabilities: WalletCapabilities,
     pub available_strategies: Vec<WalletStrategyType>,
+    /// Network the connected wallet reports, if it reports one
+    pub network: Option<WalletNetwork>,
 }
 
 impl Default for ExtendedWalletState {
@@ -86,6 +174,7 @@ impl Default for ExtendedWalletState {
             strategy: WalletStrategyType::Wander,
             capabilities: WalletCapabilities::default(),
             available_strategies: vec![],
+            network: None,
         }
     }
 }
@@ -138,6 +227,49 @@ pub trait WalletStrategy {
     async fn decrypt(&self, _data: &[u8], _options: Option<HashMap<String, String>>) -> Result<Vec<u8>, WalletError> {
         Err(WalletError::InvalidPermissions)
     }
+    
+    /// Optional: Sign an ANS-104 DataItem, returning its serialized bytes
+    async fn sign_data_item(&self, _data: Vec<u8>, _tags: Vec<(String, String)>) -> Result<Vec<u8>, WalletError> {
+        Err(WalletError::SigningFailed("DataItem signing not supported by this wallet".to_string()))
+    }
+    
+    /// Optional: Sign a DataItem addressed to `target`, such as an AO
+    /// message to a process, returning its serialized bytes
+    async fn sign_data_item_to(&self, _target: &str, _data: Vec<u8>, _tags: Vec<(String, String)>) -> Result<Vec<u8>, WalletError> {
+        Err(WalletError::SigningFailed("Addressed DataItem signing not supported by this wallet".to_string()))
+    }
+    
+    /// Optional: Sign arbitrary bytes, such as a login challenge, so a
+    /// service can check the caller controls the address
+    async fn sign_message(&self, _message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        Err(WalletError::SigningFailed("Message signing not supported by this wallet".to_string()))
+    }
+    
+    /// Optional: Sign an ANS-104 deep hash with the wallet's Arweave key,
+    /// for a DataItem whose data is too large to hand over; see
+    /// `arweave::StreamedDataItem`
+    async fn sign_deep_hash(&self, _deep_hash: &[u8]) -> Result<Vec<u8>, WalletError> {
+        Err(WalletError::SigningFailed("Deep-hash signing not supported by this wallet".to_string()))
+    }
+    
+    /// Optional: Have the wallet post `data` itself, returning the ID.
+    /// Wander bundles small items for free and pays for larger ones as a
+    /// layer-1 transaction.
+    async fn dispatch(&self, _data: Vec<u8>, _tags: Vec<(String, String)>) -> Result<String, WalletError> {
+        Err(WalletError::SigningFailed("Dispatch not supported by this wallet".to_string()))
+    }
+    
+    /// Optional: Gateway the wallet is configured for; `None` when the
+    /// wallet doesn't say, which is treated as matching the app
+    async fn get_network(&self) -> Result<Option<WalletNetwork>, WalletError> {
+        Ok(None)
+    }
+    
+    /// Optional: Public key of the active address, base64url-encoded, for
+    /// verifying `sign_message` signatures
+    async fn get_public_key(&self) -> Result<String, WalletError> {
+        Err(WalletError::InvalidPermissions)
+    }
 }
 
 /// Wallet strategy manager
@@ -236,26 +368,24 @@ impl WalletStrategyManager {
         f(strategy.as_mut()).await
     }
     
-    /// Auto-select the best available strategy
+    /// Auto-select the best available strategy in the built-in order
     pub async fn auto_select_strategy(&mut self) -> Result<WalletStrategyType, WalletError> {
+        self.auto_select_strategy_by(&DEFAULT_STRATEGY_ORDER).await
+    }
+
+    /// Auto-select the first available strategy in `order`, or failing
+    /// that the first available at all
+    pub async fn auto_select_strategy_by(&mut self, order: &[WalletStrategyType]) -> Result<WalletStrategyType, WalletError> {
         let available = self.get_available_strategies().await;
         
         if available.is_empty() {
             return Err(WalletError::NotInstalled);
         }
         
-        // Priority order: Wander > Beacon > WalletKit > WebWallet
-        let preferred_order = vec![
-            WalletStrategyType::Wander,
-            WalletStrategyType::Beacon,
-            WalletStrategyType::WalletKit,
-            WalletStrategyType::WebWallet,
-        ];
-        
-        for preferred in preferred_order {
-            if available.contains(&preferred) {
-                self.set_strategy(preferred)?;
-                return Ok(preferred);
+        for preferred in order {
+            if available.contains(preferred) {
+                self.set_strategy(*preferred)?;
+                return Ok(*preferred);
             }
         }
         
@@ -270,4 +400,168 @@ impl Default for WalletStrategyManager {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    // Strategy whose availability check returns a fixed answer
+    struct FakeStrategy {
+        strategy_type: WalletStrategyType,
+        available: Result<bool, WalletError>,
+    }
+
+    impl FakeStrategy {
+        fn boxed(strategy_type: WalletStrategyType, available: Result<bool, WalletError>) -> Box<dyn WalletStrategy> {
+            Box::new(Self { strategy_type, available })
+        }
+    }
+
+    #[async_trait(?Send)]
+    impl WalletStrategy for FakeStrategy {
+        fn strategy_type(&self) -> WalletStrategyType {
+            self.strategy_type
+        }
+
+        async fn is_available(&self) -> Result<bool, WalletError> {
+            self.available.clone()
+        }
+
+        fn get_capabilities(&self) -> WalletCapabilities {
+            WalletCapabilities::default()
+        }
+
+        async fn connect(&mut self, _permissions: Vec<&str>) -> Result<String, WalletError> {
+            Ok("address".to_string())
+        }
+
+        async fn disconnect(&mut self) -> Result<(), WalletError> {
+            Ok(())
+        }
+
+        async fn get_active_address(&self) -> Result<String, WalletError> {
+            Ok("address".to_string())
+        }
+
+        async fn get_permissions(&self) -> Result<Vec<String>, WalletError> {
+            Ok(vec![])
+        }
+
+        async fn sign_transaction(&self, transaction_data: HashMap<String, serde_json::Value>) -> Result<HashMap<String, serde_json::Value>, WalletError> {
+            Ok(transaction_data)
+        }
+
+        async fn check_connection(&self) -> Result<bool, WalletError> {
+            Ok(true)
+        }
+    }
+
+    fn manager(strategies: Vec<Box<dyn WalletStrategy>>) -> WalletStrategyManager {
+        let mut manager = WalletStrategyManager::new();
+        for strategy in strategies {
+            manager.register_strategy(strategy);
+        }
+        manager
+    }
+
+    #[wasm_bindgen_test]
+    async fn auto_select_prefers_wander() {
+        let mut manager = manager(vec![
+            FakeStrategy::boxed(WalletStrategyType::WebWallet, Ok(true)),
+            FakeStrategy::boxed(WalletStrategyType::Beacon, Ok(true)),
+            FakeStrategy::boxed(WalletStrategyType::Wander, Ok(true)),
+        ]);
+
+        assert_eq!(manager.auto_select_strategy().await.unwrap(), WalletStrategyType::Wander);
+        assert_eq!(manager.get_current_strategy().unwrap().strategy_type(), WalletStrategyType::Wander);
+    }
+
+    #[wasm_bindgen_test]
+    async fn auto_select_follows_priority_order() {
+        let mut manager = manager(vec![
+            FakeStrategy::boxed(WalletStrategyType::Wander, Ok(false)),
+            FakeStrategy::boxed(WalletStrategyType::WebWallet, Ok(true)),
+            FakeStrategy::boxed(WalletStrategyType::WalletKit, Ok(true)),
+        ]);
+
+        assert_eq!(manager.auto_select_strategy().await.unwrap(), WalletStrategyType::WalletKit);
+    }
+
+    #[wasm_bindgen_test]
+    async fn auto_select_skips_strategies_that_fail_their_check() {
+        let mut manager = manager(vec![
+            FakeStrategy::boxed(WalletStrategyType::Wander, Err(WalletError::NotInstalled)),
+            FakeStrategy::boxed(WalletStrategyType::Beacon, Ok(true)),
+        ]);
+
+        assert_eq!(manager.get_available_strategies().await, vec![WalletStrategyType::Beacon]);
+        assert_eq!(manager.auto_select_strategy().await.unwrap(), WalletStrategyType::Beacon);
+    }
+
+    #[wasm_bindgen_test]
+    async fn auto_select_follows_the_users_order() {
+        let mut manager = manager(vec![
+            FakeStrategy::boxed(WalletStrategyType::Wander, Ok(true)),
+            FakeStrategy::boxed(WalletStrategyType::Beacon, Ok(true)),
+        ]);
+
+        let order = preference_order(&[WalletStrategyType::WebWallet, WalletStrategyType::Beacon]);
+        assert_eq!(order.len(), DEFAULT_STRATEGY_ORDER.len());
+        assert_eq!(&order[..3], &[WalletStrategyType::WebWallet, WalletStrategyType::Beacon, WalletStrategyType::Wander]);
+        assert_eq!(manager.auto_select_strategy_by(&order).await.unwrap(), WalletStrategyType::Beacon);
+    }
+
+    #[wasm_bindgen_test]
+    async fn auto_select_without_available_strategies_fails() {
+        let mut empty = WalletStrategyManager::new();
+        assert!(matches!(empty.auto_select_strategy().await, Err(WalletError::NotInstalled)));
+
+        let mut unavailable = manager(vec![FakeStrategy::boxed(WalletStrategyType::Wander, Ok(false))]);
+        assert!(matches!(unavailable.auto_select_strategy().await, Err(WalletError::NotInstalled)));
+        assert!(unavailable.get_current_strategy().is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn slow_operations_time_out_or_cancel() {
+        let slow = async {
+            TimeoutFuture::new(1_000).await;
+            Ok("address".to_string())
+        };
+        assert!(matches!(with_timeout(10, slow).await, Err(WalletError::TimedOut)));
+
+        let fast = async { Ok(1) };
+        assert_eq!(with_timeout(1_000, fast).await.unwrap(), 1);
+
+        let waiting = with_timeout(1_000, async {
+            TimeoutFuture::new(500).await;
+            Ok(())
+        });
+        let cancel = async {
+            TimeoutFuture::new(10).await;
+            cancel_wallet_operations();
+        };
+        let (result, _) = futures::join!(waiting, cancel);
+        assert!(matches!(result, Err(WalletError::Cancelled)));
+    }
+
+    #[wasm_bindgen_test]
+    fn registering_a_type_again_replaces_it() {
+        let manager = manager(vec![
+            FakeStrategy::boxed(WalletStrategyType::Wander, Ok(false)),
+            FakeStrategy::boxed(WalletStrategyType::Wander, Ok(true)),
+        ]);
+
+        assert_eq!(manager.strategy_count(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn set_strategy_rejects_unregistered_types() {
+        let mut manager = manager(vec![FakeStrategy::boxed(WalletStrategyType::Wander, Ok(true))]);
+
+        assert!(manager.set_strategy(WalletStrategyType::Beacon).is_err());
+        assert!(manager.set_strategy(WalletStrategyType::Wander).is_ok());
+        assert_eq!(manager.get_current_strategy().unwrap().strategy_type(), WalletStrategyType::Wander);
+    }
+}
@@ -32,6 +32,12 @@ impl WalletKitStrategy {
     }
 }
 
+impl Default for WalletKitStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait(?Send)]
 impl WalletStrategy for WalletKitStrategy {
     fn strategy_type(&self) -> WalletStrategyType {
@@ -50,6 +56,8 @@ impl WalletStrategy for WalletKitStrategy {
             supports_batch_signing: true,
             supports_permissions: true,
             supports_multiple_addresses: false,
+            can_dispatch: false,
+            can_sign_deep_hash: false,
         }
     }
     
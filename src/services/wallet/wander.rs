@@ -4,8 +4,9 @@ use wasm_bindgen::prelude::*;
 // use wasm_bindgen_futures::JsFuture; // Not used currently
 use web_sys::js_sys;
 use anyhow::Result;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 
-use crate::services::wallet::{WalletError, WalletStrategy, WalletStrategyType, WalletCapabilities};
+use crate::services::wallet::{WalletError, WalletNetwork, WalletStrategy, WalletStrategyType, WalletCapabilities};
 
 // WASM bindings for Wander wallet (formerly ArConnect)
 #[wasm_bindgen]
@@ -31,8 +32,29 @@ extern "C" {
     #[wasm_bindgen(js_namespace = ["window", "arweaveWallet"], catch)]
     async fn getAllAddresses() -> Result<JsValue, JsValue>;
     
+    #[wasm_bindgen(js_namespace = ["window", "arweaveWallet"], catch)]
+    async fn signDataItem(data_item: JsValue) -> Result<JsValue, JsValue>;
+    
+    #[wasm_bindgen(js_namespace = ["window", "arweaveWallet"], catch)]
+    async fn signMessage(data: JsValue, options: JsValue) -> Result<JsValue, JsValue>;
+    
+    #[wasm_bindgen(js_namespace = ["window", "arweaveWallet"], catch)]
+    async fn getActivePublicKey() -> Result<JsValue, JsValue>;
+    
+    #[wasm_bindgen(js_namespace = ["window", "arweaveWallet"], catch)]
+    async fn getArweaveConfig() -> Result<JsValue, JsValue>;
+    
+    #[wasm_bindgen(js_namespace = ["window", "arweaveWallet"], js_name = "dispatch", catch)]
+    async fn dispatchTransaction(transaction: JsValue) -> Result<JsValue, JsValue>;
+    
+    #[wasm_bindgen(js_namespace = ["window", "arweaveWallet"], js_name = "encrypt", catch)]
+    async fn encryptData(data: JsValue, algorithm: JsValue) -> Result<JsValue, JsValue>;
+    
+    #[wasm_bindgen(js_namespace = ["window", "arweaveWallet"], js_name = "decrypt", catch)]
+    async fn decryptData(data: JsValue, algorithm: JsValue) -> Result<JsValue, JsValue>;
+    
     // Check if wallet extension is available
-    #[wasm_bindgen(js_namespace = ["window"], js_name = "arweaveWallet")]
+    #[wasm_bindgen(thread_local_v2, js_namespace = ["window"], js_name = "arweaveWallet")]
     static ARWEAVE_WALLET: JsValue;
 }
 
@@ -46,7 +68,13 @@ impl WanderStrategy {
     
     /// Check if Wander wallet extension is available
     fn is_wallet_available() -> bool {
-        !ARWEAVE_WALLET.is_undefined() && !ARWEAVE_WALLET.is_null()
+        ARWEAVE_WALLET.with(|wallet| !wallet.is_undefined() && !wallet.is_null())
+    }
+}
+
+impl Default for WanderStrategy {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -68,6 +96,8 @@ impl WalletStrategy for WanderStrategy {
             supports_batch_signing: false,
             supports_permissions: true,
             supports_multiple_addresses: true,
+            can_dispatch: true,
+            can_sign_deep_hash: false,
         }
     }
     
@@ -186,17 +216,122 @@ impl WalletStrategy for WanderStrategy {
         }
     }
     
-    /// Encrypt data with Wander wallet (if supported)
-    async fn encrypt(&self, _data: &[u8], _options: Option<HashMap<String, String>>) -> Result<Vec<u8>, WalletError> {
-        // TODO: Implement encryption if Wander wallet supports it
-        // For now, return error as feature not implemented
-        Err(WalletError::InvalidPermissions)
+    /// Sign a DataItem with Wander wallet
+    async fn sign_data_item(&self, data: Vec<u8>, tags: Vec<(String, String)>) -> Result<Vec<u8>, WalletError> {
+        sign_with_wander(None, data, tags).await
+    }
+    
+    /// Sign a DataItem addressed to `target` with Wander wallet
+    async fn sign_data_item_to(&self, target: &str, data: Vec<u8>, tags: Vec<(String, String)>) -> Result<Vec<u8>, WalletError> {
+        sign_with_wander(Some(target), data, tags).await
+    }
+    
+    /// Sign a message with RSA-PSS over its SHA-256 hash
+    async fn sign_message(&self, message: &[u8]) -> Result<Vec<u8>, WalletError> {
+        let options = js_sys::Object::new();
+        js_sys::Reflect::set(&options, &"hashAlgorithm".into(), &"SHA-256".into())?;
+        
+        let signature = signMessage(js_sys::Uint8Array::from(message).into(), options.into()).await?;
+        Ok(js_sys::Uint8Array::new(&signature).to_vec())
+    }
+    
+    async fn get_public_key(&self) -> Result<String, WalletError> {
+        getActivePublicKey()
+            .await?
+            .as_string()
+            .ok_or_else(|| WalletError::ConnectionFailed("Wander returned no public key".to_string()))
+    }
+    
+    /// Post through Wander's `dispatch()`, which takes a transaction with
+    /// base64url tags and answers `{id, type}`
+    async fn dispatch(&self, data: Vec<u8>, tags: Vec<(String, String)>) -> Result<String, WalletError> {
+        let tags_js = js_sys::Array::new();
+        for (name, value) in &tags {
+            let tag = js_sys::Object::new();
+            js_sys::Reflect::set(&tag, &"name".into(), &URL_SAFE_NO_PAD.encode(name).into())?;
+            js_sys::Reflect::set(&tag, &"value".into(), &URL_SAFE_NO_PAD.encode(value).into())?;
+            tags_js.push(&tag);
+        }
+        
+        let transaction = js_sys::Object::new();
+        js_sys::Reflect::set(&transaction, &"format".into(), &2.into())?;
+        js_sys::Reflect::set(&transaction, &"data".into(), &js_sys::Uint8Array::from(data.as_slice()))?;
+        js_sys::Reflect::set(&transaction, &"tags".into(), &tags_js)?;
+        
+        let result = dispatchTransaction(transaction.into()).await?;
+        let id = js_sys::Reflect::get(&result, &"id".into())?
+            .as_string()
+            .ok_or_else(|| WalletError::TransactionFailed("Wander returned no transaction ID".to_string()))?;
+        let kind = js_sys::Reflect::get(&result, &"type".into())?.as_string().unwrap_or_default();
+        log::info!("Wander dispatched {} ({})", id, kind);
+        Ok(id)
+    }
+    
+    /// Gateway from `getArweaveConfig()`, which answers `{host, port, protocol}`
+    async fn get_network(&self) -> Result<Option<WalletNetwork>, WalletError> {
+        let config = getArweaveConfig().await?;
+        let field = |name: &str| js_sys::Reflect::get(&config, &name.into()).ok().filter(|value| !value.is_undefined());
+        let Some(host) = field("host").and_then(|host| host.as_string()) else {
+            return Ok(None);
+        };
+        let protocol = field("protocol").and_then(|protocol| protocol.as_string()).unwrap_or_else(|| "https".to_string());
+        let port = field("port").and_then(|port| port.as_f64().map(|port| port as u16).or_else(|| port.as_string()?.parse().ok()));
+        let gateway = match port {
+            Some(443) if protocol == "https" => format!("{}://{}", protocol, host),
+            Some(80) if protocol == "http" => format!("{}://{}", protocol, host),
+            Some(port) => format!("{}://{}:{}", protocol, host, port),
+            None => format!("{}://{}", protocol, host),
+        };
+        Ok(Some(WalletNetwork::from_gateway(&gateway)))
     }
     
-    /// Decrypt data with Wander wallet (if supported)
-    async fn decrypt(&self, _data: &[u8], _options: Option<HashMap<String, String>>) -> Result<Vec<u8>, WalletError> {
-        // TODO: Implement decryption if Wander wallet supports it
-        // For now, return error as feature not implemented
-        Err(WalletError::InvalidPermissions)
+    /// Encrypt data to the wallet's own key with RSA-OAEP
+    ///
+    /// Only small payloads (such as a content key) fit in one RSA block.
+    async fn encrypt(&self, data: &[u8], _options: Option<HashMap<String, String>>) -> Result<Vec<u8>, WalletError> {
+        let algorithm = js_sys::Object::new();
+        js_sys::Reflect::set(&algorithm, &"name".into(), &"RSA-OAEP".into())?;
+        
+        let encrypted = encryptData(js_sys::Uint8Array::from(data).into(), algorithm.into()).await?;
+        Ok(js_sys::Uint8Array::new(&encrypted).to_vec())
+    }
+    
+    /// Decrypt data encrypted to the wallet's key with RSA-OAEP
+    async fn decrypt(&self, data: &[u8], _options: Option<HashMap<String, String>>) -> Result<Vec<u8>, WalletError> {
+        let algorithm = js_sys::Object::new();
+        js_sys::Reflect::set(&algorithm, &"name".into(), &"RSA-OAEP".into())?;
+        
+        let decrypted = decryptData(js_sys::Uint8Array::from(data).into(), algorithm.into()).await?;
+        Ok(js_sys::Uint8Array::new(&decrypted).to_vec())
     }
-}
\ No newline at end of file
+}
+
+/// Sign a DataItem through `window.arweaveWallet.signDataItem`
+async fn sign_with_wander(target: Option<&str>, data: Vec<u8>, tags: Vec<(String, String)>) -> Result<Vec<u8>, WalletError> {
+    let tags_js = js_sys::Array::new();
+    for (name, value) in &tags {
+        let tag = js_sys::Object::new();
+        js_sys::Reflect::set(&tag, &"name".into(), &name.as_str().into())?;
+        js_sys::Reflect::set(&tag, &"value".into(), &value.as_str().into())?;
+        tags_js.push(&tag);
+    }
+    
+    let data_item = js_sys::Object::new();
+    js_sys::Reflect::set(&data_item, &"data".into(), &js_sys::Uint8Array::from(data.as_slice()))?;
+    js_sys::Reflect::set(&data_item, &"tags".into(), &tags_js)?;
+    if let Some(target) = target {
+        js_sys::Reflect::set(&data_item, &"target".into(), &target.into())?;
+    }
+    
+    match signDataItem(data_item.into()).await {
+        Ok(signed) => {
+            log::info!("DataItem signed successfully with Wander wallet");
+            Ok(js_sys::Uint8Array::new(&signed).to_vec())
+        }
+        Err(js_error) => {
+            let error = WalletError::from(js_error);
+            log::error!("Wander wallet DataItem signing failed: {}", error);
+            Err(error)
+        }
+    }
+}
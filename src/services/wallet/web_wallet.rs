@@ -13,6 +13,7 @@ use crate::services::wallet::{WalletError, WalletStrategy, WalletStrategyType, W
 /// 
 /// Note: This is a placeholder implementation. The actual arweave-wallet-connector
 /// would need to be integrated through JS interop or Rust bindings.
+#[allow(dead_code)] // The app config is read once the connector is integrated
 pub struct WebWalletStrategy {
     app_name: String,
     app_logo: Option<String>,
@@ -42,6 +43,12 @@ impl WebWalletStrategy {
     }
 }
 
+impl Default for WebWalletStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait(?Send)]
 impl WalletStrategy for WebWalletStrategy {
     fn strategy_type(&self) -> WalletStrategyType {
@@ -60,6 +67,8 @@ impl WalletStrategy for WebWalletStrategy {
             supports_batch_signing: false,
             supports_permissions: false, // Web wallets typically don't use permission system
             supports_multiple_addresses: false,
+            can_dispatch: false,
+            can_sign_deep_hash: false,
         }
     }
     
@@ -0,0 +1,123 @@
+use js_sys::{Object, Reflect, Uint8Array};
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::utils::crypto::sha256_hex;
+
+/// Chunk size hashed by `digest_file` and `LocalFile::digest`, matching
+/// Arweave's data chunks
+pub const HASH_CHUNK_SIZE: usize = 256 * 1024;
+
+// Bindings for public/worker-bridge.js
+#[wasm_bindgen]
+extern "C" {
+    /// Whether dedicated workers are available
+    #[wasm_bindgen(js_namespace = ["window", "workerBridge"], js_name = isSupported, catch)]
+    fn is_supported_js() -> Result<bool, JsValue>;
+
+    /// Run `task` in the data worker, reporting progress from 0 to 1
+    #[wasm_bindgen(js_namespace = ["window", "workerBridge"], js_name = run, catch)]
+    async fn run_js(task: &str, payload: JsValue, on_progress: &Closure<dyn FnMut(f64)>) -> Result<JsValue, JsValue>;
+}
+
+#[derive(Debug, Clone)]
+pub enum WorkerError {
+    Unsupported,
+    TaskFailed(String),
+    InvalidResult(String),
+}
+
+impl std::fmt::Display for WorkerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorkerError::Unsupported => write!(f, "Web Workers are not available in this browser"),
+            WorkerError::TaskFailed(msg) => write!(f, "Background task failed: {}", msg),
+            WorkerError::InvalidResult(msg) => write!(f, "Background task returned an invalid result: {}", msg),
+        }
+    }
+}
+
+impl From<JsValue> for WorkerError {
+    fn from(value: JsValue) -> Self {
+        let message = value
+            .dyn_ref::<js_sys::Error>()
+            .map(|e| String::from(e.message()))
+            .unwrap_or_else(|| format!("{:?}", value));
+        WorkerError::TaskFailed(message)
+    }
+}
+
+/// SHA-256 of a file and of each of its `HASH_CHUNK_SIZE` chunks, hex encoded
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileDigest {
+    pub sha256: String,
+    pub chunk_hashes: Vec<String>,
+    pub size: usize,
+}
+
+/// Whether tasks can run off the main thread
+pub fn worker_available() -> bool {
+    is_supported_js().unwrap_or(false)
+}
+
+async fn run(task: &str, payload: JsValue, on_progress: impl FnMut(f64) + 'static) -> Result<JsValue, WorkerError> {
+    if !worker_available() {
+        return Err(WorkerError::Unsupported);
+    }
+    let on_progress = Closure::<dyn FnMut(f64)>::new(on_progress);
+    let result = run_js(task, payload, &on_progress).await?;
+    Ok(result)
+}
+
+/// What a task hashes: bytes already in memory, or a chosen file the
+/// worker reads itself
+pub enum HashInput<'a> {
+    Bytes(&'a [u8]),
+    Blob(&'a web_sys::Blob),
+}
+
+impl HashInput<'_> {
+    fn payload(&self) -> Result<JsValue, WorkerError> {
+        let payload = Object::new();
+        match self {
+            HashInput::Bytes(data) => Reflect::set(&payload, &"data".into(), &Uint8Array::from(*data))?,
+            HashInput::Blob(blob) => Reflect::set(&payload, &"blob".into(), blob)?,
+        };
+        Ok(payload.into())
+    }
+}
+
+/// Hash a file in the data worker
+///
+/// Browsers without workers hash on the main thread instead, reporting
+/// progress once at the end.
+pub async fn digest_file(data: &[u8], mut on_progress: impl FnMut(f64) + 'static) -> Result<FileDigest, WorkerError> {
+    if !worker_available() {
+        let digest = FileDigest {
+            sha256: sha256_hex(data),
+            chunk_hashes: data.chunks(HASH_CHUNK_SIZE).map(sha256_hex).collect(),
+            size: data.len(),
+        };
+        on_progress(1.0);
+        return Ok(digest);
+    }
+    digest(HashInput::Bytes(data), on_progress).await
+}
+
+/// `digest_file` in the data worker only, failing with `Unsupported`
+/// where there are no workers
+pub async fn digest(input: HashInput<'_>, on_progress: impl FnMut(f64) + 'static) -> Result<FileDigest, WorkerError> {
+    let result = run("digest", input.payload()?, on_progress).await?;
+    serde_wasm_bindgen::from_value(result).map_err(|e| WorkerError::InvalidResult(e.to_string()))
+}
+
+/// SHA-384 in the data worker, failing with `Unsupported` where there are
+/// no workers
+pub async fn sha384(input: HashInput<'_>) -> Result<[u8; 48], WorkerError> {
+    let result = run("sha384", input.payload()?, |_| {}).await?;
+    Uint8Array::new(&result)
+        .to_vec()
+        .try_into()
+        .map_err(|bytes: Vec<u8>| WorkerError::InvalidResult(format!("SHA-384 digest of {} bytes", bytes.len())))
+}
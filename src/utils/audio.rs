@@ -0,0 +1,77 @@
+// PCM helpers for the upload audio preprocessor
+
+/// Analysis window for silence detection (seconds)
+const SILENCE_WINDOW_SECS: f32 = 0.01;
+
+/// RMS level below which a window counts as silent (about -40 dBFS)
+const SILENCE_THRESHOLD: f32 = 0.01;
+
+/// Audio kept on either side of detected speech (seconds)
+const SILENCE_PADDING_SECS: f32 = 0.25;
+
+/// Peak amplitude of `buckets` equal slices of `samples`, for drawing a
+/// waveform
+pub fn waveform_peaks(samples: &[f32], buckets: usize) -> Vec<f32> {
+    if samples.is_empty() || buckets == 0 {
+        return Vec::new();
+    }
+    let size = samples.len().div_ceil(buckets);
+    samples
+        .chunks(size)
+        .map(|chunk| chunk.iter().fold(0.0f32, |peak, s| peak.max(s.abs())))
+        .collect()
+}
+
+/// Start and end (in seconds) of the audible part of `samples`, with a
+/// little padding; `None` if everything is silent
+pub fn audible_bounds(samples: &[f32], sample_rate: f32) -> Option<(f32, f32)> {
+    let window = ((sample_rate * SILENCE_WINDOW_SECS) as usize).max(1);
+    let is_loud = |chunk: &[f32]| {
+        let rms = (chunk.iter().map(|s| s * s).sum::<f32>() / chunk.len() as f32).sqrt();
+        rms >= SILENCE_THRESHOLD
+    };
+
+    let windows: Vec<&[f32]> = samples.chunks(window).collect();
+    let first = windows.iter().position(|chunk| is_loud(chunk))?;
+    let last = windows.iter().rposition(|chunk| is_loud(chunk))?;
+
+    let duration = samples.len() as f32 / sample_rate;
+    let start = (first * window) as f32 / sample_rate - SILENCE_PADDING_SECS;
+    let end = ((last + 1) * window) as f32 / sample_rate + SILENCE_PADDING_SECS;
+    Some((start.max(0.0), end.min(duration)))
+}
+
+/// Size in bytes of a 16-bit PCM WAV file
+pub fn wav_size(frames: usize, channels: usize) -> usize {
+    44 + frames * channels * 2
+}
+
+/// Encode planar float channels as a 16-bit PCM WAV file
+pub fn encode_wav(channels: &[Vec<f32>], sample_rate: u32) -> Vec<u8> {
+    let channel_count = channels.len().max(1);
+    let frames = channels.first().map_or(0, Vec::len);
+    let data_len = (frames * channel_count * 2) as u32;
+    let byte_rate = sample_rate * channel_count as u32 * 2;
+
+    let mut out = Vec::with_capacity(wav_size(frames, channel_count));
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&(36 + data_len).to_le_bytes());
+    out.extend_from_slice(b"WAVEfmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&1u16.to_le_bytes());
+    out.extend_from_slice(&(channel_count as u16).to_le_bytes());
+    out.extend_from_slice(&sample_rate.to_le_bytes());
+    out.extend_from_slice(&byte_rate.to_le_bytes());
+    out.extend_from_slice(&(channel_count as u16 * 2).to_le_bytes());
+    out.extend_from_slice(&16u16.to_le_bytes());
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&data_len.to_le_bytes());
+
+    for frame in 0..frames {
+        for channel in channels {
+            let sample = channel.get(frame).copied().unwrap_or(0.0).clamp(-1.0, 1.0);
+            out.extend_from_slice(&((sample * i16::MAX as f32) as i16).to_le_bytes());
+        }
+    }
+    out
+}
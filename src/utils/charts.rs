@@ -0,0 +1,162 @@
+//! Geometry for the SVG charts
+//!
+//! Bar and line charts draw into a `VIEW_WIDTH` by `VIEW_HEIGHT` box that
+//! the SVG stretches to fit its container, so nothing here needs to know
+//! the size on screen. Donuts draw each slice as a dash along a circle
+//! whose circumference is 100, so a slice's dash length is its percentage.
+
+/// Width of the bar and line chart view box
+pub const VIEW_WIDTH: f64 = 100.0;
+/// Height of the bar and line chart view box
+pub const VIEW_HEIGHT: f64 = 40.0;
+/// Radius of a circle with a circumference of 100
+pub const DONUT_RADIUS: f64 = 15.915_494;
+/// Part of each bar's slot left empty between bars
+const BAR_GAP: f64 = 0.2;
+
+/// One labelled value in a chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartPoint {
+    pub label: String,
+    pub value: f64,
+}
+
+impl ChartPoint {
+    pub fn new(label: impl Into<String>, value: f64) -> Self {
+        Self { label: label.into(), value }
+    }
+}
+
+/// A bar's rectangle in view box units
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// A donut slice as a dash along the circle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DonutSlice {
+    /// Percentage of the whole
+    pub length: f64,
+    /// `stroke-dashoffset` that starts the slice where the last one ended,
+    /// counting clockwise from the top
+    pub offset: f64,
+}
+
+// Largest value, at least 1 so an all-zero series draws flat
+fn scale_max(points: &[ChartPoint]) -> f64 {
+    points.iter().map(|point| point.value).fold(1.0, f64::max)
+}
+
+/// One bar per point, side by side across the view box
+pub fn bars(points: &[ChartPoint]) -> Vec<Bar> {
+    let max = scale_max(points);
+    let slot = VIEW_WIDTH / points.len().max(1) as f64;
+    points
+        .iter()
+        .enumerate()
+        .map(|(index, point)| {
+            let height = point.value.max(0.0) / max * VIEW_HEIGHT;
+            Bar {
+                x: index as f64 * slot + slot * BAR_GAP / 2.0,
+                y: VIEW_HEIGHT - height,
+                width: slot * (1.0 - BAR_GAP),
+                height,
+            }
+        })
+        .collect()
+}
+
+/// Where each point of a line chart sits, spread evenly from edge to edge
+pub fn line_coordinates(points: &[ChartPoint]) -> Vec<(f64, f64)> {
+    let max = scale_max(points);
+    let step = VIEW_WIDTH / points.len().saturating_sub(1).max(1) as f64;
+    points
+        .iter()
+        .enumerate()
+        .map(|(index, point)| {
+            // A single point sits in the middle
+            let x = if points.len() == 1 { VIEW_WIDTH / 2.0 } else { index as f64 * step };
+            (x, VIEW_HEIGHT - point.value.max(0.0) / max * VIEW_HEIGHT)
+        })
+        .collect()
+}
+
+/// SVG path through `coordinates`
+pub fn line_path(coordinates: &[(f64, f64)]) -> String {
+    coordinates
+        .iter()
+        .enumerate()
+        .map(|(index, (x, y))| format!("{}{:.2} {:.2}", if index == 0 { "M" } else { "L" }, x, y))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// SVG path filling the area between the line and the bottom edge
+pub fn area_path(coordinates: &[(f64, f64)]) -> String {
+    match (coordinates.first(), coordinates.last()) {
+        (Some((first_x, _)), Some((last_x, _))) => {
+            format!("{} L{:.2} {:.2} L{:.2} {:.2} Z", line_path(coordinates), last_x, VIEW_HEIGHT, first_x, VIEW_HEIGHT)
+        }
+        _ => String::new(),
+    }
+}
+
+/// One slice per point; all empty when there's nothing to share out
+pub fn donut_slices(points: &[ChartPoint]) -> Vec<DonutSlice> {
+    let total: f64 = points.iter().map(|point| point.value.max(0.0)).sum();
+    let mut before = 0.0;
+    points
+        .iter()
+        .map(|point| {
+            let length = if total > 0.0 { point.value.max(0.0) / total * 100.0 } else { 0.0 };
+            // Dashes start at three o'clock; a quarter turn back puts them at the top
+            let slice = DonutSlice { length, offset: 25.0 - before };
+            before += length;
+            slice
+        })
+        .collect()
+}
+
+/// A value without decimals when it's whole
+pub fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn scales_bars_lines_and_slices() {
+        let points = vec![ChartPoint::new("Mon", 2.0), ChartPoint::new("Tue", 0.0), ChartPoint::new("Wed", 4.0)];
+
+        let bars = bars(&points);
+        assert_eq!(bars.len(), 3);
+        assert_eq!((bars[2].y, bars[2].height), (0.0, VIEW_HEIGHT));
+        assert_eq!(bars[0].height, VIEW_HEIGHT / 2.0);
+        assert_eq!(bars[1].height, 0.0);
+        assert!(bars[0].x + bars[0].width < bars[1].x);
+
+        let coordinates = line_coordinates(&points);
+        assert_eq!(coordinates, vec![(0.0, 20.0), (50.0, VIEW_HEIGHT), (VIEW_WIDTH, 0.0)]);
+        assert_eq!(line_path(&coordinates), "M0.00 20.00 L50.00 40.00 L100.00 0.00");
+        assert!(area_path(&coordinates).ends_with("L100.00 40.00 L0.00 40.00 Z"));
+        assert_eq!(line_coordinates(&points[..1]), vec![(VIEW_WIDTH / 2.0, 0.0)]);
+
+        let slices = donut_slices(&[ChartPoint::new("Audio", 3.0), ChartPoint::new("Video", 1.0)]);
+        assert_eq!(slices, vec![DonutSlice { length: 75.0, offset: 25.0 }, DonutSlice { length: 25.0, offset: -50.0 }]);
+        assert!(donut_slices(&[ChartPoint::new("None", 0.0)]).iter().all(|slice| slice.length == 0.0));
+
+        assert_eq!(format_value(12.0), "12");
+        assert_eq!(format_value(2.5), "2.5");
+    }
+}
@@ -0,0 +1,309 @@
+use chrono::{DateTime, Utc};
+
+use crate::models::ContentMetadata;
+use crate::config::app_config;
+use crate::utils::constants::APP_NAME;
+use crate::utils::download::download_bytes;
+
+/// Supported citation formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CitationStyle {
+    Chicago,
+    Mla,
+    Sbl,
+}
+
+impl CitationStyle {
+    pub const ALL: [CitationStyle; 3] = [CitationStyle::Chicago, CitationStyle::Mla, CitationStyle::Sbl];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CitationStyle::Chicago => "Chicago",
+            CitationStyle::Mla => "MLA",
+            CitationStyle::Sbl => "SBL",
+        }
+    }
+
+    /// Format a citation for `item` in this style
+    pub fn format(&self, item: &ContentMetadata) -> String {
+        let url = permanent_url(&item.tx_id);
+        let archived = item.block_timestamp.and_then(to_date);
+        let created = item.created_at.and_then(to_date);
+        let title = item.title.trim_end_matches('.');
+
+        match self {
+            CitationStyle::Chicago => {
+                let mut parts = Vec::new();
+                if let Some(speaker) = item.speaker.as_ref() {
+                    parts.push(format!("{}.", invert_name(speaker)));
+                }
+                parts.push(format!("\u{201c}{}.\u{201d}", title));
+                if let Some(series) = item.series.as_ref() {
+                    parts.push(format!("{}.", series));
+                }
+                match (item.ministry.as_ref(), created) {
+                    (Some(ministry), Some(date)) => parts.push(format!("{}, {}.", ministry, date.format("%B %-d, %Y"))),
+                    (Some(ministry), None) => parts.push(format!("{}.", ministry)),
+                    (None, Some(date)) => parts.push(format!("{}.", date.format("%B %-d, %Y"))),
+                    (None, None) => {}
+                }
+                match archived {
+                    Some(date) => parts.push(format!("Archived in {}, {}.", APP_NAME, date.format("%B %-d, %Y"))),
+                    None => parts.push(format!("Archived in {}.", APP_NAME)),
+                }
+                parts.push(format!("{}.", url));
+                parts.join(" ")
+            }
+            CitationStyle::Mla => {
+                let mut parts = Vec::new();
+                if let Some(speaker) = item.speaker.as_ref() {
+                    parts.push(format!("{}.", invert_name(speaker)));
+                }
+                parts.push(format!("\u{201c}{}.\u{201d}", title));
+
+                let mut container = Vec::new();
+                if let Some(series) = item.series.as_ref() {
+                    container.push(series.clone());
+                }
+                if let Some(ministry) = item.ministry.as_ref() {
+                    container.push(ministry.clone());
+                }
+                if let Some(date) = created {
+                    container.push(date.format("%-d %b. %Y").to_string());
+                }
+                if !container.is_empty() {
+                    parts.push(format!("{}.", container.join(", ")));
+                }
+
+                parts.push(format!("{}, {}.", APP_NAME, url));
+                if let Some(date) = archived {
+                    parts.push(format!("Archived {}.", date.format("%-d %b. %Y")));
+                }
+                parts.join(" ")
+            }
+            CitationStyle::Sbl => {
+                let mut parts = Vec::new();
+                if let Some(speaker) = item.speaker.as_ref() {
+                    parts.push(format!("{}.", invert_name(speaker)));
+                }
+                parts.push(format!("\u{201c}{}.\u{201d}", title));
+                if let Some(series) = item.series.as_ref() {
+                    parts.push(format!("{}.", series));
+                }
+                let mut publication = Vec::new();
+                if let Some(ministry) = item.ministry.as_ref() {
+                    publication.push(ministry.clone());
+                }
+                if let Some(date) = created {
+                    publication.push(date.format("%Y").to_string());
+                }
+                if !publication.is_empty() {
+                    parts.push(format!("{}.", publication.join(", ")));
+                }
+                match archived {
+                    Some(date) => parts.push(format!(
+                        "{}. Arweave transaction {}, archived {}.",
+                        url,
+                        item.tx_id,
+                        date.format("%-d %B %Y")
+                    )),
+                    None => parts.push(format!("{}. Arweave transaction {}.", url, item.tx_id)),
+                }
+                parts.join(" ")
+            }
+        }
+    }
+}
+
+/// Gateway-independent URL for a transaction
+///
+/// Always uses the canonical gateway rather than the user's pinned one so
+/// citations stay identical no matter who generates them.
+pub fn permanent_url(tx_id: &str) -> String {
+    format!("{}/{}", app_config().primary_gateway(), tx_id)
+}
+
+/// All styles as plain text, one per paragraph, for exports
+pub fn citations_text(item: &ContentMetadata) -> String {
+    CitationStyle::ALL
+        .iter()
+        .map(|style| format!("{}\n{}\n", style.display_name(), style.format(item)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn to_date(timestamp: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(timestamp, 0)
+}
+
+/// "John Smith" -> "Smith, John"; single names and "Last, First" pass through
+fn invert_name(name: &str) -> String {
+    let name = name.trim();
+    if name.contains(',') {
+        return name.to_string();
+    }
+    match name.rsplit_once(' ') {
+        Some((given, family)) => format!("{}, {}", family, given),
+        None => name.to_string(),
+    }
+}
+
+/// Reference-manager file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceFormat {
+    Ris,
+    Bibtex,
+}
+
+impl ReferenceFormat {
+    pub const ALL: [ReferenceFormat; 2] = [ReferenceFormat::Ris, ReferenceFormat::Bibtex];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ReferenceFormat::Ris => "RIS",
+            ReferenceFormat::Bibtex => "BibTeX",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReferenceFormat::Ris => "ris",
+            ReferenceFormat::Bibtex => "bib",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ReferenceFormat::Ris => "application/x-research-info-systems",
+            ReferenceFormat::Bibtex => "application/x-bibtex",
+        }
+    }
+
+    /// One file holding an entry per item
+    pub fn export(&self, items: &[ContentMetadata]) -> String {
+        let entries: Vec<String> = match self {
+            ReferenceFormat::Ris => items.iter().map(ris_entry).collect(),
+            ReferenceFormat::Bibtex => items.iter().map(bibtex_entry).collect(),
+        };
+        entries.join("\n")
+    }
+
+    /// Download `items` as a reference file
+    pub fn download(&self, items: &[ContentMetadata]) -> Result<(), String> {
+        let filename = match items {
+            [item] => format!("{}.{}", item.tx_id, self.extension()),
+            _ => format!("faithful-archive-references.{}", self.extension()),
+        };
+        download_bytes(&filename, self.export(items).as_bytes(), self.mime_type())
+    }
+}
+
+fn ris_entry(item: &ContentMetadata) -> String {
+    let media = item.content_type.split('/').next().unwrap_or_default();
+    let kind = match media {
+        "audio" => "SOUND",
+        "video" => "VIDEO",
+        _ => "ELEC",
+    };
+
+    let mut lines = vec![format!("TY  - {}", kind), format!("TI  - {}", item.title)];
+    if let Some(speaker) = item.speaker.as_ref() {
+        lines.push(format!("AU  - {}", invert_name(speaker)));
+    }
+    if let Some(series) = item.series.as_ref() {
+        lines.push(format!("T2  - {}", series));
+    }
+    if let Some(ministry) = item.ministry.as_ref() {
+        lines.push(format!("PB  - {}", ministry));
+    }
+    if let Some(date) = item.created_at.and_then(to_date) {
+        lines.push(format!("PY  - {}", date.format("%Y")));
+        lines.push(format!("DA  - {}", date.format("%Y/%m/%d")));
+    }
+    if let Some(description) = item.description.as_ref() {
+        lines.push(format!("AB  - {}", description.replace('\n', " ")));
+    }
+    for scripture_ref in &item.scripture_refs {
+        lines.push(format!("KW  - {}", scripture_ref));
+    }
+    if let Some(language) = item.language.as_ref() {
+        lines.push(format!("LA  - {}", language));
+    }
+    lines.push(format!("UR  - {}", permanent_url(&item.tx_id)));
+    lines.push(format!("DB  - {}", APP_NAME));
+    lines.push(format!("AN  - {}", item.tx_id));
+    if let Some(date) = item.block_timestamp.and_then(to_date) {
+        lines.push(format!("Y2  - {}", date.format("%Y/%m/%d")));
+    }
+    lines.push("ER  - ".to_string());
+    lines.join("\r\n") + "\r\n"
+}
+
+fn bibtex_entry(item: &ContentMetadata) -> String {
+    let created = item.created_at.and_then(to_date);
+    let mut fields = vec![("title", format!("{{{}}}", bibtex_escape(&item.title)))];
+    if let Some(speaker) = item.speaker.as_ref() {
+        fields.push(("author", bibtex_escape(&invert_name(speaker))));
+    }
+    if let Some(series) = item.series.as_ref() {
+        fields.push(("series", bibtex_escape(series)));
+    }
+    if let Some(ministry) = item.ministry.as_ref() {
+        fields.push(("organization", bibtex_escape(ministry)));
+    }
+    if let Some(date) = created {
+        fields.push(("year", date.format("%Y").to_string()));
+        fields.push(("date", date.format("%Y-%m-%d").to_string()));
+    }
+    if let Some(description) = item.description.as_ref() {
+        fields.push(("abstract", bibtex_escape(description)));
+    }
+    if !item.scripture_refs.is_empty() {
+        fields.push(("keywords", bibtex_escape(&item.scripture_refs.join(", "))));
+    }
+    if let Some(language) = item.language.as_ref() {
+        fields.push(("language", bibtex_escape(language)));
+    }
+    fields.push(("url", permanent_url(&item.tx_id)));
+    let archived = match item.block_timestamp.and_then(to_date) {
+        Some(date) => format!("Archived in {} on {}. ", APP_NAME, date.format("%Y-%m-%d")),
+        None => format!("Archived in {}. ", APP_NAME),
+    };
+    fields.push(("note", format!("{}Arweave transaction {}", archived, bibtex_escape(&item.tx_id))));
+
+    let body: Vec<String> = fields
+        .iter()
+        .map(|(name, value)| format!("  {} = {{{}}}", name, value))
+        .collect();
+    format!("@misc{{{},\n{}\n}}\n", bibtex_key(item, created), body.join(",\n"))
+}
+
+/// Citation key like `smith2023-ab12cd34`; the ID suffix keeps keys unique
+fn bibtex_key(item: &ContentMetadata, created: Option<DateTime<Utc>>) -> String {
+    let surname = item
+        .speaker
+        .as_deref()
+        .and_then(|speaker| speaker.split_whitespace().last())
+        .unwrap_or("faithfularchive");
+    let mut key: String = surname.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase();
+    if let Some(date) = created {
+        key.push_str(&date.format("%Y").to_string());
+    }
+    let suffix: String = item.tx_id.chars().filter(|c| c.is_ascii_alphanumeric()).take(8).collect();
+    format!("{}-{}", key, suffix.to_lowercase())
+}
+
+fn bibtex_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '{' | '}' | '%' | '&' | '$' | '#' | '_' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push(' '),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,200 @@
+// Application-wide constants
+//
+// Endpoint constants are the built-in defaults; read the active values from
+// `crate::config::app_config()`.
+
+/// App-Name tag value attached to every DataItem we publish
+pub const APP_NAME: &str = "Faithful-Archive";
+
+/// Version of the running build
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Built-in gateways in priority order; arweave.net first, then ar.io gateways
+pub const DEFAULT_GATEWAYS: &[&str] = &[
+    "https://arweave.net",
+    "https://ar-io.net",
+    "https://permagate.io",
+];
+
+/// IndexedDB database name for local caching
+pub const DB_NAME: &str = "faithful_archive";
+
+/// How often background sync checks pending transactions (ms)
+pub const CONFIRMATION_POLL_INTERVAL_MS: u32 = 60_000;
+
+/// Number of confirmations after which a transaction is considered final
+pub const REQUIRED_CONFIRMATIONS: u64 = 10;
+
+/// Bundler that new signed DataItems are posted to
+pub const DEFAULT_BUNDLER: &str = "https://upload.ardrive.io/v1/tx";
+
+/// Bundlers that accept re-seeded signed DataItems
+pub const ALTERNATE_BUNDLERS: &[&str] = &[
+    "https://upload.ardrive.io/v1/tx",
+    "https://node2.irys.xyz/tx/arweave",
+];
+
+/// AO messenger unit that signed AO messages are posted to
+pub const AO_MU_URL: &str = "https://mu.ao-testnet.xyz";
+
+/// AO compute unit that process state is read from
+pub const AO_CU_URL: &str = "https://cu.ao-testnet.xyz";
+
+/// AO process that records which moderator is reviewing what. Empty until
+/// one is deployed, which leaves review assignments off.
+pub const AO_MODERATION_PROCESS: &str = "";
+
+/// AO token process that tips in AO are transferred on
+pub const AO_TOKEN_PROCESS: &str = "0syT13r0s0tgPmIed95bJnuSqaD29HQNN8D3ElLSrsc";
+
+/// Domain ArNS names resolve under, as in `name.ar.io`
+pub const ARNS_DOMAIN: &str = "ar.io";
+
+/// Where new ArNS names are bought; the app only updates existing ones
+pub const ARNS_REGISTRATION_URL: &str = "https://arns.app";
+
+/// Minimum time between scheduled archive re-verification runs (seconds)
+pub const REVERIFY_INTERVAL_SECS: i64 = 24 * 60 * 60;
+
+/// How long computed platform statistics are reused before they're
+/// counted again (seconds)
+pub const PLATFORM_STATS_MAX_AGE_SECS: i64 = 6 * 60 * 60;
+
+/// Number of published items sampled on each scheduled re-verification
+pub const REVERIFY_SAMPLE_SIZE: usize = 25;
+
+/// Size of each Range request when downloading content (bytes)
+pub const FETCH_CHUNK_SIZE: u64 = 1024 * 1024;
+
+/// Size of each slice read from a chosen file when hashing or uploading it;
+/// a multiple of the 256 KiB hash chunk (bytes)
+pub const FILE_READ_CHUNK_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Largest file hashed in the data worker, which has to read it whole;
+/// larger ones are hashed a slice at a time on the main thread (bytes)
+pub const WORKER_HASH_MAX_BYTES: u64 = 512 * 1024 * 1024;
+
+/// Files larger than this are content-scanned by their first bytes only.
+/// Only audio and video get that big, and their checks look at the header
+/// (bytes)
+pub const SCAN_READ_LIMIT: u64 = 64 * 1024 * 1024;
+
+/// Items at or below this size are cached in IndexedDB (bytes)
+pub const CONTENT_CACHE_ITEM_LIMIT: u64 = 5 * 1024 * 1024;
+
+/// Total IndexedDB budget for cached content before LRU eviction (bytes)
+pub const CONTENT_CACHE_MAX_BYTES: u64 = 50 * 1024 * 1024;
+
+/// Items up to this size have their signature checked when their page
+/// opens; larger ones wait for the viewer to ask (bytes)
+pub const AUTO_VERIFY_MAX_BYTES: u64 = 25 * 1024 * 1024;
+
+/// Shortest passphrase accepted for encrypting an upload (characters)
+pub const MIN_PASSPHRASE_CHARS: usize = 8;
+
+/// Wallet addresses of the moderation team. Hide-registry entries and
+/// rights-claim acknowledgments are only trusted from these addresses, and
+/// the team's claim encryption key is looked up from them.
+pub const MODERATOR_ADDRESSES: &[&str] = &[];
+
+/// Wallet addresses allowed to publish release notes. Notes signed by
+/// anyone else are ignored by the "What's new" screen.
+pub const RELEASE_PUBLISHER_ADDRESSES: &[&str] = &[];
+
+/// Largest item Wander's `dispatch()` bundles for free; anything bigger
+/// it posts as a layer-1 transaction the wallet pays for
+pub const DISPATCH_FREE_LIMIT: usize = 100 * 1024;
+
+/// Turbo payment service that sells the bundler's upload credits
+pub const TURBO_PAYMENT_URL: &str = "https://payment.ardrive.io";
+
+/// Public IPFS gateway used for mirror links
+pub const IPFS_GATEWAY: &str = "https://ipfs.io";
+
+/// Public-domain Bible text API used by the passage viewer
+pub const PASSAGE_API_URL: &str = "https://bible-api.com";
+
+/// DNS-over-HTTPS resolver that ministry domain proofs are checked with
+pub const DNS_OVER_HTTPS_URL: &str = "https://cloudflare-dns.com/dns-query";
+
+/// Translation requested from the passage API (World English Bible)
+pub const PASSAGE_TRANSLATION: &str = "web";
+
+/// Longest side of uploaded cover images after resizing (pixels)
+pub const COVER_MAX_DIMENSION: u32 = 1200;
+
+/// WebP quality for re-encoded cover images, from 0.0 to 1.0
+pub const COVER_IMAGE_QUALITY: f64 = 0.8;
+
+/// Gateway and GraphQL requests allowed in flight at once across the app
+pub const MAX_CONCURRENT_REQUESTS: usize = 6;
+
+/// Steady rate of requests sent to any one gateway host
+pub const HOST_REQUESTS_PER_SECOND: f64 = 10.0;
+
+/// Requests a host may receive back to back before the rate applies
+pub const HOST_REQUEST_BURST: u32 = 5;
+
+/// Retries of a request a gateway answered with 429 or 503
+pub const MAX_REQUEST_RETRIES: u32 = 3;
+
+/// Backoff before the first retry of a throttled request (ms)
+pub const RETRY_BASE_DELAY_MS: u32 = 500;
+
+/// Longest wait for a throttled gateway; if it asks for longer the
+/// request fails over instead (ms)
+pub const MAX_RETRY_DELAY_MS: u32 = 30_000;
+
+/// How long cached gateway metadata is fresh when the response doesn't
+/// say (seconds)
+pub const HTTP_CACHE_MAX_AGE_SECS: i64 = 60;
+
+/// How long past its freshness cached metadata may be shown while it is
+/// refreshed, when the response doesn't say (seconds)
+pub const HTTP_CACHE_STALE_SECS: i64 = 24 * 60 * 60;
+
+/// How long expired responses are kept as an offline fallback (seconds)
+pub const HTTP_CACHE_RETENTION_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// How often background sync refreshes the hide registry and Following
+/// feed (ms)
+pub const METADATA_SYNC_INTERVAL_MS: u32 = 10 * 60_000;
+
+/// How often background sync looks for approvals to send to the webhook
+/// and retries failed deliveries (ms)
+pub const OUTBOX_SYNC_INTERVAL_MS: u32 = 5 * 60_000;
+
+/// Delay before the first retry of a failed webhook delivery; it doubles
+/// with each attempt (seconds)
+pub const WEBHOOK_RETRY_BASE_SECS: i64 = 60;
+
+/// Longest wait between webhook delivery attempts (seconds)
+pub const WEBHOOK_RETRY_MAX_SECS: i64 = 6 * 60 * 60;
+
+/// Webhook deliveries are given up after this many failed attempts
+pub const WEBHOOK_MAX_ATTEMPTS: u32 = 8;
+
+/// Background sync intervals are this many times longer with data saver on
+pub const DATA_SAVER_SYNC_MULTIPLIER: u32 = 5;
+
+/// Regaining focus only syncs tasks that haven't run for this long (ms)
+pub const SYNC_FOCUS_MIN_GAP_MS: u32 = 30_000;
+
+/// How often the background sync scheduler looks for due tasks (ms)
+pub const SYNC_TICK_MS: u32 = 15_000;
+
+/// Notifications kept before the oldest are dropped
+pub const MAX_NOTIFICATIONS: usize = 200;
+
+/// Days of local usage stats kept before the oldest are dropped
+pub const USAGE_STATS_RETENTION_DAYS: u64 = 90;
+
+/// Shared usage totals are rounded down to a multiple of this, so small
+/// counts can't single out a listener
+pub const SHARED_COUNT_BUCKET: u32 = 5;
+
+/// Time a wallet has to finish connecting, including Beacon pairing (ms)
+pub const WALLET_CONNECT_TIMEOUT_MS: u32 = 2 * 60_000;
+
+/// Time a wallet has to sign, which includes the user reading the prompt (ms)
+pub const WALLET_SIGN_TIMEOUT_MS: u32 = 5 * 60_000;
@@ -0,0 +1,89 @@
+// Encryption utilities
+use sha2::{Digest, Sha256};
+use wasm_bindgen::prelude::*;
+
+// Bindings for public/crypto-bridge.js
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["window", "cryptoBridge"], js_name = encryptForRecipient, catch)]
+    async fn encrypt_for_recipient_js(plaintext: &[u8], public_key_jwk: &str) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "cryptoBridge"], js_name = deriveKey, catch)]
+    async fn derive_key_js(passphrase: &str, salt: &[u8]) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "cryptoBridge"], js_name = encryptWithKey, catch)]
+    async fn encrypt_with_key_js(plaintext: &[u8], raw_key: &[u8]) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "cryptoBridge"], js_name = decryptWithKey, catch)]
+    async fn decrypt_with_key_js(envelope: &[u8], raw_key: &[u8]) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["window", "cryptoBridge"], js_name = verifyRsaPss, catch)]
+    async fn verify_rsa_pss_js(owner: &str, signature: &[u8], message: &[u8]) -> Result<JsValue, JsValue>;
+}
+
+#[derive(Debug, Clone)]
+pub enum CryptoError {
+    EncryptionFailed(String),
+    DecryptionFailed(String),
+    VerificationFailed(String),
+}
+
+impl std::fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CryptoError::EncryptionFailed(msg) => write!(f, "Encryption failed: {}", msg),
+            CryptoError::DecryptionFailed(msg) => write!(f, "Decryption failed: {}", msg),
+            CryptoError::VerificationFailed(msg) => write!(f, "Signature check failed: {}", msg),
+        }
+    }
+}
+
+/// Encrypt `plaintext` so only the holder of the RSA private key matching
+/// `public_key_jwk` can read it. Returns a JSON envelope.
+pub async fn encrypt_for_recipient(plaintext: &[u8], public_key_jwk: &str) -> Result<String, CryptoError> {
+    encrypt_for_recipient_js(plaintext, public_key_jwk)
+        .await
+        .map_err(|e| CryptoError::EncryptionFailed(format!("{:?}", e)))?
+        .as_string()
+        .ok_or_else(|| CryptoError::EncryptionFailed("Bridge returned no envelope".to_string()))
+}
+
+/// 256-bit key derived from `passphrase` with PBKDF2-SHA256
+pub async fn derive_key(passphrase: &str, salt: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    let key = derive_key_js(passphrase, salt)
+        .await
+        .map_err(|e| CryptoError::EncryptionFailed(format!("{:?}", e)))?;
+    Ok(js_sys::Uint8Array::new(&key).to_vec())
+}
+
+/// AES-256-GCM encrypt under `raw_key`; the output starts with the IV
+pub async fn encrypt_with_key(plaintext: &[u8], raw_key: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    let envelope = encrypt_with_key_js(plaintext, raw_key)
+        .await
+        .map_err(|e| CryptoError::EncryptionFailed(format!("{:?}", e)))?;
+    Ok(js_sys::Uint8Array::new(&envelope).to_vec())
+}
+
+/// Reverse `encrypt_with_key`; fails if the key is wrong or the data was
+/// tampered with
+pub async fn decrypt_with_key(envelope: &[u8], raw_key: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    let plaintext = decrypt_with_key_js(envelope, raw_key)
+        .await
+        .map_err(|e| CryptoError::DecryptionFailed(format!("{:?}", e)))?;
+    Ok(js_sys::Uint8Array::new(&plaintext).to_vec())
+}
+
+/// Whether `signature` is a valid Arweave RSA-PSS signature of `message`
+/// by the key whose modulus is `owner` (base64url)
+pub async fn verify_rsa_pss(owner: &str, signature: &[u8], message: &[u8]) -> Result<bool, CryptoError> {
+    verify_rsa_pss_js(owner, signature, message)
+        .await
+        .map_err(|e| CryptoError::VerificationFailed(format!("{:?}", e)))?
+        .as_bool()
+        .ok_or_else(|| CryptoError::VerificationFailed("Bridge returned no result".to_string()))
+}
+
+/// Hex-encoded SHA-256 digest
+pub fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
@@ -0,0 +1,213 @@
+//! Arweave deep hashes, the messages DataItem and transaction owners sign
+
+use gloo_timers::future::TimeoutFuture;
+use sha2::{Digest, Sha384};
+
+use crate::services::worker::{self, worker_available, HashInput};
+use crate::utils::constants::FILE_READ_CHUNK_SIZE;
+
+/// The signed fields of an ANS-104 DataItem other than its data
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataItemParts {
+    /// 1 = Arweave, 2 = ED25519, 3 = Ethereum, 4 = Solana
+    pub signature_type: u16,
+    pub owner: Vec<u8>,
+    pub target: Option<[u8; 32]>,
+    pub anchor: Option<[u8; 32]>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl DataItemParts {
+    /// Deep hash of the item carrying `data_size` bytes whose SHA-384 is
+    /// `data_digest`, so the data can be hashed a slice at a time
+    pub fn deep_hash(&self, data_size: u64, data_digest: &[u8]) -> [u8; 48] {
+        list_hash(&[
+            blob_hash(b"dataitem"),
+            blob_hash(b"1"),
+            blob_hash(self.signature_type.to_string().as_bytes()),
+            blob_hash(&self.owner),
+            blob_hash(self.target.as_ref().map_or(&[][..], |target| &target[..])),
+            blob_hash(self.anchor.as_ref().map_or(&[][..], |anchor| &anchor[..])),
+            blob_hash(&encode_tags(&self.tags)),
+            blob_hash_of(data_size, data_digest),
+        ])
+    }
+}
+
+/// The signed fields of a format 2 base-layer transaction, decoded from
+/// the gateway's `/tx/{id}` record
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionParts {
+    pub owner: Vec<u8>,
+    pub target: Vec<u8>,
+    /// Winston, as a decimal string
+    pub quantity: String,
+    /// Winston, as a decimal string
+    pub reward: String,
+    pub last_tx: Vec<u8>,
+    pub tags: Vec<(Vec<u8>, Vec<u8>)>,
+    pub data_size: String,
+    pub data_root: Vec<u8>,
+}
+
+impl TransactionParts {
+    /// The message the owner signed, as arweave-js builds it
+    pub fn deep_hash(&self) -> [u8; 48] {
+        let tags: Vec<[u8; 48]> = self
+            .tags
+            .iter()
+            .map(|(name, value)| list_hash(&[blob_hash(name), blob_hash(value)]))
+            .collect();
+        list_hash(&[
+            blob_hash(b"2"),
+            blob_hash(&self.owner),
+            blob_hash(&self.target),
+            blob_hash(self.quantity.as_bytes()),
+            blob_hash(self.reward.as_bytes()),
+            blob_hash(&self.last_tx),
+            list_hash(&tags),
+            blob_hash(self.data_size.as_bytes()),
+            blob_hash(&self.data_root),
+        ])
+    }
+}
+
+/// SHA-384 of `data`, in the data worker if there is one, otherwise
+/// letting the page update between slices
+pub async fn data_digest(data: &[u8]) -> [u8; 48] {
+    if worker_available() {
+        match worker::sha384(HashInput::Bytes(data)).await {
+            Ok(digest) => return digest,
+            Err(e) => log::warn!("Hashing on the main thread instead: {}", e),
+        }
+    }
+
+    let mut hasher = Sha384::new();
+    for slice in data.chunks(FILE_READ_CHUNK_SIZE as usize) {
+        hasher.update(slice);
+        TimeoutFuture::new(0).await;
+    }
+    hasher.finalize().into()
+}
+
+/// ANS-104 tags in Avro: a block count, each name and value as bytes, then
+/// an empty block; no tags at all encode as nothing
+pub fn encode_tags(tags: &[(String, String)]) -> Vec<u8> {
+    if tags.is_empty() {
+        return Vec::new();
+    }
+    let mut encoded = Vec::new();
+    write_long(&mut encoded, tags.len() as i64);
+    for (name, value) in tags {
+        write_long(&mut encoded, name.len() as i64);
+        encoded.extend_from_slice(name.as_bytes());
+        write_long(&mut encoded, value.len() as i64);
+        encoded.extend_from_slice(value.as_bytes());
+    }
+    write_long(&mut encoded, 0);
+    encoded
+}
+
+/// Avro long: zigzag, then a little-endian base-128 varint
+fn write_long(out: &mut Vec<u8>, n: i64) {
+    let mut n = ((n << 1) ^ (n >> 63)) as u64;
+    while n >= 0x80 {
+        out.push((n as u8 & 0x7f) | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+fn sha384(parts: &[&[u8]]) -> [u8; 48] {
+    let mut hasher = Sha384::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// Deep hash of a blob of `len` bytes whose SHA-384 is `digest`
+fn blob_hash_of(len: u64, digest: &[u8]) -> [u8; 48] {
+    sha384(&[&sha384(&[format!("blob{}", len).as_bytes()]), digest])
+}
+
+fn blob_hash(data: &[u8]) -> [u8; 48] {
+    blob_hash_of(data.len() as u64, &sha384(&[data]))
+}
+
+/// Deep hash of a list, given its items' deep hashes
+fn list_hash(items: &[[u8; 48]]) -> [u8; 48] {
+    items
+        .iter()
+        .fold(sha384(&[format!("list{}", items.len()).as_bytes()]), |acc, item| sha384(&[&acc, item]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn tags_encode_as_avro() {
+        assert_eq!(encode_tags(&[("a".to_string(), "b".to_string())]), [2, 2, b'a', 2, b'b', 0]);
+        assert!(encode_tags(&[]).is_empty());
+
+        let mut long = Vec::new();
+        write_long(&mut long, 300);
+        assert_eq!(long, [0xd8, 0x04]);
+    }
+
+    #[wasm_bindgen_test]
+    async fn hashes_data_items_and_transactions_field_by_field() {
+        let data = vec![5u8; FILE_READ_CHUNK_SIZE as usize + 3];
+        let digest = data_digest(&data).await;
+        assert_eq!(digest, sha384(&[&data]));
+
+        let item = DataItemParts {
+            signature_type: 1,
+            owner: vec![9u8; 512],
+            target: None,
+            anchor: Some([4u8; 32]),
+            tags: vec![("Content-Type".to_string(), "text/plain".to_string())],
+        };
+        assert_eq!(
+            item.deep_hash(data.len() as u64, &digest),
+            list_hash(&[
+                blob_hash(b"dataitem"),
+                blob_hash(b"1"),
+                blob_hash(b"1"),
+                blob_hash(&[9u8; 512]),
+                blob_hash(&[]),
+                blob_hash(&[4u8; 32]),
+                blob_hash(&encode_tags(&item.tags)),
+                blob_hash(&data),
+            ])
+        );
+
+        let tx = TransactionParts {
+            owner: vec![9u8; 512],
+            target: Vec::new(),
+            quantity: "0".to_string(),
+            reward: "100".to_string(),
+            last_tx: vec![1u8; 48],
+            tags: vec![(b"Type".to_vec(), b"Tip".to_vec())],
+            data_size: "0".to_string(),
+            data_root: Vec::new(),
+        };
+        let tags = list_hash(&[list_hash(&[blob_hash(b"Type"), blob_hash(b"Tip")])]);
+        assert_eq!(
+            tx.deep_hash(),
+            list_hash(&[
+                blob_hash(b"2"),
+                blob_hash(&[9u8; 512]),
+                blob_hash(&[]),
+                blob_hash(b"0"),
+                blob_hash(b"100"),
+                blob_hash(&[1u8; 48]),
+                tags,
+                blob_hash(b"0"),
+                blob_hash(&[]),
+            ])
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! Showing addresses, transaction IDs and file sizes
+//!
+//! Shortening counts characters rather than bytes, so user-supplied or
+//! non-ASCII input can be cut without splitting a character.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use dioxus::prelude::*;
+
+/// How long "Copied" feedback stays up (ms)
+const COPIED_FEEDBACK_MS: u32 = 2000;
+
+/// Keep the first `head` and last `tail` characters, joined by "..."
+///
+/// Text no longer than `head + tail` is returned unchanged.
+pub fn truncate_middle(text: &str, head: usize, tail: usize) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() <= head + tail {
+        return text.to_string();
+    }
+    let start: String = chars[..head].iter().collect();
+    let end: String = chars[chars.len() - tail..].iter().collect();
+    format!("{}...{}", start, end)
+}
+
+/// Short form of an address or transaction ID, e.g. "vh-NTH...G2JI"
+pub fn short_address(address: &str) -> String {
+    truncate_middle(address.trim(), 6, 4)
+}
+
+/// "28.4 MB", in binary units as file managers show them
+pub fn format_size(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1 << 30 => format!("{:.1} GB", b as f64 / (1u64 << 30) as f64),
+        b if b >= 1 << 20 => format!("{:.1} MB", b as f64 / (1u64 << 20) as f64),
+        b if b >= 1 << 10 => format!("{:.1} KB", b as f64 / (1u64 << 10) as f64),
+        b => format!("{} B", b),
+    }
+}
+
+/// Split an address into groups of `size` characters so it can be read
+/// aloud or compared group by group
+pub fn address_chunks(address: &str, size: usize) -> Vec<String> {
+    let chars: Vec<char> = address.trim().chars().collect();
+    chars.chunks(size.max(1)).map(|chunk| chunk.iter().collect()).collect()
+}
+
+/// Copy `text` to the system clipboard
+pub async fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    let window = web_sys::window().ok_or("No window")?;
+    wasm_bindgen_futures::JsFuture::from(window.navigator().clipboard().write_text(text))
+        .await
+        .map(|_| ())
+        .map_err(|_| "Could not copy to the clipboard".to_string())
+}
+
+/// Clipboard copy whose "copied" flag clears itself after a moment
+#[derive(Clone, Copy, PartialEq)]
+pub struct CopyFeedback {
+    copied: Signal<bool>,
+    error: Signal<Option<String>>,
+}
+
+impl CopyFeedback {
+    /// True for a short while after a successful copy
+    pub fn copied(&self) -> bool {
+        *self.copied.read()
+    }
+
+    /// Why the last copy failed, if it did
+    pub fn error(&self) -> Option<String> {
+        self.error.read().clone()
+    }
+
+    pub fn copy(&self, text: String) {
+        let mut copied = self.copied;
+        let mut error = self.error;
+        spawn(async move {
+            match copy_to_clipboard(&text).await {
+                Ok(()) => {
+                    error.set(None);
+                    copied.set(true);
+                    gloo_timers::future::TimeoutFuture::new(COPIED_FEEDBACK_MS).await;
+                    copied.set(false);
+                }
+                Err(e) => error.set(Some(e)),
+            }
+        });
+    }
+}
+
+/// Hook for a copy button
+pub fn use_copy_feedback() -> CopyFeedback {
+    CopyFeedback {
+        copied: use_signal(|| false),
+        error: use_signal(|| None),
+    }
+}
+
+// Blockies' xorshift generator, seeded from the address's UTF-16 units as
+// in the JS library
+struct BlockiesRandom {
+    seed: [i32; 4],
+}
+
+impl BlockiesRandom {
+    fn new(address: &str) -> Self {
+        let mut seed = [0i32; 4];
+        for (i, unit) in address.encode_utf16().enumerate() {
+            let slot = seed[i % 4];
+            seed[i % 4] = (slot << 5).wrapping_sub(slot).wrapping_add(unit as i32);
+        }
+        Self { seed }
+    }
+
+    fn next(&mut self) -> f64 {
+        let t = self.seed[0] ^ (self.seed[0] << 11);
+        self.seed[0] = self.seed[1];
+        self.seed[1] = self.seed[2];
+        self.seed[2] = self.seed[3];
+        self.seed[3] = self.seed[3] ^ (self.seed[3] >> 19) ^ t ^ (t >> 8);
+        self.seed[3] as u32 as f64 / (1u64 << 31) as f64
+    }
+
+    fn color(&mut self) -> String {
+        let hue = (self.next() * 360.0).floor();
+        let saturation = self.next() * 60.0 + 40.0;
+        let lightness = (self.next() + self.next() + self.next() + self.next()) * 25.0;
+        format!("hsl({},{:.0}%,{:.0}%)", hue, saturation, lightness)
+    }
+}
+
+/// Cells per side of an identicon
+const IDENTICON_SIZE: usize = 8;
+
+/// Blockies-style identicon for an address as an SVG document
+///
+/// The same address always gives the same picture: an 8×8 grid mirrored
+/// left to right, in a foreground, background and spot color.
+pub fn identicon_svg(address: &str) -> String {
+    let mut random = BlockiesRandom::new(address.trim());
+    let color = random.color();
+    let background = random.color();
+    let spot = random.color();
+
+    let half = IDENTICON_SIZE.div_ceil(2);
+    let mut rects = String::new();
+    for y in 0..IDENTICON_SIZE {
+        let row: Vec<u8> = (0..half).map(|_| (random.next() * 2.3).floor() as u8).collect();
+        for x in 0..IDENTICON_SIZE {
+            let cell = if x < half { row[x] } else { row[IDENTICON_SIZE - 1 - x] };
+            let fill = match cell {
+                1 => &color,
+                2 => &spot,
+                _ => continue,
+            };
+            rects.push_str(&format!(r#"<rect x="{}" y="{}" width="1" height="1" fill="{}"/>"#, x, y, fill));
+        }
+    }
+
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {size} {size}" shape-rendering="crispEdges"><rect width="{size}" height="{size}" fill="{background}"/>{rects}</svg>"#,
+        size = IDENTICON_SIZE,
+        background = background,
+        rects = rects,
+    )
+}
+
+/// `identicon_svg` as a data URL for an `img` `src`
+pub fn identicon_data_url(address: &str) -> String {
+    format!("data:image/svg+xml;base64,{}", STANDARD.encode(identicon_svg(address)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn truncate_middle_keeps_short_text() {
+        assert_eq!(truncate_middle("abc", 6, 4), "abc");
+        assert_eq!(truncate_middle("0123456789", 6, 4), "0123456789");
+    }
+
+    #[wasm_bindgen_test]
+    fn truncate_middle_counts_characters() {
+        assert_eq!(truncate_middle("ééééééééééééé", 2, 2), "éé...éé");
+    }
+
+    #[wasm_bindgen_test]
+    fn address_chunks_groups_characters() {
+        assert_eq!(address_chunks("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(address_chunks("ab", 0), vec!["a", "b"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn identicons_are_deterministic() {
+        let address = "vh-NTHVvlKZqRxc8LyyTNok65yQ55a_PJ1zWLb9G2JI";
+        assert_eq!(identicon_svg(address), identicon_svg(address));
+        assert_ne!(identicon_svg(address), identicon_svg("MockWa11etAddressForDeve1opment000000000000"));
+    }
+}
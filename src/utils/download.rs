@@ -0,0 +1,28 @@
+// Browser file download helper
+use wasm_bindgen::JsCast;
+
+/// Offer `bytes` to the user as a file download
+pub fn download_bytes(filename: &str, bytes: &[u8], mime_type: &str) -> Result<(), String> {
+    let window = web_sys::window().ok_or("No window available")?;
+    let document = window.document().ok_or("No document available")?;
+
+    let parts = js_sys::Array::new();
+    parts.push(&js_sys::Uint8Array::from(bytes));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+        .map_err(|e| format!("{:?}", e))?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(|e| format!("{:?}", e))?;
+
+    let anchor = document
+        .create_element("a")
+        .map_err(|e| format!("{:?}", e))?
+        .dyn_into::<web_sys::HtmlAnchorElement>()
+        .map_err(|_| "Failed to create download link".to_string())?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    let _ = web_sys::Url::revoke_object_url(&url);
+    Ok(())
+}
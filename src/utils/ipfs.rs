@@ -0,0 +1,336 @@
+//! IPFS content identifiers
+//!
+//! Congregations that already publish to IPFS can name the CID of the same
+//! file when uploading, and the item then links to its IPFS mirror. The
+//! CID is recomputed from the uploaded bytes first, so the mirror link is
+//! only published when it really points at the same file.
+//!
+//! Recomputing follows `ipfs add` with its default settings: 256 KiB
+//! chunks in a balanced UnixFS DAG of up to 174 links per node, with
+//! dag-pb leaves for CIDv0 and raw leaves for CIDv1. CIDv1 DAGs with the
+//! 1 MiB chunks some pinning services use are also recognised.
+
+use std::fmt;
+use std::str::FromStr;
+
+use sha2::{Digest, Sha256};
+
+use crate::config::app_config;
+
+/// Multicodec of a raw block
+const RAW_CODEC: u64 = 0x55;
+/// Multicodec of a dag-pb (UnixFS) node
+const DAG_PB_CODEC: u64 = 0x70;
+/// Multihash code and length of SHA-256
+const SHA2_256: u64 = 0x12;
+const SHA2_256_LENGTH: u64 = 32;
+
+/// Chunk size of `ipfs add`
+const DEFAULT_CHUNK_SIZE: usize = 256 * 1024;
+/// Chunk sizes tried for CIDv1 DAGs
+const CHUNK_SIZES: [usize; 2] = [DEFAULT_CHUNK_SIZE, 1024 * 1024];
+/// Most links a balanced DAG node holds
+const MAX_LINKS: usize = 174;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+/// A SHA-256 CID, version 0 (`Qm…`) or 1 (`b…`, base32)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cid {
+    version: u8,
+    codec: u64,
+    digest: [u8; 32],
+}
+
+impl Cid {
+    fn v0(digest: [u8; 32]) -> Self {
+        Self { version: 0, codec: DAG_PB_CODEC, digest }
+    }
+
+    fn v1(codec: u64, digest: [u8; 32]) -> Self {
+        Self { version: 1, codec, digest }
+    }
+
+    /// Binary form, as stored in dag-pb links
+    fn to_bytes(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(36);
+        if self.version == 1 {
+            put_varint(&mut bytes, 1);
+            put_varint(&mut bytes, self.codec);
+        }
+        put_varint(&mut bytes, SHA2_256);
+        put_varint(&mut bytes, SHA2_256_LENGTH);
+        bytes.extend_from_slice(&self.digest);
+        bytes
+    }
+
+    /// Whether `data` is the file this CID identifies
+    pub fn matches(&self, data: &[u8]) -> bool {
+        match (self.version, self.codec) {
+            (0, _) => unixfs_root(data, DEFAULT_CHUNK_SIZE, false) == *self,
+            (1, RAW_CODEC) => sha256(data) == self.digest,
+            (1, DAG_PB_CODEC) => CHUNK_SIZES.iter().any(|&chunk_size| unixfs_root(data, chunk_size, true) == *self),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Cid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.version {
+            0 => write!(f, "{}", base58_encode(&self.to_bytes())),
+            _ => write!(f, "b{}", base32_encode(&self.to_bytes())),
+        }
+    }
+}
+
+impl FromStr for Cid {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let invalid = || format!("{} isn't a valid IPFS CID", text);
+        if text.len() == 46 && text.starts_with("Qm") {
+            let bytes = base58_decode(text).ok_or_else(invalid)?;
+            let digest = read_sha256(&mut bytes.as_slice()).ok_or_else(invalid)?;
+            return Ok(Cid::v0(digest));
+        }
+        let Some(base32) = text.strip_prefix('b') else {
+            return Err("Only CIDv0 (Qm…) and base32 CIDv1 (b…) are supported".to_string());
+        };
+        let bytes = base32_decode(base32).ok_or_else(invalid)?;
+        let mut rest = bytes.as_slice();
+        if read_varint(&mut rest) != Some(1) {
+            return Err(invalid());
+        }
+        let codec = read_varint(&mut rest).ok_or_else(invalid)?;
+        if codec != RAW_CODEC && codec != DAG_PB_CODEC {
+            return Err("Only CIDs of files (raw or dag-pb) are supported".to_string());
+        }
+        let digest = read_sha256(&mut rest).ok_or_else(|| "Only SHA-256 CIDs are supported".to_string())?;
+        Ok(Cid::v1(codec, digest))
+    }
+}
+
+/// Link to `cid` on the configured IPFS gateway
+pub fn mirror_url(cid: &str) -> String {
+    format!("{}/ipfs/{}", app_config().ipfs_gateway, cid)
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+fn put_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = bytes.split_first()?;
+        *bytes = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+// A SHA-256 multihash filling the rest of `bytes`
+fn read_sha256(bytes: &mut &[u8]) -> Option<[u8; 32]> {
+    if read_varint(bytes)? != SHA2_256 || read_varint(bytes)? != SHA2_256_LENGTH {
+        return None;
+    }
+    (*bytes).try_into().ok()
+}
+
+fn base58_encode(bytes: &[u8]) -> String {
+    let mut digits: Vec<u8> = Vec::new();
+    for &byte in bytes {
+        let mut carry = u32::from(byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    std::iter::repeat_n('1', zeros)
+        .chain(digits.iter().rev().map(|&digit| BASE58_ALPHABET[digit as usize] as char))
+        .collect()
+}
+
+fn base58_decode(text: &str) -> Option<Vec<u8>> {
+    let mut bytes: Vec<u8> = Vec::new();
+    for c in text.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&letter| letter == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    let zeros = text.bytes().take_while(|&c| c == b'1').count();
+    bytes.extend(std::iter::repeat_n(0, zeros));
+    bytes.reverse();
+    Some(bytes)
+}
+
+fn base32_encode(bytes: &[u8]) -> String {
+    let mut text = String::new();
+    let (mut buffer, mut bits) = (0u32, 0);
+    for &byte in bytes {
+        buffer = (buffer << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            text.push(BASE32_ALPHABET[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        text.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+    text
+}
+
+fn base32_decode(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let (mut buffer, mut bits) = (0u32, 0);
+    for c in text.bytes() {
+        let value = BASE32_ALPHABET.iter().position(|&letter| letter == c.to_ascii_lowercase())? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+fn put_field_varint(out: &mut Vec<u8>, field: u64, value: u64) {
+    put_varint(out, field << 3);
+    put_varint(out, value);
+}
+
+fn put_field_bytes(out: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    put_varint(out, (field << 3) | 2);
+    put_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+// UnixFS `Data` message of a file node
+fn unixfs_file(data: Option<&[u8]>, file_size: u64, block_sizes: &[u64]) -> Vec<u8> {
+    let mut out = Vec::new();
+    put_field_varint(&mut out, 1, 2);
+    if let Some(data) = data {
+        put_field_bytes(&mut out, 2, data);
+    }
+    put_field_varint(&mut out, 3, file_size);
+    for &size in block_sizes {
+        put_field_varint(&mut out, 4, size);
+    }
+    out
+}
+
+/// A block in a UnixFS DAG, as its parent links to it
+struct DagBlock {
+    cid: Cid,
+    /// Size of the block plus everything below it
+    total_size: u64,
+    /// Bytes of file data below it
+    file_size: u64,
+}
+
+// Links come before data in a dag-pb node
+fn dag_pb_node(children: &[DagBlock], data: &[u8]) -> Vec<u8> {
+    let mut node = Vec::new();
+    for child in children {
+        let mut link = Vec::new();
+        put_field_bytes(&mut link, 1, &child.cid.to_bytes());
+        put_field_bytes(&mut link, 2, b"");
+        put_field_varint(&mut link, 3, child.total_size);
+        put_field_bytes(&mut node, 2, &link);
+    }
+    put_field_bytes(&mut node, 1, data);
+    node
+}
+
+fn dag_pb_block(children: &[DagBlock], data: &[u8], cid_version: u8) -> DagBlock {
+    let node = dag_pb_node(children, data);
+    let digest = sha256(&node);
+    DagBlock {
+        cid: if cid_version == 0 { Cid::v0(digest) } else { Cid::v1(DAG_PB_CODEC, digest) },
+        total_size: node.len() as u64 + children.iter().map(|child| child.total_size).sum::<u64>(),
+        file_size: children.iter().map(|child| child.file_size).sum(),
+    }
+}
+
+/// Root CID `ipfs add` gives `data` with this chunk size and leaf type
+fn unixfs_root(data: &[u8], chunk_size: usize, raw_leaves: bool) -> Cid {
+    let cid_version = if raw_leaves { 1 } else { 0 };
+    let mut level: Vec<DagBlock> = data
+        .chunks(chunk_size)
+        .map(|chunk| {
+            if raw_leaves {
+                DagBlock { cid: Cid::v1(RAW_CODEC, sha256(chunk)), total_size: chunk.len() as u64, file_size: chunk.len() as u64 }
+            } else {
+                let mut block = dag_pb_block(&[], &unixfs_file(Some(chunk), chunk.len() as u64, &[]), cid_version);
+                block.file_size = chunk.len() as u64;
+                block
+            }
+        })
+        .collect();
+    if level.is_empty() {
+        return dag_pb_block(&[], &unixfs_file(None, 0, &[]), cid_version).cid;
+    }
+
+    // Filling each parent before starting the next gives the same tree as
+    // the balanced layout
+    while level.len() > 1 {
+        level = level
+            .chunks(MAX_LINKS)
+            .map(|children| {
+                let file_size = children.iter().map(|child| child.file_size).sum();
+                let block_sizes: Vec<u64> = children.iter().map(|child| child.file_size).collect();
+                dag_pb_block(children, &unixfs_file(None, file_size, &block_sizes), cid_version)
+            })
+            .collect();
+    }
+    level[0].cid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn cids_of_small_files_match_ipfs_add() {
+        let data = b"hello world\n";
+        let v0: Cid = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o".parse().unwrap();
+        let v1: Cid = "bafkreifjjcie6lypi6ny7amxnfftagclbuxndqonfipmb64f2km2devei4".parse().unwrap();
+        assert!(v0.matches(data));
+        assert!(v1.matches(data));
+        assert!(!v0.matches(b"hello world"));
+        assert_eq!(v0.to_string(), "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o");
+        assert_eq!(v1.to_string(), "bafkreifjjcie6lypi6ny7amxnfftagclbuxndqonfipmb64f2km2devei4");
+        assert!("zdj7W".parse::<Cid>().is_err());
+        assert!("Qm000000000000000000000000000000000000000000".parse::<Cid>().is_err());
+    }
+}
@@ -0,0 +1,167 @@
+//! BCP 47 language tags
+//!
+//! Items carry a `Language` tag such as "en", "pt-BR" or "zh-Hant". Tags
+//! are checked for well-formedness and stored in canonical case ("pt-br"
+//! becomes "pt-BR") so the gateway's exact-match tag filters find them.
+//! Filtering follows RFC 4647 basic matching: "pt" also matches "pt-BR",
+//! but "pt-BR" doesn't match "pt-PT".
+
+/// Languages offered by the Browse filter, as (tag, English name)
+pub const COMMON_LANGUAGES: &[(&str, &str)] = &[
+    ("en", "English"),
+    ("es", "Spanish"),
+    ("pt", "Portuguese"),
+    ("fr", "French"),
+    ("de", "German"),
+    ("it", "Italian"),
+    ("nl", "Dutch"),
+    ("pl", "Polish"),
+    ("ro", "Romanian"),
+    ("ru", "Russian"),
+    ("uk", "Ukrainian"),
+    ("ar", "Arabic"),
+    ("he", "Hebrew"),
+    ("am", "Amharic"),
+    ("sw", "Swahili"),
+    ("yo", "Yoruba"),
+    ("hi", "Hindi"),
+    ("zh", "Chinese"),
+    ("ko", "Korean"),
+    ("ja", "Japanese"),
+    ("id", "Indonesian"),
+    ("tl", "Tagalog"),
+    ("vi", "Vietnamese"),
+    ("ht", "Haitian Creole"),
+];
+
+/// Regional and script variants also asked for when browsing a bare
+/// language, since the gateway only matches tag values exactly
+const COMMON_VARIANTS: &[&str] = &[
+    "en-US", "en-GB", "en-AU", "en-CA", "en-NG", "en-ZA",
+    "es-ES", "es-MX", "es-US", "es-419",
+    "pt-BR", "pt-PT", "pt-AO", "pt-MZ",
+    "fr-FR", "fr-CA",
+    "zh-CN", "zh-TW", "zh-Hans", "zh-Hant",
+];
+
+/// `tag` in canonical case, or `None` when it isn't a well-formed BCP 47
+/// tag
+///
+/// Checks the shape only (a 2–3 letter ISO 639 language, then 1–8
+/// character subtags, with each extension singleton followed by a
+/// subtag), not whether every subtag is registered. Longer language
+/// subtags are well-formed BCP 47 but none are in use, and rejecting them
+/// catches "English" typed in place of "en".
+pub fn normalize_language_tag(tag: &str) -> Option<String> {
+    let subtags: Vec<&str> = tag.trim().split(['-', '_']).collect();
+    let (language, rest) = subtags.split_first()?;
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut canonical = vec![language.to_ascii_lowercase()];
+    // Extension and private-use subtags are all lowercase
+    let mut in_extension = false;
+    let mut awaiting_extension = false;
+    for subtag in rest {
+        if subtag.is_empty() || subtag.len() > 8 || !subtag.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        if subtag.len() == 1 {
+            if awaiting_extension {
+                return None;
+            }
+            in_extension = true;
+            awaiting_extension = true;
+            canonical.push(subtag.to_ascii_lowercase());
+            continue;
+        }
+        awaiting_extension = false;
+        let canonical_subtag = match subtag.len() {
+            _ if in_extension => subtag.to_ascii_lowercase(),
+            2 => subtag.to_ascii_uppercase(),
+            4 if subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                let lower = subtag.to_ascii_lowercase();
+                lower[..1].to_ascii_uppercase() + &lower[1..]
+            }
+            _ => subtag.to_ascii_lowercase(),
+        };
+        canonical.push(canonical_subtag);
+    }
+    if awaiting_extension {
+        return None;
+    }
+    Some(canonical.join("-"))
+}
+
+/// The language subtag of `tag`, lowercased
+pub fn primary_language(tag: &str) -> String {
+    tag.trim().split(['-', '_']).next().unwrap_or_default().to_ascii_lowercase()
+}
+
+/// Whether an item tagged `tag` belongs under the filter `filter`
+pub fn language_matches(filter: &str, tag: &str) -> bool {
+    let filter = filter.trim().replace('_', "-").to_ascii_lowercase();
+    let tag = tag.trim().replace('_', "-").to_ascii_lowercase();
+    tag == filter || tag.starts_with(&format!("{}-", filter))
+}
+
+/// Tag values to ask the gateway for when filtering on `filter`: the
+/// filter itself plus the common variants it covers
+pub fn language_query_values(filter: &str) -> Vec<&str> {
+    let mut values = vec![filter];
+    values.extend(COMMON_VARIANTS.iter().copied().filter(|variant| *variant != filter && language_matches(filter, variant)));
+    values
+}
+
+/// English name for a tag, with any region or script after it, e.g.
+/// "Portuguese (BR)"; unknown languages show the tag itself
+pub fn language_name(tag: &str) -> String {
+    let primary = primary_language(tag);
+    let Some((_, name)) = COMMON_LANGUAGES.iter().find(|(code, _)| *code == primary) else {
+        return tag.trim().to_string();
+    };
+    let rest = tag.trim().split_once(['-', '_']).map(|(_, rest)| rest);
+    match rest {
+        Some(rest) => format!("{} ({})", name, rest),
+        None => name.to_string(),
+    }
+}
+
+/// The browser's preferred language, for suggesting an upload's language
+pub fn browser_language() -> Option<String> {
+    let language = web_sys::window()?.navigator().language()?;
+    normalize_language_tag(&language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn tags_are_checked_and_recased() {
+        assert_eq!(normalize_language_tag(" pt_br ").as_deref(), Some("pt-BR"));
+        assert_eq!(normalize_language_tag("ZH-hant-tw").as_deref(), Some("zh-Hant-TW"));
+        assert_eq!(normalize_language_tag("es-419").as_deref(), Some("es-419"));
+        assert_eq!(normalize_language_tag("en-US-x-Twain").as_deref(), Some("en-US-x-twain"));
+        assert!(normalize_language_tag("English").is_none());
+        assert!(normalize_language_tag("").is_none());
+        assert!(normalize_language_tag("e").is_none());
+        assert!(normalize_language_tag("en--US").is_none());
+        assert!(normalize_language_tag("en-x").is_none());
+        assert!(normalize_language_tag("en-verylongsubtag").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    fn bare_languages_match_their_regions() {
+        assert!(language_matches("pt", "pt-BR"));
+        assert!(language_matches("PT-br", "pt-BR"));
+        assert!(!language_matches("pt-BR", "pt-PT"));
+        assert!(!language_matches("p", "pt"));
+        assert!(language_query_values("pt").contains(&"pt-BR"));
+        assert_eq!(language_query_values("pt-BR"), vec!["pt-BR"]);
+        assert_eq!(language_name("pt-BR"), "Portuguese (BR)");
+        assert_eq!(language_name("xx"), "xx");
+    }
+}
@@ -0,0 +1,238 @@
+//! Arweave data chunking and the Merkle tree over it, laid out exactly as
+//! arweave-js does so `data_root`s agree
+
+use sha2::{Digest, Sha256};
+
+/// Largest chunk the network accepts
+pub const MAX_CHUNK_SIZE: usize = 256 * 1024;
+
+/// Smallest chunk allowed anywhere but at the end of the data
+pub const MIN_CHUNK_SIZE: usize = 32 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MerkleError {
+    /// A tree needs at least one chunk, even an empty one
+    NoChunks,
+}
+
+impl std::fmt::Display for MerkleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MerkleError::NoChunks => write!(f, "There are no chunks to build a data root from"),
+        }
+    }
+}
+
+/// One chunk of the data and where it sits
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chunk {
+    pub data_hash: [u8; 32],
+    pub min_byte_range: usize,
+    pub max_byte_range: usize,
+}
+
+impl Chunk {
+    /// `bytes` starting `min_byte_range` bytes into the data
+    pub fn new(bytes: &[u8], min_byte_range: usize) -> Self {
+        Self { data_hash: hash(&[bytes]), min_byte_range, max_byte_range: min_byte_range + bytes.len() }
+    }
+}
+
+/// Merkle path from `data_root` down to one chunk
+#[derive(Debug, Clone, PartialEq)]
+pub struct Proof {
+    /// Last byte of the chunk; what `/chunk` calls `offset`
+    pub offset: usize,
+    pub proof: Vec<u8>,
+}
+
+/// Data split into chunks, with its Merkle root and a proof per chunk
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkedData {
+    pub data_size: usize,
+    pub data_root: [u8; 32],
+    pub chunks: Vec<Chunk>,
+    pub proofs: Vec<Proof>,
+}
+
+fn hash(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// `value` as a 32-byte big-endian number
+fn note(value: usize) -> [u8; 32] {
+    let mut buffer = [0u8; 32];
+    buffer[24..].copy_from_slice(&(value as u64).to_be_bytes());
+    buffer
+}
+
+enum NodeKind {
+    Leaf { data_hash: [u8; 32] },
+    Branch { byte_range: usize, left: Box<Node>, right: Box<Node> },
+}
+
+struct Node {
+    id: [u8; 32],
+    max_byte_range: usize,
+    kind: NodeKind,
+}
+
+/// Byte ranges `size` bytes of data are split into; always at least one,
+/// and an empty one after a whole number of full chunks
+pub fn chunk_bounds(size: usize) -> Vec<(usize, usize)> {
+    let mut bounds = Vec::new();
+    let mut cursor = 0;
+    while size - cursor >= MAX_CHUNK_SIZE {
+        // Split the last two chunks evenly rather than leave a runt
+        let rest = size - cursor;
+        let remainder = rest - MAX_CHUNK_SIZE;
+        let chunk_size = if remainder > 0 && remainder < MIN_CHUNK_SIZE { rest.div_ceil(2) } else { MAX_CHUNK_SIZE };
+        bounds.push((cursor, cursor + chunk_size));
+        cursor += chunk_size;
+    }
+    bounds.push((cursor, size));
+    bounds
+}
+
+fn leaf(chunk: &Chunk) -> Node {
+    Node {
+        id: hash(&[&hash(&[&chunk.data_hash]), &hash(&[&note(chunk.max_byte_range)])]),
+        max_byte_range: chunk.max_byte_range,
+        kind: NodeKind::Leaf { data_hash: chunk.data_hash },
+    }
+}
+
+fn branch(left: Node, right: Node) -> Node {
+    Node {
+        id: hash(&[&hash(&[&left.id]), &hash(&[&right.id]), &hash(&[&note(left.max_byte_range)])]),
+        max_byte_range: right.max_byte_range,
+        kind: NodeKind::Branch { byte_range: left.max_byte_range, left: Box::new(left), right: Box::new(right) },
+    }
+}
+
+fn build_root(mut layer: Vec<Node>) -> Result<Node, MerkleError> {
+    while layer.len() > 1 {
+        let mut next = Vec::with_capacity(layer.len().div_ceil(2));
+        let mut nodes = layer.into_iter();
+        while let Some(left) = nodes.next() {
+            // An odd node out moves up a layer as it is
+            next.push(match nodes.next() {
+                Some(right) => branch(left, right),
+                None => left,
+            });
+        }
+        layer = next;
+    }
+    layer.pop().ok_or(MerkleError::NoChunks)
+}
+
+fn collect_proofs(node: &Node, path: &[u8], proofs: &mut Vec<Proof>) {
+    match &node.kind {
+        NodeKind::Leaf { data_hash } => proofs.push(Proof {
+            offset: node.max_byte_range.saturating_sub(1),
+            proof: [path, data_hash, &note(node.max_byte_range)].concat(),
+        }),
+        NodeKind::Branch { byte_range, left, right } => {
+            let path = [path, &left.id, &right.id, &note(*byte_range)].concat();
+            collect_proofs(left, &path, proofs);
+            collect_proofs(right, &path, proofs);
+        }
+    }
+}
+
+/// Merkle tree over `chunks` of `data_size` bytes, as laid out by
+/// `chunk_bounds`
+pub fn merkle_tree(mut chunks: Vec<Chunk>, data_size: usize) -> Result<ChunkedData, MerkleError> {
+    let root = build_root(chunks.iter().map(leaf).collect())?;
+    let mut proofs = Vec::with_capacity(chunks.len());
+    collect_proofs(&root, &[], &mut proofs);
+
+    // arweave-js roots the tree on a trailing empty chunk but never
+    // uploads it; follow suit so the roots agree
+    if chunks.last().is_some_and(|chunk| chunk.max_byte_range == chunk.min_byte_range) {
+        chunks.pop();
+        proofs.pop();
+    }
+
+    Ok(ChunkedData { data_size, data_root: root.id, chunks, proofs })
+}
+
+/// Split `data` into chunks and build its Merkle tree
+pub fn chunk_data(data: &[u8]) -> Result<ChunkedData, MerkleError> {
+    let chunks = chunk_bounds(data.len())
+        .into_iter()
+        .map(|(min, max)| Chunk::new(&data[min..max], min))
+        .collect();
+    merkle_tree(chunks, data.len())
+}
+
+/// `data_root` of a base-layer transaction carrying `data`
+pub fn data_root(data: &[u8]) -> Result<[u8; 32], MerkleError> {
+    chunk_data(data).map(|chunked| chunked.data_root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+    use wasm_bindgen_test::*;
+
+    fn pattern(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn small_data_is_one_chunk_under_its_leaf() {
+        let data = b"In the beginning was the Word";
+        let chunked = chunk_data(data).unwrap();
+        assert_eq!(chunked.chunks.len(), 1);
+        assert_eq!(chunked.proofs[0].offset, data.len() - 1);
+        // A lone leaf is the root, and its proof is just hash and offset
+        let data_hash = hash(&[data]);
+        assert_eq!(chunked.data_root, hash(&[&hash(&[&data_hash]), &hash(&[&note(data.len())])]));
+        assert_eq!(chunked.proofs[0].proof, [&data_hash[..], &note(data.len())].concat());
+    }
+
+    #[wasm_bindgen_test]
+    fn chunks_cover_the_data_without_runts() {
+        // One byte short of a minimum chunk past the maximum: split evenly
+        let data = vec![7u8; MAX_CHUNK_SIZE + MIN_CHUNK_SIZE - 1];
+        let chunked = chunk_data(&data).unwrap();
+        let sizes: Vec<usize> = chunked.chunks.iter().map(|chunk| chunk.max_byte_range - chunk.min_byte_range).collect();
+        assert_eq!(sizes.iter().sum::<usize>(), data.len());
+        assert!(sizes.iter().all(|size| *size >= MIN_CHUNK_SIZE));
+        assert_eq!(chunked.proofs.len(), chunked.chunks.len());
+
+        // Exactly two full chunks: the empty tail is rooted but not uploaded
+        let chunked = chunk_data(&vec![0u8; 2 * MAX_CHUNK_SIZE]).unwrap();
+        assert_eq!(chunked.chunks.len(), 2);
+        assert_eq!(chunked.proofs.iter().map(|proof| proof.offset).collect::<Vec<_>>(), vec![MAX_CHUNK_SIZE - 1, 2 * MAX_CHUNK_SIZE - 1]);
+
+        assert_eq!(merkle_tree(Vec::new(), 0), Err(MerkleError::NoChunks));
+    }
+
+    // Roots from arweave-js `computeRootHash` and `generateTransactionChunks`
+    // for the same data
+    #[wasm_bindgen_test]
+    fn data_roots_match_arweave_js() {
+        let cases = [
+            (0, "x9bUbvLyiRlsOOqClNkKV0LAohFd-PfXfb_XoYosfQI"),
+            (5, "rLZwYh3sMsjtP-AhqRDgnE_ssS8oCTgmIKzqVI0FKY0"),
+            (256 * 1024, "gty7KB2baLFp7OGxuV2wBeX3NippS1tNVlMOZryIq5o"),
+            (256 * 1024 + 100, "cn5Lfc_v4sHhr3Fx_qyV-eI1_L8QSHbtccmZ1YzokOs"),
+            (600 * 1024, "N_gS_f6fPYsmpog4LZJLEwgKVekMLOLMrdCXsi2Z_oc"),
+        ];
+        for (len, root) in cases {
+            assert_eq!(URL_SAFE_NO_PAD.encode(data_root(&pattern(len)).unwrap()), root, "{} bytes", len);
+        }
+
+        let data: Vec<u8> = (0..1_000_000).map(|i| (i * 7 % 251) as u8).collect();
+        let chunked = chunk_data(&data).unwrap();
+        assert_eq!(URL_SAFE_NO_PAD.encode(chunked.data_root), "XCSLT8SX8sEFoDKTdpSuZj2b057NCY32iORTI51fgWA");
+        assert_eq!(chunked.chunks.len(), 4);
+    }
+}
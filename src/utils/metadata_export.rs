@@ -0,0 +1,226 @@
+use chrono::DateTime;
+use serde::Serialize;
+
+use crate::models::ContentMetadata;
+use crate::services::downloads::download_filename;
+use crate::utils::citation::permanent_url;
+use crate::utils::download::download_bytes;
+
+/// Metadata file formats for record-keeping and moving to other tools
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataFormat {
+    Csv,
+    Json,
+}
+
+impl MetadataFormat {
+    pub const ALL: [MetadataFormat; 2] = [MetadataFormat::Csv, MetadataFormat::Json];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MetadataFormat::Csv => "CSV",
+            MetadataFormat::Json => "JSON",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            MetadataFormat::Csv => "csv",
+            MetadataFormat::Json => "json",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            MetadataFormat::Csv => "text/csv",
+            MetadataFormat::Json => "application/json",
+        }
+    }
+
+    /// One file with a row or object per item
+    pub fn export(&self, items: &[ContentMetadata]) -> String {
+        let records: Vec<MetadataRecord> = items.iter().map(MetadataRecord::from).collect();
+        match self {
+            MetadataFormat::Csv => csv(&records),
+            MetadataFormat::Json => serde_json::to_string_pretty(&records).unwrap_or_default(),
+        }
+    }
+
+    /// Download `items` as a metadata file named after `name`
+    pub fn download(&self, name: &str, items: &[ContentMetadata]) -> Result<(), String> {
+        let date = chrono::Local::now().format("%Y-%m-%d");
+        let filename = format!("{}-{}.{}", name, date, self.extension());
+        download_bytes(&filename, self.export(items).as_bytes(), self.mime_type())
+    }
+}
+
+/// The exported fields of an item
+///
+/// Column names match what the bulk importer recognises, so an export can
+/// be edited and imported elsewhere alongside the items' downloads.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct MetadataRecord {
+    tx_id: String,
+    /// Name the item is saved under when downloaded
+    file: String,
+    title: String,
+    speaker: String,
+    ministry: String,
+    series: String,
+    series_id: String,
+    scripture_refs: Vec<String>,
+    language: String,
+    description: String,
+    content_type: String,
+    duration_secs: Option<u32>,
+    /// RFC 3339, from the `Created-At` tag
+    created_at: String,
+    /// RFC 3339; empty while pending
+    archived_at: String,
+    block_height: Option<u64>,
+    owner: String,
+    file_hash: String,
+    encrypted: bool,
+    licensed: bool,
+    url: String,
+}
+
+impl From<&ContentMetadata> for MetadataRecord {
+    fn from(item: &ContentMetadata) -> Self {
+        let text = |value: &Option<String>| value.clone().unwrap_or_default();
+        let date = |timestamp: Option<i64>| {
+            timestamp
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+                .map(|date| date.to_rfc3339())
+                .unwrap_or_default()
+        };
+        Self {
+            tx_id: item.tx_id.clone(),
+            file: download_filename(&item.title, &item.content_type),
+            title: item.title.clone(),
+            speaker: text(&item.speaker),
+            ministry: text(&item.ministry),
+            series: text(&item.series),
+            series_id: text(&item.series_id),
+            scripture_refs: item.scripture_refs.clone(),
+            language: text(&item.language),
+            description: text(&item.description),
+            content_type: item.content_type.clone(),
+            duration_secs: item.duration_secs,
+            created_at: date(item.created_at),
+            archived_at: date(item.block_timestamp),
+            block_height: item.block_height,
+            owner: item.owner.clone(),
+            file_hash: text(&item.file_hash),
+            encrypted: item.encryption.is_some(),
+            licensed: item.license.is_some(),
+            url: permanent_url(&item.tx_id),
+        }
+    }
+}
+
+const CSV_HEADERS: [&str; 20] = [
+    "Tx ID",
+    "File",
+    "Title",
+    "Speaker",
+    "Ministry",
+    "Series",
+    "Series ID",
+    "Scripture",
+    "Language",
+    "Description",
+    "Content Type",
+    "Duration (s)",
+    "Created At",
+    "Archived At",
+    "Block Height",
+    "Owner",
+    "File Hash",
+    "Encrypted",
+    "Licensed",
+    "URL",
+];
+
+fn csv(records: &[MetadataRecord]) -> String {
+    let optional = |value: Option<u64>| value.map(|value| value.to_string()).unwrap_or_default();
+    let mut lines = vec![CSV_HEADERS.iter().map(|header| csv_field(header)).collect::<Vec<_>>().join(",")];
+    for record in records {
+        let fields = [
+            record.tx_id.clone(),
+            record.file.clone(),
+            record.title.clone(),
+            record.speaker.clone(),
+            record.ministry.clone(),
+            record.series.clone(),
+            record.series_id.clone(),
+            record.scripture_refs.join("; "),
+            record.language.clone(),
+            record.description.clone(),
+            record.content_type.clone(),
+            optional(record.duration_secs.map(u64::from)),
+            record.created_at.clone(),
+            record.archived_at.clone(),
+            optional(record.block_height),
+            record.owner.clone(),
+            record.file_hash.clone(),
+            record.encrypted.to_string(),
+            record.licensed.to_string(),
+            record.url.clone(),
+        ];
+        lines.push(fields.iter().map(|field| csv_field(field)).collect::<Vec<_>>().join(","));
+    }
+    lines.join("\r\n") + "\r\n"
+}
+
+/// Quote a field if it needs it
+///
+/// Metadata comes from any uploader, so text a spreadsheet would run as a
+/// formula is prefixed with `'`.
+pub(crate) fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) { format!("'{}", value) } else { value.to_string() };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::bulk_import::ImportManifest;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn csv_fields_are_quoted_and_defused() {
+        assert_eq!(csv_field("Grace"), "Grace");
+        assert_eq!(csv_field("Grace, Alone"), "\"Grace, Alone\"");
+        assert_eq!(csv_field("The \"Word\""), "\"The \"\"Word\"\"\"");
+        assert_eq!(csv_field("=HYPERLINK(\"x\")"), "\"'=HYPERLINK(\"\"x\"\")\"");
+    }
+
+    #[wasm_bindgen_test]
+    fn exports_import_through_bulk_import() {
+        let tags = vec![
+            ("Title".to_string(), "Grace, Alone".to_string()),
+            ("Content-Type".to_string(), "audio/mpeg".to_string()),
+            ("Speaker".to_string(), "Ann Lee".to_string()),
+            ("Scripture-Ref-1".to_string(), "Eph 2:8".to_string()),
+            ("Scripture-Ref-2".to_string(), "Rom 3:24".to_string()),
+            ("Language".to_string(), "en".to_string()),
+        ];
+        let item = ContentMetadata::from_tags("tx-1", "owner", &tags, None, None).unwrap();
+
+        for format in MetadataFormat::ALL {
+            let manifest = ImportManifest::parse(&format!("export.{}", format.extension()), &format.export(std::slice::from_ref(&item))).unwrap();
+            let rows = manifest.import_rows(&manifest.guess_mapping());
+            assert_eq!(rows.len(), 1, "{}", format.display_name());
+            assert_eq!(rows[0].file, "Grace-Alone.mp3");
+            assert_eq!(rows[0].title, "Grace, Alone");
+            assert_eq!(rows[0].speaker, "Ann Lee");
+            assert_eq!(rows[0].scripture_refs, "Eph 2:8; Rom 3:24");
+            assert_eq!(rows[0].language, "en");
+        }
+    }
+}
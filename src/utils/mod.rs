@@ -0,0 +1,16 @@
+// Utility modules for Faithful Archive
+pub mod constants;
+pub mod crypto;
+pub mod deep_hash;
+pub mod merkle;
+pub mod ipfs;
+pub mod tar;
+pub mod download;
+pub mod citation;
+pub mod metadata_export;
+pub mod query_string;
+pub mod audio;
+pub mod display;
+pub mod language;
+pub mod qr;
+pub mod charts;
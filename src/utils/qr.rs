@@ -0,0 +1,61 @@
+//! QR codes as SVG
+//!
+//! Encoded in Rust with `qrcodegen`, so showing a code needs no script or
+//! network request, and drawn as one SVG path so it stays sharp at any size.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use qrcodegen::{QrCode, QrCodeEcc};
+
+/// Light modules around the code; scanners need at least four
+const QUIET_ZONE: i32 = 4;
+
+/// `text` as a QR code SVG document, or `None` if it's too long to encode
+///
+/// Uses medium error correction, enough to survive a phone held at an angle
+/// to a glossy screen.
+pub fn qr_svg(text: &str) -> Option<String> {
+    let code = QrCode::encode_text(text, QrCodeEcc::Medium).ok()?;
+    let size = code.size() + QUIET_ZONE * 2;
+
+    let mut path = String::new();
+    for y in 0..code.size() {
+        for x in 0..code.size() {
+            if code.get_module(x, y) {
+                path.push_str(&format!("M{},{}h1v1h-1z", x + QUIET_ZONE, y + QUIET_ZONE));
+            }
+        }
+    }
+
+    Some(format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {size} {size}" shape-rendering="crispEdges"><rect width="{size}" height="{size}" fill="#ffffff"/><path d="{path}" fill="#000000"/></svg>"##,
+        size = size,
+        path = path,
+    ))
+}
+
+/// `qr_svg` as a data URL for an `img` `src`
+pub fn qr_data_url(text: &str) -> Option<String> {
+    qr_svg(text).map(|svg| format!("data:image/svg+xml;base64,{}", STANDARD.encode(svg)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn codes_include_a_quiet_zone() {
+        // "HELLO" fits a version 1 code: 21 modules per side
+        let svg = qr_svg("HELLO").unwrap();
+        assert!(svg.contains(r#"viewBox="0 0 29 29""#));
+        // The top-left finder pattern starts inside the quiet zone
+        assert!(svg.contains("M4,4h1v1h-1z"));
+        assert!(!svg.contains("M0,0"));
+    }
+
+    #[wasm_bindgen_test]
+    fn overlong_text_is_rejected() {
+        assert!(qr_svg(&"x".repeat(5000)).is_none());
+        assert!(qr_data_url("https://faithful-archive.org/item/abc").unwrap().starts_with("data:image/svg+xml;base64,"));
+    }
+}
@@ -0,0 +1,77 @@
+// URL query string encoding helpers
+
+/// Encode key/value pairs as `a=1&b=2`, skipping empty values
+pub fn encode_pairs(pairs: &[(&str, String)]) -> String {
+    pairs
+        .iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(key, value)| format!("{}={}", key, encode_component(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Parse `a=1&b=2` (with or without a leading `?`) into decoded pairs
+pub fn parse_pairs(query: &str) -> Vec<(String, String)> {
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode_component(key), decode_component(value))
+        })
+        .collect()
+}
+
+pub fn encode_component(value: &str) -> String {
+    js_sys::encode_uri_component(value).into()
+}
+
+/// Decode a query component, treating `+` as a space; malformed input is
+/// returned unchanged
+pub fn decode_component(value: &str) -> String {
+    let value = value.replace('+', " ");
+    js_sys::decode_uri_component(&value)
+        .map(String::from)
+        .unwrap_or(value)
+}
+
+/// Page state that round-trips through the URL query string
+///
+/// Implementors only describe their fields as key/value pairs; the
+/// `query_segment!` macro wires them into the router so the state survives
+/// refresh and can be shared as a link.
+pub trait QueryParams: Default {
+    /// Pairs to serialize; empty values are omitted
+    fn to_pairs(&self) -> Vec<(&'static str, String)>;
+
+    /// Rebuild from decoded pairs, ignoring unknown keys and bad values
+    fn from_pairs(pairs: &[(String, String)]) -> Self;
+
+    fn to_query_string(&self) -> String {
+        encode_pairs(&self.to_pairs())
+    }
+
+    fn from_query_string(query: &str) -> Self {
+        Self::from_pairs(&parse_pairs(query))
+    }
+}
+
+/// Implement the router's query segment traits (`Display` and
+/// `From<&str>`) for a `QueryParams` type
+macro_rules! query_segment {
+    ($ty:ty) => {
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", $crate::utils::query_string::QueryParams::to_query_string(self))
+            }
+        }
+
+        impl From<&str> for $ty {
+            fn from(query: &str) -> Self {
+                <$ty as $crate::utils::query_string::QueryParams>::from_query_string(query)
+            }
+        }
+    };
+}
+pub(crate) use query_segment;
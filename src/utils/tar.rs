@@ -0,0 +1,59 @@
+// Minimal ustar archive writer for exports
+
+const BLOCK_SIZE: usize = 512;
+
+/// Builds an uncompressed tar archive in memory
+#[derive(Default)]
+pub struct TarBuilder {
+    buffer: Vec<u8>,
+}
+
+impl TarBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a regular file; `name` must be at most 100 bytes
+    pub fn add_file(&mut self, name: &str, data: &[u8], modified: i64) {
+        let mut header = [0u8; BLOCK_SIZE];
+
+        let name_bytes = name.as_bytes();
+        let name_len = name_bytes.len().min(100);
+        header[..name_len].copy_from_slice(&name_bytes[..name_len]);
+
+        write_octal(&mut header[100..108], 0o644);
+        write_octal(&mut header[108..116], 0);
+        write_octal(&mut header[116..124], 0);
+        write_octal(&mut header[124..136], data.len() as u64);
+        write_octal(&mut header[136..148], modified.max(0) as u64);
+        header[156] = b'0';
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+
+        // Checksum is computed with the checksum field filled with spaces
+        header[148..156].fill(b' ');
+        let checksum: u64 = header.iter().map(|byte| *byte as u64).sum();
+        write_octal(&mut header[148..155], checksum);
+        header[155] = b' ';
+
+        self.buffer.extend_from_slice(&header);
+        self.buffer.extend_from_slice(data);
+        let padding = (BLOCK_SIZE - data.len() % BLOCK_SIZE) % BLOCK_SIZE;
+        self.buffer.extend(std::iter::repeat_n(0u8, padding));
+    }
+
+    /// Finish the archive with the two terminating zero blocks
+    pub fn finish(mut self) -> Vec<u8> {
+        self.buffer.extend(std::iter::repeat_n(0u8, BLOCK_SIZE * 2));
+        self.buffer
+    }
+}
+
+/// Zero-padded, NUL-terminated octal as tar headers expect
+fn write_octal(field: &mut [u8], value: u64) {
+    let digits = field.len() - 1;
+    let formatted = format!("{:0width$o}", value, width = digits);
+    let start = formatted.len().saturating_sub(digits);
+    field[..digits].copy_from_slice(&formatted.as_bytes()[start..]);
+    field[digits] = 0;
+}